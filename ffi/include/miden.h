@@ -0,0 +1,89 @@
+#ifndef MIDEN_H
+#define MIDEN_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* CONSTANTS */
+
+#define MIDEN_FALCON_PUBLIC_KEY_LEN 897
+
+#define MIDEN_ACCOUNT_TYPE_REGULAR_IMMUTABLE_CODE 0
+#define MIDEN_ACCOUNT_TYPE_REGULAR_UPDATABLE_CODE 1
+
+#define MIDEN_NOTE_TYPE_PUBLIC 1
+#define MIDEN_NOTE_TYPE_PRIVATE 2
+#define MIDEN_NOTE_TYPE_ENCRYPTED 3
+
+/* ERROR CODES */
+
+typedef enum MidenErrorCode {
+    MIDEN_OK = 0,
+    MIDEN_NULL_POINTER = 1,
+    MIDEN_INVALID_ACCOUNT_ID = 2,
+    MIDEN_INVALID_ACCOUNT_TYPE = 3,
+    MIDEN_INVALID_PUBLIC_KEY = 4,
+    MIDEN_INVALID_WORD = 5,
+    MIDEN_INVALID_ASSET = 6,
+    MIDEN_CREATE_ACCOUNT_FAILED = 7,
+    MIDEN_CREATE_NOTE_FAILED = 8,
+    MIDEN_DESERIALIZATION_FAILED = 9,
+    MIDEN_INVALID_NOTE_METADATA = 10,
+} MidenErrorCode;
+
+/* STRUCTS */
+
+/* A word consisting of four field elements, each represented by its canonical integer value. */
+typedef struct MidenWord {
+    uint64_t elements[4];
+} MidenWord;
+
+/* A byte buffer owned by the caller, which must be released via miden_buffer_free(). */
+typedef struct MidenBuffer {
+    uint8_t *data;
+    size_t len;
+} MidenBuffer;
+
+/* ACCOUNT IDS */
+
+MidenErrorCode miden_account_id_from_hex(const char *hex, uint64_t *out_account_id);
+
+MidenErrorCode miden_account_id_to_hex(uint64_t account_id, MidenBuffer *out_hex);
+
+/* ACCOUNTS */
+
+MidenErrorCode miden_create_basic_wallet(
+    const uint8_t *init_seed,
+    const uint8_t *public_key,
+    uint8_t account_type,
+    MidenBuffer *out_account,
+    uint64_t *out_account_id,
+    MidenWord *out_account_seed);
+
+/* NOTES */
+
+MidenErrorCode miden_create_p2id_note(
+    uint64_t sender,
+    uint64_t target,
+    const MidenWord *assets,
+    size_t num_assets,
+    uint8_t note_type,
+    uint64_t aux,
+    const MidenWord *serial_seed,
+    MidenBuffer *out_note);
+
+MidenErrorCode miden_note_id(const uint8_t *note, size_t len, MidenWord *out_note_id);
+
+/* BUFFERS */
+
+void miden_buffer_free(MidenBuffer buffer);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MIDEN_H */
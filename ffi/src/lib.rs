@@ -0,0 +1,464 @@
+use core::{ffi::c_char, ptr, slice};
+use std::ffi::CStr;
+
+use miden_lib::{accounts::wallets::create_basic_wallet, notes::create_p2id_note, AuthScheme};
+use miden_objects::{
+    accounts::{AccountId, AccountType},
+    assets::Asset,
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
+    notes::{Note, NoteType},
+    utils::serde::{Deserializable, Serializable},
+    Felt, Word,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The length in bytes of an encoded RPO Falcon512 public key.
+pub const MIDEN_FALCON_PUBLIC_KEY_LEN: usize = 897;
+
+/// The account type of regular accounts with immutable code.
+pub const MIDEN_ACCOUNT_TYPE_REGULAR_IMMUTABLE_CODE: u8 = 0;
+
+/// The account type of regular accounts with updatable code.
+pub const MIDEN_ACCOUNT_TYPE_REGULAR_UPDATABLE_CODE: u8 = 1;
+
+/// The note type of notes the details of which are stored on-chain.
+pub const MIDEN_NOTE_TYPE_PUBLIC: u8 = 1;
+
+/// The note type of notes the details of which are shared off-chain.
+pub const MIDEN_NOTE_TYPE_PRIVATE: u8 = 2;
+
+/// The note type of notes the details of which are stored on-chain in encrypted form.
+pub const MIDEN_NOTE_TYPE_ENCRYPTED: u8 = 3;
+
+// ERROR CODES
+// ================================================================================================
+
+/// The result of a call to one of the functions of this crate.
+///
+/// The numeric values of the error codes are stable and must not be changed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidenErrorCode {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidAccountId = 2,
+    InvalidAccountType = 3,
+    InvalidPublicKey = 4,
+    InvalidWord = 5,
+    InvalidAsset = 6,
+    CreateAccountFailed = 7,
+    CreateNoteFailed = 8,
+    DeserializationFailed = 9,
+    InvalidNoteMetadata = 10,
+}
+
+// STRUCTS
+// ================================================================================================
+
+/// A word consisting of four field elements, each represented by its canonical integer value.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MidenWord {
+    pub elements: [u64; 4],
+}
+
+impl TryFrom<MidenWord> for Word {
+    type Error = MidenErrorCode;
+
+    fn try_from(word: MidenWord) -> Result<Self, Self::Error> {
+        let mut result = [Felt::default(); 4];
+        for (felt, &element) in result.iter_mut().zip(word.elements.iter()) {
+            *felt = Felt::try_from(element).map_err(|_| MidenErrorCode::InvalidWord)?;
+        }
+        Ok(result)
+    }
+}
+
+impl From<Word> for MidenWord {
+    fn from(word: Word) -> Self {
+        Self { elements: word.map(|felt| felt.as_int()) }
+    }
+}
+
+/// A byte buffer allocated by this crate.
+///
+/// Buffers returned by the functions of this crate are owned by the caller and must be released
+/// via [miden_buffer_free()].
+#[repr(C)]
+#[derive(Debug)]
+pub struct MidenBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl From<Vec<u8>> for MidenBuffer {
+    fn from(bytes: Vec<u8>) -> Self {
+        let bytes = bytes.into_boxed_slice();
+        let len = bytes.len();
+        let data = Box::into_raw(bytes) as *mut u8;
+        Self { data, len }
+    }
+}
+
+// ACCOUNT IDS
+// ================================================================================================
+
+/// Parses the provided nul-terminated, big-endian hex string (e.g., "0x8a65fc5a39e4cd16") into an
+/// account ID, and writes the account ID into `out_account_id`.
+///
+/// # Safety
+/// `hex` must be a valid nul-terminated string, and `out_account_id` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_account_id_from_hex(
+    hex: *const c_char,
+    out_account_id: *mut u64,
+) -> MidenErrorCode {
+    if hex.is_null() || out_account_id.is_null() {
+        return MidenErrorCode::NullPointer;
+    }
+
+    let hex = match CStr::from_ptr(hex).to_str() {
+        Ok(hex) => hex,
+        Err(_) => return MidenErrorCode::InvalidAccountId,
+    };
+    match AccountId::from_hex(hex) {
+        Ok(account_id) => {
+            *out_account_id = account_id.into();
+            MidenErrorCode::Ok
+        },
+        Err(_) => MidenErrorCode::InvalidAccountId,
+    }
+}
+
+/// Writes the big-endian hex encoding of the provided account ID into `out_hex`; the encoding is
+/// not nul-terminated.
+///
+/// # Safety
+/// `out_hex` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_account_id_to_hex(
+    account_id: u64,
+    out_hex: *mut MidenBuffer,
+) -> MidenErrorCode {
+    if out_hex.is_null() {
+        return MidenErrorCode::NullPointer;
+    }
+
+    match AccountId::try_from(account_id) {
+        Ok(account_id) => {
+            *out_hex = account_id.to_hex().into_bytes().into();
+            MidenErrorCode::Ok
+        },
+        Err(_) => MidenErrorCode::InvalidAccountId,
+    }
+}
+
+// ACCOUNTS
+// ================================================================================================
+
+/// Creates a new basic wallet account authenticated via RPO Falcon512 signatures of the provided
+/// public key.
+///
+/// On success, the serialized account is written into `out_account`, its ID into
+/// `out_account_id`, and the seed of the account into `out_account_seed`; the seed is required to
+/// execute the first transaction against the account.
+///
+/// # Safety
+/// `init_seed` must point to 32 readable bytes, `public_key` must point to
+/// [MIDEN_FALCON_PUBLIC_KEY_LEN] readable bytes, and the output pointers must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_create_basic_wallet(
+    init_seed: *const u8,
+    public_key: *const u8,
+    account_type: u8,
+    out_account: *mut MidenBuffer,
+    out_account_id: *mut u64,
+    out_account_seed: *mut MidenWord,
+) -> MidenErrorCode {
+    if init_seed.is_null()
+        || public_key.is_null()
+        || out_account.is_null()
+        || out_account_id.is_null()
+        || out_account_seed.is_null()
+    {
+        return MidenErrorCode::NullPointer;
+    }
+
+    let account_type = match account_type {
+        MIDEN_ACCOUNT_TYPE_REGULAR_IMMUTABLE_CODE => AccountType::RegularAccountImmutableCode,
+        MIDEN_ACCOUNT_TYPE_REGULAR_UPDATABLE_CODE => AccountType::RegularAccountUpdatableCode,
+        _ => return MidenErrorCode::InvalidAccountType,
+    };
+
+    let mut pk_bytes = [0u8; MIDEN_FALCON_PUBLIC_KEY_LEN];
+    ptr::copy_nonoverlapping(public_key, pk_bytes.as_mut_ptr(), MIDEN_FALCON_PUBLIC_KEY_LEN);
+    let pub_key = match PublicKey::new(pk_bytes) {
+        Ok(pub_key) => pub_key,
+        Err(_) => return MidenErrorCode::InvalidPublicKey,
+    };
+
+    let mut seed = [0u8; 32];
+    ptr::copy_nonoverlapping(init_seed, seed.as_mut_ptr(), seed.len());
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key };
+    match create_basic_wallet(seed, auth_scheme, account_type, None) {
+        Ok((account, account_seed)) => {
+            *out_account_id = account.id().into();
+            *out_account_seed = account_seed.into();
+            *out_account = account.to_bytes().into();
+            MidenErrorCode::Ok
+        },
+        Err(_) => MidenErrorCode::CreateAccountFailed,
+    }
+}
+
+// NOTES
+// ================================================================================================
+
+/// Creates a P2ID note which sends the provided assets from the sender account to the target
+/// account, and writes the serialized note into `out_note`.
+///
+/// The assets are provided as `num_assets` words, and the serial number of the note is drawn
+/// from a random coin seeded with `serial_seed`. `note_type` must be one of the `MIDEN_NOTE_TYPE_*`
+/// constants, and `aux` must be a canonical field element.
+///
+/// # Safety
+/// `assets` must point to `num_assets` readable words, `serial_seed` must be valid for reads, and
+/// `out_note` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_create_p2id_note(
+    sender: u64,
+    target: u64,
+    assets: *const MidenWord,
+    num_assets: usize,
+    note_type: u8,
+    aux: u64,
+    serial_seed: *const MidenWord,
+    out_note: *mut MidenBuffer,
+) -> MidenErrorCode {
+    if (assets.is_null() && num_assets > 0) || serial_seed.is_null() || out_note.is_null() {
+        return MidenErrorCode::NullPointer;
+    }
+
+    let (sender, target) = match (AccountId::try_from(sender), AccountId::try_from(target)) {
+        (Ok(sender), Ok(target)) => (sender, target),
+        _ => return MidenErrorCode::InvalidAccountId,
+    };
+
+    let assets = if num_assets == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(assets, num_assets)
+    };
+    let mut note_assets = Vec::with_capacity(assets.len());
+    for &asset in assets {
+        let asset = match Word::try_from(asset) {
+            Ok(asset) => asset,
+            Err(err) => return err,
+        };
+        match Asset::try_from(asset) {
+            Ok(asset) => note_assets.push(asset),
+            Err(_) => return MidenErrorCode::InvalidAsset,
+        }
+    }
+
+    let (note_type, aux) = match (NoteType::try_from(note_type), Felt::try_from(aux)) {
+        (Ok(note_type), Ok(aux)) => (note_type, aux),
+        _ => return MidenErrorCode::InvalidNoteMetadata,
+    };
+
+    let serial_seed = match Word::try_from(*serial_seed) {
+        Ok(serial_seed) => serial_seed,
+        Err(err) => return err,
+    };
+
+    let rng = RpoRandomCoin::new(serial_seed);
+    match create_p2id_note(sender, target, note_assets, note_type, aux, rng) {
+        Ok(note) => {
+            *out_note = note.to_bytes().into();
+            MidenErrorCode::Ok
+        },
+        Err(_) => MidenErrorCode::CreateNoteFailed,
+    }
+}
+
+/// Deserializes the provided note, and writes the ID of the note into `out_note_id`.
+///
+/// # Safety
+/// `note` must point to `len` readable bytes, and `out_note_id` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_note_id(
+    note: *const u8,
+    len: usize,
+    out_note_id: *mut MidenWord,
+) -> MidenErrorCode {
+    if note.is_null() || out_note_id.is_null() {
+        return MidenErrorCode::NullPointer;
+    }
+
+    match Note::read_from_bytes(slice::from_raw_parts(note, len)) {
+        Ok(note) => {
+            *out_note_id = Word::from(note.id()).into();
+            MidenErrorCode::Ok
+        },
+        Err(_) => MidenErrorCode::DeserializationFailed,
+    }
+}
+
+// BUFFERS
+// ================================================================================================
+
+/// Releases the provided buffer; buffers with a null data pointer are ignored.
+///
+/// # Safety
+/// `buffer` must have been returned by one of the functions of this crate, and must not be used
+/// after it has been released.
+#[no_mangle]
+pub unsafe extern "C" fn miden_buffer_free(buffer: MidenBuffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)));
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use core::{ptr, slice};
+    use std::ffi::CString;
+
+    use miden_objects::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        },
+        assets::FungibleAsset,
+        Word,
+    };
+
+    use super::{
+        miden_account_id_from_hex, miden_account_id_to_hex, miden_buffer_free,
+        miden_create_basic_wallet, miden_create_p2id_note, miden_note_id, MidenBuffer,
+        MidenErrorCode, MidenWord, MIDEN_FALCON_PUBLIC_KEY_LEN, MIDEN_NOTE_TYPE_PRIVATE,
+    };
+
+    fn empty_buffer() -> MidenBuffer {
+        MidenBuffer { data: ptr::null_mut(), len: 0 }
+    }
+
+    #[test]
+    fn account_id_hex_round_trip() {
+        let account_id = ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN;
+
+        let mut hex = empty_buffer();
+        let result = unsafe { miden_account_id_to_hex(account_id, &mut hex) };
+        assert_eq!(result, MidenErrorCode::Ok);
+        let hex_bytes = unsafe { slice::from_raw_parts(hex.data, hex.len) }.to_vec();
+        unsafe { miden_buffer_free(hex) };
+
+        let hex = CString::new(hex_bytes).unwrap();
+        let mut parsed = 0;
+        let result = unsafe { miden_account_id_from_hex(hex.as_ptr(), &mut parsed) };
+        assert_eq!(result, MidenErrorCode::Ok);
+        assert_eq!(parsed, account_id);
+
+        let hex = CString::new("0xzz").unwrap();
+        let result = unsafe { miden_account_id_from_hex(hex.as_ptr(), &mut parsed) };
+        assert_eq!(result, MidenErrorCode::InvalidAccountId);
+        let result = unsafe { miden_account_id_from_hex(ptr::null(), &mut parsed) };
+        assert_eq!(result, MidenErrorCode::NullPointer);
+    }
+
+    #[test]
+    fn create_p2id_note_and_compute_id() {
+        let sender = ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN;
+        let target = ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN;
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Word = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let assets = [MidenWord::from(asset)];
+        let serial_seed = MidenWord { elements: [1, 2, 3, 4] };
+
+        let mut note = empty_buffer();
+        let result = unsafe {
+            miden_create_p2id_note(
+                sender,
+                target,
+                assets.as_ptr(),
+                1,
+                MIDEN_NOTE_TYPE_PRIVATE,
+                0,
+                &serial_seed,
+                &mut note,
+            )
+        };
+        assert_eq!(result, MidenErrorCode::Ok);
+
+        let mut note_id = MidenWord::default();
+        let result = unsafe { miden_note_id(note.data, note.len, &mut note_id) };
+        assert_eq!(result, MidenErrorCode::Ok);
+        assert_ne!(note_id, MidenWord::default());
+
+        // truncated notes cannot be deserialized
+        let result = unsafe { miden_note_id(note.data, note.len - 1, &mut note_id) };
+        assert_eq!(result, MidenErrorCode::DeserializationFailed);
+        unsafe { miden_buffer_free(note) };
+
+        // the note type must be valid
+        let mut note = empty_buffer();
+        let result = unsafe {
+            miden_create_p2id_note(
+                sender,
+                target,
+                assets.as_ptr(),
+                1,
+                0,
+                0,
+                &serial_seed,
+                &mut note,
+            )
+        };
+        assert_eq!(result, MidenErrorCode::InvalidNoteMetadata);
+
+        // assets must be valid
+        let assets = [MidenWord { elements: [1, 2, 3, 4] }];
+        let mut note = empty_buffer();
+        let result = unsafe {
+            miden_create_p2id_note(
+                sender,
+                target,
+                assets.as_ptr(),
+                1,
+                MIDEN_NOTE_TYPE_PRIVATE,
+                0,
+                &serial_seed,
+                &mut note,
+            )
+        };
+        assert_eq!(result, MidenErrorCode::InvalidAsset);
+    }
+
+    #[test]
+    fn create_basic_wallet_rejects_invalid_account_type() {
+        let init_seed = [0u8; 32];
+        let public_key = [0u8; MIDEN_FALCON_PUBLIC_KEY_LEN];
+        let mut account = empty_buffer();
+        let mut account_id = 0;
+        let mut account_seed = MidenWord::default();
+
+        let result = unsafe {
+            miden_create_basic_wallet(
+                init_seed.as_ptr(),
+                public_key.as_ptr(),
+                2,
+                &mut account,
+                &mut account_id,
+                &mut account_seed,
+            )
+        };
+        assert_eq!(result, MidenErrorCode::InvalidAccountType);
+    }
+}
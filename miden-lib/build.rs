@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     env, fs,
     fs::File,
     io::{self, BufRead, BufReader, Write},
@@ -18,6 +19,13 @@ const ASM_DIR: &str = "asm";
 const ASM_MIDEN_DIR: &str = "miden";
 const ASM_NOTE_SCRIPTS_DIR: &str = "note_scripts";
 const ASM_KERNELS_DIR: &str = "kernels/transaction";
+const ASM_TX_KERNEL_LIB_DIR: &str = "miden/kernels/tx";
+const KERNEL_ERRORS_FILE: &str = "tx_kernel_errors.rs";
+const KERNEL_MEMORY_FILE: &str = "tx_kernel_memory.rs";
+
+/// Constants defined outside of the memory module of the transaction kernel which are part of the
+/// memory layout exposed by `miden_lib::transaction::memory`.
+const KERNEL_MEMORY_CONSTANTS: &[&str] = &["NOTE_MEM_SIZE"];
 
 // PRE-PROCESSING
 // ================================================================================================
@@ -54,6 +62,20 @@ fn main() -> io::Result<()> {
         &target_dir.join(ASM_NOTE_SCRIPTS_DIR),
     )?;
 
+    // generate the constants for the errors of the transaction kernel
+    generate_kernel_errors(
+        &[source_dir.join(ASM_TX_KERNEL_LIB_DIR), source_dir.join(ASM_KERNELS_DIR)],
+        &Path::new(&build_dir).join(KERNEL_ERRORS_FILE),
+    )?;
+
+    // generate the constants for the memory layout of the transaction kernel
+    let tx_kernel_lib_dir = source_dir.join(ASM_TX_KERNEL_LIB_DIR);
+    generate_kernel_memory_layout(
+        &tx_kernel_lib_dir.join("memory.masm"),
+        &tx_kernel_lib_dir.join("constants.masm"),
+        &Path::new(&build_dir).join(KERNEL_MEMORY_FILE),
+    )?;
+
     Ok(())
 }
 
@@ -157,6 +179,161 @@ fn compile_kernels(source_dir: &Path, target_dir: &Path) -> io::Result<()> {
     Ok(())
 }
 
+// GENERATE KERNEL ERRORS
+// ================================================================================================
+
+/// Generates a Rust file with a constant for each error of the transaction kernel.
+///
+/// Errors are defined in the MASM sources as constants whose name starts with `ERR_`, e.g.:
+///
+/// ```masm
+/// # Nonce of a new account must be ZERO.
+/// const.ERR_PROLOGUE_NEW_ACCOUNT_NONCE_NOT_ZERO=524325
+/// ```
+///
+/// The comment preceding the constant is used as the description of the error.
+///
+/// Panics:
+/// - If two errors have the same name but different codes, or the same code but different names.
+fn generate_kernel_errors(source_dirs: &[PathBuf], target_file: &Path) -> io::Result<()> {
+    let mut errors = BTreeMap::<u32, (String, String)>::new();
+    for source_dir in source_dirs {
+        for masm_file_path in get_masm_files(source_dir)? {
+            for (code, name, description) in parse_kernel_errors(&masm_file_path)? {
+                match errors.get(&code) {
+                    Some((existing, _)) if *existing != name => {
+                        panic!("kernel errors {existing} and {name} have the same code {code}")
+                    },
+                    _ => {
+                        errors.insert(code, (name, description));
+                    },
+                }
+            }
+        }
+    }
+
+    let mut names = errors.values().map(|(name, _)| name).collect::<Vec<_>>();
+    names.sort();
+    if let Some(name) = names.windows(2).find(|pair| pair[0] == pair[1]) {
+        panic!("kernel error {} is defined with more than one code", name[0]);
+    }
+
+    let mut output = String::from(
+        "// This file is generated by build.rs from the MASM sources of the transaction kernel.\n\n",
+    );
+    for (code, (name, description)) in errors.iter() {
+        output.push_str(&format!("/// {description}\npub const {name}: u32 = {code};\n\n"));
+    }
+    output.push_str("/// All errors of the transaction kernel, sorted by code.\n");
+    output.push_str("pub const TX_KERNEL_ERRORS: &[TxKernelError] = &[\n");
+    for (name, description) in errors.values() {
+        output.push_str(&format!(
+            "    TxKernelError {{ code: {name}, name: {name:?}, description: {description:?} }},\n"
+        ));
+    }
+    output.push_str("];\n");
+
+    fs::write(target_file, output)
+}
+
+/// Returns the code, name and description of all kernel errors defined in the specified MASM file.
+fn parse_kernel_errors(masm_file_path: &Path) -> io::Result<Vec<(u32, String, String)>> {
+    let mut errors = Vec::new();
+    for (name, code, comment) in parse_constants(masm_file_path)? {
+        if name.starts_with("ERR_") {
+            let code = parse_constant_value(&name, &code)?;
+            errors.push((code, name, comment.join(" ")));
+        }
+    }
+
+    Ok(errors)
+}
+
+// GENERATE KERNEL MEMORY LAYOUT
+// ================================================================================================
+
+/// Generates a Rust file with a constant for each address and offset of the memory layout of the
+/// transaction kernel.
+///
+/// The memory layout is defined by the constants of the memory module of the transaction kernel,
+/// e.g.:
+///
+/// ```masm
+/// # The memory address at which a pointer to the consumed note being executed is stored.
+/// const.CURRENT_CONSUMED_NOTE_PTR=1
+/// ```
+///
+/// In addition, the constants listed in [KERNEL_MEMORY_CONSTANTS] are taken from the constants
+/// module of the kernel. Constants whose name ends with `_PTR` are typed as `MemoryAddress`, all
+/// other constants as `MemoryOffset`. The comment preceding a constant is used as its doc comment.
+fn generate_kernel_memory_layout(
+    memory_file: &Path,
+    constants_file: &Path,
+    target_file: &Path,
+) -> io::Result<()> {
+    let mut constants = parse_constants(memory_file)?;
+    constants.extend(
+        parse_constants(constants_file)?
+            .into_iter()
+            .filter(|(name, ..)| KERNEL_MEMORY_CONSTANTS.contains(&name.as_str())),
+    );
+
+    let mut output = String::from(
+        "// This file is generated by build.rs from the MASM sources of the transaction kernel.\n\n",
+    );
+    for (name, value, comment) in constants {
+        let value = parse_constant_value(&name, &value)?;
+        let ty = if name.ends_with("_PTR") {
+            "MemoryAddress"
+        } else {
+            "MemoryOffset"
+        };
+        for line in comment {
+            output.push_str(&format!("/// {line}\n"));
+        }
+        output.push_str(&format!("pub const {name}: {ty} = {value};\n\n"));
+    }
+
+    fs::write(target_file, output)
+}
+
+// PARSE CONSTANTS
+// ================================================================================================
+
+/// Returns the name, value and preceding comment lines of all constants defined in the specified
+/// MASM file.
+fn parse_constants(masm_file_path: &Path) -> io::Result<Vec<(String, String, Vec<String>)>> {
+    let mut constants = Vec::new();
+    let mut comment = Vec::new();
+    for line in fs::read_to_string(masm_file_path)?.lines() {
+        let line = line.trim();
+        if let Some(text) = line.strip_prefix("# ") {
+            comment.push(text.trim().to_string());
+            continue;
+        }
+
+        if let Some((name, value)) =
+            line.strip_prefix("const.").and_then(|constant| constant.split_once('='))
+        {
+            let value = value.split('#').next().unwrap_or_default().trim();
+            constants.push((name.to_string(), value.to_string(), comment.clone()));
+        }
+        comment.clear();
+    }
+
+    Ok(constants)
+}
+
+/// Parses the value of the constant with the specified name as a u32.
+fn parse_constant_value(name: &str, value: &str) -> io::Result<u32> {
+    value.parse::<u32>().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid value of constant {name}: {value}"),
+        )
+    })
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,168 @@
+use miden_objects::{
+    accounts::{AccountComponent, ComponentStorageSlot},
+    assets::TokenSymbol,
+    utils::{format, string::String, vec},
+    AccountError, Felt, ZERO,
+};
+
+use super::{
+    faucets::{build_fungible_faucet_metadata, FungibleFaucet},
+    wallets::{SpendingLimit, OWNER_PUBLIC_KEY_SLOT, SPENDING_LIMIT_SLOT, SPENDING_OUTFLOW_SLOT},
+    AuthScheme,
+};
+use crate::auth::{build_multisig_config, build_session_key_config, build_threshold_config};
+
+// AUTHENTICATION
+// ================================================================================================
+
+/// Returns the component which authenticates transactions using the specified authentication
+/// scheme.
+///
+/// The component exports the authentication procedures of the scheme and declares the storage
+/// slots holding its public key information, as described in
+/// [create_basic_wallet()](super::wallets::create_basic_wallet). If `updatable_code` is true and
+/// the scheme is [AuthScheme::RpoFalcon512], the component also exports `update_code`; this must
+/// only be set for accounts with updatable code.
+///
+/// # Errors
+/// Returns an error if the configuration of the authentication scheme is invalid.
+pub fn auth_component(
+    auth_scheme: AuthScheme,
+    updatable_code: bool,
+) -> Result<AccountComponent, AccountError> {
+    let (procedures, storage_slots): (&[&str], _) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => (
+            if updatable_code {
+                &["auth_tx_rpo_falcon512", "rotate_key", "update_code"]
+            } else {
+                &["auth_tx_rpo_falcon512", "rotate_key"]
+            },
+            vec![(0, ComponentStorageSlot::Value(pub_key.into()))],
+        ),
+        AuthScheme::RpoFalcon512Multisig { pub_keys, threshold } => (
+            &["auth_tx_rpo_falcon512_multisig"],
+            vec![
+                (0, ComponentStorageSlot::Value(build_multisig_config(&pub_keys, threshold)?)),
+                (1, ComponentStorageSlot::Words(pub_keys)),
+            ],
+        ),
+        AuthScheme::RpoFalcon512Threshold { pub_keys, threshold } => (
+            &["auth_tx_rpo_falcon512_threshold"],
+            vec![(0, ComponentStorageSlot::Words(build_threshold_config(&pub_keys, threshold)?))],
+        ),
+        AuthScheme::SessionKey { root_key, session_key, expires_at_block } => (
+            &["auth_tx_rpo_falcon512", "auth_tx_rpo_falcon512_session_key", "set_session_key"],
+            vec![
+                (0, ComponentStorageSlot::Value(root_key.into())),
+                (
+                    1,
+                    ComponentStorageSlot::Words(build_session_key_config(
+                        session_key,
+                        expires_at_block,
+                    )),
+                ),
+            ],
+        ),
+        AuthScheme::NoAuth => (&["auth_tx_no_auth"], vec![]),
+    };
+
+    let source =
+        format!("use.miden::contracts::auth::basic\n{}", build_exports("basic", procedures));
+
+    let mut component = AccountComponent::new(source);
+    for (index, slot) in storage_slots {
+        component = component.with_storage_slot(index, slot);
+    }
+
+    Ok(component)
+}
+
+// BASIC WALLET
+// ================================================================================================
+
+/// Returns the component which implements the basic wallet interface.
+///
+/// The component exports the procedures described in
+/// [create_basic_wallet()](super::wallets::create_basic_wallet). If a [SpendingLimit] is
+/// specified, the component declares the slots [SPENDING_LIMIT_SLOT], [OWNER_PUBLIC_KEY_SLOT] and
+/// [SPENDING_OUTFLOW_SLOT]; otherwise, it does not use any storage slots.
+///
+/// The procedures of the basic wallet require authentication, and thus the component must be
+/// combined with an authentication component (see [auth_component()]). The read-only procedures
+/// of the `miden::contracts::wallets::basic_views` module, which do not require authentication,
+/// are exported in both cases.
+pub fn basic_wallet_component(spending_limit: Option<SpendingLimit>) -> AccountComponent {
+    const IMPORT: &str = concat!(
+        "use.miden::contracts::wallets::basic->basic_wallet\n",
+        "use.miden::contracts::wallets::basic_views\n",
+    );
+    const VIEWS: [&str; 2] = ["get_balance", "get_public_key"];
+
+    let build_source = |procedures: &[&str]| {
+        format!(
+            "{IMPORT}{}{}",
+            build_exports("basic_wallet", procedures),
+            build_exports("basic_views", &VIEWS)
+        )
+    };
+
+    match spending_limit {
+        None => {
+            let procedures = ["receive_asset", "send_asset", "add_asset_to_note", "fill_swap"];
+            AccountComponent::new(build_source(&procedures))
+        },
+        Some(SpendingLimit { limit, owner_key }) => {
+            let procedures = ["receive_asset", "send_asset_with_limit", "unlock_spending_limit"];
+            AccountComponent::new(build_source(&procedures))
+                .with_storage_slot(SPENDING_LIMIT_SLOT, ComponentStorageSlot::Value(limit.into()))
+                .with_storage_slot(
+                    OWNER_PUBLIC_KEY_SLOT,
+                    ComponentStorageSlot::Value(owner_key.into()),
+                )
+                .with_storage_slot(SPENDING_OUTFLOW_SLOT, ComponentStorageSlot::Value([ZERO; 4]))
+        },
+    }
+}
+
+// BASIC FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Returns the component which implements the basic fungible faucet interface with the provided
+/// token metadata.
+///
+/// The component exports `distribute` and `burn` (see
+/// [create_basic_fungible_faucet()](super::faucets::create_basic_fungible_faucet)) and declares
+/// the slot at which the token metadata is stored. Minting requires the transaction to be
+/// authenticated, and thus the component must be combined with an authentication component (see
+/// [auth_component()]). The component can only be used by fungible faucet accounts.
+///
+/// # Errors
+/// Returns an error if the token metadata is invalid.
+pub fn basic_fungible_faucet_component(
+    symbol: TokenSymbol,
+    decimals: u8,
+    max_supply: Felt,
+) -> Result<AccountComponent, AccountError> {
+    let metadata = build_fungible_faucet_metadata(symbol, decimals, max_supply)?;
+
+    let source = "
+    use.miden::contracts::faucets::basic_fungible
+    export.basic_fungible::distribute
+    export.basic_fungible::burn
+    ";
+
+    Ok(AccountComponent::new(source)
+        .with_storage_slot(FungibleFaucet::METADATA_SLOT, ComponentStorageSlot::Value(metadata)))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the statements re-exporting the specified procedures of the library imported under
+/// the specified alias.
+fn build_exports(alias: &str, procedures: &[&str]) -> String {
+    procedures
+        .iter()
+        .map(|procedure| format!("export.{alias}::{procedure}\n"))
+        .collect()
+}
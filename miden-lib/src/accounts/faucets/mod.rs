@@ -1,12 +1,14 @@
 use miden_objects::{
-    accounts::{Account, AccountCode, AccountId, AccountStorage, AccountType, StorageSlotType},
+    accounts::{Account, AccountBuilder, AccountType, StorageMap, StorageSlotType},
     assembly::LibraryPath,
-    assets::{AssetVault, TokenSymbol},
-    utils::{string::ToString, vec},
-    AccountError, Felt, Word, ZERO,
+    assets::{NonFungibleAsset, TokenSymbol},
+    crypto::merkle::Smt,
+    utils::string::ToString,
+    AccountError, Digest, Felt, StarkField, Word, ZERO,
 };
 
 use super::{AuthScheme, Library, MidenLib, TransactionKernel};
+use crate::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
 
 // FUNGIBLE FAUCET
 // ================================================================================================
@@ -25,7 +27,9 @@ const MAX_DECIMALS: u8 = 12;
 /// authentication scheme. `burn` does not require authentication and can be called by anyone.
 ///
 /// Public key information for the scheme is stored in the account storage at slot 0. The token
-/// metadata is stored in the account storage at slot 1.
+/// metadata is stored in the account storage at slot 1. The total supply issued so far is tracked
+/// by the transaction kernel in the faucet reserved slot, and `distribute` fails if minting would
+/// raise it above `max_supply` (see [FungibleFaucet::issued_supply()]).
 pub fn create_basic_fungible_faucet(
     init_seed: [u8; 32],
     symbol: TokenSymbol,
@@ -38,6 +42,14 @@ pub fn create_basic_fungible_faucet(
     // TODO: consider using a trait when we have more auth schemes.
     let auth_data: Word = match auth_scheme {
         AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+        AuthScheme::RpoFalcon512Multisig { .. }
+        | AuthScheme::RpoFalcon512Threshold { .. }
+        | AuthScheme::SessionKey { .. }
+        | AuthScheme::NoAuth => {
+            return Err(AccountError::AuthSchemeNotSupported(
+                "only single-key authentication is supported by this account type".to_string(),
+            ))
+        },
     };
 
     let miden = MidenLib::default();
@@ -46,10 +58,29 @@ pub fn create_basic_fungible_faucet(
         .get_module_ast(&LibraryPath::new(path).unwrap())
         .expect("Getting module AST failed");
 
-    let account_assembler = TransactionKernel::assembler();
-    let account_code = AccountCode::new(faucet_code_ast.clone(), &account_assembler)?;
+    let metadata = build_fungible_faucet_metadata(symbol, decimals, max_supply)?;
 
-    // First check that the metadata is valid.
+    // We store the authentication data and the token metadata in the account storage:
+    // - slot 0: authentication data
+    // - slot 1: token metadata as [max_supply, decimals, token_symbol, 0]
+    AccountBuilder::new(TransactionKernel::assembler())
+        .code(faucet_code_ast.clone())
+        .storage_slot(0, auth_data)
+        .storage_slot(1, metadata)
+        .account_type(AccountType::FungibleFaucet)
+        .build(init_seed)
+}
+
+/// Returns the token metadata of a basic fungible faucet as stored in the account storage, i.e.,
+/// as [max_supply, decimals, token_symbol, 0].
+///
+/// # Errors
+/// Returns an error if the decimals or the maximum supply exceed their limits.
+pub(crate) fn build_fungible_faucet_metadata(
+    symbol: TokenSymbol,
+    decimals: u8,
+    max_supply: Felt,
+) -> Result<Word, AccountError> {
     if decimals > MAX_DECIMALS {
         return Err(AccountError::FungibleFaucetInvalidMetadata(
             "Decimals must be less than 13".to_string(),
@@ -61,27 +92,251 @@ pub fn create_basic_fungible_faucet(
     }
 
     // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as [a3, a2, a1, a0, ...]
-    let metadata = [max_supply, Felt::from(decimals), symbol.into(), ZERO];
+    Ok([max_supply, Felt::from(decimals), symbol.into(), ZERO])
+}
 
-    // We store the authentication data and the token metadata in the account storage:
+/// The token metadata of a basic fungible faucet.
+#[derive(Debug, Clone, Copy)]
+pub struct FungibleFaucet {
+    symbol: TokenSymbol,
+    decimals: u8,
+    max_supply: u64,
+}
+
+impl FungibleFaucet {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The slot in the account storage at which the token metadata is stored.
+    pub const METADATA_SLOT: u8 = 1;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the token metadata stored in the storage of the specified faucet.
+    ///
+    /// # Errors
+    /// Returns an error if the account is not a fungible faucet, or if the stored metadata is
+    /// invalid.
+    pub fn from_account(account: &Account) -> Result<Self, AccountError> {
+        check_fungible_faucet(account)?;
+
+        let [max_supply, decimals, symbol, _]: Word =
+            account.storage().get_item(Self::METADATA_SLOT).into();
+        let symbol = TokenSymbol::try_from(symbol)
+            .map_err(|err| AccountError::FungibleFaucetInvalidMetadata(err.to_string()))?;
+        let decimals = u8::try_from(decimals.as_int())
+            .ok()
+            .filter(|decimals| *decimals <= MAX_DECIMALS)
+            .ok_or_else(|| {
+                AccountError::FungibleFaucetInvalidMetadata(
+                    "Decimals must be less than 13".to_string(),
+                )
+            })?;
+        if max_supply.as_int() > MAX_MAX_SUPPLY {
+            return Err(AccountError::FungibleFaucetInvalidMetadata(
+                "Max supply must be < 2^63".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            symbol,
+            decimals,
+            max_supply: max_supply.as_int(),
+        })
+    }
+
+    /// Returns the total supply issued by the specified faucet so far, i.e., the amount of assets
+    /// minted minus the amount of assets burned by the faucet.
+    ///
+    /// The issued supply is tracked by the transaction kernel in the faucet reserved slot of the
+    /// account storage.
+    ///
+    /// # Errors
+    /// Returns an error if the account is not a fungible faucet.
+    pub fn issued_supply(account: &Account) -> Result<u64, AccountError> {
+        check_fungible_faucet(account)?;
+
+        let [_, _, _, total_issuance]: Word =
+            account.storage().get_item(FAUCET_STORAGE_DATA_SLOT).into();
+        Ok(total_issuance.as_int())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the symbol of the token.
+    pub fn symbol(&self) -> TokenSymbol {
+        self.symbol
+    }
+
+    /// Returns the decimals of the token.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the maximum supply of the token.
+    pub fn max_supply(&self) -> u64 {
+        self.max_supply
+    }
+}
+
+/// Returns an error if the specified account is not a fungible faucet.
+fn check_fungible_faucet(account: &Account) -> Result<(), AccountError> {
+    if account.account_type() != AccountType::FungibleFaucet {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Account is not a fungible faucet".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Creates a new faucet account with basic non-fungible faucet interface, specified
+/// authentication scheme, and provided collection metadata (collection symbol, max supply).
+///
+/// The basic non-fungible faucet interface exposes the following procedures:
+/// - `distribute`, which mints a non-fungible asset and creates a note for the provided recipient.
+/// - `distribute_with_metadata`, which does the same as `distribute` and records the commitment to
+///   the metadata of the asset (see [NonFungibleFaucet::asset_metadata()]).
+/// - `get_asset_metadata`, which returns the metadata commitment of the provided asset.
+/// - `burn`, which burns the provided asset.
+///
+/// `distribute` and `distribute_with_metadata` require authentication. The authentication
+/// procedure is defined by the specified authentication scheme. `burn` does not require
+/// authentication and can be called by anyone.
+///
+/// Public key information for the scheme is stored in the account storage at slot 0. The
+/// collection metadata is stored in the account storage at slot 1, the number of assets minted so
+/// far at slot 2, and the map of the asset metadata commitments at slot 3.
+pub fn create_basic_non_fungible_faucet(
+    init_seed: [u8; 32],
+    symbol: TokenSymbol,
+    max_supply: Felt,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let auth_data: Word = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+        AuthScheme::RpoFalcon512Multisig { .. }
+        | AuthScheme::RpoFalcon512Threshold { .. }
+        | AuthScheme::SessionKey { .. }
+        | AuthScheme::NoAuth => {
+            return Err(AccountError::AuthSchemeNotSupported(
+                "only single-key authentication is supported by this account type".to_string(),
+            ))
+        },
+    };
+
+    let miden = MidenLib::default();
+    let path = "miden::contracts::faucets::basic_non_fungible";
+    let faucet_code_ast = miden
+        .get_module_ast(&LibraryPath::new(path).unwrap())
+        .expect("Getting module AST failed");
+
+    // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as [a3, a2, a1, a0, ...]
+    let metadata = [max_supply, ZERO, symbol.into(), ZERO];
+
+    // We store the authentication data, the collection metadata and the number of minted assets
+    // in the account storage:
     // - slot 0: authentication data
-    // - slot 1: token metadata as [max_supply, decimals, token_symbol, 0]
-    let account_storage = AccountStorage::new(vec![
-        (0, (StorageSlotType::Value { value_arity: 0 }, auth_data)),
-        (1, (StorageSlotType::Value { value_arity: 0 }, metadata)),
-    ])?;
-    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
-
-    let account_seed = AccountId::get_account_seed(
-        init_seed,
-        AccountType::FungibleFaucet,
-        false,
-        account_code.root(),
-        account_storage.root(),
-    )?;
-    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
-    Ok((
-        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
-        account_seed,
-    ))
+    // - slot 1: collection metadata as [max_supply, 0, collection_symbol, 0]
+    // - slot 2: number of minted assets as [num_minted, 0, 0, 0]
+    // - slot 3: map of the metadata commitments of the minted assets
+    // The faucet reserved slot holds the root of the (initially empty) tree of minted assets.
+    AccountBuilder::new(TransactionKernel::assembler())
+        .code(faucet_code_ast.clone())
+        .storage_slot(0, auth_data)
+        .storage_slot(1, metadata)
+        .storage_slot(2, [ZERO; 4])
+        .storage_map(NonFungibleFaucet::ASSET_METADATA_SLOT, StorageMap::new())
+        .storage_slot_with_type(
+            FAUCET_STORAGE_DATA_SLOT,
+            StorageSlotType::Map { value_arity: 0 },
+            *Smt::default().root(),
+        )
+        .account_type(AccountType::NonFungibleFaucet)
+        .build(init_seed)
+}
+
+/// The collection metadata of a basic non-fungible faucet.
+#[derive(Debug, Clone, Copy)]
+pub struct NonFungibleFaucet {
+    symbol: TokenSymbol,
+    max_supply: u64,
+}
+
+impl NonFungibleFaucet {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The slot in the account storage at which the collection metadata is stored.
+    pub const METADATA_SLOT: u8 = 1;
+
+    /// The slot in the account storage at which the map of the asset metadata commitments is
+    /// stored.
+    pub const ASSET_METADATA_SLOT: u8 = 3;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the collection metadata stored in the storage of the specified faucet.
+    ///
+    /// # Errors
+    /// Returns an error if the account is not a non-fungible faucet, or if the stored metadata is
+    /// invalid.
+    pub fn from_account(account: &Account) -> Result<Self, AccountError> {
+        check_non_fungible_faucet(account)?;
+
+        let [max_supply, _, symbol, _]: Word =
+            account.storage().get_item(Self::METADATA_SLOT).into();
+        let symbol = TokenSymbol::try_from(symbol)
+            .map_err(|err| AccountError::FungibleFaucetInvalidMetadata(err.to_string()))?;
+
+        Ok(Self { symbol, max_supply: max_supply.as_int() })
+    }
+
+    /// Returns the commitment to the metadata of the specified asset recorded by the specified
+    /// faucet, or None if the asset was minted without metadata.
+    ///
+    /// The commitment can be checked against the metadata of the asset via
+    /// `NonFungibleAssetMetadata::commitment()`.
+    ///
+    /// # Errors
+    /// Returns an error if the account is not a non-fungible faucet, or if the entries of the
+    /// map of the asset metadata commitments are not known.
+    pub fn asset_metadata(
+        account: &Account,
+        asset: &NonFungibleAsset,
+    ) -> Result<Option<Digest>, AccountError> {
+        check_non_fungible_faucet(account)?;
+
+        let key: Word = (*asset).into();
+        let metadata = account.storage().get_map_item(Self::ASSET_METADATA_SLOT, key.into())?;
+        Ok((metadata != StorageMap::EMPTY_VALUE).then(|| metadata.into()))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the symbol of the collection.
+    pub fn symbol(&self) -> TokenSymbol {
+        self.symbol
+    }
+
+    /// Returns the maximum number of assets which can be minted in the collection.
+    pub fn max_supply(&self) -> u64 {
+        self.max_supply
+    }
+}
+
+/// Returns an error if the specified account is not a non-fungible faucet.
+fn check_non_fungible_faucet(account: &Account) -> Result<(), AccountError> {
+    if account.account_type() != AccountType::NonFungibleFaucet {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Account is not a non-fungible faucet".to_string(),
+        ));
+    }
+    Ok(())
 }
@@ -1,4 +1,86 @@
+use miden_objects::{
+    accounts::AccountCode,
+    assembly::{ModuleAst, ProgramAst},
+    utils::{
+        collections::Vec,
+        format,
+        string::{String, ToString},
+    },
+    AccountError, Digest, Felt, Hasher, StarkField, Word, ZERO,
+};
+
 use super::{auth::AuthScheme, transaction::TransactionKernel, Library, MidenLib};
 
+pub mod components;
 pub mod faucets;
+pub mod oracle;
+pub mod profile;
+pub mod raffle;
 pub mod wallets;
+
+// CODE UPDATE
+// ================================================================================================
+
+/// Domain separation tag of the message signed to authorize the update of the code of an account
+/// (see [compute_code_update_message()]).
+pub const UPDATE_CODE_TAG: u64 = 2;
+
+/// Compiles `new_code` into [AccountCode] and returns it together with a transaction script which
+/// replaces the code of an account with it.
+///
+/// The transaction script authenticates the transaction via `auth_tx_rpo_falcon512`, and then
+/// replaces the code via `update_code` of the [AuthScheme::RpoFalcon512] scheme; both procedures
+/// must be exposed by the account, as is the case for basic wallets with updatable code using this
+/// scheme. Both the transaction and the update are signed by the key stored at slot 0, and thus
+/// its key pair must be provided via the advice map when executing the transaction; the message
+/// signed to authorize the update is computed by [compute_code_update_message()].
+///
+/// The new code takes effect once the transaction is applied: procedures of the current code which
+/// are not part of the new code can no longer be invoked by subsequent transactions. Unless the new
+/// code exposes `update_code` as well, the code of the account cannot be updated again.
+///
+/// # Errors
+/// Returns an error if `new_code` cannot be compiled.
+pub fn build_code_update_tx(
+    new_code: ModuleAst,
+) -> Result<(AccountCode, ProgramAst), AccountError> {
+    let new_code = AccountCode::new(new_code, &TransactionKernel::assembler())?;
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        call.auth_tx::auth_tx_rpo_falcon512
+        push.{code_root}
+        call.auth_tx::update_code dropw
+    end
+    ",
+        code_root = prepare_word(&new_code.root().into())
+    );
+    let tx_script =
+        ProgramAst::parse(&tx_script_src).expect("code update transaction script is well formed");
+
+    Ok((new_code, tx_script))
+}
+
+/// Returns the message the key stored at slot 0 of an account has to sign in order to authorize
+/// the update of the account code to the code with root `new_code_root` in a transaction executed
+/// against the account state with hash `initial_account_hash`.
+///
+/// The message is computed as
+/// hash(hash([UPDATE_CODE_TAG, 0, 0, 0], new_code_root), initial_account_hash).
+pub fn compute_code_update_message(new_code_root: Digest, initial_account_hash: Digest) -> Digest {
+    let tag: Word = [Felt::new(UPDATE_CODE_TAG), ZERO, ZERO, ZERO];
+
+    let tagged_code_root = Hasher::merge(&[tag.into(), new_code_root]);
+    Hasher::merge(&[tagged_code_root, initial_account_hash])
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the provided word formatted as arguments of a MASM `push` instruction.
+pub(crate) fn prepare_word(word: &Word) -> String {
+    word.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join(".")
+}
@@ -0,0 +1,124 @@
+use miden_objects::{
+    accounts::{Account, AccountBuilder, AccountType},
+    assembly::{ModuleAst, ProgramAst},
+    utils::{format, string::ToString},
+    AccountError, Felt, StarkField, Word, ZERO,
+};
+
+use super::{AuthScheme, TransactionKernel};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of units of the base asset a price published by an oracle account refers to, i.e.,
+/// prices have 6 decimals.
+pub const PRICE_SCALE: u64 = 1_000_000;
+
+// ORACLE ACCOUNT
+// ================================================================================================
+
+/// Creates a new price oracle account with the specified authentication scheme.
+///
+/// The oracle account exposes the `set_price` procedure, which publishes a price at a storage
+/// slot of the account together with the number of the block referenced by the publishing
+/// transaction (see [build_price_update_tx_script()]). Other accounts read the published prices
+/// via foreign account reads, using the `read_price` and `read_recent_price` procedures of the
+/// `miden::contracts::oracle` module; ORACLE_SWAP notes (see
+/// [create_oracle_swap_note()](crate::notes::create_oracle_swap_note)) rely on this interface.
+///
+/// The oracle account is an on-chain account with immutable code, so that its prices are public
+/// and readers can rely on the publishing logic. Public key information for the authentication
+/// scheme is stored in the account storage at slot 0; prices can be published at any other slot.
+pub fn create_oracle_account(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let storage_slot_0_data: Word = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+        AuthScheme::RpoFalcon512Multisig { .. }
+        | AuthScheme::RpoFalcon512Threshold { .. }
+        | AuthScheme::SessionKey { .. }
+        | AuthScheme::NoAuth => {
+            return Err(AccountError::AuthSchemeNotSupported(
+                "only single-key authentication is supported by oracle accounts".to_string(),
+            ))
+        },
+    };
+
+    let account_code_src = "
+    use.miden::contracts::oracle
+    use.miden::contracts::auth::basic
+
+    export.oracle::set_price
+    export.basic::auth_tx_rpo_falcon512
+    ";
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+
+    AccountBuilder::new(TransactionKernel::assembler())
+        .code(account_code_ast)
+        .storage_slot(0, storage_slot_0_data)
+        .account_type(AccountType::RegularAccountImmutableCode)
+        .on_chain(true)
+        .build(init_seed)
+}
+
+/// Returns the price published by the specified oracle account at the specified storage slot,
+/// together with the number of the block at which it was published.
+///
+/// Returns None if no price has been published at the slot.
+pub fn get_oracle_price(account: &Account, slot: u8) -> Option<(u32, u32)> {
+    let entry: Word = account.storage().get_item(slot).into();
+    if entry == Word::default() {
+        return None;
+    }
+
+    Some((entry[0].as_int() as u32, entry[1].as_int() as u32))
+}
+
+/// Returns the value of a storage slot at which the specified price is published.
+///
+/// This can be used to set up the storage of oracle accounts for testing and genesis state.
+pub fn build_price_entry(price: u32, updated_at_block: u32) -> Word {
+    [Felt::from(price), Felt::from(updated_at_block), ZERO, ZERO]
+}
+
+/// Converts an amount of the base asset into an amount of the quote asset at the specified price.
+///
+/// The converted amount is rounded down. This mirrors the `convert_amount` procedure of the
+/// `miden::contracts::oracle` module.
+pub fn convert_amount(amount: u32, price: u32) -> u64 {
+    amount as u64 * price as u64 / PRICE_SCALE
+}
+
+/// Returns a transaction script which publishes the specified price at the specified storage slot
+/// of an oracle account, and authenticates the transaction using the specified authentication
+/// scheme.
+///
+/// The transaction will fail if the slot is the slot at which the public key of the oracle owner
+/// is stored.
+pub fn build_price_update_tx_script(slot: u8, price: u32, auth_scheme: &AuthScheme) -> ProgramAst {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+        AuthScheme::RpoFalcon512Multisig { .. } => "auth_tx_rpo_falcon512_multisig",
+        AuthScheme::RpoFalcon512Threshold { .. } => "auth_tx_rpo_falcon512_threshold",
+        AuthScheme::SessionKey { .. } => "auth_tx_rpo_falcon512_session_key",
+        AuthScheme::NoAuth => "auth_tx_no_auth",
+    };
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::oracle
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        push.{slot}.{price}
+        call.oracle::set_price drop drop
+        call.auth_tx::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).expect("price update transaction script is well formed")
+}
@@ -0,0 +1,152 @@
+use miden_objects::{
+    accounts::{Account, StorageSlotType},
+    assembly::ProgramAst,
+    utils::{collections::Vec, format, vec},
+    Digest, Hasher, Word,
+};
+
+use super::{prepare_word, AuthScheme};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Storage slot at which the commitment to the display name of an account is stored.
+pub const DISPLAY_NAME_SLOT: u8 = 251;
+
+/// Storage slot at which the hash of the avatar URI of an account is stored.
+pub const AVATAR_URI_SLOT: u8 = 252;
+
+/// Storage slot at which the contact key of an account is stored.
+pub const CONTACT_KEY_SLOT: u8 = 253;
+
+// ACCOUNT PROFILE
+// ================================================================================================
+
+/// Profile data of an account, stored in reserved storage slots.
+///
+/// The profile consists of:
+/// - A commitment to the display name, stored at [DISPLAY_NAME_SLOT].
+/// - A hash of the avatar URI, stored at [AVATAR_URI_SLOT].
+/// - A contact key (e.g., a public key used to encrypt notes addressed to the account), stored at
+///   [CONTACT_KEY_SLOT].
+///
+/// Accounts maintain their profile via the procedures of the `miden::contracts::profile` module,
+/// which have to be exported by the account code. Unset fields are represented by empty words.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AccountProfile {
+    display_name: Digest,
+    avatar_uri: Digest,
+    contact_key: Word,
+}
+
+impl AccountProfile {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountProfile] instantiated from the provided display name, avatar URI and
+    /// contact key.
+    pub fn new(display_name: &str, avatar_uri: &str, contact_key: Word) -> Self {
+        Self {
+            display_name: Self::hash_display_name(display_name),
+            avatar_uri: Self::hash_avatar_uri(avatar_uri),
+            contact_key,
+        }
+    }
+
+    /// Returns the profile stored in the storage of the specified account.
+    pub fn from_account(account: &Account) -> Self {
+        let storage = account.storage();
+        Self {
+            display_name: storage.get_item(DISPLAY_NAME_SLOT),
+            avatar_uri: storage.get_item(AVATAR_URI_SLOT),
+            contact_key: storage.get_item(CONTACT_KEY_SLOT).into(),
+        }
+    }
+
+    /// Returns the commitment to the specified display name.
+    pub fn hash_display_name(display_name: &str) -> Digest {
+        Hasher::hash(display_name.as_bytes())
+    }
+
+    /// Returns the hash of the specified avatar URI.
+    pub fn hash_avatar_uri(avatar_uri: &str) -> Digest {
+        Hasher::hash(avatar_uri.as_bytes())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the commitment to the display name of the account.
+    pub fn display_name(&self) -> Digest {
+        self.display_name
+    }
+
+    /// Returns the hash of the avatar URI of the account.
+    pub fn avatar_uri(&self) -> Digest {
+        self.avatar_uri
+    }
+
+    /// Returns the contact key of the account.
+    pub fn contact_key(&self) -> Word {
+        self.contact_key
+    }
+
+    /// Returns true if the specified display name matches the commitment of this profile.
+    pub fn has_display_name(&self, display_name: &str) -> bool {
+        self.display_name == Self::hash_display_name(display_name)
+    }
+
+    /// Returns true if the specified avatar URI matches the hash stored in this profile.
+    pub fn has_avatar_uri(&self, avatar_uri: &str) -> bool {
+        self.avatar_uri == Self::hash_avatar_uri(avatar_uri)
+    }
+
+    /// Returns the storage slots holding this profile.
+    ///
+    /// This can be used to populate the storage of a new account with an initial profile.
+    pub fn storage_slots(&self) -> Vec<(u8, (StorageSlotType, Word))> {
+        let slot_type = StorageSlotType::Value { value_arity: 0 };
+        vec![
+            (DISPLAY_NAME_SLOT, (slot_type, self.display_name.into())),
+            (AVATAR_URI_SLOT, (slot_type, self.avatar_uri.into())),
+            (CONTACT_KEY_SLOT, (slot_type, self.contact_key)),
+        ]
+    }
+
+    // TRANSACTION SCRIPTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a transaction script which replaces the profile of the executing account with this
+    /// profile and authenticates the transaction using the specified authentication scheme.
+    ///
+    /// The account must export the `set_display_name`, `set_avatar_uri` and `set_contact_key`
+    /// procedures of the `miden::contracts::profile` module.
+    pub fn build_update_tx_script(&self, auth_scheme: &AuthScheme) -> ProgramAst {
+        let auth_scheme_procedure = match auth_scheme {
+            AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+            AuthScheme::RpoFalcon512Multisig { .. } => "auth_tx_rpo_falcon512_multisig",
+            AuthScheme::RpoFalcon512Threshold { .. } => "auth_tx_rpo_falcon512_threshold",
+            AuthScheme::SessionKey { .. } => "auth_tx_rpo_falcon512_session_key",
+            AuthScheme::NoAuth => "auth_tx_no_auth",
+        };
+
+        let tx_script_src = format!(
+            "
+    use.miden::contracts::profile
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        push.{display_name} call.profile::set_display_name dropw
+        push.{avatar_uri} call.profile::set_avatar_uri dropw
+        push.{contact_key} call.profile::set_contact_key dropw
+        call.auth_tx::{auth_scheme_procedure}
+    end
+    ",
+            display_name = prepare_word(&self.display_name.into()),
+            avatar_uri = prepare_word(&self.avatar_uri.into()),
+            contact_key = prepare_word(&self.contact_key),
+        );
+
+        ProgramAst::parse(&tx_script_src).expect("profile update transaction script is well formed")
+    }
+}
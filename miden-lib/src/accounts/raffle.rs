@@ -0,0 +1,242 @@
+use miden_objects::{
+    accounts::{Account, AccountBuilder, AccountId, AccountType},
+    assembly::{ModuleAst, ProgramAst},
+    assets::Asset,
+    utils::{
+        collections::Vec,
+        format,
+        string::{String, ToString},
+    },
+    AccountError, Digest, Felt, Hasher, StarkField, Word, ZERO,
+};
+
+use super::{prepare_word, AuthScheme, TransactionKernel};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Storage slot at which the commitment to the organizer's seed is stored.
+pub const SEED_COMMITMENT_SLOT: u8 = 1;
+
+/// Storage slot at which the raffle deadline (block number) is stored.
+pub const DEADLINE_SLOT: u8 = 2;
+
+/// Storage slot at which the number of sold tickets is stored.
+pub const NUM_TICKETS_SLOT: u8 = 3;
+
+/// Storage slot at which the entropy accumulated from ticket commitments is stored.
+pub const ENTROPY_SLOT: u8 = 4;
+
+/// Storage slot at which the prize asset is stored. The slot is cleared when the raffle is settled.
+pub const PRIZE_SLOT: u8 = 5;
+
+/// Storage slot at which the ticket price asset is stored.
+pub const TICKET_PRICE_SLOT: u8 = 6;
+
+/// Storage slot at which the data of the first ticket is stored. Each ticket occupies two slots.
+pub const TICKETS_OFFSET_SLOT: u8 = 8;
+
+/// Maximum number of tickets a raffle can sell.
+pub const MAX_TICKETS: u8 = 64;
+
+// RAFFLE ACCOUNT
+// ================================================================================================
+
+/// Creates a new raffle account with the specified authentication scheme.
+///
+/// The raffle account exposes the following procedures:
+/// - `register_ticket`, which is invoked by RAFFLE_TICKET notes (see
+///   [create_raffle_ticket_note()](crate::notes::create_raffle_ticket_note)) to sell a ticket for
+///   `ticket_price` to the note sender before the `deadline` block. A ticket note must carry
+///   exactly the ticket price, and transactions selling tickets expire at the `deadline` block.
+/// - `settle`, which reveals the seed committed to by `seed_commitment` in a transaction executed
+///   against the `deadline` block, derives the winning ticket from the seed and the hash of the
+///   `deadline` block, and sends the `prize` to its owner in a P2ID note. The raffle can be
+///   settled only once.
+/// - `receive_asset`, which can be used to add an asset to the account (e.g., to fund the prize).
+///
+/// The account is created with an empty vault, and the prize has to be deposited into the account
+/// before the raffle can be settled. The code of the account is immutable so that participants can
+/// rely on the settlement logic. Public key information for the authentication scheme is stored in
+/// the account storage at slot 0.
+///
+/// Since settling the raffle requires revealing the committed seed, the raffle can also be created
+/// with the [AuthScheme::NoAuth] scheme; in this case, slot 0 is left empty and anyone knowing the
+/// seed can settle the raffle.
+///
+/// Since the hash of the `deadline` block is not known before ticket sales end, neither the
+/// organizer nor the participants can choose the winner. However, the organizer can prevent the
+/// raffle from being settled by not revealing the seed.
+///
+/// The seed commitment can be computed using [compute_commitment()].
+pub fn create_raffle_account(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    seed_commitment: Digest,
+    deadline: u32,
+    ticket_price: Asset,
+    prize: Asset,
+) -> Result<(Account, Word), AccountError> {
+    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
+        AuthScheme::NoAuth => ("basic::auth_tx_no_auth", Word::default()),
+        AuthScheme::RpoFalcon512Multisig { .. }
+        | AuthScheme::RpoFalcon512Threshold { .. }
+        | AuthScheme::SessionKey { .. } => {
+            return Err(AccountError::AuthSchemeNotSupported(
+                "only single-key or no authentication is supported by this account type"
+                    .to_string(),
+            ))
+        },
+    };
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::raffle
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.raffle::register_ticket
+    export.raffle::settle
+    export.basic_wallet::receive_asset
+    export.{auth_scheme_procedure}
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+
+    let seed_commitment: Word = seed_commitment.into();
+    let deadline: Word = [Felt::from(deadline), ZERO, ZERO, ZERO];
+
+    AccountBuilder::new(TransactionKernel::assembler())
+        .code(account_code_ast)
+        .storage_slot(0, storage_slot_0_data)
+        .storage_slot(SEED_COMMITMENT_SLOT, seed_commitment)
+        .storage_slot(DEADLINE_SLOT, deadline)
+        .storage_slot(PRIZE_SLOT, prize.into())
+        .storage_slot(TICKET_PRICE_SLOT, ticket_price.into())
+        .account_type(AccountType::RegularAccountImmutableCode)
+        .build(init_seed)
+}
+
+/// Returns the commitment to the provided secret, computed as hash(secret, 0).
+///
+/// This is used for the organizer's seed commitment, and can be used for the commitments carried
+/// by RAFFLE_TICKET notes.
+pub fn compute_commitment(secret: Word) -> Digest {
+    Hasher::merge(&[secret.into(), Digest::default()])
+}
+
+// RAFFLE TICKET
+// ================================================================================================
+
+/// A ticket sold by a raffle account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaffleTicket {
+    index: u8,
+    participant: AccountId,
+    recipient: Digest,
+}
+
+impl RaffleTicket {
+    /// Returns the index of this ticket, i.e., the number of tickets sold before it.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the ID of the account which bought this ticket.
+    pub fn participant(&self) -> AccountId {
+        self.participant
+    }
+
+    /// Returns the recipient of the note which pays out the prize if this ticket wins.
+    pub fn recipient(&self) -> Digest {
+        self.recipient
+    }
+}
+
+/// Returns the tickets sold by the specified raffle account, ordered by index.
+///
+/// # Errors
+/// Returns an error if any of the recorded participants is not a valid account ID.
+pub fn get_raffle_tickets(account: &Account) -> Result<Vec<RaffleTicket>, AccountError> {
+    let storage = account.storage();
+    let num_tickets: Word = storage.get_item(NUM_TICKETS_SLOT).into();
+    let num_tickets = num_tickets[0].as_int().min(MAX_TICKETS as u64) as u8;
+
+    (0..num_tickets)
+        .map(|index| {
+            let recipient = storage.get_item(TICKETS_OFFSET_SLOT + 2 * index);
+            let participant: Word = storage.get_item(TICKETS_OFFSET_SLOT + 2 * index + 1).into();
+            Ok(RaffleTicket {
+                index,
+                participant: AccountId::try_from(participant[0])?,
+                recipient,
+            })
+        })
+        .collect()
+}
+
+/// Returns the ticket which wins the raffle held by the specified account if the organizer reveals
+/// the provided seed, or None if no tickets have been sold.
+///
+/// This mirrors the computation performed by the `settle` procedure: the index of the winning
+/// ticket is (RAND[0] mod 2^32) mod num_tickets, where
+/// RAND = hash(hash(ENTROPY, seed), deadline_block_hash).
+///
+/// # Errors
+/// Returns an error if any of the recorded participants is not a valid account ID.
+pub fn compute_winning_ticket(
+    account: &Account,
+    seed: Word,
+    deadline_block_hash: Digest,
+) -> Result<Option<RaffleTicket>, AccountError> {
+    let mut tickets = get_raffle_tickets(account)?;
+    if tickets.is_empty() {
+        return Ok(None);
+    }
+
+    let entropy = account.storage().get_item(ENTROPY_SLOT);
+    let rand = Hasher::merge(&[entropy, seed.into()]);
+    let rand: Word = Hasher::merge(&[rand, deadline_block_hash]).into();
+    let winner_idx = (rand[0].as_int() as u32) % tickets.len() as u32;
+
+    Ok(Some(tickets.swap_remove(winner_idx as usize)))
+}
+
+/// Returns a transaction script which settles the raffle by revealing the specified seed and
+/// authenticates the transaction using the specified authentication scheme.
+///
+/// The transaction must be executed against the deadline block of the raffle. It will fail if the
+/// raffle has already been settled, if the last known block is not the deadline block, if the
+/// seed does not match the seed commitment, if no tickets have been sold, or if the prize has not
+/// been deposited into the raffle account.
+pub fn build_raffle_settlement_tx_script(seed: Word, auth_scheme: &AuthScheme) -> ProgramAst {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+        AuthScheme::RpoFalcon512Multisig { .. } => "auth_tx_rpo_falcon512_multisig",
+        AuthScheme::RpoFalcon512Threshold { .. } => "auth_tx_rpo_falcon512_threshold",
+        AuthScheme::SessionKey { .. } => "auth_tx_rpo_falcon512_session_key",
+        AuthScheme::NoAuth => "auth_tx_no_auth",
+    };
+
+    let seed = prepare_word(&seed);
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::raffle
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        push.{seed}
+        call.raffle::settle dropw
+        call.auth_tx::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).expect("raffle settlement transaction script is well formed")
+}
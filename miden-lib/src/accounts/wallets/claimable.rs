@@ -0,0 +1,149 @@
+use miden_objects::{
+    accounts::{Account, AccountBuilder, AccountId, AccountType, StorageMap},
+    assembly::ModuleAst,
+    assets::Asset,
+    utils::{
+        collections::Vec,
+        format,
+        string::{String, ToString},
+    },
+    AccountError, AssetError, Digest, Hasher, Word, ZERO,
+};
+
+use super::{AuthScheme, TransactionKernel};
+use crate::notes::scripts;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Storage slot at which the claim map, mapping the IDs of pending claim entries to their assets,
+/// is stored.
+pub const CLAIMS_SLOT: u8 = 1;
+
+/// Storage slot at which the script root of claim notes, i.e., the root of the P2IDC note script,
+/// is stored.
+pub const CLAIM_SCRIPT_ROOT_SLOT: u8 = 2;
+
+// CLAIMABLE WALLET
+// ================================================================================================
+
+/// Creates a new account with claimable wallet interface and the specified authentication scheme.
+///
+/// The claimable wallet interface exposes three procedures:
+/// - `receive_asset`, which records an asset as a pending claim entry and moves it into a claim
+///   note addressed to the account itself, instead of adding it to the account vault. The claim
+///   note is built by the account, and thus cannot be redirected by the payer.
+/// - `claim`, which removes a pending claim entry and adds its asset to the account vault.
+/// - `send_asset`, which can be used to remove an asset from the account and put into a note
+///    addressed to the specified recipient.
+///
+/// Incoming payments should be sent using P2IDC notes (see
+/// [create_p2idc_note()](crate::notes::create_p2idc_note)). An entry is claimed by consuming its
+/// claim note; until then, the asset of the entry is not part of the account vault and cannot be
+/// spent. Public key information for the authentication scheme is stored in the account storage at
+/// slot 0, the claim map at slot [CLAIMS_SLOT], and the root of the P2IDC note script at slot
+/// [CLAIM_SCRIPT_ROOT_SLOT].
+pub fn create_claimable_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    account_type: AccountType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountTypeNotSupported(account_type));
+    }
+
+    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
+        AuthScheme::RpoFalcon512Multisig { .. }
+        | AuthScheme::RpoFalcon512Threshold { .. }
+        | AuthScheme::SessionKey { .. }
+        | AuthScheme::NoAuth => {
+            return Err(AccountError::AuthSchemeNotSupported(
+                "only single-key authentication is supported by this account type".to_string(),
+            ))
+        },
+    };
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::wallets::claimable->claimable_wallet
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.claimable_wallet::receive_asset
+    export.claimable_wallet::claim
+    export.basic_wallet::send_asset
+    export.{auth_scheme_procedure}
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+
+    AccountBuilder::new(TransactionKernel::assembler())
+        .code(account_code_ast)
+        .storage_slot(0, storage_slot_0_data)
+        .storage_map(CLAIMS_SLOT, StorageMap::new())
+        .storage_slot(CLAIM_SCRIPT_ROOT_SLOT, scripts::p2idc().hash().into())
+        .account_type(account_type)
+        .build(init_seed)
+}
+
+/// Returns the serial number of the claim note of the entry with the specified ID in the claimable
+/// wallet with the specified account ID.
+///
+/// The serial number is computed as hash([account_id, 0, 0, 0], entry_id).
+pub fn compute_claim_serial_num(account_id: AccountId, entry_id: Digest) -> Word {
+    let account_id: Word = [account_id.into(), ZERO, ZERO, ZERO];
+    Hasher::merge(&[account_id.into(), entry_id]).into()
+}
+
+// CLAIM ENTRY
+// ================================================================================================
+
+/// A pending entry in the claim map of a claimable wallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimEntry {
+    entry_id: Digest,
+    asset: Asset,
+}
+
+impl ClaimEntry {
+    /// Returns the ID of this entry, i.e., the serial number of the P2IDC note with which the
+    /// asset was paid.
+    pub fn entry_id(&self) -> Digest {
+        self.entry_id
+    }
+
+    /// Returns the asset recorded by this entry.
+    pub fn asset(&self) -> &Asset {
+        &self.asset
+    }
+}
+
+/// Returns the pending claim entries recorded in the storage of the specified claimable wallet,
+/// sorted by ID.
+///
+/// # Errors
+/// Returns an error if any of the claim entries does not contain a valid asset.
+pub fn get_claim_entries(account: &Account) -> Result<Vec<ClaimEntry>, AssetError> {
+    let Some(claims) = account.storage().get_map(CLAIMS_SLOT) else {
+        return Ok(Vec::new());
+    };
+
+    let mut entries = claims
+        .entries()
+        .filter(|(_, value)| *value != Word::default())
+        .map(|(entry_id, value)| {
+            Ok(ClaimEntry {
+                entry_id: *entry_id,
+                asset: Asset::try_from(*value)?,
+            })
+        })
+        .collect::<Result<Vec<_>, AssetError>>()?;
+    entries.sort_by_key(|entry| entry.entry_id);
+
+    Ok(entries)
+}
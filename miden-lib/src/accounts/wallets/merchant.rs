@@ -0,0 +1,207 @@
+use miden_objects::{
+    accounts::{Account, AccountBuilder, AccountType},
+    assembly::{ModuleAst, ProgramAst},
+    assets::FungibleAsset,
+    utils::{
+        collections::Vec,
+        format,
+        string::{String, ToString},
+    },
+    AccountError, AssetError, StarkField, Word, ZERO,
+};
+
+use super::{AuthScheme, TransactionKernel};
+use crate::accounts::prepare_word;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Storage slot at which the payment request for invoice ID 0 is stored.
+pub const REQUESTS_OFFSET_SLOT: u8 = 1;
+
+/// Maximum number of payment requests a merchant wallet can hold.
+pub const MAX_REQUESTS: u8 = 128;
+
+// MERCHANT WALLET
+// ================================================================================================
+
+/// Creates a new account with merchant wallet interface and the specified authentication scheme.
+///
+/// The merchant wallet interface exposes the following procedures:
+/// - `add_payment_request`, which records an open payment request for an invoice.
+/// - `receive_payment`, which accepts a payment against an open payment request and marks the
+///    request as paid.
+/// - `receive_asset`, which can be used to add an asset to the account.
+/// - `send_asset`, which can be used to remove an asset from the account and put into a note
+///    addressed to the specified recipient.
+/// - `fill_swap`, which is used by SWAP notes to add the offered asset to the account and to send
+///    the requested asset back to the creator of the note.
+///
+/// Payments should be sent using PAYMENT notes (see
+/// [create_payment_note()](crate::notes::create_payment_note)). Public key information for the
+/// authentication scheme is stored in the account storage at slot 0, and payment requests occupy
+/// slots [REQUESTS_OFFSET_SLOT] through `REQUESTS_OFFSET_SLOT + MAX_REQUESTS - 1`.
+pub fn create_merchant_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    account_type: AccountType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountTypeNotSupported(account_type));
+    }
+
+    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
+        AuthScheme::RpoFalcon512Multisig { .. }
+        | AuthScheme::RpoFalcon512Threshold { .. }
+        | AuthScheme::SessionKey { .. }
+        | AuthScheme::NoAuth => {
+            return Err(AccountError::AuthSchemeNotSupported(
+                "only single-key authentication is supported by this account type".to_string(),
+            ))
+        },
+    };
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::wallets::merchant->merchant_wallet
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.merchant_wallet::add_payment_request
+    export.merchant_wallet::receive_payment
+    export.basic_wallet::receive_asset
+    export.basic_wallet::send_asset
+    export.basic_wallet::fill_swap
+    export.{auth_scheme_procedure}
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+
+    AccountBuilder::new(TransactionKernel::assembler())
+        .code(account_code_ast)
+        .storage_slot(0, storage_slot_0_data)
+        .account_type(account_type)
+        .build(init_seed)
+}
+
+// PAYMENT REQUEST
+// ================================================================================================
+
+/// Status of a payment request recorded by a merchant wallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentStatus {
+    Open,
+    Paid,
+}
+
+impl PaymentStatus {
+    const OPEN: u64 = 1;
+    const PAID: u64 = 2;
+}
+
+/// A payment request (invoice) recorded in the storage of a merchant wallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaymentRequest {
+    invoice_id: u8,
+    asset: FungibleAsset,
+    status: PaymentStatus,
+}
+
+impl PaymentRequest {
+    /// Returns the ID of the invoice this request was recorded for.
+    pub fn invoice_id(&self) -> u8 {
+        self.invoice_id
+    }
+
+    /// Returns the asset requested by the invoice.
+    pub fn asset(&self) -> FungibleAsset {
+        self.asset
+    }
+
+    /// Returns the status of this request.
+    pub fn status(&self) -> PaymentStatus {
+        self.status
+    }
+
+    /// Returns true if the invoice has been paid.
+    pub fn is_paid(&self) -> bool {
+        self.status == PaymentStatus::Paid
+    }
+}
+
+/// Returns all payment requests recorded in the storage of the specified merchant wallet, ordered
+/// by invoice ID.
+///
+/// This can be used to reconcile invoices against the state of the account: requests which were
+/// paid by consumed PAYMENT notes are reported with [PaymentStatus::Paid].
+///
+/// # Errors
+/// Returns an error if any of the recorded requests does not contain a valid fungible asset.
+pub fn get_payment_requests(account: &Account) -> Result<Vec<PaymentRequest>, AssetError> {
+    let storage = account.storage();
+
+    let mut requests = Vec::new();
+    for invoice_id in 0..MAX_REQUESTS {
+        let mut request: Word = storage.get_item(REQUESTS_OFFSET_SLOT + invoice_id).into();
+        let status = match request[2].as_int() {
+            PaymentStatus::OPEN => PaymentStatus::Open,
+            PaymentStatus::PAID => PaymentStatus::Paid,
+            _ => continue,
+        };
+
+        request[2] = ZERO;
+        let asset = FungibleAsset::try_from(request)?;
+        requests.push(PaymentRequest { invoice_id, asset, status });
+    }
+
+    Ok(requests)
+}
+
+/// Returns a transaction script which records open payment requests for the specified
+/// `(invoice_id, asset)` pairs and authenticates the transaction using the specified
+/// authentication scheme.
+///
+/// The transaction will fail if any of the invoice IDs is not smaller than [MAX_REQUESTS], or if
+/// a request for any of the invoice IDs has already been recorded.
+pub fn build_payment_requests_tx_script(
+    requests: &[(u8, FungibleAsset)],
+    auth_scheme: &AuthScheme,
+) -> ProgramAst {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+        AuthScheme::RpoFalcon512Multisig { .. } => "auth_tx_rpo_falcon512_multisig",
+        AuthScheme::RpoFalcon512Threshold { .. } => "auth_tx_rpo_falcon512_threshold",
+        AuthScheme::SessionKey { .. } => "auth_tx_rpo_falcon512_session_key",
+        AuthScheme::NoAuth => "auth_tx_no_auth",
+    };
+
+    let add_requests = requests
+        .iter()
+        .map(|(invoice_id, asset)| {
+            let asset: Word = (*asset).into();
+            format!(
+                "push.{invoice_id} push.{asset}\ncall.merchant_wallet::add_payment_request dropw drop\n",
+                asset = prepare_word(&asset)
+            )
+        })
+        .collect::<String>();
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::wallets::merchant->merchant_wallet
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        {add_requests}
+        call.auth_tx::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).expect("payment request transaction script is well formed")
+}
@@ -1,80 +1,360 @@
 use miden_objects::{
-    accounts::{Account, AccountCode, AccountId, AccountStorage, AccountType, StorageSlotType},
-    assembly::ModuleAst,
-    assets::AssetVault,
-    utils::{
-        format,
-        string::{String, ToString},
-        vec,
-    },
-    AccountError, Word, ZERO,
+    accounts::{Account, AccountBuilder, AccountType},
+    assembly::ProgramAst,
+    assets::{Asset, FungibleAsset},
+    crypto::dsa::rpo_falcon512::PublicKey,
+    utils::{collections::Vec, format, string::ToString},
+    AccountError, Digest, Felt, Hasher, Word, ZERO,
 };
 
 use super::{AuthScheme, TransactionKernel};
+use crate::accounts::{
+    components::{auth_component, basic_wallet_component},
+    prepare_word,
+};
+
+mod claimable;
+pub use claimable::{
+    compute_claim_serial_num, create_claimable_wallet, get_claim_entries, ClaimEntry, CLAIMS_SLOT,
+    CLAIM_SCRIPT_ROOT_SLOT,
+};
+
+mod merchant;
+pub use merchant::{
+    build_payment_requests_tx_script, create_merchant_wallet, get_payment_requests, PaymentRequest,
+    PaymentStatus, MAX_REQUESTS, REQUESTS_OFFSET_SLOT,
+};
+
+mod views;
+pub use views::{
+    build_get_balance_tx_script, build_get_public_key_tx_script, build_public_key_foreign_inputs,
+    build_read_public_key_tx_script, get_balance_from_output, get_public_key_from_output,
+    PUBLIC_KEY_SLOT,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Storage slot at which the spending limit of a basic wallet is stored.
+pub const SPENDING_LIMIT_SLOT: u8 = 2;
+
+/// Storage slot at which the public key of the owner of a basic wallet with a spending limit is
+/// stored.
+pub const OWNER_PUBLIC_KEY_SLOT: u8 = 3;
+
+/// Storage slot at which a basic wallet with a spending limit tracks the outflow of the limited
+/// asset in the current transaction.
+pub const SPENDING_OUTFLOW_SLOT: u8 = 4;
+
+/// Domain separation tag of the message signed to authorize the rotation of the public key of a
+/// basic wallet (see [compute_rotate_key_message()]).
+pub const ROTATE_KEY_TAG: u64 = 1;
+
+/// Domain separation tag of the message signed to authorize the replacement of the session key of
+/// a basic wallet (see [compute_set_session_key_message()]).
+pub const SET_SESSION_KEY_TAG: u64 = 3;
+
+// SPENDING LIMIT
+// ================================================================================================
+
+/// A per-transaction spending limit of a basic wallet.
+///
+/// The total amount of the limited asset sent out of the wallet by a single transaction must not
+/// exceed the amount of `limit`, unless the owner lifts the limit for the transaction by signing
+/// its initial account hash with the key matching `owner_key` (via the `unlock_spending_limit`
+/// procedure). Assets issued by other faucets are not limited.
+pub struct SpendingLimit {
+    pub limit: FungibleAsset,
+    pub owner_key: PublicKey,
+}
+
+// INITIAL STATE
+// ================================================================================================
+
+/// The initial state of a basic wallet beyond the state defined by its interface.
+///
+/// The storage slots and the assets are part of the account from its creation, and thus the seed
+/// of the account ID is computed over the resulting storage root. This makes it possible to create
+/// wallets holding genesis state (e.g., a profile or an initial balance) without modifying the
+/// account after it was created.
+#[derive(Debug, Clone, Default)]
+pub struct WalletInitialState {
+    /// Values of the storage slots not used by the wallet interface, as (index, value) pairs.
+    pub storage_slots: Vec<(u8, Word)>,
+    /// Assets held in the vault of the wallet.
+    pub assets: Vec<Asset>,
+}
 
 // BASIC WALLET
 // ================================================================================================
 
 /// Creates a new account with basic wallet interface and the specified authentication scheme.
-/// Basic wallets can be specified to have either mutable or immutable code.
 ///
-/// The basic wallet interface exposes two procedures:
+/// Basic wallets are regular accounts: `account_type` can be either
+/// [AccountType::RegularAccountUpdatableCode] or [AccountType::RegularAccountImmutableCode]. The
+/// code of wallets of the latter type can never be changed, which makes them easier to audit.
+///
+/// The basic wallet interface exposes four procedures:
 /// - `receive_asset`, which can be used to add an asset to the account.
 /// - `send_asset`, which can be used to remove an asset from the account and put into a note
 ///    addressed to the specified recipient.
+/// - `add_asset_to_note`, which can be used to remove a further asset from the account and add it
+///    to a note created via `send_asset` (e.g., by BASKET_SWAP notes).
+/// - `fill_swap`, which is used by SWAP notes to add the offered asset to the account and to send
+///    the requested asset back to the creator of the note.
+///
+/// If a [SpendingLimit] is specified, `send_asset`, `add_asset_to_note` and `fill_swap` are
+/// replaced by:
+/// - `send_asset_with_limit`, which works as `send_asset` but enforces the spending limit.
+/// - `unlock_spending_limit`, which lifts the spending limit for the current transaction if it is
+///    authorized by the owner.
+///
+/// `add_asset_to_note` and `fill_swap` are not exposed by such wallets, since they would allow
+/// sending assets out of the wallet without enforcing the limit.
+///
+/// The limit is stored in the account storage at slot [SPENDING_LIMIT_SLOT], the public key of the
+/// owner at slot [OWNER_PUBLIC_KEY_SLOT], and the outflow of the current transaction is tracked at
+/// slot [SPENDING_OUTFLOW_SLOT].
 ///
-/// Both methods require authentication. The authentication procedure is defined by the specified
+/// All methods require authentication. The authentication procedure is defined by the specified
 /// authentication scheme. Public key information for the scheme is stored in the account storage
-/// at slot 0.
+/// at slot 0; wallets using the [AuthScheme::RpoFalcon512] scheme also expose `rotate_key`, which
+/// replaces the public key (see [build_rotate_key_tx_script()]), and wallets with updatable code
+/// using this scheme expose `update_code`, which replaces the account code (see
+/// [build_code_update_tx()](crate::accounts::build_code_update_tx)). For the
+/// [AuthScheme::RpoFalcon512Multisig] scheme, slot 0 holds the threshold and the number of keys,
+/// and the public keys are stored at slot 1; for the [AuthScheme::RpoFalcon512Threshold] scheme,
+/// slot 0 holds the commitment to the threshold, the number of keys and the public keys. For the
+/// [AuthScheme::SessionKey] scheme, the root key is stored at slot 0 and the session key
+/// configuration at slot 1; such wallets expose the authentication procedures of both keys as well
+/// as `set_session_key`.
+///
+/// Regardless of the spending limit and the authentication scheme, the wallet also exposes the
+/// read-only views of the `miden::contracts::wallets::basic_views` module, which let explorers
+/// query any basic wallet in the same way. Unlike the methods above, the views do not require
+/// authentication:
+/// - `get_balance`, which returns the balance of the specified fungible asset in the vault (see
+///    [build_get_balance_tx_script()]).
+/// - `get_public_key`, which returns the public key information stored at slot [PUBLIC_KEY_SLOT]
+///    (see [build_get_public_key_tx_script()]). The same information can be read by transactions
+///    executed against other accounts via [build_read_public_key_tx_script()].
+///
+/// The wallet is composed of the [basic_wallet_component()] and the [auth_component()]; accounts
+/// combining the basic wallet interface with further components (e.g., custom modules) can be
+/// created from these components via [AccountBuilder::component()].
+///
+/// # Errors
+/// Returns an error if:
+/// - `account_type` is a faucet account type.
+/// - The authentication scheme is [AuthScheme::NoAuth].
+/// - The configuration of the authentication scheme is invalid.
 pub fn create_basic_wallet(
     init_seed: [u8; 32],
     auth_scheme: AuthScheme,
     account_type: AccountType,
+    spending_limit: Option<SpendingLimit>,
+) -> Result<(Account, Word), AccountError> {
+    create_basic_wallet_with_initial_state(
+        init_seed,
+        auth_scheme,
+        account_type,
+        spending_limit,
+        WalletInitialState::default(),
+    )
+}
+
+/// Creates a new account with basic wallet interface, the specified authentication scheme and the
+/// specified initial storage and assets.
+///
+/// The wallet is created as described in [create_basic_wallet()], and the storage slots and the
+/// assets of `initial_state` are added to the account before the seed of its ID is computed.
+///
+/// Note that the transaction kernel expects new accounts to have an empty vault; wallets with
+/// initial assets are meant to be used for genesis state and testing.
+///
+/// # Errors
+/// Returns an error if:
+/// - `account_type` is a faucet account type.
+/// - The authentication scheme is [AuthScheme::NoAuth].
+/// - The configuration of the authentication scheme is invalid.
+/// - A storage slot of `initial_state` is used by the authentication scheme or the spending limit
+///   of the wallet, or is reserved by the account storage.
+/// - The assets of `initial_state` cannot be added to the vault (e.g., an asset is added twice).
+pub fn create_basic_wallet_with_initial_state(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    account_type: AccountType,
+    spending_limit: Option<SpendingLimit>,
+    initial_state: WalletInitialState,
 ) -> Result<(Account, Word), AccountError> {
     if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
-        return Err(AccountError::AccountIdInvalidFieldElement(
-            "Basic wallet accounts cannot have a faucet account type".to_string(),
+        return Err(AccountError::AccountTypeNotSupported(account_type));
+    }
+
+    if matches!(auth_scheme, AuthScheme::NoAuth) {
+        return Err(AccountError::AuthSchemeNotSupported(
+            "wallets cannot be created without authentication".to_string(),
         ));
     }
 
-    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
-    };
+    let updatable_code = account_type == AccountType::RegularAccountUpdatableCode;
+    let wallet_component = basic_wallet_component(spending_limit);
+    let auth_component = auth_component(auth_scheme, updatable_code)?;
+
+    // slots used by the components of the wallet cannot be set by the caller
+    let mut account_builder = AccountBuilder::new(TransactionKernel::assembler());
+    for (index, value) in initial_state.storage_slots {
+        if wallet_component.uses_storage_slot(index) || auth_component.uses_storage_slot(index) {
+            return Err(AccountError::StorageSlotIsReserved(index));
+        }
+        account_builder = account_builder.storage_slot(index, value);
+    }
+    for asset in initial_state.assets {
+        account_builder = account_builder.asset(asset);
+    }
 
-    let account_code_string: String = format!(
+    account_builder
+        .component(wallet_component)
+        .component(auth_component)
+        .account_type(account_type)
+        .build(init_seed)
+}
+
+/// Returns a transaction script which fills the SWAP notes consumed by a transaction executed
+/// against a basic wallet, and authenticates the transaction using the specified authentication
+/// scheme.
+///
+/// The swap orders are filled by the SWAP note scripts via the `fill_swap` procedure of the basic
+/// wallet, which claims the offered asset and sends the requested asset back to the creator of the
+/// note; the transaction script only needs to authenticate the resulting account state changes.
+pub fn build_fill_swap_tx_script(auth_scheme: &AuthScheme) -> ProgramAst {
+    build_auth_tx_script(auth_scheme)
+}
+
+/// Returns a transaction script which cancels the SWAP notes consumed by a transaction executed
+/// against the basic wallet which created them, and authenticates the transaction using the
+/// specified authentication scheme.
+///
+/// When a SWAP note is consumed by its creator, the SWAP note script returns the offered asset to
+/// the creator via the `receive_asset` procedure of the basic wallet instead of filling the order;
+/// the transaction script only needs to authenticate the resulting account state changes.
+pub fn build_cancel_tx_script(auth_scheme: &AuthScheme) -> ProgramAst {
+    build_auth_tx_script(auth_scheme)
+}
+
+/// Returns a transaction script which replaces the session key of a basic wallet using the
+/// [AuthScheme::SessionKey] scheme, and authenticates the transaction using the root key.
+///
+/// The new session key is valid until the block with number `expires_at_block` (inclusive). Both
+/// the replacement and the transaction are signed by the root key, and thus the key pair of the
+/// root key must be provided via the advice map when executing the transaction; the message signed
+/// to authorize the replacement is computed by [compute_set_session_key_message()].
+pub fn build_set_session_key_tx_script(
+    session_key: PublicKey,
+    expires_at_block: u32,
+) -> ProgramAst {
+    let session_key: Word = session_key.into();
+
+    let tx_script_src = format!(
         "
-    use.miden::contracts::wallets::basic->basic_wallet
-    use.miden::contracts::auth::basic
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        push.{expires_at_block} push.{session_key}
+        call.auth_tx::set_session_key dropw drop
+        call.auth_tx::auth_tx_rpo_falcon512
+    end
+    ",
+        session_key = prepare_word(&session_key)
+    );
 
-    export.basic_wallet::receive_asset
-    export.basic_wallet::send_asset
-    export.{auth_scheme_procedure}
+    ProgramAst::parse(&tx_script_src).expect("set session key transaction script is well formed")
+}
 
-    "
+/// Returns a transaction script which replaces the public key of a basic wallet using the
+/// [AuthScheme::RpoFalcon512] scheme with `new_key`, and authenticates the transaction using the
+/// current key.
+///
+/// Both the transaction and the replacement are signed by the current key, and thus the key pair of
+/// the current key must be provided via the advice map when executing the transaction; the message
+/// signed to authorize the replacement is computed by [compute_rotate_key_message()]. Once the
+/// transaction is applied, only `new_key` can authenticate transactions against the wallet.
+pub fn build_rotate_key_tx_script(new_key: PublicKey) -> ProgramAst {
+    let new_key: Word = new_key.into();
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        call.auth_tx::auth_tx_rpo_falcon512
+        push.{new_key}
+        call.auth_tx::rotate_key dropw
+    end
+    ",
+        new_key = prepare_word(&new_key)
     );
-    let account_code_src: &str = &account_code_string;
 
-    let account_code_ast = ModuleAst::parse(account_code_src)
-        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
-    let account_assembler = TransactionKernel::assembler();
-    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
+    ProgramAst::parse(&tx_script_src).expect("rotate key transaction script is well formed")
+}
 
-    let account_storage = AccountStorage::new(vec![(
-        0,
-        (StorageSlotType::Value { value_arity: 0 }, storage_slot_0_data),
-    )])?;
-    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+/// Returns the message the current key of a basic wallet has to sign in order to authorize the
+/// replacement of the key with `new_key` in a transaction executed against the account state with
+/// hash `initial_account_hash`.
+///
+/// The message is computed as hash(hash([ROTATE_KEY_TAG, 0, 0, 0], new_key), initial_account_hash).
+pub fn compute_rotate_key_message(new_key: PublicKey, initial_account_hash: Digest) -> Digest {
+    let tag: Word = [Felt::new(ROTATE_KEY_TAG), ZERO, ZERO, ZERO];
+    let new_key: Word = new_key.into();
 
-    let account_seed = AccountId::get_account_seed(
-        init_seed,
-        account_type,
-        false,
-        account_code.root(),
-        account_storage.root(),
-    )?;
-    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
-    Ok((
-        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
-        account_seed,
-    ))
+    let tagged_key = Hasher::merge(&[tag.into(), new_key.into()]);
+    Hasher::merge(&[tagged_key, initial_account_hash])
+}
+
+/// Returns the message the root key of a basic wallet has to sign in order to authorize the
+/// replacement of the session key with `session_key`, valid until the block with number
+/// `expires_at_block`, in a transaction executed against the account state with hash
+/// `initial_account_hash`.
+///
+/// The message is computed as hash(hash([SET_SESSION_KEY_TAG, 0, 0, 0], session_config),
+/// initial_account_hash), where session_config = hash(session_key, [expires_at_block, 0, 0, 0]).
+pub fn compute_set_session_key_message(
+    session_key: PublicKey,
+    expires_at_block: u32,
+    initial_account_hash: Digest,
+) -> Digest {
+    let tag: Word = [Felt::new(SET_SESSION_KEY_TAG), ZERO, ZERO, ZERO];
+    let session_key: Word = session_key.into();
+    let expiry: Word = [Felt::from(expires_at_block), ZERO, ZERO, ZERO];
+
+    let config = Hasher::merge(&[session_key.into(), expiry.into()]);
+    let tagged_config = Hasher::merge(&[tag.into(), config]);
+    Hasher::merge(&[tagged_config, initial_account_hash])
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a transaction script which only authenticates the transaction using the specified
+/// authentication scheme.
+pub(crate) fn build_auth_tx_script(auth_scheme: &AuthScheme) -> ProgramAst {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+        AuthScheme::RpoFalcon512Multisig { .. } => "auth_tx_rpo_falcon512_multisig",
+        AuthScheme::RpoFalcon512Threshold { .. } => "auth_tx_rpo_falcon512_threshold",
+        AuthScheme::SessionKey { .. } => "auth_tx_rpo_falcon512_session_key",
+        AuthScheme::NoAuth => "auth_tx_no_auth",
+    };
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        call.auth_tx::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).expect("authentication transaction script is well formed")
 }
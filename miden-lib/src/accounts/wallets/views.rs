@@ -0,0 +1,126 @@
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assembly::ProgramAst,
+    block::AccountWitness,
+    transaction::ForeignAccountInputs,
+    utils::format,
+    StarkField, TransactionInputError, Word, TX_SCRIPT_OUTPUT_WORDS,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Storage slot at which a basic wallet stores the public key information of its authentication
+/// scheme, i.e., the slot returned by the `get_public_key` view.
+pub const PUBLIC_KEY_SLOT: u8 = 0;
+
+// LOCAL VIEWS
+// ================================================================================================
+
+/// Returns a transaction script which reads the balance of the fungible asset issued by the
+/// specified faucet from the vault of the basic wallet the transaction is executed against.
+///
+/// The script invokes the `get_balance` procedure of the `miden::contracts::wallets::basic_views`
+/// module and does not modify the wallet, and thus the transaction does not need to be
+/// authenticated. The balance is returned via the transaction script output (see
+/// [get_balance_from_output()]).
+pub fn build_get_balance_tx_script(faucet_id: AccountId) -> ProgramAst {
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::wallets::basic_views
+
+    begin
+        dropw push.{faucet_id}
+        call.basic_views::get_balance
+        # => [balance, 0, 0, 0, 0, ...]
+
+        swap drop movdn.3
+        # => [0, 0, 0, balance, ...]
+    end
+    ",
+        faucet_id = u64::from(faucet_id)
+    );
+
+    ProgramAst::parse(&tx_script_src).expect("get balance transaction script is well formed")
+}
+
+/// Returns a transaction script which reads the public key information of the basic wallet the
+/// transaction is executed against.
+///
+/// The script invokes the `get_public_key` procedure of the
+/// `miden::contracts::wallets::basic_views` module and does not modify the wallet, and thus the
+/// transaction does not need to be authenticated. The public key information is returned via the
+/// transaction script output (see [get_public_key_from_output()]).
+pub fn build_get_public_key_tx_script() -> ProgramAst {
+    let tx_script_src = "
+    use.miden::contracts::wallets::basic_views
+
+    begin
+        dropw call.basic_views::get_public_key
+        # => [PUB_KEY, ...]
+    end
+    ";
+
+    ProgramAst::parse(tx_script_src).expect("get public key transaction script is well formed")
+}
+
+// FOREIGN VIEWS
+// ================================================================================================
+
+/// Returns a transaction script which reads the public key information of the specified basic
+/// wallet via a foreign account read.
+///
+/// The transaction can be executed against any account; the wallet must be a foreign account of
+/// the transaction whose [PUBLIC_KEY_SLOT] is readable (see [build_public_key_foreign_inputs()]).
+/// The public key information is returned via the transaction script output (see
+/// [get_public_key_from_output()]).
+///
+/// The vault of a foreign account cannot be read, and thus balances can only be read by
+/// transactions executed against the wallet itself (see [build_get_balance_tx_script()]).
+pub fn build_read_public_key_tx_script(wallet_id: AccountId) -> ProgramAst {
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::wallets::basic_views
+
+    begin
+        dropw push.{wallet_id}
+        exec.basic_views::read_public_key
+        # => [PUB_KEY, 0, 0, 0, 0, ...]
+
+        swapw dropw
+        # => [PUB_KEY, ...]
+    end
+    ",
+        wallet_id = u64::from(wallet_id)
+    );
+
+    ProgramAst::parse(&tx_script_src).expect("read public key transaction script is well formed")
+}
+
+/// Returns the foreign account inputs which make the public key information of the provided basic
+/// wallet readable by a transaction.
+///
+/// # Errors
+/// Returns an error if the witness is not a witness of the provided wallet.
+pub fn build_public_key_foreign_inputs(
+    wallet: &Account,
+    witness: AccountWitness,
+) -> Result<ForeignAccountInputs, TransactionInputError> {
+    ForeignAccountInputs::from_account(wallet, witness, &[PUBLIC_KEY_SLOT])
+}
+
+// OUTPUT PARSING
+// ================================================================================================
+
+/// Returns the balance read by a transaction script built via [build_get_balance_tx_script()]
+/// from the output of the script.
+pub fn get_balance_from_output(output: &[Word; TX_SCRIPT_OUTPUT_WORDS]) -> u64 {
+    output[0][0].as_int()
+}
+
+/// Returns the public key information read by a transaction script built via
+/// [build_get_public_key_tx_script()] or [build_read_public_key_tx_script()] from the output of
+/// the script.
+pub fn get_public_key_from_output(output: &[Word; TX_SCRIPT_OUTPUT_WORDS]) -> Word {
+    output[0]
+}
@@ -1,4 +1,22 @@
-use miden_objects::crypto::dsa::rpo_falcon512;
+use miden_objects::{
+    accounts::AccountStorage,
+    crypto::dsa::rpo_falcon512,
+    utils::{
+        collections::{BTreeSet, Vec},
+        vec,
+    },
+    AccountError, Felt, Hasher, Word, ZERO,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of public keys of an account using the [AuthScheme::RpoFalcon512Multisig]
+/// scheme; this is bounded by the maximum arity of a storage value.
+pub const MAX_MULTISIG_KEYS: usize = 254;
+
+// AUTH SCHEME
+// ================================================================================================
 
 /// Defines authentication schemes available to standard and faucet accounts.
 pub enum AuthScheme {
@@ -7,4 +25,140 @@ pub enum AuthScheme {
     /// the standard in that instead of using SHAKE256 hash function in the hash-to-point algorithm we
     /// use RPO256. This makes the signature more efficient to verify in Miden VM.
     RpoFalcon512 { pub_key: rpo_falcon512::PublicKey },
+    /// A k-of-n authentication scheme which relies on RPO Falcon512 signatures: a transaction is
+    /// authenticated if it is signed by at least `threshold` of the specified public keys.
+    ///
+    /// The threshold and the number of keys are stored in the account storage at slot 0 as
+    /// [threshold, num_keys, 0, 0], and the public keys are stored as a multi-word value at slot 1.
+    /// The signers of a transaction are specified via the advice map entry built by
+    /// [build_multisig_signers_entry()].
+    RpoFalcon512Multisig { pub_keys: Vec<Word>, threshold: u32 },
+    /// A k-of-n authentication scheme which relies on RPO Falcon512 signatures, and which keeps a
+    /// single commitment in the account storage regardless of the number of signers.
+    ///
+    /// Slot 0 stores the commitment to the multi-word value
+    /// [[threshold, num_keys, 0, 0], PUB_KEY_0, ..., PUB_KEY_n-1]; the public keys themselves are
+    /// only provided via the advice map. The signers of a transaction are specified via the advice
+    /// map entry built by [build_threshold_signers_entry()].
+    RpoFalcon512Threshold { pub_keys: Vec<Word>, threshold: u32 },
+    /// A two-key authentication scheme which relies on RPO Falcon512 signatures: transactions can
+    /// be authenticated either by the root key, or by a temporary session key until the block with
+    /// number `expires_at_block` (inclusive).
+    ///
+    /// The root key is stored in the account storage at slot 0, and the session key and the expiry
+    /// block number are stored as a multi-word value [SESSION_KEY, [expires_at_block, 0, 0, 0]] at
+    /// slot 1. A transaction authenticated by the session key expires at the expiry block, i.e., it
+    /// cannot be included in a later block. The session key can be replaced using the
+    /// `set_session_key` procedure, which requires a signature of the root key.
+    SessionKey {
+        root_key: rpo_falcon512::PublicKey,
+        session_key: rpo_falcon512::PublicKey,
+        expires_at_block: u32,
+    },
+    /// A scheme which does not authenticate transactions: the authentication procedure only
+    /// increments the nonce of the account.
+    ///
+    /// This is meant for contract accounts whose security comes entirely from the logic of their
+    /// procedures (e.g., AMM pools), since anyone can execute transactions against such accounts.
+    /// No authentication data is stored in the account storage.
+    NoAuth,
+}
+
+// MULTISIG HELPERS
+// ================================================================================================
+
+/// Returns the advice map entry which specifies the signers of a transaction executed against an
+/// account using the [AuthScheme::RpoFalcon512Multisig] scheme with the provided public keys.
+///
+/// The signers are specified by their indexes into the list of public keys; duplicate indexes are
+/// ignored. For the transaction to be authenticated, the number of signers must be equal to the
+/// threshold of the account, and the key pair of every signer must be provided via the advice map
+/// as well.
+pub fn build_multisig_signers_entry(pub_keys: &[Word], signers: &[usize]) -> (Word, Vec<Felt>) {
+    let keys_commitment = AccountStorage::compute_words_commitment(pub_keys);
+    let key = Hasher::hash_elements(keys_commitment.as_elements());
+
+    let signers = signers.iter().copied().collect::<BTreeSet<_>>();
+    (key.into(), signers.into_iter().map(|index| Felt::new(index as u64)).collect())
+}
+
+/// Returns the advice map entry which specifies the signers of a transaction executed against an
+/// account using the [AuthScheme::RpoFalcon512Threshold] scheme with the provided public keys and
+/// threshold.
+///
+/// The signers are specified by their indexes into the list of public keys; duplicate indexes are
+/// ignored. For the transaction to be authenticated, the number of signers must be equal to the
+/// threshold of the account, and the key pair of every signer must be provided via the advice map
+/// as well.
+///
+/// # Errors
+/// Returns an error if the public keys and the threshold are not a valid threshold configuration.
+pub fn build_threshold_signers_entry(
+    pub_keys: &[Word],
+    threshold: u32,
+    signers: &[usize],
+) -> Result<(Word, Vec<Felt>), AccountError> {
+    let config = build_threshold_config(pub_keys, threshold)?;
+    let config_commitment = AccountStorage::compute_words_commitment(&config);
+    let key = Hasher::hash_elements(config_commitment.as_elements());
+
+    let signers = signers.iter().copied().collect::<BTreeSet<_>>();
+    let value = core::iter::once(Felt::new(pub_keys.len() as u64))
+        .chain(signers.into_iter().map(|index| Felt::new(index as u64)))
+        .collect();
+
+    Ok((key.into(), value))
+}
+
+/// Returns the configuration of an account using the [AuthScheme::RpoFalcon512Threshold] scheme as
+/// the multi-word value [[threshold, num_keys, 0, 0], PUB_KEY_0, ..., PUB_KEY_n-1].
+///
+/// # Errors
+/// Returns an error if:
+/// - The number of public keys is greater than [MAX_MULTISIG_KEYS].
+/// - The threshold is zero or greater than the number of public keys.
+pub(crate) fn build_threshold_config(
+    pub_keys: &[Word],
+    threshold: u32,
+) -> Result<Vec<Word>, AccountError> {
+    let mut config = vec![build_multisig_config(pub_keys, threshold)?];
+    config.extend_from_slice(pub_keys);
+    Ok(config)
+}
+
+/// Returns the configuration of an account using the [AuthScheme::RpoFalcon512Multisig] scheme as
+/// [threshold, num_keys, 0, 0].
+///
+/// # Errors
+/// Returns an error if:
+/// - The number of public keys is greater than [MAX_MULTISIG_KEYS].
+/// - The threshold is zero or greater than the number of public keys.
+pub(crate) fn build_multisig_config(
+    pub_keys: &[Word],
+    threshold: u32,
+) -> Result<Word, AccountError> {
+    if pub_keys.len() > MAX_MULTISIG_KEYS {
+        return Err(AccountError::MultisigTooManyKeys {
+            max: MAX_MULTISIG_KEYS,
+            actual: pub_keys.len(),
+        });
+    }
+    if threshold == 0 || threshold as usize > pub_keys.len() {
+        return Err(AccountError::MultisigInvalidThreshold { threshold, num_keys: pub_keys.len() });
+    }
+
+    let num_keys = pub_keys.len() as u8;
+    Ok([Felt::from(threshold), Felt::from(num_keys), ZERO, ZERO])
+}
+
+// SESSION KEY HELPERS
+// ================================================================================================
+
+/// Returns the configuration of an account using the [AuthScheme::SessionKey] scheme as the
+/// multi-word value [SESSION_KEY, [expires_at_block, 0, 0, 0]].
+pub(crate) fn build_session_key_config(
+    session_key: rpo_falcon512::PublicKey,
+    expires_at_block: u32,
+) -> Vec<Word> {
+    vec![session_key.into(), [Felt::from(expires_at_block), ZERO, ZERO, ZERO]]
 }
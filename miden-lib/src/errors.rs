@@ -0,0 +1,40 @@
+use core::fmt;
+
+// TRANSACTION KERNEL ERRORS
+// ================================================================================================
+
+// The constants below are generated by the build script from the `ERR_` constants defined in the
+// MASM sources of the transaction kernel.
+include!(concat!(env!("OUT_DIR"), "/tx_kernel_errors.rs"));
+
+/// An error raised by a failed assertion in the transaction kernel.
+///
+/// When an assertion of the transaction kernel fails, the VM reports the error code of the
+/// assertion. [TxKernelError::from_code()] can be used to translate this code into the name and
+/// the description of the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxKernelError {
+    /// The code reported by the VM when the assertion fails.
+    pub code: u32,
+    /// The name of the error constant, e.g., `ERR_VAULT_FUNGIBLE_OVERFLOW`.
+    pub name: &'static str,
+    /// A human-readable description of the condition enforced by the assertion.
+    pub description: &'static str,
+}
+
+impl TxKernelError {
+    /// Returns the kernel error with the specified code, or None if the code does not belong to an
+    /// assertion of the transaction kernel.
+    pub fn from_code(code: u32) -> Option<&'static TxKernelError> {
+        TX_KERNEL_ERRORS
+            .binary_search_by_key(&code, |error| error.code)
+            .ok()
+            .map(|index| &TX_KERNEL_ERRORS[index])
+    }
+}
+
+impl fmt::Display for TxKernelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.name, self.code, self.description)
+    }
+}
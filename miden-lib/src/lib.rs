@@ -9,9 +9,12 @@ use miden_objects::{
 };
 
 mod auth;
-pub use auth::AuthScheme;
+pub use auth::{
+    build_multisig_signers_entry, build_threshold_signers_entry, AuthScheme, MAX_MULTISIG_KEYS,
+};
 
 pub mod accounts;
+pub mod errors;
 pub mod notes;
 pub mod transaction;
 
@@ -0,0 +1,45 @@
+use miden_objects::{
+    assets::Asset, notes::NoteTag, utils::collections::Vec, Digest, Felt, NoteError, Word, ZERO,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of assets a BASKET_SWAP note can request.
+///
+/// This is bounded by the number of note inputs, as the payback recipient and the header occupy
+/// 8 inputs and every requested asset occupies 4 inputs.
+pub const MAX_REQUESTED_ASSETS: usize = 30;
+
+// BASKET SWAP
+// ================================================================================================
+
+/// Returns the note inputs of a BASKET_SWAP note which pays the requested assets back to the
+/// specified recipient, in a note with the specified tag.
+///
+/// # Errors
+/// Returns an error if:
+/// - The list of requested assets is empty.
+/// - The number of requested assets is greater than [MAX_REQUESTED_ASSETS].
+pub(super) fn build_basket_swap_inputs(
+    payback_recipient: Digest,
+    payback_tag: NoteTag,
+    requested: &[Asset],
+) -> Result<Vec<Felt>, NoteError> {
+    if requested.is_empty() {
+        return Err(NoteError::EmptyAssetList);
+    }
+    if requested.len() > MAX_REQUESTED_ASSETS {
+        return Err(NoteError::TooManyRequestedAssets(requested.len()));
+    }
+
+    let mut inputs = Vec::with_capacity(8 + 4 * requested.len());
+    inputs.extend_from_slice(payback_recipient.as_elements());
+    inputs.extend_from_slice(&[payback_tag.into(), Felt::from(requested.len() as u8), ZERO, ZERO]);
+    for asset in requested {
+        let asset: Word = (*asset).into();
+        inputs.extend_from_slice(&asset);
+    }
+
+    Ok(inputs)
+}
@@ -0,0 +1,55 @@
+use miden_objects::{
+    accounts::AccountId, crypto::rand::FeltRng, utils::collections::Vec, Felt, NoteError, Word,
+    ZERO,
+};
+
+use super::utils::build_p2id_recipient;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of payouts a BATCH_P2ID note can contain.
+///
+/// This is bounded by the number of note inputs, as the header occupies 4 inputs and every payout
+/// occupies 8 inputs.
+pub const MAX_BATCH_PAYOUTS: usize = 15;
+
+// BATCH PAYOUTS
+// ================================================================================================
+
+/// Returns the note inputs of a BATCH_P2ID note for the specified distributor and payouts,
+/// together with the serial numbers of the P2ID notes created for the payouts.
+///
+/// The passed-in `rng` is used to generate the serial numbers of the P2ID notes.
+///
+/// # Errors
+/// Returns an error if:
+/// - The list of payouts is empty.
+/// - The number of payouts is greater than [MAX_BATCH_PAYOUTS].
+pub(super) fn build_batch_inputs<R: FeltRng>(
+    distributor: AccountId,
+    payouts: &[(AccountId, u64)],
+    rng: &mut R,
+) -> Result<(Vec<Felt>, Vec<Word>), NoteError> {
+    if payouts.is_empty() {
+        return Err(NoteError::EmptyBatchPayouts);
+    }
+    if payouts.len() > MAX_BATCH_PAYOUTS {
+        return Err(NoteError::TooManyBatchPayouts(payouts.len()));
+    }
+
+    let mut inputs = Vec::with_capacity(4 + 8 * payouts.len());
+    inputs.extend_from_slice(&[distributor.into(), Felt::from(payouts.len() as u8), ZERO, ZERO]);
+
+    let mut serial_nums = Vec::with_capacity(payouts.len());
+    for (target, amount) in payouts {
+        let serial_num = rng.draw_word();
+        let recipient = build_p2id_recipient(*target, serial_num)?;
+
+        inputs.extend_from_slice(recipient.as_elements());
+        inputs.extend_from_slice(&[(*target).into(), Felt::new(*amount), ZERO, ZERO]);
+        serial_nums.push(serial_num);
+    }
+
+    Ok((inputs, serial_nums))
+}
@@ -0,0 +1,204 @@
+use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
+    crypto::rand::FeltRng,
+    notes::{Note, NoteScript, NoteTag},
+    transaction::OutputNote,
+    utils::collections::Vec,
+    Digest, Felt, NoteError, Word,
+};
+
+// NOTE CHAIN INPUT
+// ================================================================================================
+
+/// An input of a note in a [NoteChain].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteChainInput {
+    /// A fixed input value.
+    Value(Felt),
+    /// The recipient of the note created in the next step of the chain. This placeholder expands
+    /// into the four elements of the recipient digest.
+    NextRecipient,
+}
+
+impl From<Felt> for NoteChainInput {
+    fn from(value: Felt) -> Self {
+        Self::Value(value)
+    }
+}
+
+// NOTE CHAIN STEP
+// ================================================================================================
+
+/// Specification of a single step of a [NoteChain].
+///
+/// A step describes a note and the account which is expected to consume it. The note of the first
+/// step is created by the origin of the chain; the note of every other step is created by the
+/// transaction consuming the note of the previous step, and thus its sender is the consumer of the
+/// previous step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteChainStep {
+    script: NoteScript,
+    inputs: Vec<NoteChainInput>,
+    assets: Vec<Asset>,
+    consumer: AccountId,
+    tag: NoteTag,
+}
+
+impl NoteChainStep {
+    /// Returns a new [NoteChainStep] instantiated from the provided parameters.
+    pub fn new(
+        script: NoteScript,
+        inputs: Vec<NoteChainInput>,
+        assets: Vec<Asset>,
+        consumer: AccountId,
+        tag: NoteTag,
+    ) -> Self {
+        Self { script, inputs, assets, consumer, tag }
+    }
+
+    /// Returns the ID of the account expected to consume the note of this step.
+    pub fn consumer(&self) -> AccountId {
+        self.consumer
+    }
+}
+
+// NOTE CHAIN TRANSACTION
+// ================================================================================================
+
+/// A transaction which executes a single step of a [NoteChain].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteChainTransaction {
+    account_id: AccountId,
+    input_note: Note,
+    expected_output_note: Option<OutputNote>,
+}
+
+impl NoteChainTransaction {
+    /// Returns the ID of the account against which the transaction should be executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the note which should be consumed by the transaction.
+    pub fn input_note(&self) -> &Note {
+        &self.input_note
+    }
+
+    /// Returns the note the transaction is expected to create, or None if this is the last step
+    /// of the chain.
+    pub fn expected_output_note(&self) -> Option<&OutputNote> {
+        self.expected_output_note.as_ref()
+    }
+}
+
+// NOTE CHAIN
+// ================================================================================================
+
+/// A sequence of dependent notes where each note is created by the transaction consuming the note
+/// of the previous step.
+///
+/// Multi-hop flows (e.g., a swap followed by a payment) require the notes of later steps to be
+/// known in advance, because the scripts of earlier notes need to commit to their recipients. A
+/// [NoteChain] precomputes serial numbers and recipients for every step, resolving
+/// [NoteChainInput::NextRecipient] placeholders, and produces the list of transactions which
+/// execute the chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteChain {
+    origin: AccountId,
+    steps: Vec<NoteChainStep>,
+}
+
+impl NoteChain {
+    /// Returns a new [NoteChain] starting at the `origin` account, which creates the note of the
+    /// first step.
+    pub fn new(origin: AccountId, steps: Vec<NoteChainStep>) -> Self {
+        Self { origin, steps }
+    }
+
+    /// Returns the account which creates the note of the first step.
+    pub fn origin(&self) -> AccountId {
+        self.origin
+    }
+
+    /// Returns the steps of this chain.
+    pub fn steps(&self) -> &[NoteChainStep] {
+        &self.steps
+    }
+
+    /// Builds the notes of all steps and returns the transactions which execute the chain, in
+    /// execution order.
+    ///
+    /// Serial numbers are drawn from the passed-in `rng` in step order, so building the same chain
+    /// with identically seeded generators yields identical notes. The serial numbers of the
+    /// resulting notes can be retrieved via [Note::serial_num()] on the input notes of the
+    /// returned transactions.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The chain does not contain any steps.
+    /// - The last step uses [NoteChainInput::NextRecipient] as an input.
+    /// - Any of the notes cannot be created (e.g., too many inputs or assets).
+    pub fn build<R: FeltRng>(&self, mut rng: R) -> Result<Vec<NoteChainTransaction>, NoteError> {
+        if self.steps.is_empty() {
+            return Err(NoteError::EmptyNoteChain);
+        }
+
+        let serial_nums: Vec<Word> = self.steps.iter().map(|_| rng.draw_word()).collect();
+
+        // notes are built back to front, as every note may commit to the recipient of the next one
+        let mut transactions = Vec::with_capacity(self.steps.len());
+        let mut next_note: Option<Note> = None;
+        for (index, step) in self.steps.iter().enumerate().rev() {
+            let next_recipient = next_note.as_ref().map(|note| note.recipient());
+            let inputs = resolve_inputs(index, &step.inputs, next_recipient)?;
+
+            let sender = match index {
+                0 => self.origin,
+                _ => self.steps[index - 1].consumer,
+            };
+
+            let note = Note::new(
+                step.script.clone(),
+                &inputs,
+                &step.assets,
+                serial_nums[index],
+                sender,
+                step.tag,
+            )?;
+
+            transactions.push(NoteChainTransaction {
+                account_id: step.consumer,
+                input_note: note.clone(),
+                expected_output_note: next_note.as_ref().map(OutputNote::from),
+            });
+            next_note = Some(note);
+        }
+
+        transactions.reverse();
+        Ok(transactions)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Expands the provided step inputs into a list of field elements.
+fn resolve_inputs(
+    step: usize,
+    inputs: &[NoteChainInput],
+    next_recipient: Option<Digest>,
+) -> Result<Vec<Felt>, NoteError> {
+    let mut values = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        match input {
+            NoteChainInput::Value(value) => values.push(*value),
+            NoteChainInput::NextRecipient => {
+                let recipient = next_recipient.ok_or(NoteError::NoteChainMissingNextStep(step))?;
+                values.extend_from_slice(recipient.as_elements());
+            },
+        }
+    }
+
+    Ok(values)
+}
@@ -0,0 +1,139 @@
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305, Nonce,
+};
+use hkdf::Hkdf;
+use miden_objects::{
+    notes::{EncryptedNoteDetails, NoteDetails},
+    utils::serde::{Deserializable, Serializable},
+    NoteError,
+};
+use rand::{CryptoRng, RngCore};
+use sha2::Sha256;
+use x25519_dalek::{PublicKey, SharedSecret, StaticSecret};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Domain separation label used when deriving the symmetric encryption key from the shared secret.
+const KEY_DERIVATION_DOMAIN: &[u8] = b"miden-note-details-encryption-v1";
+
+// ENCRYPTION KEYS
+// ================================================================================================
+
+/// The public key to which the details of notes targeted at an account are encrypted.
+///
+/// Account authentication keys are signature keys, and thus cannot be used for encryption;
+/// instead, the recipient of a note publishes an X25519 public key alongside its account ID.
+pub type NoteEncryptionKey = PublicKey;
+
+/// The secret key used by the recipient of a note to decrypt the details of the note.
+pub type NoteDecryptionKey = StaticSecret;
+
+/// Returns a new [NoteDecryptionKey] generated using the passed-in cryptographically secure `rng`,
+/// e.g., [OsRng](rand::rngs::OsRng).
+///
+/// The corresponding [NoteEncryptionKey] can be obtained via `NoteEncryptionKey::from(&key)`.
+pub fn generate_note_decryption_key<R: CryptoRng + RngCore>(mut rng: R) -> NoteDecryptionKey {
+    StaticSecret::random_from_rng(&mut rng)
+}
+
+// ENCRYPTION
+// ================================================================================================
+
+/// Encrypts the provided note details to the specified recipient key.
+///
+/// A fresh ephemeral key pair is drawn from the passed-in cryptographically secure `rng` for every
+/// envelope; the note details are encrypted with ChaCha20-Poly1305 under a key derived via
+/// HKDF-SHA256 from the X25519 shared secret between the ephemeral key and the recipient key. The
+/// ID of the note is authenticated together with the ciphertext.
+///
+/// # Errors
+/// Returns an error if the recipient key is a low-order point, and thus no secret can be agreed
+/// upon with it.
+pub fn encrypt_note_details<R: CryptoRng + RngCore>(
+    details: &NoteDetails,
+    recipient_key: &NoteEncryptionKey,
+    mut rng: R,
+) -> Result<EncryptedNoteDetails, NoteError> {
+    let ephemeral_secret = StaticSecret::random_from_rng(&mut rng);
+    let ephemeral_key = PublicKey::from(&ephemeral_secret);
+
+    let shared_secret = ephemeral_secret.diffie_hellman(recipient_key);
+    if !shared_secret.was_contributory() {
+        return Err(NoteError::InvalidNoteEncryptionKey);
+    }
+
+    let note_id = details.id();
+    let cipher = build_cipher(&shared_secret, &ephemeral_key, recipient_key);
+    let payload = Payload {
+        msg: &details.to_bytes(),
+        aad: &note_id.as_bytes(),
+    };
+    let ciphertext = cipher.encrypt(&Nonce::default(), payload).expect("encryption failed");
+
+    Ok(EncryptedNoteDetails::new(note_id, ephemeral_key.to_bytes(), ciphertext))
+}
+
+/// Decrypts the provided encrypted note details using the recipient's secret key.
+///
+/// # Errors
+/// Returns an error if:
+/// - The note details were not encrypted to the public key of the specified secret key, or the
+///   envelope has been tampered with.
+/// - The decrypted note details cannot be deserialized.
+/// - The ID of the decrypted note details does not match the note ID of the envelope.
+pub fn decrypt_note_details(
+    encrypted: &EncryptedNoteDetails,
+    secret_key: &NoteDecryptionKey,
+) -> Result<NoteDetails, NoteError> {
+    let note_id = encrypted.note_id();
+    let ephemeral_key = PublicKey::from(*encrypted.ephemeral_key());
+    let recipient_key = PublicKey::from(secret_key);
+
+    let shared_secret = secret_key.diffie_hellman(&ephemeral_key);
+    if !shared_secret.was_contributory() {
+        return Err(NoteError::NoteDecryptionFailed(note_id));
+    }
+
+    let cipher = build_cipher(&shared_secret, &ephemeral_key, &recipient_key);
+    let payload = Payload {
+        msg: encrypted.ciphertext(),
+        aad: &note_id.as_bytes(),
+    };
+    let plaintext = cipher
+        .decrypt(&Nonce::default(), payload)
+        .map_err(|_| NoteError::NoteDecryptionFailed(note_id))?;
+
+    let details =
+        NoteDetails::read_from_bytes(&plaintext).map_err(NoteError::NoteDeserializationError)?;
+    if details.id() != note_id {
+        return Err(NoteError::InconsistentEncryptedNoteId(note_id, details.id()));
+    }
+
+    Ok(details)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the cipher keyed with the key derived from the provided shared secret and public keys.
+///
+/// The key is derived via HKDF-SHA256 with the shared secret as input key material, and the
+/// domain separation label followed by the ephemeral and recipient keys as context information.
+///
+/// Since a fresh ephemeral key is used for every envelope, every derived key is used to encrypt a
+/// single message, and thus the default (all-zero) nonce can be used safely.
+fn build_cipher(
+    shared_secret: &SharedSecret,
+    ephemeral_key: &PublicKey,
+    recipient_key: &PublicKey,
+) -> ChaCha20Poly1305 {
+    let info = [KEY_DERIVATION_DOMAIN, ephemeral_key.as_bytes(), recipient_key.as_bytes()];
+
+    let mut key = [0_u8; 32];
+    Hkdf::<Sha256>::new(None, shared_secret.as_bytes())
+        .expand_multi_info(&info, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    ChaCha20Poly1305::new(&key.into())
+}
@@ -0,0 +1,168 @@
+use miden_objects::{
+    accounts::AccountId,
+    crypto::dsa::rpo_falcon512::{Polynomial, PublicKey, Signature},
+    notes::Note,
+    utils::collections::Vec,
+    Digest, Felt, Hasher, NoteError, StarkField, Word, ZERO,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of signers an ESCROW note can commit to.
+///
+/// This is bounded by the number of note inputs, as the header and the escrow ID occupy 8 inputs
+/// and every public key occupies 4 inputs.
+pub const MAX_ESCROW_SIGNERS: usize = 30;
+
+/// Index of the note input at which the public key of the first signer is stored.
+const SIGNERS_OFFSET: usize = 8;
+
+// ESCROW APPROVALS
+// ================================================================================================
+
+/// Returns the note inputs of an ESCROW note for the specified signers, threshold and escrow ID.
+///
+/// # Errors
+/// Returns an error if:
+/// - The number of signers is greater than [MAX_ESCROW_SIGNERS].
+/// - The threshold is zero or greater than the number of signers.
+/// - The list of signers contains duplicates.
+pub(super) fn build_escrow_inputs(
+    signers: &[PublicKey],
+    threshold: usize,
+    escrow_id: Word,
+) -> Result<Vec<Felt>, NoteError> {
+    if signers.len() > MAX_ESCROW_SIGNERS {
+        return Err(NoteError::TooManyEscrowSigners(signers.len()));
+    }
+    if threshold == 0 || threshold > signers.len() {
+        return Err(NoteError::InvalidEscrowThreshold { threshold, num_signers: signers.len() });
+    }
+
+    let mut inputs = Vec::with_capacity(SIGNERS_OFFSET + 4 * signers.len());
+    inputs.extend_from_slice(&[Felt::from(threshold as u8), Felt::from(signers.len() as u8)]);
+    inputs.extend_from_slice(&[ZERO, ZERO]);
+    inputs.extend_from_slice(&escrow_id);
+
+    let mut pub_keys: Vec<Word> = Vec::with_capacity(signers.len());
+    for signer in signers {
+        let pub_key: Word = (*signer).into();
+        if pub_keys.contains(&pub_key) {
+            return Err(NoteError::DuplicateEscrowSigner(pub_key));
+        }
+        inputs.extend_from_slice(&pub_key);
+        pub_keys.push(pub_key);
+    }
+
+    Ok(inputs)
+}
+
+/// Returns the message the signers of the specified ESCROW note have to sign in order to approve
+/// the consumption of the note by the `consumer` account.
+///
+/// The message is computed as hash(ESCROW_ID, [consumer, 0, 0, 0]).
+///
+/// # Panics
+/// Panics if the note has fewer inputs than an ESCROW note.
+pub fn compute_escrow_approval_message(note: &Note, consumer: AccountId) -> Digest {
+    let inputs = note.inputs().values();
+    let escrow_id: Word = [inputs[4], inputs[5], inputs[6], inputs[7]];
+    let consumer: Word = [consumer.into(), ZERO, ZERO, ZERO];
+
+    Hasher::merge(&[escrow_id.into(), consumer.into()])
+}
+
+/// Returns the note args which select the specified approvers among the signers of the specified
+/// ESCROW note.
+///
+/// The returned word should be provided as the note args of the note when executing the consuming
+/// transaction.
+///
+/// # Errors
+/// Returns an error if any of the approvers is not a signer of the note.
+pub fn build_escrow_note_args(note: &Note, approvers: &[PublicKey]) -> Result<Word, NoteError> {
+    let signers = escrow_signers(note);
+
+    let mut signer_bitmap = 0_u32;
+    for approver in approvers {
+        let pub_key: Word = (*approver).into();
+        let index = signers
+            .iter()
+            .position(|signer| signer == &pub_key)
+            .ok_or(NoteError::UnknownEscrowSigner(pub_key))?;
+        signer_bitmap |= 1 << index;
+    }
+
+    Ok([Felt::from(signer_bitmap), ZERO, ZERO, ZERO])
+}
+
+/// Returns the advice map entries which provide the specified approvals of the consumption of the
+/// specified ESCROW note by the `consumer` account.
+///
+/// Every approval is a signature produced by a signer over the approval message of the note (see
+/// [compute_escrow_approval_message()]). The signatures are keyed by hash(PUB_KEY, MSG), under
+/// which the transaction host looks up precomputed signatures before signing a message, and are
+/// verified by the note script; thus, the secret keys of the signers are never provided to the
+/// consuming transaction.
+///
+/// The returned entries should be provided as inputs of the transaction script of the consuming
+/// transaction, alongside the entries required by the authentication scheme of the consuming
+/// account.
+///
+/// # Errors
+/// Returns an error if any of the signatures is not a valid signature of the approval message
+/// under the public key it is provided with.
+pub fn build_escrow_signature_inputs(
+    note: &Note,
+    consumer: AccountId,
+    approvals: &[(PublicKey, Signature)],
+) -> Result<Vec<(Word, Vec<Felt>)>, NoteError> {
+    let message = compute_escrow_approval_message(note, consumer);
+
+    approvals
+        .iter()
+        .map(|(pub_key, signature)| {
+            let pub_key_word: Word = (*pub_key).into();
+            if !pub_key.verify(message.into(), signature) {
+                return Err(NoteError::InvalidEscrowSignature(pub_key_word));
+            }
+            let key = Hasher::merge(&[pub_key_word.into(), message]);
+            Ok((key.into(), signature_to_advice_elements(signature)))
+        })
+        .collect()
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the public keys of the signers stored in the inputs of the specified ESCROW note.
+///
+/// If the note is not an ESCROW note, the returned list may be empty or contain arbitrary words.
+fn escrow_signers(note: &Note) -> Vec<Word> {
+    let inputs = note.inputs().values();
+    let num_signers = inputs.get(1).map(|value| value.as_int() as usize).unwrap_or_default();
+
+    inputs
+        .get(SIGNERS_OFFSET..)
+        .unwrap_or_default()
+        .chunks_exact(4)
+        .take(num_signers)
+        .map(|key| [key[0], key[1], key[2], key[3]])
+        .collect()
+}
+
+/// Returns the elements of the specified signature in the order in which they are expected on the
+/// advice stack by the Falcon signature verification procedure, i.e., the nonce, the expanded
+/// public key, the signature polynomial, and the product of the latter two.
+fn signature_to_advice_elements(signature: &Signature) -> Vec<Felt> {
+    let pub_key_poly = signature.pub_key_poly();
+    let sig_poly = signature.sig_poly();
+    let product = Polynomial::mul_modulo_p(&pub_key_poly, &sig_poly);
+
+    let mut elements: Vec<Felt> = signature.nonce().to_vec();
+    elements.extend(pub_key_poly.inner().iter().map(|coef| Felt::from(*coef)));
+    elements.extend(sig_poly.inner().iter().map(|coef| Felt::from(*coef)));
+    elements.extend(product.iter().map(|coef| Felt::new(*coef)));
+    elements
+}
@@ -1,10 +1,23 @@
 use miden_objects::{
-    accounts::AccountId, assets::Asset, crypto::rand::FeltRng, notes::Note,
-    utils::collections::Vec, Felt, NoteError, Word,
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::FeltRng},
+    notes::{Note, NoteAssets, NoteDetails, NoteInputs, NoteMetadata, NoteTag, NoteType},
+    utils::collections::Vec,
+    Digest, Felt, NoteError, Word,
 };
 
 use self::utils::build_note_script;
+use crate::accounts::wallets::compute_claim_serial_num;
 
+pub mod basket;
+pub mod batch;
+pub mod chain;
+pub mod encryption;
+pub mod escrow;
+pub mod rng;
+pub mod scripts;
+pub mod subscription;
 pub mod utils;
 
 // STANDARDIZED SCRIPTS
@@ -16,7 +29,8 @@ pub mod utils;
 /// by specifying the target's account ID.
 ///
 /// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
-/// is set to the target's account ID.
+/// is derived from the target's account ID (see [NoteTag::for_account()]), and its metadata
+/// carries the specified `note_type` and `aux` value.
 ///
 /// # Errors
 /// Returns an error if deserialization or compilation of the `P2ID` script fails.
@@ -24,18 +38,144 @@ pub fn create_p2id_note<R: FeltRng>(
     sender: AccountId,
     target: AccountId,
     assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
     mut rng: R,
 ) -> Result<Note, NoteError> {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
     let note_script = build_note_script(bytes)?;
 
-    let inputs = [target.into()];
-    let tag: Felt = target.into();
+    let inputs = NoteInputs::new(vec![target.into()])?;
+    let metadata = NoteMetadata::new(sender, NoteTag::for_account(target))?
+        .with_note_type(note_type)
+        .with_aux(aux);
+    let serial_num = rng.draw_word();
+
+    Ok(Note::from_parts(
+        note_script,
+        inputs,
+        NoteAssets::new(&assets)?,
+        serial_num,
+        metadata,
+    ))
+}
+
+/// Generates a P2ID_ANY note - pay to any of a set of ids note.
+///
+/// This script enables the transfer of assets from the `sender` account to any one of the
+/// `targets` accounts, e.g., to either of two devices of the same user, or to a hot/cold wallet
+/// pair. The target account IDs are committed to by the note inputs, and the script checks at note
+/// consumption that the ID of the executing account is one of them.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is derived from the ID of the first target account (see [NoteTag::for_account()]).
+///
+/// # Errors
+/// Returns an error if:
+/// - Deserialization or compilation of the `P2ID_ANY` script fails.
+/// - The list of targets is empty.
+/// - The list of targets contains duplicates.
+/// - The number of targets is greater than the maximum number of note inputs.
+pub fn create_p2id_any_note<R: FeltRng>(
+    sender: AccountId,
+    targets: &[AccountId],
+    assets: Vec<Asset>,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID_ANY.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let first_target = targets.first().ok_or(NoteError::EmptyNoteTargets)?;
+    for (i, target) in targets.iter().enumerate() {
+        if targets[..i].contains(target) {
+            return Err(NoteError::DuplicateNoteTarget(*target));
+        }
+    }
+
+    let inputs: Vec<Felt> = targets.iter().map(|target| (*target).into()).collect();
+    let tag = NoteTag::for_account(*first_target);
     let serial_num = rng.draw_word();
 
     Note::new(note_script, &inputs, &assets, serial_num, sender, tag)
 }
 
+/// Generates a P2IDC note - pay to id claimable note, and the claim note for the payment.
+///
+/// This script enables the transfer of an asset from the `sender` account to the `target` account
+/// by specifying the target's account ID. Unlike P2ID notes, the target must be a claimable wallet
+/// (see [create_claimable_wallet()](crate::accounts::wallets::create_claimable_wallet)): when the
+/// payment note is consumed, the asset is not added to the target's vault but recorded as a
+/// pending claim entry, and moved into a claim note built by the target and addressed to itself.
+/// The asset is added to the target's vault only once the target consumes the claim note.
+///
+/// The ID of the claim entry is the serial number of the payment note, which is generated using
+/// the passed-in `rng`. The serial number of the claim note is derived from the entry ID (see
+/// [compute_claim_serial_num()](crate::accounts::wallets::compute_claim_serial_num)), and the tags
+/// of both notes are derived from the target's account ID (see [NoteTag::for_account()]).
+///
+/// The payment note is returned together with the claim note which is created when the payment
+/// note is consumed.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2IDC` script fails.
+pub fn create_p2idc_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    asset: Asset,
+    mut rng: R,
+) -> Result<(Note, Note), NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDC.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let tag = NoteTag::for_account(target);
+    let serial_num = rng.draw_word();
+
+    let claim_inputs = [
+        serial_num[0],
+        serial_num[1],
+        serial_num[2],
+        serial_num[3],
+        target.into(),
+    ];
+    let claim_serial_num = compute_claim_serial_num(target, serial_num.into());
+    let claim_note =
+        Note::new(note_script.clone(), &claim_inputs, &[asset], claim_serial_num, target, tag)?;
+
+    let note = Note::new(note_script, &[target.into()], &[asset], serial_num, sender, tag)?;
+
+    Ok((note, claim_note))
+}
+
+/// Generates a PAYMENT note - payment of an invoice issued by a merchant wallet.
+///
+/// This script enables the payment of the invoice with the specified `invoice_id` from the
+/// `sender` account to the `merchant` account. The merchant must be a merchant wallet (see
+/// [create_merchant_wallet()](crate::accounts::wallets::create_merchant_wallet)) with an open
+/// payment request for the invoice; the note can be consumed only if `asset` matches the requested
+/// asset exactly.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is derived from the merchant's account ID (see [NoteTag::for_account()]).
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `PAYMENT` script fails.
+pub fn create_payment_note<R: FeltRng>(
+    sender: AccountId,
+    merchant: AccountId,
+    asset: FungibleAsset,
+    invoice_id: u8,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/PAYMENT.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let inputs = [merchant.into(), Felt::from(invoice_id)];
+    let tag = NoteTag::for_account(merchant);
+    let serial_num = rng.draw_word();
+
+    Note::new(note_script, &inputs, &[asset.into()], serial_num, sender, tag)
+}
+
 /// Generates a P2IDR note - pay to id with recall after a certain block height.
 ///
 /// This script enables the transfer of assets from the sender `sender` account to the `target`
@@ -44,7 +184,7 @@ pub fn create_p2id_note<R: FeltRng>(
 /// specified timeframe.
 ///
 /// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
-/// is set to the target's account ID.
+/// is derived from the target's account ID (see [NoteTag::for_account()]).
 ///
 /// # Errors
 /// Returns an error if deserialization or compilation of the `P2IDR` script fails.
@@ -59,31 +199,211 @@ pub fn create_p2idr_note<R: FeltRng>(
     let note_script = build_note_script(bytes)?;
 
     let inputs = [target.into(), recall_height.into()];
-    let tag: Felt = target.into();
+    let tag = NoteTag::for_account(target);
     let serial_num = rng.draw_word();
 
     Note::new(note_script.clone(), &inputs, &assets, serial_num, sender, tag)
 }
 
+/// Generates an HTLC note - hash-time-locked contract.
+///
+/// This script enables the transfer of assets from the `sender` account to the `target` account,
+/// provided that the target knows the preimage of the specified `hashlock` (see
+/// [compute_htlc_hashlock()](utils::compute_htlc_hashlock)). The target redeems the note by
+/// passing the preimage via the note args before the block height reaches `timeout_height`; the
+/// redeeming transaction expires at the block preceding `timeout_height`. If the note has not been
+/// redeemed by the target, the sender can reclaim the assets once the block height reaches
+/// `timeout_height`.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is derived from the target's account ID (see [NoteTag::for_account()]).
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `HTLC` script fails.
+pub fn create_htlc_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    hashlock: Digest,
+    timeout_height: u32,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/HTLC.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let inputs = [
+        hashlock[0],
+        hashlock[1],
+        hashlock[2],
+        hashlock[3],
+        target.into(),
+        timeout_height.into(),
+    ];
+    let tag = NoteTag::for_account(target);
+    let serial_num = rng.draw_word();
+
+    Note::new(note_script, &inputs, &assets, serial_num, sender, tag)
+}
+
+/// Generates a BATCH_P2ID note - pay to multiple ids via a distributor.
+///
+/// This script enables the `sender` account to pay out amounts of the fungible asset issued by
+/// `faucet_id` to multiple target accounts with a single note. The note contains the sum of the
+/// `payouts` amounts and can be consumed only by the `distributor` account, which creates one P2ID
+/// note for every `(target, amount)` pair of `payouts` in the consuming transaction.
+///
+/// The passed-in `rng` is used to generate a serial number for the note, and the serial numbers
+/// of the P2ID notes paying out to the targets. The latter are returned together with the note, in
+/// the same order as `payouts`, as they are needed to consume the P2ID notes. The returned note's
+/// tag is derived from the distributor's account ID (see [NoteTag::for_account()]).
+///
+/// # Errors
+/// Returns an error if:
+/// - Deserialization or compilation of the `BATCH_P2ID` script fails.
+/// - The list of payouts is empty.
+/// - The number of payouts is greater than [batch::MAX_BATCH_PAYOUTS].
+/// - The sum of the payout amounts is not a valid amount of a fungible asset issued by
+///   `faucet_id`.
+pub fn create_batch_p2id_note<R: FeltRng>(
+    sender: AccountId,
+    distributor: AccountId,
+    faucet_id: AccountId,
+    payouts: &[(AccountId, u64)],
+    mut rng: R,
+) -> Result<(Note, Vec<Word>), NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BATCH_P2ID.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let total_amount =
+        payouts.iter().fold(0_u64, |total, (_, amount)| total.saturating_add(*amount));
+    let asset = FungibleAsset::new(faucet_id, total_amount).map_err(NoteError::InvalidAssetData)?;
+
+    let (inputs, payout_serial_nums) = batch::build_batch_inputs(distributor, payouts, &mut rng)?;
+    let tag = NoteTag::for_account(distributor);
+    let serial_num = rng.draw_word();
+
+    let note = Note::new(note_script, &inputs, &[asset.into()], serial_num, sender, tag)?;
+
+    Ok((note, payout_serial_nums))
+}
+
+/// Generates a RAFFLE_TICKET note - purchase of a ticket of a raffle.
+///
+/// This script enables the `sender` account to buy a ticket of the raffle held by the `raffle`
+/// account (see [create_raffle_account()](crate::accounts::raffle::create_raffle_account)). The
+/// `assets` of the note pay for the ticket and must consist of exactly the ticket price of the
+/// raffle. The `commitment` is accumulated into the raffle entropy; it is never revealed, and the
+/// randomness the winning ticket is derived from does not rely on it.
+///
+/// The passed-in `rng` is used to generate a serial number for the note, and the serial number of
+/// the P2ID note which pays out the prize to `sender` if the ticket wins. The latter is returned
+/// together with the note, as it is needed to consume the prize note. The returned note's tag
+/// is derived from the raffle's account ID (see [NoteTag::for_account()]).
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `RAFFLE_TICKET` script fails.
+pub fn create_raffle_ticket_note<R: FeltRng>(
+    sender: AccountId,
+    raffle: AccountId,
+    assets: Vec<Asset>,
+    commitment: Digest,
+    mut rng: R,
+) -> Result<(Note, Word), NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/RAFFLE_TICKET.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let prize_serial_num = rng.draw_word();
+    let prize_recipient = utils::build_p2id_recipient(sender, prize_serial_num)?;
+
+    let inputs = [
+        prize_recipient[0],
+        prize_recipient[1],
+        prize_recipient[2],
+        prize_recipient[3],
+        commitment[0],
+        commitment[1],
+        commitment[2],
+        commitment[3],
+        raffle.into(),
+    ];
+
+    let tag = NoteTag::for_account(raffle);
+    let serial_num = rng.draw_word();
+
+    let note = Note::new(note_script, &inputs, &assets, serial_num, sender, tag)?;
+
+    Ok((note, prize_serial_num))
+}
+
+/// Generates an ESCROW note - pay to any account approved by m of n signers.
+///
+/// This script enables the transfer of assets from the `sender` account to any account whose
+/// consumption of the note is approved by at least `threshold` of the specified `signers`.
+/// Approvals are RPO Falcon512 signatures which are verified when the note is consumed, and are
+/// independent of the authentication scheme of the consuming account. Helpers for computing the
+/// approval message and assembling a set of approvals are provided in the [escrow] module.
+///
+/// The passed-in `rng` is used to generate a serial number for the note, and a unique escrow ID
+/// which the approvals are bound to. The returned note's tag is set to 0.
+///
+/// # Errors
+/// Returns an error if:
+/// - Deserialization or compilation of the `ESCROW` script fails.
+/// - The number of signers is greater than [escrow::MAX_ESCROW_SIGNERS].
+/// - The threshold is zero or greater than the number of signers.
+/// - The list of signers contains duplicates.
+pub fn create_escrow_note<R: FeltRng>(
+    sender: AccountId,
+    signers: &[PublicKey],
+    threshold: usize,
+    assets: Vec<Asset>,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/ESCROW.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let escrow_id = rng.draw_word();
+    let inputs = escrow::build_escrow_inputs(signers, threshold, escrow_id)?;
+    let tag = NoteTag::default();
+    let serial_num = rng.draw_word();
+
+    Note::new(note_script, &inputs, &assets, serial_num, sender, tag)
+}
+
 /// Generates a SWAP note - swap of assets between two accounts.
 ///
 /// This script enables a swap of 2 assets between the `sender` account and any other account that
 /// is willing to consume the note. The consumer will receive the `offered_asset` and will create a
 /// new P2ID note with `sender` as target, containing the `requested_asset`.
 ///
+/// The `sender` can consume the note at any time, which cancels the swap order and returns the
+/// `offered_asset` to the `sender` (see
+/// [build_cancel_tx_script()](crate::accounts::wallets::build_cancel_tx_script)). If
+/// `expires_at_block` is specified, the swap can be filled by other accounts only before the block
+/// height reaches the specified value.
+///
+/// Besides the SWAP note, the details of the P2ID note which is expected to be created by the
+/// consumer of the SWAP note are returned; these can be used by the `sender` to consume the
+/// payback note. Both the SWAP note and the payback note carry the specified `note_type` and `aux`
+/// value in their metadata.
+///
 /// # Errors
 /// Returns an error if deserialization or compilation of the `SWAP` script fails.
 pub fn create_swap_note<R: FeltRng>(
     sender: AccountId,
     offered_asset: Asset,
     requested_asset: Asset,
+    expires_at_block: Option<u32>,
+    note_type: NoteType,
+    aux: Felt,
     mut rng: R,
-) -> Result<(Note, Word), NoteError> {
+) -> Result<(Note, NoteDetails), NoteError> {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
     let note_script = build_note_script(bytes)?;
 
     let payback_serial_num = rng.draw_word();
-    let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
+    let payback_note = utils::build_p2id_note_details(sender, requested_asset, payback_serial_num)?;
+    let payback_recipient = payback_note.recipient();
     let asset_word: Word = requested_asset.into();
 
     let inputs = [
@@ -95,13 +415,220 @@ pub fn create_swap_note<R: FeltRng>(
         asset_word[1],
         asset_word[2],
         asset_word[3],
-        sender.into(),
+        NoteTag::for_account(sender).into(),
+        expires_at_block.unwrap_or(0).into(),
+        note_type.into(),
+        aux,
+    ];
+
+    let metadata = NoteMetadata::new(sender, NoteTag::default())?
+        .with_note_type(note_type)
+        .with_aux(aux);
+    let serial_num = rng.draw_word();
+
+    let note = Note::from_parts(
+        note_script,
+        NoteInputs::new(inputs.to_vec())?,
+        NoteAssets::new(&[offered_asset])?,
+        serial_num,
+        metadata,
+    );
+
+    Ok((note, payback_note))
+}
+
+/// Generates a BASKET_SWAP note - swap of a bundle of assets for another bundle of assets.
+///
+/// This script enables a swap of several assets between the `sender` account and any other
+/// account that is willing to consume the note. The consumer will receive all `offered` assets and
+/// will create a single P2ID note with `sender` as target, containing all `requested` assets; the
+/// exchange is atomic, i.e., either all assets are exchanged or the note cannot be consumed. The
+/// consumer must expose the `receive_asset`, `send_asset` and `add_asset_to_note` procedures of
+/// the basic wallet.
+///
+/// The `sender` can consume the note at any time, which cancels the swap order and returns the
+/// `offered` assets to the `sender`.
+///
+/// Besides the BASKET_SWAP note, the details of the P2ID note which is expected to be created by
+/// the consumer of the BASKET_SWAP note are returned; these can be used by the `sender` to consume
+/// the payback note. The assets of the payback note are in the same order as `requested`.
+///
+/// # Errors
+/// Returns an error if:
+/// - Deserialization or compilation of the `BASKET_SWAP` script fails.
+/// - `offered` or `requested` is empty, or contains the same asset (or fungible assets issued by
+///   the same faucet) more than once.
+/// - The number of requested assets is greater than [basket::MAX_REQUESTED_ASSETS].
+pub fn create_basket_swap_note<R: FeltRng>(
+    sender: AccountId,
+    offered: &[Asset],
+    requested: &[Asset],
+    mut rng: R,
+) -> Result<(Note, NoteDetails), NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BASKET_SWAP.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let payback_serial_num = rng.draw_word();
+    let payback_note =
+        utils::build_multi_asset_p2id_note_details(sender, requested, payback_serial_num)?;
+    let inputs = basket::build_basket_swap_inputs(
+        payback_note.recipient(),
+        NoteTag::for_account(sender),
+        requested,
+    )?;
+
+    let tag = NoteTag::default();
+    let serial_num = rng.draw_word();
+
+    let note = Note::new(note_script, &inputs, offered, serial_num, sender, tag)?;
+
+    Ok((note, payback_note))
+}
+
+/// Generates an ORACLE_SWAP note - swap of a fungible asset at a price published by an oracle.
+///
+/// This script enables a swap of the `offered` asset for an asset issued by the faucet of
+/// `min_requested` between the `sender` account and any other account that is willing to consume
+/// the note. Unlike SWAP notes, the requested amount is not fixed when the note is created: it is
+/// computed when the note is consumed from the amount of `offered` and the price published by the
+/// `oracle` account at `price_slot` (see [oracle](crate::accounts::oracle)). The consumer will
+/// receive the `offered` asset and will create a new P2ID note with `sender` as target, containing
+/// the requested amount of the requested asset.
+///
+/// The note can be consumed only if the oracle is a foreign account of the consuming transaction,
+/// the price was published at most `max_price_age` blocks before the block in which the
+/// transaction is included, and the requested amount is at least the amount of `min_requested`.
+/// To this end, the consuming transaction expires `max_price_age` blocks after the price was
+/// published. The `sender`
+/// can consume the note at any time, which cancels the swap order and returns the `offered` asset
+/// to the `sender`.
+///
+/// Besides the ORACLE_SWAP note, the serial number of the P2ID note which is expected to be
+/// created by the consumer of the ORACLE_SWAP note is returned. Since the requested amount is
+/// known only once the note is consumed, the `sender` needs to combine the serial number with the
+/// assets of the payback note to consume it.
+///
+/// # Errors
+/// Returns an error if:
+/// - Deserialization or compilation of the `ORACLE_SWAP` script fails.
+/// - The amount of `offered` is not smaller than 2^32.
+pub fn create_oracle_swap_note<R: FeltRng>(
+    sender: AccountId,
+    offered: FungibleAsset,
+    min_requested: FungibleAsset,
+    oracle: AccountId,
+    price_slot: u8,
+    max_price_age: u32,
+    mut rng: R,
+) -> Result<(Note, Word), NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/ORACLE_SWAP.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    if u32::try_from(offered.amount()).is_err() {
+        return Err(NoteError::OracleSwapAmountTooLarge(offered.amount()));
+    }
+
+    let payback_serial_num = rng.draw_word();
+    let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
+
+    let inputs = [
+        payback_recipient[0],
+        payback_recipient[1],
+        payback_recipient[2],
+        payback_recipient[3],
+        NoteTag::for_account(sender).into(),
+        min_requested.faucet_id().into(),
+        oracle.into(),
+        Felt::from(price_slot),
+        Felt::new(min_requested.amount()),
+        max_price_age.into(),
     ];
 
-    let tag: Felt = Felt::new(0);
+    let tag = NoteTag::default();
     let serial_num = rng.draw_word();
 
-    let note = Note::new(note_script.clone(), &inputs, &[offered_asset], serial_num, sender, tag)?;
+    let note = Note::new(note_script, &inputs, &[offered.into()], serial_num, sender, tag)?;
 
     Ok((note, payback_serial_num))
 }
+
+/// Generates a BURN note - return of an asset to its issuing faucet for burning.
+///
+/// This script enables the `sender` account to send the `asset` back to the faucet which issued
+/// it; the faucet burns the asset when consuming the note. Both basic fungible and basic
+/// non-fungible faucets can consume BURN notes, and consuming the note does not require the
+/// authentication of the faucet owner.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is derived from the faucet's account ID (see [NoteTag::for_account()]).
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `BURN` script fails.
+pub fn create_burn_note<R: FeltRng>(
+    sender: AccountId,
+    asset: Asset,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BURN.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let tag = NoteTag::for_account(asset.faucet_id());
+    let serial_num = rng.draw_word();
+
+    Note::new(note_script, &[], &[asset], serial_num, sender, tag)
+}
+
+/// Generates a SUBSCRIPTION note - recurring payment from a payer to a payee.
+///
+/// This script enables the `payer` account to pay `amount_per_interval` to the `payee` account
+/// once every `interval_blocks` blocks, for up to `num_intervals` intervals. The note contains the
+/// total amount of all intervals. Every time the payee consumes the note, it receives the amount of
+/// one interval and creates a clone of the note carrying the remaining amount, which the payee can
+/// consume once `interval_blocks` blocks have passed. The first interval can be claimed
+/// immediately. The payer can consume the note at any time, which cancels the subscription and
+/// returns the remaining amount to the payer.
+///
+/// The details of the clone note created by a claim can be computed via
+/// [subscription::build_subscription_clone_details()].
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag,
+/// as well as the tag of its clones, is derived from the payee's account ID (see
+/// [NoteTag::for_account()]).
+///
+/// # Errors
+/// Returns an error if:
+/// - Deserialization or compilation of the `SUBSCRIPTION` script fails.
+/// - The amount per interval or the number of intervals is zero.
+/// - The interval is zero.
+/// - The total amount of all intervals is not a valid amount of a fungible asset.
+pub fn create_subscription_note<R: FeltRng>(
+    payer: AccountId,
+    payee: AccountId,
+    amount_per_interval: FungibleAsset,
+    interval_blocks: u32,
+    num_intervals: u32,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SUBSCRIPTION.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    if num_intervals == 0 {
+        return Err(NoteError::EmptySubscription);
+    }
+    let total_amount = amount_per_interval.amount().saturating_mul(num_intervals as u64);
+    let asset = FungibleAsset::new(amount_per_interval.faucet_id(), total_amount)
+        .map_err(NoteError::InvalidAssetData)?;
+
+    let tag = NoteTag::for_account(payee);
+    let inputs = subscription::build_subscription_inputs(
+        payer,
+        payee,
+        amount_per_interval.amount(),
+        interval_blocks,
+        0,
+        tag,
+    )?;
+    let serial_num = rng.draw_word();
+
+    Note::new(note_script, &inputs, &[asset.into()], serial_num, payer, tag)
+}
@@ -0,0 +1,249 @@
+use core::ops::Range;
+
+use miden_objects::{
+    crypto::rand::FeltRng,
+    notes::{Note, NoteId, NoteTag, SerialNumSecret},
+    utils::{collections::Vec, vec},
+    Felt, NoteError, StarkField, Word,
+};
+#[cfg(feature = "std")]
+use rand::rngs::OsRng;
+use rand::{Error, RngCore};
+
+// OS RANDOMNESS
+// ================================================================================================
+
+/// A [FeltRng] which draws field elements from the entropy source of the operating system.
+///
+/// Unlike [RpoRandomCoin](miden_objects::crypto::rand::RpoRandomCoin), this generator does not
+/// need to be seeded, and thus is the simplest way to draw unpredictable serial numbers for new
+/// notes. Elements are drawn uniformly via rejection sampling.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsFeltRng;
+
+#[cfg(feature = "std")]
+impl FeltRng for OsFeltRng {
+    fn draw_element(&mut self) -> Felt {
+        loop {
+            let value = OsRng.next_u64();
+            if value < Felt::MODULUS {
+                return Felt::new(value);
+            }
+        }
+    }
+
+    fn draw_word(&mut self) -> Word {
+        [
+            self.draw_element(),
+            self.draw_element(),
+            self.draw_element(),
+            self.draw_element(),
+        ]
+    }
+}
+
+#[cfg(feature = "std")]
+impl RngCore for OsFeltRng {
+    fn next_u32(&mut self) -> u32 {
+        OsRng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        OsRng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        OsRng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        OsRng.try_fill_bytes(dest)
+    }
+}
+
+// EXPLICIT SERIAL NUMBERS
+// ================================================================================================
+
+/// A [FeltRng] which returns the provided words, in order, instead of random ones.
+///
+/// Passing a [SerialNumRng] to a note creation helper makes the helper use explicit serial
+/// numbers, which allows re-creating a note from its recorded serial number. The serial number of
+/// the created note is always the last word drawn by the helpers; helpers which draw further
+/// words (e.g., the serial number of the payback note of a SWAP note, or the ID of an ESCROW note)
+/// draw them first, and thus these words must precede the serial number of the note.
+///
+/// # Panics
+/// Drawing from a [SerialNumRng] panics once all its words have been drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerialNumRng {
+    elements: Vec<Felt>,
+    next: usize,
+}
+
+impl SerialNumRng {
+    /// Returns a new [SerialNumRng] which returns the provided words in the provided order.
+    pub fn new(words: Vec<Word>) -> Self {
+        Self {
+            elements: words.into_iter().flatten().collect(),
+            next: 0,
+        }
+    }
+
+    /// Returns the number of elements which have not been drawn yet.
+    pub fn remaining(&self) -> usize {
+        self.elements.len() - self.next
+    }
+}
+
+impl From<Word> for SerialNumRng {
+    fn from(serial_num: Word) -> Self {
+        Self::new(vec![serial_num])
+    }
+}
+
+impl FeltRng for SerialNumRng {
+    fn draw_element(&mut self) -> Felt {
+        let element = *self
+            .elements
+            .get(self.next)
+            .expect("all explicit serial numbers have been drawn");
+        self.next += 1;
+        element
+    }
+
+    fn draw_word(&mut self) -> Word {
+        [
+            self.draw_element(),
+            self.draw_element(),
+            self.draw_element(),
+            self.draw_element(),
+        ]
+    }
+}
+
+impl RngCore for SerialNumRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draw_element().as_int()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand::rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+// DERIVED SERIAL NUMBERS
+// ================================================================================================
+
+/// A [FeltRng] which returns the serial numbers derived from a [SerialNumSecret] for the specified
+/// tag, with increasing counters.
+///
+/// Passing a [DerivedSerialNumRng] to a note creation helper makes the serial numbers of the
+/// created note (and of its payback note, if any) recoverable from the secret (see
+/// [recover_derived_note()]). The tag is usually the tag of the created note, so that the notes to
+/// be recovered can be looked up on chain by their tags. Every drawn word or element uses up one
+/// counter; [DerivedSerialNumRng::counter()] returns the counter to start from for the next note.
+/// A mutable reference to the rng can be passed to consecutive helpers, so that the notes they
+/// create use consecutive counters.
+///
+/// # Panics
+/// Drawing from a [DerivedSerialNumRng] panics once the counter reaches [u32::MAX].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivedSerialNumRng {
+    secret: SerialNumSecret,
+    tag: NoteTag,
+    counter: u32,
+}
+
+impl DerivedSerialNumRng {
+    /// Returns a new [DerivedSerialNumRng] which derives serial numbers for the specified tag,
+    /// starting with the specified counter.
+    pub fn new(secret: SerialNumSecret, tag: NoteTag, counter: u32) -> Self {
+        Self { secret, tag, counter }
+    }
+
+    /// Returns the counter from which the next serial number will be derived.
+    pub fn counter(&self) -> u32 {
+        self.counter
+    }
+}
+
+impl FeltRng for DerivedSerialNumRng {
+    fn draw_element(&mut self) -> Felt {
+        self.draw_word()[0]
+    }
+
+    fn draw_word(&mut self) -> Word {
+        let serial_num = self.secret.derive(self.tag, self.counter);
+        self.counter = self.counter.checked_add(1).expect("serial number counter overflowed");
+        serial_num
+    }
+}
+
+impl FeltRng for &mut DerivedSerialNumRng {
+    fn draw_element(&mut self) -> Felt {
+        (**self).draw_element()
+    }
+
+    fn draw_word(&mut self) -> Word {
+        (**self).draw_word()
+    }
+}
+
+impl RngCore for DerivedSerialNumRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draw_element().as_int()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand::rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Recovers the note with the specified ID whose serial numbers were derived from the provided
+/// secret for the specified tag.
+///
+/// For every counter in `counters`, the note is rebuilt by `build_note` from a
+/// [DerivedSerialNumRng] starting at the counter, e.g., by passing the rng to the note creation
+/// helper which created the note in the first place. The first note whose ID matches `note_id` is
+/// returned together with its counter; None is returned if no counter in the range yields the note.
+///
+/// # Errors
+/// Returns an error if `build_note` fails for any of the counters.
+pub fn recover_derived_note<F>(
+    secret: SerialNumSecret,
+    tag: NoteTag,
+    note_id: NoteId,
+    counters: Range<u32>,
+    mut build_note: F,
+) -> Result<Option<(u32, Note)>, NoteError>
+where
+    F: FnMut(DerivedSerialNumRng) -> Result<Note, NoteError>,
+{
+    for counter in counters {
+        let note = build_note(DerivedSerialNumRng::new(secret, tag, counter))?;
+        if note.id() == note_id {
+            return Ok(Some((counter, note)));
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,194 @@
+use miden_objects::{notes::NoteScript, Digest, NoteError};
+
+use super::utils::build_note_script;
+
+// WELL-KNOWN SCRIPTS
+// ================================================================================================
+
+/// Standard note scripts provided by this library.
+///
+/// A well-known script can be identified by its hash (see [WellKnownScript::try_from()]), which
+/// makes it possible to classify notes without assembling the scripts of the notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WellKnownScript {
+    P2ID,
+    P2IDAny,
+    P2IDC,
+    P2IDR,
+    Payment,
+    RaffleTicket,
+    Escrow,
+    Swap,
+    Htlc,
+    BatchP2ID,
+    Burn,
+    Subscription,
+    BasketSwap,
+    OracleSwap,
+}
+
+impl WellKnownScript {
+    /// All well-known scripts.
+    pub const ALL: [Self; 14] = [
+        Self::P2ID,
+        Self::P2IDAny,
+        Self::P2IDC,
+        Self::P2IDR,
+        Self::Payment,
+        Self::RaffleTicket,
+        Self::Escrow,
+        Self::Swap,
+        Self::Htlc,
+        Self::BatchP2ID,
+        Self::Burn,
+        Self::Subscription,
+        Self::BasketSwap,
+        Self::OracleSwap,
+    ];
+
+    /// Returns the compiled note script.
+    pub fn script(&self) -> NoteScript {
+        build_note_script(self.script_bytes()).expect("well-known note script is well formed")
+    }
+
+    /// Returns the hash of the note script.
+    pub fn hash(&self) -> Digest {
+        self.script().hash()
+    }
+
+    /// Returns the serialized AST of the note script.
+    fn script_bytes(&self) -> &'static [u8] {
+        match self {
+            Self::P2ID => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"))
+            },
+            Self::P2IDAny => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID_ANY.masb"))
+            },
+            Self::P2IDC => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDC.masb"))
+            },
+            Self::P2IDR => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"))
+            },
+            Self::Payment => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/PAYMENT.masb"))
+            },
+            Self::RaffleTicket => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/RAFFLE_TICKET.masb"))
+            },
+            Self::Escrow => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/ESCROW.masb"))
+            },
+            Self::Swap => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"))
+            },
+            Self::Htlc => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/HTLC.masb"))
+            },
+            Self::BatchP2ID => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BATCH_P2ID.masb"))
+            },
+            Self::Burn => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BURN.masb"))
+            },
+            Self::Subscription => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SUBSCRIPTION.masb"))
+            },
+            Self::BasketSwap => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BASKET_SWAP.masb"))
+            },
+            Self::OracleSwap => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/ORACLE_SWAP.masb"))
+            },
+        }
+    }
+}
+
+impl TryFrom<Digest> for WellKnownScript {
+    type Error = NoteError;
+
+    /// Returns the well-known script with the specified hash.
+    ///
+    /// # Errors
+    /// Returns an error if the hash is not the hash of a well-known script.
+    fn try_from(script_hash: Digest) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|script| script.hash() == script_hash)
+            .ok_or(NoteError::UnknownNoteScript(script_hash))
+    }
+}
+
+// SCRIPT ACCESSORS
+// ================================================================================================
+
+/// Returns the compiled `P2ID` note script.
+pub fn p2id() -> NoteScript {
+    WellKnownScript::P2ID.script()
+}
+
+/// Returns the compiled `P2ID_ANY` note script.
+pub fn p2id_any() -> NoteScript {
+    WellKnownScript::P2IDAny.script()
+}
+
+/// Returns the compiled `P2IDC` note script.
+pub fn p2idc() -> NoteScript {
+    WellKnownScript::P2IDC.script()
+}
+
+/// Returns the compiled `P2IDR` note script.
+pub fn p2idr() -> NoteScript {
+    WellKnownScript::P2IDR.script()
+}
+
+/// Returns the compiled `PAYMENT` note script.
+pub fn payment() -> NoteScript {
+    WellKnownScript::Payment.script()
+}
+
+/// Returns the compiled `RAFFLE_TICKET` note script.
+pub fn raffle_ticket() -> NoteScript {
+    WellKnownScript::RaffleTicket.script()
+}
+
+/// Returns the compiled `ESCROW` note script.
+pub fn escrow() -> NoteScript {
+    WellKnownScript::Escrow.script()
+}
+
+/// Returns the compiled `SWAP` note script.
+pub fn swap() -> NoteScript {
+    WellKnownScript::Swap.script()
+}
+
+/// Returns the compiled `HTLC` note script.
+pub fn htlc() -> NoteScript {
+    WellKnownScript::Htlc.script()
+}
+
+/// Returns the compiled `BATCH_P2ID` note script.
+pub fn batch_p2id() -> NoteScript {
+    WellKnownScript::BatchP2ID.script()
+}
+
+/// Returns the compiled `BURN` note script.
+pub fn burn() -> NoteScript {
+    WellKnownScript::Burn.script()
+}
+
+/// Returns the compiled `SUBSCRIPTION` note script.
+pub fn subscription() -> NoteScript {
+    WellKnownScript::Subscription.script()
+}
+
+/// Returns the compiled `BASKET_SWAP` note script.
+pub fn basket_swap() -> NoteScript {
+    WellKnownScript::BasketSwap.script()
+}
+
+/// Returns the compiled `ORACLE_SWAP` note script.
+pub fn oracle_swap() -> NoteScript {
+    WellKnownScript::OracleSwap.script()
+}
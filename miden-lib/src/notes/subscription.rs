@@ -0,0 +1,118 @@
+use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
+    notes::{NoteAssets, NoteDetails, NoteInputs, NoteTag},
+    utils::{collections::Vec, vec},
+    Felt, NoteError, StarkField, Word, ONE,
+};
+
+use super::scripts::WellKnownScript;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of note inputs of a SUBSCRIPTION note.
+const NUM_SUBSCRIPTION_INPUTS: usize = 6;
+
+/// Index of the note input at which the amount per interval is stored.
+const AMOUNT_IDX: usize = 2;
+
+/// Index of the note input at which the interval is stored.
+const INTERVAL_IDX: usize = 3;
+
+/// Index of the note input at which the next claim block height is stored.
+const NEXT_CLAIM_IDX: usize = 4;
+
+// SUBSCRIPTION INPUTS
+// ================================================================================================
+
+/// Returns the note inputs of a SUBSCRIPTION note for the specified parameters.
+///
+/// # Errors
+/// Returns an error if the amount per interval or the interval is zero.
+pub(super) fn build_subscription_inputs(
+    payer: AccountId,
+    payee: AccountId,
+    amount_per_interval: u64,
+    interval_blocks: u32,
+    next_claim_block: u32,
+    tag: NoteTag,
+) -> Result<Vec<Felt>, NoteError> {
+    if amount_per_interval == 0 {
+        return Err(NoteError::EmptySubscription);
+    }
+    if interval_blocks == 0 {
+        return Err(NoteError::InvalidSubscriptionInterval(interval_blocks));
+    }
+
+    Ok(vec![
+        payee.into(),
+        payer.into(),
+        Felt::new(amount_per_interval),
+        interval_blocks.into(),
+        next_claim_block.into(),
+        tag.into(),
+    ])
+}
+
+// SUBSCRIPTION CLAIMS
+// ================================================================================================
+
+/// Returns the details of the clone note created when the payee claims the specified SUBSCRIPTION
+/// note in the block with the specified number, or `None` if the claim exhausts the note asset and
+/// thus no clone note is created.
+///
+/// The details are needed by the payee to claim the next interval of the subscription.
+///
+/// # Errors
+/// Returns an error if:
+/// - The note is not a well-formed SUBSCRIPTION note.
+/// - The next claim of the note is not available at the specified block height.
+pub fn build_subscription_clone_details(
+    note: &NoteDetails,
+    claim_block_num: u32,
+) -> Result<Option<NoteDetails>, NoteError> {
+    let invalid_note = || NoteError::InvalidSubscriptionNote(note.id());
+
+    if note.script().hash() != WellKnownScript::Subscription.hash() {
+        return Err(invalid_note());
+    }
+    let inputs = note.inputs().values();
+    if inputs.len() != NUM_SUBSCRIPTION_INPUTS {
+        return Err(invalid_note());
+    }
+    let mut asset = match note.assets().iter().collect::<Vec<_>>().as_slice() {
+        [Asset::Fungible(asset)] => *asset,
+        _ => return Err(invalid_note()),
+    };
+
+    let next_claim_block =
+        u32::try_from(inputs[NEXT_CLAIM_IDX].as_int()).map_err(|_| invalid_note())?;
+    if claim_block_num < next_claim_block {
+        return Err(NoteError::SubscriptionClaimTooEarly { next_claim_block, claim_block_num });
+    }
+    let interval_blocks =
+        u32::try_from(inputs[INTERVAL_IDX].as_int()).map_err(|_| invalid_note())?;
+
+    let amount_per_interval = inputs[AMOUNT_IDX].as_int();
+    if asset.amount() <= amount_per_interval {
+        return Ok(None);
+    }
+    asset.sub(amount_per_interval).map_err(NoteError::InvalidAssetData)?;
+
+    let mut clone_inputs = inputs.to_vec();
+    clone_inputs[NEXT_CLAIM_IDX] = claim_block_num
+        .checked_add(interval_blocks)
+        .map(Felt::from)
+        .ok_or_else(invalid_note)?;
+
+    let mut serial_num: Word = note.serial_num();
+    serial_num[3] += ONE;
+
+    Ok(Some(NoteDetails::new(
+        note.script().clone(),
+        NoteInputs::new(clone_inputs)?,
+        NoteAssets::new(&[asset.into()])?,
+        serial_num,
+    )))
+}
@@ -1,6 +1,10 @@
 use miden_objects::{
-    accounts::AccountId, assembly::ProgramAst, notes::NoteScript, Digest, Hasher, NoteError, Word,
-    ZERO,
+    accounts::AccountId,
+    assembly::ProgramAst,
+    assets::Asset,
+    notes::{NoteAssets, NoteDetails, NoteInputs, NoteScript},
+    utils::vec,
+    Digest, Hasher, NoteError, Word,
 };
 
 use crate::transaction::TransactionKernel;
@@ -28,8 +32,63 @@ pub fn build_p2id_recipient(target: AccountId, serial_num: Word) -> Result<Diges
 
     let merge_script = Hasher::merge(&[serial_num_hash, script_hash]);
 
-    Ok(Hasher::merge(&[
-        merge_script,
-        Hasher::hash_elements(&[target.into(), ZERO, ZERO, ZERO]),
-    ]))
+    // the inputs commitment must be computed in the same way as for the note itself, so that the
+    // recipient matches the one of a P2ID note created via `create_p2id_note()`
+    let inputs = NoteInputs::new(vec![target.into()])?;
+
+    Ok(Hasher::merge(&[merge_script, inputs.commitment()]))
+}
+
+/// Returns the details of a P2ID note which transfers the specified asset to the `target` account.
+///
+/// The details match the ones of a note created via `create_p2id_note()` with the same serial
+/// number; in particular, the recipient of the note is equal to the one returned by
+/// [build_p2id_recipient()].
+pub fn build_p2id_note_details(
+    target: AccountId,
+    asset: Asset,
+    serial_num: Word,
+) -> Result<NoteDetails, NoteError> {
+    build_multi_asset_p2id_note_details(target, &[asset], serial_num)
+}
+
+/// Returns the details of a P2ID note which transfers the specified assets to the `target`
+/// account.
+///
+/// The assets are committed to in the specified order, which must match the order in which they
+/// are added to the note by the transaction creating it.
+pub fn build_multi_asset_p2id_note_details(
+    target: AccountId,
+    assets: &[Asset],
+    serial_num: Word,
+) -> Result<NoteDetails, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let inputs = NoteInputs::new(vec![target.into()])?;
+    let assets = NoteAssets::new(assets)?;
+
+    Ok(NoteDetails::new(note_script, inputs, assets, serial_num))
+}
+
+/// Returns the hash lock of an HTLC note for the specified preimage.
+///
+/// The hash lock is computed in the same way as the `hash` instruction of the Miden VM computes
+/// the hash of a single word, so that the preimage can be verified by the `HTLC` note script.
+pub fn compute_htlc_hashlock(preimage: Word) -> Digest {
+    Hasher::hash_elements(&preimage)
+}
+
+/// Returns the details of a BURN note which returns the specified asset to its issuing faucet.
+///
+/// The details match the ones of a note created via `create_burn_note()` with the same serial
+/// number.
+pub fn build_burn_note_details(asset: Asset, serial_num: Word) -> Result<NoteDetails, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BURN.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let inputs = NoteInputs::new(vec![])?;
+    let assets = NoteAssets::new(&[asset])?;
+
+    Ok(NoteDetails::new(note_script, inputs, assets, serial_num))
 }
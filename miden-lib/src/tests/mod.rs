@@ -1,9 +1,10 @@
 use std::path::PathBuf;
 
 use miden_objects::{vm::StackInputs, Felt, Hasher, Word, ONE, ZERO};
-use vm_processor::{ContextId, MemAdviceProvider, Process, ProcessState};
+use mock::run_within_tx_kernel;
+use vm_processor::{ContextId, ExecutionError, MemAdviceProvider, Process, ProcessState};
 
-use super::Library;
+use super::{errors::TxKernelError, transaction::memory, Library};
 
 mod test_account;
 mod test_asset;
@@ -37,9 +38,68 @@ fn test_compile() {
     assert!(exists);
 }
 
+#[test]
+fn test_kernel_errors_are_sorted_and_unique() {
+    let errors = super::errors::TX_KERNEL_ERRORS;
+    assert!(!errors.is_empty());
+    assert!(errors.windows(2).all(|pair| pair[0].code < pair[1].code));
+
+    for error in errors {
+        assert!(error.name.starts_with("ERR_"));
+        assert!(!error.description.is_empty());
+        assert_eq!(TxKernelError::from_code(error.code), Some(error));
+    }
+    assert_eq!(TxKernelError::from_code(0), None);
+}
+
+#[test]
+fn test_memory_layout_matches_kernel() {
+    let code = "
+        use.miden::kernels::tx::memory
+
+        begin
+            push.3 exec.memory::get_consumed_note_nullifier_ptr
+            push.3 exec.memory::get_consumed_note_header_hash_ptr
+            push.3 exec.memory::get_consumed_note_ptr
+            push.3 exec.memory::get_created_note_ptr
+            push.3 exec.memory::get_foreign_acct_data_ptr
+        end
+        ";
+
+    let process =
+        run_within_tx_kernel("", code, StackInputs::default(), MemAdviceProvider::default(), None)
+            .unwrap();
+
+    let expected = [
+        memory::foreign_acct_data_ptr(3),
+        memory::created_note_data_ptr(3),
+        memory::consumed_note_data_ptr(3),
+        memory::consumed_note_header_hash_ptr(3),
+        memory::consumed_note_nullifier_ptr(3),
+    ];
+    for (i, ptr) in expected.into_iter().enumerate() {
+        assert_eq!(process.stack.get(i), Felt::from(ptr));
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
 fn build_module_path(dir: &str, file: &str) -> PathBuf {
     [env!("CARGO_MANIFEST_DIR"), "asm", dir, file].iter().collect()
 }
+
+/// Asserts that the execution failed because of the kernel assertion with the specified code.
+fn assert_kernel_error<T>(result: Result<T, ExecutionError>, expected: u32) {
+    match result {
+        Ok(_) => panic!("execution was expected to fail"),
+        Err(ExecutionError::FailedAssertion { err_code, .. }) => assert_eq!(
+            err_code,
+            expected,
+            "expected kernel error {:?}, but got {:?}",
+            TxKernelError::from_code(expected),
+            TxKernelError::from_code(err_code)
+        ),
+        Err(err) => panic!("execution failed with an unexpected error: {err}"),
+    }
+}
@@ -1,7 +1,7 @@
 use miden_objects::{
     accounts::{
-        AccountId, AccountType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_INSUFFICIENT_ONES,
-        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+        AccountId, AccountStorage, AccountType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_INSUFFICIENT_ONES, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
     },
@@ -9,8 +9,9 @@ use miden_objects::{
 };
 use mock::{
     constants::{
-        storage_item_0, storage_item_1, CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH,
-        CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0,
+        storage_item_0, storage_item_1, storage_map_2, STORAGE_ARRAY_INDEX_0,
+        STORAGE_ARRAY_VALUE_0, STORAGE_INDEX_2, STORAGE_INDEX_3, STORAGE_MAP_KEY_0,
+        STORAGE_MAP_VALUE_0,
     },
     mock::{
         account::MockAccountType,
@@ -205,7 +206,7 @@ fn test_is_faucet_procedure() {
             eq.{expected} assert
         end
     ",
-            account_id = account_id,
+            account_id = account_id.to_hex(),
             expected = if account_id.is_faucet() { 1 } else { 0 },
         );
 
@@ -311,8 +312,66 @@ fn test_set_item() {
     let _process = run_tx(&transaction).unwrap();
 }
 
-// TODO: reenable once storage map support is implemented
-#[ignore]
+#[test]
+fn test_set_and_get_item_words() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // the value spans three words, and thus is padded with an empty word
+    let words: Vec<Word> = (0..3)
+        .map(|i| [Felt::new(i), Felt::new(i + 1), Felt::new(i + 2), ONE])
+        .collect();
+    let commitment = AccountStorage::compute_words_commitment(&words);
+
+    let code = format!(
+        "
+    use.miden::account
+    use.miden::kernels::tx::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # write the value into memory
+        push.{word_0} push.1000 mem_storew dropw
+        push.{word_1} push.1001 mem_storew dropw
+        push.{word_2} push.1002 mem_storew dropw
+
+        # set the value
+        push.1000 push.3 push.{item_index}
+        exec.account::set_item_words
+
+        # assert empty old commitment
+        dropw padw assert_eqw
+
+        # assert the slot holds the commitment to the value
+        push.{item_index} exec.account::get_item
+        push.{commitment} assert_eqw
+
+        # get the value
+        push.2000 push.3 push.{item_index}
+        exec.account::get_item_words
+
+        # assert the commitment and the value are correct
+        push.{commitment} assert_eqw
+        push.2000 assert_eq
+        padw mem_loadw.2000 push.{word_0} assert_eqw
+        padw mem_loadw.2001 push.{word_1} assert_eqw
+        padw mem_loadw.2002 push.{word_2} assert_eqw
+        padw mem_loadw.2003 padw assert_eqw
+    end
+    ",
+        word_0 = prepare_word(&words[0]),
+        word_1 = prepare_word(&words[1]),
+        word_2 = prepare_word(&words[2]),
+        item_index = 12,
+        commitment = prepare_word(&commitment),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, None, &code, None);
+    let _process = run_tx(&transaction).unwrap();
+}
+
 #[test]
 fn test_get_map_item() {
     let tx_inputs =
@@ -327,29 +386,130 @@ fn test_get_map_item() {
             # prepare the transaction
             exec.prologue::prepare_transaction
 
-            # push the account storage index the child root is stored at
-            push.{CHILD_ROOT_PARENT_LEAF_INDEX}
+            # get the value stored under the key in the map
+            push.{map_key} push.{STORAGE_INDEX_2}
+            exec.account::get_map_item
 
-            # get the child root
-            exec.account::get_item
+            # assert the map value is correct
+            push.{map_value} assert_eqw
 
-            # get a value from the child tree
-            push.{CHILD_STORAGE_INDEX_0}
+            # keys which have not been set map to an empty word
+            push.{unset_key} push.{STORAGE_INDEX_2}
+            exec.account::get_map_item
+            padw assert_eqw
+        end
+        ",
+        map_key = prepare_word(&STORAGE_MAP_KEY_0),
+        map_value = prepare_word(&STORAGE_MAP_VALUE_0),
+        unset_key = prepare_word(&STORAGE_MAP_VALUE_0),
+    );
 
-            # get the item
-            push.{CHILD_SMT_DEPTH} mtree_get
+    let transaction = prepare_transaction(tx_inputs, None, code.as_str(), None);
+    let _process = run_tx(&transaction).unwrap();
+}
+
+#[test]
+fn test_set_map_item() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let new_key: Word = [Felt::new(201), Felt::new(202), Felt::new(203), Felt::new(204)];
+    let new_value: Word = [Felt::new(21), Felt::new(22), Felt::new(23), Felt::new(24)];
+    let mut map = storage_map_2();
+    let old_root = map.root();
+    map.insert(new_key.into(), new_value);
+
+    let code = format!(
+        "
+        use.miden::account
+        use.miden::kernels::tx::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # set a new entry in the map
+            push.{new_value} push.{new_key} push.{STORAGE_INDEX_2}
+            exec.account::set_map_item
 
-            # assert the child value is correct
-            push.{child_value} assert_eqw
+            # assert the old root of the map and the old value of the entry are correct
+            push.{old_root} assert_eqw
+            padw assert_eqw
+
+            # assert the new entry can be read from the map
+            push.{new_key} push.{STORAGE_INDEX_2}
+            exec.account::get_map_item
+            push.{new_value} assert_eqw
+
+            # assert the slot holds the new root of the map
+            push.{STORAGE_INDEX_2} exec.account::get_item
+            push.{new_root} assert_eqw
         end
         ",
-        child_value = prepare_word(&CHILD_STORAGE_VALUE_0)
+        new_key = prepare_word(&new_key),
+        new_value = prepare_word(&new_value),
+        old_root = prepare_word(&old_root),
+        new_root = prepare_word(&map.root()),
     );
 
     let transaction = prepare_transaction(tx_inputs, None, code.as_str(), None);
     let _process = run_tx(&transaction).unwrap();
 }
 
+#[test]
+fn test_get_array_item() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = format!(
+        "
+        use.miden::account
+        use.miden::kernels::tx::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # get the item stored at the index in the array
+            push.{STORAGE_ARRAY_INDEX_0} push.{STORAGE_INDEX_3}
+            exec.account::get_array_item
+
+            # assert the array item is correct
+            push.{array_value} assert_eqw
+
+            # items which have not been set are empty words
+            push.{unset_index} push.{STORAGE_INDEX_3}
+            exec.account::get_array_item
+            padw assert_eqw
+        end
+        ",
+        array_value = prepare_word(&STORAGE_ARRAY_VALUE_0),
+        unset_index = STORAGE_ARRAY_INDEX_0 + 1,
+    );
+
+    let transaction = prepare_transaction(tx_inputs, None, code.as_str(), None);
+    let _process = run_tx(&transaction).unwrap();
+
+    // array items can only be read from array slots
+    let code = format!(
+        "
+        use.miden::account
+        use.miden::kernels::tx::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+            push.0 push.{STORAGE_INDEX_2}
+            exec.account::get_array_item
+        end
+        "
+    );
+
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let transaction = prepare_transaction(tx_inputs, None, code.as_str(), None);
+    assert!(run_tx(&transaction).is_err());
+}
+
 // ACCOUNT VAULT TESTS
 // ================================================================================================
 
@@ -1,16 +1,25 @@
+use miden_objects::{
+    accounts::AccountId,
+    notes::{NoteEnvelope, NoteMetadata, NoteTag},
+    transaction::OutputNotes,
+    utils::collections::Vec,
+    OUTPUT_NOTES_COMMITMENT_CHUNK_SIZE,
+};
 use mock::{
+    constants::ACCOUNT_ID_SENDER,
     mock::{notes::AssetPreservationStatus, transaction::mock_executed_tx},
-    procedures::output_notes_data_procedure,
+    procedures::{output_notes_data_procedure, prepare_word},
     run_within_tx_kernel,
 };
 
 use super::{
-    build_module_path, ContextId, MemAdviceProvider, ProcessState, Word, TX_KERNEL_DIR, ZERO,
+    build_module_path, ContextId, Felt, MemAdviceProvider, ProcessState, StackInputs, Word,
+    TX_KERNEL_DIR, ZERO,
 };
 use crate::transaction::{
-    memory::{CREATED_NOTE_ASSET_HASH_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE},
-    ToTransactionKernelInputs, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
-    TX_SCRIPT_ROOT_WORD_IDX,
+    memory::{created_note_data_ptr, CREATED_NOTE_ASSETS_HASH_OFFSET},
+    ToTransactionKernelInputs, EXPIRATION_BLOCK_NUM_ELEMENT_IDX, FINAL_ACCOUNT_HASH_WORD_IDX,
+    OUTPUT_NOTES_COMMITMENT_WORD_IDX, TX_SCRIPT_ROOT_WORD_IDX,
 };
 
 const EPILOGUE_FILE: &str = "epilogue.masm";
@@ -68,11 +77,18 @@ fn test_epilogue() {
         executed_transaction.final_account().hash().as_elements(),
     );
 
+    // assert the transaction does not expire
+    assert_eq!(
+        process.stack.get(EXPIRATION_BLOCK_NUM_ELEMENT_IDX),
+        Felt::from(executed_transaction.expiration_block_num())
+    );
+    assert_eq!(executed_transaction.expiration_block_num(), u32::MAX);
+
     // assert stack has been truncated correctly
     assert_eq!(process.stack.depth(), 16);
 
     // assert the bottom of the stack is filled with zeros
-    for i in 12..16 {
+    for i in 13..16 {
         assert_eq!(process.stack.get(i), ZERO);
     }
 }
@@ -110,15 +126,14 @@ fn test_compute_created_note_id() {
 
         // assert the note asset hash is correct
         let expected_asset_hash = note.assets().commitment();
-        let asset_hash_memory_address =
-            CREATED_NOTE_SECTION_OFFSET + i * NOTE_MEM_SIZE + CREATED_NOTE_ASSET_HASH_OFFSET;
+        let asset_hash_memory_address = created_note_data_ptr(i) + CREATED_NOTE_ASSETS_HASH_OFFSET;
         let actual_asset_hash =
             process.get_mem_value(ContextId::root(), asset_hash_memory_address).unwrap();
         assert_eq!(expected_asset_hash.as_elements(), actual_asset_hash);
 
         // assert the note ID is correct
         let expected_id = note.id();
-        let note_id_memory_address = CREATED_NOTE_SECTION_OFFSET + i * NOTE_MEM_SIZE;
+        let note_id_memory_address = created_note_data_ptr(i);
         let actual_note_id =
             process.get_mem_value(ContextId::root(), note_id_memory_address).unwrap();
         assert_eq!(&actual_note_id, expected_id.as_elements());
@@ -229,3 +244,57 @@ fn test_epilogue_increment_nonce_violation() {
 
     assert!(process.is_err());
 }
+
+#[test]
+fn test_compute_output_notes_commitment_multiple_chunks() {
+    // create more notes than fit into a single commitment chunk
+    let num_notes = OUTPUT_NOTES_COMMITMENT_CHUNK_SIZE as u32 + 1;
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let metadata = NoteMetadata::new(sender, NoteTag::from(7)).unwrap();
+    let metadata_word = prepare_word(&metadata.into());
+
+    // the recipient of note i is set to [i, 0, 0, 0]
+    let imports = "use.miden::kernels::tx::memory\nuse.miden::kernels::tx::note\n";
+    let code = format!(
+        "
+        begin
+            push.{num_notes} exec.memory::set_num_created_notes
+
+            push.0 push.1
+            while.true
+                push.{metadata_word} dup.4 exec.memory::get_created_note_ptr
+                exec.memory::set_created_note_metadata
+
+                push.0.0.0 dup.3 dup.4 exec.memory::get_created_note_ptr
+                exec.memory::set_created_note_recipient
+
+                add.1 dup push.{num_notes} neq
+            end
+            drop
+
+            exec.note::compute_output_notes_commitment
+        end
+        "
+    );
+
+    let process = run_within_tx_kernel(
+        imports,
+        &code,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        None,
+    )
+    .unwrap();
+
+    // the IDs of the notes are computed by the kernel while computing the commitment
+    let notes = (0..num_notes)
+        .map(|i| {
+            let note_id_ptr = created_note_data_ptr(i);
+            let note_id = process.get_mem_value(ContextId::root(), note_id_ptr).unwrap();
+            NoteEnvelope::new(note_id.into(), metadata)
+        })
+        .collect::<Vec<_>>();
+    let output_notes = OutputNotes::new(notes).unwrap();
+
+    assert_eq!(process.stack.get_word(0), output_notes.commitment().as_elements());
+}
@@ -12,8 +12,11 @@ use mock::{
     run_tx,
 };
 
-use super::ONE;
-use crate::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
+use super::{assert_kernel_error, ONE};
+use crate::{
+    errors::{ERR_FAUCET_ISSUANCE_EXCEEDS_MAX, ERR_FUNGIBLE_ASSET_FAUCET_MISMATCH},
+    transaction::memory::FAUCET_STORAGE_DATA_SLOT,
+};
 
 // FUNGIBLE FAUCET MINT TESTS
 // ================================================================================================
@@ -119,7 +122,7 @@ fn test_mint_fungible_asset_inconsistent_faucet_id() {
     let transaction = prepare_transaction(tx_inputs, None, &code, None);
     let process = run_tx(&transaction);
 
-    assert!(process.is_err());
+    assert_kernel_error(process, ERR_FUNGIBLE_ASSET_FAUCET_MISMATCH);
 }
 
 #[test]
@@ -151,7 +154,7 @@ fn test_mint_fungible_asset_fails_saturate_max_amount() {
     let transaction = prepare_transaction(tx_inputs, None, &code, None);
     let process = run_tx(&transaction);
 
-    assert!(process.is_err());
+    assert_kernel_error(process, ERR_FAUCET_ISSUANCE_EXCEEDS_MAX);
 }
 
 // NON-FUNGIBLE FAUCET MINT TESTS
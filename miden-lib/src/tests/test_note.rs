@@ -1,11 +1,17 @@
 use miden_objects::{
-    notes::Note,
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{EncryptedNoteDetails, Note, NoteDetails, NoteId, NoteTag, NoteType, SerialNumSecret},
     transaction::{PreparedTransaction, TransactionArgs},
     utils::collections::BTreeMap,
-    WORD_SIZE,
+    Digest, NoteError, WORD_SIZE,
 };
 use mock::{
-    consumed_note_data_ptr,
+    constants::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_SENDER,
+    },
     mock::{
         account::MockAccountType, host::MockHost, notes::AssetPreservationStatus,
         transaction::mock_inputs,
@@ -14,9 +20,21 @@ use mock::{
     procedures::prepare_word,
     run_tx,
 };
+use rand::rngs::OsRng;
 
 use super::{ContextId, Felt, Process, ProcessState, ZERO};
-use crate::transaction::memory::CURRENT_CONSUMED_NOTE_PTR;
+use crate::{
+    notes::{
+        create_p2id_note, create_swap_note,
+        encryption::{
+            decrypt_note_details, encrypt_note_details, generate_note_decryption_key,
+            NoteEncryptionKey,
+        },
+        rng::{recover_derived_note, DerivedSerialNumRng, OsFeltRng, SerialNumRng},
+        scripts::{self, WellKnownScript},
+    },
+    transaction::memory::{consumed_note_data_ptr, CURRENT_CONSUMED_NOTE_PTR},
+};
 
 #[test]
 fn test_get_sender_no_sender() {
@@ -72,6 +90,49 @@ fn test_get_sender() {
     assert_eq!(process.stack.get(0), sender);
 }
 
+#[test]
+fn test_get_serial_number() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let notes = tx_inputs.input_notes();
+
+    // calling get_serial_number should return the serial number of the note being processed
+    let code = format!(
+        "
+        use.miden::kernels::tx::prologue
+        use.miden::kernels::tx::note->note_internal
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # prepare note 0
+            exec.note_internal::prepare_note
+            dropw dropw
+
+            # assert the serial number is correct
+            exec.note::get_serial_number
+            push.{note_0_serial_num} assert_eqw
+
+            # prepare note 1
+            exec.note_internal::increment_current_consumed_note_ptr drop
+            exec.note_internal::prepare_note
+            dropw dropw
+
+            # assert the serial number is correct
+            exec.note::get_serial_number
+            push.{note_1_serial_num} assert_eqw
+        end
+        ",
+        note_0_serial_num = prepare_word(&notes.get_note(0).note().serial_num()),
+        note_1_serial_num = prepare_word(&notes.get_note(1).note().serial_num()),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, None, &code, None);
+    let _process = run_tx(&transaction).unwrap();
+}
+
 #[test]
 fn test_get_vault_data() {
     let tx_inputs =
@@ -382,3 +443,140 @@ fn note_setup_memory_assertions(process: &Process<MockHost>) {
         Felt::from(consumed_note_data_ptr(0))
     );
 }
+
+#[test]
+fn test_well_known_script_lookup() {
+    for script in WellKnownScript::ALL {
+        assert_eq!(WellKnownScript::try_from(script.hash()), Ok(script));
+    }
+
+    assert_eq!(WellKnownScript::try_from(scripts::p2id().hash()), Ok(WellKnownScript::P2ID));
+    assert_eq!(WellKnownScript::try_from(scripts::swap().hash()), Ok(WellKnownScript::Swap));
+    assert_eq!(
+        WellKnownScript::try_from(Digest::default()),
+        Err(NoteError::UnknownNoteScript(Digest::default()))
+    );
+}
+
+#[test]
+fn test_encrypted_note_details() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+    // the asset list of a note cannot be empty, so the note carries a single fungible asset
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let note =
+        create_p2id_note(sender, target, vec![asset], NoteType::Encrypted, ZERO, rng(1)).unwrap();
+    let details = NoteDetails::from(&note);
+
+    let secret_key = generate_note_decryption_key(OsRng);
+    let public_key = NoteEncryptionKey::from(&secret_key);
+    let encrypted = encrypt_note_details(&details, &public_key, OsRng).unwrap();
+    assert_eq!(encrypted.note_id(), note.id());
+    assert_eq!(decrypt_note_details(&encrypted, &secret_key), Ok(details));
+
+    // the details cannot be decrypted with a different key
+    let other_key = generate_note_decryption_key(OsRng);
+    assert_eq!(
+        decrypt_note_details(&encrypted, &other_key),
+        Err(NoteError::NoteDecryptionFailed(note.id()))
+    );
+
+    // the note ID is authenticated together with the ciphertext
+    let tampered = EncryptedNoteDetails::new(
+        NoteId::from(Digest::default()),
+        *encrypted.ephemeral_key(),
+        encrypted.ciphertext().to_vec(),
+    );
+    assert!(decrypt_note_details(&tampered, &secret_key).is_err());
+}
+
+#[test]
+fn test_note_rngs() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // a note can be re-created from its serial number
+    let note =
+        create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, rng(1)).unwrap();
+    let serial_num_rng = SerialNumRng::from(note.serial_num());
+    let recreated =
+        create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, serial_num_rng)
+            .unwrap();
+    assert_eq!(recreated.id(), note.id());
+
+    // the serial number of the note is drawn after the serial number of the payback note
+    let requested: Asset = FungibleAsset::new(faucet_id, 50).unwrap().into();
+    let (note, payback) =
+        create_swap_note(sender, asset, requested, None, NoteType::Public, ZERO, rng(2)).unwrap();
+    let serial_num_rng = SerialNumRng::new(vec![payback.serial_num(), note.serial_num()]);
+    let (recreated, recreated_payback) =
+        create_swap_note(sender, asset, requested, None, NoteType::Public, ZERO, serial_num_rng)
+            .unwrap();
+    assert_eq!(recreated.id(), note.id());
+    assert_eq!(recreated_payback.id(), payback.id());
+
+    // notes created with OS randomness have distinct serial numbers
+    let note_1 =
+        create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, OsFeltRng).unwrap();
+    let note_2 =
+        create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, OsFeltRng).unwrap();
+    assert_ne!(note_1.serial_num(), note_2.serial_num());
+}
+
+#[test]
+fn test_derived_serial_nums() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let requested: Asset = FungibleAsset::new(faucet_id, 50).unwrap().into();
+
+    let secret = SerialNumSecret::from_seed([3; 32]);
+    let tag = NoteTag::for_account(target);
+
+    // the serial numbers of consecutive notes are derived with consecutive counters
+    let mut rng = DerivedSerialNumRng::new(secret, tag, 0);
+    let p2id_note =
+        create_p2id_note(sender, target, vec![asset], NoteType::Private, ZERO, &mut rng).unwrap();
+    assert_eq!(p2id_note.serial_num(), secret.derive(tag, 0));
+    let (swap_note, payback) =
+        create_swap_note(sender, asset, requested, None, NoteType::Private, ZERO, &mut rng)
+            .unwrap();
+    assert_eq!(payback.serial_num(), secret.derive(tag, 1));
+    assert_eq!(swap_note.serial_num(), secret.derive(tag, 2));
+    assert_eq!(rng.counter(), 3);
+
+    // the notes can be recovered from the secret
+    let recovered = recover_derived_note(secret, tag, swap_note.id(), 0..10, |rng| {
+        create_swap_note(sender, asset, requested, None, NoteType::Private, ZERO, rng)
+            .map(|(note, _)| note)
+    })
+    .unwrap();
+    assert_eq!(recovered.map(|(counter, note)| (counter, note.id())), Some((1, swap_note.id())));
+
+    let build_p2id_note = |rng: DerivedSerialNumRng| {
+        create_p2id_note(sender, target, vec![asset], NoteType::Private, ZERO, rng)
+    };
+    let recovered =
+        recover_derived_note(secret, tag, p2id_note.id(), 0..10, build_p2id_note).unwrap();
+    assert_eq!(recovered.map(|(counter, _)| counter), Some(0));
+
+    // notes derived with other secrets or outside of the counter range are not recovered
+    let other_secret = SerialNumSecret::from_seed([4; 32]);
+    assert_eq!(
+        recover_derived_note(other_secret, tag, p2id_note.id(), 0..10, build_p2id_note).unwrap(),
+        None
+    );
+    assert_eq!(
+        recover_derived_note(secret, tag, p2id_note.id(), 1..10, build_p2id_note).unwrap(),
+        None
+    );
+}
+
+fn rng(seed: u64) -> RpoRandomCoin {
+    RpoRandomCoin::new([Felt::new(seed), ZERO, ZERO, ZERO])
+}
@@ -1,12 +1,22 @@
 use miden_objects::{
-    notes::Note,
+    accounts::{
+        AccountId, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    },
+    assets::{Asset, FungibleAsset},
+    notes::{Note, NoteAssets, NoteInputs, NoteMetadata, NoteTag, NoteType},
     transaction::{OutputNote, OutputNotes},
 };
 use mock::{
-    constants::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+    constants::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_SENDER, STORAGE_INDEX_0, STORAGE_INDEX_1, STORAGE_VALUE_0,
+    },
     mock::{
-        account::MockAccountType, host::MockHost, notes::AssetPreservationStatus,
-        transaction::mock_inputs,
+        account::{mock_account, MockAccountType},
+        host::MockHost,
+        notes::AssetPreservationStatus,
+        transaction::{mock_inputs, mock_inputs_with_foreign_accounts},
     },
     prepare_transaction,
     procedures::prepare_word,
@@ -16,9 +26,13 @@ use mock::{
 use super::{
     ContextId, Felt, MemAdviceProvider, Process, ProcessState, StackInputs, Word, ONE, ZERO,
 };
-use crate::transaction::memory::{
-    CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_NUM_ASSETS_OFFSET,
-    CREATED_NOTE_RECIPIENT_OFFSET, CREATED_NOTE_SECTION_OFFSET, NUM_CREATED_NOTES_PTR,
+use crate::transaction::{
+    memory::{
+        CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_NUM_ASSETS_OFFSET,
+        CREATED_NOTE_RECIPIENT_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE,
+        NUM_CREATED_NOTES_PTR, TX_EXPIRATION_BLOCK_NUM_PTR,
+    },
+    TransactionKernel,
 };
 
 #[test]
@@ -29,6 +43,8 @@ fn test_create_note() {
 
     let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
     let tag = Felt::new(4);
+    let note_type = Felt::from(NoteType::Private);
+    let aux = Felt::new(27);
     let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
 
     let code = format!(
@@ -40,6 +56,8 @@ fn test_create_note() {
         exec.prologue::prepare_transaction
 
         push.{recipient}
+        push.{aux}
+        push.{note_type}
         push.{tag}
         push.{asset}
 
@@ -69,7 +87,7 @@ fn test_create_note() {
     // assert the metadata is stored at the correct memory location.
     assert_eq!(
         read_root_mem_value(&process, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_METADATA_OFFSET),
-        [tag, Felt::from(account_id), ZERO, ZERO]
+        [tag, Felt::from(account_id), note_type, aux]
     );
 
     // assert the number of assets is stored at the correct memory location.
@@ -106,6 +124,7 @@ fn test_create_note_too_many_notes() {
         exec.memory::set_num_created_notes
 
         push.{recipient}
+        push.0.1
         push.{tag}
         push.{asset}
 
@@ -124,6 +143,204 @@ fn test_create_note_too_many_notes() {
     assert!(process.is_err());
 }
 
+#[test]
+fn test_create_note_invalid_tag() {
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    // tags must be u32 values, and network execution of notes targeted at off-chain accounts is
+    // not allowed
+    let off_chain_account =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let invalid_tags = [
+        Felt::new(u32::MAX as u64 + 1),
+        NoteTag::for_account(off_chain_account).network().into(),
+    ];
+
+    for tag in invalid_tags {
+        let tx_inputs =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+        let code = format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{recipient}
+            push.0.1
+            push.{tag}
+            push.{asset}
+
+            exec.tx::create_note
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            asset = prepare_word(&asset)
+        );
+
+        let transaction = prepare_transaction(tx_inputs, None, &code, None);
+        assert!(run_tx(&transaction).is_err());
+    }
+}
+
+#[test]
+fn test_create_note_invalid_note_type() {
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    // note types must be public (1), private (2) or encrypted (3)
+    for note_type in [0, 4, u32::MAX as u64 + 1] {
+        let tx_inputs =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+        let code = format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{recipient}
+            push.0
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+
+            exec.tx::create_note
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            asset = prepare_word(&asset)
+        );
+
+        let transaction = prepare_transaction(tx_inputs, None, &code, None);
+        assert!(run_tx(&transaction).is_err());
+    }
+}
+
+#[test]
+fn test_add_asset_to_note() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let input_note = tx_inputs.input_notes().get_note(0).note();
+
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let asset_1: Asset = FungibleAsset::new(faucet_id_1, 10).unwrap().into();
+    let asset_2: Asset = FungibleAsset::new(faucet_id_2, 20).unwrap().into();
+
+    // create an output note carrying both assets
+    let output_note = Note::new(
+        input_note.script().clone(),
+        &[],
+        &[asset_1, asset_2],
+        [Felt::new(8); 4],
+        tx_inputs.account().id(),
+        NoteTag::from(8888),
+    )
+    .unwrap();
+
+    let expected_output_notes_hash = OutputNotes::new(vec![OutputNote::from(output_note.clone())])
+        .unwrap()
+        .commitment();
+
+    let code = format!(
+        "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+
+        # create the output note with the first asset
+        push.{recipient}
+        push.0.1
+        push.{tag}
+        push.{asset_1}
+        exec.tx::create_note
+
+        # add the second asset to the note
+        push.{asset_2}
+        exec.tx::add_asset_to_note
+        drop
+
+        # compute the output notes hash
+        exec.tx::get_output_notes_hash
+        push.{expected} assert_eqw
+    end
+    ",
+        recipient = prepare_word(&output_note.recipient()),
+        tag = output_note.metadata().tag(),
+        asset_1 = prepare_word(&Word::from(asset_1)),
+        asset_2 = prepare_word(&Word::from(asset_2)),
+        expected = prepare_word(&expected_output_notes_hash)
+    );
+
+    let transaction = prepare_transaction(tx_inputs, None, &code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    // assert the number of assets of the note has been incremented to 2
+    assert_eq!(
+        read_root_mem_value(&process, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_NUM_ASSETS_OFFSET),
+        [Felt::new(2), ZERO, ZERO, ZERO]
+    );
+
+    // assert the second asset is stored after the first one
+    assert_eq!(
+        read_root_mem_value(&process, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_ASSETS_OFFSET + 1),
+        Word::from(asset_2)
+    );
+}
+
+#[test]
+fn test_add_asset_to_note_invalid() {
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    // an asset issued by the same faucet as the asset of the note cannot be added to the note, and
+    // assets can be added only to notes created by the transaction
+    let same_faucet_asset =
+        [Felt::new(20), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+    let other_asset = [Felt::new(20), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2)];
+    let invalid_additions =
+        [(same_faucet_asset, 0), (other_asset, 1), (other_asset, NOTE_MEM_SIZE)];
+
+    for (added_asset, ptr_offset) in invalid_additions {
+        let tx_inputs =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+        let code = format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{recipient}
+            push.0.1
+            push.{tag}
+            push.{asset}
+            exec.tx::create_note
+
+            add.{ptr_offset}
+            push.{added_asset}
+            exec.tx::add_asset_to_note
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            asset = prepare_word(&asset),
+            added_asset = prepare_word(&added_asset)
+        );
+
+        let transaction = prepare_transaction(tx_inputs, None, &code, None);
+        assert!(run_tx(&transaction).is_err());
+    }
+}
+
 #[test]
 fn test_get_output_notes_hash() {
     let tx_inputs =
@@ -137,7 +354,7 @@ fn test_get_output_notes_hash() {
 
     // create output note 1
     let output_serial_no_1 = [Felt::new(8); 4];
-    let output_tag_1 = Felt::new(8888);
+    let output_tag_1 = NoteTag::from(8888);
     let output_note_1 = Note::new(
         input_note_1.script().clone(),
         &[],
@@ -148,18 +365,21 @@ fn test_get_output_notes_hash() {
     )
     .unwrap();
 
-    // create output note 2
+    // create output note 2; the note type and the aux value are committed to by the output notes
+    // hash
     let output_serial_no_2 = [Felt::new(11); 4];
-    let output_tag_2 = Felt::new(1111);
-    let output_note_2 = Note::new(
+    let output_tag_2 = NoteTag::from(1111);
+    let output_metadata_2 = NoteMetadata::new(tx_inputs.account().id(), output_tag_2)
+        .unwrap()
+        .with_note_type(NoteType::Encrypted)
+        .with_aux(Felt::new(27));
+    let output_note_2 = Note::from_parts(
         input_note_2.script().clone(),
-        &[],
-        &[input_asset_2],
+        NoteInputs::new(vec![]).unwrap(),
+        NoteAssets::new(&[input_asset_2]).unwrap(),
         output_serial_no_2,
-        tx_inputs.account().id(),
-        output_tag_2,
-    )
-    .unwrap();
+        output_metadata_2,
+    );
 
     // compute expected output notes hash
     let expected_output_notes_hash = OutputNotes::new(vec![
@@ -179,6 +399,7 @@ fn test_get_output_notes_hash() {
 
         # create output note 1
         push.{recipient_1}
+        push.0.1
         push.{tag_1}
         push.{asset_1}
         exec.tx::create_note
@@ -186,6 +407,8 @@ fn test_get_output_notes_hash() {
 
         # create output note 2
         push.{recipient_2}
+        push.{aux_2}
+        push.{note_type_2}
         push.{tag_2}
         push.{asset_2}
         exec.tx::create_note
@@ -202,6 +425,8 @@ fn test_get_output_notes_hash() {
             **output_note_1.assets().iter().take(1).collect::<Vec<_>>().first().unwrap()
         )),
         recipient_2 = prepare_word(&output_note_2.recipient()),
+        aux_2 = output_metadata_2.aux(),
+        note_type_2 = Felt::from(output_metadata_2.note_type()),
         tag_2 = output_note_2.metadata().tag(),
         asset_2 = prepare_word(&Word::from(
             **output_note_2.assets().iter().take(1).collect::<Vec<_>>().first().unwrap()
@@ -213,6 +438,125 @@ fn test_get_output_notes_hash() {
     let _process = run_tx(&transaction).unwrap();
 }
 
+#[test]
+fn test_get_block_timestamp() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+        exec.tx::get_block_timestamp
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs.clone(), None, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    assert_eq!(process.stack.get(0), tx_inputs.block_header().timestamp());
+}
+
+#[test]
+fn test_get_foreign_account_item() {
+    let foreign_account = mock_account(
+        Some(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN),
+        ONE,
+        None,
+        &TransactionKernel::assembler(),
+    );
+    let tx_inputs = mock_inputs_with_foreign_accounts(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        &[(foreign_account, vec![STORAGE_INDEX_0])],
+    );
+
+    let code = |account_id: u64, index: u8| {
+        format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{index}.{account_id}
+            exec.tx::get_foreign_account_item
+
+            push.{value} assert_eqw
+        end
+        ",
+            value = prepare_word(&STORAGE_VALUE_0)
+        )
+    };
+
+    // the provided storage slot of the foreign account can be read
+    let code_ok = code(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, STORAGE_INDEX_0);
+    let transaction = prepare_transaction(tx_inputs.clone(), None, &code_ok, None);
+    assert!(run_tx(&transaction).is_ok());
+
+    // storage slots which were not provided cannot be read
+    let code_err = code(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, STORAGE_INDEX_1);
+    let transaction = prepare_transaction(tx_inputs.clone(), None, &code_err, None);
+    assert!(run_tx(&transaction).is_err());
+
+    // accounts which are not foreign accounts of the transaction cannot be read
+    let code_err = code(ACCOUNT_ID_SENDER, STORAGE_INDEX_0);
+    let transaction = prepare_transaction(tx_inputs, None, &code_err, None);
+    assert!(run_tx(&transaction).is_err());
+}
+
+#[test]
+fn test_update_expiration_block_num() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let block_num = tx_inputs.block_header().block_num() as u64;
+
+    let code = |expiration_block_nums: &[u64]| {
+        let updates = expiration_block_nums
+            .iter()
+            .map(|block_num| format!("push.{block_num} exec.tx::update_expiration_block_num\n"))
+            .collect::<String>();
+        format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+            {updates}
+        end
+        "
+        )
+    };
+
+    // the transaction does not expire unless the expiration block number is updated
+    let transaction = prepare_transaction(tx_inputs.clone(), None, &code(&[]), None);
+    let process = run_tx(&transaction).unwrap();
+    assert_eq!(
+        read_root_mem_value(&process, TX_EXPIRATION_BLOCK_NUM_PTR)[0],
+        Felt::from(u32::MAX)
+    );
+
+    // the expiration block number can be lowered, but not raised
+    let updates = [block_num + 20, block_num + 10, block_num + 30];
+    let transaction = prepare_transaction(tx_inputs.clone(), None, &code(&updates), None);
+    let process = run_tx(&transaction).unwrap();
+    assert_eq!(
+        read_root_mem_value(&process, TX_EXPIRATION_BLOCK_NUM_PTR)[0],
+        Felt::new(block_num + 10)
+    );
+
+    // the expiration block number must be a u32 value greater than the last known block number
+    for invalid_block_num in [block_num, u32::MAX as u64 + 1] {
+        let transaction =
+            prepare_transaction(tx_inputs.clone(), None, &code(&[invalid_block_num]), None);
+        assert!(run_tx(&transaction).is_err());
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
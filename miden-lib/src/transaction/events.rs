@@ -1,6 +1,6 @@
 use core::fmt;
 
-use super::TransactionEventParsingError;
+use super::{TransactionEventParsingError, TransactionTraceParsingError};
 
 // TRANSACTION EVENT
 // ================================================================================================
@@ -20,6 +20,8 @@ pub enum TransactionEvent {
     AccountStorageSetItem = 0x2_0002,     // 131074
     AccountIncrementNonce = 0x2_0003,     // 131075
     AccountPushProcedureIndex = 0x2_0004, // 131076
+    AccountStorageSetMapItem = 0x2_0005,  // 131077
+    TxScriptOutput = 0x2_0006,            // 131078
 }
 
 impl TransactionEvent {
@@ -47,7 +49,68 @@ impl TryFrom<u32> for TransactionEvent {
             0x2_0002 => Ok(TransactionEvent::AccountStorageSetItem),
             0x2_0003 => Ok(TransactionEvent::AccountIncrementNonce),
             0x2_0004 => Ok(TransactionEvent::AccountPushProcedureIndex),
+            0x2_0005 => Ok(TransactionEvent::AccountStorageSetMapItem),
+            0x2_0006 => Ok(TransactionEvent::TxScriptOutput),
             _ => Err(TransactionEventParsingError::InvalidTransactionEvent(value)),
         }
     }
 }
+
+// TRANSACTION TRACE
+// ================================================================================================
+
+/// Traces which may be emitted by a transaction kernel to mark the boundaries of the sections of
+/// the transaction program.
+///
+/// The traces are emitted via the `trace.<trace_id>` instruction and are handled by the host only
+/// if tracing is enabled in the execution options. Trace IDs are structured in the same way as
+/// event IDs (see [TransactionEvent]).
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TransactionTrace {
+    PrologueStart = 0x2_0000,           // 131072
+    PrologueEnd = 0x2_0001,             // 131073
+    NotesProcessingStart = 0x2_0002,    // 131074
+    NotesProcessingEnd = 0x2_0003,      // 131075
+    NoteExecutionStart = 0x2_0004,      // 131076
+    NoteExecutionEnd = 0x2_0005,        // 131077
+    TxScriptProcessingStart = 0x2_0006, // 131078
+    TxScriptProcessingEnd = 0x2_0007,   // 131079
+    EpilogueStart = 0x2_0008,           // 131080
+    EpilogueEnd = 0x2_0009,             // 131081
+}
+
+impl TransactionTrace {
+    /// Value of the top 16 bits of a transaction kernel trace ID.
+    pub const TRACE_ID_PREFIX: u16 = 2;
+}
+
+impl fmt::Display for TransactionTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl TryFrom<u32> for TransactionTrace {
+    type Error = TransactionTraceParsingError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value >> 16 != Self::TRACE_ID_PREFIX as u32 {
+            return Err(TransactionTraceParsingError::NotTransactionTrace(value));
+        }
+
+        match value {
+            0x2_0000 => Ok(TransactionTrace::PrologueStart),
+            0x2_0001 => Ok(TransactionTrace::PrologueEnd),
+            0x2_0002 => Ok(TransactionTrace::NotesProcessingStart),
+            0x2_0003 => Ok(TransactionTrace::NotesProcessingEnd),
+            0x2_0004 => Ok(TransactionTrace::NoteExecutionStart),
+            0x2_0005 => Ok(TransactionTrace::NoteExecutionEnd),
+            0x2_0006 => Ok(TransactionTrace::TxScriptProcessingStart),
+            0x2_0007 => Ok(TransactionTrace::TxScriptProcessingEnd),
+            0x2_0008 => Ok(TransactionTrace::EpilogueStart),
+            0x2_0009 => Ok(TransactionTrace::EpilogueEnd),
+            _ => Err(TransactionTraceParsingError::InvalidTransactionTrace(value)),
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use miden_objects::{
     accounts::Account,
+    assets::PartialVault,
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNotes, PreparedTransaction, TransactionArgs,
-        TransactionInputs, TransactionScript, TransactionWitness,
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNotes, PreparedTransaction,
+        TransactionArgs, TransactionInputs, TransactionWitness,
     },
     utils::{collections::Vec, vec},
     vm::{AdviceInputs, StackInputs},
-    Felt, Word, ZERO,
+    Felt, Word, ONE, ZERO,
 };
 
 use super::TransactionKernel;
@@ -86,13 +87,19 @@ fn extend_advice_inputs(
     advice_inputs: &mut AdviceInputs,
 ) {
     // build the advice stack
-    build_advice_stack(tx_inputs, tx_args.tx_script(), advice_inputs);
+    build_advice_stack(tx_inputs, tx_args, advice_inputs);
 
     // build the advice map and Merkle store for relevant components
     add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
-    add_account_to_advice_inputs(tx_inputs.account(), tx_inputs.account_seed(), advice_inputs);
+    add_account_to_advice_inputs(
+        tx_inputs.account(),
+        tx_inputs.account_seed(),
+        tx_inputs.partial_vault(),
+        advice_inputs,
+    );
     add_input_notes_to_advice_inputs(tx_inputs.input_notes(), tx_args, advice_inputs);
-    add_tx_script_inputs_to_advice_map(tx_args.tx_script(), advice_inputs);
+    add_foreign_accounts_to_advice_inputs(tx_inputs.foreign_accounts(), advice_inputs);
+    add_tx_args_to_advice_inputs(tx_args, advice_inputs);
 }
 
 // ADVICE STACK BUILDER
@@ -115,10 +122,14 @@ fn extend_advice_inputs(
 ///  elements[44..47]  = account storage root
 ///  elements[48..51]  = account code root
 ///  elements[52]      = number of input notes
-///  elements[53..57]  = account seed, if one was provided; otherwise [ZERO; 4]
+///  elements[53..57]  = transaction script root, if a script was provided; otherwise [ZERO; 4]
+///  elements[57..61]  = transaction fee, if the transaction pays a fee; otherwise [ZERO; 4]
+///  elements[61]      = number of foreign accounts
+///  elements[62..]    = for each foreign account: [account ID, ZERO, ZERO, account nonce],
+///                      account vault root, account storage root, account code root
 fn build_advice_stack(
     tx_inputs: &TransactionInputs,
-    tx_script: Option<&TransactionScript>,
+    tx_args: &TransactionArgs,
     inputs: &mut AdviceInputs,
 ) {
     // push block header info into the stack
@@ -144,7 +155,7 @@ fn build_advice_stack(
     inputs.extend_stack([Felt::from(tx_inputs.input_notes().num_notes() as u32)]);
 
     // push tx_script root onto the stack
-    if let Some(tx_script) = tx_script {
+    if let Some(tx_script) = tx_args.tx_script() {
         // insert the transaction script hash into the advice stack
         inputs.extend_stack(*tx_script.hash());
     } else {
@@ -152,6 +163,20 @@ fn build_advice_stack(
         // script root
         inputs.extend_stack(Word::default());
     }
+
+    // push the fee onto the stack; an empty word means that the transaction pays no fee
+    inputs.extend_stack(tx_args.fee().map(Word::from).unwrap_or_default());
+
+    // push the stubs of the foreign accounts onto the stack
+    let foreign_accounts = tx_inputs.foreign_accounts();
+    inputs.extend_stack([Felt::from(foreign_accounts.len() as u32)]);
+    for foreign_account in foreign_accounts {
+        let account = foreign_account.account();
+        inputs.extend_stack([account.id().into(), ZERO, ZERO, account.nonce()]);
+        inputs.extend_stack(account.vault_root());
+        inputs.extend_stack(account.storage_root());
+        inputs.extend_stack(account.code_root());
+    }
 }
 
 // CHAIN MMR INJECTOR
@@ -187,17 +212,23 @@ fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
 ///
 /// Inserts the following items into the Merkle store:
 /// - The Merkle nodes associated with the storage slots tree.
-/// - The Merkle nodes associated with the account vault tree.
+/// - The Merkle nodes associated with the known storage maps.
+/// - The Merkle nodes associated with the account vault tree, or only the nodes authenticating the
+///   tracked leaves when a partial vault is provided.
 /// - The Merkle nodes associated with the account code procedures tree.
 ///
 /// Inserts the following entries into the advice map:
 /// - The storage types commitment |-> storage slot types vector.
+/// - The multi-word value commitment |-> multi-word value, for each known multi-word storage value.
+/// - The node |-> (key, value), for all leaf nodes of the known storage maps.
 /// - The account procedure root |-> procedure index, for each account procedure.
-/// - The node |-> (key, value), for all leaf nodes of the asset vault SMT.
+/// - The node |-> (key, value), for all leaf nodes of the asset vault SMT, or only for the tracked
+///   leaf nodes when a partial vault is provided.
 /// - [account_id, 0, 0, 0] |-> account_seed, when account seed is provided.
 fn add_account_to_advice_inputs(
     account: &Account,
     account_seed: Option<Word>,
+    partial_vault: Option<&PartialVault>,
     inputs: &mut AdviceInputs,
 ) {
     // --- account storage ----------------------------------------------------
@@ -212,15 +243,41 @@ fn add_account_to_advice_inputs(
         storage.layout().iter().map(Felt::from).collect(),
     )]);
 
-    // --- account vault ------------------------------------------------------
-    let vault = account.vault();
+    // extend advice map with multi-word value commitment |-> multi-word value
+    inputs.extend_map(storage.multi_word_advice_entries());
 
-    // extend the merkle store with account vault data
-    inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
+    // extend the merkle store and the advice map with the storage maps data
+    for (_, map) in storage.maps() {
+        inputs.extend_merkle_store(map.inner_nodes());
+        inputs.extend_map(map.leaves().map(|leaf| (leaf.hash(), leaf.to_elements())));
+    }
 
-    // populate advice map with Sparse Merkle Tree leaf nodes
-    inputs
-        .extend_map(vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+    // extend the merkle store with the storage arrays data
+    for (_, array) in storage.arrays() {
+        inputs.extend_merkle_store(array.inner_nodes());
+    }
+
+    // --- account vault ------------------------------------------------------
+    match partial_vault {
+        Some(partial_vault) => {
+            // extend the merkle store with the nodes authenticating the tracked vault leaves
+            inputs.extend_merkle_store(partial_vault.inner_nodes());
+
+            // populate advice map with the tracked Sparse Merkle Tree leaf nodes
+            inputs.extend_map(partial_vault.leaves().map(|leaf| (leaf.hash(), leaf.to_elements())));
+        },
+        None => {
+            let vault = account.vault();
+
+            // extend the merkle store with account vault data
+            inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
+
+            // populate advice map with Sparse Merkle Tree leaf nodes
+            inputs.extend_map(
+                vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())),
+            );
+        },
+    }
 
     // --- account code -------------------------------------------------------
     let code = account.code();
@@ -261,13 +318,17 @@ fn add_account_to_advice_inputs(
 ///   ...
 ///   out[34 + num_assets * 4..] = Word::default() (this is conditional padding only applied
 ///                                                 if the number of assets is odd)
+///   out[-11]      = is_authenticated
 ///   out[-10]      = origin.block_number
 ///   out[-9..-5]   = origin.SUB_HASH
 ///   out[-5..-1]   = origin.NOTE_ROOT
 ///   out[-1]       = origin.node_index
 ///
+/// The origin of the note is added only for authenticated notes, i.e., for unauthenticated notes
+/// the `is_authenticated` flag (set to ZERO) is the last data item of the note.
+///
 /// Inserts the following items into the Merkle store:
-/// - The Merkle nodes associated with the note's authentication path.
+/// - The Merkle nodes associated with the authentication paths of authenticated notes.
 ///
 /// Inserts the following entries into the advice map:
 /// - inputs_hash |-> inputs
@@ -286,21 +347,12 @@ fn add_input_notes_to_advice_inputs(
     let mut note_data = Vec::new();
     for input_note in notes.iter() {
         let note = input_note.note();
-        let proof = input_note.proof();
         let note_arg = tx_args.get_note_args(note.id()).unwrap_or(&[ZERO; 4]);
 
         // insert note inputs and assets into the advice map
         inputs.extend_map([(note.inputs().commitment(), note.inputs().to_padded_values())]);
         inputs.extend_map([(note.assets().commitment(), note.assets().to_padded_assets())]);
 
-        // insert note authentication path nodes into the Merkle store
-        inputs.extend_merkle_store(
-            proof
-                .note_path()
-                .inner_nodes(proof.origin().node_index.value(), note.authentication_hash())
-                .unwrap(),
-        );
-
         // add the note elements to the combined vector of note data
         note_data.extend(note.serial_num());
         note_data.extend(*note.script().hash());
@@ -315,6 +367,22 @@ fn add_input_notes_to_advice_inputs(
         note_data.push((note.assets().num_assets() as u32).into());
         note_data.extend(note.assets().to_padded_assets());
 
+        // unauthenticated notes are committed to by their headers instead of being authenticated
+        // against the chain, and thus have no origin
+        let Some(proof) = input_note.proof() else {
+            note_data.push(ZERO);
+            continue;
+        };
+        note_data.push(ONE);
+
+        // insert note authentication path nodes into the Merkle store
+        inputs.extend_merkle_store(
+            proof
+                .note_path()
+                .inner_nodes(proof.origin().node_index.value(), note.authentication_hash())
+                .unwrap(),
+        );
+
         note_data.push(proof.origin().block_num.into());
         note_data.extend(*proof.sub_hash());
         note_data.extend(*proof.note_root());
@@ -332,16 +400,30 @@ fn add_input_notes_to_advice_inputs(
     inputs.extend_map([(notes.commitment(), note_data)]);
 }
 
-// TRANSACTION SCRIPT INJECTOR
+// FOREIGN ACCOUNTS INJECTOR
 // ------------------------------------------------------------------------------------------------
 
-/// Inserts the following entries into the advice map:
-/// - input_hash |-> input, for each tx_script input
-fn add_tx_script_inputs_to_advice_map(
-    tx_script: Option<&TransactionScript>,
+/// Inserts the following items into the Merkle store:
+/// - The Merkle nodes authenticating each foreign account in the account database.
+/// - The Merkle nodes authenticating the readable storage slots of each foreign account.
+fn add_foreign_accounts_to_advice_inputs(
+    foreign_accounts: &[ForeignAccountInputs],
     inputs: &mut AdviceInputs,
 ) {
-    if let Some(tx_script) = tx_script {
-        inputs.extend_map(tx_script.inputs().iter().map(|(hash, input)| (*hash, input.clone())));
+    for foreign_account in foreign_accounts {
+        inputs.extend_merkle_store(foreign_account.inner_nodes());
     }
 }
+
+// TRANSACTION ARGS INJECTOR
+// ------------------------------------------------------------------------------------------------
+
+/// Inserts the following entries into the advice map:
+/// - key |-> values, for each entry of the advice map of the transaction args; these include the
+///   inputs of the transaction script.
+///
+/// Inserts the nodes of the Merkle store of the transaction args into the Merkle store.
+fn add_tx_args_to_advice_inputs(tx_args: &TransactionArgs, inputs: &mut AdviceInputs) {
+    inputs.extend_map(tx_args.advice_map().iter().map(|(key, values)| (*key, values.clone())));
+    inputs.extend_merkle_store(tx_args.merkle_store().inner_nodes());
+}
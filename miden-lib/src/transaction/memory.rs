@@ -21,75 +21,17 @@ pub const FAUCET_STORAGE_DATA_SLOT: StorageSlot = 254;
 /// The account storage slot at which the slot types commitment is stored.
 pub const SLOT_TYPES_COMMITMENT_STORAGE_SLOT: StorageSlot = 255;
 
-// BOOKKEEPING
+// KERNEL MEMORY LAYOUT
 // ------------------------------------------------------------------------------------------------
 
-/// The memory address at which the transaction vault root is stored.
-pub const TX_VAULT_ROOT_PTR: MemoryAddress = 0;
-
-/// The memory address at which a pointer to the consumed note being executed is stored.
-pub const CURRENT_CONSUMED_NOTE_PTR: MemoryAddress = 1;
-
-/// The memory address at which the number of created notes is stored.
-pub const NUM_CREATED_NOTES_PTR: MemoryAddress = 2;
-
-/// The memory address at which the input vault root is stored
-pub const INPUT_VAULT_ROOT_PTR: MemoryAddress = 3;
-
-/// The memory address at which the output vault root is stored
-pub const OUTPUT_VAULT_ROOT_PTR: MemoryAddress = 4;
-
-// GLOBAL INPUTS
-// ------------------------------------------------------------------------------------------------
-
-/// The memory address at which the global inputs section begins.
-pub const GLOBAL_INPUTS_SECTION_OFFSET: MemoryOffset = 100;
-
-/// The memory address at which the latest known block hash is stored.
-pub const BLK_HASH_PTR: MemoryAddress = 100;
-
-/// The memory address at which the account id is stored.
-pub const ACCT_ID_PTR: MemoryAddress = 101;
-
-/// The memory address at which the initial account hash is stored.
-pub const INIT_ACCT_HASH_PTR: MemoryAddress = 102;
-
-/// The memory address at which the global nullifier commitment is stored.
-pub const NULLIFIER_COM_PTR: MemoryAddress = 103;
-
-/// The memory address at which the initial nonce is stored.
-pub const INIT_NONCE_PTR: MemoryAddress = 104;
-
-/// The memory address at which the transaction script mast root is store
-pub const TX_SCRIPT_ROOT_PTR: MemoryAddress = 105;
+// The constants below are generated by the build script from the constants defined in the memory
+// module of the transaction kernel, which is the source of truth for the memory layout. Sizes are
+// typed as `MemoryOffset`.
+include!(concat!(env!("OUT_DIR"), "/tx_kernel_memory.rs"));
 
 // BLOCK DATA
 // ------------------------------------------------------------------------------------------------
 
-/// The memory address at which the block data section begins
-pub const BLOCK_DATA_SECTION_OFFSET: MemoryOffset = 200;
-
-/// The memory address at which the previous block hash is stored
-pub const PREV_BLOCK_HASH_PTR: MemoryAddress = 200;
-
-/// The memory address at which the chain root is stored
-pub const CHAIN_ROOT_PTR: MemoryAddress = 201;
-
-/// The memory address at which the state root is stored
-pub const ACCT_DB_ROOT_PTR: MemoryAddress = 202;
-
-/// The memory address at which the nullifier db root is store
-pub const NULLIFIER_DB_ROOT_PTR: MemoryAddress = 203;
-
-/// The memory address at which the batch root is stored
-pub const BATCH_ROOT_PTR: MemoryAddress = 204;
-
-/// The memory address at which the proof hash is stored
-pub const PROOF_HASH_PTR: MemoryAddress = 205;
-
-/// The memory address at which the block number is stored
-pub const BLOCK_METADATA_PTR: MemoryAddress = 206;
-
 /// The index of the block number within the block metadata
 pub const BLOCK_NUMBER_IDX: DataIndex = 0;
 
@@ -99,33 +41,16 @@ pub const PROTOCOL_VERSION_IDX: DataIndex = 1;
 /// The index of the timestamp within the block metadata
 pub const TIMESTAMP_IDX: DataIndex = 2;
 
-/// The memory address at which the note root is stored
-pub const NOTE_ROOT_PTR: MemoryAddress = 207;
-
-// CHAIN DATA
-// ------------------------------------------------------------------------------------------------
-
-/// The memory address at which the chain data section begins
-pub const CHAIN_MMR_PTR: MemoryAddress = 300;
-
-/// The memory address at which the total number of leaves in the chain MMR is stored
-pub const CHAIN_MMR_NUM_LEAVES_PTR: MemoryAddress = 300;
-
-/// The memory address at which the chain mmr peaks are stored
-pub const CHAIN_MMR_PEAKS_PTR: MemoryAddress = 301;
-
 // ACCOUNT DATA
 // ------------------------------------------------------------------------------------------------
 
 /// The size of the memory segment allocated to core account data (excluding new code root)
-pub const ACCT_DATA_MEM_SIZE: MemSize = 4;
-
-/// The memory address at which the account data section begins
-pub const ACCT_DATA_SECTION_OFFSET: MemoryOffset = 400;
+pub const ACCT_DATA_MEM_SIZE: MemSize =
+    (ACCT_CORE_DATA_SECTION_END_OFFSET - ACCT_DATA_SECTION_OFFSET) as MemSize;
 
 /// The offset at which the account id and nonce is stored relative to the start of the account
 /// data segment.
-pub const ACCT_ID_AND_NONCE_OFFSET: MemoryOffset = 0;
+pub const ACCT_ID_AND_NONCE_OFFSET: MemoryOffset = ACCT_ID_AND_NONCE_PTR - ACCT_DATA_SECTION_OFFSET;
 
 /// The index of the account id within the account id and nonce data.
 pub const ACCT_ID_IDX: DataIndex = 0;
@@ -133,64 +58,41 @@ pub const ACCT_ID_IDX: DataIndex = 0;
 /// The index of the account nonce within the account id and nonce data.
 pub const ACCT_NONCE_IDX: DataIndex = 3;
 
-/// The memory address at which the account id and nonce is stored.
-/// The account id is stored in the first element.
-/// The account nonce is stored in the fourth element.
-pub const ACCT_ID_AND_NONCE_PTR: MemoryAddress =
-    ACCT_DATA_SECTION_OFFSET + ACCT_ID_AND_NONCE_OFFSET;
-
 /// The offset at which the account vault root is stored relative to the start of the account
 /// data segment.
-pub const ACCT_VAULT_ROOT_OFFSET: MemoryOffset = 1;
-
-/// The memory address at which the account vault root is stored.
-pub const ACCT_VAULT_ROOT_PTR: MemoryAddress = ACCT_DATA_SECTION_OFFSET + ACCT_VAULT_ROOT_OFFSET;
+pub const ACCT_VAULT_ROOT_OFFSET: MemoryOffset = ACCT_VAULT_ROOT_PTR - ACCT_DATA_SECTION_OFFSET;
 
 /// The offset at which the account storage root is stored relative to the start of the account
 /// data segment.
-pub const ACCT_STORAGE_ROOT_OFFSET: MemoryOffset = 2;
-
-/// The memory address at which the account storage root is stored.
-pub const ACCT_STORAGE_ROOT_PTR: MemoryAddress =
-    ACCT_DATA_SECTION_OFFSET + ACCT_STORAGE_ROOT_OFFSET;
+pub const ACCT_STORAGE_ROOT_OFFSET: MemoryOffset = ACCT_STORAGE_ROOT_PTR - ACCT_DATA_SECTION_OFFSET;
 
 /// The offset at which the account code root is stored relative to the start of the account
 /// data segment.
-pub const ACCT_CODE_ROOT_OFFSET: MemoryOffset = 3;
-
-/// The memory address at which the account code root is stored.
-pub const ACCT_CODE_ROOT_PTR: MemoryAddress = ACCT_DATA_SECTION_OFFSET + ACCT_CODE_ROOT_OFFSET;
+pub const ACCT_CODE_ROOT_OFFSET: MemoryOffset = ACCT_CODE_ROOT_PTR - ACCT_DATA_SECTION_OFFSET;
 
 /// The offset at which the accounts new code root is stored relative to the start of the account
 /// data segment.
-pub const ACCT_NEW_CODE_ROOT_OFFSET: MemoryOffset = 4;
-
-/// The memory address at which the new account code root is stored
-pub const ACCT_NEW_CODE_ROOT_PTR: MemoryAddress =
-    ACCT_DATA_SECTION_OFFSET + ACCT_NEW_CODE_ROOT_OFFSET;
-
-/// The memory address at which the account storage slot type data beings
-pub const ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET: MemoryAddress = 405;
+pub const ACCT_NEW_CODE_ROOT_OFFSET: MemoryOffset =
+    ACCT_NEW_CODE_ROOT_PTR - ACCT_DATA_SECTION_OFFSET;
 
 // NOTES DATA
 // ================================================================================================
 
-/// The size of the memory segment allocated to each note.
-pub const NOTE_MEM_SIZE: MemoryAddress = 512;
-
 // INPUT NOTES DATA
 // ------------------------------------------------------------------------------------------------
 // Inputs note section contains data of all notes consumed by a transaction. The section starts at
 // memory offset 1_048_576 with a word containing the total number of input notes and is followed
-// by note nullifiers and note data like so:
+// by note nullifiers, note header hashes and note data like so:
 //
-// ┌─────────┬───────────┬───────────┬─────┬───────────┬─────────┬────────┬────────┬─────┬────────┐
-// │   NUM   │  NOTE 0   │  NOTE 1   │ ... │  NOTE n   │ PADDING │ NOTE 0 │ NOTE 1 │ ... │ NOTE n │
-// │  NOTES  │ NULLIFIER │ NULLIFIER │     │ NULLIFIER │         │  DATA  │  DATA  │     │  DATA  │
-// └─────────┴───────────┴───────────┴─────┴───────────┴─────────┴────────┴────────┴─────┴────────┘
-//  1_048_576  1_048_577   1_048_578        1_048_576+n      1_064_960   +512    +1024  +512n
+// ┌─────────┬───────────┬─────┬───────────┬─────────┬──────────┬─────┬──────────┬─────────┬────────┬─────┬────────┐
+// │   NUM   │  NOTE 0   │ ... │  NOTE n   │ PADDING │  NOTE 0  │ ... │  NOTE n  │ PADDING │ NOTE 0 │ ... │ NOTE n │
+// │  NOTES  │ NULLIFIER │     │ NULLIFIER │         │ HDR HASH │     │ HDR HASH │         │  DATA  │     │  DATA  │
+// └─────────┴───────────┴─────┴───────────┴─────────┴──────────┴─────┴──────────┴─────────┴────────┴─────┴────────┘
+//  1_048_576  1_048_577        1_048_577+n           1_056_768        1_056_768+n           1_064_960        +512n
 //
-// Each nullifier occupies a single word. A data section for each note consists of exactly 512
+// Each nullifier and each header hash occupies a single word. The header hash of a note is the
+// hash of its ID and metadata for unauthenticated notes, and [ZERO; 4] for notes authenticated
+// against the chain during the prologue. A data section for each note consists of exactly 512
 // words and is laid out like so:
 //
 // ┌──────┬────────┬────────┬────────┬────────┬──────┬───────┬────────┬────────┬───────┬─────┬───────┬─────────┬
@@ -202,27 +104,6 @@ pub const NOTE_MEM_SIZE: MemoryAddress = 512;
 // Even though both NUM_NOTES and NUM_ASSETS take up a whole word, the actual values for these
 // variables are stored in the first element of the word.
 
-/// The memory address at which the consumed note section begins.
-pub const CONSUMED_NOTE_SECTION_OFFSET: MemoryOffset = 1_048_576;
-
-/// The memory address at which the consumed note data section begins.
-pub const CONSUMED_NOTE_DATA_SECTION_OFFSET: MemoryAddress = 1_064_960;
-
-/// The memory address at which the number of consumed notes is stored.
-pub const CONSUMED_NOTE_NUM_PTR: MemoryAddress = CONSUMED_NOTE_SECTION_OFFSET;
-
-/// The offsets at which data of a consumed note is stored relative to the start of its data segment.
-pub const CONSUMED_NOTE_ID_OFFSET: MemoryOffset = 0;
-pub const CONSUMED_NOTE_SERIAL_NUM_OFFSET: MemoryOffset = 1;
-pub const CONSUMED_NOTE_SCRIPT_ROOT_OFFSET: MemoryOffset = 2;
-pub const CONSUMED_NOTE_INPUTS_HASH_OFFSET: MemoryOffset = 3;
-pub const CONSUMED_NOTE_ASSETS_HASH_OFFSET: MemoryOffset = 4;
-pub const CONSUMED_NOTE_METADATA_OFFSET: MemoryOffset = 5;
-pub const CONSUMED_NOTE_ARGS_OFFSET: MemoryOffset = 6;
-pub const CONSUMED_NOTE_NUM_INPUTS_OFFSET: MemoryOffset = 7;
-pub const CONSUMED_NOTE_NUM_ASSETS_OFFSET: MemoryOffset = 8;
-pub const CONSUMED_NOTE_ASSETS_OFFSET: MemoryOffset = 9;
-
 // OUTPUT NOTES DATA
 // ------------------------------------------------------------------------------------------------
 // Output notes section contains data of all notes produced by a transaction. The section starts at
@@ -244,16 +125,38 @@ pub const CONSUMED_NOTE_ASSETS_OFFSET: MemoryOffset = 9;
 // Even though NUM_ASSETS takes up a while word, the actual value of this variable is stored in the
 // first element of the word.
 
-/// The memory address at which the created notes section begins.
-pub const CREATED_NOTE_SECTION_OFFSET: MemoryOffset = 4_194_304;
-
 /// The size of the core created note data segment.
-pub const CREATED_NOTE_CORE_DATA_SIZE: MemSize = 4;
+pub const CREATED_NOTE_CORE_DATA_SIZE: MemSize = CREATED_NOTE_NUM_ASSETS_OFFSET as MemSize;
+
+// HELPER FUNCTIONS
+// ================================================================================================
 
-/// The offsets at which data of a created note is stored relative to the start of its data segment.
-pub const CREATED_NOTE_ID_OFFSET: MemoryOffset = 0;
-pub const CREATED_NOTE_METADATA_OFFSET: MemoryOffset = 1;
-pub const CREATED_NOTE_RECIPIENT_OFFSET: MemoryOffset = 2;
-pub const CREATED_NOTE_ASSET_HASH_OFFSET: MemoryOffset = 3;
-pub const CREATED_NOTE_NUM_ASSETS_OFFSET: MemoryOffset = 4;
-pub const CREATED_NOTE_ASSETS_OFFSET: MemoryOffset = 5;
+/// Returns the memory address at which the nullifier of the consumed note with the specified
+/// index is stored.
+pub const fn consumed_note_nullifier_ptr(note_idx: u32) -> MemoryAddress {
+    CONSUMED_NOTE_NUM_PTR + 1 + note_idx
+}
+
+/// Returns the memory address at which the header hash of the consumed note with the specified
+/// index is stored.
+pub const fn consumed_note_header_hash_ptr(note_idx: u32) -> MemoryAddress {
+    CONSUMED_NOTE_HEADER_HASH_SECTION_OFFSET + note_idx
+}
+
+/// Returns the memory address at which the data segment of the consumed note with the specified
+/// index begins.
+pub const fn consumed_note_data_ptr(note_idx: u32) -> MemoryAddress {
+    CONSUMED_NOTE_DATA_SECTION_OFFSET + note_idx * NOTE_MEM_SIZE
+}
+
+/// Returns the memory address at which the data segment of the created note with the specified
+/// index begins.
+pub const fn created_note_data_ptr(note_idx: u32) -> MemoryAddress {
+    CREATED_NOTE_SECTION_OFFSET + note_idx * NOTE_MEM_SIZE
+}
+
+/// Returns the memory address at which the data segment of the foreign account with the specified
+/// index begins.
+pub const fn foreign_acct_data_ptr(acct_idx: u32) -> MemoryAddress {
+    FOREIGN_ACCT_DATA_OFFSET + acct_idx * FOREIGN_ACCT_DATA_SIZE
+}
@@ -1,31 +1,41 @@
 use miden_objects::{
     accounts::AccountId,
     assembly::{Assembler, AssemblyContext, ProgramAst},
-    transaction::{OutputNotes, TransactionOutputs},
+    assets::FungibleAsset,
+    transaction::{KernelRegistry, OutputNotes, TransactionOutputs},
     utils::{collections::Vec, group_slice_elements, serde::DeserializationError},
     vm::{AdviceMap, ProgramInfo, StackInputs, StackOutputs},
-    Digest, Felt, TransactionOutputError, Word,
+    Digest, Felt, TransactionOutputError, Word, TX_KERNEL_VERSION, WORD_SIZE, ZERO,
 };
 use miden_stdlib::StdLibrary;
 
 use super::MidenLib;
 
 pub mod memory;
+pub mod scripts;
 
 mod events;
-pub use events::TransactionEvent;
+pub use events::{TransactionEvent, TransactionTrace};
 
 mod inputs;
 pub use inputs::ToTransactionKernelInputs;
 
 mod outputs;
 pub use outputs::{
-    notes_try_from_elements, parse_final_account_stub, FINAL_ACCOUNT_HASH_WORD_IDX,
+    notes_try_from_elements, parse_final_account_stub, EXPIRATION_BLOCK_NUM_ELEMENT_IDX,
+    FEE_AMOUNT_ELEMENT_IDX, FEE_FAUCET_ID_ELEMENT_IDX, FINAL_ACCOUNT_HASH_WORD_IDX,
     OUTPUT_NOTES_COMMITMENT_WORD_IDX, TX_SCRIPT_ROOT_WORD_IDX,
 };
 
+mod summary;
+pub use summary::{
+    AssetFlow, NoteScriptKind, NoteSummary, ToTransactionSummary, TransactionSummary,
+};
+
 mod errors;
-pub use errors::{TransactionEventParsingError, TransactionKernelError};
+pub use errors::{
+    TransactionEventParsingError, TransactionKernelError, TransactionTraceParsingError,
+};
 
 // TRANSACTION KERNEL
 // ================================================================================================
@@ -66,13 +76,37 @@ impl TransactionKernel {
         ProgramInfo::new(kernel_main.hash(), assembler.kernel().clone())
     }
 
+    /// Returns a [KernelRegistry] containing the current version of the transaction kernel.
+    ///
+    /// # Panics
+    /// Panics if the transaction kernel source is not well-formed.
+    pub fn kernel_registry() -> KernelRegistry {
+        KernelRegistry::new().with_kernel(TX_KERNEL_VERSION, Self::program_info())
+    }
+
     // ASSEMBLER CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
     /// Returns a new Miden assembler instantiated with the transaction kernel and loaded with the
     /// Miden stdlib as well as with midenlib.
     pub fn assembler() -> Assembler {
+        Self::build_assembler(false)
+    }
+
+    /// Returns a new Miden assembler in debug mode instantiated with the transaction kernel and
+    /// loaded with the Miden stdlib as well as with midenlib.
+    ///
+    /// Programs compiled by this assembler retain the debug decorators which map the executed
+    /// operations back to the MASM instructions and procedures they were compiled from.
+    pub fn debug_assembler() -> Assembler {
+        Self::build_assembler(true)
+    }
+
+    /// Returns a new Miden assembler instantiated with the transaction kernel and the libraries
+    /// required by transactions, in debug mode if `in_debug_mode` is true.
+    fn build_assembler(in_debug_mode: bool) -> Assembler {
         Assembler::default()
+            .with_debug_mode(in_debug_mode)
             .with_library(&MidenLib::default())
             .expect("failed to load miden-lib")
             .with_library(&StdLibrary::default())
@@ -112,12 +146,24 @@ impl TransactionKernel {
         StackInputs::new(inputs)
     }
 
+    /// Returns the stack with the public outputs of the transaction kernel, arranged as described
+    /// in [TransactionKernel::parse_output_stack()].
     pub fn build_output_stack(
         final_acct_hash: Digest,
         output_notes_hash: Digest,
         tx_script_root: Option<Digest>,
+        expiration_block_num: u32,
+        fee: Option<FungibleAsset>,
     ) -> StackOutputs {
-        let mut outputs: Vec<Felt> = Vec::with_capacity(9);
+        let (fee_faucet_id, fee_amount) = match fee {
+            Some(fee) => (fee.faucet_id().into(), Felt::new(fee.amount())),
+            None => (ZERO, ZERO),
+        };
+
+        let mut outputs: Vec<Felt> = Vec::with_capacity(15);
+        outputs.push(fee_amount);
+        outputs.push(fee_faucet_id);
+        outputs.push(Felt::from(expiration_block_num));
         outputs.extend(final_acct_hash);
         outputs.extend(output_notes_hash);
         outputs.extend(tx_script_root.unwrap_or_default());
@@ -129,20 +175,47 @@ impl TransactionKernel {
     ///
     /// The data on the stack is expected to be arranged as follows:
     ///
-    /// Stack: [TXSR, CNC, FAH]
+    /// Stack: [TXSR, CNC, FAH, expiration_block_num, fee_faucet_id, fee_amount]
     ///
     /// Where:
     /// - TXSR is the transaction script root.
     /// - CNC is the commitment to the notes created by the transaction.
     /// - FAH is the final account hash of the account that the transaction is being
     ///   executed against.
-    pub fn parse_output_stack(stack: &StackOutputs) -> (Digest, Digest, Digest) {
-        // TODO: use constants
-        let tx_script_root = stack.get_stack_word(0).expect("first word missing").into();
-        let output_notes_hash = stack.get_stack_word(4).expect("second word missing").into();
-        let final_account_hash = stack.get_stack_word(8).expect("third word missing").into();
+    /// - expiration_block_num is the block number after which the transaction can no longer be
+    ///   included in a block.
+    /// - fee_faucet_id and fee_amount are the faucet ID and the amount of the fee paid by the
+    ///   transaction; both are zero if the transaction pays no fee.
+    ///
+    /// The fee is returned as a word with the layout of a fungible asset, i.e.,
+    /// [fee_amount, 0, 0, fee_faucet_id].
+    pub fn parse_output_stack(stack: &StackOutputs) -> (Digest, Digest, Digest, Felt, Word) {
+        let tx_script_root = stack
+            .get_stack_word(TX_SCRIPT_ROOT_WORD_IDX * WORD_SIZE)
+            .expect("first word missing")
+            .into();
+        let output_notes_hash = stack
+            .get_stack_word(OUTPUT_NOTES_COMMITMENT_WORD_IDX * WORD_SIZE)
+            .expect("second word missing")
+            .into();
+        let final_account_hash = stack
+            .get_stack_word(FINAL_ACCOUNT_HASH_WORD_IDX * WORD_SIZE)
+            .expect("third word missing")
+            .into();
+        let expiration_block_num = stack
+            .get_stack_item(EXPIRATION_BLOCK_NUM_ELEMENT_IDX)
+            .expect("expiration block number missing");
+        let fee_faucet_id =
+            stack.get_stack_item(FEE_FAUCET_ID_ELEMENT_IDX).expect("fee faucet ID missing");
+        let fee_amount = stack.get_stack_item(FEE_AMOUNT_ELEMENT_IDX).expect("fee amount missing");
 
-        (final_account_hash, output_notes_hash, tx_script_root)
+        (
+            final_account_hash,
+            output_notes_hash,
+            tx_script_root,
+            expiration_block_num,
+            [fee_amount, ZERO, ZERO, fee_faucet_id],
+        )
     }
 
     // TRANSACTION OUTPUT PARSER
@@ -152,21 +225,39 @@ impl TransactionKernel {
     ///
     /// The output stack is expected to be arrange as follows:
     ///
-    /// Stack: [TXSR, CNC, FAH]
+    /// Stack: [TXSR, CNC, FAH, expiration_block_num, fee_faucet_id, fee_amount]
     ///
     /// Where:
     /// - TXSR is the transaction script root.
     /// - CNC is the commitment to the notes created by the transaction.
     /// - FAH is the final account hash of the account that the transaction is being
     ///   executed against.
+    /// - expiration_block_num is the block number after which the transaction can no longer be
+    ///   included in a block.
+    /// - fee_faucet_id and fee_amount are the faucet ID and the amount of the fee paid by the
+    ///   transaction; both are zero if the transaction pays no fee.
     ///
     /// The actual data describing the new account state and output notes is expected to be located
     /// in the provided advice map under keys CNC and FAH.
+    ///
+    /// The transaction script output is not part of the output stack, and thus is not set by this
+    /// function; it is reported to the host when the transaction script returns.
     pub fn parse_transaction_outputs(
         stack: &StackOutputs,
         adv_map: &AdviceMap,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
-        let (final_acct_hash, output_notes_hash, _tx_script_root) = Self::parse_output_stack(stack);
+        let (final_acct_hash, output_notes_hash, _tx_script_root, expiration_block_num, fee) =
+            Self::parse_output_stack(stack);
+
+        let expiration_block_num = u32::try_from(expiration_block_num.as_int())
+            .map_err(|_| TransactionOutputError::InvalidExpirationBlockNum(expiration_block_num))?;
+
+        // an empty fee word means that the transaction pays no fee
+        let fee = if fee == Word::default() {
+            None
+        } else {
+            Some(FungibleAsset::try_from(fee).map_err(TransactionOutputError::InvalidFee)?)
+        };
 
         // --- parse final account state --------------------------------------
         let final_account_data: &[Word] = group_slice_elements(
@@ -208,6 +299,12 @@ impl TransactionKernel {
             output_notes
         };
 
-        Ok(TransactionOutputs { account, output_notes })
+        Ok(TransactionOutputs {
+            account,
+            output_notes,
+            expiration_block_num,
+            fee,
+            tx_script_output: None,
+        })
     }
 }
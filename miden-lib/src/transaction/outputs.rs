@@ -1,7 +1,7 @@
 use miden_objects::{
     accounts::{AccountId, AccountStub},
     assets::Asset,
-    notes::{NoteAssets, NoteId, NoteMetadata},
+    notes::{NoteAssets, NoteId, NoteMetadata, PartialNote},
     transaction::OutputNote,
     utils::collections::Vec,
     AccountError, Digest, NoteError, Word, WORD_SIZE,
@@ -9,9 +9,10 @@ use miden_objects::{
 
 use super::memory::{
     ACCT_CODE_ROOT_OFFSET, ACCT_DATA_MEM_SIZE, ACCT_ID_AND_NONCE_OFFSET, ACCT_ID_IDX,
-    ACCT_NONCE_IDX, ACCT_STORAGE_ROOT_OFFSET, ACCT_VAULT_ROOT_OFFSET, CREATED_NOTE_ASSETS_OFFSET,
-    CREATED_NOTE_ASSET_HASH_OFFSET, CREATED_NOTE_CORE_DATA_SIZE, CREATED_NOTE_ID_OFFSET,
-    CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_NUM_ASSETS_OFFSET, CREATED_NOTE_RECIPIENT_OFFSET,
+    ACCT_NONCE_IDX, ACCT_STORAGE_ROOT_OFFSET, ACCT_VAULT_ROOT_OFFSET,
+    CREATED_NOTE_ASSETS_HASH_OFFSET, CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_CORE_DATA_SIZE,
+    CREATED_NOTE_ID_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_NUM_ASSETS_OFFSET,
+    CREATED_NOTE_RECIPIENT_OFFSET,
 };
 
 // STACK OUTPUTS
@@ -26,6 +27,15 @@ pub const OUTPUT_NOTES_COMMITMENT_WORD_IDX: usize = 1;
 /// The index of the word at which the final account hash is stored on the output stack.
 pub const FINAL_ACCOUNT_HASH_WORD_IDX: usize = 2;
 
+/// The index of the element at which the expiration block number is stored on the output stack.
+pub const EXPIRATION_BLOCK_NUM_ELEMENT_IDX: usize = 12;
+
+/// The index of the element at which the faucet ID of the fee is stored on the output stack.
+pub const FEE_FAUCET_ID_ELEMENT_IDX: usize = 13;
+
+/// The index of the element at which the amount of the fee is stored on the output stack.
+pub const FEE_AMOUNT_ELEMENT_IDX: usize = 14;
+
 // ACCOUNT STUB EXTRACTOR
 // ================================================================================================
 
@@ -57,7 +67,7 @@ pub fn notes_try_from_elements(elements: &[Word]) -> Result<OutputNote, NoteErro
     let metadata: NoteMetadata = elements[CREATED_NOTE_METADATA_OFFSET as usize].try_into()?;
     let recipient = elements[CREATED_NOTE_RECIPIENT_OFFSET as usize].into();
     let num_assets = elements[CREATED_NOTE_NUM_ASSETS_OFFSET as usize][0];
-    let asset_hash: Digest = elements[CREATED_NOTE_ASSET_HASH_OFFSET as usize].into();
+    let asset_hash: Digest = elements[CREATED_NOTE_ASSETS_HASH_OFFSET as usize].into();
 
     if elements.len()
         < (CREATED_NOTE_ASSETS_OFFSET as usize + num_assets.as_int() as usize) * WORD_SIZE
@@ -77,10 +87,10 @@ pub fn notes_try_from_elements(elements: &[Word]) -> Result<OutputNote, NoteErro
         return Err(NoteError::InconsistentStubAssetHash(asset_hash, assets.commitment()));
     }
 
-    let stub = OutputNote::new(recipient, assets, metadata);
+    let stub = PartialNote::new(recipient, assets, metadata);
     if stub.id() != note_id {
         return Err(NoteError::InconsistentStubId(stub.id(), note_id));
     }
 
-    Ok(stub)
+    Ok(OutputNote::Partial(stub))
 }
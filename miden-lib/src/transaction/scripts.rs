@@ -0,0 +1,179 @@
+use miden_objects::{
+    accounts::AccountId,
+    assembly::ProgramAst,
+    assets::Asset,
+    crypto::rand::FeltRng,
+    notes::{Note, NoteDetails, NoteTag, NoteType},
+    utils::{collections::Vec, format, string::String},
+    Digest, Felt, NoteError, Word,
+};
+
+use crate::{
+    accounts::{prepare_word, wallets::build_auth_tx_script},
+    notes::utils::{build_burn_note_details, build_p2id_note_details},
+    AuthScheme,
+};
+
+// CONSUME NOTES SCRIPT
+// ================================================================================================
+
+/// Returns a transaction script which authenticates the consumption of the specified notes using
+/// the specified authentication scheme.
+///
+/// The assets of the consumed notes are moved into the account by the note scripts themselves
+/// (e.g., P2ID notes call `receive_asset` of the basic wallet); thus, the transaction script only
+/// needs to authenticate the transaction. The script can be executed against any account which
+/// exposes the authentication procedure of the specified scheme.
+///
+/// If `allowed_scripts` is provided, the notes are checked against it before the script is built:
+/// consuming notes with arbitrary scripts executes arbitrary code against the account, and thus
+/// wallets will usually want to consume only notes with well-known scripts (see
+/// [WellKnownScript](crate::notes::scripts::WellKnownScript)).
+///
+/// # Errors
+/// Returns an error if the script of any of the notes is not in `allowed_scripts`.
+pub fn build_consume_notes_script(
+    notes: &[Note],
+    allowed_scripts: Option<&[Digest]>,
+    auth_scheme: &AuthScheme,
+) -> Result<ProgramAst, NoteError> {
+    if let Some(allowed_scripts) = allowed_scripts {
+        for note in notes {
+            let script_hash = note.script().hash();
+            if !allowed_scripts.contains(&script_hash) {
+                return Err(NoteError::NoteScriptNotAllowed(note.id(), script_hash));
+            }
+        }
+    }
+
+    Ok(build_auth_tx_script(auth_scheme))
+}
+
+// BURN SCRIPT
+// ================================================================================================
+
+/// Returns a transaction script which sends the specified asset out of a basic wallet back to the
+/// faucet which issued it for burning, and authenticates the transaction using the specified
+/// authentication scheme, together with the details of the BURN note created by the script.
+///
+/// The asset is burned, and the issued supply of a fungible faucet is decreased accordingly, once
+/// the faucet consumes the BURN note (see
+/// [create_burn_note()](crate::notes::create_burn_note)); consuming the note does not require
+/// the authentication of the faucet owner. The serial number of the note is drawn from the
+/// passed-in `rng`.
+///
+/// The BURN note is a public note. The script can be executed against any account which exposes
+/// the `send_asset` procedure of the basic wallet and the authentication procedure of the
+/// specified scheme.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `BURN` script fails.
+pub fn build_burn_tx_script<R: FeltRng>(
+    asset: Asset,
+    auth_scheme: &AuthScheme,
+    mut rng: R,
+) -> Result<(ProgramAst, NoteDetails), NoteError> {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+        AuthScheme::RpoFalcon512Multisig { .. } => "auth_tx_rpo_falcon512_multisig",
+        AuthScheme::RpoFalcon512Threshold { .. } => "auth_tx_rpo_falcon512_threshold",
+        AuthScheme::SessionKey { .. } => "auth_tx_rpo_falcon512_session_key",
+        AuthScheme::NoAuth => "auth_tx_no_auth",
+    };
+
+    let note = build_burn_note_details(asset, rng.draw_word())?;
+    let recipient: Word = note.recipient().into();
+    let tag = NoteTag::for_account(asset.faucet_id()).inner();
+    let asset: Word = asset.into();
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::wallets::basic->wallet
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        push.{recipient} push.0 push.{note_type} push.{tag} push.{asset}
+        call.wallet::send_asset drop dropw dropw drop drop
+        call.auth_tx::{auth_scheme_procedure}
+    end
+    ",
+        recipient = prepare_word(&recipient),
+        note_type = Felt::from(NoteType::Public),
+        asset = prepare_word(&asset)
+    );
+
+    let tx_script =
+        ProgramAst::parse(&tx_script_src).expect("burn transaction script is well formed");
+
+    Ok((tx_script, note))
+}
+
+// SEND NOTES SCRIPT
+// ================================================================================================
+
+/// Returns a transaction script which sends the specified payments out of a basic wallet and
+/// authenticates the transaction using the specified authentication scheme, together with the
+/// details of the notes created by the script.
+///
+/// Every payment is a `(target, assets)` pair. The assets are sent to the target account via public
+/// P2ID notes tagged with [NoteTag::for_account()]; since a note created by a transaction carries a
+/// single asset, one note is created for every asset of a payment. The serial numbers of the notes
+/// are drawn from the passed-in `rng`, and the returned note details are ordered in the same way as
+/// the notes created by the transaction.
+///
+/// The script can be executed against any account which exposes the `send_asset` procedure of the
+/// basic wallet and the authentication procedure of the specified scheme (e.g., accounts created
+/// via [create_basic_wallet()](crate::accounts::wallets::create_basic_wallet)).
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2ID` script fails.
+pub fn build_send_notes_script<R: FeltRng>(
+    payments: &[(AccountId, Vec<Asset>)],
+    auth_scheme: &AuthScheme,
+    mut rng: R,
+) -> Result<(ProgramAst, Vec<NoteDetails>), NoteError> {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+        AuthScheme::RpoFalcon512Multisig { .. } => "auth_tx_rpo_falcon512_multisig",
+        AuthScheme::RpoFalcon512Threshold { .. } => "auth_tx_rpo_falcon512_threshold",
+        AuthScheme::SessionKey { .. } => "auth_tx_rpo_falcon512_session_key",
+        AuthScheme::NoAuth => "auth_tx_no_auth",
+    };
+
+    let mut notes = Vec::new();
+    let mut send_assets = String::new();
+    for (target, assets) in payments {
+        let tag = NoteTag::for_account(*target).inner();
+        for asset in assets {
+            let details = build_p2id_note_details(*target, *asset, rng.draw_word())?;
+            let recipient: Word = details.recipient().into();
+            let asset: Word = (*asset).into();
+
+            send_assets.push_str(&format!(
+                "push.{recipient} push.0 push.{note_type} push.{tag} push.{asset}
+                call.wallet::send_asset drop dropw dropw drop drop\n",
+                recipient = prepare_word(&recipient),
+                note_type = Felt::from(NoteType::Public),
+                asset = prepare_word(&asset)
+            ));
+            notes.push(details);
+        }
+    }
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::wallets::basic->wallet
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        {send_assets}
+        call.auth_tx::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    let tx_script =
+        ProgramAst::parse(&tx_script_src).expect("send notes transaction script is well formed");
+
+    Ok((tx_script, notes))
+}
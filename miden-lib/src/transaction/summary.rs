@@ -0,0 +1,412 @@
+use core::fmt;
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    notes::NoteId,
+    transaction::{ExecutedTransaction, OutputNote, TransactionId},
+    utils::collections::{BTreeMap, Vec},
+    Digest, StarkField,
+};
+
+use crate::notes::scripts::WellKnownScript;
+
+// TRANSACTION SUMMARY
+// ================================================================================================
+
+/// Defines how a [TransactionSummary] can be derived from self.
+pub trait ToTransactionSummary {
+    /// Returns a human-readable summary of the effects of the transaction.
+    fn summary(&self) -> TransactionSummary;
+}
+
+/// A summary of the effects of a transaction, meant to be presented to the user of a wallet (e.g.,
+/// "you are about to send 100 TOKEN_A and receive a swap note").
+///
+/// The summary describes:
+/// - The assets which entered and left the account vault, aggregated per faucet.
+/// - The notes consumed and created by the transaction, with their scripts classified against the
+///   [well-known scripts](WellKnownScript).
+/// - The storage slots changed by the transaction.
+/// - The increment of the account nonce, and the fee paid by the transaction.
+///
+/// A summary can be displayed as text via its [Display](fmt::Display) implementation, or, with
+/// the `json` feature enabled, encoded as JSON via [TransactionSummary::to_json()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionSummary {
+    transaction_id: TransactionId,
+    account_id: AccountId,
+    assets: Vec<AssetFlow>,
+    consumed_notes: Vec<NoteSummary>,
+    created_notes: Vec<NoteSummary>,
+    storage_slots: Vec<u8>,
+    nonce_delta: u64,
+    fee: Option<FungibleAsset>,
+}
+
+impl TransactionSummary {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the transaction.
+    pub fn transaction_id(&self) -> TransactionId {
+        self.transaction_id
+    }
+
+    /// Returns the ID of the account against which the transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the assets which entered and left the account vault, ordered by faucet ID.
+    pub fn assets(&self) -> &[AssetFlow] {
+        &self.assets
+    }
+
+    /// Returns the notes consumed by the transaction.
+    pub fn consumed_notes(&self) -> &[NoteSummary] {
+        &self.consumed_notes
+    }
+
+    /// Returns the notes created by the transaction.
+    pub fn created_notes(&self) -> &[NoteSummary] {
+        &self.created_notes
+    }
+
+    /// Returns the indexes of the storage slots changed by the transaction, in ascending order.
+    pub fn storage_slots(&self) -> &[u8] {
+        &self.storage_slots
+    }
+
+    /// Returns the amount by which the transaction incremented the account nonce.
+    pub fn nonce_delta(&self) -> u64 {
+        self.nonce_delta
+    }
+
+    /// Returns the fee paid by the transaction, if any.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.fee
+    }
+}
+
+impl ToTransactionSummary for ExecutedTransaction {
+    fn summary(&self) -> TransactionSummary {
+        let diff = self.diff();
+
+        // aggregate vault changes per faucet; non-fungible assets are counted
+        let mut assets: BTreeMap<AccountId, AssetFlow> = BTreeMap::new();
+        for (&faucet_id, &amount) in diff.fungible_assets_in() {
+            asset_flow(&mut assets, faucet_id).amount_in += amount;
+        }
+        for (&faucet_id, &amount) in diff.fungible_assets_out() {
+            asset_flow(&mut assets, faucet_id).amount_out += amount;
+        }
+        for asset in diff.non_fungible_assets_in() {
+            asset_flow(&mut assets, asset.faucet_id()).amount_in += 1;
+        }
+        for asset in diff.non_fungible_assets_out() {
+            asset_flow(&mut assets, asset.faucet_id()).amount_out += 1;
+        }
+
+        // classify notes against the well-known scripts; the hashes of the scripts are computed
+        // only once, since computing them requires building the scripts
+        let has_notes = !self.input_notes().is_empty() || !self.output_notes().is_empty();
+        let well_known_scripts: Vec<_> = if has_notes {
+            WellKnownScript::ALL.iter().map(|script| (*script, script.hash())).collect()
+        } else {
+            Vec::new()
+        };
+        let classify = |script_root: Digest| {
+            well_known_scripts
+                .iter()
+                .find(|(_, hash)| *hash == script_root)
+                .map(|(script, _)| NoteScriptKind::WellKnown(*script))
+                .unwrap_or(NoteScriptKind::Custom)
+        };
+
+        let consumed_notes = self
+            .input_notes()
+            .iter()
+            .map(|input_note| {
+                let note = input_note.note();
+                NoteSummary {
+                    id: note.id(),
+                    script: classify(note.script().hash()),
+                    script_root: Some(note.script().hash()),
+                    assets: Some(note.assets().iter().copied().collect()),
+                }
+            })
+            .collect();
+
+        let created_notes = self
+            .output_notes()
+            .iter()
+            .map(|note| {
+                let script_root = match note {
+                    OutputNote::Full(note) => Some(note.script().hash()),
+                    OutputNote::Partial(_) | OutputNote::Header(_) => None,
+                };
+                NoteSummary {
+                    id: note.id(),
+                    script: script_root.map(classify).unwrap_or(NoteScriptKind::Unknown),
+                    script_root,
+                    assets: note.assets().map(|assets| assets.iter().copied().collect()),
+                }
+            })
+            .collect();
+
+        let nonce_delta = diff
+            .nonce_change()
+            .map(|(before, after)| after.as_int() - before.as_int())
+            .unwrap_or_default();
+
+        TransactionSummary {
+            transaction_id: self.id(),
+            account_id: self.account_id(),
+            assets: assets.into_values().collect(),
+            consumed_notes,
+            created_notes,
+            storage_slots: diff.storage_changes().iter().map(|change| change.index).collect(),
+            nonce_delta,
+            fee: self.fee(),
+        }
+    }
+}
+
+impl fmt::Display for TransactionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "transaction {} against account {}", self.transaction_id, self.account_id)?;
+
+        if !self.assets.is_empty() {
+            writeln!(f, "assets:")?;
+            for flow in self.assets.iter() {
+                writeln!(f, "  {}: +{} -{}", flow.faucet_id, flow.amount_in, flow.amount_out)?;
+            }
+        }
+
+        for (title, notes) in
+            [("consumed notes", &self.consumed_notes), ("created notes", &self.created_notes)]
+        {
+            if !notes.is_empty() {
+                writeln!(f, "{title}:")?;
+                for note in notes.iter() {
+                    writeln!(f, "  {note}")?;
+                }
+            }
+        }
+
+        if !self.storage_slots.is_empty() {
+            write!(f, "storage slots changed:")?;
+            for slot in self.storage_slots.iter() {
+                write!(f, " {slot}")?;
+            }
+            writeln!(f)?;
+        }
+
+        if let Some(fee) = self.fee {
+            writeln!(f, "fee: {} of {}", fee.amount(), fee.faucet_id())?;
+        }
+
+        write!(f, "nonce incremented by {}", self.nonce_delta)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the flow of the assets issued by the specified faucet, inserting an empty flow if
+/// there is none.
+fn asset_flow(assets: &mut BTreeMap<AccountId, AssetFlow>, faucet_id: AccountId) -> &mut AssetFlow {
+    assets
+        .entry(faucet_id)
+        .or_insert(AssetFlow { faucet_id, amount_in: 0, amount_out: 0 })
+}
+
+// ASSET FLOW
+// ================================================================================================
+
+/// The amounts of the assets issued by a single faucet which entered and left the account vault
+/// during a transaction.
+///
+/// For non-fungible faucets, the amounts are the numbers of assets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetFlow {
+    pub faucet_id: AccountId,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+// NOTE SUMMARY
+// ================================================================================================
+
+/// The classification of the script of a note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteScriptKind {
+    /// One of the scripts provided by this library.
+    WellKnown(WellKnownScript),
+    /// A script which is not provided by this library.
+    Custom,
+    /// The script of the note is not known, i.e., only the header or the recipient of the note
+    /// is known.
+    Unknown,
+}
+
+impl fmt::Display for NoteScriptKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WellKnown(script) => write!(f, "{script:?}"),
+            Self::Custom => write!(f, "custom script"),
+            Self::Unknown => write!(f, "unknown script"),
+        }
+    }
+}
+
+/// A summary of a note consumed or created by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteSummary {
+    id: NoteId,
+    script: NoteScriptKind,
+    script_root: Option<Digest>,
+    assets: Option<Vec<Asset>>,
+}
+
+impl NoteSummary {
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.id
+    }
+
+    /// Returns the classification of the script of the note.
+    pub fn script(&self) -> NoteScriptKind {
+        self.script
+    }
+
+    /// Returns the root of the script of the note, or None if the script is not known.
+    pub fn script_root(&self) -> Option<Digest> {
+        self.script_root
+    }
+
+    /// Returns the assets of the note, or None if only the header of the note is known.
+    pub fn assets(&self) -> Option<&[Asset]> {
+        self.assets.as_deref()
+    }
+}
+
+impl fmt::Display for NoteSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.id, self.script)?;
+        match &self.assets {
+            Some(assets) => {
+                for asset in assets.iter() {
+                    match asset {
+                        Asset::Fungible(asset) => {
+                            write!(f, ", {} of {}", asset.amount(), asset.faucet_id())?
+                        },
+                        Asset::NonFungible(asset) => {
+                            write!(f, ", non-fungible asset of {}", asset.faucet_id())?
+                        },
+                    }
+                }
+                Ok(())
+            },
+            None => write!(f, ", assets unknown"),
+        }
+    }
+}
+
+// JSON ENCODING
+// ================================================================================================
+
+#[cfg(feature = "json")]
+impl TransactionSummary {
+    /// Returns the JSON encoding of this summary.
+    ///
+    /// The encoding follows the conventions of the [canonical JSON encoding of
+    /// transactions](miden_objects::json), and has the `"transaction_summary"` type:
+    ///
+    /// ```json
+    /// {
+    ///   "version": 1,
+    ///   "type": "transaction_summary",
+    ///   "id": "0x...",
+    ///   "account_id": "0x...",
+    ///   "assets": [{ "faucet_id": "0x...", "amount_in": "100", "amount_out": "0" }, ...],
+    ///   "consumed_notes": [{ "id": "0x...", "script": "P2ID", "script_root": "0x...",
+    ///                        "assets": [...] }, ...],
+    ///   "created_notes": [{ "id": "0x...", "script": "unknown", "script_root": null,
+    ///                       "assets": null }, ...],
+    ///   "storage_slots": [0, 3],
+    ///   "nonce_delta": 1,
+    ///   "fee": { "faucet_id": "0x...", "amount": "1234" } | null
+    /// }
+    /// ```
+    ///
+    /// Fungible assets of notes are encoded as `{ "faucet_id": "0x...", "amount": "100" }`, and
+    /// non-fungible assets as `{ "faucet_id": "0x...", "data": "0x<asset word>" }`. The `script`
+    /// field of a note contains the name of a well-known script, `"custom"` or `"unknown"`.
+    pub fn to_json(&self) -> miden_objects::utils::string::String {
+        use miden_objects::{json::JSON_ENCODING_VERSION, utils::string::ToString};
+        use serde_json::json;
+
+        json!({
+            "version": JSON_ENCODING_VERSION,
+            "type": "transaction_summary",
+            "id": self.transaction_id.to_hex(),
+            "account_id": self.account_id.to_hex(),
+            "assets": self
+                .assets
+                .iter()
+                .map(|flow| {
+                    json!({
+                        "faucet_id": flow.faucet_id.to_hex(),
+                        "amount_in": flow.amount_in.to_string(),
+                        "amount_out": flow.amount_out.to_string(),
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "consumed_notes": self.consumed_notes.iter().map(encode_note).collect::<Vec<_>>(),
+            "created_notes": self.created_notes.iter().map(encode_note).collect::<Vec<_>>(),
+            "storage_slots": self.storage_slots,
+            "nonce_delta": self.nonce_delta,
+            "fee": self.fee.map(|fee| encode_asset(&Asset::Fungible(fee))),
+        })
+        .to_string()
+    }
+}
+
+#[cfg(feature = "json")]
+fn encode_note(note: &NoteSummary) -> serde_json::Value {
+    use miden_objects::utils::format;
+
+    let script = match note.script {
+        NoteScriptKind::WellKnown(script) => format!("{script:?}"),
+        NoteScriptKind::Custom => "custom".into(),
+        NoteScriptKind::Unknown => "unknown".into(),
+    };
+
+    serde_json::json!({
+        "id": note.id.to_hex(),
+        "script": script,
+        "script_root": note.script_root.map(|root| root.to_hex()),
+        "assets": note
+            .assets
+            .as_ref()
+            .map(|assets| assets.iter().map(encode_asset).collect::<Vec<_>>()),
+    })
+}
+
+#[cfg(feature = "json")]
+fn encode_asset(asset: &Asset) -> serde_json::Value {
+    use miden_objects::{utils::string::ToString, Word};
+
+    match asset {
+        Asset::Fungible(asset) => serde_json::json!({
+            "faucet_id": asset.faucet_id().to_hex(),
+            "amount": asset.amount().to_string(),
+        }),
+        Asset::NonFungible(asset) => serde_json::json!({
+            "faucet_id": asset.faucet_id().to_hex(),
+            "data": Digest::from(Word::from(*asset)).to_hex(),
+        }),
+    }
+}
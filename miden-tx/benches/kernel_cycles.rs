@@ -0,0 +1,333 @@
+//! Measures the number of VM cycles and the execution time of the standard transaction flows.
+//!
+//! Run with `cargo bench -p miden-tx --features testing --bench kernel_cycles`. The cycle counts
+//! are deterministic, and thus can be compared across changes to the transaction kernel.
+
+use std::time::Duration;
+
+use miden_lib::{
+    accounts::wallets::build_fill_swap_tx_script,
+    notes::{create_p2id_note, create_swap_note},
+    transaction::TransactionKernel,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{Account, AccountCode, AccountId, AccountStorage, StorageSlotType},
+    assembly::{ModuleAst, ProgramAst},
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{
+        dsa::rpo_falcon512::KeyPair, merkle::MerklePath, rand::RpoRandomCoin, utils::Serializable,
+    },
+    notes::{Note, NoteId, NoteScript, NoteTag, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
+    BlockHeader, Felt, Word, ZERO,
+};
+use miden_tx::{
+    testing::{measure_cycles, CycleMeasurement},
+    DataStore, DataStoreError, TransactionExecutor,
+};
+use mock::{
+    constants::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        DEFAULT_ACCOUNT_CODE, DEFAULT_AUTH_SCRIPT,
+    },
+    mock::chain::MockChain,
+    utils::prepare_word,
+};
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
+
+/// Number of times each transaction is executed to measure its execution time.
+const NUM_ITERATIONS: u32 = 10;
+
+const RECEIVE_NOTE_SCRIPT: &str = "
+    use.miden::note
+    use.miden::contracts::wallets::basic->wallet
+
+    begin
+        dropw
+        exec.note::get_assets drop
+        mem_loadw
+        call.wallet::receive_asset
+        dropw
+    end
+";
+
+fn main() {
+    let flows: [(&str, fn() -> ExecutedTransaction); 4] = [
+        ("wallet receive", wallet_receive),
+        ("p2id consume", p2id_consume),
+        ("swap fill", swap_fill),
+        ("faucet mint", faucet_mint),
+    ];
+
+    println!(
+        "{:<16} {:>10} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12}",
+        "flow", "prologue", "notes", "tx script", "epilogue", "total", "trace len", "time (ms)"
+    );
+    for (name, build_transaction) in flows {
+        let transaction = build_transaction();
+        let measurement = measure(&transaction);
+        let measurements = &measurement.measurements;
+        println!(
+            "{:<16} {:>10} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12.2}",
+            name,
+            measurements.prologue,
+            measurements.notes_processing,
+            measurements.tx_script_processing,
+            measurements.epilogue,
+            measurement.total_cycles(),
+            measurements.trace_length,
+            measurement.execution_time.as_secs_f64() * 1000.0,
+        );
+    }
+}
+
+/// Measures the provided transaction [NUM_ITERATIONS] times and returns the cycle counts
+/// together with the mean execution time.
+fn measure(transaction: &ExecutedTransaction) -> CycleMeasurement {
+    let mut total_time = Duration::ZERO;
+    let mut measurement = None;
+    for _ in 0..NUM_ITERATIONS {
+        let result = measure_cycles(transaction).expect("failed to measure transaction");
+        total_time += result.execution_time;
+        measurement = Some(result);
+    }
+
+    let mut measurement = measurement.expect("no iterations");
+    measurement.execution_time = total_time / NUM_ITERATIONS;
+    measurement
+}
+
+// FLOWS
+// ================================================================================================
+
+/// A wallet consumes a note which moves its asset into the wallet.
+fn wallet_receive() -> ExecutedTransaction {
+    let (account, key_pair) = wallet_account();
+    let asset = fungible_asset(100);
+
+    let code = ProgramAst::parse(RECEIVE_NOTE_SCRIPT).unwrap();
+    let (script, _) = NoteScript::new(code, &TransactionKernel::assembler()).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let note = Note::new(script, &[], &[asset], serial_num, sender, NoteTag::from(1)).unwrap();
+
+    execute(account, &key_pair, vec![note], |_| {
+        ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap()
+    })
+}
+
+/// A wallet consumes a P2ID note targeting it.
+fn p2id_consume() -> ExecutedTransaction {
+    let (account, key_pair) = wallet_account();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note = create_p2id_note(
+        sender,
+        account.id(),
+        vec![fungible_asset(100)],
+        NoteType::Public,
+        ZERO,
+        rng_with_seed(1),
+    )
+    .unwrap();
+
+    execute(account, &key_pair, vec![note], |_| {
+        ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap()
+    })
+}
+
+/// A wallet fills a SWAP note, creating the payback note for the creator of the SWAP note.
+fn swap_fill() -> ExecutedTransaction {
+    let requested_asset = fungible_asset(50);
+    let (mut account, key_pair) = wallet_account();
+    account = Account::new(
+        account.id(),
+        AssetVault::new(&[requested_asset]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        account.nonce(),
+    );
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let offered_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let offered_asset = FungibleAsset::new(offered_faucet_id, 100).unwrap().into();
+    let (note, _) = create_swap_note(
+        sender,
+        offered_asset,
+        requested_asset,
+        None,
+        NoteType::Public,
+        ZERO,
+        rng_with_seed(2),
+    )
+    .unwrap();
+
+    execute(account, &key_pair, vec![note], |key_pair| {
+        build_fill_swap_tx_script(&AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() })
+    })
+}
+
+/// A fungible faucet mints an asset into a new note.
+fn faucet_mint() -> ExecutedTransaction {
+    let key_pair = KeyPair::new().unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let code = ModuleAst::parse(include_str!(
+        "../../miden-lib/asm/miden/contracts/faucets/basic_fungible.masm"
+    ))
+    .unwrap();
+    let code = AccountCode::new(code, &TransactionKernel::assembler()).unwrap();
+    let max_supply = [Felt::new(1_000_000), Felt::new(0), Felt::new(0), Felt::new(0)];
+    let storage = AccountStorage::new(vec![
+        (0, (StorageSlotType::Value { value_arity: 0 }, key_pair.public_key().into())),
+        (1, (StorageSlotType::Value { value_arity: 0 }, max_supply)),
+    ])
+    .unwrap();
+    let account =
+        Account::new(faucet_id, AssetVault::new(&[]).unwrap(), storage, code, Felt::new(1));
+
+    execute(account, &key_pair, vec![], |_| {
+        let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+        ProgramAst::parse(&format!(
+            "
+            use.miden::contracts::faucets::basic_fungible->faucet
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+                push.{recipient}
+                push.0
+                push.{note_type}
+                push.{tag}
+                push.{amount}
+                call.faucet::distribute
+
+                call.auth_tx::auth_tx_rpo_falcon512
+                dropw dropw drop drop
+            end
+            ",
+            recipient = prepare_word(&recipient),
+            note_type = Felt::from(NoteType::Public),
+            tag = NoteTag::from(4),
+            amount = 100,
+        ))
+        .unwrap()
+    })
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes a transaction against the provided account which consumes the specified notes and
+/// runs the transaction script built by `build_tx_script`, signing the transaction with the
+/// provided key pair.
+fn execute(
+    account: Account,
+    key_pair: &KeyPair,
+    notes: Vec<Note>,
+    build_tx_script: impl FnOnce(&KeyPair) -> ProgramAst,
+) -> ExecutedTransaction {
+    let account_id = account.id();
+
+    // record the account and the notes in a mock chain, and execute the transaction against the
+    // block following the one in which the notes were recorded
+    let mut mock_chain = MockChain::new(Pcg64::seed_from_u64(0));
+    mock_chain.add_account(account, Word::default());
+    for note in notes.iter() {
+        mock_chain.add_note(note.clone()).unwrap();
+    }
+    mock_chain.seal_block();
+    let block_ref = mock_chain.seal_block().block_num();
+
+    let note_ids = notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let tx_inputs = mock_chain.transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    let mut executor = TransactionExecutor::new(InputsDataStore(tx_inputs));
+    executor.load_account(account_id).unwrap();
+
+    let pub_key: Word = key_pair.public_key().into();
+    let secret_key = key_pair.to_bytes().iter().map(|byte| Felt::new(*byte as u64)).collect();
+    let tx_script = executor
+        .compile_tx_script(build_tx_script(key_pair), vec![(pub_key, secret_key)], vec![])
+        .unwrap();
+
+    executor
+        .execute_transaction(
+            account_id,
+            block_ref,
+            &note_ids,
+            Some(TransactionArgs::with_tx_script(tx_script)),
+        )
+        .unwrap()
+}
+
+/// Returns an existing wallet account with the default account code, together with the key pair
+/// authenticating its transactions.
+fn wallet_account() -> (Account, KeyPair) {
+    let key_pair = KeyPair::new().unwrap();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let code = ModuleAst::parse(DEFAULT_ACCOUNT_CODE).unwrap();
+    let code = AccountCode::new(code, &TransactionKernel::assembler()).unwrap();
+    let storage = AccountStorage::new(vec![(
+        0,
+        (StorageSlotType::Value { value_arity: 0 }, key_pair.public_key().into()),
+    )])
+    .unwrap();
+    let account =
+        Account::new(account_id, AssetVault::new(&[]).unwrap(), storage, code, Felt::new(1));
+
+    (account, key_pair)
+}
+
+fn fungible_asset(amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+fn rng_with_seed(seed: u64) -> RpoRandomCoin {
+    RpoRandomCoin::new([Felt::new(seed), Felt::new(0), Felt::new(0), Felt::new(0)])
+}
+
+// DATA STORE
+// ================================================================================================
+
+/// A [DataStore] which serves the inputs of a single transaction.
+struct InputsDataStore(TransactionInputs);
+
+impl DataStore for InputsDataStore {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        assert_eq!(account_id, self.0.account().id());
+        assert_eq!(block_num, self.0.block_header().block_num());
+        assert_eq!(notes.len(), self.0.input_notes().num_notes());
+        Ok(self.0.clone())
+    }
+
+    fn get_block_headers(
+        &self,
+        block_ref: u32,
+        block_nums: &[u32],
+    ) -> Result<Vec<(BlockHeader, MerklePath)>, DataStoreError> {
+        assert_eq!(block_ref, self.0.block_header().block_num());
+        let block_chain = self.0.block_chain();
+        block_nums
+            .iter()
+            .map(|&block_num| {
+                let block_header = block_chain
+                    .get_block(block_num)
+                    .ok_or(DataStoreError::BlockNotFound(block_num))?;
+                let path = block_chain.open(block_num).unwrap().merkle_path;
+                Ok((*block_header, path))
+            })
+            .collect()
+    }
+
+    fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
+        assert_eq!(account_id, self.0.account().id());
+        Ok(self.0.account().code().module().clone())
+    }
+}
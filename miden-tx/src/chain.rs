@@ -0,0 +1,333 @@
+use miden_objects::{
+    accounts::{Account, AccountId},
+    block::NullifierTree,
+    crypto::merkle::{LeafIndex, Mmr, SimpleSmt},
+    notes::{Note, NoteDetails, NoteId, NoteInclusionProof, NoteMetadata},
+    transaction::{ChainMmr, ExecutedTransaction, InputNote, OutputNote, TransactionArgs},
+    utils::collections::{BTreeMap, BTreeSet, Vec},
+    BlockHeader, BlockHeaderBuilder, Digest, Felt, Hasher, Word, ACCOUNT_TREE_DEPTH,
+    NOTE_TREE_DEPTH,
+};
+
+use crate::{stores::MemoryDataStore, LocalChainError, TransactionExecutor};
+
+// PROPOSED TRANSACTION
+// ================================================================================================
+
+/// A transaction to be executed by a [LocalChainSimulator] against the latest block of the local
+/// chain.
+#[derive(Debug, Clone)]
+pub struct ProposedTransaction {
+    account_id: AccountId,
+    input_notes: Vec<NoteId>,
+    tx_args: Option<TransactionArgs>,
+}
+
+impl ProposedTransaction {
+    /// Returns a new [ProposedTransaction] which executes against the specified account, consuming
+    /// the specified notes.
+    pub fn new(
+        account_id: AccountId,
+        input_notes: Vec<NoteId>,
+        tx_args: Option<TransactionArgs>,
+    ) -> Self {
+        Self { account_id, input_notes, tx_args }
+    }
+
+    /// Returns the ID of the account against which the transaction is executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the IDs of the notes consumed by the transaction.
+    pub fn input_notes(&self) -> &[NoteId] {
+        &self.input_notes
+    }
+
+    /// Returns the arguments of the transaction.
+    pub fn tx_args(&self) -> Option<&TransactionArgs> {
+        self.tx_args.as_ref()
+    }
+}
+
+// LOCAL CHAIN SIMULATOR
+// ================================================================================================
+
+/// A local chain against which a sequence of transactions can be executed, each transaction
+/// observing the effects of the transactions executed before it.
+///
+/// Every executed transaction is followed by a mocked block: the account delta of the transaction
+/// is applied to the account, the nullifiers of its input notes are recorded, and its output notes
+/// are added to the note tree of the new block. Block headers are otherwise mocked: the account,
+/// nullifier and note roots and the chain root commit to the state of the local chain, but the
+/// batch root and the proof hash are empty.
+///
+/// The transaction kernel outputs only the recipients of the notes created by a transaction;
+/// thus, an output note can be consumed by a subsequent transaction only if its details are known
+/// to the simulator, either because the note is output in full, or because its details have been
+/// registered via [LocalChainSimulator::add_note_details()]. This makes it possible to test
+/// multi-step protocols (e.g., a SWAP note being filled, followed by the maker consuming the
+/// payback note) without stitching the state together manually.
+pub struct LocalChainSimulator {
+    executor: TransactionExecutor<MemoryDataStore>,
+    data_store: MemoryDataStore,
+    chain: Mmr,
+    blocks: Vec<BlockHeader>,
+    account_ids: BTreeSet<AccountId>,
+    nullifiers: NullifierTree,
+    pending_notes: Vec<(NoteId, NoteMetadata, Option<NoteDetails>)>,
+    created_notes: BTreeMap<NoteId, (NoteMetadata, NoteInclusionProof)>,
+    note_details: BTreeMap<NoteId, NoteDetails>,
+}
+
+impl LocalChainSimulator {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [LocalChainSimulator] consisting of an empty genesis block.
+    pub fn new() -> Self {
+        let data_store = MemoryDataStore::new();
+        let mut simulator = Self {
+            executor: TransactionExecutor::new(data_store.clone()),
+            data_store,
+            chain: Mmr::default(),
+            blocks: Vec::new(),
+            account_ids: BTreeSet::new(),
+            nullifiers: NullifierTree::new(),
+            pending_notes: Vec::new(),
+            created_notes: BTreeMap::new(),
+            note_details: BTreeMap::new(),
+        };
+        simulator.seal_block();
+        simulator
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the executor used to execute transactions against the local chain, e.g., to
+    /// compile transaction scripts.
+    pub fn executor(&self) -> &TransactionExecutor<MemoryDataStore> {
+        &self.executor
+    }
+
+    /// Returns the data store holding the state of the local chain.
+    pub fn data_store(&self) -> &MemoryDataStore {
+        &self.data_store
+    }
+
+    /// Returns the current state of the account with the specified ID, if the account is known
+    /// to the local chain.
+    pub fn account(&self, account_id: AccountId) -> Option<Account> {
+        self.data_store.account(account_id)
+    }
+
+    /// Returns the unspent note with the specified ID, if the note has been included in a block
+    /// and its details are known.
+    pub fn note(&self, note_id: NoteId) -> Option<InputNote> {
+        self.data_store.note(note_id)
+    }
+
+    /// Returns the header of the latest block of the local chain.
+    pub fn latest_block_header(&self) -> BlockHeader {
+        *self.blocks.last().expect("the local chain contains the genesis block")
+    }
+
+    /// Returns the root of the nullifier tree of the local chain.
+    pub fn nullifier_root(&self) -> Digest {
+        self.nullifiers.root()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided account to the local chain and loads its code into the executor.
+    ///
+    /// The seed of the account must be provided for new accounts, and must be None for existing
+    /// accounts.
+    ///
+    /// # Errors
+    /// Returns an error if the code of the account cannot be loaded into the executor.
+    pub fn add_account(
+        &mut self,
+        account: Account,
+        seed: Option<Word>,
+    ) -> Result<(), LocalChainError> {
+        let account_id = account.id();
+        self.data_store.insert_account(account, seed);
+        self.executor
+            .load_account(account_id)
+            .map_err(LocalChainError::LoadAccountFailed)?;
+        self.account_ids.insert(account_id);
+        Ok(())
+    }
+
+    /// Adds the provided note to the next block of the local chain; the note can be consumed once
+    /// the block has been sealed.
+    pub fn add_note(&mut self, note: &Note) {
+        self.pending_notes.push((note.id(), *note.metadata(), Some(note.into())));
+    }
+
+    /// Registers the details of a note which is expected to be created by a transaction, so that
+    /// the note can be consumed once it has been included in a block.
+    ///
+    /// If the note has already been included in a block, it becomes consumable immediately.
+    pub fn add_note_details(&mut self, details: NoteDetails) {
+        match self.created_notes.get(&details.id()) {
+            Some((metadata, proof)) => {
+                let note = details.into_input_note(*metadata, proof.clone());
+                self.data_store.insert_note(note);
+            },
+            None => {
+                self.note_details.insert(details.id(), details);
+            },
+        }
+    }
+
+    /// Seals a new block containing the notes added since the previous block, and returns the
+    /// header of the new block.
+    pub fn seal_block(&mut self) -> BlockHeader {
+        let block_num = self.blocks.len() as u32;
+        let notes = build_note_tree(&self.pending_notes);
+
+        let builder = match self.blocks.last() {
+            Some(prev) => BlockHeaderBuilder::next(prev),
+            None => BlockHeaderBuilder::new(),
+        };
+        let chain_root = self.chain.peaks(self.chain.forest()).unwrap().hash_peaks();
+        let header = builder
+            .chain_root(chain_root)
+            .account_root(self.build_account_tree().root())
+            .nullifier_root(self.nullifiers.root())
+            .note_root(notes.root())
+            .timestamp(Felt::from(block_num))
+            .build();
+
+        // record the created notes, making the notes with known details consumable
+        for (index, (note_id, metadata, details)) in self.pending_notes.drain(..).enumerate() {
+            let leaf_index = LeafIndex::new(index as u64).expect("index is within the note tree");
+            let proof = NoteInclusionProof::new(
+                block_num,
+                header.sub_hash(),
+                header.note_root(),
+                index as u64,
+                notes.open(&leaf_index).path,
+            )
+            .expect("note index is within the note tree");
+
+            if let Some(details) = details.or_else(|| self.note_details.remove(&note_id)) {
+                self.data_store.insert_note(details.into_input_note(metadata, proof.clone()));
+            }
+            self.created_notes.insert(note_id, (metadata, proof));
+        }
+
+        // the chain MMR of a block commits to all blocks preceding it
+        self.data_store.insert_block(
+            header,
+            ChainMmr::from_block_headers(&self.blocks).expect("blocks form the chain"),
+        );
+        self.blocks.push(header);
+        self.chain.add(header.hash());
+
+        header
+    }
+
+    /// Executes the proposed transaction against the latest block of the local chain, applies
+    /// its effects to the local chain, and seals a new block containing its output notes.
+    ///
+    /// The state of the local chain is not modified if an error is returned.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction cannot be executed.
+    /// - The effects of the transaction cannot be applied to the state of the local chain.
+    pub fn execute_transaction(
+        &mut self,
+        transaction: &ProposedTransaction,
+    ) -> Result<ExecutedTransaction, LocalChainError> {
+        let block_ref = self.latest_block_header().block_num();
+        let executed_transaction = self
+            .executor
+            .execute_transaction(
+                transaction.account_id(),
+                block_ref,
+                transaction.input_notes(),
+                transaction.tx_args().cloned(),
+            )
+            .map_err(LocalChainError::ExecuteTransactionFailed)?;
+
+        self.data_store
+            .apply_transaction(&executed_transaction)
+            .map_err(LocalChainError::ApplyTransactionFailed)?;
+
+        // the effects of the transaction are included in the next block
+        for note in executed_transaction.input_notes().iter() {
+            self.nullifiers
+                .insert(note.nullifier(), block_ref + 1)
+                .expect("the data store rejects notes which have already been consumed");
+        }
+        for note in executed_transaction.output_notes().iter() {
+            let details: Option<NoteDetails> = match note {
+                OutputNote::Full(note) => Some(note.into()),
+                _ => None,
+            };
+            self.pending_notes.push((note.id(), *note.metadata(), details));
+        }
+        self.seal_block();
+
+        Ok(executed_transaction)
+    }
+
+    /// Executes the proposed transactions one after another, sealing a block after each of them,
+    /// and returns the executed transactions.
+    ///
+    /// # Errors
+    /// Returns an error if any of the transactions fails to be executed or applied; the effects of
+    /// the transactions preceding the failed transaction remain applied to the local chain.
+    pub fn execute_chain(
+        &mut self,
+        transactions: &[ProposedTransaction],
+    ) -> Result<Vec<ExecutedTransaction>, LocalChainError> {
+        transactions
+            .iter()
+            .map(|transaction| self.execute_transaction(transaction))
+            .collect()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the account tree committing to the current state of all existing accounts.
+    fn build_account_tree(&self) -> SimpleSmt<ACCOUNT_TREE_DEPTH> {
+        let leaves = self
+            .account_ids
+            .iter()
+            .filter_map(|&account_id| self.data_store.account(account_id))
+            .filter(|account| !account.is_new())
+            .map(|account| (u64::from(account.id()), *account.hash()));
+
+        SimpleSmt::with_leaves(leaves).expect("account IDs are unique")
+    }
+}
+
+impl Default for LocalChainSimulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the note tree of a block containing the provided notes; the leaf of every note is the
+/// hash of the note ID and the note metadata.
+fn build_note_tree(
+    notes: &[(NoteId, NoteMetadata, Option<NoteDetails>)],
+) -> SimpleSmt<NOTE_TREE_DEPTH> {
+    let leaves = notes.iter().enumerate().map(|(index, (note_id, metadata, _))| {
+        let leaf = Hasher::merge(&[note_id.inner(), Word::from(metadata).into()]);
+        (index as u64, Word::from(leaf))
+    });
+
+    SimpleSmt::with_leaves(leaves).expect("note tree has enough leaves for all notes of a block")
+}
@@ -1,6 +1,11 @@
 use miden_objects::{
-    assembly::{Assembler, AssemblyContext, ModuleAst, ProgramAst},
-    transaction::{InputNotes, TransactionScript},
+    accounts::ProcedureAccess,
+    assembly::{Assembler, AssemblyContext, Library, ModuleAst, ProgramAst},
+    transaction::{
+        ExecutedTransaction, InputNotes, TransactionArgs, TransactionInputs, TransactionScript,
+        TransactionWitness,
+    },
+    utils::collections::Vec,
     Felt, NoteError, TransactionScriptError, Word,
 };
 
@@ -12,6 +17,32 @@ use super::{
 #[cfg(test)]
 mod tests;
 
+// CONSTANTS
+// ================================================================================================
+
+/// A program calling the authentication procedures of miden-lib which are skipped when a
+/// transaction is simulated.
+const AUTH_PROCEDURES_PROGRAM: &str = "
+    use.miden::contracts::auth::basic
+
+    begin
+        call.basic::auth_tx_rpo_falcon512
+        call.basic::auth_tx_rpo_falcon512_multisig
+        call.basic::auth_tx_rpo_falcon512_threshold
+        call.basic::auth_tx_rpo_falcon512_session_key
+    end
+";
+
+/// A program calling the procedure of miden-lib which authenticates simulated transactions, i.e.,
+/// which only increments the nonce of the account.
+const SIMULATION_AUTH_PROGRAM: &str = "
+    use.miden::contracts::auth::basic
+
+    begin
+        call.basic::auth_tx_no_auth
+    end
+";
+
 // TRANSACTION COMPILER
 // ================================================================================================
 
@@ -23,9 +54,17 @@ mod tests;
 ///
 /// In addition to transaction compilation, transaction compiler provides methods which can be
 /// used to compile Miden account code and note scripts.
+///
+/// Programs are compiled against the transaction kernel, miden-lib and the Miden standard library.
+/// Further libraries (e.g., helper modules shared by the scripts of a project) can be loaded via
+/// [TransactionCompiler::with_library()].
+///
+/// Scripts are checked against the [ProcedureAccess] of the account procedures they call: note
+/// scripts may only call note-callable procedures, while transaction scripts may call all
+/// procedures which are not internal.
 pub struct TransactionCompiler {
     assembler: Assembler,
-    account_procedures: BTreeMap<AccountId, Vec<Digest>>,
+    account_procedures: BTreeMap<AccountId, Vec<(Digest, ProcedureAccess)>>,
     kernel_main: CodeBlock,
 }
 
@@ -34,8 +73,20 @@ impl TransactionCompiler {
     // --------------------------------------------------------------------------------------------
     /// Returns a new [TransactionCompiler].
     pub fn new() -> TransactionCompiler {
-        let assembler = TransactionKernel::assembler();
+        Self::with_assembler(TransactionKernel::assembler())
+    }
+
+    /// Returns a new [TransactionCompiler] which compiles programs in debug mode.
+    ///
+    /// Programs compiled in debug mode retain the debug decorators which map the executed
+    /// operations back to MASM source instructions; this makes execution failures easier to
+    /// diagnose at the expense of larger programs.
+    pub fn new_debug() -> TransactionCompiler {
+        Self::with_assembler(TransactionKernel::debug_assembler())
+    }
 
+    /// Returns a new [TransactionCompiler] which uses the provided assembler.
+    fn with_assembler(assembler: Assembler) -> TransactionCompiler {
         // compile transaction kernel main
         let main_ast = TransactionKernel::main().expect("main is well formed");
         let kernel_main = assembler
@@ -49,11 +100,32 @@ impl TransactionCompiler {
         }
     }
 
+    /// Loads the provided library into this compiler.
+    ///
+    /// Account code, note scripts and transaction scripts compiled by this compiler can then
+    /// import the modules of the library (e.g., `use.myproject::math` for a library with namespace
+    /// `myproject`).
+    ///
+    /// # Errors
+    /// Returns an error if the library cannot be loaded (e.g., because it depends on a library
+    /// which has not been loaded).
+    pub fn with_library<L: Library>(self, library: &L) -> Result<Self, TransactionCompilerError> {
+        let assembler = self
+            .assembler
+            .with_library(library)
+            .map_err(TransactionCompilerError::LoadLibraryFailed)?;
+        Ok(Self { assembler, ..self })
+    }
+
     // ACCOUNT CODE AND NOTE SCRIPT COMPILERS
     // --------------------------------------------------------------------------------------------
 
     /// Compiles the provided module into [AccountCode] and associates the resulting procedures
     /// with the specified account ID.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "load_account", skip_all, fields(account_id = %account_id), err)
+    )]
     pub fn load_account(
         &mut self,
         account_id: AccountId,
@@ -61,22 +133,53 @@ impl TransactionCompiler {
     ) -> Result<AccountCode, TransactionCompilerError> {
         let account_code = AccountCode::new(account_code, &self.assembler)
             .map_err(TransactionCompilerError::LoadAccountFailed)?;
-        self.account_procedures.insert(account_id, account_code.procedures().to_vec());
+        self.load_account_code(account_id, &account_code);
         Ok(account_code)
     }
 
+    /// Associates the procedures of the provided [AccountCode], together with their access, with
+    /// the specified account ID.
+    ///
+    /// Unlike [TransactionCompiler::load_account()], this retains the access of the procedures set
+    /// via [AccountCode::with_procedure_access()].
+    pub fn load_account_code(&mut self, account_id: AccountId, account_code: &AccountCode) {
+        let procedures = account_code
+            .procedures()
+            .iter()
+            .map(|&procedure| {
+                let access = account_code
+                    .get_procedure_access(procedure)
+                    .expect("procedure is defined by the account code");
+                (procedure, access)
+            })
+            .collect();
+        self.account_procedures.insert(account_id, procedures);
+    }
+
     /// Loads the provided account interface (vector of procedure digests) into this compiler.
     /// Returns the old account interface if it previously existed.
+    ///
+    /// All procedures of the interface are assumed to be note-callable.
     pub fn load_account_interface(
         &mut self,
         account_id: AccountId,
         procedures: Vec<Digest>,
     ) -> Option<Vec<Digest>> {
-        self.account_procedures.insert(account_id, procedures)
+        let procedures = procedures
+            .into_iter()
+            .map(|procedure| (procedure, ProcedureAccess::NoteCallable))
+            .collect();
+        self.account_procedures
+            .insert(account_id, procedures)
+            .map(|procedures| procedures.into_iter().map(|(procedure, _)| procedure).collect())
     }
 
     /// Compiles the provided program into the [NoteScript] and checks (to the extent possible)
     /// if a note could be executed against all accounts with the specified interfaces.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "compile_note_script", skip_all, err)
+    )]
     pub fn compile_note_script(
         &self,
         note_script_ast: ProgramAst,
@@ -92,7 +195,7 @@ impl TransactionCompiler {
         for note_target in target_account_proc.into_iter() {
             verify_program_account_compatibility(
                 &code_block,
-                &self.get_target_interface(note_target)?,
+                &self.get_target_interface(note_target, ScriptType::NoteScript)?,
                 ScriptType::NoteScript,
             )?;
         }
@@ -102,6 +205,10 @@ impl TransactionCompiler {
 
     /// Constructs a [TransactionScript] by compiling the provided source code and checking the
     /// compatibility of the resulting program with the target account interfaces.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "compile_tx_script", skip_all, err)
+    )]
     pub fn compile_tx_script<T>(
         &self,
         tx_script_ast: ProgramAst,
@@ -122,32 +229,125 @@ impl TransactionCompiler {
         for target in target_account_proc.into_iter() {
             verify_program_account_compatibility(
                 &code_block,
-                &self.get_target_interface(target)?,
+                &self.get_target_interface(target, ScriptType::TransactionScript)?,
                 ScriptType::TransactionScript,
             )?;
         }
         Ok(tx_script)
     }
 
+    // COMPATIBILITY CHECKS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks whether the provided note script can be executed against the specified account
+    /// interface, and returns a [CompatibilityReport] listing the account procedures called by the
+    /// script which the interface does not expose to note scripts.
+    ///
+    /// This performs the same check as the compilation of transactions consuming the note, and
+    /// thus allows detecting notes which cannot be consumed by an account before attempting to
+    /// execute the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note script cannot be compiled.
+    /// - The target is an account ID whose interface has not been loaded into this compiler.
+    pub fn check_note_compatibility(
+        &self,
+        note_script: &NoteScript,
+        target: ScriptTarget,
+    ) -> Result<CompatibilityReport, TransactionCompilerError> {
+        let code = note_script.code();
+        let code_block = self
+            .assembler
+            .compile_in_context(code, &mut AssemblyContext::for_program(Some(code)))
+            .map_err(TransactionCompilerError::CompileNoteScriptFailed)?;
+        let interface = self.get_target_interface(target, ScriptType::NoteScript)?;
+
+        Ok(CompatibilityReport::new(&code_block, &interface))
+    }
+
     // TRANSACTION PROGRAM BUILDER
     // --------------------------------------------------------------------------------------------
     /// Compiles a transaction which executes the provided notes and an optional tx script against
     /// the specified account. Returns the the compiled transaction program.
     ///
     /// The account is assumed to have been previously loaded into this compiler.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "compile_transaction",
+            skip_all,
+            fields(account_id = %account_id, num_notes = notes.num_notes()),
+            err
+        )
+    )]
     pub fn compile_transaction(
         &self,
         account_id: AccountId,
         notes: &InputNotes,
         tx_script: Option<&ProgramAst>,
     ) -> Result<Program, TransactionCompilerError> {
-        // Fetch the account interface from the `account_procedures` map. Return an error if the
-        // interface is not found.
-        let target_account_interface = self
-            .account_procedures
-            .get(&account_id)
-            .cloned()
-            .ok_or(TransactionCompilerError::AccountInterfaceNotFound(account_id))?;
+        #[cfg(feature = "tracing")]
+        let timer = crate::timer::Timer::start();
+
+        let (program, _) = self.build_transaction_program(account_id, notes, tx_script, false)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(duration_ms = timer.elapsed_ms(), "compiled transaction program");
+
+        Ok(program)
+    }
+
+    /// Compiles a transaction which executes the provided notes and an optional tx script against
+    /// the specified account without authenticating the transaction.
+    ///
+    /// The program is compiled as by [TransactionCompiler::compile_transaction()], except that the
+    /// calls of the tx script to the authentication procedures of miden-lib are replaced with calls
+    /// to the procedure returned by [TransactionCompiler::simulation_auth_procedure()], which only
+    /// increments the nonce of the account. Returns the compiled program together with the root of
+    /// the rewritten tx script.
+    ///
+    /// The account is assumed to have been previously loaded into this compiler.
+    pub(crate) fn compile_simulated_transaction(
+        &self,
+        account_id: AccountId,
+        notes: &InputNotes,
+        tx_script: Option<&ProgramAst>,
+    ) -> Result<(Program, Option<Digest>), TransactionCompilerError> {
+        self.build_transaction_program(account_id, notes, tx_script, true)
+    }
+
+    /// Returns the root of the procedure which authenticates simulated transactions, i.e., the
+    /// `auth_tx_no_auth` procedure of miden-lib.
+    ///
+    /// For the nonce to be incremented, the procedure must be a procedure of the account against
+    /// which a simulated transaction is executed.
+    pub(crate) fn simulation_auth_procedure(&self) -> Digest {
+        self.compile_call_targets(SIMULATION_AUTH_PROGRAM, &mut AssemblyContext::for_program(None))
+            [0]
+    }
+
+    /// Compiles a transaction which executes the provided notes and an optional tx script against
+    /// the specified account, and returns the compiled program together with the root of the tx
+    /// script.
+    ///
+    /// If `skip_auth` is true, the calls of the tx script to the authentication procedures of
+    /// miden-lib are replaced with calls to the simulation authentication procedure.
+    fn build_transaction_program(
+        &self,
+        account_id: AccountId,
+        notes: &InputNotes,
+        tx_script: Option<&ProgramAst>,
+        skip_auth: bool,
+    ) -> Result<(Program, Option<Digest>), TransactionCompilerError> {
+        // Fetch the account interfaces exposed to note scripts and to the transaction script from
+        // the `account_procedures` map. Return an error if the interface is not found.
+        let note_interface =
+            self.get_target_interface(ScriptTarget::AccountId(account_id), ScriptType::NoteScript)?;
+        let tx_script_interface = self.get_target_interface(
+            ScriptTarget::AccountId(account_id),
+            ScriptType::TransactionScript,
+        )?;
 
         // Transaction must contain at least one input note or a transaction script
         if notes.is_empty() && tx_script.is_none() {
@@ -159,18 +359,31 @@ impl TransactionCompiler {
 
         // Compile note scripts
         let note_script_programs =
-            self.compile_notes(&target_account_interface, notes, &mut assembly_context)?;
+            self.compile_notes(&note_interface, notes, &mut assembly_context)?;
 
         // Compile the transaction script
-        let tx_script_program = match tx_script {
+        let mut tx_script_program = match tx_script {
             Some(tx_script) => Some(self.compile_tx_script_program(
                 tx_script,
                 &mut assembly_context,
-                target_account_interface,
+                tx_script_interface,
             )?),
             None => None,
         };
 
+        // Replace the calls to the authentication procedures; the replacement procedure is
+        // compiled in the same context, so that it is included in the [CodeBlockTable]
+        if skip_auth {
+            let auth_procedures = self.compile_call_targets(
+                AUTH_PROCEDURES_PROGRAM,
+                &mut AssemblyContext::for_program(None),
+            );
+            let simulation_auth =
+                self.compile_call_targets(SIMULATION_AUTH_PROGRAM, &mut assembly_context)[0];
+            tx_script_program = tx_script_program
+                .map(|program| replace_calls(&program, &auth_procedures, simulation_auth));
+        }
+
         // Create [CodeBlockTable] from [AssemblyContext]
         let mut cb_table = self
             .assembler
@@ -183,6 +396,7 @@ impl TransactionCompiler {
         });
 
         // insert transaction script into [CodeBlockTable]
+        let tx_script_root = tx_script_program.as_ref().map(CodeBlock::hash);
         if let Some(tx_script_program) = tx_script_program {
             cb_table.insert(tx_script_program);
         }
@@ -194,13 +408,112 @@ impl TransactionCompiler {
             cb_table,
         );
 
-        // Create compiled transaction
-        Ok(program)
+        Ok((program, tx_script_root))
+    }
+
+    /// Compiles the provided program source of miden-lib in the provided context, and returns the
+    /// procedures called by the program in order of their calls.
+    fn compile_call_targets(
+        &self,
+        source: &str,
+        assembly_context: &mut AssemblyContext,
+    ) -> Vec<Digest> {
+        let program_ast = ProgramAst::parse(source).expect("program is well formed");
+        let program = self
+            .assembler
+            .compile_in_context(&program_ast, assembly_context)
+            .expect("program of miden-lib compiles");
+
+        collect_call_branches(&program).concat()
+    }
+
+    // TRANSACTION DESERIALIZERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads a [TransactionWitness] serialized via `to_bytes()` from the provided bytes and
+    /// recompiles its program.
+    ///
+    /// This allows a transaction executed by one party to be proven by another party (e.g., a
+    /// proving service), which can pass the returned witness to a
+    /// [TransactionProver](crate::TransactionProver). The code of the account against which the
+    /// transaction was executed is loaded into this compiler.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The bytes are not a valid serialized transaction witness.
+    /// - The transaction program cannot be compiled.
+    /// - The hash of the compiled program is different from the hash recorded in the witness.
+    pub fn read_transaction_witness(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<TransactionWitness, TransactionCompilerError> {
+        let (program_hash, tx_inputs, tx_args, advice_witness) =
+            TransactionWitness::read_without_program(bytes)
+                .map_err(TransactionCompilerError::DeserializationFailed)?;
+        let program = self.recompile_transaction(program_hash, &tx_inputs, &tx_args)?;
+
+        Ok(TransactionWitness::new(program, tx_inputs, tx_args, advice_witness))
+    }
+
+    /// Reads an [ExecutedTransaction] serialized via `to_bytes()` from the provided bytes and
+    /// recompiles its program.
+    ///
+    /// The code of the account against which the transaction was executed is loaded into this
+    /// compiler.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The bytes are not a valid serialized executed transaction.
+    /// - The transaction program cannot be compiled.
+    /// - The hash of the compiled program is different from the hash recorded in the transaction.
+    pub fn read_executed_transaction(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<ExecutedTransaction, TransactionCompilerError> {
+        let (program_hash, tx_inputs, tx_outputs, account_delta, tx_args, advice_witness) =
+            ExecutedTransaction::read_without_program(bytes)
+                .map_err(TransactionCompilerError::DeserializationFailed)?;
+        let program = self.recompile_transaction(program_hash, &tx_inputs, &tx_args)?;
+
+        Ok(ExecutedTransaction::new(
+            program,
+            tx_inputs,
+            tx_outputs,
+            account_delta,
+            tx_args,
+            advice_witness,
+        ))
     }
 
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Loads the code of the account and compiles the program of the transaction specified by the
+    /// provided inputs and arguments, checking that the hash of the program is the expected one.
+    fn recompile_transaction(
+        &mut self,
+        program_hash: Digest,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Result<Program, TransactionCompilerError> {
+        let account = tx_inputs.account();
+        self.load_account_code(account.id(), account.code());
+
+        let program = self.compile_transaction(
+            account.id(),
+            tx_inputs.input_notes(),
+            tx_args.tx_script().map(|tx_script| tx_script.code()),
+        )?;
+        if program.hash() != program_hash {
+            return Err(TransactionCompilerError::TransactionProgramMismatch {
+                expected: program_hash,
+                actual: program.hash(),
+            });
+        }
+
+        Ok(program)
+    }
+
     /// Compiles the provided notes into [CodeBlock]s (programs) and verifies that each note is
     /// compatible with the target account interfaces. Returns a vector of the compiled note
     /// programs.
@@ -250,7 +563,8 @@ impl TransactionCompiler {
         Ok(tx_script_code_block)
     }
 
-    /// Returns the account interface associated with the provided [ScriptTarget].
+    /// Returns the account interface associated with the provided [ScriptTarget], i.e., the
+    /// procedures of the target which can be called by scripts of the specified type.
     ///
     /// # Errors
     /// - If the account interface associated with the [AccountId] provided as a target can not be
@@ -258,12 +572,19 @@ impl TransactionCompiler {
     fn get_target_interface(
         &self,
         target: ScriptTarget,
+        script_type: ScriptType,
     ) -> Result<Vec<Digest>, TransactionCompilerError> {
         match target {
             ScriptTarget::AccountId(id) => self
                 .account_procedures
                 .get(&id)
-                .cloned()
+                .map(|procedures| {
+                    procedures
+                        .iter()
+                        .filter(|(_, access)| script_type.can_call(access))
+                        .map(|(procedure, _)| *procedure)
+                        .collect()
+                })
                 .ok_or(TransactionCompilerError::AccountInterfaceNotFound(id)),
             ScriptTarget::Procedures(procs) => Ok(procs),
         }
@@ -282,7 +603,7 @@ impl Default for TransactionCompiler {
 /// Verifies that the provided program is compatible with the target account interface.
 ///
 /// This is achieved by checking that at least one execution branch in the program is compatible
-/// with the target account interface.
+/// with the target account interface (see [CompatibilityReport]).
 ///
 /// # Errors
 /// Returns an error if the program is not compatible with the target account interface.
@@ -291,13 +612,8 @@ fn verify_program_account_compatibility(
     target_account_interface: &[Digest],
     script_type: ScriptType,
 ) -> Result<(), TransactionCompilerError> {
-    // collect call branches
-    let branches = collect_call_branches(program);
-
     // if none of the branches are compatible with the target account, return an error
-    if !branches.iter().any(|call_targets| {
-        call_targets.iter().all(|target| target_account_interface.contains(target))
-    }) {
+    if !CompatibilityReport::new(program, target_account_interface).is_compatible() {
         return match script_type {
             ScriptType::NoteScript => {
                 Err(TransactionCompilerError::NoteIncompatibleWithAccountInterface(program.hash()))
@@ -358,6 +674,90 @@ fn recursively_collect_call_branches(code_block: &CodeBlock, branches: &mut Vec<
     }
 }
 
+/// Returns the provided code block with all calls to any of the `targets` procedures replaced with
+/// calls to the `replacement` procedure.
+fn replace_calls(code_block: &CodeBlock, targets: &[Digest], replacement: Digest) -> CodeBlock {
+    match code_block {
+        CodeBlock::Join(block) => CodeBlock::new_join([
+            replace_calls(block.first(), targets, replacement),
+            replace_calls(block.second(), targets, replacement),
+        ]),
+        CodeBlock::Split(block) => CodeBlock::new_split(
+            replace_calls(block.on_true(), targets, replacement),
+            replace_calls(block.on_false(), targets, replacement),
+        ),
+        CodeBlock::Loop(block) => {
+            CodeBlock::new_loop(replace_calls(block.body(), targets, replacement))
+        },
+        CodeBlock::Call(block) if !block.is_syscall() && targets.contains(&block.fn_hash()) => {
+            CodeBlock::new_call(replacement)
+        },
+        _ => code_block.clone(),
+    }
+}
+
+// COMPATIBILITY REPORT
+// ================================================================================================
+
+/// The result of checking a script against an account interface.
+///
+/// The calls of a script to account procedures may depend on the branches taken during its
+/// execution; a script is compatible with an account interface if all account procedures called
+/// in at least one of its execution branches are exposed by the interface. For every execution
+/// branch, the report lists the called procedures which are missing from the interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompatibilityReport {
+    script_root: Digest,
+    missing_by_branch: Vec<Vec<Digest>>,
+}
+
+impl CompatibilityReport {
+    /// Returns the report of checking the provided program against the provided interface.
+    fn new(program: &CodeBlock, interface: &[Digest]) -> Self {
+        let missing_by_branch = collect_call_branches(program)
+            .into_iter()
+            .map(|mut call_targets| {
+                call_targets.retain(|target| !interface.contains(target));
+                call_targets
+            })
+            .collect();
+
+        Self {
+            script_root: program.hash(),
+            missing_by_branch,
+        }
+    }
+
+    /// Returns the MAST root of the checked script.
+    pub fn script_root(&self) -> Digest {
+        self.script_root
+    }
+
+    /// Returns true if at least one execution branch of the script calls only procedures exposed
+    /// by the account interface.
+    pub fn is_compatible(&self) -> bool {
+        self.missing_by_branch.iter().any(|missing| missing.is_empty())
+    }
+
+    /// Returns the procedures called by any of the execution branches of the script which are
+    /// missing from the account interface, without duplicates and in order of their first call.
+    pub fn missing_procedures(&self) -> Vec<Digest> {
+        let mut missing_procedures = Vec::new();
+        for procedure in self.missing_by_branch.iter().flatten() {
+            if !missing_procedures.contains(procedure) {
+                missing_procedures.push(*procedure);
+            }
+        }
+        missing_procedures
+    }
+
+    /// Returns the procedures missing from the account interface for every execution branch of
+    /// the script.
+    pub fn missing_by_branch(&self) -> &[Vec<Digest>] {
+        &self.missing_by_branch
+    }
+}
+
 // SCRIPT TARGET
 // ================================================================================================
 
@@ -365,7 +765,8 @@ fn recursively_collect_call_branches(code_block: &CodeBlock, branches: &mut Vec<
 /// transaction scripts.
 ///
 /// This is specified as an account ID (for which the interface should be fetched) or a vector of
-/// procedure digests which represents the account interface.
+/// procedure digests which represents the account interface. All procedures of an interface
+/// specified as a vector of digests are assumed to be note-callable.
 #[derive(Clone)]
 pub enum ScriptTarget {
     AccountId(AccountId),
@@ -375,7 +776,18 @@ pub enum ScriptTarget {
 // SCRIPT TYPE
 // ================================================================================================
 
+#[derive(Clone, Copy)]
 enum ScriptType {
     NoteScript,
     TransactionScript,
 }
+
+impl ScriptType {
+    /// Returns true if scripts of this type can call account procedures with the specified access.
+    fn can_call(&self, access: &ProcedureAccess) -> bool {
+        match self {
+            Self::NoteScript => access.is_note_callable(),
+            Self::TransactionScript => access.is_tx_script_callable(),
+        }
+    }
+}
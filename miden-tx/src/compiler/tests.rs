@@ -1,12 +1,20 @@
+use std::fs;
+
 use miden_objects::{
-    accounts::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+    accounts::{ProcedureAccess, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN},
+    assembly::{LibraryNamespace, MaslLibrary, Version},
     assets::{Asset, FungibleAsset},
-    notes::{Note, NoteInclusionProof},
+    notes::{Note, NoteInclusionProof, NoteTag},
     transaction::{InputNote, InputNotes},
-    Felt, FieldElement, Word,
+    Felt, Word,
 };
 
-use super::{AccountId, ModuleAst, ProgramAst, ScriptTarget, TransactionCompiler};
+use tempfile::tempdir;
+
+use super::{
+    AccountCode, AccountId, Digest, ModuleAst, ProgramAst, ScriptTarget, TransactionCompiler,
+    TransactionCompilerError, TransactionKernel,
+};
 
 // CONSTANTS
 // ================================================================================================
@@ -41,6 +49,13 @@ export.additional_procedure_2
 end
 ";
 
+// Module of a user library with namespace `myproject`:
+const MATH_LIBRARY_MASM: &str = "\
+export.double
+    dup add
+end
+";
+
 // TESTS
 // ================================================================================================
 
@@ -127,6 +142,147 @@ fn test_compile_valid_note_script() {
     }
 }
 
+#[test]
+fn test_check_note_compatibility() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code = tx_compiler
+        .load_account(account_id, ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap())
+        .unwrap();
+    let target = ScriptTarget::AccountId(account_id);
+
+    let additional_code = AccountCode::new(
+        ModuleAst::parse(ADDITIONAL_PROCEDURES).unwrap(),
+        &TransactionKernel::assembler(),
+    )
+    .unwrap();
+    let proc_1 = find_procedure(&account_code, ACCT_PROC_1);
+    let add_proc_1 = find_procedure(&additional_code, ADD_PROC_1);
+    let add_proc_2 = find_procedure(&additional_code, ADD_PROC_2);
+
+    // one of the branches calls only procedures of the account
+    let script_src = format!(
+        "begin
+            call.{ACCT_PROC_1}
+            if.true
+                call.{ACCT_PROC_2}
+            else
+                call.{ADD_PROC_1}
+            end
+        end"
+    );
+    let note_script = tx_compiler
+        .compile_note_script(ProgramAst::parse(&script_src).unwrap(), vec![])
+        .unwrap();
+    let report = tx_compiler.check_note_compatibility(&note_script, target.clone()).unwrap();
+    assert!(report.is_compatible());
+    assert_eq!(report.script_root(), note_script.hash());
+    assert_eq!(report.missing_procedures(), vec![add_proc_1]);
+
+    // all branches call procedures which the account does not have
+    let script_src = format!(
+        "begin
+            call.{ACCT_PROC_1}
+            if.true
+                call.{ADD_PROC_1}
+            else
+                call.{ADD_PROC_2}
+                call.{ADD_PROC_1}
+            end
+        end"
+    );
+    let note_script = tx_compiler
+        .compile_note_script(ProgramAst::parse(&script_src).unwrap(), vec![])
+        .unwrap();
+    let report = tx_compiler.check_note_compatibility(&note_script, target.clone()).unwrap();
+    assert!(!report.is_compatible());
+    assert_eq!(report.missing_procedures(), vec![add_proc_2, add_proc_1]);
+    assert_eq!(report.missing_by_branch(), &[vec![add_proc_2, add_proc_1], vec![add_proc_1]]);
+
+    // procedures which are not note-callable are missing from the interface of notes
+    let account_code = account_code
+        .with_procedure_access(proc_1, ProcedureAccess::AuthRequired)
+        .unwrap();
+    tx_compiler.load_account_code(account_id, &account_code);
+    let note_script = tx_compiler
+        .compile_note_script(
+            ProgramAst::parse(&format!("begin call.{ACCT_PROC_1} end")).unwrap(),
+            vec![],
+        )
+        .unwrap();
+    let report = tx_compiler.check_note_compatibility(&note_script, target).unwrap();
+    assert!(!report.is_compatible());
+    assert_eq!(report.missing_procedures(), vec![proc_1]);
+
+    // the interface of unknown accounts cannot be checked
+    let unknown_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN + 1).unwrap();
+    assert!(matches!(
+        tx_compiler
+            .check_note_compatibility(&note_script, ScriptTarget::AccountId(unknown_account_id)),
+        Err(TransactionCompilerError::AccountInterfaceNotFound(_))
+    ));
+}
+
+#[test]
+fn test_compile_scripts_respecting_procedure_access() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code = tx_compiler
+        .load_account(account_id, ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap())
+        .unwrap();
+
+    let proc_1 = find_procedure(&account_code, ACCT_PROC_1);
+    let proc_2 = find_procedure(&account_code, ACCT_PROC_2);
+
+    // procedure 1 requires authentication while procedure 2 is internal
+    let account_code = account_code
+        .with_procedure_access(proc_1, ProcedureAccess::AuthRequired)
+        .unwrap()
+        .with_procedure_access(proc_2, ProcedureAccess::Internal)
+        .unwrap();
+    tx_compiler.load_account_code(account_id, &account_code);
+    let target = vec![ScriptTarget::AccountId(account_id)];
+
+    // notes cannot call procedures which are not note-callable
+    let script_src = format!("begin call.{ACCT_PROC_1} end");
+    let result =
+        tx_compiler.compile_note_script(ProgramAst::parse(&script_src).unwrap(), target.clone());
+    assert!(matches!(
+        result,
+        Err(TransactionCompilerError::NoteIncompatibleWithAccountInterface(_))
+    ));
+
+    // transaction scripts can call procedures which require authentication
+    assert!(tx_compiler
+        .compile_tx_script(ProgramAst::parse(&script_src).unwrap(), vec![], target.clone())
+        .is_ok());
+
+    // but not internal procedures
+    let script_src = format!("begin call.{ACCT_PROC_2} end");
+    let result = tx_compiler.compile_tx_script(
+        ProgramAst::parse(&script_src).unwrap(),
+        vec![],
+        target.clone(),
+    );
+    assert!(matches!(
+        result,
+        Err(TransactionCompilerError::TxScriptIncompatibleWithAccountInterface(_))
+    ));
+
+    // note-callable procedures can be called by notes
+    let account_code = account_code
+        .with_procedure_access(proc_1, ProcedureAccess::NoteCallable)
+        .unwrap();
+    tx_compiler.load_account_code(account_id, &account_code);
+    let script_src = format!("begin call.{ACCT_PROC_1} end");
+    assert!(tx_compiler
+        .compile_note_script(ProgramAst::parse(&script_src).unwrap(), target)
+        .is_ok());
+}
+
 fn mock_consumed_notes(
     tx_compiler: &mut TransactionCompiler,
     target_account: AccountId,
@@ -160,7 +316,7 @@ fn mock_consumed_notes(
         &[fungible_asset_1, fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_1,
         sender,
-        Felt::ZERO,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -171,7 +327,7 @@ fn mock_consumed_notes(
         &[fungible_asset_1, fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_2,
         sender,
-        Felt::ZERO,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -209,9 +365,49 @@ fn test_transaction_compilation_succeeds() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn test_compile_scripts_with_library() {
+    let library_dir = tempdir().unwrap();
+    fs::write(library_dir.path().join("math.masm"), MATH_LIBRARY_MASM).unwrap();
+    let namespace = LibraryNamespace::try_from("myproject".to_string()).unwrap();
+    let version = Version::try_from("0.1.0").unwrap();
+    let library =
+        MaslLibrary::read_from_dir(library_dir.path(), namespace, false, version).unwrap();
+
+    let script_src = "
+    use.myproject::math
+
+    begin
+        push.3 exec.math::double drop
+    end
+    ";
+
+    // scripts importing the library cannot be compiled unless the library is loaded
+    let tx_compiler = TransactionCompiler::new();
+    let result =
+        tx_compiler.compile_tx_script(ProgramAst::parse(script_src).unwrap(), vec![], vec![]);
+    assert!(matches!(result, Err(TransactionCompilerError::CompileTxScriptFailed(_))));
+
+    let tx_compiler = tx_compiler.with_library(&library).unwrap();
+    assert!(tx_compiler
+        .compile_tx_script(ProgramAst::parse(script_src).unwrap(), vec![], vec![])
+        .is_ok());
+    assert!(tx_compiler
+        .compile_note_script(ProgramAst::parse(script_src).unwrap(), vec![])
+        .is_ok());
+}
+
 // HELPERS
 // ================================================================================================
 
+fn find_procedure(account_code: &AccountCode, hex: &str) -> Digest {
+    *account_code
+        .procedures()
+        .iter()
+        .find(|procedure| procedure.to_hex() == hex)
+        .expect("procedure is defined by the account code")
+}
+
 fn hex_to_bytes(hex: &str) -> Vec<u8> {
     (2..hex.len())
         .step_by(2)
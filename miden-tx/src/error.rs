@@ -1,12 +1,24 @@
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
 use core::fmt;
 
+use miden_lib::errors::TxKernelError;
 use miden_objects::{
-    assembly::AssemblyError, notes::NoteId, Felt, NoteError, TransactionInputError,
-    TransactionOutputError,
+    assembly::AssemblyError,
+    assets::{Asset, FungibleAsset},
+    notes::{NoteId, NoteTag, Nullifier},
+    transaction::TransactionId,
+    utils::{serde::DeserializationError, string::String},
+    Felt, NoteError, ProvenTransactionError, TransactionInputError, TransactionOutputError,
 };
+use miden_prover::HashFunction;
 use miden_verifier::VerificationError;
+use vm_processor::ExecutionOptionsError;
 
-use super::{AccountError, AccountId, Digest, ExecutionError};
+use super::{
+    AccountError, AccountId, Digest, ExecutionError, PipelineStage, ProverBackend,
+    TransactionDebugInfo,
+};
 
 // TRANSACTION COMPILER ERROR
 // ================================================================================================
@@ -17,21 +29,94 @@ pub enum TransactionCompilerError {
     BuildCodeBlockTableFailed(AssemblyError),
     CompileNoteScriptFailed(AssemblyError),
     CompileTxScriptFailed(AssemblyError),
+    DeserializationFailed(DeserializationError),
     LoadAccountFailed(AccountError),
+    LoadLibraryFailed(AssemblyError),
     NoteIncompatibleWithAccountInterface(Digest),
     NoteScriptError(NoteError),
     NoTransactionDriver,
+    TransactionProgramMismatch { expected: Digest, actual: Digest },
     TxScriptIncompatibleWithAccountInterface(Digest),
 }
 
+impl TransactionCompilerError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction compiler errors are in the range 10000..10100. The code of an error
+    /// never changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AccountInterfaceNotFound(_) => 10000,
+            Self::BuildCodeBlockTableFailed(_) => 10001,
+            Self::CompileNoteScriptFailed(_) => 10002,
+            Self::CompileTxScriptFailed(_) => 10003,
+            Self::DeserializationFailed(_) => 10004,
+            Self::LoadAccountFailed(_) => 10005,
+            Self::NoteIncompatibleWithAccountInterface(_) => 10006,
+            Self::NoteScriptError(_) => 10007,
+            Self::NoTransactionDriver => 10008,
+            Self::TransactionProgramMismatch { .. } => 10009,
+            Self::TxScriptIncompatibleWithAccountInterface(_) => 10010,
+            Self::LoadLibraryFailed(_) => 10011,
+        }
+    }
+}
+
 impl fmt::Display for TransactionCompilerError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AccountInterfaceNotFound(account_id) => {
+                write!(f, "interface of account {account_id} is not loaded into the compiler")
+            },
+            Self::BuildCodeBlockTableFailed(_) => {
+                write!(f, "failed to build the code block table of the transaction program")
+            },
+            Self::CompileNoteScriptFailed(_) => write!(f, "failed to compile note script"),
+            Self::CompileTxScriptFailed(_) => write!(f, "failed to compile transaction script"),
+            Self::DeserializationFailed(_) => write!(f, "failed to deserialize transaction data"),
+            Self::LoadAccountFailed(_) => write!(f, "failed to load account code"),
+            Self::LoadLibraryFailed(_) => write!(f, "failed to load library"),
+            Self::NoteIncompatibleWithAccountInterface(script_root) => write!(
+                f,
+                "note script {} calls procedures which are not in the account interface or are \
+                 not callable by notes",
+                script_root.to_hex()
+            ),
+            Self::NoteScriptError(_) => write!(f, "invalid note script"),
+            Self::NoTransactionDriver => {
+                write!(f, "transaction has neither input notes nor a transaction script")
+            },
+            Self::TransactionProgramMismatch { expected, actual } => write!(
+                f,
+                "transaction program {} does not match the expected program {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::TxScriptIncompatibleWithAccountInterface(script_root) => write!(
+                f,
+                "transaction script {} calls procedures which are not in the account interface or \
+                 are internal",
+                script_root.to_hex()
+            ),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionCompilerError {}
+impl std::error::Error for TransactionCompilerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::BuildCodeBlockTableFailed(err)
+            | Self::CompileNoteScriptFailed(err)
+            | Self::CompileTxScriptFailed(err)
+            | Self::LoadLibraryFailed(err) => Some(err),
+            Self::DeserializationFailed(err) => Some(err),
+            Self::LoadAccountFailed(err) => Some(err),
+            Self::NoteScriptError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // TRANSACTION EXECUTOR ERROR
 // ================================================================================================
@@ -41,8 +126,15 @@ pub enum TransactionExecutorError {
     CompileNoteScriptFailed(TransactionCompilerError),
     CompileTransactionScriptFailed(TransactionCompilerError),
     CompileTransactionFailed(TransactionCompilerError),
+    CycleLimitExceeded(u32),
     ExecuteTransactionProgramFailed(ExecutionError),
+    ExecuteTransactionProgramFailedWithDebugInfo(ExecutionError, Box<TransactionDebugInfo>),
+    ExpectedOutputNoteMismatch {
+        expected: NoteId,
+        actual: NoteId,
+    },
     FetchAccountCodeFailed(DataStoreError),
+    FetchBlockHeadersFailed(DataStoreError),
     FetchTransactionInputsFailed(DataStoreError),
     InconsistentAccountId {
         input_id: AccountId,
@@ -52,18 +144,234 @@ pub enum TransactionExecutorError {
         expected: Option<Felt>,
         actual: Option<Felt>,
     },
+    InvalidExecutionOptions(ExecutionOptionsError),
+    InvalidSimulationAccount(AccountError),
+    InvalidTransactionInputs(TransactionInputError),
     InvalidTransactionOutput(TransactionOutputError),
     LoadAccountFailed(TransactionCompilerError),
+    LoadLibraryFailed(TransactionCompilerError),
+    PolicyViolation(TransactionPolicyError),
+    ReexecutedAccountDeltaMismatch,
+    ReexecutedFinalAccountMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    ReexecutedOutputNotesMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    SimulationOfNewAccountNotSupported(AccountId),
+}
+
+impl TransactionExecutorError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction executor errors are in the range 10100..10200. The code of an error
+    /// never changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::CompileNoteScriptFailed(_) => 10100,
+            Self::CompileTransactionScriptFailed(_) => 10101,
+            Self::CompileTransactionFailed(_) => 10102,
+            Self::CycleLimitExceeded(_) => 10103,
+            Self::ExecuteTransactionProgramFailed(_) => 10104,
+            Self::ExecuteTransactionProgramFailedWithDebugInfo(..) => 10105,
+            Self::ExpectedOutputNoteMismatch { .. } => 10106,
+            Self::FetchAccountCodeFailed(_) => 10107,
+            Self::FetchTransactionInputsFailed(_) => 10108,
+            Self::InconsistentAccountId { .. } => 10109,
+            Self::InconsistentAccountNonceDelta { .. } => 10110,
+            Self::InvalidExecutionOptions(_) => 10111,
+            Self::InvalidSimulationAccount(_) => 10112,
+            Self::InvalidTransactionInputs(_) => 10113,
+            Self::InvalidTransactionOutput(_) => 10114,
+            Self::LoadAccountFailed(_) => 10115,
+            Self::PolicyViolation(_) => 10116,
+            Self::ReexecutedAccountDeltaMismatch => 10117,
+            Self::ReexecutedFinalAccountMismatch { .. } => 10118,
+            Self::ReexecutedOutputNotesMismatch { .. } => 10119,
+            Self::SimulationOfNewAccountNotSupported(_) => 10120,
+            Self::LoadLibraryFailed(_) => 10121,
+            Self::FetchBlockHeadersFailed(_) => 10122,
+        }
+    }
+
+    /// Returns the error of the transaction kernel which caused the execution of the transaction
+    /// program to fail.
+    ///
+    /// Returns None if the error was not caused by a failed assertion of the transaction kernel.
+    pub fn kernel_error(&self) -> Option<&'static TxKernelError> {
+        match self {
+            Self::ExecuteTransactionProgramFailed(err)
+            | Self::ExecuteTransactionProgramFailedWithDebugInfo(err, _) => {
+                kernel_error_from_execution_error(err)
+            },
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for TransactionExecutorError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::CompileNoteScriptFailed(_) => write!(f, "failed to compile note script"),
+            Self::CompileTransactionScriptFailed(_) => {
+                write!(f, "failed to compile transaction script")
+            },
+            Self::CompileTransactionFailed(_) => write!(f, "failed to compile transaction"),
+            Self::CycleLimitExceeded(max_cycles) => {
+                write!(f, "transaction exceeded the limit of {max_cycles} cycles")
+            },
+            Self::ExecuteTransactionProgramFailed(_)
+            | Self::ExecuteTransactionProgramFailedWithDebugInfo(..) => match self.kernel_error() {
+                Some(kernel_error) => write!(
+                    f,
+                    "failed to execute transaction program: kernel assertion {} failed: {}",
+                    kernel_error.name, kernel_error.description
+                ),
+                None => write!(f, "failed to execute transaction program"),
+            },
+            Self::ExpectedOutputNoteMismatch { expected, actual } => write!(
+                f,
+                "output note {actual} has the recipient of expected output note {expected}, but \
+                 different assets or metadata"
+            ),
+            Self::FetchAccountCodeFailed(_) => write!(f, "failed to fetch account code"),
+            Self::FetchBlockHeadersFailed(_) => write!(f, "failed to fetch block headers"),
+            Self::FetchTransactionInputsFailed(_) => {
+                write!(f, "failed to fetch transaction inputs")
+            },
+            Self::InconsistentAccountId { input_id, output_id } => write!(
+                f,
+                "transaction was executed against account {input_id}, but output account \
+                 {output_id}"
+            ),
+            Self::InconsistentAccountNonceDelta { expected, actual } => write!(
+                f,
+                "account nonce delta {actual:?} is inconsistent with expected delta {expected:?}"
+            ),
+            Self::InvalidExecutionOptions(_) => write!(f, "invalid execution options"),
+            Self::InvalidSimulationAccount(_) => write!(f, "account cannot be simulated"),
+            Self::InvalidTransactionInputs(_) => write!(f, "invalid transaction inputs"),
+            Self::InvalidTransactionOutput(_) => write!(f, "invalid transaction output"),
+            Self::LoadAccountFailed(_) => write!(f, "failed to load account"),
+            Self::LoadLibraryFailed(_) => write!(f, "failed to load library"),
+            Self::PolicyViolation(_) => write!(f, "transaction violates the executor policy"),
+            Self::ReexecutedAccountDeltaMismatch => {
+                write!(
+                    f,
+                    "account delta of the re-executed transaction differs from the claimed one"
+                )
+            },
+            Self::ReexecutedFinalAccountMismatch { expected, actual } => write!(
+                f,
+                "final account hash {} of the re-executed transaction differs from the claimed \
+                 hash {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::ReexecutedOutputNotesMismatch { expected, actual } => write!(
+                f,
+                "output notes commitment {} of the re-executed transaction differs from the \
+                 claimed commitment {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::SimulationOfNewAccountNotSupported(account_id) => {
+                write!(f, "transactions against new account {account_id} cannot be simulated")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionExecutorError {}
+impl std::error::Error for TransactionExecutorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::CompileNoteScriptFailed(err)
+            | Self::CompileTransactionScriptFailed(err)
+            | Self::CompileTransactionFailed(err)
+            | Self::LoadAccountFailed(err)
+            | Self::LoadLibraryFailed(err) => Some(err),
+            Self::ExecuteTransactionProgramFailed(err)
+            | Self::ExecuteTransactionProgramFailedWithDebugInfo(err, _) => Some(err),
+            Self::FetchAccountCodeFailed(err)
+            | Self::FetchBlockHeadersFailed(err)
+            | Self::FetchTransactionInputsFailed(err) => Some(err),
+            Self::InvalidExecutionOptions(err) => Some(err),
+            Self::InvalidSimulationAccount(err) => Some(err),
+            Self::InvalidTransactionInputs(err) => Some(err),
+            Self::InvalidTransactionOutput(err) => Some(err),
+            Self::PolicyViolation(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// TRANSACTION POLICY ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionPolicyError {
+    InputNoteScriptNotAllowed {
+        note_id: NoteId,
+        script_root: Digest,
+    },
+    OutgoingAmountExceeded {
+        faucet_id: AccountId,
+        max: u64,
+        actual: u64,
+    },
+    OutgoingNonFungibleAsset(Asset),
+    OutputNoteTagNotAllowed {
+        note_id: NoteId,
+        tag: NoteTag,
+    },
+    TransactionScriptNotAllowed(Digest),
+}
+
+impl TransactionPolicyError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction policy errors are in the range 10200..10300. The code of an error
+    /// never changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::InputNoteScriptNotAllowed { .. } => 10200,
+            Self::OutgoingAmountExceeded { .. } => 10201,
+            Self::OutgoingNonFungibleAsset(_) => 10202,
+            Self::OutputNoteTagNotAllowed { .. } => 10203,
+            Self::TransactionScriptNotAllowed(_) => 10204,
+        }
+    }
+}
+
+impl fmt::Display for TransactionPolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InputNoteScriptNotAllowed { note_id, script_root } => {
+                write!(f, "script {} of input note {note_id} is not allowed", script_root.to_hex())
+            },
+            Self::OutgoingAmountExceeded { faucet_id, max, actual } => write!(
+                f,
+                "transaction sends {actual} units of faucet {faucet_id}, but at most {max} are \
+                 allowed"
+            ),
+            Self::OutgoingNonFungibleAsset(asset) => {
+                write!(f, "sending non-fungible asset {asset:?} is not allowed")
+            },
+            Self::OutputNoteTagNotAllowed { note_id, tag } => {
+                write!(f, "tag {tag} of output note {note_id} is not allowed")
+            },
+            Self::TransactionScriptNotAllowed(script_root) => {
+                write!(f, "transaction script {} is not allowed", script_root.to_hex())
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionPolicyError {}
 
 // TRANSACTION PROVER ERROR
 // ================================================================================================
@@ -71,17 +379,212 @@ impl std::error::Error for TransactionExecutorError {}
 #[derive(Debug)]
 pub enum TransactionProverError {
     ProveTransactionProgramFailed(ExecutionError),
+    InvalidAccountDelta(AccountError),
+    InvalidBlowupFactor(usize),
+    InvalidGrindingBits(u32),
+    InvalidProvenTransaction(ProvenTransactionError),
+    InvalidRemoteResponse(DeserializationError),
     InvalidTransactionOutput(TransactionOutputError),
+    ProvingTaskPanicked,
+    RemoteProofMismatch(TransactionId),
+    RemoteProvingFailed(String),
+    UnknownKernel(Digest),
+    UnsupportedBackend {
+        backend: ProverBackend,
+        hash_fn: HashFunction,
+    },
+    UnsupportedSecurityLevel {
+        security_level: u32,
+        hash_fn: HashFunction,
+    },
+}
+
+impl TransactionProverError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction prover errors are in the range 10300..10400. The code of an error
+    /// never changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::ProveTransactionProgramFailed(_) => 10300,
+            Self::InvalidAccountDelta(_) => 10301,
+            Self::InvalidBlowupFactor(_) => 10302,
+            Self::InvalidGrindingBits(_) => 10303,
+            Self::InvalidProvenTransaction(_) => 10304,
+            Self::InvalidTransactionOutput(_) => 10305,
+            Self::ProvingTaskPanicked => 10306,
+            Self::UnknownKernel(_) => 10307,
+            Self::UnsupportedBackend { .. } => 10308,
+            Self::UnsupportedSecurityLevel { .. } => 10309,
+            Self::InvalidRemoteResponse(_) => 10310,
+            Self::RemoteProofMismatch(_) => 10311,
+            Self::RemoteProvingFailed(_) => 10312,
+        }
+    }
 }
 
 impl fmt::Display for TransactionProverError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::ProveTransactionProgramFailed(_) => {
+                write!(f, "failed to prove transaction program")
+            },
+            Self::InvalidAccountDelta(_) => write!(f, "invalid account delta"),
+            Self::InvalidBlowupFactor(blowup_factor) => {
+                write!(f, "blowup factor {blowup_factor} is not a power of two between 2 and 128")
+            },
+            Self::InvalidGrindingBits(grinding_bits) => {
+                write!(f, "{grinding_bits} grinding bits exceed the maximum of 32")
+            },
+            Self::InvalidProvenTransaction(_) => write!(f, "invalid proven transaction"),
+            Self::InvalidRemoteResponse(_) => {
+                write!(f, "proving service returned an invalid proven transaction")
+            },
+            Self::InvalidTransactionOutput(_) => write!(f, "invalid transaction output"),
+            Self::ProvingTaskPanicked => write!(f, "proving thread panicked"),
+            Self::RemoteProofMismatch(tx_id) => write!(
+                f,
+                "proven transaction {} returned by the proving service does not prove the \
+                 submitted transaction",
+                tx_id.to_hex()
+            ),
+            Self::RemoteProvingFailed(err) => write!(f, "remote proving failed: {err}"),
+            Self::UnknownKernel(kernel_root) => {
+                write!(f, "transaction kernel {} is not known to the prover", kernel_root.to_hex())
+            },
+            Self::UnsupportedBackend { backend, hash_fn } => write!(
+                f,
+                "proofs with hash function {hash_fn:?} are not generated by the {backend} backend \
+                 in this build"
+            ),
+            Self::UnsupportedSecurityLevel { security_level, hash_fn } => write!(
+                f,
+                "security level of {security_level} bits is not supported by hash function \
+                 {hash_fn:?}"
+            ),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionProverError {}
+impl std::error::Error for TransactionProverError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ProveTransactionProgramFailed(err) => Some(err),
+            Self::InvalidAccountDelta(err) => Some(err),
+            Self::InvalidProvenTransaction(err) => Some(err),
+            Self::InvalidRemoteResponse(err) => Some(err),
+            Self::InvalidTransactionOutput(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// STATE SIMULATOR ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateSimulatorError {
+    AccountDeltaApplicationFailed(AccountError),
+    AccountNotFound(AccountId),
+    FinalAccountHashMismatch { expected: Digest, actual: Digest },
+    InitialAccountHashMismatch { expected: Digest, actual: Digest },
+    NoteAlreadyConsumed(NoteId),
+}
+
+impl StateSimulatorError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of state simulator errors are in the range 10400..10500. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AccountDeltaApplicationFailed(_) => 10400,
+            Self::AccountNotFound(_) => 10401,
+            Self::FinalAccountHashMismatch { .. } => 10402,
+            Self::InitialAccountHashMismatch { .. } => 10403,
+            Self::NoteAlreadyConsumed(_) => 10404,
+        }
+    }
+}
+
+impl fmt::Display for StateSimulatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccountDeltaApplicationFailed(_) => write!(f, "failed to apply account delta"),
+            Self::AccountNotFound(account_id) => write!(f, "account {account_id} not found"),
+            Self::FinalAccountHashMismatch { expected, actual } => write!(
+                f,
+                "final account hash {} differs from the expected hash {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::InitialAccountHashMismatch { expected, actual } => write!(
+                f,
+                "initial account hash {} differs from the current hash {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::NoteAlreadyConsumed(note_id) => {
+                write!(f, "note {note_id} has already been consumed")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StateSimulatorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AccountDeltaApplicationFailed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// LOCAL CHAIN ERROR
+// ================================================================================================
+
+#[derive(Debug)]
+pub enum LocalChainError {
+    ApplyTransactionFailed(DataStoreError),
+    ExecuteTransactionFailed(TransactionExecutorError),
+    LoadAccountFailed(TransactionExecutorError),
+}
+
+impl LocalChainError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of local chain errors are in the range 10500..10600. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::ApplyTransactionFailed(_) => 10500,
+            Self::ExecuteTransactionFailed(_) => 10501,
+            Self::LoadAccountFailed(_) => 10502,
+        }
+    }
+}
+
+impl fmt::Display for LocalChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApplyTransactionFailed(_) => write!(f, "failed to apply transaction"),
+            Self::ExecuteTransactionFailed(_) => write!(f, "failed to execute transaction"),
+            Self::LoadAccountFailed(_) => write!(f, "failed to load account"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LocalChainError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ApplyTransactionFailed(err) => Some(err),
+            Self::ExecuteTransactionFailed(err) | Self::LoadAccountFailed(err) => Some(err),
+        }
+    }
+}
 
 // TRANSACTION VERIFIER ERROR
 // ================================================================================================
@@ -89,35 +592,238 @@ impl std::error::Error for TransactionProverError {}
 #[derive(Debug)]
 pub enum TransactionVerifierError {
     TransactionVerificationFailed(VerificationError),
+    InitialAccountHashMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    InsufficientFee {
+        required: FungibleAsset,
+        actual: Option<FungibleAsset>,
+    },
     InsufficientProofSecurityLevel(u32, u32),
+    InvalidAccountWitness(AccountId),
+    InvalidNullifierWitness(Nullifier),
+    MissingNullifierWitness(Nullifier),
+    NullifierAlreadySpent(Nullifier),
+    TransactionExpired {
+        expiration_block_num: u32,
+        block_num: u32,
+    },
+    UnknownKernel(Digest),
+}
+
+impl TransactionVerifierError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction verifier errors are in the range 10600..10700. The code of an error
+    /// never changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::TransactionVerificationFailed(_) => 10600,
+            Self::InitialAccountHashMismatch { .. } => 10601,
+            Self::InsufficientFee { .. } => 10609,
+            Self::InsufficientProofSecurityLevel(..) => 10602,
+            Self::InvalidAccountWitness(_) => 10603,
+            Self::InvalidNullifierWitness(_) => 10604,
+            Self::MissingNullifierWitness(_) => 10605,
+            Self::NullifierAlreadySpent(_) => 10606,
+            Self::TransactionExpired { .. } => 10607,
+            Self::UnknownKernel(_) => 10608,
+        }
+    }
 }
 
 impl fmt::Display for TransactionVerifierError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::TransactionVerificationFailed(_) => {
+                write!(f, "failed to verify transaction proof")
+            },
+            Self::InitialAccountHashMismatch { expected, actual } => write!(
+                f,
+                "initial account hash {} of the transaction differs from the account hash {} in \
+                 the account tree",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::InsufficientFee { required, actual: None } => write!(
+                f,
+                "transaction pays no fee, but a fee of at least {} issued by faucet {} is required",
+                required.amount(),
+                required.faucet_id()
+            ),
+            Self::InsufficientFee { required, actual: Some(actual) } => write!(
+                f,
+                "transaction pays a fee of {} issued by faucet {}, but a fee of at least {} issued \
+                 by faucet {} is required",
+                actual.amount(),
+                actual.faucet_id(),
+                required.amount(),
+                required.faucet_id()
+            ),
+            Self::InsufficientProofSecurityLevel(actual, min) => {
+                write!(f, "proof security level of {actual} bits is below the required {min} bits")
+            },
+            Self::InvalidAccountWitness(account_id) => {
+                write!(f, "witness of account {account_id} is not valid")
+            },
+            Self::InvalidNullifierWitness(nullifier) => {
+                write!(f, "witness of nullifier {nullifier} is not valid")
+            },
+            Self::MissingNullifierWitness(nullifier) => {
+                write!(f, "witness of nullifier {nullifier} was not provided")
+            },
+            Self::NullifierAlreadySpent(nullifier) => {
+                write!(f, "nullifier {nullifier} has already been spent")
+            },
+            Self::TransactionExpired { expiration_block_num, block_num } => write!(
+                f,
+                "transaction expired at block {expiration_block_num}, but the current block is \
+                 {block_num}"
+            ),
+            Self::UnknownKernel(kernel_root) => write!(
+                f,
+                "transaction kernel {} is not known to the verifier",
+                kernel_root.to_hex()
+            ),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionVerifierError {}
+impl std::error::Error for TransactionVerifierError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::TransactionVerificationFailed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // DATA STORE ERROR
 // ================================================================================================
 
 #[derive(Debug)]
 pub enum DataStoreError {
+    AccountDeltaApplicationFailed(AccountError),
     AccountNotFound(AccountId),
     BlockNotFound(u32),
+    FinalAccountHashMismatch { expected: Digest, actual: Digest },
     InvalidTransactionInput(TransactionInputError),
     InternalError(String),
+    NoteAlreadyConsumed(NoteId),
     NoteNotFound(NoteId),
 }
 
+impl DataStoreError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of data store errors are in the range 10700..10800. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AccountDeltaApplicationFailed(_) => 10700,
+            Self::AccountNotFound(_) => 10701,
+            Self::BlockNotFound(_) => 10702,
+            Self::FinalAccountHashMismatch { .. } => 10703,
+            Self::InvalidTransactionInput(_) => 10704,
+            Self::InternalError(_) => 10705,
+            Self::NoteNotFound(_) => 10706,
+            Self::NoteAlreadyConsumed(_) => 10707,
+        }
+    }
+}
+
 impl fmt::Display for DataStoreError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AccountDeltaApplicationFailed(_) => write!(f, "failed to apply account delta"),
+            Self::AccountNotFound(account_id) => write!(f, "account {account_id} not found"),
+            Self::BlockNotFound(block_num) => write!(f, "block {block_num} not found"),
+            Self::FinalAccountHashMismatch { expected, actual } => write!(
+                f,
+                "final account hash {} differs from the expected hash {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::InvalidTransactionInput(_) => write!(f, "invalid transaction inputs"),
+            Self::InternalError(msg) => write!(f, "internal data store error: {msg}"),
+            Self::NoteAlreadyConsumed(note_id) => {
+                write!(f, "note {note_id} has already been consumed")
+            },
+            Self::NoteNotFound(note_id) => write!(f, "note {note_id} not found"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DataStoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AccountDeltaApplicationFailed(err) => Some(err),
+            Self::InvalidTransactionInput(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// PIPELINE ERROR
+// ================================================================================================
+
+#[derive(Debug)]
+pub enum PipelineError {
+    Aborted(PipelineStage),
+    ExecuteTransactionFailed(TransactionExecutorError),
+    ProveTransactionFailed(TransactionProverError),
+    VerifyTransactionFailed(TransactionVerifierError),
+}
+
+impl PipelineError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of pipeline errors are in the range 10800..10900. The code of an error never changes,
+    /// and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Aborted(_) => 10800,
+            Self::ExecuteTransactionFailed(_) => 10801,
+            Self::ProveTransactionFailed(_) => 10802,
+            Self::VerifyTransactionFailed(_) => 10803,
+        }
+    }
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Aborted(stage) => write!(f, "pipeline was aborted by a hook after {stage}"),
+            Self::ExecuteTransactionFailed(_) => write!(f, "failed to execute transaction"),
+            Self::ProveTransactionFailed(_) => write!(f, "failed to prove transaction"),
+            Self::VerifyTransactionFailed(_) => write!(f, "failed to verify transaction"),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for DataStoreError {}
+impl std::error::Error for PipelineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Aborted(_) => None,
+            Self::ExecuteTransactionFailed(err) => Some(err),
+            Self::ProveTransactionFailed(err) => Some(err),
+            Self::VerifyTransactionFailed(err) => Some(err),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the error of the transaction kernel which corresponds to the code of the failed
+/// assertion, if the execution failed because of a failed assertion.
+fn kernel_error_from_execution_error(err: &ExecutionError) -> Option<&'static TxKernelError> {
+    match err {
+        ExecutionError::FailedAssertion { err_code, .. } => TxKernelError::from_code(*err_code),
+        _ => None,
+    }
+}
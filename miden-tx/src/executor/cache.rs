@@ -0,0 +1,99 @@
+use miden_objects::{
+    accounts::AccountCode, assembly::ModuleAst, utils::collections::BTreeMap, Digest,
+};
+
+// CODE CACHE
+// ================================================================================================
+
+/// A least-recently-used cache of compiled account code, keyed by code root.
+///
+/// The cache allows a [TransactionExecutor](super::TransactionExecutor) to skip assembling the
+/// code of accounts which have been loaded before. Cached code is looked up by its module, as
+/// the code root is known only once the module has been assembled.
+#[derive(Debug, Clone)]
+pub(super) struct CodeCache {
+    capacity: usize,
+    entries: BTreeMap<Digest, (AccountCode, u64)>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl CodeCache {
+    /// Returns a new empty [CodeCache] which holds at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: BTreeMap::new(),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns the compiled code of the specified module if it is in the cache, and marks the
+    /// entry as the most recently used one.
+    pub fn get(&mut self, module: &ModuleAst) -> Option<AccountCode> {
+        self.clock += 1;
+        let clock = self.clock;
+
+        let entry = self.entries.values_mut().find(|(code, _)| code.module() == module);
+        match entry {
+            Some((code, last_used)) => {
+                *last_used = clock;
+                self.hits += 1;
+                Some(code.clone())
+            },
+            None => {
+                self.misses += 1;
+                None
+            },
+        }
+    }
+
+    /// Inserts the provided compiled code into the cache, evicting the least recently used entry
+    /// if the cache is full.
+    pub fn insert(&mut self, code: AccountCode) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.clock += 1;
+        if !self.entries.contains_key(&code.root()) && self.entries.len() >= self.capacity {
+            let lru_root = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(root, _)| *root)
+                .expect("cache is not empty");
+            self.entries.remove(&lru_root);
+        }
+        self.entries.insert(code.root(), (code, self.clock));
+    }
+
+    /// Returns the statistics of this cache.
+    pub fn stats(&self) -> CodeCacheStats {
+        CodeCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            num_entries: self.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+// CODE CACHE STATS
+// ================================================================================================
+
+/// Statistics of the account code cache of a [TransactionExecutor](super::TransactionExecutor).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CodeCacheStats {
+    /// Number of account code loads served from the cache.
+    pub hits: u64,
+    /// Number of account code loads which required assembling the account code.
+    pub misses: u64,
+    /// Number of entries currently in the cache.
+    pub num_entries: usize,
+    /// Maximum number of entries in the cache.
+    pub capacity: usize,
+}
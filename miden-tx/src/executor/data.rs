@@ -1,5 +1,6 @@
 use miden_objects::{
-    accounts::AccountId, assembly::ModuleAst, notes::NoteId, transaction::TransactionInputs,
+    accounts::AccountId, assembly::ModuleAst, crypto::merkle::MerklePath, notes::NoteId,
+    transaction::TransactionInputs, utils::collections::Vec, BlockHeader,
 };
 
 use crate::DataStoreError;
@@ -17,6 +18,14 @@ pub trait DataStore {
     /// recorded in the chain. In general, it is recommended that bock_ref corresponds to the
     /// latest block available in the data store.
     ///
+    /// If the transaction needs to read the storage of other accounts (e.g., of an oracle), the
+    /// data store should attach the stubs, witnesses and storage openings of these accounts to the
+    /// returned inputs via [TransactionInputs::with_foreign_accounts()].
+    ///
+    /// The chain MMR of the returned inputs does not need to contain the blocks in which the input
+    /// notes were created: it may track only the peaks of the chain as of block_ref. The executor
+    /// requests the headers of the missing blocks via [DataStore::get_block_headers()].
+    ///
     /// # Errors
     /// Returns an error if:
     /// - The account with the specified ID could not be found in the data store.
@@ -31,6 +40,57 @@ pub trait DataStore {
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError>;
 
+    /// Returns the headers of the blocks with the specified numbers, each together with the
+    /// authentication path of the block in the chain MMR as of the block with number block_ref.
+    ///
+    /// The executor calls this method with the blocks which are needed to authenticate the input
+    /// notes of a transaction but are not present in the chain MMR returned by
+    /// [DataStore::get_transaction_inputs()]; all of the blocks precede block_ref.
+    ///
+    /// # Errors
+    /// Returns an error if any of the blocks could not be found in the data store.
+    fn get_block_headers(
+        &self,
+        block_ref: u32,
+        block_nums: &[u32],
+    ) -> Result<Vec<(BlockHeader, MerklePath)>, DataStoreError>;
+
     /// Returns the account code [ModuleAst] associated with the the specified [AccountId].
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError>;
 }
+
+// ASYNC DATA STORE TRAIT
+// ================================================================================================
+
+/// The [AsyncDataStore] trait defines an asynchronous version of the [DataStore] interface.
+///
+/// This is intended for data stores backed by databases or remote nodes, so that the
+/// [TransactionExecutor](super::TransactionExecutor) does not need to block a thread while the
+/// data required for transaction execution is being fetched.
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncDataStore {
+    /// Returns account, chain, and input note data required to execute a transaction against
+    /// the account with the specified ID and consuming the set of specified input notes.
+    ///
+    /// See [DataStore::get_transaction_inputs()] for the details of the expected behavior.
+    async fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError>;
+
+    /// Returns the headers of the blocks with the specified numbers, each together with the
+    /// authentication path of the block in the chain MMR as of the block with number block_ref.
+    ///
+    /// See [DataStore::get_block_headers()] for the details of the expected behavior.
+    async fn get_block_headers(
+        &self,
+        block_ref: u32,
+        block_nums: &[u32],
+    ) -> Result<Vec<(BlockHeader, MerklePath)>, DataStoreError>;
+
+    /// Returns the account code [ModuleAst] associated with the the specified [AccountId].
+    async fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError>;
+}
@@ -0,0 +1,111 @@
+use miden_lib::transaction::memory::{
+    ACCT_DATA_SECTION_OFFSET, CONSUMED_NOTE_SECTION_OFFSET, CURRENT_CONSUMED_NOTE_PTR,
+    NOTE_MEM_SIZE,
+};
+use miden_objects::{
+    utils::{collections::Vec, string::String},
+    vm::{Program, StackInputs},
+    Felt, StarkField, Word,
+};
+use vm_processor::{ContextId, Host, VmState};
+
+// TRANSACTION DEBUG INFO
+// ================================================================================================
+
+/// A snapshot of the state of the VM at the point at which the execution of a transaction program
+/// failed.
+///
+/// The snapshot is taken at the last operation which was executed successfully, and contains the
+/// regions of the kernel memory which are most useful for diagnosing failures of note and
+/// transaction scripts. The location of the failure is available only if the transaction program
+/// was compiled in debug mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionDebugInfo {
+    /// Clock cycle of the last operation executed before the failure.
+    pub clk: u32,
+    /// Context in which the last operation was executed.
+    pub ctx: ContextId,
+    /// Name of the procedure containing the last executed MASM instruction.
+    pub procedure: Option<String>,
+    /// Last executed MASM instruction.
+    pub instruction: Option<String>,
+    /// Operand stack after the last executed operation, with the top of the stack first.
+    pub stack: Vec<Felt>,
+    /// Kernel memory holding the data of the note which was being consumed, as (address, word)
+    /// pairs; empty if no note was being consumed.
+    pub consumed_note_memory: Vec<(u64, Word)>,
+    /// Kernel memory holding the data of the account, as (address, word) pairs.
+    pub account_memory: Vec<(u64, Word)>,
+}
+
+impl TransactionDebugInfo {
+    /// Executes the provided transaction program step by step and returns a snapshot of the state
+    /// of the VM at the point at which the execution failed.
+    ///
+    /// The program is expected to fail with the provided inputs; if it does not, the snapshot is
+    /// taken at the last executed operation.
+    pub(super) fn from_failed_execution<H: Host>(
+        program: &Program,
+        stack_inputs: StackInputs,
+        host: H,
+    ) -> Self {
+        // the kernel memory can be modified only in the root context, and thus the memory of the
+        // last state in the root context reflects the kernel memory at the point of failure
+        let mut last_kernel_state: Option<VmState> = None;
+        let mut last_user_state: Option<VmState> = None;
+        for state in vm_processor::execute_iter(program, stack_inputs, host) {
+            match state {
+                Ok(state) if state.ctx == ContextId::root() => {
+                    last_kernel_state = Some(state);
+                    last_user_state = None;
+                },
+                Ok(state) => last_user_state = Some(state),
+                Err(_) => break,
+            }
+        }
+
+        let kernel_memory = last_kernel_state
+            .as_ref()
+            .map(|state| state.memory.as_slice())
+            .unwrap_or_default();
+        let note_ptr = kernel_memory_word(kernel_memory, CURRENT_CONSUMED_NOTE_PTR as u64)
+            .map(|word| word[0].as_int())
+            .unwrap_or_default();
+        let consumed_note_memory = if note_ptr != 0 {
+            kernel_memory_region(kernel_memory, note_ptr, note_ptr + NOTE_MEM_SIZE as u64)
+        } else {
+            Vec::new()
+        };
+        let account_memory = kernel_memory_region(
+            kernel_memory,
+            ACCT_DATA_SECTION_OFFSET as u64,
+            CONSUMED_NOTE_SECTION_OFFSET as u64,
+        );
+
+        let last_state = last_user_state.as_ref().or(last_kernel_state.as_ref());
+        let asmop = last_state.and_then(|state| state.asmop.as_ref());
+
+        Self {
+            clk: last_state.map(|state| state.clk).unwrap_or_default(),
+            ctx: last_state.map(|state| state.ctx).unwrap_or(ContextId::root()),
+            procedure: asmop.map(|asmop| asmop.context_name().into()),
+            instruction: asmop.map(|asmop| asmop.op().into()),
+            stack: last_state.map(|state| state.stack.clone()).unwrap_or_default(),
+            consumed_note_memory,
+            account_memory,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the word stored at the specified address of the provided memory, if any.
+fn kernel_memory_word(memory: &[(u64, Word)], addr: u64) -> Option<Word> {
+    memory.iter().find(|(word_addr, _)| *word_addr == addr).map(|(_, word)| *word)
+}
+
+/// Returns the words stored in the provided memory at addresses in the range [start, end).
+fn kernel_memory_region(memory: &[(u64, Word)], start: u64, end: u64) -> Vec<(u64, Word)> {
+    memory.iter().filter(|(addr, _)| (start..end).contains(addr)).copied().collect()
+}
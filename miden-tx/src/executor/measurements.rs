@@ -0,0 +1,60 @@
+use miden_objects::{notes::NoteId, utils::collections::Vec};
+
+use crate::host::TransactionProgress;
+
+// TRANSACTION MEASUREMENTS
+// ================================================================================================
+
+/// Measurements of the execution of a transaction, which can be used to estimate the cost of
+/// proving the transaction and to profile note and transaction scripts.
+///
+/// Cycle counts are measured between the traces emitted by the transaction kernel at the
+/// boundaries of the sections of the transaction program, and thus include the kernel code
+/// executed in each section (e.g., the setup of each note before its script is invoked).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionMeasurements {
+    /// Number of cycles spent executing the transaction prologue.
+    pub prologue: usize,
+    /// Number of cycles spent processing the input notes, including executing the note scripts.
+    pub notes_processing: usize,
+    /// Number of cycles spent executing each of the input notes, in the order of consumption.
+    pub note_execution: Vec<(NoteId, usize)>,
+    /// Number of cycles spent executing the transaction script.
+    pub tx_script_processing: usize,
+    /// Number of cycles spent executing the transaction epilogue.
+    pub epilogue: usize,
+    /// Number of entries in the advice map of the transaction witness.
+    pub advice_map_size: usize,
+    /// Length of the execution trace of the transaction, padded to the next power of two.
+    pub trace_length: usize,
+}
+
+impl TransactionMeasurements {
+    /// Returns new [TransactionMeasurements] built from the progress recorded while executing a
+    /// transaction consuming the specified notes.
+    pub(super) fn new<I>(
+        tx_progress: &TransactionProgress,
+        note_ids: I,
+        advice_map_size: usize,
+        trace_length: usize,
+    ) -> Self
+    where
+        I: IntoIterator<Item = NoteId>,
+    {
+        Self {
+            prologue: tx_progress.prologue(),
+            notes_processing: tx_progress.notes_processing(),
+            note_execution: note_ids.into_iter().zip(tx_progress.note_execution()).collect(),
+            tx_script_processing: tx_progress.tx_script_processing(),
+            epilogue: tx_progress.epilogue(),
+            advice_map_size,
+            trace_length,
+        }
+    }
+
+    /// Returns the total number of cycles spent in the measured sections of the transaction
+    /// program.
+    pub fn total_cycles(&self) -> usize {
+        self.prologue + self.notes_processing + self.tx_script_processing + self.epilogue
+    }
+}
@@ -1,21 +1,51 @@
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
 use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
+
 use miden_objects::{
-    assembly::ProgramAst,
-    transaction::{TransactionArgs, TransactionInputs, TransactionScript},
+    assembly::{MaslLibrary, ModuleAst, ProgramAst},
+    notes::Note,
+    transaction::{OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionScript},
+    utils::collections::Vec,
     vm::{Program, StackOutputs},
     Felt, Word, ZERO,
 };
-use vm_processor::ExecutionOptions;
+use vm_processor::{ExecutionError, ExecutionOptions, MemAdviceProvider};
 
 use super::{
-    AccountCode, AccountId, Digest, ExecutedTransaction, NoteId, NoteScript, PreparedTransaction,
-    RecAdviceProvider, ScriptTarget, TransactionCompiler, TransactionExecutorError,
-    TransactionHost,
+    AccountCode, AccountId, CompatibilityReport, Digest, ExecutedTransaction, NoteId, NoteScript,
+    PreparedTransaction, RecAdviceProvider, ScriptTarget, TransactionCompiler,
+    TransactionExecutorError, TransactionHost,
 };
 
+mod cache;
+use cache::CodeCache;
+pub use cache::CodeCacheStats;
+
 mod data;
+#[cfg(feature = "async")]
+pub use data::AsyncDataStore;
 pub use data::DataStore;
 
+mod debug;
+pub use debug::TransactionDebugInfo;
+
+mod measurements;
+pub use measurements::TransactionMeasurements;
+
+mod policy;
+pub use policy::TransactionPolicy;
+
+mod reexecution;
+#[cfg(feature = "testing")]
+pub(crate) use reexecution::reexecute_witness_with_measurements;
+pub use reexecution::{reexecute_transaction, reexecute_witness};
+
+#[cfg(feature = "std")]
+mod simulation;
+#[cfg(feature = "std")]
+pub use simulation::SimulatedTransaction;
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -27,54 +57,129 @@ pub use data::DataStore;
 /// - Execute the transaction program and create an [ExecutedTransaction].
 ///
 /// The transaction executor is generic over the [DataStore] which allows it to be used with
-/// different data backend implementations.
+/// different data backend implementations. With the `async` feature enabled, the executor can
+/// also be used with an `AsyncDataStore` via `TransactionExecutor::execute_transaction_async()`.
 ///
 /// The [TransactionExecutor::execute_transaction()] method is the main entry point for the
 /// executor and produces an [ExecutedTransaction] for the transaction. The executed transaction
 /// can then be used to by the prover to generate a proof transaction execution.
-pub struct TransactionExecutor<D: DataStore> {
+///
+/// Transactions can be restricted by a [TransactionPolicy], which the executor evaluates both
+/// before and after executing a transaction.
+///
+/// Libraries which are not part of miden-lib or the Miden standard library can be made available to
+/// account code and scripts via [TransactionExecutor::with_library()].
+///
+/// Optionally, the executor can cache the compiled code of the accounts it loads (see
+/// [TransactionExecutor::with_code_cache()]), so that loading the same account code repeatedly
+/// does not require assembling it every time.
+///
+/// The cost of executing a transaction can be measured via
+/// [TransactionExecutor::execute_transaction_with_measurements()], and failures of transaction
+/// programs can be diagnosed via [TransactionExecutor::execute_transaction_debug()]. With the
+/// `std` feature enabled, the effects of a transaction can be previewed without the secret key of
+/// the account via `TransactionExecutor::simulate_transaction()`.
+///
+/// With the `tracing` feature enabled, compiling and executing transactions emits `tracing` spans
+/// and events recording the duration and the cycle count of every execution; the
+/// [LocalTransactionProver](crate::LocalTransactionProver) and the
+/// [TransactionVerifier](crate::TransactionVerifier) are instrumented in the same way.
+pub struct TransactionExecutor<D> {
     data_store: D,
     compiler: TransactionCompiler,
     exec_options: ExecutionOptions,
+    policy: TransactionPolicy,
+    code_cache: Option<CodeCache>,
+    libraries: Vec<MaslLibrary>,
 }
 
-impl<D: DataStore> TransactionExecutor<D> {
+impl<D> TransactionExecutor<D> {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Creates a new [TransactionExecutor] instance with the specified [DataStore].
+    /// Creates a new [TransactionExecutor] instance with the specified data store.
     pub fn new(data_store: D) -> Self {
         Self {
             data_store,
             compiler: TransactionCompiler::new(),
             exec_options: ExecutionOptions::default(),
+            policy: TransactionPolicy::default(),
+            code_cache: None,
+            libraries: Vec::new(),
         }
     }
 
-    // STATE MUTATORS
-    // --------------------------------------------------------------------------------------------
+    /// Sets the [TransactionPolicy] which transactions executed by this executor must satisfy.
+    pub fn with_policy(mut self, policy: TransactionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
 
-    /// Fetches the account code from the [DataStore], compiles it, and loads the compiled code
-    /// into the internal cache.
+    /// Limits the number of VM cycles a transaction executed by this executor may take; the
+    /// execution of transactions exceeding this limit is aborted with
+    /// [TransactionExecutorError::CycleLimitExceeded].
     ///
-    /// This also returns the [AccountCode] object built from the loaded account code.
+    /// By default, the number of cycles is limited only by the maximum supported by the VM.
     ///
-    /// # Errors:
-    /// Returns an error if:
-    /// - If the account code cannot be fetched from the [DataStore].
-    /// - If the account code fails to be loaded into the compiler.
-    pub fn load_account(
-        &mut self,
-        account_id: AccountId,
-    ) -> Result<AccountCode, TransactionExecutorError> {
-        let account_code = self
-            .data_store
-            .get_account_code(account_id)
-            .map_err(TransactionExecutorError::FetchAccountCodeFailed)?;
-        self.compiler
-            .load_account(account_id, account_code)
-            .map_err(TransactionExecutorError::LoadAccountFailed)
+    /// # Errors
+    /// Returns an error if the limit is smaller than the minimum number of cycles supported by
+    /// the VM.
+    pub fn with_max_cycles(mut self, max_cycles: u32) -> Result<Self, TransactionExecutorError> {
+        let expected_cycles = self.exec_options.expected_cycles().min(max_cycles);
+        self.exec_options = ExecutionOptions::new(
+            Some(max_cycles),
+            expected_cycles,
+            self.exec_options.enable_tracing(),
+        )
+        .map_err(TransactionExecutorError::InvalidExecutionOptions)?;
+        Ok(self)
+    }
+
+    /// Loads the provided library into the compiler of this executor.
+    ///
+    /// Account code, note scripts and transaction scripts compiled by this executor can then
+    /// import the modules of the library (e.g., `use.myproject::math` for a library with namespace
+    /// `myproject`), in addition to the modules of miden-lib and the Miden standard library.
+    ///
+    /// # Errors
+    /// Returns an error if the library cannot be loaded (e.g., because it depends on a library
+    /// which has not been loaded).
+    pub fn with_library(mut self, library: MaslLibrary) -> Result<Self, TransactionExecutorError> {
+        self.compiler = self
+            .compiler
+            .with_library(&library)
+            .map_err(TransactionExecutorError::LoadLibraryFailed)?;
+        self.libraries.push(library);
+        Ok(self)
+    }
+
+    /// Enables caching of compiled account code, keeping at most `capacity` distinct account codes;
+    /// the least recently used code is evicted once the cache is full.
+    pub fn with_code_cache(mut self, capacity: usize) -> Self {
+        self.code_cache = Some(CodeCache::new(capacity));
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [TransactionPolicy] of this executor.
+    pub fn policy(&self) -> &TransactionPolicy {
+        &self.policy
     }
 
+    /// Returns the maximum number of VM cycles a transaction executed by this executor may take.
+    pub fn max_cycles(&self) -> u32 {
+        self.exec_options.max_cycles()
+    }
+
+    /// Returns the statistics of the account code cache, or None if the cache is not enabled.
+    pub fn code_cache_stats(&self) -> Option<CodeCacheStats> {
+        self.code_cache.as_ref().map(|cache| cache.stats())
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
     /// Loads the provided account interface (vector of procedure digests) into the compiler.
     ///
     /// Returns the old interface for the specified account ID if it previously existed.
@@ -86,6 +191,15 @@ impl<D: DataStore> TransactionExecutor<D> {
         self.compiler.load_account_interface(account_id, procedures)
     }
 
+    /// Loads the procedures of the provided [AccountCode], together with their access, into the
+    /// compiler.
+    ///
+    /// This allows restricting the procedures which can be called by note scripts consumed by the
+    /// specified account (see [AccountCode::with_procedure_access()]).
+    pub fn load_account_code(&mut self, account_id: AccountId, account_code: &AccountCode) {
+        self.compiler.load_account_code(account_id, account_code)
+    }
+
     // COMPILERS
     // --------------------------------------------------------------------------------------------
 
@@ -102,6 +216,21 @@ impl<D: DataStore> TransactionExecutor<D> {
             .map_err(TransactionExecutorError::CompileNoteScriptFailed)
     }
 
+    /// Checks whether the provided note script can be executed against the specified account
+    /// interface, and returns a [CompatibilityReport] listing the procedures called by the script
+    /// which are missing from the interface.
+    ///
+    /// See [TransactionCompiler::check_note_compatibility()] for details.
+    pub fn check_note_compatibility(
+        &self,
+        note_script: &NoteScript,
+        target: ScriptTarget,
+    ) -> Result<CompatibilityReport, TransactionExecutorError> {
+        self.compiler
+            .check_note_compatibility(note_script, target)
+            .map_err(TransactionExecutorError::CompileNoteScriptFailed)
+    }
+
     /// Compiles the provided transaction script source and inputs into a [TransactionScript] and
     /// checks (to the extent possible) that the transaction script can be executed against all
     /// accounts with the specified interfaces.
@@ -119,6 +248,164 @@ impl<D: DataStore> TransactionExecutor<D> {
             .map_err(TransactionExecutorError::CompileTransactionScriptFailed)
     }
 
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the provided account code into the compiler, compiling it unless the compiled code is
+    /// found in the account code cache, and returns the compiled [AccountCode].
+    ///
+    /// # Errors:
+    /// Returns an error if the account code fails to be loaded into the compiler.
+    fn load_account_module(
+        &mut self,
+        account_id: AccountId,
+        account_code: ModuleAst,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        let cached_code = self.code_cache.as_mut().and_then(|cache| cache.get(&account_code));
+        if let Some(cached_code) = cached_code {
+            self.compiler.load_account_code(account_id, &cached_code);
+            return Ok(cached_code);
+        }
+
+        let account_code = self
+            .compiler
+            .load_account(account_id, account_code)
+            .map_err(TransactionExecutorError::LoadAccountFailed)?;
+        if let Some(code_cache) = self.code_cache.as_mut() {
+            code_cache.insert(account_code.clone());
+        }
+
+        Ok(account_code)
+    }
+
+    /// Compiles the transaction specified by the provided inputs and arguments into an executable
+    /// program using the [TransactionCompiler], and returns a [PreparedTransaction].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - The transaction inputs are not valid (see [TransactionInputs::validate()]).
+    /// - The transaction can not be compiled.
+    fn compile_transaction(
+        &self,
+        account_id: AccountId,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+    ) -> Result<PreparedTransaction, TransactionExecutorError> {
+        // fail early on invalid inputs rather than on a failed assertion during execution
+        tx_inputs
+            .validate()
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+
+        let tx_program = self
+            .compiler
+            .compile_transaction(
+                account_id,
+                tx_inputs.input_notes(),
+                tx_args.tx_script().map(|x| x.code()),
+            )
+            .map_err(TransactionExecutorError::CompileTransactionFailed)?;
+
+        Ok(PreparedTransaction::new(tx_program, tx_inputs, tx_args))
+    }
+
+    /// Executes the provided [PreparedTransaction] with the specified execution options and
+    /// returns an [ExecutedTransaction] together with the [TransactionMeasurements] of its
+    /// execution.
+    ///
+    /// Cycle counts are measured only if tracing is enabled in the execution options.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the transaction program can not be executed or exceeds the cycle limit of this
+    ///   executor.
+    /// - If the transaction violates the [TransactionPolicy] of this executor.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "execute_transaction",
+            skip_all,
+            fields(account_id = %transaction.account().id()),
+            err
+        )
+    )]
+    fn execute_prepared_transaction(
+        &self,
+        transaction: PreparedTransaction,
+        exec_options: ExecutionOptions,
+    ) -> Result<(ExecutedTransaction, TransactionMeasurements), TransactionExecutorError> {
+        #[cfg(feature = "tracing")]
+        let timer = crate::timer::Timer::start();
+
+        self.policy
+            .check_prepared_transaction(&transaction)
+            .map_err(TransactionExecutorError::PolicyViolation)?;
+
+        let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
+        let advice_recorder: RecAdviceProvider = advice_inputs.into();
+        let mut host = TransactionHost::new(transaction.account().into(), advice_recorder);
+
+        let result =
+            vm_processor::execute(transaction.program(), stack_inputs, &mut host, exec_options)
+                .map_err(|err| match err {
+                    ExecutionError::CycleLimitExceeded(max_cycles) => {
+                        TransactionExecutorError::CycleLimitExceeded(max_cycles)
+                    },
+                    err => TransactionExecutorError::ExecuteTransactionProgramFailed(err),
+                })?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            duration_ms = timer.elapsed_ms(),
+            cycles = result.get_trace_len(),
+            "executed transaction program"
+        );
+
+        let (tx_program, tx_inputs, tx_args) = transaction.into_parts();
+
+        let (executed_transaction, measurements) = build_executed_transaction(
+            tx_program,
+            tx_args,
+            tx_inputs,
+            result.stack_outputs().clone(),
+            result.get_trace_len(),
+            host,
+        )?;
+        self.policy
+            .check_executed_transaction(&executed_transaction)
+            .map_err(TransactionExecutorError::PolicyViolation)?;
+
+        Ok((executed_transaction, measurements))
+    }
+}
+
+impl<D: DataStore> TransactionExecutor<D> {
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches the account code from the [DataStore], compiles it, and loads the compiled code
+    /// into the internal cache.
+    ///
+    /// If the account code cache is enabled and already contains the fetched code, the cached
+    /// compiled code is loaded instead of compiling the code again.
+    ///
+    /// This also returns the [AccountCode] object built from the loaded account code.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the account code cannot be fetched from the [DataStore].
+    /// - If the account code fails to be loaded into the compiler.
+    pub fn load_account(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        let account_code = self
+            .data_store
+            .get_account_code(account_id)
+            .map_err(TransactionExecutorError::FetchAccountCodeFailed)?;
+
+        self.load_account_module(account_id, account_code)
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -134,6 +421,7 @@ impl<D: DataStore> TransactionExecutor<D> {
     /// - If required data can not be fetched from the [DataStore].
     /// - If the transaction program can not be compiled.
     /// - If the transaction program can not be executed.
+    /// - If the transaction violates the [TransactionPolicy] of this executor.
     pub fn execute_transaction(
         &self,
         account_id: AccountId,
@@ -143,28 +431,206 @@ impl<D: DataStore> TransactionExecutor<D> {
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
         let transaction =
             self.prepare_transaction(account_id, block_ref, notes, tx_args.unwrap_or_default())?;
+        self.execute_prepared_transaction(transaction, self.exec_options)
+            .map(|(executed_transaction, _)| executed_transaction)
+    }
 
-        let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
-        let advice_recorder: RecAdviceProvider = advice_inputs.into();
-        let mut host = TransactionHost::new(transaction.account().into(), advice_recorder);
+    /// Prepares and executes a transaction consuming both notes fetched from the [DataStore] and
+    /// the provided unauthenticated notes, and returns an [ExecutedTransaction].
+    ///
+    /// Unauthenticated notes are consumed without proofs of their inclusion in the chain, which
+    /// makes it possible to consume notes created in the same block or transmitted off-chain
+    /// before they are included in a block. The transaction kernel commits to the headers of such
+    /// notes, and the resulting [ProvenTransaction](miden_objects::transaction::ProvenTransaction)
+    /// lists them so that the operator can authenticate them when the transaction is included in
+    /// a block.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If any of the unauthenticated notes is also among the notes fetched from the
+    ///   [DataStore], or the total number of notes exceeds the maximum.
+    /// - If the transaction program can not be compiled.
+    /// - If the transaction program can not be executed.
+    /// - If the transaction violates the [TransactionPolicy] of this executor.
+    pub fn execute_transaction_with_unauthenticated_notes(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        unauthenticated_notes: &[Note],
+        tx_args: Option<TransactionArgs>,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs = self
+            .fetch_transaction_inputs(account_id, block_ref, notes)?
+            .with_unauthenticated_notes(unauthenticated_notes.to_vec())
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
 
-        let result = vm_processor::execute(
-            transaction.program(),
-            stack_inputs,
-            &mut host,
-            self.exec_options,
-        )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+        let transaction =
+            self.compile_transaction(account_id, tx_inputs, tx_args.unwrap_or_default())?;
+        self.execute_prepared_transaction(transaction, self.exec_options)
+            .map(|(executed_transaction, _)| executed_transaction)
+    }
 
-        let (tx_program, tx_inputs, tx_args) = transaction.into_parts();
+    /// Prepares and executes a transaction specified by the provided arguments and returns an
+    /// [ExecutedTransaction] together with the [TransactionMeasurements] of its execution.
+    ///
+    /// This is the same as [TransactionExecutor::execute_transaction()], except that the
+    /// transaction program is executed with tracing enabled so that the number of cycles spent in
+    /// each section of the program (i.e., prologue, note scripts, transaction script, and
+    /// epilogue) can be measured.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction program can not be compiled.
+    /// - If the transaction program can not be executed.
+    /// - If the transaction violates the [TransactionPolicy] of this executor.
+    pub fn execute_transaction_with_measurements(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: Option<TransactionArgs>,
+    ) -> Result<(ExecutedTransaction, TransactionMeasurements), TransactionExecutorError> {
+        let transaction =
+            self.prepare_transaction(account_id, block_ref, notes, tx_args.unwrap_or_default())?;
+        self.execute_prepared_transaction(transaction, self.exec_options.with_tracing())
+    }
 
-        build_executed_transaction(
-            tx_program,
-            tx_args,
+    /// Simulates the execution of a transaction specified by the provided arguments and returns
+    /// the changes the transaction would make to the account together with the notes it would
+    /// create.
+    ///
+    /// The transaction is executed by the full transaction kernel, except that authentication is
+    /// skipped: the calls of the transaction script to the authentication procedures of miden-lib
+    /// (e.g., `auth_tx_rpo_falcon512` or `auth_tx_rpo_falcon512_multisig`) are replaced with calls
+    /// to a procedure which only increments the nonce of the account, and this procedure is added
+    /// to the code of the account for the duration of the simulation. The storage of the account
+    /// is left unchanged, and thus the simulation works regardless of the authentication scheme of
+    /// the account. This makes it possible to preview the effects of a transaction before asking
+    /// the owner of the account to sign it.
+    ///
+    /// The simulation does not produce a provable witness, as the code of the account is altered.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the account is new, or its code can not be extended with the simulation
+    ///   authentication procedure.
+    /// - If the transaction program can not be compiled.
+    /// - If the transaction program can not be executed.
+    /// - If the transaction violates the [TransactionPolicy] of this executor.
+    #[cfg(feature = "std")]
+    pub fn simulate_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: Option<TransactionArgs>,
+    ) -> Result<SimulatedTransaction, TransactionExecutorError> {
+        let tx_inputs = self.fetch_transaction_inputs(account_id, block_ref, notes)?;
+        let tx_args = tx_args.unwrap_or_default();
+        tx_inputs
+            .validate()
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+
+        let (tx_program, tx_script_root) = self
+            .compiler
+            .compile_simulated_transaction(
+                account_id,
+                tx_inputs.input_notes(),
+                tx_args.tx_script().map(|x| x.code()),
+            )
+            .map_err(TransactionExecutorError::CompileTransactionFailed)?;
+        let (tx_inputs, tx_args) = simulation::build_simulation_inputs(
             tx_inputs,
-            result.stack_outputs().clone(),
-            host,
-        )
+            tx_args,
+            self.compiler.simulation_auth_procedure(),
+            tx_script_root,
+        )?;
+
+        let transaction = PreparedTransaction::new(tx_program, tx_inputs, tx_args);
+        let (executed_transaction, _) =
+            self.execute_prepared_transaction(transaction, self.exec_options)?;
+
+        let (account_delta, tx_outputs, _) = executed_transaction.into_parts();
+        Ok(SimulatedTransaction::new(account_delta, tx_outputs.output_notes))
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments in debug mode and
+    /// returns an [ExecutedTransaction].
+    ///
+    /// The transaction is compiled by a [TransactionCompiler](crate::TransactionCompiler) in debug
+    /// mode (which requires the account code to be fetched from the [DataStore] and compiled
+    /// anew) and executed with debugging enabled. If the execution of the transaction program
+    /// fails, the program is executed once more step by step to capture the state of the VM at
+    /// the point of failure, which is returned as [TransactionDebugInfo] alongside the execution
+    /// error.
+    ///
+    /// This is considerably slower than [TransactionExecutor::execute_transaction()] and is meant
+    /// to be used for diagnosing failing transactions only.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the account code or the data required to execute the transaction can not be fetched
+    ///   from the [DataStore].
+    /// - If the transaction program can not be compiled.
+    /// - If the transaction program can not be executed, in which case
+    ///   [TransactionExecutorError::ExecuteTransactionProgramFailedWithDebugInfo] is returned.
+    /// - If the transaction violates the [TransactionPolicy] of this executor.
+    pub fn execute_transaction_debug(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: Option<TransactionArgs>,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let account_code = self
+            .data_store
+            .get_account_code(account_id)
+            .map_err(TransactionExecutorError::FetchAccountCodeFailed)?;
+        let tx_inputs = self.fetch_transaction_inputs(account_id, block_ref, notes)?;
+        let tx_args = tx_args.unwrap_or_default();
+
+        // compile the transaction in debug mode so that the program retains the debug decorators
+        let mut compiler = self
+            .libraries
+            .iter()
+            .try_fold(TransactionCompiler::new_debug(), |compiler, library| {
+                compiler.with_library(library)
+            })
+            .map_err(TransactionExecutorError::LoadLibraryFailed)?;
+        compiler
+            .load_account(account_id, account_code)
+            .map_err(TransactionExecutorError::LoadAccountFailed)?;
+        let tx_program = compiler
+            .compile_transaction(
+                account_id,
+                tx_inputs.input_notes(),
+                tx_args.tx_script().map(|x| x.code()),
+            )
+            .map_err(TransactionExecutorError::CompileTransactionFailed)?;
+        let transaction = PreparedTransaction::new(tx_program, tx_inputs, tx_args);
+
+        // keep the inputs of the transaction program to be able to replay a failed execution
+        let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
+        let tx_program = transaction.program().clone();
+        let account = transaction.account().into();
+
+        match self.execute_prepared_transaction(transaction, self.exec_options.with_debugging()) {
+            Err(TransactionExecutorError::ExecuteTransactionProgramFailed(err)) => {
+                let advice_provider: MemAdviceProvider = advice_inputs.into();
+                let host = TransactionHost::new(account, advice_provider);
+                let debug_info =
+                    TransactionDebugInfo::from_failed_execution(&tx_program, stack_inputs, host);
+                Err(TransactionExecutorError::ExecuteTransactionProgramFailedWithDebugInfo(
+                    err,
+                    Box::new(debug_info),
+                ))
+            },
+            result => result.map(|(executed_transaction, _)| executed_transaction),
+        }
     }
 
     // HELPER METHODS
@@ -185,43 +651,173 @@ impl<D: DataStore> TransactionExecutor<D> {
         notes: &[NoteId],
         tx_args: TransactionArgs,
     ) -> Result<PreparedTransaction, TransactionExecutorError> {
+        let tx_inputs = self.fetch_transaction_inputs(account_id, block_ref, notes)?;
+
+        self.compile_transaction(account_id, tx_inputs, tx_args)
+    }
+
+    /// Fetches the data required to execute the transaction from the [DataStore].
+    ///
+    /// If the chain MMR returned by the [DataStore] does not contain some of the blocks needed to
+    /// authenticate the input notes, the headers of these blocks are fetched from the [DataStore]
+    /// and added to the chain MMR.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the transaction inputs or the missing block headers can not be fetched from the
+    ///   [DataStore].
+    /// - If any of the fetched block headers can not be added to the chain MMR.
+    fn fetch_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, TransactionExecutorError> {
         let tx_inputs = self
             .data_store
             .get_transaction_inputs(account_id, block_ref, notes)
             .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
-        let tx_program = self
-            .compiler
-            .compile_transaction(
-                account_id,
-                tx_inputs.input_notes(),
-                tx_args.tx_script().map(|x| x.code()),
-            )
-            .map_err(TransactionExecutorError::CompileTransactionFailed)?;
+        let block_nums = tx_inputs.untracked_note_blocks();
+        if block_nums.is_empty() {
+            return Ok(tx_inputs);
+        }
+        let block_headers = self
+            .data_store
+            .get_block_headers(block_ref, &block_nums)
+            .map_err(TransactionExecutorError::FetchBlockHeadersFailed)?;
 
-        Ok(PreparedTransaction::new(tx_program, tx_inputs, tx_args))
+        tx_inputs
+            .with_block_headers(block_headers)
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDataStore> TransactionExecutor<D> {
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches the account code from the [AsyncDataStore], compiles it, and loads the compiled
+    /// code into the internal cache.
+    ///
+    /// This is the asynchronous counterpart of [TransactionExecutor::load_account()].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the account code cannot be fetched from the [AsyncDataStore].
+    /// - If the account code fails to be loaded into the compiler.
+    pub async fn load_account_async(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        let account_code = self
+            .data_store
+            .get_account_code(account_id)
+            .await
+            .map_err(TransactionExecutorError::FetchAccountCodeFailed)?;
+        self.load_account_module(account_id, account_code)
+    }
+
+    // TRANSACTION EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Prepares and executes a transaction specified by the provided arguments and returns an
+    /// [ExecutedTransaction].
+    ///
+    /// This is the asynchronous counterpart of [TransactionExecutor::execute_transaction()]: only
+    /// fetching the data required to execute the transaction from the [AsyncDataStore] is
+    /// asynchronous, while compilation and execution of the transaction program are performed
+    /// synchronously.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [AsyncDataStore].
+    /// - If the transaction program can not be compiled.
+    /// - If the transaction program can not be executed.
+    /// - If the transaction violates the [TransactionPolicy] of this executor.
+    pub async fn execute_transaction_async(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: Option<TransactionArgs>,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs = self.fetch_transaction_inputs_async(account_id, block_ref, notes).await?;
+
+        let transaction =
+            self.compile_transaction(account_id, tx_inputs, tx_args.unwrap_or_default())?;
+        self.execute_prepared_transaction(transaction, self.exec_options)
+            .map(|(executed_transaction, _)| executed_transaction)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches the data required to execute the transaction from the [AsyncDataStore], including
+    /// the headers of the blocks needed to authenticate the input notes which are missing from the
+    /// chain MMR.
+    ///
+    /// This is the asynchronous counterpart of [TransactionExecutor::fetch_transaction_inputs()].
+    async fn fetch_transaction_inputs_async(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, TransactionExecutorError> {
+        let tx_inputs = self
+            .data_store
+            .get_transaction_inputs(account_id, block_ref, notes)
+            .await
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        let block_nums = tx_inputs.untracked_note_blocks();
+        if block_nums.is_empty() {
+            return Ok(tx_inputs);
+        }
+        let block_headers = self
+            .data_store
+            .get_block_headers(block_ref, &block_nums)
+            .await
+            .map_err(TransactionExecutorError::FetchBlockHeadersFailed)?;
+
+        tx_inputs
+            .with_block_headers(block_headers)
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)
     }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Creates a new [ExecutedTransaction] from the provided data.
+/// Creates a new [ExecutedTransaction] and the [TransactionMeasurements] of its execution from the
+/// provided data.
 fn build_executed_transaction(
     program: Program,
     tx_args: TransactionArgs,
     tx_inputs: TransactionInputs,
     stack_outputs: StackOutputs,
+    trace_length: usize,
     host: TransactionHost<RecAdviceProvider>,
-) -> Result<ExecutedTransaction, TransactionExecutorError> {
+) -> Result<(ExecutedTransaction, TransactionMeasurements), TransactionExecutorError> {
+    let tx_progress = host.tx_progress().clone();
+    let tx_script_output = host.tx_script_output();
     let (advice_recorder, account_delta) = host.into_parts();
 
     // finalize the advice recorder
     let (advice_witness, _, map, _store) = advice_recorder.finalize();
+    let measurements = TransactionMeasurements::new(
+        &tx_progress,
+        tx_inputs.input_notes().iter().map(|note| note.id()),
+        map.len(),
+        trace_length,
+    );
 
     // parse transaction results
-    let tx_outputs = TransactionKernel::parse_transaction_outputs(&stack_outputs, &map.into())
+    let mut tx_outputs = TransactionKernel::parse_transaction_outputs(&stack_outputs, &map.into())
         .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
+    tx_outputs.output_notes = add_output_note_details(tx_outputs.output_notes, &tx_args)?;
+    tx_outputs.tx_script_output = tx_script_output;
     let final_account = &tx_outputs.account;
 
     let initial_account = tx_inputs.account();
@@ -249,12 +845,56 @@ fn build_executed_transaction(
         });
     }
 
-    Ok(ExecutedTransaction::new(
+    let executed_transaction = ExecutedTransaction::new(
         program,
         tx_inputs,
         tx_outputs,
         account_delta,
         tx_args,
         advice_witness,
-    ))
+    );
+
+    Ok((executed_transaction, measurements))
+}
+
+/// Replaces the output notes whose details are known from the expected output notes of the
+/// provided transaction arguments with full notes.
+///
+/// The transaction kernel outputs only the recipients, assets and metadata of the created notes;
+/// the commitment to the output notes is not affected by the replacement because the IDs and the
+/// metadata of the notes remain the same.
+///
+/// # Errors
+/// Returns an error if a created note has the recipient of an expected output note, but is not
+/// the expected note (i.e., its assets differ from the assets of the expected note).
+fn add_output_note_details(
+    output_notes: OutputNotes,
+    tx_args: &TransactionArgs,
+) -> Result<OutputNotes, TransactionExecutorError> {
+    let mut notes = Vec::with_capacity(output_notes.num_notes());
+    for note in output_notes {
+        let OutputNote::Partial(partial) = note else {
+            notes.push(note);
+            continue;
+        };
+
+        if let Some(details) = tx_args.get_expected_output_note(partial.id()) {
+            notes.push(OutputNote::Full(Note::from_details(details.clone(), *partial.metadata())));
+            continue;
+        }
+
+        let mismatch = tx_args
+            .expected_output_notes()
+            .find(|details| details.recipient() == *partial.recipient());
+        if let Some(details) = mismatch {
+            return Err(TransactionExecutorError::ExpectedOutputNoteMismatch {
+                expected: details.id(),
+                actual: partial.id(),
+            });
+        }
+
+        notes.push(OutputNote::Partial(partial));
+    }
+
+    OutputNotes::new(notes).map_err(TransactionExecutorError::InvalidTransactionOutput)
 }
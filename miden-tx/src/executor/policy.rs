@@ -0,0 +1,171 @@
+use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
+    notes::NoteTag,
+    transaction::{ExecutedTransaction, PreparedTransaction},
+    utils::collections::{BTreeMap, BTreeSet},
+    Digest,
+};
+
+use crate::TransactionPolicyError;
+
+// TRANSACTION POLICY
+// ================================================================================================
+
+/// A set of rules which transactions must satisfy in order to be executed by a
+/// [TransactionExecutor](super::TransactionExecutor).
+///
+/// A policy is evaluated in two stages:
+/// - Before execution, the inputs of the transaction are checked (i.e., the scripts of the input
+///   notes and the transaction script).
+/// - After execution, the effects of the transaction are checked (i.e., the assets which left the
+///   account vault and the notes created by the transaction).
+///
+/// The default policy does not restrict transactions in any way.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TransactionPolicy {
+    max_outgoing_amounts: BTreeMap<AccountId, u64>,
+    deny_outgoing_non_fungible_assets: bool,
+    allowed_output_note_tags: Option<BTreeSet<NoteTag>>,
+    allowed_input_note_scripts: Option<BTreeSet<Digest>>,
+    allowed_tx_scripts: Option<BTreeSet<Digest>>,
+}
+
+impl TransactionPolicy {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionPolicy] which does not restrict transactions in any way.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the amount of the fungible asset issued by the specified faucet which can leave the
+    /// account vault in a single transaction.
+    pub fn with_max_outgoing_amount(mut self, faucet_id: AccountId, max_amount: u64) -> Self {
+        self.max_outgoing_amounts.insert(faucet_id, max_amount);
+        self
+    }
+
+    /// Forbids non-fungible assets from leaving the account vault.
+    pub fn deny_outgoing_non_fungible_assets(mut self) -> Self {
+        self.deny_outgoing_non_fungible_assets = true;
+        self
+    }
+
+    /// Restricts the tags of the notes created by transactions to the specified set.
+    pub fn with_allowed_output_note_tags<I: IntoIterator<Item = NoteTag>>(
+        mut self,
+        tags: I,
+    ) -> Self {
+        self.allowed_output_note_tags = Some(tags.into_iter().collect());
+        self
+    }
+
+    /// Restricts the scripts of the notes consumed by transactions to the specified set of script
+    /// roots.
+    pub fn with_allowed_input_note_scripts<I: IntoIterator<Item = Digest>>(
+        mut self,
+        script_roots: I,
+    ) -> Self {
+        self.allowed_input_note_scripts = Some(script_roots.into_iter().collect());
+        self
+    }
+
+    /// Restricts the transaction scripts to the specified set of script roots.
+    ///
+    /// Transactions without a transaction script are not affected by this restriction.
+    pub fn with_allowed_tx_scripts<I: IntoIterator<Item = Digest>>(
+        mut self,
+        script_roots: I,
+    ) -> Self {
+        self.allowed_tx_scripts = Some(script_roots.into_iter().collect());
+        self
+    }
+
+    // POLICY CHECKS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks the inputs of the provided transaction against this policy.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script of any input note is not in the set of allowed input note scripts.
+    /// - The transaction script is not in the set of allowed transaction scripts.
+    pub fn check_prepared_transaction(
+        &self,
+        transaction: &PreparedTransaction,
+    ) -> Result<(), TransactionPolicyError> {
+        if let Some(allowed_scripts) = &self.allowed_input_note_scripts {
+            for input_note in transaction.input_notes().iter() {
+                let note = input_note.note();
+                let script_root = note.script().hash();
+                if !allowed_scripts.contains(&script_root) {
+                    return Err(TransactionPolicyError::InputNoteScriptNotAllowed {
+                        note_id: note.id(),
+                        script_root,
+                    });
+                }
+            }
+        }
+
+        if let (Some(allowed_scripts), Some(tx_script)) =
+            (&self.allowed_tx_scripts, transaction.tx_args().tx_script())
+        {
+            if !allowed_scripts.contains(tx_script.hash()) {
+                return Err(TransactionPolicyError::TransactionScriptNotAllowed(*tx_script.hash()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the effects of the provided transaction against this policy.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The amount of a fungible asset which left the account vault exceeds the configured
+    ///   maximum for its faucet.
+    /// - A non-fungible asset left the account vault while outgoing non-fungible assets are
+    ///   denied.
+    /// - The tag of any output note is not in the set of allowed output note tags.
+    pub fn check_executed_transaction(
+        &self,
+        transaction: &ExecutedTransaction,
+    ) -> Result<(), TransactionPolicyError> {
+        for asset in transaction.account_delta().vault().removed_assets.iter() {
+            match asset {
+                Asset::Fungible(asset) => {
+                    if let Some(&max_amount) = self.max_outgoing_amounts.get(&asset.faucet_id()) {
+                        if asset.amount() > max_amount {
+                            return Err(TransactionPolicyError::OutgoingAmountExceeded {
+                                faucet_id: asset.faucet_id(),
+                                max: max_amount,
+                                actual: asset.amount(),
+                            });
+                        }
+                    }
+                },
+                Asset::NonFungible(_) => {
+                    if self.deny_outgoing_non_fungible_assets {
+                        return Err(TransactionPolicyError::OutgoingNonFungibleAsset(*asset));
+                    }
+                },
+            }
+        }
+
+        if let Some(allowed_tags) = &self.allowed_output_note_tags {
+            for note in transaction.output_notes().iter() {
+                let tag = note.metadata().tag();
+                if !allowed_tags.contains(&tag) {
+                    return Err(TransactionPolicyError::OutputNoteTagNotAllowed {
+                        note_id: note.id(),
+                        tag,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
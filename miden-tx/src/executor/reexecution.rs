@@ -0,0 +1,106 @@
+use miden_lib::transaction::ToTransactionKernelInputs;
+use miden_objects::transaction::TransactionWitness;
+use vm_processor::ExecutionOptions;
+
+use super::{
+    build_executed_transaction, ExecutedTransaction, RecAdviceProvider, TransactionExecutorError,
+    TransactionHost, TransactionMeasurements,
+};
+
+// WITNESS RE-EXECUTION
+// ================================================================================================
+
+/// Re-executes the transaction described by the provided witness and returns the resulting
+/// [ExecutedTransaction].
+///
+/// The transaction program is executed against the transaction inputs and the advice witness
+/// contained in the witness only; no [DataStore](crate::DataStore) is required. This allows
+/// proving services and auditors to validate a witness submitted by a client (e.g., read via
+/// [TransactionCompiler::read_transaction_witness()](crate::TransactionCompiler)) before
+/// spending time on proving it.
+///
+/// # Errors
+/// Returns an error if:
+/// - The transaction program cannot be executed against the inputs of the witness.
+/// - The outputs of the transaction are invalid or inconsistent with its inputs.
+pub fn reexecute_witness(
+    witness: &TransactionWitness,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    execute_witness(witness, ExecutionOptions::default())
+        .map(|(executed_transaction, _)| executed_transaction)
+}
+
+/// Re-executes the transaction described by the provided witness with tracing enabled and returns
+/// the resulting [ExecutedTransaction] together with the [TransactionMeasurements] of its
+/// execution.
+///
+/// # Errors
+/// Returns an error under the same conditions as [reexecute_witness()].
+#[cfg(feature = "testing")]
+pub(crate) fn reexecute_witness_with_measurements(
+    witness: &TransactionWitness,
+) -> Result<(ExecutedTransaction, TransactionMeasurements), TransactionExecutorError> {
+    execute_witness(witness, ExecutionOptions::default().with_tracing())
+}
+
+/// Re-executes the provided transaction from its witness and checks that the final account
+/// state, the account delta and the output notes of the transaction match the claimed ones.
+///
+/// Returns the re-executed transaction.
+///
+/// # Errors
+/// Returns an error if:
+/// - The transaction cannot be re-executed (see [reexecute_witness()]).
+/// - The final account state, the account delta or the output notes resulting from the
+///   re-execution are different from the ones claimed by the provided transaction.
+pub fn reexecute_transaction(
+    transaction: &ExecutedTransaction,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let witness = TransactionWitness::from(transaction.clone());
+    let reexecuted = reexecute_witness(&witness)?;
+
+    if reexecuted.final_account().hash() != transaction.final_account().hash() {
+        return Err(TransactionExecutorError::ReexecutedFinalAccountMismatch {
+            expected: transaction.final_account().hash(),
+            actual: reexecuted.final_account().hash(),
+        });
+    }
+
+    if reexecuted.account_delta() != transaction.account_delta() {
+        return Err(TransactionExecutorError::ReexecutedAccountDeltaMismatch);
+    }
+
+    if reexecuted.output_notes() != transaction.output_notes() {
+        return Err(TransactionExecutorError::ReexecutedOutputNotesMismatch {
+            expected: transaction.output_notes().commitment(),
+            actual: reexecuted.output_notes().commitment(),
+        });
+    }
+
+    Ok(reexecuted)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the transaction program of the provided witness with the specified options.
+fn execute_witness(
+    witness: &TransactionWitness,
+    exec_options: ExecutionOptions,
+) -> Result<(ExecutedTransaction, TransactionMeasurements), TransactionExecutorError> {
+    let (stack_inputs, advice_inputs) = witness.get_kernel_inputs();
+    let advice_recorder: RecAdviceProvider = advice_inputs.into();
+    let mut host = TransactionHost::new(witness.account().into(), advice_recorder);
+
+    let result = vm_processor::execute(witness.program(), stack_inputs, &mut host, exec_options)
+        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+
+    build_executed_transaction(
+        witness.program().clone(),
+        witness.tx_args().clone(),
+        witness.tx_inputs().clone(),
+        result.stack_outputs().clone(),
+        result.get_trace_len(),
+        host,
+    )
+}
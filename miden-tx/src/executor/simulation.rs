@@ -0,0 +1,147 @@
+use miden_objects::{
+    accounts::{Account, AccountCode, AccountDelta},
+    transaction::{OutputNotes, TransactionArgs, TransactionInputs, TransactionScript},
+    AccountError, Digest,
+};
+
+use crate::TransactionExecutorError;
+
+// SIMULATED TRANSACTION
+// ================================================================================================
+
+/// The effects which a transaction would have if it were executed, as determined by
+/// [TransactionExecutor::simulate_transaction()](super::TransactionExecutor::simulate_transaction).
+///
+/// Unlike an [ExecutedTransaction](miden_objects::transaction::ExecutedTransaction), a simulated
+/// transaction can not be proven.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedTransaction {
+    account_delta: AccountDelta,
+    output_notes: OutputNotes,
+}
+
+impl SimulatedTransaction {
+    /// Returns a new [SimulatedTransaction] instantiated from the provided effects.
+    pub(super) fn new(account_delta: AccountDelta, output_notes: OutputNotes) -> Self {
+        Self { account_delta, output_notes }
+    }
+
+    /// Returns the changes the transaction would make to the account.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the notes the transaction would create.
+    pub fn output_notes(&self) -> &OutputNotes {
+        &self.output_notes
+    }
+
+    /// Consumes the simulated transaction and returns its account delta and output notes.
+    pub fn into_parts(self) -> (AccountDelta, OutputNotes) {
+        (self.account_delta, self.output_notes)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the transaction inputs and arguments with which a transaction can be simulated without
+/// authenticating it.
+///
+/// The provided `simulation_auth` procedure, which only increments the nonce of the account, is
+/// added to the code of the account, and the transaction script is replaced with the compiled
+/// script whose root is `tx_script_root`, in which the calls to the authentication procedures of
+/// the account have been replaced with calls to `simulation_auth`. The storage and the vault of
+/// the account are left unchanged, and thus the simulation works regardless of the
+/// authentication scheme of the account. This does not affect the account delta and the output
+/// notes of the transaction, but does affect the commitments to the initial and final states of
+/// the account.
+///
+/// # Errors
+/// Returns an error if:
+/// - The account is new, as the ID of a new account commits to its initial code.
+/// - The simulation authentication procedure can not be added to the code of the account.
+pub(super) fn build_simulation_inputs(
+    tx_inputs: TransactionInputs,
+    tx_args: TransactionArgs,
+    simulation_auth: Digest,
+    tx_script_root: Option<Digest>,
+) -> Result<(TransactionInputs, TransactionArgs), TransactionExecutorError> {
+    if tx_inputs.account().is_new() {
+        return Err(TransactionExecutorError::SimulationOfNewAccountNotSupported(
+            tx_inputs.account().id(),
+        ));
+    }
+
+    // add the simulation authentication procedure to the code of the account
+    let partial_vault = tx_inputs.partial_vault().cloned();
+    let foreign_accounts = tx_inputs.foreign_accounts().to_vec();
+    let (account, account_seed, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+    let code = build_simulation_code(account.code(), simulation_auth)?;
+    let account = Account::new(
+        account.id(),
+        account.vault().clone(),
+        account.storage().clone(),
+        code,
+        account.nonce(),
+    );
+
+    let mut tx_inputs =
+        TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+            .expect("transaction inputs were validated by the data store");
+    if let Some(partial_vault) = partial_vault {
+        tx_inputs = tx_inputs
+            .with_partial_vault(partial_vault)
+            .expect("vault of the simulated account is unchanged");
+    }
+    let tx_inputs = tx_inputs
+        .with_foreign_accounts(foreign_accounts)
+        .expect("foreign accounts were validated by the data store");
+
+    // replace the transaction script with the one which skips authentication
+    let mut tx_args = tx_args;
+    if let (Some(tx_script), Some(tx_script_root)) = (tx_args.tx_script(), tx_script_root) {
+        let tx_script = TransactionScript::from_parts(
+            tx_script.code().clone(),
+            tx_script_root,
+            tx_script.inputs().iter().map(|(key, values)| ((*key).into(), values.clone())),
+        )
+        .expect("transaction script inputs are valid");
+        tx_args.set_tx_script(tx_script);
+    }
+
+    Ok((tx_inputs, tx_args))
+}
+
+/// Returns the provided account code extended with the simulation authentication procedure.
+///
+/// The access of the existing procedures is preserved. If the code already contains the
+/// procedure, the code is returned unchanged.
+fn build_simulation_code(
+    code: &AccountCode,
+    simulation_auth: Digest,
+) -> Result<AccountCode, TransactionExecutorError> {
+    if code.has_procedure(simulation_auth) {
+        return Ok(code.clone());
+    }
+    if code.num_procedures() == AccountCode::MAX_NUM_PROCEDURES {
+        return Err(TransactionExecutorError::InvalidSimulationAccount(
+            AccountError::AccountCodeTooManyProcedures {
+                max: AccountCode::MAX_NUM_PROCEDURES,
+                actual: code.num_procedures() + 1,
+            },
+        ));
+    }
+
+    let mut procedures = code.procedures().to_vec();
+    procedures.push(simulation_auth);
+    let mut simulation_code = AccountCode::from_parts(code.module().clone(), procedures);
+    for procedure in code.procedures() {
+        let access = code.get_procedure_access(*procedure).expect("procedure is in the code");
+        simulation_code = simulation_code
+            .with_procedure_access(*procedure, access)
+            .expect("procedure is in the extended code");
+    }
+
+    Ok(simulation_code)
+}
@@ -2,14 +2,14 @@ use miden_lib::transaction::{memory::ACCT_STORAGE_ROOT_PTR, TransactionKernelErr
 use miden_objects::{
     accounts::{
         AccountDelta, AccountId, AccountStorage, AccountStorageDelta, AccountStub,
-        AccountVaultDelta,
+        AccountVaultDelta, StorageMapDelta, StorageSlotType,
     },
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     utils::{
-        collections::{btree_map::Entry, BTreeMap},
+        collections::{btree_map::Entry, BTreeMap, Vec},
         string::ToString,
     },
-    Digest, Felt, Word, EMPTY_WORD, ZERO,
+    Digest, Felt, Word, EMPTY_WORD, WORD_SIZE, ZERO,
 };
 use vm_processor::{ContextId, ProcessState};
 
@@ -27,6 +27,8 @@ const STORAGE_TREE_DEPTH: Felt = Felt::new(AccountStorage::STORAGE_TREE_DEPTH as
 ///
 /// Currently, this tracks:
 /// - Changes to the account storage slots.
+/// - Changes to the entries of the account storage maps.
+/// - Changes to the words of the account multi-word storage values.
 /// - Changes to the account vault.
 /// - Changes to the account nonce.
 ///
@@ -122,8 +124,107 @@ impl<A: AdviceProvider> TransactionHost<A> {
         if current_slot_value != new_slot_value {
             let slot_index = slot_index.as_int() as u8;
             self.account_delta.storage.slot_updates.insert(slot_index, new_slot_value);
+
+            // record the words of multi-word values, so that the values can be reconstructed
+            // from the delta
+            match self.get_storage_value_words(storage_root, slot_index, new_slot_value)? {
+                Some(words) => {
+                    self.account_delta.storage.word_updates.insert(slot_index, words);
+                },
+                None => {
+                    self.account_delta.storage.word_updates.remove(&slot_index);
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the words of the value with the provided commitment if the specified slot is a
+    /// multi-word value slot, and the words are present in the advice map.
+    ///
+    /// The transaction kernel inserts the words of a multi-word value into the advice map under
+    /// the commitment to the value before setting the value.
+    fn get_storage_value_words(
+        &self,
+        storage_root: Word,
+        slot_index: u8,
+        commitment: Word,
+    ) -> Result<Option<Vec<Word>>, TransactionKernelError> {
+        // get the storage layout via the layout commitment stored in the reserved slot
+        let layout_index = Felt::from(AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX);
+        let layout_commitment = self
+            .adv_provider
+            .get_tree_node(storage_root, &STORAGE_TREE_DEPTH, &layout_index)
+            .map_err(|err| {
+                TransactionKernelError::MissingStorageSlotValue(
+                    AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX,
+                    err.to_string(),
+                )
+            })?;
+        let slot_type = self
+            .adv_provider
+            .get_mapped_values(&layout_commitment.into())
+            .and_then(|layout| layout.get(slot_index as usize))
+            .and_then(|slot_type| StorageSlotType::try_from(slot_type.as_int() as u16).ok());
+        let num_words = match slot_type {
+            Some(StorageSlotType::Value { value_arity }) if value_arity > 0 => value_arity as usize,
+            _ => return Ok(None),
+        };
+
+        let Some(elements) = self.adv_provider.get_mapped_values(&commitment.into()) else {
+            return Ok(None);
+        };
+        if elements.len() < num_words * WORD_SIZE {
+            return Ok(None);
+        }
+        let words: Vec<Word> = elements
+            .chunks_exact(WORD_SIZE)
+            .take(num_words)
+            .map(|word| [word[0], word[1], word[2], word[3]])
+            .collect();
+
+        if Word::from(AccountStorage::compute_words_commitment(&words)) != commitment {
+            return Ok(None);
         }
 
+        Ok(Some(words))
+    }
+
+    /// Extracts information from the process state about the storage map entry being updated and
+    /// records the latest value of this entry.
+    pub(super) fn on_account_storage_set_map_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        // get slot index from the stack and make sure it is valid
+        let slot_index = process.get_stack_item(0);
+        if slot_index.as_int() as usize >= AccountStorage::NUM_STORAGE_SLOTS {
+            return Err(TransactionKernelError::InvalidStorageSlotIndex(slot_index.as_int()));
+        }
+
+        // get the key of the entry and the value to which the entry is being updated
+        let key = [
+            process.get_stack_item(4),
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+            process.get_stack_item(1),
+        ];
+        let new_value = [
+            process.get_stack_item(8),
+            process.get_stack_item(7),
+            process.get_stack_item(6),
+            process.get_stack_item(5),
+        ];
+
+        let slot_index = slot_index.as_int() as u8;
+        self.account_delta
+            .storage
+            .map_updates
+            .entry(slot_index)
+            .or_default()
+            .insert(key.into(), new_value);
+
         Ok(())
     }
 
@@ -169,9 +270,13 @@ impl<A: AdviceProvider> TransactionHost<A> {
 ///
 /// The delta tracker is composed of:
 /// - A map which records the latest states for the updated storage slots.
+/// - A map which records the latest values of the updated entries of the storage maps.
+/// - A map which records the words of the latest values of the updated multi-word value slots.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 struct AccountStorageDeltaTracker {
     slot_updates: BTreeMap<u8, Word>,
+    map_updates: BTreeMap<u8, BTreeMap<Digest, Word>>,
+    word_updates: BTreeMap<u8, Vec<Word>>,
 }
 
 impl AccountStorageDeltaTracker {
@@ -189,7 +294,35 @@ impl AccountStorageDeltaTracker {
             }
         }
 
-        AccountStorageDelta { cleared_items, updated_items }
+        let updated_maps = self
+            .map_updates
+            .into_iter()
+            .map(|(idx, entries)| {
+                let mut map_delta = StorageMapDelta::default();
+                for (key, value) in entries {
+                    if value == EMPTY_WORD {
+                        map_delta.cleared_leaves.push(key);
+                    } else {
+                        map_delta.updated_leaves.push((key, value));
+                    }
+                }
+                (idx, map_delta)
+            })
+            .collect();
+
+        // words of cleared slots are not recorded
+        let updated_words = self
+            .word_updates
+            .into_iter()
+            .filter(|(idx, _)| updated_items.iter().any(|(item_idx, _)| item_idx == idx))
+            .collect();
+
+        AccountStorageDelta {
+            cleared_items,
+            updated_items,
+            updated_maps,
+            updated_words,
+        }
     }
 }
 
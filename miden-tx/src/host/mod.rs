@@ -1,8 +1,10 @@
-use miden_lib::transaction::{TransactionEvent, TransactionKernelError};
+use miden_lib::transaction::{
+    TransactionEvent, TransactionKernelError, TransactionTrace, TransactionTraceParsingError,
+};
 use miden_objects::{
     accounts::{AccountDelta, AccountStub},
     utils::{collections::BTreeMap, string::ToString},
-    Digest,
+    Digest, Hasher, Word, TX_SCRIPT_OUTPUT_WORDS,
 };
 use vm_processor::{
     crypto::NodeIndex, AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ContextId,
@@ -15,20 +17,29 @@ use account_delta::AccountDeltaTracker;
 mod account_procs;
 use account_procs::AccountProcedureIndexMap;
 
+mod tx_progress;
+pub use tx_progress::TransactionProgress;
+
 // TRANSACTION HOST
 // ================================================================================================
 
 /// Transaction host is responsible for handling [Host] requests made by a transaction kernel.
 ///
-/// Transaction host is composed of two components:
+/// Transaction host is composed of the following components:
 /// - An advice provider which is used to provide non-deterministic inputs to the transaction
 ///   runtime.
 /// - An account vault delta tracker which is used to keep track of changes made to the asset
 ///   of the account the transaction is being executed against.
+/// - A progress tracker which records the clock cycles at which the sections of the transaction
+///   program were entered and exited (only if tracing is enabled).
+/// - The outputs of the transaction script, which are reported by the transaction kernel when the
+///   script returns.
 pub struct TransactionHost<A> {
     adv_provider: A,
     account_delta: AccountDeltaTracker,
     acct_procedure_index_map: AccountProcedureIndexMap,
+    tx_progress: TransactionProgress,
+    tx_script_output: Option<[Word; TX_SCRIPT_OUTPUT_WORDS]>,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
@@ -39,9 +50,22 @@ impl<A: AdviceProvider> TransactionHost<A> {
             adv_provider,
             account_delta: AccountDeltaTracker::new(&account),
             acct_procedure_index_map: proc_index_map,
+            tx_progress: TransactionProgress::default(),
+            tx_script_output: None,
         }
     }
 
+    /// Returns the progress of the transaction execution recorded by this host.
+    pub fn tx_progress(&self) -> &TransactionProgress {
+        &self.tx_progress
+    }
+
+    /// Returns the words returned by the transaction script, with the word at the top of the stack
+    /// first, or None if no transaction script has been executed.
+    pub fn tx_script_output(&self) -> Option<[Word; TX_SCRIPT_OUTPUT_WORDS]> {
+        self.tx_script_output
+    }
+
     /// Consumes `self` and returns the advice provider and account vault delta.
     pub fn into_parts(self) -> (A, AccountDelta) {
         (self.adv_provider, self.account_delta.into_delta())
@@ -60,6 +84,18 @@ impl<A: AdviceProvider> TransactionHost<A> {
             .expect("failed to push value onto advice stack");
         Ok(())
     }
+
+    fn on_tx_script_output<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        let mut output = [Word::default(); TX_SCRIPT_OUTPUT_WORDS];
+        for (word_idx, word) in output.iter_mut().enumerate() {
+            *word = process.get_stack_word(word_idx);
+        }
+        self.tx_script_output = Some(output);
+        Ok(())
+    }
 }
 
 impl<A: AdviceProvider> Host for TransactionHost<A> {
@@ -76,6 +112,18 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
         process: &S,
         injector: AdviceInjector,
     ) -> Result<HostResponse, ExecutionError> {
+        // signatures provided via the advice map under hash(PUB_KEY, MSG) take precedence over
+        // signing the message, so that messages can be signed without providing secret keys
+        if let AdviceInjector::SigToStack { .. } = injector {
+            let pub_key = process.get_stack_word(0);
+            let msg = process.get_stack_word(1);
+            let key = Hasher::merge(&[pub_key.into(), msg.into()]);
+            if self.adv_provider.get_mapped_values(&key).is_some() {
+                self.adv_provider.push_stack(AdviceSource::Map { key, include_len: false })?;
+                return Ok(HostResponse::None);
+            }
+        }
+
         self.adv_provider.set_advice(process, &injector)
     }
 
@@ -98,11 +146,35 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             AccountVaultAddAsset => self.on_account_vault_add_asset(process),
             AccountVaultRemoveAsset => self.on_account_vault_remove_asset(process),
             AccountStorageSetItem => self.on_account_storage_set_item(process),
+            AccountStorageSetMapItem => self.on_account_storage_set_map_item(process),
             AccountIncrementNonce => self.on_account_increment_nonce(process),
             AccountPushProcedureIndex => self.on_account_push_procedure_index(process),
+            TxScriptOutput => self.on_tx_script_output(process),
         }
         .map_err(|err| ExecutionError::EventError(err.to_string()))?;
 
         Ok(HostResponse::None)
     }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        // only traces emitted by the transaction kernel are recorded
+        if process.ctx() != ContextId::root() {
+            return Ok(HostResponse::None);
+        }
+
+        let trace = match TransactionTrace::try_from(trace_id) {
+            Ok(trace) => trace,
+            Err(TransactionTraceParsingError::NotTransactionTrace(_)) => {
+                return Ok(HostResponse::None)
+            },
+            Err(err) => return Err(ExecutionError::EventError(err.to_string())),
+        };
+        self.tx_progress.record(trace, process.clk());
+
+        Ok(HostResponse::None)
+    }
 }
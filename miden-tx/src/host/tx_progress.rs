@@ -0,0 +1,99 @@
+use miden_lib::transaction::TransactionTrace;
+use miden_objects::utils::collections::Vec;
+
+// TRANSACTION PROGRESS
+// ================================================================================================
+
+/// Keeps track of the clock cycles at which the sections of a transaction program were entered
+/// and exited.
+///
+/// The sections are delimited by the traces emitted by the transaction kernel, which are handled
+/// by the host only if tracing is enabled in the execution options; otherwise, the number of
+/// cycles reported for every section is 0.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionProgress {
+    prologue: CycleInterval,
+    notes_processing: CycleInterval,
+    note_execution: Vec<CycleInterval>,
+    tx_script_processing: CycleInterval,
+    epilogue: CycleInterval,
+}
+
+impl TransactionProgress {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of cycles spent executing the transaction prologue.
+    pub fn prologue(&self) -> usize {
+        self.prologue.len()
+    }
+
+    /// Returns the number of cycles spent processing the input notes, including the execution of
+    /// the note scripts.
+    pub fn notes_processing(&self) -> usize {
+        self.notes_processing.len()
+    }
+
+    /// Returns an iterator over the number of cycles spent executing each of the input notes, in
+    /// the order in which the notes were consumed.
+    pub fn note_execution(&self) -> impl Iterator<Item = usize> + '_ {
+        self.note_execution.iter().map(|interval| interval.len())
+    }
+
+    /// Returns the number of cycles spent executing the transaction script.
+    pub fn tx_script_processing(&self) -> usize {
+        self.tx_script_processing.len()
+    }
+
+    /// Returns the number of cycles spent executing the transaction epilogue.
+    pub fn epilogue(&self) -> usize {
+        self.epilogue.len()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records that the specified trace was emitted at the specified clock cycle.
+    pub(super) fn record(&mut self, trace: TransactionTrace, clk: u32) {
+        use TransactionTrace::*;
+        match trace {
+            PrologueStart => self.prologue.start = Some(clk),
+            PrologueEnd => self.prologue.end = Some(clk),
+            NotesProcessingStart => self.notes_processing.start = Some(clk),
+            NotesProcessingEnd => self.notes_processing.end = Some(clk),
+            NoteExecutionStart => {
+                self.note_execution.push(CycleInterval { start: Some(clk), end: None })
+            },
+            NoteExecutionEnd => {
+                if let Some(interval) = self.note_execution.last_mut() {
+                    interval.end = Some(clk);
+                }
+            },
+            TxScriptProcessingStart => self.tx_script_processing.start = Some(clk),
+            TxScriptProcessingEnd => self.tx_script_processing.end = Some(clk),
+            EpilogueStart => self.epilogue.start = Some(clk),
+            EpilogueEnd => self.epilogue.end = Some(clk),
+        }
+    }
+}
+
+// CYCLE INTERVAL
+// ================================================================================================
+
+/// An interval of clock cycles; either of the bounds may be missing if the corresponding trace
+/// has not been emitted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct CycleInterval {
+    start: Option<u32>,
+    end: Option<u32>,
+}
+
+impl CycleInterval {
+    /// Returns the number of cycles in this interval, or 0 if the interval is not closed.
+    fn len(&self) -> usize {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => end.saturating_sub(start) as usize,
+            _ => 0,
+        }
+    }
+}
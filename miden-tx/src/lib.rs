@@ -1,3 +1,9 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
 use miden_lib::transaction::TransactionKernel;
 pub use miden_objects::transaction::TransactionInputs;
 use miden_objects::{
@@ -10,24 +16,57 @@ use miden_objects::{
 };
 use vm_processor::{ExecutionError, RecAdviceProvider};
 
+mod chain;
+pub use chain::{LocalChainSimulator, ProposedTransaction};
+
 mod compiler;
-pub use compiler::{ScriptTarget, TransactionCompiler};
+pub use compiler::{CompatibilityReport, ScriptTarget, TransactionCompiler};
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor};
+#[cfg(feature = "async")]
+pub use executor::AsyncDataStore;
+#[cfg(feature = "std")]
+pub use executor::SimulatedTransaction;
+pub use executor::{
+    reexecute_transaction, reexecute_witness, CodeCacheStats, DataStore, TransactionDebugInfo,
+    TransactionExecutor, TransactionMeasurements, TransactionPolicy,
+};
 
 pub mod host;
 pub use host::TransactionHost;
 
+mod pipeline;
+pub use pipeline::{LocalTransactionPipeline, PipelineHooks, PipelineStage};
+
+pub mod planner;
+
 mod prover;
-pub use prover::{ProvingOptions, TransactionProver};
+#[cfg(all(feature = "async", feature = "std"))]
+pub use prover::ProvingHandle;
+pub use prover::{
+    LocalTransactionProver, ProverBackend, ProvingOptions, ProvingOptionsBuilder, TransactionProver,
+};
+#[cfg(feature = "remote")]
+pub use prover::{ProvingServiceClient, RemoteTransactionProver};
+
+mod simulator;
+pub use simulator::StateSimulator;
+
+pub mod stores;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "tracing")]
+mod timer;
 
 mod verifier;
-pub use verifier::TransactionVerifier;
+pub use verifier::{ProofSecurityPolicy, TransactionVerifier};
 
 mod error;
 pub use error::{
-    DataStoreError, TransactionCompilerError, TransactionExecutorError, TransactionProverError,
+    DataStoreError, LocalChainError, PipelineError, StateSimulatorError, TransactionCompilerError,
+    TransactionExecutorError, TransactionPolicyError, TransactionProverError,
     TransactionVerifierError,
 };
 
@@ -0,0 +1,206 @@
+use core::{fmt, ops::ControlFlow};
+
+use miden_objects::{
+    accounts::AccountId,
+    notes::NoteId,
+    transaction::{ExecutedTransaction, ProvenTransaction, TransactionArgs},
+};
+
+use crate::{
+    DataStore, LocalTransactionProver, PipelineError, ProofSecurityPolicy, ProvingOptions,
+    TransactionExecutor, TransactionVerifier,
+};
+
+// PIPELINE STAGE
+// ================================================================================================
+
+/// A stage of a [LocalTransactionPipeline] after which the pipeline can be aborted by its
+/// [PipelineHooks].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineStage {
+    /// The execution of the transaction.
+    Execution,
+    /// The proving of the executed transaction.
+    Proving,
+}
+
+impl fmt::Display for PipelineStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Execution => write!(f, "execution"),
+            Self::Proving => write!(f, "proving"),
+        }
+    }
+}
+
+// PIPELINE HOOKS
+// ================================================================================================
+
+/// Callbacks invoked by a [LocalTransactionPipeline] between its stages.
+///
+/// Hooks can inspect the intermediate results of the pipeline (e.g., to log the cycle count of an
+/// executed transaction, or to persist a proven transaction before it is verified), and can abort
+/// the pipeline by returning [ControlFlow::Break]. All methods do nothing by default; `()`
+/// implements the trait with the default methods.
+pub trait PipelineHooks {
+    /// Invoked after the transaction has been executed and before it is proven.
+    fn after_execution(&mut self, _transaction: &ExecutedTransaction) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked after the transaction has been proven and before it is verified.
+    fn after_proving(&mut self, _transaction: &ProvenTransaction) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked after the proof of the transaction has been verified.
+    fn after_verification(&mut self, _transaction: &ProvenTransaction) {}
+}
+
+impl PipelineHooks for () {}
+
+// LOCAL TRANSACTION PIPELINE
+// ================================================================================================
+
+/// A pipeline which executes, proves and verifies transactions in the current process.
+///
+/// The pipeline combines a [TransactionExecutor], a [LocalTransactionProver] and a
+/// [TransactionVerifier], so that a transaction can be turned into a verified
+/// [ProvenTransaction] via a single call to [LocalTransactionPipeline::process()]. By default,
+/// transactions are proven with the default [ProvingOptions] and verified against the default
+/// [ProofSecurityPolicy]; [PipelineHooks] are invoked between the stages.
+///
+/// The code of the accounts against which transactions are processed must be loaded into the
+/// executor beforehand, e.g., via [LocalTransactionPipeline::executor_mut()].
+pub struct LocalTransactionPipeline<D, H = ()> {
+    executor: TransactionExecutor<D>,
+    prover: LocalTransactionProver,
+    verifier: TransactionVerifier,
+    hooks: H,
+}
+
+impl<D> LocalTransactionPipeline<D> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [LocalTransactionPipeline] which executes transactions via the provided
+    /// executor.
+    pub fn new(executor: TransactionExecutor<D>) -> Self {
+        Self {
+            executor,
+            prover: LocalTransactionProver::new(ProvingOptions::default()),
+            verifier: TransactionVerifier::new(ProofSecurityPolicy::default()),
+            hooks: (),
+        }
+    }
+}
+
+impl<D, H> LocalTransactionPipeline<D, H> {
+    /// Sets the options with which transactions are proven by this pipeline.
+    pub fn with_proving_options(mut self, proof_options: ProvingOptions) -> Self {
+        self.prover = LocalTransactionProver::new(proof_options);
+        self
+    }
+
+    /// Replaces the prover of this pipeline with the provided one, e.g., to use a specific
+    /// [ProverBackend](crate::ProverBackend).
+    pub fn with_prover(mut self, prover: LocalTransactionProver) -> Self {
+        self.prover = prover;
+        self
+    }
+
+    /// Replaces the verifier of this pipeline with the provided one, e.g., to require a higher
+    /// proof security level.
+    pub fn with_verifier(mut self, verifier: TransactionVerifier) -> Self {
+        self.verifier = verifier;
+        self
+    }
+
+    /// Returns a pipeline which invokes the provided hooks between its stages.
+    pub fn with_hooks<T: PipelineHooks>(self, hooks: T) -> LocalTransactionPipeline<D, T> {
+        LocalTransactionPipeline {
+            executor: self.executor,
+            prover: self.prover,
+            verifier: self.verifier,
+            hooks,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the executor used by this pipeline.
+    pub fn executor(&self) -> &TransactionExecutor<D> {
+        &self.executor
+    }
+
+    /// Returns a mutable reference to the executor used by this pipeline, e.g., to load account
+    /// code into it.
+    pub fn executor_mut(&mut self) -> &mut TransactionExecutor<D> {
+        &mut self.executor
+    }
+
+    /// Returns the prover used by this pipeline.
+    pub fn prover(&self) -> &LocalTransactionProver {
+        &self.prover
+    }
+
+    /// Returns the verifier used by this pipeline.
+    pub fn verifier(&self) -> &TransactionVerifier {
+        &self.verifier
+    }
+
+    /// Returns the hooks of this pipeline.
+    pub fn hooks(&self) -> &H {
+        &self.hooks
+    }
+
+    /// Consumes this pipeline and returns its hooks.
+    pub fn into_hooks(self) -> H {
+        self.hooks
+    }
+}
+
+impl<D: DataStore, H: PipelineHooks> LocalTransactionPipeline<D, H> {
+    /// Executes, proves and verifies the transaction specified by the provided arguments, and
+    /// returns the resulting [ProvenTransaction].
+    ///
+    /// The arguments are the same as for [TransactionExecutor::execute_transaction()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction cannot be executed.
+    /// - The executed transaction cannot be proven.
+    /// - The proof of the transaction cannot be verified.
+    /// - The pipeline is aborted by one of its hooks.
+    pub fn process(
+        &mut self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: Option<TransactionArgs>,
+    ) -> Result<ProvenTransaction, PipelineError> {
+        let executed_transaction = self
+            .executor
+            .execute_transaction(account_id, block_ref, notes, tx_args)
+            .map_err(PipelineError::ExecuteTransactionFailed)?;
+        if self.hooks.after_execution(&executed_transaction).is_break() {
+            return Err(PipelineError::Aborted(PipelineStage::Execution));
+        }
+
+        let proven_transaction = self
+            .prover
+            .prove_transaction(executed_transaction)
+            .map_err(PipelineError::ProveTransactionFailed)?;
+        if self.hooks.after_proving(&proven_transaction).is_break() {
+            return Err(PipelineError::Aborted(PipelineStage::Proving));
+        }
+
+        self.verifier
+            .verify(proven_transaction.clone())
+            .map_err(PipelineError::VerifyTransactionFailed)?;
+        self.hooks.after_verification(&proven_transaction);
+
+        Ok(proven_transaction)
+    }
+}
@@ -0,0 +1,410 @@
+//! Planning of the transactions which consume a set of notes.
+//!
+//! The number of notes a single transaction can consume is limited by the number of cycles the
+//! transaction may take (e.g., because of the cycle limit of the executor, or because the time
+//! needed to prove a transaction grows with the length of its execution trace). An account holding
+//! many notes thus needs to consume them in several transactions. The [NotePlanner] splits a set
+//! of candidate notes into transactions which fit a [TransactionBudget], based on per-script
+//! [CycleEstimates] which can be derived from [TransactionMeasurements].
+
+use miden_objects::{
+    notes::{Note, NoteId},
+    utils::collections::{BTreeMap, Vec},
+    Digest, MAX_INPUT_NOTES_PER_TX,
+};
+
+use crate::TransactionMeasurements;
+
+// CYCLE ESTIMATES
+// ================================================================================================
+
+/// Estimates of the number of cycles spent by transactions consuming notes.
+///
+/// The cycles of a transaction are estimated as a fixed number of base cycles (i.e., the cycles
+/// spent in the prologue, the transaction script and the epilogue), plus the cycles spent
+/// executing each of the consumed notes. The cycles of a note are estimated based on its script;
+/// notes with scripts for which no estimate is known are assumed to take a default number of
+/// cycles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleEstimates {
+    base_cycles: usize,
+    default_note_cycles: usize,
+    script_cycles: BTreeMap<Digest, usize>,
+}
+
+impl CycleEstimates {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [CycleEstimates] with the specified base cycles of a transaction and the
+    /// specified cycles of notes with unknown scripts.
+    pub fn new(base_cycles: usize, default_note_cycles: usize) -> Self {
+        Self {
+            base_cycles,
+            default_note_cycles,
+            script_cycles: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the estimated number of cycles spent executing notes with the specified script.
+    pub fn with_script_cycles(mut self, script_root: Digest, cycles: usize) -> Self {
+        self.script_cycles.insert(script_root, cycles);
+        self
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Updates the estimates with the measurements of a transaction which consumed the provided
+    /// notes.
+    ///
+    /// Estimates are conservative: the base cycles and the cycles of every measured note script
+    /// are raised to the measured cycles if these are higher, but never lowered. Measured notes
+    /// which are not among the provided notes are ignored.
+    pub fn record_measurements(&mut self, measurements: &TransactionMeasurements, notes: &[Note]) {
+        let base_cycles =
+            measurements.prologue + measurements.tx_script_processing + measurements.epilogue;
+        self.base_cycles = self.base_cycles.max(base_cycles);
+
+        for (note_id, cycles) in measurements.note_execution.iter() {
+            if let Some(note) = notes.iter().find(|note| note.id() == *note_id) {
+                let script_cycles = self.script_cycles.entry(note.script().hash()).or_default();
+                *script_cycles = (*script_cycles).max(*cycles);
+            }
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the estimated number of cycles a transaction spends in addition to executing its
+    /// notes.
+    pub fn base_cycles(&self) -> usize {
+        self.base_cycles
+    }
+
+    /// Returns the estimated number of cycles spent executing a note with the specified script.
+    pub fn note_cycles(&self, script_root: Digest) -> usize {
+        self.script_cycles
+            .get(&script_root)
+            .copied()
+            .unwrap_or(self.default_note_cycles)
+    }
+
+    /// Returns the estimated number of cycles of a transaction consuming the provided notes.
+    pub fn transaction_cycles<'a, I>(&self, notes: I) -> usize
+    where
+        I: IntoIterator<Item = &'a Note>,
+    {
+        notes
+            .into_iter()
+            .map(|note| self.note_cycles(note.script().hash()))
+            .fold(self.base_cycles, usize::saturating_add)
+    }
+}
+
+// TRANSACTION BUDGET
+// ================================================================================================
+
+/// The resources a single planned transaction may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionBudget {
+    max_cycles: usize,
+    max_notes: usize,
+}
+
+impl TransactionBudget {
+    /// Returns a new [TransactionBudget] which limits the estimated number of cycles of a
+    /// transaction to the specified number, and the number of its notes to
+    /// [MAX_INPUT_NOTES_PER_TX].
+    pub fn new(max_cycles: usize) -> Self {
+        Self {
+            max_cycles,
+            max_notes: MAX_INPUT_NOTES_PER_TX,
+        }
+    }
+
+    /// Limits the number of notes consumed by a transaction to the specified number.
+    ///
+    /// The number is clamped to the range between 1 and [MAX_INPUT_NOTES_PER_TX].
+    pub fn with_max_notes(mut self, max_notes: usize) -> Self {
+        self.max_notes = max_notes.clamp(1, MAX_INPUT_NOTES_PER_TX);
+        self
+    }
+
+    /// Returns the maximum estimated number of cycles of a transaction.
+    pub fn max_cycles(&self) -> usize {
+        self.max_cycles
+    }
+
+    /// Returns the maximum number of notes consumed by a transaction.
+    pub fn max_notes(&self) -> usize {
+        self.max_notes
+    }
+}
+
+// SELECTION STRATEGY
+// ================================================================================================
+
+/// The strategy by which a [NotePlanner] selects the notes of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionStrategy {
+    /// Selects as many notes as fit the budget, starting with the cheapest notes.
+    ///
+    /// This maximizes the number of notes consumed by every transaction, but may leave the budget
+    /// of a transaction partially unused, and thus may require more transactions overall.
+    #[default]
+    MaxNotes,
+    /// Selects the notes which use as much of the budget as possible.
+    ///
+    /// The selection is computed via dynamic programming over the cycle estimates, which are
+    /// rounded up to a granularity of 1/4096 of the budget, and thus tends to minimize the number
+    /// of transactions needed to consume all notes at the cost of more computation.
+    FillBudget,
+}
+
+// NOTE PLAN
+// ================================================================================================
+
+/// A transaction planned by a [NotePlanner].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedTransaction {
+    notes: Vec<NoteId>,
+    estimated_cycles: usize,
+}
+
+impl PlannedTransaction {
+    /// Returns the IDs of the notes to be consumed by the transaction, in the order in which the
+    /// notes were provided to the planner.
+    pub fn notes(&self) -> &[NoteId] {
+        &self.notes
+    }
+
+    /// Returns the estimated number of cycles of the transaction.
+    pub fn estimated_cycles(&self) -> usize {
+        self.estimated_cycles
+    }
+}
+
+/// The transactions planned by a [NotePlanner] to consume a set of notes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotePlan {
+    transactions: Vec<PlannedTransaction>,
+    oversized_notes: Vec<NoteId>,
+}
+
+impl NotePlan {
+    /// Returns the planned transactions, in the order in which they are meant to be executed.
+    pub fn transactions(&self) -> &[PlannedTransaction] {
+        &self.transactions
+    }
+
+    /// Returns the IDs of the notes which cannot be consumed by any transaction within the
+    /// budget, even on their own.
+    pub fn oversized_notes(&self) -> &[NoteId] {
+        &self.oversized_notes
+    }
+}
+
+// NOTE PLANNER
+// ================================================================================================
+
+/// Splits a set of notes into transactions which fit a [TransactionBudget].
+///
+/// The first planned transaction consumes a maximal subset of the notes (as defined by the
+/// [SelectionStrategy] of the planner), and the remaining notes are split into follow-up
+/// transactions in the same way. Plans are based on [CycleEstimates], and thus a planned
+/// transaction may exceed its budget when executed if the estimates are too low.
+#[derive(Debug, Clone)]
+pub struct NotePlanner {
+    estimates: CycleEstimates,
+    budget: TransactionBudget,
+    strategy: SelectionStrategy,
+}
+
+impl NotePlanner {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NotePlanner] which plans transactions within the specified budget using the
+    /// default [SelectionStrategy].
+    pub fn new(estimates: CycleEstimates, budget: TransactionBudget) -> Self {
+        Self {
+            estimates,
+            budget,
+            strategy: SelectionStrategy::default(),
+        }
+    }
+
+    /// Sets the strategy by which this planner selects the notes of a transaction.
+    pub fn with_strategy(mut self, strategy: SelectionStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the cycle estimates used by this planner.
+    pub fn estimates(&self) -> &CycleEstimates {
+        &self.estimates
+    }
+
+    /// Returns the budget of the transactions planned by this planner.
+    pub fn budget(&self) -> TransactionBudget {
+        self.budget
+    }
+
+    // PLANNING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the transaction consuming a maximal subset of the provided notes within the budget,
+    /// or None if none of the notes fits the budget.
+    pub fn select(&self, notes: &[Note]) -> Option<PlannedTransaction> {
+        let (candidates, _) = self.candidates(notes);
+        let selected = self.select_candidates(&candidates);
+        (!selected.is_empty()).then(|| self.build_transaction(&candidates, &selected))
+    }
+
+    /// Returns the plan of the transactions consuming the provided notes within the budget.
+    pub fn plan(&self, notes: &[Note]) -> NotePlan {
+        let (mut candidates, oversized_notes) = self.candidates(notes);
+
+        let mut transactions = Vec::new();
+        while !candidates.is_empty() {
+            let selected = self.select_candidates(&candidates);
+            transactions.push(self.build_transaction(&candidates, &selected));
+
+            // selected indexes are sorted, and thus can be removed from the back
+            for index in selected.into_iter().rev() {
+                candidates.remove(index);
+            }
+        }
+
+        NotePlan { transactions, oversized_notes }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of cycles available for executing the notes of a transaction.
+    fn note_capacity(&self) -> usize {
+        self.budget.max_cycles.saturating_sub(self.estimates.base_cycles)
+    }
+
+    /// Returns the IDs and the estimated cycles of the provided notes which fit the budget on
+    /// their own, together with the IDs of the notes which do not.
+    fn candidates(&self, notes: &[Note]) -> (Vec<(NoteId, usize)>, Vec<NoteId>) {
+        let capacity = self.note_capacity();
+        let (candidates, oversized): (Vec<_>, Vec<_>) = notes
+            .iter()
+            .map(|note| (note.id(), self.estimates.note_cycles(note.script().hash())))
+            .partition(|(_, cycles)| *cycles <= capacity);
+
+        (candidates, oversized.into_iter().map(|(note_id, _)| note_id).collect())
+    }
+
+    /// Returns the sorted indexes of the candidates selected for the next transaction.
+    ///
+    /// All candidates are assumed to fit the budget on their own, and thus at least one candidate
+    /// is selected if there are any.
+    fn select_candidates(&self, candidates: &[(NoteId, usize)]) -> Vec<usize> {
+        let mut selected = match self.strategy {
+            SelectionStrategy::MaxNotes => {
+                select_max_notes(candidates, self.note_capacity(), self.budget.max_notes)
+            },
+            SelectionStrategy::FillBudget => {
+                select_fill_budget(candidates, self.note_capacity(), self.budget.max_notes)
+            },
+        };
+        selected.sort_unstable();
+        selected
+    }
+
+    /// Returns the planned transaction consuming the candidates with the specified indexes.
+    fn build_transaction(
+        &self,
+        candidates: &[(NoteId, usize)],
+        selected: &[usize],
+    ) -> PlannedTransaction {
+        let notes = selected.iter().map(|&index| candidates[index].0).collect();
+        let estimated_cycles = selected
+            .iter()
+            .map(|&index| candidates[index].1)
+            .fold(self.estimates.base_cycles, usize::saturating_add);
+
+        PlannedTransaction { notes, estimated_cycles }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the indexes of the largest number of candidates whose cycles fit the capacity, by
+/// selecting the cheapest candidates first.
+fn select_max_notes(
+    candidates: &[(NoteId, usize)],
+    capacity: usize,
+    max_notes: usize,
+) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..candidates.len()).collect();
+    order.sort_by_key(|&index| candidates[index].1);
+
+    let mut used_cycles = 0;
+    order
+        .into_iter()
+        .take(max_notes)
+        .take_while(|&index| {
+            used_cycles += candidates[index].1;
+            used_cycles <= capacity
+        })
+        .collect()
+}
+
+/// Returns the indexes of the candidates whose cycles use as much of the capacity as possible.
+///
+/// This solves a subset-sum problem over the cycles of the candidates, rounded up to a granularity
+/// of 1/4096 of the capacity, via dynamic programming: for every total, the table records the
+/// smallest number of candidates with this total, so that totals reachable with at most
+/// `max_notes` candidates can be identified.
+fn select_fill_budget(
+    candidates: &[(NoteId, usize)],
+    capacity: usize,
+    max_notes: usize,
+) -> Vec<usize> {
+    const NUM_BUCKETS: usize = 4096;
+
+    let granularity = capacity.div_ceil(NUM_BUCKETS).max(1);
+    let max_total = capacity / granularity;
+    let weights: Vec<usize> =
+        candidates.iter().map(|(_, cycles)| cycles.div_ceil(granularity)).collect();
+
+    // min_notes[total] is the smallest number of candidates whose weights sum up to total, and
+    // taken[i][total] records whether candidate i is part of such a selection
+    let mut min_notes = vec![usize::MAX; max_total + 1];
+    min_notes[0] = 0;
+    let mut taken = vec![vec![false; max_total + 1]; candidates.len()];
+    for (index, &weight) in weights.iter().enumerate() {
+        for total in (weight..=max_total).rev() {
+            let num_notes = min_notes[total - weight].saturating_add(1);
+            if num_notes < min_notes[total] {
+                min_notes[total] = num_notes;
+                taken[index][total] = true;
+            }
+        }
+    }
+
+    let mut total = (0..=max_total)
+        .rev()
+        .find(|&total| min_notes[total] <= max_notes)
+        .expect("an empty selection is always possible");
+
+    let mut selected = Vec::new();
+    for index in (0..candidates.len()).rev() {
+        if taken[index][total] {
+            selected.push(index);
+            total -= weights[index];
+        }
+    }
+    selected
+}
@@ -0,0 +1,64 @@
+use core::fmt;
+
+use miden_prover::HashFunction;
+
+// PROVER BACKEND
+// ================================================================================================
+
+/// Hardware backends which can be used to generate transaction proofs.
+///
+/// The backend is selected by the underlying prover at compile time: hardware acceleration is
+/// enabled via the corresponding feature of this crate (e.g., `metal`), and is only used on the
+/// platforms supported by the accelerator and for the hash functions it implements. In all other
+/// cases, proofs are generated on the CPU.
+///
+/// A [LocalTransactionProver](super::LocalTransactionProver) can be required to use a specific
+/// backend via `LocalTransactionProver::with_backend()`, which fails if the backend would not be
+/// used in the current build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProverBackend {
+    /// Proofs are generated on the CPU (using multiple threads with the `concurrent` feature).
+    Cpu,
+    /// Trace commitments are computed on the GPU via Apple's Metal API.
+    ///
+    /// Available with the `metal` feature on Apple silicon, for the RPO hash function.
+    #[cfg(feature = "metal")]
+    Metal,
+}
+
+impl ProverBackend {
+    /// All backends supported by the current build, whether or not they are available on the
+    /// current platform.
+    pub const ALL: &'static [ProverBackend] = &[
+        ProverBackend::Cpu,
+        #[cfg(feature = "metal")]
+        ProverBackend::Metal,
+    ];
+
+    /// Returns the backend used by the underlying prover to generate proofs with the specified
+    /// hash function.
+    pub fn for_hash_function(hash_fn: HashFunction) -> Self {
+        #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
+        if hash_fn == HashFunction::Rpo256 {
+            return ProverBackend::Metal;
+        }
+
+        let _ = hash_fn;
+        ProverBackend::Cpu
+    }
+
+    /// Returns true if proofs with the specified hash function are generated using this backend.
+    pub fn supports(&self, hash_fn: HashFunction) -> bool {
+        Self::for_hash_function(hash_fn) == *self
+    }
+}
+
+impl fmt::Display for ProverBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProverBackend::Cpu => write!(f, "cpu"),
+            #[cfg(feature = "metal")]
+            ProverBackend::Metal => write!(f, "metal"),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    sync::{Arc, Condvar, Mutex},
+    thread,
+};
+
+use miden_objects::transaction::{ProvenTransaction, TransactionWitness};
+
+use super::{LocalTransactionProver, TransactionProverError};
+
+// PROVING HANDLE
+// ================================================================================================
+
+/// A handle to a transaction being proven on a dedicated thread.
+///
+/// The handle is returned by [LocalTransactionProver::prove_transaction_async()]. It can be
+/// awaited as a [Future], or the result can be retrieved by blocking the current thread via
+/// [ProvingHandle::wait()].
+///
+/// Proving can be cancelled via [ProvingHandle::cancel()] or by dropping the handle. If proving
+/// has not started yet, it is skipped altogether; otherwise, the proving thread runs to completion
+/// (the prover cannot be interrupted), but its result is discarded.
+#[derive(Debug)]
+pub struct ProvingHandle {
+    task: Arc<ProvingTask>,
+}
+
+impl ProvingHandle {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Spawns a new thread which proves the provided transaction witness using the specified
+    /// prover, and returns a handle to it.
+    pub(super) fn spawn(prover: LocalTransactionProver, tx_witness: TransactionWitness) -> Self {
+        let task = Arc::new(ProvingTask::default());
+
+        let worker_task = task.clone();
+        thread::spawn(move || {
+            // the guard makes sure the handle is notified even if the prover panics
+            let mut guard = CompletionGuard { task: worker_task, result: None };
+            if !guard.task.is_cancelled() {
+                guard.result = Some(prover.prove_transaction(tx_witness));
+            }
+        });
+
+        Self { task }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if proving has completed and the result can be retrieved without blocking.
+    pub fn is_finished(&self) -> bool {
+        self.task.state.lock().expect("proving task lock poisoned").result.is_some()
+    }
+
+    // PUBLIC METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Blocks the current thread until proving completes and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error if the transaction cannot be proven, or if the proving thread panicked.
+    pub fn wait(self) -> Result<ProvenTransaction, TransactionProverError> {
+        let mut state = self.task.state.lock().expect("proving task lock poisoned");
+        loop {
+            if let Some(result) = state.result.take() {
+                return result;
+            }
+            state = self.task.completed.wait(state).expect("proving task lock poisoned");
+        }
+    }
+
+    /// Cancels proving of the transaction.
+    pub fn cancel(self) {
+        // cancellation is performed when the handle is dropped
+    }
+}
+
+impl Future for ProvingHandle {
+    type Output = Result<ProvenTransaction, TransactionProverError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.task.state.lock().expect("proving task lock poisoned");
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            },
+        }
+    }
+}
+
+impl Drop for ProvingHandle {
+    fn drop(&mut self) {
+        self.task.state.lock().expect("proving task lock poisoned").cancelled = true;
+    }
+}
+
+// PROVING TASK
+// ================================================================================================
+
+/// State shared between a [ProvingHandle] and the thread proving the transaction.
+#[derive(Debug, Default)]
+struct ProvingTask {
+    state: Mutex<ProvingTaskState>,
+    completed: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct ProvingTaskState {
+    result: Option<Result<ProvenTransaction, TransactionProverError>>,
+    waker: Option<Waker>,
+    cancelled: bool,
+}
+
+impl ProvingTask {
+    fn is_cancelled(&self) -> bool {
+        self.state.lock().expect("proving task lock poisoned").cancelled
+    }
+}
+
+/// Stores the result of a proving task and notifies the [ProvingHandle] when dropped.
+///
+/// If the proving thread panics before the result is set, the handle receives a
+/// [TransactionProverError::ProvingTaskPanicked] error.
+struct CompletionGuard {
+    task: Arc<ProvingTask>,
+    result: Option<Result<ProvenTransaction, TransactionProverError>>,
+}
+
+impl Drop for CompletionGuard {
+    fn drop(&mut self) {
+        // the lock may be poisoned only if a thread panicked while holding it, in which case the
+        // state is still consistent because it is only ever updated by a single assignment
+        let mut state = match self.task.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        state.result =
+            Some(self.result.take().unwrap_or(Err(TransactionProverError::ProvingTaskPanicked)));
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+        self.task.completed.notify_all();
+    }
+}
@@ -1,7 +1,7 @@
 use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
-use miden_objects::{
-    notes::Nullifier,
-    transaction::{InputNotes, ProvenTransaction, TransactionWitness},
+use miden_objects::transaction::{
+    AccountDetails, ExecutedTransaction, InputNoteCommitment, InputNotes, KernelRegistry,
+    ProvenTransaction, TransactionWitness,
 };
 use miden_prover::prove;
 pub use miden_prover::ProvingOptions;
@@ -9,20 +9,121 @@ use vm_processor::{Digest, MemAdviceProvider};
 
 use super::{TransactionHost, TransactionProverError};
 
-/// Transaction prover is a stateless component which is responsible for proving transactions.
+mod backend;
+pub use backend::ProverBackend;
+
+#[cfg(all(feature = "async", feature = "std"))]
+mod handle;
+#[cfg(all(feature = "async", feature = "std"))]
+pub use handle::ProvingHandle;
+
+mod options;
+pub use options::ProvingOptionsBuilder;
+
+#[cfg(feature = "remote")]
+mod remote;
+#[cfg(feature = "remote")]
+pub use remote::{ProvingServiceClient, RemoteTransactionProver};
+
+// TRANSACTION PROVER TRAIT
+// ================================================================================================
+
+/// The [TransactionProver] trait defines the interface of the components which turn executed
+/// transactions into [ProvenTransaction]s.
+///
+/// This allows the proving of transactions to be delegated, e.g., to a proving service, by
+/// environments which cannot prove transactions locally. [LocalTransactionProver] proves
+/// transactions in the current process; with the `remote` feature enabled,
+/// `RemoteTransactionProver` delegates proving to a proving service.
+pub trait TransactionProver {
+    /// Proves the provided transaction and returns a [ProvenTransaction].
+    fn prove(
+        &self,
+        transaction: ExecutedTransaction,
+    ) -> Result<ProvenTransaction, TransactionProverError>;
+}
+
+// LOCAL TRANSACTION PROVER
+// ================================================================================================
+
+/// Local transaction prover is a stateless component which is responsible for proving
+/// transactions in the current process.
+///
+/// Local transaction prover exposes the `prove_transaction` method which takes a
+/// [TransactionWitness], or anything that can be converted into a [TransactionWitness], and
+/// returns a [ProvenTransaction]. Only transactions executed against a kernel contained in the
+/// prover's [KernelRegistry] can be proven.
 ///
-/// Transaction prover exposes the `prove_transaction` method which takes a [TransactionWitness],
-/// or anything that can be converted into a [TransactionWitness], and returns a [ProvenTransaction].
-pub struct TransactionProver {
+/// With the `async` feature enabled, transactions can also be proven on a dedicated thread via
+/// `LocalTransactionProver::prove_transaction_async()`, so that the calling thread is not blocked
+/// while the proof is being generated.
+///
+/// The hardware backend used for proving depends on the features this crate is compiled with
+/// and on the hash function of the proving options (see [ProverBackend]).
+#[derive(Clone)]
+pub struct LocalTransactionProver {
     proof_options: ProvingOptions,
+    kernel_registry: KernelRegistry,
+    backend: ProverBackend,
 }
 
-impl TransactionProver {
+impl LocalTransactionProver {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Creates a new [TransactionProver] instance.
+    /// Creates a new [LocalTransactionProver] instance which proves transactions against the
+    /// current transaction kernel.
     pub fn new(proof_options: ProvingOptions) -> Self {
-        Self { proof_options }
+        Self {
+            backend: ProverBackend::for_hash_function(proof_options.hash_fn()),
+            proof_options,
+            kernel_registry: TransactionKernel::kernel_registry(),
+        }
+    }
+
+    /// Creates a new [LocalTransactionProver] instance with the proving options defined by the
+    /// provided [ProvingOptionsBuilder].
+    ///
+    /// # Errors
+    /// Returns an error if the builder does not define valid proving options.
+    pub fn from_options_builder(
+        builder: ProvingOptionsBuilder,
+    ) -> Result<Self, TransactionProverError> {
+        Ok(Self::new(builder.build()?))
+    }
+
+    /// Replaces the registry of transaction kernels this prover accepts with the provided one.
+    pub fn with_kernel_registry(mut self, kernel_registry: KernelRegistry) -> Self {
+        self.kernel_registry = kernel_registry;
+        self
+    }
+
+    /// Requires this prover to generate proofs using the specified [ProverBackend].
+    ///
+    /// # Errors
+    /// Returns an error if the backend would not be used to generate proofs with the proving
+    /// options of this prover, e.g., because this crate was compiled without the feature enabling
+    /// the backend, the current platform is not supported by the backend, or the hash function of
+    /// the proving options is not implemented by the backend.
+    pub fn with_backend(mut self, backend: ProverBackend) -> Result<Self, TransactionProverError> {
+        let hash_fn = self.proof_options.hash_fn();
+        if !backend.supports(hash_fn) {
+            return Err(TransactionProverError::UnsupportedBackend { backend, hash_fn });
+        }
+        self.backend = backend;
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [ProvingOptions] used by this prover.
+    pub fn proof_options(&self) -> &ProvingOptions {
+        &self.proof_options
+    }
+
+    /// Returns the [ProverBackend] used by this prover to generate proofs.
+    pub fn backend(&self) -> ProverBackend {
+        self.backend
     }
 
     // TRANSACTION PROVER
@@ -31,19 +132,41 @@ impl TransactionProver {
     /// Proves the provided transaction and returns a [ProvenTransaction].
     ///
     /// # Errors
+    /// - If the transaction program is not a kernel contained in the kernel registry.
     /// - If the consumed note data in the transaction witness is corrupt.
     /// - If the transaction program cannot be proven.
     /// - If the transaction result is corrupt.
+    /// - If the account details of an on-chain account are inconsistent with the final account
+    ///   state.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "prove_transaction",
+            skip_all,
+            fields(account_id = tracing::field::Empty, backend = %self.backend),
+            err
+        )
+    )]
     pub fn prove_transaction<T: Into<TransactionWitness>>(
         &self,
         transaction: T,
     ) -> Result<ProvenTransaction, TransactionProverError> {
         let tx_witness: TransactionWitness = transaction.into();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current()
+            .record("account_id", tracing::field::display(tx_witness.account().id()));
+
+        // make sure the transaction is executed against a known kernel
+        let kernel_root = tx_witness.program().hash();
+        if !self.kernel_registry.contains(kernel_root) {
+            return Err(TransactionProverError::UnknownKernel(kernel_root));
+        }
 
         // extract required data from the transaction witness
         let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
 
-        let input_notes: InputNotes<Nullifier> = (tx_witness.tx_inputs().input_notes()).into();
+        let input_notes: InputNotes<InputNoteCommitment> =
+            (tx_witness.tx_inputs().input_notes()).into();
 
         let account_id = tx_witness.account().id();
         let initial_account_hash = tx_witness.account().hash();
@@ -52,17 +175,43 @@ impl TransactionProver {
 
         let advice_provider: MemAdviceProvider = advice_inputs.into();
         let mut host = TransactionHost::new(tx_witness.account().into(), advice_provider);
+
+        #[cfg(feature = "tracing")]
+        let timer = crate::timer::Timer::start();
         let (stack_outputs, proof) =
             prove(tx_witness.program(), stack_inputs, &mut host, self.proof_options.clone())
                 .map_err(TransactionProverError::ProveTransactionProgramFailed)?;
 
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            duration_ms = timer.elapsed_ms(),
+            proof_size = proof.to_bytes().len(),
+            "proved transaction program"
+        );
+
         // extract transaction outputs and process transaction data
-        let (advice_provider, _event_handler) = host.into_parts();
+        let (advice_provider, account_delta) = host.into_parts();
         let (_, map, _) = advice_provider.into_parts();
         let tx_outputs = TransactionKernel::parse_transaction_outputs(&stack_outputs, &map.into())
             .map_err(TransactionProverError::InvalidTransactionOutput)?;
 
-        Ok(ProvenTransaction::new(
+        // on-chain accounts carry the full state for new accounts and the delta otherwise, so
+        // that the state of the account can be reconstructed from the proven transaction
+        let account_details = if account_id.is_on_chain() {
+            if tx_witness.account().is_new() {
+                let mut account = tx_witness.account().clone();
+                account
+                    .apply_delta(&account_delta)
+                    .map_err(TransactionProverError::InvalidAccountDelta)?;
+                Some(AccountDetails::Full(account))
+            } else {
+                Some(AccountDetails::Delta(account_delta))
+            }
+        } else {
+            None
+        };
+
+        ProvenTransaction::new(
             account_id,
             if tx_witness.account().is_new() {
                 Digest::default()
@@ -72,9 +221,37 @@ impl TransactionProver {
             tx_outputs.account.hash(),
             input_notes,
             tx_outputs.output_notes.into(),
+            account_details,
             tx_script_root,
+            kernel_root,
             block_hash,
+            tx_outputs.expiration_block_num,
+            tx_outputs.fee,
             proof,
-        ))
+        )
+        .map_err(TransactionProverError::InvalidProvenTransaction)
+    }
+
+    /// Starts proving the provided transaction on a dedicated thread and returns a
+    /// [ProvingHandle] for it.
+    ///
+    /// The returned handle can be awaited to obtain the [ProvenTransaction], waited on
+    /// synchronously, or used to cancel proving. Errors are the same as for
+    /// [LocalTransactionProver::prove_transaction()].
+    #[cfg(all(feature = "async", feature = "std"))]
+    pub fn prove_transaction_async<T: Into<TransactionWitness>>(
+        &self,
+        transaction: T,
+    ) -> ProvingHandle {
+        ProvingHandle::spawn(self.clone(), transaction.into())
+    }
+}
+
+impl TransactionProver for LocalTransactionProver {
+    fn prove(
+        &self,
+        transaction: ExecutedTransaction,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        self.prove_transaction(transaction)
     }
 }
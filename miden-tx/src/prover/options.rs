@@ -0,0 +1,173 @@
+use miden_prover::{FieldExtension, HashFunction, ProvingOptions};
+
+use crate::TransactionProverError;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum blowup factor supported by the prover.
+const MAX_BLOWUP_FACTOR: usize = 128;
+
+/// Maximum number of grinding bits supported by the prover.
+const MAX_GRINDING_BITS: u32 = 32;
+
+/// Maximum security level which can be achieved with the quadratic extension of the base field;
+/// higher security levels require the cubic extension.
+const MAX_QUADRATIC_EXTENSION_SECURITY: u32 = 100;
+
+/// Maximum security level which can be achieved with the 192-bit BLAKE3 hash function.
+const MAX_BLAKE3_192_SECURITY: u32 = 96;
+
+// PROVING OPTIONS BUILDER
+// ================================================================================================
+
+/// A builder of the [ProvingOptions] used by a
+/// [LocalTransactionProver](super::LocalTransactionProver).
+///
+/// The builder trades proof size against proving time: a higher blowup factor results in smaller
+/// proofs (as fewer queries are needed to reach the target security level) but slower proving,
+/// and vice versa. Grinding bits reduce the number of queries at the cost of a proof-of-work
+/// computed by the prover. The number of queries is derived from the other parameters so that the
+/// proof reaches the target security level.
+///
+/// The following presets are provided:
+/// - [ProvingOptionsBuilder::fast()] minimizes proving time at 96-bit security.
+/// - [ProvingOptionsBuilder::regular_96bit()] matches the default 96-bit security options.
+/// - [ProvingOptionsBuilder::recursive_128bit()] produces 128-bit secure proofs which can be
+///   efficiently verified in the Miden VM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProvingOptionsBuilder {
+    blowup_factor: usize,
+    grinding_bits: u32,
+    hash_fn: HashFunction,
+    security_level: u32,
+}
+
+impl ProvingOptionsBuilder {
+    // PRESETS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a builder for 96-bit secure proofs optimized for proving time.
+    pub fn fast() -> Self {
+        Self {
+            blowup_factor: 4,
+            grinding_bits: 20,
+            hash_fn: HashFunction::Blake3_192,
+            security_level: 96,
+        }
+    }
+
+    /// Returns a builder for regular 96-bit secure proofs.
+    pub fn regular_96bit() -> Self {
+        Self {
+            blowup_factor: 8,
+            grinding_bits: 16,
+            hash_fn: HashFunction::Blake3_192,
+            security_level: 96,
+        }
+    }
+
+    /// Returns a builder for 128-bit secure proofs which can be efficiently verified in the Miden
+    /// VM.
+    pub fn recursive_128bit() -> Self {
+        Self {
+            blowup_factor: 16,
+            grinding_bits: 21,
+            hash_fn: HashFunction::Rpo256,
+            security_level: 128,
+        }
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the blowup factor of the execution trace; must be a power of two between 2 and 128.
+    pub fn with_blowup_factor(mut self, blowup_factor: usize) -> Self {
+        self.blowup_factor = blowup_factor;
+        self
+    }
+
+    /// Sets the number of grinding bits; must be at most 32.
+    pub fn with_grinding_bits(mut self, grinding_bits: u32) -> Self {
+        self.grinding_bits = grinding_bits;
+        self
+    }
+
+    /// Sets the hash function used by the prover.
+    ///
+    /// Proofs generated with [HashFunction::Rpo256] can be efficiently verified in the Miden VM.
+    pub fn with_hash_function(mut self, hash_fn: HashFunction) -> Self {
+        self.hash_fn = hash_fn;
+        self
+    }
+
+    /// Sets the target (conjectured) security level of the proofs in bits.
+    pub fn with_security_level(mut self, security_level: u32) -> Self {
+        self.security_level = security_level;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of queries needed to reach the target security level.
+    pub fn num_queries(&self) -> usize {
+        let bits_per_query = self.blowup_factor.ilog2();
+        let query_security = self.security_level.saturating_sub(self.grinding_bits);
+        query_security.div_ceil(bits_per_query).max(1) as usize
+    }
+
+    /// Returns the [ProvingOptions] defined by this builder.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The blowup factor is not a power of two between 2 and 128.
+    /// - The number of grinding bits is greater than 32.
+    /// - The target security level cannot be reached with the specified hash function.
+    pub fn build(&self) -> Result<ProvingOptions, TransactionProverError> {
+        if !self.blowup_factor.is_power_of_two()
+            || !(2..=MAX_BLOWUP_FACTOR).contains(&self.blowup_factor)
+        {
+            return Err(TransactionProverError::InvalidBlowupFactor(self.blowup_factor));
+        }
+        if self.grinding_bits > MAX_GRINDING_BITS {
+            return Err(TransactionProverError::InvalidGrindingBits(self.grinding_bits));
+        }
+        if self.hash_fn == HashFunction::Blake3_192 && self.security_level > MAX_BLAKE3_192_SECURITY
+        {
+            return Err(TransactionProverError::UnsupportedSecurityLevel {
+                security_level: self.security_level,
+                hash_fn: self.hash_fn,
+            });
+        }
+
+        let field_extension = if self.security_level <= MAX_QUADRATIC_EXTENSION_SECURITY {
+            FieldExtension::Quadratic
+        } else {
+            FieldExtension::Cubic
+        };
+
+        // proofs generated with an algebraic hash function are meant to be verified recursively,
+        // for which smaller FRI folding factors and remainders are more efficient
+        let (fri_folding_factor, fri_remainder_max_degree) = match self.hash_fn {
+            HashFunction::Rpo256 => (4, 7),
+            _ => (8, 255),
+        };
+
+        Ok(ProvingOptions::new(
+            self.num_queries(),
+            self.blowup_factor,
+            self.grinding_bits,
+            field_extension,
+            fri_folding_factor,
+            fri_remainder_max_degree,
+            self.hash_fn,
+        ))
+    }
+}
+
+impl Default for ProvingOptionsBuilder {
+    fn default() -> Self {
+        Self::regular_96bit()
+    }
+}
@@ -0,0 +1,117 @@
+use miden_objects::{
+    transaction::{
+        ExecutedTransaction, InputNoteCommitment, InputNotes, ProvenTransaction, TransactionWitness,
+    },
+    utils::{
+        collections::Vec,
+        serde::{Deserializable, Serializable},
+        string::String,
+    },
+    Digest,
+};
+
+use super::{TransactionProver, TransactionProverError};
+
+// PROVING SERVICE CLIENT
+// ================================================================================================
+
+/// The [ProvingServiceClient] trait defines the transport used by a [RemoteTransactionProver] to
+/// communicate with a proving service, e.g., over gRPC or HTTP.
+///
+/// The client is given the serialized [TransactionWitness] of the transaction to be proven, and
+/// returns the serialized [ProvenTransaction] produced by the service.
+pub trait ProvingServiceClient {
+    /// Sends the serialized transaction witness to the proving service and returns the serialized
+    /// proven transaction received in response.
+    ///
+    /// # Errors
+    /// Returns a description of the failure if the request could not be delivered, or if the
+    /// proving service failed to prove the transaction.
+    fn prove(&self, tx_witness: Vec<u8>) -> Result<Vec<u8>, String>;
+}
+
+// REMOTE TRANSACTION PROVER
+// ================================================================================================
+
+/// Remote transaction prover delegates the proving of transactions to a proving service, e.g.,
+/// for environments such as mobile or browser wallets which cannot prove transactions locally.
+///
+/// The prover serializes the [TransactionWitness] of a transaction, ships it to the service via
+/// the provided [ProvingServiceClient], and deserializes the [ProvenTransaction] returned by the
+/// service. The returned transaction is checked to prove the shipped witness, i.e., to be executed
+/// against the same account state, block, input notes and kernel; the proof itself is not
+/// verified, which can be done using a [TransactionVerifier](crate::TransactionVerifier).
+pub struct RemoteTransactionProver<C: ProvingServiceClient> {
+    client: C,
+}
+
+impl<C: ProvingServiceClient> RemoteTransactionProver<C> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Creates a new [RemoteTransactionProver] instance which proves transactions using the
+    /// proving service reached via the specified client.
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the client used by this prover to communicate with the proving service.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    // TRANSACTION PROVER
+    // --------------------------------------------------------------------------------------------
+
+    /// Proves the provided transaction using the proving service and returns a
+    /// [ProvenTransaction].
+    ///
+    /// # Errors
+    /// - If the proving service cannot be reached or fails to prove the transaction.
+    /// - If the response of the proving service is not a valid serialized proven transaction.
+    /// - If the returned proven transaction does not prove the provided transaction.
+    pub fn prove_transaction<T: Into<TransactionWitness>>(
+        &self,
+        transaction: T,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let tx_witness: TransactionWitness = transaction.into();
+
+        let response = self
+            .client
+            .prove(tx_witness.to_bytes())
+            .map_err(TransactionProverError::RemoteProvingFailed)?;
+        let proven_transaction = ProvenTransaction::read_from_bytes(&response)
+            .map_err(TransactionProverError::InvalidRemoteResponse)?;
+
+        // make sure the service proved the transaction it was sent
+        let initial_account_hash = if tx_witness.account().is_new() {
+            Digest::default()
+        } else {
+            tx_witness.account().hash()
+        };
+        let input_notes: InputNotes<InputNoteCommitment> =
+            (tx_witness.tx_inputs().input_notes()).into();
+
+        if proven_transaction.account_id() != tx_witness.account().id()
+            || proven_transaction.initial_account_hash() != initial_account_hash
+            || proven_transaction.block_ref() != tx_witness.block_header().hash()
+            || proven_transaction.input_notes().commitment() != input_notes.commitment()
+            || proven_transaction.kernel_root() != tx_witness.program().hash()
+        {
+            return Err(TransactionProverError::RemoteProofMismatch(proven_transaction.id()));
+        }
+
+        Ok(proven_transaction)
+    }
+}
+
+impl<C: ProvingServiceClient> TransactionProver for RemoteTransactionProver<C> {
+    fn prove(
+        &self,
+        transaction: ExecutedTransaction,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        self.prove_transaction(transaction)
+    }
+}
@@ -0,0 +1,164 @@
+use miden_objects::{
+    accounts::{Account, AccountId},
+    notes::{Note, NoteId},
+    transaction::{ExecutedTransaction, OutputNote},
+    utils::collections::{BTreeMap, BTreeSet},
+};
+
+use crate::StateSimulatorError;
+
+// STATE SIMULATOR
+// ================================================================================================
+
+/// A local view of a set of accounts and unspent notes which can be advanced by applying the
+/// effects of executed transactions.
+///
+/// Applying an [ExecutedTransaction] consumes its input notes, applies its account delta to the
+/// affected account, and adds its output notes to the set of unspent notes. This makes it possible
+/// to project the state of accounts (e.g., vault balances) across a queue of transactions which
+/// have been executed locally but not yet included in a block.
+///
+/// The simulator detects the following conflicts:
+/// - A note is consumed by more than one transaction.
+/// - A transaction was executed against an account state other than the current state of the
+///   account in the simulator (e.g., the transaction was executed before a transaction which is
+///   applied ahead of it).
+#[derive(Debug, Default, Clone)]
+pub struct StateSimulator {
+    accounts: BTreeMap<AccountId, Account>,
+    unspent_notes: BTreeMap<NoteId, OutputNote>,
+    consumed_notes: BTreeSet<NoteId>,
+}
+
+impl StateSimulator {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StateSimulator] instantiated with the provided accounts and unspent notes.
+    pub fn new<A, N>(accounts: A, notes: N) -> Self
+    where
+        A: IntoIterator<Item = Account>,
+        N: IntoIterator<Item = Note>,
+    {
+        let mut simulator = Self::default();
+        accounts.into_iter().for_each(|account| simulator.add_account(account));
+        notes.into_iter().for_each(|note| simulator.add_note(&note));
+        simulator
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the current state of the account with the specified ID, if the account is tracked
+    /// by this simulator.
+    pub fn account(&self, account_id: AccountId) -> Option<&Account> {
+        self.accounts.get(&account_id)
+    }
+
+    /// Returns an iterator over all accounts tracked by this simulator.
+    pub fn accounts(&self) -> impl Iterator<Item = &Account> {
+        self.accounts.values()
+    }
+
+    /// Returns the balance of the fungible asset issued by the specified faucet in the vault of
+    /// the specified account, or None if the account is not tracked by this simulator or the
+    /// faucet ID is not a fungible faucet ID.
+    pub fn balance(&self, account_id: AccountId, faucet_id: AccountId) -> Option<u64> {
+        self.accounts.get(&account_id)?.vault().get_balance(faucet_id).ok()
+    }
+
+    /// Returns an iterator over all unspent notes tracked by this simulator.
+    pub fn unspent_notes(&self) -> impl Iterator<Item = &OutputNote> {
+        self.unspent_notes.values()
+    }
+
+    /// Returns true if the note with the specified ID is unspent.
+    pub fn is_unspent(&self, note_id: NoteId) -> bool {
+        self.unspent_notes.contains_key(&note_id)
+    }
+
+    /// Returns true if the note with the specified ID was consumed by an applied transaction.
+    pub fn is_consumed(&self, note_id: NoteId) -> bool {
+        self.consumed_notes.contains(&note_id)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided account to this simulator, replacing the previous state of the account
+    /// if it was already tracked.
+    pub fn add_account(&mut self, account: Account) {
+        self.accounts.insert(account.id(), account);
+    }
+
+    /// Adds the provided note to the set of unspent notes of this simulator.
+    pub fn add_note(&mut self, note: &Note) {
+        self.unspent_notes.insert(note.id(), note.into());
+    }
+
+    /// Applies the effects of the provided transaction to the state of this simulator.
+    ///
+    /// Input notes which are not tracked by this simulator are assumed to be unspent. New accounts
+    /// which are not tracked by this simulator are added on their first transaction.
+    ///
+    /// The state of the simulator is not modified if an error is returned.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the input notes of the transaction was already consumed.
+    /// - The account of the transaction is an existing account which is not tracked by this
+    ///   simulator.
+    /// - The transaction was executed against an account state different from the current state
+    ///   of the account.
+    /// - Applying the account delta fails, or results in a state which is different from the final
+    ///   account state of the transaction.
+    pub fn apply_transaction(
+        &mut self,
+        transaction: &ExecutedTransaction,
+    ) -> Result<(), StateSimulatorError> {
+        // make sure none of the input notes has been consumed already
+        for note in transaction.input_notes().iter() {
+            if self.consumed_notes.contains(&note.id()) {
+                return Err(StateSimulatorError::NoteAlreadyConsumed(note.id()));
+            }
+        }
+
+        // compute the new state of the account
+        let initial_account = transaction.initial_account();
+        let mut account = match self.accounts.get(&initial_account.id()) {
+            Some(account) => account.clone(),
+            None if initial_account.is_new() => initial_account.clone(),
+            None => return Err(StateSimulatorError::AccountNotFound(initial_account.id())),
+        };
+
+        if account.hash() != initial_account.hash() {
+            return Err(StateSimulatorError::InitialAccountHashMismatch {
+                expected: account.hash(),
+                actual: initial_account.hash(),
+            });
+        }
+
+        account
+            .apply_delta(transaction.account_delta())
+            .map_err(StateSimulatorError::AccountDeltaApplicationFailed)?;
+
+        if account.hash() != transaction.final_account().hash() {
+            return Err(StateSimulatorError::FinalAccountHashMismatch {
+                expected: transaction.final_account().hash(),
+                actual: account.hash(),
+            });
+        }
+
+        // update the state
+        self.accounts.insert(account.id(), account);
+        for note in transaction.input_notes().iter() {
+            self.unspent_notes.remove(&note.id());
+            self.consumed_notes.insert(note.id());
+        }
+        for note in transaction.output_notes().iter() {
+            self.unspent_notes.insert(note.id(), note.clone());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+#[cfg(not(feature = "std"))]
+use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use core::cell::{Ref, RefCell, RefMut};
+#[cfg(feature = "std")]
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assembly::ModuleAst,
+    block::NullifierTree,
+    crypto::merkle::{MerklePath, PartialMmr},
+    notes::{NoteId, Nullifier},
+    transaction::{ChainMmr, ExecutedTransaction, InputNote, InputNotes, TransactionInputs},
+    utils::collections::{BTreeMap, Vec},
+    BlockHeader, Word,
+};
+
+use crate::{DataStore, DataStoreError};
+
+// MEMORY DATA STORE
+// ================================================================================================
+
+/// A [DataStore] which keeps all data required for transaction execution in memory.
+///
+/// The store holds accounts (together with the seeds of new accounts), input notes, block headers
+/// together with the chain MMRs for these blocks, and the nullifiers of the notes consumed by the
+/// transactions applied to the store. Notes whose nullifiers are in the store are rejected as
+/// inputs of further transactions, which prevents double-spends before the transactions are
+/// executed. The store is cheap to clone, and all clones share the same underlying data: this way,
+/// the store can be updated (e.g., via [MemoryDataStore::apply_transaction()]) after it has been
+/// passed to a [TransactionExecutor](crate::TransactionExecutor).
+///
+/// Transaction inputs are returned with a chain MMR tracking only the peaks of the chain; the
+/// blocks needed to authenticate the input notes are served via [DataStore::get_block_headers()].
+///
+/// Without the `std` feature, the clones share the data via a reference-counted cell instead of a
+/// lock, and thus the store cannot be shared across threads.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryDataStore {
+    state: SharedState,
+}
+
+#[cfg(feature = "std")]
+type SharedState = Arc<RwLock<MemoryDataStoreState>>;
+
+#[cfg(not(feature = "std"))]
+type SharedState = Rc<RefCell<MemoryDataStoreState>>;
+
+#[derive(Debug, Default)]
+struct MemoryDataStoreState {
+    accounts: BTreeMap<AccountId, (Account, Option<Word>)>,
+    blocks: BTreeMap<u32, (BlockHeader, ChainMmr)>,
+    notes: BTreeMap<NoteId, InputNote>,
+    nullifiers: NullifierTree,
+}
+
+impl MemoryDataStore {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [MemoryDataStore].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the current state of the account with the specified ID, if the account is in this
+    /// store.
+    pub fn account(&self, account_id: AccountId) -> Option<Account> {
+        self.read().accounts.get(&account_id).map(|(account, _)| account.clone())
+    }
+
+    /// Returns the input note with the specified ID, if the note is in this store.
+    pub fn note(&self, note_id: NoteId) -> Option<InputNote> {
+        self.read().notes.get(&note_id).cloned()
+    }
+
+    /// Returns the header of the block with the specified number, if the block is in this store.
+    pub fn block_header(&self, block_num: u32) -> Option<BlockHeader> {
+        self.read().blocks.get(&block_num).map(|(block_header, _)| *block_header)
+    }
+
+    /// Returns the number of the block in which the note with the specified nullifier was consumed
+    /// according to this store, or None if the note has not been consumed.
+    pub fn nullifier_block_num(&self, nullifier: Nullifier) -> Option<u32> {
+        self.read().nullifiers.get_block_num(nullifier)
+    }
+
+    /// Returns the number of the latest block in this store, or None if the store does not
+    /// contain any blocks.
+    pub fn latest_block_num(&self) -> Option<u32> {
+        self.read().blocks.keys().next_back().copied()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the provided account into this store, replacing the previous state of the account
+    /// if it was already in the store.
+    ///
+    /// The seed of the account must be provided for new accounts, and must be None for existing
+    /// accounts.
+    pub fn insert_account(&self, account: Account, seed: Option<Word>) {
+        self.write().accounts.insert(account.id(), (account, seed));
+    }
+
+    /// Inserts the provided input note into this store.
+    pub fn insert_note(&self, note: InputNote) {
+        self.write().notes.insert(note.id(), note);
+    }
+
+    /// Removes the note with the specified ID from this store, and returns the removed note.
+    pub fn remove_note(&self, note_id: NoteId) -> Option<InputNote> {
+        self.write().notes.remove(&note_id)
+    }
+
+    /// Inserts the provided block header into this store, together with the chain MMR of the
+    /// block, i.e., the MMR of all blocks preceding this block.
+    ///
+    /// The chain MMR must track all blocks in which the input notes consumed against this block
+    /// have been created; these blocks are served to the executor via
+    /// [DataStore::get_block_headers()].
+    pub fn insert_block(&self, block_header: BlockHeader, block_chain: ChainMmr) {
+        self.write()
+            .blocks
+            .insert(block_header.block_num(), (block_header, block_chain));
+    }
+
+    /// Applies the effects of the provided transaction to the data in this store: the account
+    /// delta of the transaction is applied to the account, and the input notes of the transaction
+    /// are removed from the store and their nullifiers are recorded as spent in the block following
+    /// the reference block of the transaction.
+    ///
+    /// The state of the store is not modified if an error is returned.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account of the transaction is not in this store.
+    /// - An input note of the transaction has already been consumed.
+    /// - Applying the account delta fails, or results in a state which is different from the final
+    ///   account state of the transaction.
+    pub fn apply_transaction(
+        &self,
+        transaction: &ExecutedTransaction,
+    ) -> Result<(), DataStoreError> {
+        let mut state = self.write();
+
+        let account_id = transaction.account_id();
+        let (mut account, _) = state
+            .accounts
+            .get(&account_id)
+            .cloned()
+            .ok_or(DataStoreError::AccountNotFound(account_id))?;
+        for note in transaction.input_notes().iter() {
+            if state.nullifiers.contains(note.nullifier()) {
+                return Err(DataStoreError::NoteAlreadyConsumed(note.id()));
+            }
+        }
+
+        account
+            .apply_delta(transaction.account_delta())
+            .map_err(DataStoreError::AccountDeltaApplicationFailed)?;
+        if account.hash() != transaction.final_account().hash() {
+            return Err(DataStoreError::FinalAccountHashMismatch {
+                expected: transaction.final_account().hash(),
+                actual: account.hash(),
+            });
+        }
+
+        // once a transaction has been executed against an account, the account is no longer new
+        // and thus its seed is no longer needed
+        state.accounts.insert(account_id, (account, None));
+        let block_num = transaction.block_header().block_num() + 1;
+        for note in transaction.input_notes().iter() {
+            state.notes.remove(&note.id());
+            state
+                .nullifiers
+                .insert(note.nullifier(), block_num)
+                .expect("nullifiers of input notes are unique and have not been spent");
+        }
+
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    #[cfg(feature = "std")]
+    fn read(&self) -> RwLockReadGuard<'_, MemoryDataStoreState> {
+        self.state.read().expect("memory data store lock is not poisoned")
+    }
+
+    #[cfg(feature = "std")]
+    fn write(&self) -> RwLockWriteGuard<'_, MemoryDataStoreState> {
+        self.state.write().expect("memory data store lock is not poisoned")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn read(&self) -> Ref<'_, MemoryDataStoreState> {
+        self.state.borrow()
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn write(&self) -> RefMut<'_, MemoryDataStoreState> {
+        self.state.borrow_mut()
+    }
+}
+
+impl DataStore for MemoryDataStore {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        let state = self.read();
+
+        let (account, seed) = state
+            .accounts
+            .get(&account_id)
+            .ok_or(DataStoreError::AccountNotFound(account_id))?;
+        let (block_header, block_chain) =
+            state.blocks.get(&block_ref).ok_or(DataStoreError::BlockNotFound(block_ref))?;
+
+        let input_notes = notes
+            .iter()
+            .map(|note_id| {
+                let note =
+                    state.notes.get(note_id).ok_or(DataStoreError::NoteNotFound(*note_id))?;
+                if state.nullifiers.contains(note.nullifier()) {
+                    return Err(DataStoreError::NoteAlreadyConsumed(*note_id));
+                }
+                Ok(note.clone())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+
+        // the executor fetches the blocks of the input notes via get_block_headers()
+        let block_chain = ChainMmr::new(PartialMmr::from_peaks(block_chain.peaks()), Vec::new())
+            .expect("chain MMR without blocks is valid");
+
+        TransactionInputs::new(account.clone(), *seed, *block_header, block_chain, input_notes)
+            .map_err(DataStoreError::InvalidTransactionInput)
+    }
+
+    fn get_block_headers(
+        &self,
+        block_ref: u32,
+        block_nums: &[u32],
+    ) -> Result<Vec<(BlockHeader, MerklePath)>, DataStoreError> {
+        let state = self.read();
+
+        let (_, block_chain) =
+            state.blocks.get(&block_ref).ok_or(DataStoreError::BlockNotFound(block_ref))?;
+        block_nums
+            .iter()
+            .map(|&block_num| {
+                let block_header = block_chain
+                    .get_block(block_num)
+                    .ok_or(DataStoreError::BlockNotFound(block_num))?;
+                let path = block_chain
+                    .open(block_num)
+                    .expect("blocks of the chain MMR are tracked")
+                    .merkle_path;
+                Ok((*block_header, path))
+            })
+            .collect()
+    }
+
+    fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
+        self.read()
+            .accounts
+            .get(&account_id)
+            .map(|(account, _)| account.code().module().clone())
+            .ok_or(DataStoreError::AccountNotFound(account_id))
+    }
+}
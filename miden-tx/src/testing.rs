@@ -0,0 +1,50 @@
+use std::time::{Duration, Instant};
+
+use miden_objects::transaction::{ExecutedTransaction, TransactionWitness};
+
+use crate::{
+    executor::reexecute_witness_with_measurements, TransactionExecutorError,
+    TransactionMeasurements,
+};
+
+// CYCLE MEASUREMENT
+// ================================================================================================
+
+/// The cost of executing a transaction, as measured by [measure_cycles()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleMeasurement {
+    /// Number of cycles spent in each section of the transaction program.
+    pub measurements: TransactionMeasurements,
+    /// Wall time spent executing the transaction program, including the overhead of tracing.
+    pub execution_time: Duration,
+}
+
+impl CycleMeasurement {
+    /// Returns the total number of cycles spent in the measured sections of the transaction
+    /// program.
+    pub fn total_cycles(&self) -> usize {
+        self.measurements.total_cycles()
+    }
+}
+
+/// Re-executes the provided transaction with tracing enabled and returns the number of cycles
+/// spent in each section of the transaction program together with the wall time of the
+/// execution.
+///
+/// The transaction is re-executed from its witness, and thus no data store is required. This
+/// makes it possible to measure the same transaction repeatedly, e.g., to compare the cycle
+/// counts before and after a change to the transaction kernel.
+///
+/// # Errors
+/// Returns an error if the transaction cannot be re-executed from its witness.
+pub fn measure_cycles(
+    transaction: &ExecutedTransaction,
+) -> Result<CycleMeasurement, TransactionExecutorError> {
+    let witness = TransactionWitness::from(transaction.clone());
+
+    let start = Instant::now();
+    let (_, measurements) = reexecute_witness_with_measurements(&witness)?;
+    let execution_time = start.elapsed();
+
+    Ok(CycleMeasurement { measurements, execution_time })
+}
@@ -1,23 +1,37 @@
-use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
+use miden_lib::{
+    errors::ERR_NOTE_NOT_BEING_PROCESSED,
+    transaction::{
+        memory::ACCT_ID_AND_NONCE_PTR, NoteScriptKind, ToTransactionKernelInputs,
+        ToTransactionSummary, TransactionKernel,
+    },
+};
 use miden_objects::{
-    accounts::{Account, AccountCode},
+    accounts::{Account, AccountCode, AccountDelta},
     assembly::{Assembler, ModuleAst, ProgramAst},
     assets::{Asset, FungibleAsset},
-    block::BlockHeader,
-    notes::NoteId,
+    block::{
+        apply_batches, AccountWitness, BlockBody, BlockHeader, ChainState, NullifierWitness,
+        ProposedBlock,
+    },
+    crypto::merkle::{MerklePath, MerkleTree, PartialMmr, SimpleSmt, Smt},
+    notes::{Note, NoteEnvelope, NoteId, NoteScript, NoteTag},
     transaction::{
-        ChainMmr, InputNote, InputNotes, ProvenTransaction, TransactionArgs, TransactionWitness,
+        AccountDetails, ChainMmr, ExecutedTransaction, InputNote, InputNoteCommitment, InputNotes,
+        KernelRegistry, OutputNotes, ProvenTransaction, TransactionArgs, TransactionBatch,
+        TransactionOutputs, TransactionWitness,
     },
-    Felt, Word,
+    BlockError, Digest, Felt, Hasher, JsonConversionError, TransactionBatchError,
+    TransactionInputError, Word, ACCOUNT_TREE_DEPTH, MAX_TRANSACTIONS_PER_BATCH, ONE,
+    TX_KERNEL_VERSION, ZERO,
 };
 use miden_prover::ProvingOptions;
 use mock::{
     constants::{
         non_fungible_asset, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
-        ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX, ACCOUNT_PROCEDURE_SET_CODE_PROC_IDX,
-        ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX, FUNGIBLE_ASSET_AMOUNT, MIN_PROOF_SECURITY_LEVEL,
-        STORAGE_INDEX_0,
+        ACCOUNT_ID_SENDER, ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX,
+        ACCOUNT_PROCEDURE_SET_CODE_PROC_IDX, ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX,
+        FUNGIBLE_ASSET_AMOUNT, MIN_PROOF_SECURITY_LEVEL, STORAGE_INDEX_0,
     },
     mock::{account::MockAccountType, notes::AssetPreservationStatus, transaction::mock_inputs},
     utils::prepare_word,
@@ -28,8 +42,14 @@ use vm_processor::{
 };
 
 use super::{
-    AccountId, DataStore, DataStoreError, TransactionExecutor, TransactionHost, TransactionInputs,
-    TransactionProver, TransactionVerifier,
+    planner::{CycleEstimates, NotePlanner, SelectionStrategy, TransactionBudget},
+    reexecute_transaction, reexecute_witness,
+    stores::MemoryDataStore,
+    AccountId, DataStore, DataStoreError, LocalTransactionProver, ProofSecurityPolicy,
+    ProverBackend, ProvingOptionsBuilder, StateSimulator, StateSimulatorError, TransactionCompiler,
+    TransactionCompilerError, TransactionExecutor, TransactionExecutorError, TransactionHost,
+    TransactionInputs, TransactionPolicy, TransactionPolicyError, TransactionProverError,
+    TransactionVerifier, TransactionVerifierError,
 };
 
 // TESTS
@@ -165,21 +185,24 @@ fn executed_transaction_account_delta() {
             ## ------------------------------------------------------------------------------------
             # partially deplete fungible asset balance
             push.0.1.2.3
+            push.0.1
             push.999
             push.{REMOVED_ASSET_1}
-            call.wallet::send_asset drop dropw dropw
+            call.wallet::send_asset drop dropw dropw drop drop
 
             # totally deplete fungible asset balance
             push.0.1.2.3
+            push.0.1
             push.999
             push.{REMOVED_ASSET_2}
-            call.wallet::send_asset drop dropw dropw
+            call.wallet::send_asset drop dropw dropw drop drop
 
             # send non-fungible asset
             push.0.1.2.3
+            push.0.1
             push.999
             push.{REMOVED_ASSET_3}
-            call.wallet::send_asset drop dropw dropw
+            call.wallet::send_asset drop dropw dropw drop drop
 
             ## Update account code
             ## ------------------------------------------------------------------------------------
@@ -261,6 +284,62 @@ fn executed_transaction_account_delta() {
         removed_assets.len(),
         executed_transaction.account_delta().vault().removed_assets.len()
     );
+    // transaction diff
+    // --------------------------------------------------------------------------------------------
+    let diff = executed_transaction.diff();
+    assert_eq!(diff.account_id(), account_id);
+    assert_eq!(diff.nonce_change(), Some((ONE, Felt::new(2))));
+
+    assert_eq!(diff.storage_changes().len(), 1);
+    let storage_change = diff.storage_changes()[0];
+    assert_eq!(storage_change.index, STORAGE_INDEX_0);
+    assert_eq!(
+        storage_change.before,
+        Word::from(data_store.account.storage().get_item(STORAGE_INDEX_0))
+    );
+    assert_eq!(storage_change.after, updated_slot_value);
+
+    assert_eq!(
+        diff.fungible_assets_out()
+            .get(&AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap()),
+        Some(&FUNGIBLE_ASSET_AMOUNT)
+    );
+    assert_eq!(diff.non_fungible_assets_out().len(), 1);
+    assert_eq!(diff.created_notes().len(), executed_transaction.output_notes().num_notes());
+    assert_eq!(diff.nullifiers().len(), note_ids.len());
+
+    // transaction summary
+    // --------------------------------------------------------------------------------------------
+    let summary = executed_transaction.summary();
+    assert_eq!(summary.account_id(), account_id);
+    assert_eq!(summary.nonce_delta(), 1);
+    assert_eq!(summary.storage_slots(), &[STORAGE_INDEX_0]);
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let flow = summary.assets().iter().find(|flow| flow.faucet_id == faucet_id_2).unwrap();
+    assert_eq!(flow.amount_out, FUNGIBLE_ASSET_AMOUNT);
+    assert!(summary.assets().windows(2).all(|flows| flows[0].faucet_id < flows[1].faucet_id));
+
+    // the scripts of the mock notes are not well-known scripts
+    assert_eq!(
+        summary.consumed_notes().iter().map(|note| note.id()).collect::<Vec<_>>(),
+        note_ids
+    );
+    assert!(summary
+        .consumed_notes()
+        .iter()
+        .all(|note| note.script() == NoteScriptKind::Custom && note.script_root().is_some()));
+    assert_eq!(summary.created_notes().len(), executed_transaction.output_notes().num_notes());
+
+    let text = summary.to_string();
+    assert!(text.starts_with(&format!("transaction {}", executed_transaction.id())));
+    assert!(text.contains("consumed notes:"));
+    assert!(text.ends_with("nonce incremented by 1"));
+
+    let json = summary.to_json();
+    assert!(json.contains(r#""type":"transaction_summary""#));
+    assert!(json.contains(r#""nonce_delta":1"#));
+    assert!(json.contains(&executed_transaction.id().to_hex()));
 }
 
 #[test]
@@ -280,23 +359,213 @@ fn prove_witness_and_verify() {
 
     // Prove the transaction with the witness
     let proof_options = ProvingOptions::default();
-    let prover = TransactionProver::new(proof_options);
+    let prover = LocalTransactionProver::new(proof_options);
     let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
 
     // Serialize & deserialize the ProvenTransaction
     let serialised_transaction = proven_transaction.to_bytes();
     let proven_transaction = ProvenTransaction::read_from_bytes(&serialised_transaction).unwrap();
 
+    // the JSON encoding of the ProvenTransaction round-trips, and tampered encodings are rejected
+    let json = proven_transaction.to_json();
+    let decoded = ProvenTransaction::from_json(&json).unwrap();
+    assert_eq!(decoded.to_bytes(), serialised_transaction);
+    let tampered =
+        json.replace(&proven_transaction.kernel_root().to_hex(), &Digest::default().to_hex());
+    assert_eq!(
+        ProvenTransaction::from_json(&tampered).unwrap_err(),
+        JsonConversionError::InconsistentFields
+    );
+
+    // the final state of the on-chain account can be reconstructed from the account details
+    assert!(matches!(proven_transaction.account_details(), Some(AccountDetails::Delta(_))));
+    let final_account = proven_transaction.build_final_account(Some(&data_store.account)).unwrap();
+    assert_eq!(final_account.hash(), proven_transaction.final_account_hash());
+
+    // the transaction declares the kernel it was proven against
+    let kernel_root = TransactionKernel::kernel_registry().program_hash(TX_KERNEL_VERSION).unwrap();
+    assert_eq!(proven_transaction.kernel_root(), kernel_root);
+
     // Verify that the generated proof is valid
-    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
+    assert!(verifier.kernel_roots().any(|root| root == kernel_root));
+    assert!(verifier.verify(proven_transaction.clone()).is_ok());
+
+    // the transaction can be verified against the state of the chain
+    let account_tree = SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves([(
+        u64::from(account_id),
+        Word::from(proven_transaction.initial_account_hash()),
+    )])
+    .unwrap();
+    let account_witness = AccountWitness::new(
+        account_id,
+        proven_transaction.initial_account_hash(),
+        account_tree.open(&account_id.into()).path,
+    );
+
+    let nullifiers = proven_transaction
+        .input_notes()
+        .iter()
+        .map(|note| note.nullifier())
+        .collect::<Vec<_>>();
+    let block_header = |nullifier_root| {
+        BlockHeader::new(
+            data_store.block_header.prev_hash(),
+            data_store.block_header.block_num(),
+            data_store.block_header.chain_root(),
+            account_tree.root(),
+            nullifier_root,
+            data_store.block_header.note_root(),
+            data_store.block_header.batch_root(),
+            data_store.block_header.proof_hash(),
+            data_store.block_header.version(),
+            data_store.block_header.timestamp(),
+        )
+    };
+
+    let nullifier_tree = Smt::new();
+    let nullifier_witnesses = nullifiers
+        .iter()
+        .map(|nullifier| NullifierWitness::new(*nullifier, nullifier_tree.open(&nullifier.inner())))
+        .collect::<Vec<_>>();
+    assert!(verifier
+        .verify_against_chain(
+            proven_transaction.clone(),
+            &block_header(nullifier_tree.root()),
+            &account_witness,
+            &nullifier_witnesses,
+        )
+        .is_ok());
+
+    // transactions consuming spent notes are rejected
+    let spent_value = [Felt::from(block_ref), ZERO, ZERO, ZERO];
+    let nullifier_tree = Smt::with_entries([(nullifiers[0].inner(), spent_value)]).unwrap();
+    let nullifier_witnesses = nullifiers
+        .iter()
+        .map(|nullifier| NullifierWitness::new(*nullifier, nullifier_tree.open(&nullifier.inner())))
+        .collect::<Vec<_>>();
+    assert!(matches!(
+        verifier.verify_against_chain(
+            proven_transaction.clone(),
+            &block_header(nullifier_tree.root()),
+            &account_witness,
+            &nullifier_witnesses,
+        ),
+        Err(TransactionVerifierError::NullifierAlreadySpent(nullifier)) if nullifier == nullifiers[0]
+    ));
+
+    // transactions proven against unknown kernels are rejected
+    let verifier = verifier.with_kernel_registry(KernelRegistry::new());
+    assert!(matches!(
+        verifier.verify(proven_transaction),
+        Err(TransactionVerifierError::UnknownKernel(root)) if root == kernel_root
+    ));
+}
+
+#[test]
+fn prove_serialized_witness() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    // the executed transaction can be read back by recompiling its program
+    let mut compiler = TransactionCompiler::new();
+    let tx_bytes = executed_transaction.to_bytes();
+    let deserialized = compiler.read_executed_transaction(&tx_bytes).unwrap();
+    assert_eq!(deserialized.id(), executed_transaction.id());
+    assert_eq!(deserialized.program().hash(), executed_transaction.program().hash());
+    assert_eq!(deserialized.to_bytes(), tx_bytes);
+
+    // the serialized executed transaction starts with the serialized witness
+    let witness_bytes = TransactionWitness::from(executed_transaction.clone()).to_bytes();
+    assert!(tx_bytes.starts_with(&witness_bytes));
+
+    // a proving service can reconstruct the witness from its serialized form and prove it
+    let mut compiler = TransactionCompiler::new();
+    let witness = compiler.read_transaction_witness(&witness_bytes).unwrap();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(witness).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction.id());
+
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
     assert!(verifier.verify(proven_transaction).is_ok());
+
+    // witnesses whose program does not match their inputs are rejected
+    let mut tampered_bytes = witness_bytes.clone();
+    tampered_bytes[..32].copy_from_slice(&Digest::default().to_bytes());
+    assert!(matches!(
+        compiler.read_transaction_witness(&tampered_bytes),
+        Err(TransactionCompilerError::TransactionProgramMismatch { actual, .. })
+            if actual == executed_transaction.program().hash()
+    ));
 }
 
-// TEST TRANSACTION SCRIPT
-// ================================================================================================
+#[cfg(all(feature = "async", feature = "std"))]
+#[test]
+fn prove_transaction_on_dedicated_thread() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    // the transaction proven on a dedicated thread can be verified
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let handle = prover.prove_transaction_async(executed_transaction.clone());
+    let proven_transaction = handle.wait().unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction.id());
+
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
+    assert!(verifier.verify(proven_transaction).is_ok());
+
+    // proving can be cancelled without waiting for the result
+    let handle = prover.prove_transaction_async(executed_transaction);
+    handle.cancel();
+}
 
+#[cfg(feature = "remote")]
 #[test]
-fn test_tx_script() {
+fn prove_transaction_via_proving_service() {
+    use core::cell::RefCell;
+
+    use super::{ProvingServiceClient, RemoteTransactionProver};
+
+    /// A proving service which proves the received witnesses locally, or returns a fixed response.
+    struct MockProvingService {
+        compiler: RefCell<TransactionCompiler>,
+        response: Option<Vec<u8>>,
+    }
+
+    impl ProvingServiceClient for MockProvingService {
+        fn prove(&self, tx_witness: Vec<u8>) -> Result<Vec<u8>, String> {
+            if let Some(response) = &self.response {
+                return Ok(response.clone());
+            }
+            let witness = self
+                .compiler
+                .borrow_mut()
+                .read_transaction_witness(&tx_witness)
+                .map_err(|err| err.to_string())?;
+            let prover = LocalTransactionProver::new(ProvingOptions::default());
+            prover
+                .prove_transaction(witness)
+                .map(|tx| tx.to_bytes())
+                .map_err(|err| err.to_string())
+        }
+    }
+
     let data_store = MockDataStore::default();
     let mut executor = TransactionExecutor::new(data_store.clone());
 
@@ -305,102 +574,1334 @@ fn test_tx_script() {
 
     let block_ref = data_store.block_header.block_num();
     let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
 
-    let tx_script_input_key = [Felt::new(9999), Felt::new(8888), Felt::new(9999), Felt::new(8888)];
-    let tx_script_input_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    // the transaction proven by the service can be verified
+    let prover = RemoteTransactionProver::new(MockProvingService {
+        compiler: RefCell::new(TransactionCompiler::new()),
+        response: None,
+    });
+    let proven_transaction = prover.prove_transaction(executed_transaction.clone()).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction.id());
+
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
+    assert!(verifier.verify(proven_transaction).is_ok());
+
+    // responses which are not proven transactions are rejected
+    let prover = RemoteTransactionProver::new(MockProvingService {
+        compiler: RefCell::new(TransactionCompiler::new()),
+        response: Some(vec![1, 2, 3]),
+    });
+    assert!(matches!(
+        prover.prove_transaction(executed_transaction.clone()),
+        Err(TransactionProverError::InvalidRemoteResponse(_))
+    ));
+
+    // proofs of other transactions, e.g., consuming no notes, are rejected
+    let other_transaction = executor.execute_transaction(account_id, block_ref, &[], None).unwrap();
+    let other_proof = LocalTransactionProver::new(ProvingOptions::default())
+        .prove_transaction(other_transaction)
+        .unwrap();
+    let prover = RemoteTransactionProver::new(MockProvingService {
+        compiler: RefCell::new(TransactionCompiler::new()),
+        response: Some(other_proof.to_bytes()),
+    });
+    assert!(matches!(
+        prover.prove_transaction(executed_transaction),
+        Err(TransactionProverError::RemoteProofMismatch(tx_id)) if tx_id == other_proof.id()
+    ));
+}
+
+#[test]
+fn prover_backend_must_match_build() {
+    let options = ProvingOptionsBuilder::regular_96bit().build().unwrap();
+    let prover = LocalTransactionProver::new(options);
+
+    // proofs with BLAKE3 are always generated on the CPU
+    assert_eq!(prover.backend(), ProverBackend::Cpu);
+    let prover = prover.with_backend(ProverBackend::Cpu).unwrap();
+    for backend in ProverBackend::ALL.iter().filter(|backend| **backend != ProverBackend::Cpu) {
+        assert!(matches!(
+            prover.clone().with_backend(*backend),
+            Err(TransactionProverError::UnsupportedBackend { .. })
+        ));
+    }
+}
+
+/// Compares the proving time of the backends available in the current build.
+///
+/// Run with `cargo test --release prover_backend_benchmark -- --ignored --nocapture`.
+#[test]
+#[ignore]
+fn prover_backend_benchmark() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    let options = ProvingOptionsBuilder::recursive_128bit().build().unwrap();
+    for backend in ProverBackend::ALL {
+        let Ok(prover) = LocalTransactionProver::new(options.clone()).with_backend(*backend) else {
+            println!("{backend}: not available");
+            continue;
+        };
+
+        let start = std::time::Instant::now();
+        let proven_transaction = prover.prove_transaction(executed_transaction.clone()).unwrap();
+        println!("{backend}: proved transaction in {} ms", start.elapsed().as_millis());
+        assert_eq!(proven_transaction.id(), executed_transaction.id());
+    }
+}
+
+#[test]
+fn reexecute_witness_and_check_claims() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    // re-executing the witness reproduces the effects of the transaction
+    let witness = TransactionWitness::from(executed_transaction.clone());
+    let reexecuted = reexecute_witness(&witness).unwrap();
+    assert_eq!(reexecuted.id(), executed_transaction.id());
+    assert_eq!(reexecuted.account_delta(), executed_transaction.account_delta());
+    assert_eq!(reexecuted.output_notes(), executed_transaction.output_notes());
+    assert!(reexecute_transaction(&executed_transaction).is_ok());
+
+    // transactions claiming a different account delta are rejected
+    assert!(executed_transaction.account_delta().nonce().is_some());
+    let (_, tx_outputs, witness) = executed_transaction.into_parts();
+    let claimed_transaction = ExecutedTransaction::new(
+        witness.program().clone(),
+        witness.tx_inputs().clone(),
+        tx_outputs,
+        AccountDelta::default(),
+        witness.tx_args().clone(),
+        witness.advice_witness().clone(),
+    );
+    assert!(matches!(
+        reexecute_transaction(&claimed_transaction),
+        Err(TransactionExecutorError::ReexecutedAccountDeltaMismatch)
+    ));
+}
+
+#[test]
+fn prove_transaction_with_expiration() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the transaction script lowers the expiration block number of the transaction
+    let expiration_block_num = block_ref + 5;
     let tx_script_source = format!(
         "
-    begin
-        # push the tx script input key onto the stack
-        push.{key}
+    use.miden::tx
 
-        # load the tx script input value from the map and read it onto the stack
-        adv.push_mapval adv_loadw
-
-        # assert that the value is correct
-        push.{value} assert_eqw
+    begin
+        push.{expiration_block_num}
+        exec.tx::update_expiration_block_num
     end
-",
-        key = prepare_word(&tx_script_input_key),
-        value = prepare_word(&tx_script_input_value)
+    "
     );
     let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
-    let tx_script = executor
-        .compile_tx_script(
-            tx_script_code,
-            vec![(tx_script_input_key, tx_script_input_value.into())],
-            vec![],
-        )
-        .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
     let tx_args = TransactionArgs::with_tx_script(tx_script);
 
-    // execute the transaction
-    let executed_transaction =
-        executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args));
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert_eq!(executed_transaction.expiration_block_num(), expiration_block_num);
 
-    // assert the transaction executed successfully
-    assert!(executed_transaction.is_ok());
+    // the expiration block number is carried over to the proven transaction
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    assert_eq!(proven_transaction.expiration_block_num(), expiration_block_num);
+    let deserialized = ProvenTransaction::read_from_bytes(&proven_transaction.to_bytes()).unwrap();
+    assert_eq!(deserialized.expiration_block_num(), expiration_block_num);
+
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
+    assert!(verifier.verify(proven_transaction.clone()).is_ok());
+
+    // block producers can discard the transaction once it has expired
+    assert!(verifier.check_expiration(&proven_transaction, expiration_block_num).is_ok());
+    assert!(matches!(
+        verifier.check_expiration(&proven_transaction, expiration_block_num + 1),
+        Err(TransactionVerifierError::TransactionExpired { expiration_block_num: expiration, .. })
+            if expiration == expiration_block_num
+    ));
+
+    // the expiration block number is bound to the proof of the transaction
+    let tampered_transaction = ProvenTransaction::new(
+        proven_transaction.account_id(),
+        proven_transaction.initial_account_hash(),
+        proven_transaction.final_account_hash(),
+        proven_transaction.input_notes().clone(),
+        proven_transaction.output_notes().clone(),
+        proven_transaction.account_details().cloned(),
+        proven_transaction.tx_script_root(),
+        proven_transaction.kernel_root(),
+        proven_transaction.block_ref(),
+        u32::MAX,
+        proven_transaction.fee(),
+        proven_transaction.proof().clone(),
+    )
+    .unwrap();
+    assert!(matches!(
+        verifier.verify(tampered_transaction),
+        Err(TransactionVerifierError::TransactionVerificationFailed(_))
+    ));
 }
 
-// MOCK DATA STORE
-// ================================================================================================
+#[test]
+fn prove_transaction_with_fee() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
 
-#[derive(Clone)]
-struct MockDataStore {
-    pub account: Account,
-    pub block_header: BlockHeader,
-    pub block_chain: ChainMmr,
-    pub notes: Vec<InputNote>,
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the fee is paid from the assets of the account vault
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fee = FungibleAsset::new(faucet_id, 10).unwrap();
+    let tx_args = TransactionArgs::builder().fee(fee).build();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert_eq!(executed_transaction.fee(), Some(fee));
+
+    // the fee is carried over to the proven transaction
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    assert_eq!(proven_transaction.fee(), Some(fee));
+    let deserialized = ProvenTransaction::read_from_bytes(&proven_transaction.to_bytes()).unwrap();
+    assert_eq!(deserialized.fee(), Some(fee));
+
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
+    assert!(verifier.verify(proven_transaction.clone()).is_ok());
+
+    // block producers can require a minimum fee
+    assert!(verifier.check_fee(&proven_transaction, fee).is_ok());
+    let min_fee = FungibleAsset::new(faucet_id, 11).unwrap();
+    assert!(matches!(
+        verifier.check_fee(&proven_transaction, min_fee),
+        Err(TransactionVerifierError::InsufficientFee { actual: Some(actual), .. })
+            if actual == fee
+    ));
+
+    // the fee is bound to the proof of the transaction
+    let tampered_transaction = ProvenTransaction::new(
+        proven_transaction.account_id(),
+        proven_transaction.initial_account_hash(),
+        proven_transaction.final_account_hash(),
+        proven_transaction.input_notes().clone(),
+        proven_transaction.output_notes().clone(),
+        proven_transaction.account_details().cloned(),
+        proven_transaction.tx_script_root(),
+        proven_transaction.kernel_root(),
+        proven_transaction.block_ref(),
+        proven_transaction.expiration_block_num(),
+        None,
+        proven_transaction.proof().clone(),
+    )
+    .unwrap();
+    assert!(matches!(
+        verifier.verify(tampered_transaction),
+        Err(TransactionVerifierError::TransactionVerificationFailed(_))
+    ));
 }
 
-impl MockDataStore {
-    pub fn new(asset_preservation: AssetPreservationStatus) -> Self {
-        let (account, _, block_header, block_chain, notes) =
-            mock_inputs(MockAccountType::StandardExisting, asset_preservation).into_parts();
+#[test]
+fn executing_transaction_with_fee_exceeding_balance_fails() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
 
-        Self {
-            account,
-            block_header,
-            block_chain,
-            notes: notes.into_vec(),
-        }
-    }
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the account vault holds only FUNGIBLE_ASSET_AMOUNT units of the fee asset
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fee = FungibleAsset::new(faucet_id, FUNGIBLE_ASSET_AMOUNT * 1000).unwrap();
+    let tx_args = TransactionArgs::builder().fee(fee).build();
+
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args));
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(_))
+    ));
 }
 
-impl Default for MockDataStore {
-    fn default() -> Self {
-        Self::new(AssetPreservationStatus::Preserved)
+#[test]
+fn proving_options_builder_presets() {
+    // all presets define valid proving options
+    for builder in [
+        ProvingOptionsBuilder::fast(),
+        ProvingOptionsBuilder::regular_96bit(),
+        ProvingOptionsBuilder::recursive_128bit(),
+    ] {
+        assert!(builder.build().is_ok());
     }
+
+    // the number of queries is derived from the target security level
+    let builder = ProvingOptionsBuilder::regular_96bit();
+    assert_eq!(builder.num_queries(), 27);
+    assert_eq!(builder.with_grinding_bits(0).num_queries(), 32);
+
+    // invalid parameters are rejected
+    let result = ProvingOptionsBuilder::fast().with_blowup_factor(6).build();
+    assert!(matches!(result, Err(TransactionProverError::InvalidBlowupFactor(6))));
+    let result = ProvingOptionsBuilder::fast().with_grinding_bits(33).build();
+    assert!(matches!(result, Err(TransactionProverError::InvalidGrindingBits(33))));
+    let result = ProvingOptionsBuilder::fast().with_security_level(128).build();
+    assert!(matches!(result, Err(TransactionProverError::UnsupportedSecurityLevel { .. })));
+
+    // the security policy of the verifier defines the minimum accepted security level
+    let policy = ProofSecurityPolicy::min_128bit();
+    assert!(!policy.accepts(96));
+    assert!(policy.accepts(128));
+    assert_eq!(ProofSecurityPolicy::default().min_security_level(), MIN_PROOF_SECURITY_LEVEL);
 }
 
-impl DataStore for MockDataStore {
-    fn get_transaction_inputs(
-        &self,
-        account_id: AccountId,
-        block_num: u32,
-        notes: &[NoteId],
-    ) -> Result<TransactionInputs, DataStoreError> {
-        assert_eq!(account_id, self.account.id());
-        assert_eq!(block_num, self.block_header.block_num());
-        assert_eq!(notes.len(), self.notes.len());
+// TRANSACTION POLICY
+// ================================================================================================
 
-        let notes = self
-            .notes
-            .iter()
-            .filter(|note| notes.contains(&note.id()))
-            .cloned()
-            .collect::<Vec<_>>();
+#[test]
+fn transaction_policy_restricts_input_note_scripts() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let note_scripts = data_store.notes.iter().map(|note| note.note().script().hash());
 
-        Ok(TransactionInputs::new(
-            self.account.clone(),
-            None,
-            self.block_header,
-            self.block_chain.clone(),
-            InputNotes::new(notes).unwrap(),
-        )
+    // the transaction succeeds if the scripts of all input notes are allowed
+    let policy = TransactionPolicy::new().with_allowed_input_note_scripts(note_scripts);
+    let mut executor = TransactionExecutor::new(data_store.clone()).with_policy(policy);
+    executor.load_account(account_id).unwrap();
+    assert!(executor.execute_transaction(account_id, block_ref, &note_ids, None).is_ok());
+
+    // the transaction fails if the script of any input note is not allowed
+    let policy = TransactionPolicy::new().with_allowed_input_note_scripts([]);
+    let mut executor = TransactionExecutor::new(data_store.clone()).with_policy(policy);
+    executor.load_account(account_id).unwrap();
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, None);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::PolicyViolation(
+            TransactionPolicyError::InputNoteScriptNotAllowed { .. }
+        ))
+    ));
+}
+
+#[test]
+fn transaction_executor_validates_inputs() {
+    let mut data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the inclusion proof of the first note is replaced with the proof of the second note
+    let note = data_store.notes[0].note().clone();
+    let proof = data_store.notes[1].proof().unwrap().clone();
+    let note_block_num = proof.origin().block_num;
+    data_store.notes[0] = InputNote::new(note, proof);
+
+    let mut executor = TransactionExecutor::new(data_store);
+    executor.load_account(account_id).unwrap();
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, None);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::InvalidTransactionInputs(
+            TransactionInputError::InputNoteNotInBlock(note_id, block_num)
+        )) if note_id == note_ids[0] && block_num == note_block_num
+    ));
+}
+
+#[test]
+fn prove_transaction_with_unauthenticated_notes() {
+    let mut data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+
+    // the last note is consumed without an inclusion proof
+    let unauthenticated_note = data_store.notes.pop().unwrap().note().clone();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let executed_transaction = executor
+        .execute_transaction_with_unauthenticated_notes(
+            account_id,
+            block_ref,
+            &note_ids,
+            &[unauthenticated_note.clone()],
+            None,
+        )
+        .unwrap();
+
+    let input_notes = executed_transaction.input_notes();
+    assert_eq!(input_notes.num_notes(), note_ids.len() + 1);
+    assert!(input_notes.iter().take(note_ids.len()).all(|note| note.is_authenticated()));
+    assert!(!input_notes.get_note(note_ids.len()).is_authenticated());
+
+    // the proven transaction lists the unauthenticated note so that the operator can
+    // authenticate it
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    let proven_transaction =
+        ProvenTransaction::read_from_bytes(&proven_transaction.to_bytes()).unwrap();
+    assert_eq!(
+        proven_transaction
+            .unauthenticated_notes()
+            .map(|note| note.note_id())
+            .collect::<Vec<_>>(),
+        vec![unauthenticated_note.id()]
+    );
+    assert_eq!(proven_transaction.input_notes().num_notes(), note_ids.len() + 1);
+
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
+    assert!(verifier.verify(proven_transaction).is_ok());
+
+    // a note can not be consumed both as an authenticated and an unauthenticated note
+    let result = executor.execute_transaction_with_unauthenticated_notes(
+        account_id,
+        block_ref,
+        &note_ids,
+        &[data_store.notes[0].note().clone()],
+        None,
+    );
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::InvalidTransactionInputs(
+            TransactionInputError::DuplicateInputNote(_)
+        ))
+    ));
+}
+
+#[test]
+fn build_transaction_batch() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    let nullifiers = proven_transaction
+        .input_notes()
+        .iter()
+        .map(|note| note.nullifier())
+        .collect::<Vec<_>>();
+    let output_notes = proven_transaction.output_notes().iter().copied().collect::<Vec<_>>();
+
+    // the batch of a single transaction has the effects of the transaction
+    let batch = TransactionBatch::new(vec![proven_transaction.clone()]).unwrap();
+    assert_eq!(batch.nullifiers(), nullifiers);
+    assert_eq!(batch.output_notes(), output_notes);
+    assert!(batch.unauthenticated_notes().is_empty());
+    assert_eq!(batch.expiration_block_num(), proven_transaction.expiration_block_num());
+    let update = batch.get_account_update(account_id).unwrap();
+    assert_eq!(update.initial_state_hash(), proven_transaction.initial_account_hash());
+    assert_eq!(update.final_state_hash(), proven_transaction.final_account_hash());
+
+    let deserialized = TransactionBatch::read_from_bytes(&batch.to_bytes()).unwrap();
+    assert_eq!(deserialized.id(), batch.id());
+    assert_eq!(deserialized.nullifiers(), batch.nullifiers());
+
+    // builds a transaction against the same account with the provided state transition and notes;
+    // the proof is not checked when building a batch
+    let build_transaction = |initial_account_hash: Digest,
+                             final_account_hash: Digest,
+                             input_notes: Vec<InputNoteCommitment>,
+                             output_notes: Vec<NoteEnvelope>| {
+        ProvenTransaction::new(
+            account_id,
+            initial_account_hash,
+            final_account_hash,
+            InputNotes::new(input_notes).unwrap(),
+            OutputNotes::new(output_notes).unwrap(),
+            proven_transaction.account_details().cloned(),
+            proven_transaction.tx_script_root(),
+            proven_transaction.kernel_root(),
+            proven_transaction.block_ref(),
+            proven_transaction.expiration_block_num(),
+            proven_transaction.fee(),
+            proven_transaction.proof().clone(),
+        )
+        .unwrap()
+    };
+
+    // a note created and consumed as an unauthenticated note within the batch is erased
+    let note = data_store.notes[0].note();
+    let note = &Note::from_parts(
+        note.script().clone(),
+        note.inputs().clone(),
+        note.assets().clone(),
+        [ONE, ONE, ZERO, ZERO],
+        *note.metadata(),
+    );
+    let intermediate_hash: Digest = [ONE, ZERO, ONE, ZERO].into();
+    let final_hash: Digest = [ZERO, ONE, ZERO, ONE].into();
+    let producer = build_transaction(
+        proven_transaction.final_account_hash(),
+        intermediate_hash,
+        vec![],
+        vec![note.into()],
+    );
+    let consumer = build_transaction(
+        intermediate_hash,
+        final_hash,
+        vec![InputNoteCommitment::unauthenticated(note.nullifier(), note.into())],
+        vec![],
+    );
+    let batch =
+        TransactionBatch::new(vec![proven_transaction.clone(), producer.clone(), consumer.clone()])
+            .unwrap();
+    assert_eq!(batch.nullifiers(), nullifiers);
+    assert_eq!(batch.output_notes(), output_notes);
+    assert!(batch.unauthenticated_notes().is_empty());
+    let update = batch.get_account_update(account_id).unwrap();
+    assert_eq!(update.initial_state_hash(), proven_transaction.initial_account_hash());
+    assert_eq!(update.final_state_hash(), final_hash);
+    assert_eq!(update.transactions(), [proven_transaction.id(), producer.id(), consumer.id()]);
+
+    // the unauthenticated note must be authenticated by the operator if it is not created within
+    // the batch
+    let unauthenticated_consumer = build_transaction(
+        proven_transaction.final_account_hash(),
+        final_hash,
+        vec![InputNoteCommitment::unauthenticated(note.nullifier(), note.into())],
+        vec![],
+    );
+    let batch =
+        TransactionBatch::new(vec![proven_transaction.clone(), unauthenticated_consumer]).unwrap();
+    assert_eq!(batch.unauthenticated_notes(), [NoteEnvelope::from(note)]);
+    assert_eq!(batch.nullifiers().len(), nullifiers.len() + 1);
+
+    // invalid batches are rejected
+    assert!(matches!(TransactionBatch::new(vec![]), Err(TransactionBatchError::EmptyBatch)));
+    assert!(matches!(
+        TransactionBatch::new(vec![proven_transaction.clone(), proven_transaction.clone()]),
+        Err(TransactionBatchError::DuplicateTransaction(_))
+    ));
+    assert!(matches!(
+        TransactionBatch::new(vec![proven_transaction.clone(), consumer]),
+        Err(TransactionBatchError::InconsistentAccountStateTransition { .. })
+    ));
+    let mut account_hash = proven_transaction.final_account_hash();
+    let mut transactions = vec![proven_transaction.clone()];
+    for i in 0..MAX_TRANSACTIONS_PER_BATCH as u64 {
+        let next_hash: Digest = [Felt::new(i + 2), ZERO, ZERO, ZERO].into();
+        transactions.push(build_transaction(account_hash, next_hash, vec![], vec![]));
+        account_hash = next_hash;
+    }
+    assert!(TransactionBatch::new(transactions[..MAX_TRANSACTIONS_PER_BATCH].to_vec()).is_ok());
+    assert!(matches!(
+        TransactionBatch::new(transactions),
+        Err(TransactionBatchError::TooManyTransactions { .. })
+    ));
+    let double_spend = build_transaction(
+        proven_transaction.final_account_hash(),
+        final_hash,
+        vec![InputNoteCommitment::new(nullifiers[0])],
+        vec![],
+    );
+    assert!(matches!(
+        TransactionBatch::new(vec![proven_transaction, double_spend]),
+        Err(TransactionBatchError::DuplicateInputNote { .. })
+    ));
+}
+
+#[test]
+fn build_block_from_batches() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    let batch = TransactionBatch::new(vec![proven_transaction.clone()]).unwrap();
+
+    let (mut state, genesis) = ChainState::genesis(
+        [(account_id, proven_transaction.initial_account_hash())],
+        Felt::new(10),
+    );
+    assert_eq!(state.chain_length(), 1);
+    assert_eq!(genesis.account_root(), state.account_root());
+
+    // the block applies the effects of the batch to the chain state
+    let (header, body) = apply_batches(&genesis, vec![batch.clone()], &mut state).unwrap();
+    assert_eq!(header.block_num(), 1);
+    assert_eq!(header.validate_against(&genesis), Ok(()));
+    assert_eq!(header.account_root(), state.account_root());
+    assert_eq!(header.nullifier_root(), state.nullifier_root());
+    assert_eq!(header.note_root(), body.note_root());
+    assert_eq!(state.chain_length(), 2);
+
+    assert_eq!(state.account_hash(account_id), proven_transaction.final_account_hash());
+    assert_eq!(body.updated_accounts(), [(account_id, proven_transaction.final_account_hash())]);
+    assert!(state.open_account(account_id).is_valid_for(&header));
+
+    assert_eq!(body.nullifiers(), batch.nullifiers());
+    for &nullifier in body.nullifiers() {
+        assert_eq!(state.nullifier_block_num(nullifier), Some(1));
+        let witness = state.open_nullifier(nullifier);
+        assert!(witness.is_valid_for(&header));
+        assert!(!witness.is_unspent());
+    }
+
+    // the notes created in the block can be proven against the new block header
+    assert_eq!(body.output_notes(), batch.output_notes());
+    for (index, note) in body.output_notes().iter().enumerate() {
+        let proof = body.open_note(&header, index).unwrap();
+        let leaf = Hasher::merge(&[note.note_id().inner(), Word::from(note.metadata()).into()]);
+        assert!(proof.note_path().verify(index as u64, leaf, &header.note_root()));
+    }
+    assert!(body.open_note(&header, body.output_notes().len()).is_none());
+
+    let deserialized = BlockBody::read_from_bytes(&body.to_bytes()).unwrap();
+    assert_eq!(deserialized.updated_accounts(), body.updated_accounts());
+    assert_eq!(deserialized.note_root(), body.note_root());
+
+    // the chain state must be the state as of the previous block
+    assert!(matches!(
+        apply_batches(&genesis, vec![], &mut state),
+        Err(BlockError::AccountRootMismatch { .. })
+    ));
+
+    // the same batch cannot be applied twice
+    assert!(matches!(
+        ProposedBlock::new(header, vec![batch.clone(), batch.clone()]),
+        Err(BlockError::InconsistentAccountStateTransition { .. })
+    ));
+    assert!(matches!(
+        apply_batches(&header, vec![batch], &mut state),
+        Err(BlockError::AccountStateMismatch { .. })
+    ));
+
+    // the timestamp of a block must not decrease
+    let result = ProposedBlock::new(header, vec![])
+        .unwrap()
+        .with_timestamp(Felt::new(5))
+        .apply(&mut state);
+    assert!(matches!(result, Err(BlockError::TimestampDecreased { prev: 10, current: 5 })));
+
+    // failed blocks do not modify the chain state, and empty blocks extend the chain
+    assert_eq!(state.chain_length(), 2);
+    let (next_header, body) = apply_batches(&header, vec![], &mut state).unwrap();
+    assert_eq!(next_header.validate_against(&header), Ok(()));
+    assert_eq!(next_header.account_root(), header.account_root());
+    assert!(body.updated_accounts().is_empty());
+    assert_eq!(state.chain_length(), 3);
+}
+
+#[test]
+fn transaction_executor_enforces_cycle_limit() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the execution is aborted once the cycle limit is reached
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_max_cycles(1 << 12).unwrap();
+    executor.load_account(account_id).unwrap();
+    assert_eq!(executor.max_cycles(), 1 << 12);
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, None);
+    assert!(matches!(result, Err(TransactionExecutorError::CycleLimitExceeded(_))));
+
+    // the transaction succeeds if the limit is high enough
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_max_cycles(1 << 20).unwrap();
+    executor.load_account(account_id).unwrap();
+    assert!(executor.execute_transaction(account_id, block_ref, &note_ids, None).is_ok());
+}
+
+#[test]
+fn transaction_executor_caches_account_code() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone()).with_code_cache(4);
+    assert_eq!(executor.code_cache_stats().unwrap().num_entries, 0);
+
+    // the first load compiles the account code, the second one is served from the cache
+    let account_code = executor.load_account(account_id).unwrap();
+    let cached_code = executor.load_account(account_id).unwrap();
+    assert_eq!(cached_code.root(), account_code.root());
+    assert_eq!(account_code.root(), data_store.account.code().root());
+
+    let stats = executor.code_cache_stats().unwrap();
+    assert_eq!((stats.hits, stats.misses, stats.num_entries), (1, 1, 1));
+
+    // transactions can be executed against accounts loaded from the cache
+    assert!(executor.execute_transaction(account_id, block_ref, &note_ids, None).is_ok());
+
+    // the cache is disabled by default
+    assert!(TransactionExecutor::new(data_store).code_cache_stats().is_none());
+}
+
+// STATE SIMULATOR
+// ================================================================================================
+
+#[test]
+fn transaction_executor_measures_transaction() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let (executed_transaction, measurements) = executor
+        .execute_transaction_with_measurements(account_id, block_ref, &note_ids, None)
+        .unwrap();
+
+    // measuring the transaction does not affect its outcome
+    let expected_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+    assert_eq!(executed_transaction.id(), expected_transaction.id());
+
+    // every section of the transaction program is measured
+    assert!(measurements.prologue > 0);
+    assert!(measurements.epilogue > 0);
+    assert_eq!(
+        measurements
+            .note_execution
+            .iter()
+            .map(|(note_id, _)| *note_id)
+            .collect::<Vec<_>>(),
+        note_ids
+    );
+    assert!(measurements.note_execution.iter().all(|(_, cycles)| *cycles > 0));
+    let note_cycles = measurements.note_execution.iter().map(|(_, cycles)| cycles).sum::<usize>();
+    assert!(measurements.notes_processing >= note_cycles);
+    assert!(measurements.trace_length.is_power_of_two());
+    assert!(measurements.trace_length > measurements.total_cycles());
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn measure_cycles_of_executed_transaction() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let (executed_transaction, measurements) = executor
+        .execute_transaction_with_measurements(account_id, block_ref, &note_ids, None)
+        .unwrap();
+
+    // re-executing the transaction from its witness spends the same number of cycles
+    let measurement = super::testing::measure_cycles(&executed_transaction).unwrap();
+    assert_eq!(measurement.measurements.prologue, measurements.prologue);
+    assert_eq!(measurement.measurements.note_execution, measurements.note_execution);
+    assert_eq!(measurement.measurements.epilogue, measurements.epilogue);
+    assert_eq!(measurement.total_cycles(), measurements.total_cycles());
+}
+
+#[test]
+fn transaction_executor_debug_captures_failure_state() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // a transaction script which fails with a known value on top of the stack
+    let tx_script_code = ProgramAst::parse("begin push.1234 push.0 assert end").unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let result =
+        executor.execute_transaction_debug(account_id, block_ref, &note_ids, Some(tx_args));
+    let debug_info = match result {
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailedWithDebugInfo(_, info)) => {
+            info
+        },
+        other => panic!("expected the transaction to fail with debug info, got {other:?}"),
+    };
+
+    // the state is captured in the context of the transaction script
+    assert!(debug_info.instruction.is_some());
+    assert_eq!(debug_info.stack[..2], [Felt::new(0), Felt::new(1234)]);
+
+    // the account data section of the kernel memory holds the account ID
+    let account_id_word = debug_info
+        .account_memory
+        .iter()
+        .find(|(addr, _)| *addr == ACCT_ID_AND_NONCE_PTR as u64)
+        .map(|(_, word)| *word)
+        .unwrap();
+    assert_eq!(account_id_word[0], Felt::from(account_id));
+
+    // transactions which do not fail are executed as usual
+    let executed_transaction = executor
+        .execute_transaction_debug(account_id, block_ref, &note_ids, None)
+        .unwrap();
+    let expected_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+    assert_eq!(executed_transaction.id(), expected_transaction.id());
+}
+
+#[test]
+fn transaction_executor_reports_kernel_errors() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // note procedures cannot be invoked from the transaction script
+    let tx_script_code =
+        ProgramAst::parse("use.miden::note begin exec.note::get_sender drop end").unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap_err();
+    let kernel_error = err.kernel_error().expect("transaction should fail with a kernel error");
+    assert_eq!(kernel_error.code, ERR_NOTE_NOT_BEING_PROCESSED);
+    assert_eq!(kernel_error.name, "ERR_NOTE_NOT_BEING_PROCESSED");
+    assert!(err.to_string().contains("ERR_NOTE_NOT_BEING_PROCESSED"));
+
+    // assertions outside of the kernel are not translated
+    let tx_script_code = ProgramAst::parse("begin push.0 assert end").unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap_err();
+    assert!(matches!(err, TransactionExecutorError::ExecuteTransactionProgramFailed(_)));
+    assert!(err.kernel_error().is_none());
+}
+
+#[test]
+fn state_simulator_applies_transactions() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    let mut simulator = StateSimulator::new(
+        [data_store.account.clone()],
+        data_store.notes.iter().map(|note| note.note().clone()),
+    );
+    simulator.apply_transaction(&executed_transaction).unwrap();
+
+    // the account is updated, the input notes are consumed and the output notes are unspent
+    let account = simulator.account(account_id).unwrap();
+    assert_eq!(account.hash(), executed_transaction.final_account().hash());
+    assert!(note_ids.iter().all(|&note_id| simulator.is_consumed(note_id)));
+    assert!(executed_transaction
+        .output_notes()
+        .iter()
+        .all(|note| simulator.is_unspent(note.id())));
+
+    // applying the transaction again would consume the same notes twice
+    assert_eq!(
+        simulator.apply_transaction(&executed_transaction),
+        Err(StateSimulatorError::NoteAlreadyConsumed(note_ids[0]))
+    );
+}
+
+// NOTE PLANNER
+// ================================================================================================
+
+#[test]
+fn note_planner_splits_notes_into_transactions() {
+    let assembler = TransactionKernel::assembler();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // every note has a distinct script
+    let notes = (1..=5u64)
+        .map(|i| {
+            let program = ProgramAst::parse(&format!("begin push.{i} drop end")).unwrap();
+            let (script, _) = NoteScript::new(program, &assembler).unwrap();
+            let serial_num = [Felt::new(i), ZERO, ZERO, ZERO];
+            Note::new(script, &[], &[asset], serial_num, sender, NoteTag::default()).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let note_ids = notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the last note has no script estimate, and thus exceeds the budget with the default estimate
+    let estimates = [100, 200, 200, 300]
+        .into_iter()
+        .zip(notes.iter())
+        .fold(CycleEstimates::new(50, 1000), |estimates, (cycles, note)| {
+            estimates.with_script_cycles(note.script().hash(), cycles)
+        });
+    assert_eq!(estimates.transaction_cycles(&notes[..2]), 350);
+
+    // selecting the cheapest notes first leaves a part of the budget unused
+    let planner = NotePlanner::new(estimates.clone(), TransactionBudget::new(450));
+    let selected = planner.select(&notes).unwrap();
+    assert_eq!(selected.notes(), &note_ids[..2]);
+    assert_eq!(selected.estimated_cycles(), 350);
+
+    let plan = planner.plan(&notes);
+    assert_eq!(
+        plan.transactions().iter().map(|tx| tx.notes().to_vec()).collect::<Vec<_>>(),
+        vec![note_ids[..2].to_vec(), vec![note_ids[2]], vec![note_ids[3]]]
+    );
+    assert_eq!(plan.oversized_notes(), &note_ids[4..]);
+
+    // filling the budget requires fewer transactions
+    let planner = planner.with_strategy(SelectionStrategy::FillBudget);
+    let plan = planner.plan(&notes);
+    assert_eq!(
+        plan.transactions().iter().map(|tx| tx.notes().to_vec()).collect::<Vec<_>>(),
+        vec![note_ids[1..3].to_vec(), vec![note_ids[0], note_ids[3]]]
+    );
+    assert!(plan.transactions().iter().all(|tx| tx.estimated_cycles() == 450));
+
+    // the number of notes per transaction is limited as well
+    let budget = TransactionBudget::new(450).with_max_notes(1);
+    let plan = NotePlanner::new(estimates, budget).plan(&notes);
+    assert_eq!(plan.transactions().len(), 4);
+
+    // none of the notes fits a budget which only covers the base cycles
+    let planner = NotePlanner::new(CycleEstimates::new(50, 100), TransactionBudget::new(50));
+    assert!(planner.select(&notes).is_none());
+    assert!(planner.plan(&notes).transactions().is_empty());
+    assert_eq!(planner.plan(&notes).oversized_notes(), note_ids);
+}
+
+#[test]
+fn note_planner_uses_measured_cycles() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let notes = data_store.notes.iter().map(|note| note.note().clone()).collect::<Vec<_>>();
+    let note_ids = notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let (_, measurements) = executor
+        .execute_transaction_with_measurements(account_id, block_ref, &note_ids, None)
+        .unwrap();
+
+    let mut estimates = CycleEstimates::new(0, 0);
+    estimates.record_measurements(&measurements, &notes);
+    assert_eq!(
+        estimates.base_cycles(),
+        measurements.prologue + measurements.tx_script_processing + measurements.epilogue
+    );
+    for (note, (_, cycles)) in notes.iter().zip(measurements.note_execution.iter()) {
+        assert!(estimates.note_cycles(note.script().hash()) >= *cycles);
+    }
+
+    // all notes fit into a single transaction with a budget covering their estimates
+    let budget = TransactionBudget::new(estimates.transaction_cycles(&notes));
+    let plan = NotePlanner::new(estimates, budget).plan(&notes);
+    assert_eq!(plan.transactions().len(), 1);
+    assert_eq!(plan.transactions()[0].notes(), note_ids);
+}
+
+// MEMORY DATA STORE
+// ================================================================================================
+
+#[test]
+fn memory_data_store_tracks_executed_transactions() {
+    let (account, _, block_header, block_chain, notes) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved)
+            .into_parts();
+    let account_id = account.id();
+    let block_ref = block_header.block_num();
+    let note_ids = notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let data_store = MemoryDataStore::new();
+    data_store.insert_account(account, None);
+
+    // missing items are reported
+    let result = data_store.get_transaction_inputs(account_id, block_ref, &note_ids);
+    assert!(matches!(result, Err(DataStoreError::BlockNotFound(num)) if num == block_ref));
+
+    // a chain MMR which does not track the blocks of the notes cannot serve their headers
+    let peaks_only_chain =
+        ChainMmr::new(PartialMmr::from_peaks(block_chain.peaks()), Vec::new()).unwrap();
+    data_store.insert_block(block_header, peaks_only_chain);
+    let result = data_store.get_transaction_inputs(account_id, block_ref, &note_ids);
+    assert!(matches!(result, Err(DataStoreError::NoteNotFound(id)) if id == note_ids[0]));
+
+    let notes = notes.into_vec();
+    notes.iter().cloned().for_each(|note| data_store.insert_note(note));
+
+    // the store returns the inputs with a chain MMR tracking only the peaks, and the executor
+    // fetches the headers of the blocks of the notes separately
+    let tx_inputs = data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    let mut note_blocks = notes
+        .iter()
+        .filter_map(|note| note.proof())
+        .map(|proof| proof.origin().block_num)
+        .filter(|&num| num != block_ref)
+        .collect::<Vec<_>>();
+    note_blocks.sort();
+    note_blocks.dedup();
+    assert!(!note_blocks.is_empty());
+    assert_eq!(tx_inputs.untracked_note_blocks(), note_blocks);
+    assert!(matches!(
+        data_store.get_block_headers(block_ref, &note_blocks),
+        Err(DataStoreError::BlockNotFound(num)) if num == note_blocks[0]
+    ));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, None);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::FetchBlockHeadersFailed(
+            DataStoreError::BlockNotFound(_)
+        ))
+    ));
+
+    data_store.insert_block(block_header, block_chain.clone());
+    let block_headers = data_store.get_block_headers(block_ref, &note_blocks).unwrap();
+    assert!(block_headers
+        .iter()
+        .zip(note_blocks.iter())
+        .all(|((header, _), &num)| Some(header) == block_chain.get_block(num)));
+    let tx_inputs = tx_inputs.with_block_headers(block_headers).unwrap();
+    assert!(tx_inputs.untracked_note_blocks().is_empty());
+    tx_inputs.validate().unwrap();
+
+    // the executor shares the data of the store, so the store can be updated after execution
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    data_store.apply_transaction(&executed_transaction).unwrap();
+    assert_eq!(
+        data_store.account(account_id).unwrap().hash(),
+        executed_transaction.final_account().hash()
+    );
+    assert!(note_ids.iter().all(|&note_id| data_store.note(note_id).is_none()));
+    assert!(notes
+        .iter()
+        .all(|note| data_store.nullifier_block_num(note.nullifier()) == Some(block_ref + 1)));
+
+    // the consumed notes can no longer be fetched from the store
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, None);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::FetchTransactionInputsFailed(
+            DataStoreError::NoteNotFound(_)
+        ))
+    ));
+
+    // consumed notes are rejected even if they are inserted into the store again
+    notes.iter().cloned().for_each(|note| data_store.insert_note(note));
+    let result = data_store.get_transaction_inputs(account_id, block_ref, &note_ids);
+    assert!(matches!(result, Err(DataStoreError::NoteAlreadyConsumed(id)) if id == note_ids[0]));
+    assert!(matches!(
+        data_store.apply_transaction(&executed_transaction),
+        Err(DataStoreError::NoteAlreadyConsumed(_))
+    ));
+}
+
+// TEST TRANSACTION SCRIPT
+// ================================================================================================
+
+#[test]
+fn test_tx_script() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_input_key = [Felt::new(9999), Felt::new(8888), Felt::new(9999), Felt::new(8888)];
+    let tx_script_input_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    let tx_script_source = format!(
+        "
+    begin
+        # push the tx script input key onto the stack
+        push.{key}
+
+        # load the tx script input value from the map and read it onto the stack
+        adv.push_mapval adv_loadw
+
+        # assert that the value is correct
+        push.{value} assert_eqw
+    end
+",
+        key = prepare_word(&tx_script_input_key),
+        value = prepare_word(&tx_script_input_value)
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code,
+            vec![(tx_script_input_key, tx_script_input_value.into())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    // execute the transaction
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args));
+
+    // assert the transaction executed successfully
+    assert!(executed_transaction.is_ok());
+}
+
+#[test]
+fn test_tx_script_output() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // transactions without a transaction script have no script output
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+    assert_eq!(executed_transaction.tx_script_output(), None);
+
+    let tx_script_source = "
+    begin
+        # replace the transaction script root with the first output word
+        push.1.2.3.4 swapw dropw
+        # => [OUTPUT_0]
+
+        # place the second output word below the first one, keeping the stack depth at 16
+        push.5.6.7.8 swapw movupw.3 dropw
+        # => [OUTPUT_0, OUTPUT_1]
+    end
+    ";
+    let tx_script_code = ProgramAst::parse(tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    let word = |elements: [u64; 4]| elements.map(Felt::new);
+    let expected_output =
+        [word([1, 2, 3, 4]), word([5, 6, 7, 8]), Word::default(), Word::default()];
+    assert_eq!(executed_transaction.tx_script_output(), Some(&expected_output));
+
+    // the script output is preserved when the transaction outputs are serialized
+    let (_, tx_outputs, _) = executed_transaction.into_parts();
+    let deserialized = TransactionOutputs::read_from_bytes(&tx_outputs.to_bytes()).unwrap();
+    assert_eq!(deserialized.tx_script_output, Some(expected_output));
+}
+
+#[test]
+fn test_tx_script_merkle_store() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let leaves: Vec<Word> = (0..4).map(|i| [Felt::new(i), ONE, ZERO, ONE]).collect();
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let tx_script_source = format!(
+        "
+    begin
+        # read the leaf at index 2 of the injected tree onto the stack
+        push.{root} push.2 push.2 mtree_get
+
+        # assert that the leaf is correct and drop the root
+        push.{leaf} assert_eqw dropw
+    end
+",
+        root = prepare_word(&Word::from(tree.root())),
+        leaf = prepare_word(&leaves[2])
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+
+    // without the nodes of the tree the leaf cannot be read
+    let tx_args = TransactionArgs::with_tx_script(tx_script.clone());
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args));
+    assert!(executed_transaction.is_err());
+
+    // the nodes of the tree are merged with the advice inputs generated by the kernel
+    let tx_args = TransactionArgs::builder()
+        .tx_script(tx_script)
+        .merkle_nodes(tree.inner_nodes())
+        .build()
+        .unwrap();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args));
+    assert!(executed_transaction.is_ok());
+}
+
+// ERRORS
+// ================================================================================================
+
+#[test]
+fn errors_expose_codes_and_sources() {
+    use std::error::Error;
+
+    let input_error = TransactionInputError::InputNoteNotInBlock(
+        NoteId::new(Digest::default(), Digest::default()),
+        7,
+    );
+    let error = TransactionExecutorError::InvalidTransactionInputs(input_error.clone());
+
+    assert_eq!(error.code(), 10113);
+    assert_eq!(input_error.code(), 5310);
+    assert_eq!(error.to_string(), "invalid transaction inputs");
+
+    let source = error.source().expect("error should have a source");
+    assert_eq!(source.to_string(), input_error.to_string());
+    assert!(source.to_string().contains("block 7"));
+}
+
+// MOCK DATA STORE
+// ================================================================================================
+
+#[derive(Clone)]
+struct MockDataStore {
+    pub account: Account,
+    pub block_header: BlockHeader,
+    pub block_chain: ChainMmr,
+    pub notes: Vec<InputNote>,
+}
+
+impl MockDataStore {
+    pub fn new(asset_preservation: AssetPreservationStatus) -> Self {
+        let (account, _, block_header, block_chain, notes) =
+            mock_inputs(MockAccountType::StandardExisting, asset_preservation).into_parts();
+
+        Self {
+            account,
+            block_header,
+            block_chain,
+            notes: notes.into_vec(),
+        }
+    }
+}
+
+impl Default for MockDataStore {
+    fn default() -> Self {
+        Self::new(AssetPreservationStatus::Preserved)
+    }
+}
+
+impl DataStore for MockDataStore {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        assert_eq!(account_id, self.account.id());
+        assert_eq!(block_num, self.block_header.block_num());
+        assert_eq!(notes.len(), self.notes.len());
+
+        let notes = self
+            .notes
+            .iter()
+            .filter(|note| notes.contains(&note.id()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        Ok(TransactionInputs::new(
+            self.account.clone(),
+            None,
+            self.block_header,
+            self.block_chain.clone(),
+            InputNotes::new(notes).unwrap(),
+        )
         .unwrap())
     }
 
+    fn get_block_headers(
+        &self,
+        block_ref: u32,
+        block_nums: &[u32],
+    ) -> Result<Vec<(BlockHeader, MerklePath)>, DataStoreError> {
+        assert_eq!(block_ref, self.block_header.block_num());
+        block_nums
+            .iter()
+            .map(|&block_num| {
+                let block_header = self
+                    .block_chain
+                    .get_block(block_num)
+                    .ok_or(DataStoreError::BlockNotFound(block_num))?;
+                let path = self.block_chain.open(block_num).unwrap().merkle_path;
+                Ok((*block_header, path))
+            })
+            .collect()
+    }
+
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
         assert_eq!(account_id, self.account.id());
         Ok(self.account.code().module().clone())
@@ -0,0 +1,34 @@
+// TIMER
+// ================================================================================================
+
+/// Measures the duration of a phase of the transaction lifecycle (e.g., execution or proving) for
+/// the events emitted with the `tracing` feature enabled.
+///
+/// The system clock is not available on `wasm32` targets and without the `std` feature; there,
+/// durations are not measured and are always reported as zero.
+pub(crate) struct Timer {
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    start: std::time::Instant,
+}
+
+impl Timer {
+    /// Returns a new [Timer] started at the current instant.
+    pub fn start() -> Self {
+        Self {
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    /// Returns the number of milliseconds elapsed since this timer was started.
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    pub fn elapsed_ms(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+
+    /// Returns zero, as durations cannot be measured on `wasm32` targets or without `std`.
+    #[cfg(any(not(feature = "std"), target_arch = "wasm32"))]
+    pub fn elapsed_ms(&self) -> u64 {
+        0
+    }
+}
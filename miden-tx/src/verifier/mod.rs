@@ -1,36 +1,136 @@
 use miden_lib::transaction::TransactionKernel;
-use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
+use miden_objects::{
+    assets::FungibleAsset,
+    block::{AccountWitness, NullifierWitness},
+    transaction::{KernelRegistry, ProvenTransaction},
+    BlockHeader, Digest, MIN_PROOF_SECURITY_LEVEL,
+};
 use miden_verifier::verify;
 
 use super::TransactionVerifierError;
 
+// PROOF SECURITY POLICY
+// ================================================================================================
+
+/// Defines the requirements which a transaction proof must satisfy in order to be accepted by a
+/// [TransactionVerifier].
+///
+/// The default policy requires proofs to have at least [MIN_PROOF_SECURITY_LEVEL] bits of
+/// security.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofSecurityPolicy {
+    min_security_level: u32,
+}
+
+impl ProofSecurityPolicy {
+    /// Returns a new [ProofSecurityPolicy] which accepts proofs with at least the specified number
+    /// of bits of security.
+    pub const fn new(min_security_level: u32) -> Self {
+        Self { min_security_level }
+    }
+
+    /// Returns a policy which accepts proofs with at least 96 bits of security.
+    pub const fn min_96bit() -> Self {
+        Self::new(96)
+    }
+
+    /// Returns a policy which accepts proofs with at least 128 bits of security.
+    pub const fn min_128bit() -> Self {
+        Self::new(128)
+    }
+
+    /// Returns the minimum security level (in bits) of accepted proofs.
+    pub fn min_security_level(&self) -> u32 {
+        self.min_security_level
+    }
+
+    /// Returns true if a proof with the specified security level is accepted by this policy.
+    pub fn accepts(&self, proof_security_level: u32) -> bool {
+        proof_security_level >= self.min_security_level
+    }
+}
+
+impl Default for ProofSecurityPolicy {
+    fn default() -> Self {
+        Self::new(MIN_PROOF_SECURITY_LEVEL)
+    }
+}
+
 // TRANSACTION VERIFIER
 // ================================================================================================
 
 /// The [TransactionVerifier] is used to verify  [ProvenTransaction]s.
 ///
-/// The [TransactionVerifier] contains a [ProgramInfo] object which is associated with the
-/// transaction kernel program.  The `proof_security_level` specifies the minimum security
-/// level that the transaction proof must have in order to be considered valid.
+/// The [TransactionVerifier] contains a [KernelRegistry] with the accepted versions of the
+/// transaction kernel program. Each transaction is verified against the kernel program it
+/// declares, which makes it possible to keep accepting transactions proven against a previous
+/// kernel version during a kernel upgrade.
+///
+/// The [ProofSecurityPolicy] specifies the requirements that the transaction proof must satisfy
+/// in order to be considered valid.
+///
+/// The verifier does not depend on the standard library: it can be used with the default features
+/// of this crate disabled (e.g., in light clients of other chains or in embedded validators).
 pub struct TransactionVerifier {
-    tx_program_info: ProgramInfo,
-    proof_security_level: u32,
+    kernel_registry: KernelRegistry,
+    security_policy: ProofSecurityPolicy,
 }
 
 impl TransactionVerifier {
-    /// Returns a new [TransactionVerifier] instantiated with the specified security level.
-    pub fn new(proof_security_level: u32) -> Self {
-        let tx_program_info = TransactionKernel::program_info();
-        Self { tx_program_info, proof_security_level }
+    /// Returns a new [TransactionVerifier] instantiated with the specified security policy.
+    ///
+    /// The returned verifier accepts only transactions proven against the current transaction
+    /// kernel; other kernels can be accepted via [TransactionVerifier::with_kernel_registry()].
+    pub fn new(security_policy: ProofSecurityPolicy) -> Self {
+        let kernel_registry = TransactionKernel::kernel_registry();
+        Self { kernel_registry, security_policy }
+    }
+
+    /// Replaces the registry of transaction kernels accepted by this verifier with the provided
+    /// one.
+    pub fn with_kernel_registry(mut self, kernel_registry: KernelRegistry) -> Self {
+        self.kernel_registry = kernel_registry;
+        self
     }
 
-    /// Verifies the provided [ProvenTransaction] against the transaction kernel.
+    /// Returns an iterator over the program hashes of the transaction kernels accepted by this
+    /// verifier.
+    pub fn kernel_roots(&self) -> impl Iterator<Item = Digest> + '_ {
+        self.kernel_registry
+            .iter()
+            .map(|(_, program_info)| *program_info.program_hash())
+    }
+
+    /// Returns the security policy enforced by this verifier.
+    pub fn security_policy(&self) -> &ProofSecurityPolicy {
+        &self.security_policy
+    }
+
+    /// Verifies the provided [ProvenTransaction] against the transaction kernel it declares.
     ///
     /// # Errors
     /// Returns an error if:
+    /// - The transaction kernel declared by the transaction is not accepted by this verifier.
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "verify_transaction",
+            skip_all,
+            fields(tx_id = %transaction.id()),
+            err
+        )
+    )]
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
+        #[cfg(feature = "tracing")]
+        let timer = crate::timer::Timer::start();
+
+        let (_, tx_program_info) = self
+            .kernel_registry
+            .find(transaction.kernel_root())
+            .ok_or(TransactionVerifierError::UnknownKernel(transaction.kernel_root()))?;
+
         // build stack inputs and outputs
         let stack_inputs = TransactionKernel::build_input_stack(
             transaction.account_id(),
@@ -42,25 +142,147 @@ impl TransactionVerifier {
             transaction.final_account_hash(),
             transaction.output_notes().commitment(),
             transaction.tx_script_root(),
+            transaction.expiration_block_num(),
+            transaction.fee(),
         );
 
         // verify transaction proof
         let proof_security_level = verify(
-            self.tx_program_info.clone(),
+            tx_program_info.clone(),
             stack_inputs,
             stack_outputs,
             transaction.proof().clone(),
         )
         .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
 
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            duration_ms = timer.elapsed_ms(),
+            proof_security_level,
+            "verified transaction proof"
+        );
+
         // check security level
-        if proof_security_level < self.proof_security_level {
+        if !self.security_policy.accepts(proof_security_level) {
             return Err(TransactionVerifierError::InsufficientProofSecurityLevel(
                 proof_security_level,
-                self.proof_security_level,
+                self.security_policy.min_security_level(),
             ));
         }
 
         Ok(())
     }
+
+    /// Checks that the provided [ProvenTransaction] can be included in the block with the
+    /// specified number, i.e., that the transaction has not expired as of that block.
+    ///
+    /// This check does not require verifying the transaction proof, and thus allows block
+    /// producers to cheaply discard stale transactions; the expiration block number of a
+    /// transaction is bound to its proof by [TransactionVerifier::verify()].
+    ///
+    /// # Errors
+    /// Returns an error if the expiration block number of the transaction is lower than the
+    /// specified block number.
+    pub fn check_expiration(
+        &self,
+        transaction: &ProvenTransaction,
+        block_num: u32,
+    ) -> Result<(), TransactionVerifierError> {
+        if transaction.is_expired_at(block_num) {
+            return Err(TransactionVerifierError::TransactionExpired {
+                expiration_block_num: transaction.expiration_block_num(),
+                block_num,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the provided [ProvenTransaction] pays at least the specified fee.
+    ///
+    /// This allows block producers to enforce their fee policy before verifying the transaction
+    /// proof; the fee of a transaction is bound to its proof by [TransactionVerifier::verify()].
+    ///
+    /// # Errors
+    /// Returns an error if the transaction pays no fee, pays the fee in an asset issued by a
+    /// faucet other than the faucet of the specified fee, or pays less than the specified amount.
+    pub fn check_fee(
+        &self,
+        transaction: &ProvenTransaction,
+        min_fee: FungibleAsset,
+    ) -> Result<(), TransactionVerifierError> {
+        match transaction.fee() {
+            Some(fee) if fee.is_from_same_faucet(&min_fee) && fee.amount() >= min_fee.amount() => {
+                Ok(())
+            },
+            fee => {
+                Err(TransactionVerifierError::InsufficientFee { required: min_fee, actual: fee })
+            },
+        }
+    }
+
+    /// Verifies the provided [ProvenTransaction] and checks that it can be applied on top of the
+    /// state committed to by the provided block header.
+    ///
+    /// In addition to verifying the transaction proof (see [TransactionVerifier::verify()]), this
+    /// checks that:
+    /// - The transaction has not expired as of the block following the provided one.
+    /// - The initial state of the account in the transaction matches the state of the account in
+    ///   the account database of the block, as attested by the provided account witness.
+    /// - None of the notes consumed by the transaction has been consumed as of the block, as
+    ///   attested by the provided nullifier witnesses.
+    ///
+    /// This makes it possible for light clients and auditors to validate a transaction end-to-end
+    /// without access to the full state of the chain.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Transaction verification fails.
+    /// - The transaction cannot be included in the block following the provided one.
+    /// - The account witness is not for the account of the transaction, does not open to the
+    ///   account root of the block, or attests to a state other than the initial account state.
+    /// - A nullifier witness is missing for any of the consumed notes or does not open to the
+    ///   nullifier root of the block.
+    /// - Any of the consumed notes has already been consumed.
+    pub fn verify_against_chain(
+        &self,
+        transaction: ProvenTransaction,
+        block_header: &BlockHeader,
+        account_witness: &AccountWitness,
+        nullifier_witnesses: &[NullifierWitness],
+    ) -> Result<(), TransactionVerifierError> {
+        // the transaction is applied on top of the block, and thus included in the next block
+        self.check_expiration(&transaction, block_header.block_num().saturating_add(1))?;
+
+        // check the initial account state against the account database
+        if account_witness.account_id() != transaction.account_id()
+            || !account_witness.is_valid_for(block_header)
+        {
+            return Err(TransactionVerifierError::InvalidAccountWitness(transaction.account_id()));
+        }
+        if account_witness.account_hash() != transaction.initial_account_hash() {
+            return Err(TransactionVerifierError::InitialAccountHashMismatch {
+                expected: account_witness.account_hash(),
+                actual: transaction.initial_account_hash(),
+            });
+        }
+
+        // check that the consumed notes have not been consumed already
+        for note in transaction.input_notes().iter() {
+            let nullifier = &note.nullifier();
+            let witness = nullifier_witnesses
+                .iter()
+                .find(|witness| witness.nullifier() == *nullifier)
+                .ok_or(TransactionVerifierError::MissingNullifierWitness(*nullifier))?;
+
+            if !witness.is_valid_for(block_header) {
+                return Err(TransactionVerifierError::InvalidNullifierWitness(*nullifier));
+            }
+            if !witness.is_unspent() {
+                return Err(TransactionVerifierError::NullifierAlreadySpent(*nullifier));
+            }
+        }
+
+        self.verify(transaction)
+    }
 }
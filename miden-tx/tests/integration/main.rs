@@ -6,21 +6,29 @@ use miden_objects::{
     accounts::{Account, AccountCode, AccountId, AccountStorage, StorageSlotType},
     assembly::{ModuleAst, ProgramAst},
     assets::{Asset, AssetVault, FungibleAsset},
-    crypto::{dsa::rpo_falcon512::KeyPair, utils::Serializable},
-    notes::{Note, NoteId, NoteScript},
+    block::AccountWitness,
+    crypto::{
+        dsa::rpo_falcon512::KeyPair,
+        merkle::{LeafIndex, MerklePath},
+        utils::Serializable,
+    },
+    notes::{Note, NoteId, NoteScript, NoteTag},
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNote, InputNotes, ProvenTransaction, TransactionInputs,
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes,
+        ProvenTransaction, TransactionInputs,
     },
     BlockHeader, Felt, Word,
 };
 use miden_prover::ProvingOptions;
 use miden_tx::{
-    DataStore, DataStoreError, TransactionProver, TransactionVerifier, TransactionVerifierError,
+    DataStore, DataStoreError, LocalTransactionProver, ProofSecurityPolicy, TransactionVerifier,
+    TransactionVerifierError,
 };
 use mock::{
     constants::{ACCOUNT_ID_SENDER, DEFAULT_ACCOUNT_CODE, MIN_PROOF_SECURITY_LEVEL},
     mock::{
         account::MockAccountType,
+        block::{mock_account_db, mock_block_header},
         notes::AssetPreservationStatus,
         transaction::{mock_inputs, mock_inputs_with_existing},
     },
@@ -36,6 +44,7 @@ pub struct MockDataStore {
     pub block_header: BlockHeader,
     pub block_chain: ChainMmr,
     pub notes: Vec<InputNote>,
+    pub foreign_accounts: Vec<ForeignAccountInputs>,
 }
 
 impl MockDataStore {
@@ -48,6 +57,7 @@ impl MockDataStore {
             block_header,
             block_chain,
             notes: notes.into_vec(),
+            foreign_accounts: Vec::new(),
         }
     }
 
@@ -64,6 +74,47 @@ impl MockDataStore {
             block_header,
             block_chain,
             notes: consumed_notes,
+            foreign_accounts: Vec::new(),
+        }
+    }
+
+    /// Makes the specified storage slots of the provided accounts readable by the transaction.
+    ///
+    /// The block header is rebuilt so that its account root commits to the provided accounts.
+    pub fn with_foreign_accounts(mut self, foreign_accounts: &[(Account, Vec<u8>)]) -> Self {
+        let mut accounts = vec![self.account.clone()];
+        accounts.extend(foreign_accounts.iter().map(|(account, _)| account.clone()));
+        self.block_header = mock_block_header(
+            self.block_header.block_num(),
+            Some(self.block_header.chain_root()),
+            Some(self.block_header.note_root()),
+            &accounts,
+        );
+
+        let account_db = mock_account_db(&accounts);
+        self.foreign_accounts = foreign_accounts
+            .iter()
+            .map(|(account, slots)| {
+                let path = account_db.open(&LeafIndex::from(account.id())).path;
+                let witness = AccountWitness::new(account.id(), account.hash(), path);
+                ForeignAccountInputs::from_account(account, witness, slots).unwrap()
+            })
+            .collect();
+
+        self
+    }
+}
+
+impl From<TransactionInputs> for MockDataStore {
+    fn from(tx_inputs: TransactionInputs) -> Self {
+        let foreign_accounts = tx_inputs.foreign_accounts().to_vec();
+        let (account, _, block_header, block_chain, notes) = tx_inputs.into_parts();
+        Self {
+            account,
+            block_header,
+            block_chain,
+            notes: notes.into_vec(),
+            foreign_accounts,
         }
     }
 }
@@ -99,9 +150,30 @@ impl DataStore for MockDataStore {
             self.block_chain.clone(),
             InputNotes::new(notes).unwrap(),
         )
+        .unwrap()
+        .with_foreign_accounts(self.foreign_accounts.clone())
         .unwrap())
     }
 
+    fn get_block_headers(
+        &self,
+        block_ref: u32,
+        block_nums: &[u32],
+    ) -> Result<Vec<(BlockHeader, MerklePath)>, DataStoreError> {
+        assert_eq!(block_ref, self.block_header.block_num());
+        block_nums
+            .iter()
+            .map(|&block_num| {
+                let block_header = self
+                    .block_chain
+                    .get_block(block_num)
+                    .ok_or(DataStoreError::BlockNotFound(block_num))?;
+                let path = self.block_chain.open(block_num).unwrap().merkle_path;
+                Ok((*block_header, path))
+            })
+            .collect()
+    }
+
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
         assert_eq!(account_id, self.account.id());
         Ok(self.account.code().module().clone())
@@ -117,7 +189,7 @@ pub fn prove_and_verify_transaction(
 ) -> Result<(), TransactionVerifierError> {
     // Prove the transaction
     let proof_options = ProvingOptions::default();
-    let prover = TransactionProver::new(proof_options);
+    let prover = LocalTransactionProver::new(proof_options);
     let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
 
     // Serialize & deserialize the ProvenTransaction
@@ -125,7 +197,7 @@ pub fn prove_and_verify_transaction(
     let proven_transaction = ProvenTransaction::read_from_bytes(&serialised_transaction).unwrap();
 
     // Verify that the generated proof is valid
-    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
 
     verifier.verify(proven_transaction)
 }
@@ -181,7 +253,7 @@ pub fn get_note_with_fungible_asset_and_script(
         &[fungible_asset.into()],
         SERIAL_NUM,
         sender_id,
-        Felt::new(1),
+        NoteTag::from(1),
     )
     .unwrap()
 }
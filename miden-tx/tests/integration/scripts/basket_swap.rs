@@ -0,0 +1,235 @@
+use miden_lib::{
+    accounts::wallets::{build_cancel_tx_script, build_fill_swap_tx_script},
+    notes::{basket::MAX_REQUESTED_ASSETS, create_basket_swap_note},
+    transaction::TransactionKernel,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{Account, AccountCode, AccountId, AccountStorage, StorageSlotType},
+    assembly::ModuleAst,
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::{dsa::rpo_falcon512::KeyPair, rand::RpoRandomCoin, utils::Serializable},
+    notes::{NoteMetadata, NoteTag, PartialNote},
+    transaction::{OutputNote, TransactionArgs},
+    Felt, NoteError, Word, ONE,
+};
+use miden_tx::TransactionExecutor;
+use mock::constants::{
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+};
+
+use crate::{prove_and_verify_transaction, MockDataStore};
+
+const BASKET_WALLET_CODE: &str = "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.basic_wallet::receive_asset
+    export.basic_wallet::send_asset
+    export.basic_wallet::add_asset_to_note
+    export.basic::auth_tx_rpo_falcon512
+";
+
+// BASKET SWAP TESTS
+// ===============================================================================================
+// We test the basket swap script: the consumer must receive all offered assets, and must create a
+// single payback note containing all requested assets.
+#[test]
+fn prove_basket_swap_script() {
+    // Create assets
+    let offered = [
+        fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100),
+        non_fungible_asset(1),
+    ];
+    let requested = [
+        fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 50),
+        fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, 25),
+    ];
+
+    // Create sender and target account; the target holds more of the first requested asset than
+    // requested, and exactly the requested amount of the second one
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let key_pair = KeyPair::new().unwrap();
+    let target_account = get_basket_wallet(
+        target_account_id,
+        key_pair.public_key().into(),
+        &[fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 80), requested[1]],
+    );
+
+    // Create the note containing the BASKET_SWAP script
+    let (note, payback_note) =
+        create_basket_swap_note(sender_account_id, &offered, &requested, rng()).unwrap();
+    assert_eq!(note.assets().num_assets(), offered.len());
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let tx_script = executor
+        .compile_tx_script(
+            build_fill_swap_tx_script(&auth_scheme),
+            vec![advice_map_entry(&key_pair)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // The target account has received all offered assets and paid all requested assets
+    let target_account_after = Account::new(
+        target_account_id,
+        AssetVault::new(&[
+            offered[0],
+            offered[1],
+            fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 30),
+        ])
+        .unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // A single payback note containing all requested assets has been created
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(payback_note.assets().num_assets(), requested.len());
+
+    let note_metadata =
+        NoteMetadata::new(target_account_id, NoteTag::for_account(sender_account_id)).unwrap();
+    let expected_note = OutputNote::Partial(PartialNote::new(
+        payback_note.recipient(),
+        payback_note.assets().clone(),
+        note_metadata,
+    ));
+    assert_eq!(executed_transaction.output_notes().get_note(0), &expected_note);
+}
+
+// The issuer of a basket swap note can cancel the order by consuming the note, in which case all
+// offered assets are returned to the issuer and no payback note is created.
+#[test]
+fn prove_basket_swap_cancellation() {
+    let offered = [
+        fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100),
+        non_fungible_asset(1),
+    ];
+    let requested = [fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 50)];
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let key_pair = KeyPair::new().unwrap();
+    let sender_account = get_basket_wallet(sender_account_id, key_pair.public_key().into(), &[]);
+
+    let (note, _) =
+        create_basket_swap_note(sender_account_id, &offered, &requested, rng()).unwrap();
+
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let tx_script = executor
+        .compile_tx_script(
+            build_cancel_tx_script(&auth_scheme),
+            vec![advice_map_entry(&key_pair)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    let sender_account_after = Account::new(
+        sender_account_id,
+        AssetVault::new(&offered).unwrap(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+}
+
+#[test]
+fn basket_swap_note_validation() {
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let offered = [fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100)];
+
+    // at least one asset must be requested
+    assert_eq!(
+        create_basket_swap_note(sender_account_id, &offered, &[], rng()).unwrap_err(),
+        NoteError::EmptyAssetList
+    );
+
+    // the same asset cannot be requested twice
+    let requested = [
+        fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 50),
+        fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 10),
+    ];
+    assert!(matches!(
+        create_basket_swap_note(sender_account_id, &offered, &requested, rng()),
+        Err(NoteError::DuplicateFungibleAsset(_))
+    ));
+
+    // the number of requested assets is bounded by the number of note inputs
+    let requested = (0..=MAX_REQUESTED_ASSETS as u8).map(non_fungible_asset).collect::<Vec<_>>();
+    assert_eq!(
+        create_basket_swap_note(sender_account_id, &offered, &requested, rng()).unwrap_err(),
+        NoteError::TooManyRequestedAssets(MAX_REQUESTED_ASSETS + 1)
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn get_basket_wallet(account_id: AccountId, public_key: Word, assets: &[Asset]) -> Account {
+    let account_code_ast = ModuleAst::parse(BASKET_WALLET_CODE).unwrap();
+    let account_code = AccountCode::new(account_code_ast, &TransactionKernel::assembler()).unwrap();
+    let account_storage =
+        AccountStorage::new(vec![(0, (StorageSlotType::Value { value_arity: 0 }, public_key))])
+            .unwrap();
+
+    Account::new(account_id, AssetVault::new(assets).unwrap(), account_storage, account_code, ONE)
+}
+
+fn advice_map_entry(key_pair: &KeyPair) -> (Word, Vec<Felt>) {
+    let pub_key: Word = key_pair.public_key().into();
+    (pub_key, key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect())
+}
+
+fn rng() -> RpoRandomCoin {
+    RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+}
+
+fn fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(faucet_id).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+fn non_fungible_asset(data: u8) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    NonFungibleAsset::new(&NonFungibleAssetDetails::new(faucet_id, vec![data]).unwrap())
+        .unwrap()
+        .into()
+}
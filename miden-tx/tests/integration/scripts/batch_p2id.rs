@@ -0,0 +1,130 @@
+use miden_lib::notes::{create_batch_p2id_note, utils::build_p2id_recipient};
+use miden_objects::{
+    accounts::{Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{NoteAssets, NoteMetadata, NoteTag, PartialNote},
+    transaction::{OutputNote, TransactionArgs},
+    Felt,
+};
+use miden_tx::TransactionExecutor;
+use mock::constants::{
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    prove_and_verify_transaction, MockDataStore,
+};
+
+// BATCH P2ID TESTS
+// ===============================================================================================
+// We test the batch payout script: the note can be consumed only by the distributor account, which
+// creates one P2ID note for every payout specified by the note.
+#[test]
+fn prove_batch_p2id_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    // Create sender, distributor and payout target accounts
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_keypair_felt) = get_new_key_pair_with_advice_map();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, None);
+
+    let distributor_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (distributor_pub_key, distributor_keypair_felt) = get_new_key_pair_with_advice_map();
+    let distributor_account =
+        get_account_with_default_account_code(distributor_account_id, distributor_pub_key, None);
+
+    let target_1 =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN + 1).unwrap();
+    let target_2 =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN + 2).unwrap();
+    let payouts = [(target_1, 30), (target_2, 70)];
+
+    // Create the note containing the BATCH_P2ID script
+    let (note, payout_serial_nums) = create_batch_p2id_note(
+        sender_account_id,
+        distributor_account_id,
+        faucet_id,
+        &payouts,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let total_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    assert_eq!(note.assets().iter().collect::<Vec<_>>(), vec![&total_asset]);
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Distributor Account - Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(distributor_account.clone()), Some(vec![note.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(distributor_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(distributor_pub_key, distributor_keypair_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let executed_transaction = executor
+        .execute_transaction(distributor_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // Check that all of the asset has been paid out and the nonce increased by 1
+    let distributor_account_after: Account = Account::new(
+        distributor_account_id,
+        AssetVault::new(&[]).unwrap(),
+        distributor_account.storage().clone(),
+        distributor_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), distributor_account_after.hash());
+
+    // Check that one P2ID note has been created for every payout
+    assert_eq!(executed_transaction.output_notes().num_notes(), payouts.len());
+    for (i, ((target, amount), serial_num)) in payouts.iter().zip(payout_serial_nums).enumerate() {
+        let recipient = build_p2id_recipient(*target, serial_num).unwrap();
+        let note_metadata =
+            NoteMetadata::new(distributor_account_id, NoteTag::for_account(*target)).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, *amount).unwrap().into();
+        let note_assets = NoteAssets::new(&[asset]).unwrap();
+
+        let expected_note =
+            OutputNote::Partial(PartialNote::new(recipient, note_assets, note_metadata));
+        assert_eq!(executed_transaction.output_notes().get_note(i), &expected_note);
+    }
+
+    // CONSTRUCT AND EXECUTE TX (Sender Account - Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(sender_account), Some(vec![note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let result =
+        executor.execute_transaction(sender_account_id, block_ref, &note_ids, Some(tx_args));
+
+    // Check that we got the expected result - TransactionExecutorError
+    assert!(result.is_err());
+}
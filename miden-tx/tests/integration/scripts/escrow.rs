@@ -0,0 +1,140 @@
+use miden_lib::notes::{
+    create_escrow_note,
+    escrow::{
+        build_escrow_note_args, build_escrow_signature_inputs, compute_escrow_approval_message,
+    },
+};
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::KeyPair, rand::RpoRandomCoin},
+    notes::Note,
+    transaction::{ExecutedTransaction, TransactionArgs},
+    utils::collections::{BTreeMap, Vec},
+    Felt, Word,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::constants::{
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+    ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    prove_and_verify_transaction, MockDataStore,
+};
+
+// ESCROW TESTS
+// ===============================================================================================
+// We test the escrow script with 2-of-3 approvals. The note must be consumable by an account once
+// two signers have approved, and must not be consumable with a single approval.
+#[test]
+fn prove_escrow_script() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_sk_pk_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // Create the signers and the note
+    let signers = [KeyPair::new().unwrap(), KeyPair::new().unwrap(), KeyPair::new().unwrap()];
+    let signer_pub_keys = signers.iter().map(|signer| signer.public_key()).collect::<Vec<_>>();
+
+    let note = create_escrow_note(
+        sender_account_id,
+        &signer_pub_keys,
+        2,
+        vec![fungible_asset],
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = execute_escrow_transaction(
+        target_account.clone(),
+        note.clone(),
+        &signers[..2],
+        (target_pub_key, target_sk_pk_felt.clone()),
+    )
+    .unwrap();
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let target_account_after: Account = Account::new(
+        target_account.id(),
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // A single approval is not enough to consume the note
+    let executed_transaction = execute_escrow_transaction(
+        target_account,
+        note.clone(),
+        &signers[2..],
+        (target_pub_key, target_sk_pk_felt),
+    );
+    assert!(executed_transaction.is_err());
+
+    // An approval of the consumption of the note by another account is rejected
+    let message = compute_escrow_approval_message(&note, sender_account_id);
+    let approvals = [(signers[0].public_key(), signers[0].sign(message.into()).unwrap())];
+    assert!(build_escrow_signature_inputs(&note, target_account_id, &approvals).is_err());
+}
+
+// HELPER FUNCTIONS
+// ===============================================================================================
+
+fn execute_escrow_transaction(
+    account: Account,
+    note: Note,
+    approvers: &[KeyPair],
+    account_auth: (Word, Vec<Felt>),
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let account_id = account.id();
+    let approver_pub_keys =
+        approvers.iter().map(|approver| approver.public_key()).collect::<Vec<_>>();
+    let note_args =
+        BTreeMap::from([(note.id(), build_escrow_note_args(&note, &approver_pub_keys).unwrap())]);
+
+    // the approvers sign the approval message outside of the consuming transaction
+    let message = compute_escrow_approval_message(&note, account_id);
+    let approvals = approvers
+        .iter()
+        .map(|approver| (approver.public_key(), approver.sign(message.into()).unwrap()))
+        .collect::<Vec<_>>();
+    let mut tx_script_inputs =
+        build_escrow_signature_inputs(&note, account_id, &approvals).unwrap();
+    tx_script_inputs.push(account_auth);
+
+    let data_store = MockDataStore::with_existing(Some(account), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            tx_script_inputs,
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), Some(note_args));
+
+    executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+}
@@ -1,23 +1,44 @@
 use miden_lib::{
-    accounts::faucets::create_basic_fungible_faucet,
-    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel},
+    accounts::faucets::{
+        create_basic_fungible_faucet, create_basic_non_fungible_faucet, FungibleFaucet,
+        NonFungibleFaucet,
+    },
+    notes::create_burn_note,
+    transaction::{
+        memory::FAUCET_STORAGE_DATA_SLOT, scripts::build_burn_tx_script, TransactionKernel,
+    },
     AuthScheme,
 };
 use miden_objects::{
-    accounts::{Account, AccountCode, AccountId, AccountStorage, StorageSlotType},
+    accounts::{
+        Account, AccountCode, AccountId, AccountStorage, AccountType, StorageMap, StorageSlotType,
+    },
     assembly::{ModuleAst, ProgramAst},
-    assets::{Asset, AssetVault, FungibleAsset, TokenSymbol},
-    crypto::dsa::rpo_falcon512::{KeyPair, PublicKey},
-    notes::{NoteAssets, NoteMetadata},
+    assets::{
+        Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails,
+        NonFungibleAssetMetadata, TokenSymbol,
+    },
+    crypto::{
+        dsa::rpo_falcon512::{KeyPair, PublicKey},
+        rand::RpoRandomCoin,
+        utils::Serializable,
+    },
+    notes::{NoteAssets, NoteMetadata, NoteTag, NoteType, PartialNote},
     transaction::{OutputNote, TransactionArgs},
-    Felt, Word, ZERO,
+    Felt, Hasher, Word, ONE, ZERO,
 };
 use miden_tx::TransactionExecutor;
-use mock::{constants::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, utils::prepare_word};
+use mock::{
+    constants::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_SENDER,
+    },
+    utils::prepare_word,
+};
 
 use crate::{
-    get_new_key_pair_with_advice_map, get_note_with_fungible_asset_and_script,
-    prove_and_verify_transaction, MockDataStore,
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    get_note_with_fungible_asset_and_script, prove_and_verify_transaction, MockDataStore,
 };
 
 // TESTS MINT FUNGIBLE ASSET
@@ -40,7 +61,9 @@ fn prove_faucet_contract_mint_fungible_asset_succeeds() {
     let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
 
     let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
-    let tag = Felt::new(4);
+    let tag = NoteTag::from(4);
+    let note_type = NoteType::Private;
+    let aux = Felt::new(27);
     let amount = Felt::new(100);
 
     let tx_script_code = ProgramAst::parse(
@@ -52,16 +75,19 @@ fn prove_faucet_contract_mint_fungible_asset_succeeds() {
             begin
 
                 push.{recipient}
+                push.{aux}
+                push.{note_type}
                 push.{tag}
                 push.{amount}
                 call.faucet::distribute
 
                 call.auth_tx::auth_tx_rpo_falcon512
-                dropw dropw
+                dropw dropw drop drop
 
             end
             ",
             recipient = prepare_word(&recipient),
+            note_type = Felt::from(note_type),
         )
         .as_str(),
     )
@@ -83,16 +109,25 @@ fn prove_faucet_contract_mint_fungible_asset_succeeds() {
     let fungible_asset: Asset =
         FungibleAsset::new(faucet_account.id(), amount.into()).unwrap().into();
 
-    let expected_note = OutputNote::new(
+    let expected_note = OutputNote::Partial(PartialNote::new(
         recipient.into(),
         NoteAssets::new(&[fungible_asset]).unwrap(),
-        NoteMetadata::new(faucet_account.id(), tag),
-    );
+        NoteMetadata::new(faucet_account.id(), tag)
+            .unwrap()
+            .with_note_type(note_type)
+            .with_aux(aux),
+    ));
 
     let created_note = executed_transaction.output_notes().get_note(0).clone();
     assert_eq!(created_note.recipient(), expected_note.recipient());
     assert_eq!(created_note.assets(), expected_note.assets());
     assert_eq!(created_note.metadata(), expected_note.metadata());
+
+    // the minted amount is added to the issued supply of the faucet
+    let mut faucet_account_after = faucet_account.clone();
+    faucet_account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(FungibleFaucet::issued_supply(&faucet_account).unwrap(), 0);
+    assert_eq!(FungibleFaucet::issued_supply(&faucet_account_after).unwrap(), 100);
 }
 
 #[test]
@@ -113,6 +148,8 @@ fn faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
 
     let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
     let tag = Felt::new(4);
+    let note_type = NoteType::Public;
+    let aux = Felt::new(0);
     let amount = Felt::new(250);
 
     let tx_script_code = ProgramAst::parse(
@@ -124,16 +161,19 @@ fn faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
             begin
 
                 push.{recipient}
+                push.{aux}
+                push.{note_type}
                 push.{tag}
                 push.{amount}
                 call.faucet::distribute
 
                 call.auth_tx::auth_tx_rpo_falcon512
-                dropw dropw
+                dropw dropw drop drop
 
             end
             ",
             recipient = prepare_word(&recipient),
+            note_type = Felt::from(note_type),
         )
         .as_str(),
     )
@@ -215,6 +255,240 @@ fn prove_faucet_contract_burn_fungible_asset_succeeds() {
     assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
 }
 
+#[test]
+fn prove_faucet_contract_burn_fungible_asset_via_burn_note() {
+    let (faucet_pub_key, _faucet_keypair_felts) = get_new_key_pair_with_advice_map();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+    let fungible_asset: Asset = FungibleAsset::new(faucet_account.id(), 60).unwrap().into();
+
+    // CONSTRUCT AND EXECUTE TX (send the asset back to the faucet)
+    // --------------------------------------------------------------------------------------------
+    let holder_key_pair: KeyPair = KeyPair::new().unwrap();
+    let holder_pub_key: Word = holder_key_pair.public_key().into();
+    let holder_keypair_felts = holder_key_pair
+        .to_bytes()
+        .iter()
+        .map(|a| Felt::new(*a as u64))
+        .collect::<Vec<_>>();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: holder_key_pair.public_key() };
+    let holder_account = get_account_with_default_account_code(
+        AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+        holder_pub_key,
+        Some(fungible_asset),
+    );
+
+    let (tx_script_code, burn_note_details) = build_burn_tx_script(
+        fungible_asset,
+        &auth_scheme,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let data_store = MockDataStore::with_existing(Some(holder_account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(holder_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(holder_pub_key, holder_keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(holder_account.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    let output_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(output_note.id(), burn_note_details.id());
+    assert_eq!(output_note.metadata().tag(), NoteTag::for_account(faucet_account.id()));
+    let burn_note = burn_note_details.into_note(*output_note.metadata());
+
+    // CONSTRUCT AND EXECUTE TX (burn the asset)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![burn_note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &note_ids, None)
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the burned amount is subtracted from the issued supply of the faucet
+    let mut faucet_account_after = faucet_account.clone();
+    faucet_account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(FungibleFaucet::issued_supply(&faucet_account_after).unwrap(), 40);
+}
+
+// TESTS BURN NON-FUNGIBLE ASSET
+// ================================================================================================
+
+#[test]
+fn prove_faucet_contract_burn_non_fungible_asset_via_burn_note() {
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let (faucet, _) = create_basic_non_fungible_faucet(
+        [5; 32],
+        TokenSymbol::try_from("NFT").unwrap(),
+        Felt::new(10),
+        auth_scheme,
+    )
+    .unwrap();
+
+    // use the code and storage of the new faucet for an existing faucet which has minted an asset
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset_details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(&asset_details).unwrap().into();
+
+    let mut faucet_storage = faucet.storage().clone();
+    faucet_storage.set_item(2, [ONE, ZERO, ZERO, ZERO]).unwrap();
+    let minted_assets = StorageMap::with_entries([(
+        Word::from(non_fungible_asset).into(),
+        non_fungible_asset.into(),
+    )])
+    .unwrap();
+    faucet_storage.set_map(FAUCET_STORAGE_DATA_SLOT, minted_assets).unwrap();
+    let faucet_account =
+        Account::new(faucet_id, AssetVault::default(), faucet_storage, faucet.code().clone(), ONE);
+
+    let burn_note = create_burn_note(
+        AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+        non_fungible_asset,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    assert_eq!(burn_note.metadata().tag(), NoteTag::for_account(faucet_id));
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![burn_note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &note_ids, None)
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the asset has been removed from the tree of minted assets
+    let mut faucet_account_after = faucet_account.clone();
+    faucet_account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(
+        faucet_account_after.storage().get_item(FAUCET_STORAGE_DATA_SLOT),
+        StorageMap::new().root()
+    );
+}
+
+#[test]
+fn prove_non_fungible_faucet_distribute_with_metadata() {
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let faucet_pub_key: Word = key_pair.public_key().into();
+    let faucet_keypair_felts =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect::<Vec<_>>();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let (faucet, _) = create_basic_non_fungible_faucet(
+        [5; 32],
+        TokenSymbol::try_from("NFT").unwrap(),
+        Felt::new(10),
+        auth_scheme,
+    )
+    .unwrap();
+
+    // use the code and storage of the new faucet for an existing faucet
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_account = Account::new(
+        faucet_id,
+        AssetVault::default(),
+        faucet.storage().clone(),
+        faucet.code().clone(),
+        ONE,
+    );
+
+    let asset_data = vec![1, 2, 3];
+    let metadata = NonFungibleAssetMetadata::new("ipfs://nft/1", Hasher::hash(b"rare"));
+    let asset_details = NonFungibleAssetDetails::new(faucet_id, asset_data.clone())
+        .unwrap()
+        .with_metadata(metadata);
+    let non_fungible_asset = NonFungibleAsset::new(&asset_details).unwrap();
+    let metadata_commitment = asset_details.metadata().unwrap().commitment();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::from(4);
+    let data_hash: Word = Hasher::hash(&asset_data).into();
+    let metadata_word: Word = metadata_commitment.into();
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+            use.miden::contracts::faucets::basic_non_fungible->faucet
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+                push.{recipient}
+                push.0
+                push.{note_type}
+                push.{tag}
+                push.{metadata}
+                push.{data_hash}
+                call.faucet::distribute_with_metadata
+
+                call.auth_tx::auth_tx_rpo_falcon512
+                dropw dropw drop drop
+            end
+            ",
+            recipient = prepare_word(&recipient),
+            note_type = Felt::from(NoteType::Public),
+            metadata = prepare_word(&metadata_word),
+            data_hash = prepare_word(&data_hash),
+        )
+        .as_str(),
+    )
+    .unwrap();
+
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(faucet_pub_key, faucet_keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let created_note = executed_transaction.output_notes().get_note(0);
+    let expected_assets = NoteAssets::new(&[non_fungible_asset.into()]).unwrap();
+    assert_eq!(created_note.assets(), Some(&expected_assets));
+
+    // the metadata commitment is recorded under the asset
+    let mut faucet_account_after = faucet_account.clone();
+    faucet_account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(
+        NonFungibleFaucet::asset_metadata(&faucet_account, &non_fungible_asset).unwrap(),
+        None
+    );
+    assert_eq!(
+        NonFungibleFaucet::asset_metadata(&faucet_account_after, &non_fungible_asset).unwrap(),
+        Some(metadata_commitment)
+    );
+}
+
 // TESTS FUNGIBLE CONTRACT CONSTRUCTION
 // ================================================================================================
 
@@ -246,6 +520,12 @@ fn faucet_contract_creation() {
         [Felt::new(123), Felt::new(2), token_symbol.into(), ZERO].into()
     );
 
+    let metadata = FungibleFaucet::from_account(&faucet_account).unwrap();
+    assert_eq!(metadata.max_supply(), 123);
+    assert_eq!(metadata.decimals(), 2);
+    assert_eq!(metadata.symbol().to_str(), token_symbol_string);
+    assert_eq!(FungibleFaucet::issued_supply(&faucet_account).unwrap(), 0);
+
     assert!(faucet_account.is_faucet());
 
     let exp_faucet_account_code_src =
@@ -259,6 +539,51 @@ fn faucet_contract_creation() {
     assert_eq!(faucet_account.code(), &exp_faucet_account_code);
 }
 
+#[test]
+fn non_fungible_faucet_contract_creation() {
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: PublicKey = key_pair.public_key();
+    let auth_scheme: AuthScheme = AuthScheme::RpoFalcon512 { pub_key };
+
+    let init_seed: [u8; 32] = [
+        90, 110, 209, 94, 84, 105, 250, 242, 223, 203, 216, 124, 22, 159, 14, 132, 215, 85, 183,
+        204, 149, 90, 166, 68, 100, 73, 106, 168, 125, 237, 138, 16,
+    ];
+
+    let max_supply = Felt::new(10);
+    let collection_symbol = TokenSymbol::try_from("NFT").unwrap();
+
+    let (faucet_account, _) =
+        create_basic_non_fungible_faucet(init_seed, collection_symbol, max_supply, auth_scheme)
+            .unwrap();
+
+    // check the collection metadata (slot 1), the number of minted assets (slot 2) and the map of
+    // the asset metadata commitments (slot 3)
+    assert_eq!(
+        faucet_account.storage().get_item(1),
+        [Felt::new(10), ZERO, collection_symbol.into(), ZERO].into()
+    );
+    assert_eq!(faucet_account.storage().get_item(2), [ZERO; 4].into());
+    assert_eq!(faucet_account.storage().get_item(3), StorageMap::new().root());
+
+    let collection = NonFungibleFaucet::from_account(&faucet_account).unwrap();
+    assert_eq!(collection.max_supply(), 10);
+    assert_eq!(collection.symbol().to_str(), "NFT");
+
+    assert!(faucet_account.is_faucet());
+    assert_eq!(faucet_account.account_type(), AccountType::NonFungibleFaucet);
+
+    let exp_faucet_account_code_src =
+        include_str!("../../../../miden-lib/asm/miden/contracts/faucets/basic_non_fungible.masm");
+    let exp_faucet_account_code_ast = ModuleAst::parse(exp_faucet_account_code_src).unwrap();
+    let account_assembler = TransactionKernel::assembler();
+
+    let exp_faucet_account_code =
+        AccountCode::new(exp_faucet_account_code_ast.clone(), &account_assembler).unwrap();
+
+    assert_eq!(faucet_account.code(), &exp_faucet_account_code);
+}
+
 fn get_faucet_account_with_max_supply_and_total_issuance(
     public_key: Word,
     max_supply: u64,
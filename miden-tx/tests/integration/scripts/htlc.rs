@@ -0,0 +1,246 @@
+use miden_lib::notes::{create_htlc_note, utils::compute_htlc_hashlock};
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::Note,
+    transaction::{ExecutedTransaction, TransactionArgs},
+    utils::collections::{BTreeMap, Vec},
+    Felt, Word,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::constants::{
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+    ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map, MockDataStore,
+};
+
+// HTLC TESTS
+// ===============================================================================================
+// We want to test the hash-time-locked contract script, which locks the assets of the note behind
+// a hash preimage. The target account can redeem the note by providing the preimage via the note
+// args, and the sender account can get a refund once the timeout block height is reached.
+#[test]
+fn htlc_script_redemption() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // Create the note locked behind the hash of the preimage (Note: Current block height is 4)
+    let preimage: Word = [Felt::new(11), Felt::new(22), Felt::new(33), Felt::new(44)];
+    let note = create_htlc_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        compute_htlc_hashlock(preimage),
+        6,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Target Account with the correct preimage - Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = execute_htlc_transaction(
+        target_account.clone(),
+        (target_pub_key, target_keypair_felt.clone()),
+        note.clone(),
+        preimage,
+    )
+    .unwrap();
+
+    // Assert that the target_account received the funds and the nonce increased by 1
+    let target_account_after: Account = Account::new(
+        target_account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // the transaction expires before the note can be refunded to the sender
+    assert_eq!(executed_transaction.expiration_block_num(), 5);
+
+    // CONSTRUCT AND EXECUTE TX (Target Account with a wrong preimage - Failure)
+    // --------------------------------------------------------------------------------------------
+    let wrong_preimage: Word = [Felt::new(11), Felt::new(22), Felt::new(33), Felt::new(45)];
+    let result = execute_htlc_transaction(
+        target_account,
+        (target_pub_key, target_keypair_felt),
+        note,
+        wrong_preimage,
+    );
+
+    // Check that we got the expected result - TransactionExecutorError
+    assert!(result.is_err());
+}
+
+#[test]
+fn htlc_script_redemption_after_timeout() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // Create the notes with the HTLC script (Note: Current block height is 4)
+    let preimage: Word = [Felt::new(11), Felt::new(22), Felt::new(33), Felt::new(44)];
+    let hashlock = compute_htlc_hashlock(preimage);
+
+    // CONSTRUCT AND EXECUTE TX (Target Account at or after the timeout - Failure)
+    // --------------------------------------------------------------------------------------------
+    for timeout_height in [3, 4] {
+        let note = create_htlc_note(
+            sender_account_id,
+            target_account_id,
+            vec![fungible_asset],
+            hashlock,
+            timeout_height,
+            RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        )
+        .unwrap();
+
+        let result = execute_htlc_transaction(
+            target_account.clone(),
+            (target_pub_key, target_keypair_felt.clone()),
+            note,
+            preimage,
+        );
+        assert!(result.is_err());
+    }
+
+    // CONSTRUCT AND EXECUTE TX (Target Account in the block preceding the timeout - Failure)
+    // --------------------------------------------------------------------------------------------
+    // the transaction could only be included in a block in which the note can be refunded
+    let note = create_htlc_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        hashlock,
+        5,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let result = execute_htlc_transaction(
+        target_account,
+        (target_pub_key, target_keypair_felt),
+        note,
+        preimage,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn htlc_script_refund() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_keypair_felt) = get_new_key_pair_with_advice_map();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, None);
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+    // Create the notes with the HTLC script (Note: Current block height is 4)
+    let preimage: Word = [Felt::new(11), Felt::new(22), Felt::new(33), Felt::new(44)];
+    let hashlock = compute_htlc_hashlock(preimage);
+
+    let note_locked = create_htlc_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        hashlock,
+        5,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let note_refundable = create_htlc_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        hashlock,
+        3,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Sender Account before the timeout - Failure)
+    // --------------------------------------------------------------------------------------------
+    let result = execute_htlc_transaction(
+        sender_account.clone(),
+        (sender_pub_key, sender_keypair_felt.clone()),
+        note_locked,
+        Word::default(),
+    );
+
+    // Check that we got the expected result - TransactionExecutorError
+    assert!(result.is_err());
+
+    // CONSTRUCT AND EXECUTE TX (Sender Account after the timeout - Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = execute_htlc_transaction(
+        sender_account.clone(),
+        (sender_pub_key, sender_keypair_felt),
+        note_refundable,
+        Word::default(),
+    )
+    .unwrap();
+
+    // Assert that the sender_account received the funds and the nonce increased by 1
+    let sender_account_after: Account = Account::new(
+        sender_account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
+}
+
+// HELPER FUNCTIONS
+// ===============================================================================================
+
+/// Executes a transaction consuming the provided HTLC note against the provided account, passing
+/// `note_args` to the note script.
+fn execute_htlc_transaction(
+    account: Account,
+    key_pair: (Word, Vec<Felt>),
+    note: Note,
+    note_args: Word,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let account_id = account.id();
+    let note_id = note.id();
+
+    let data_store = MockDataStore::with_existing(Some(account), Some(vec![note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![key_pair], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), Some(BTreeMap::from([(note_id, note_args)])));
+
+    executor.execute_transaction(account_id, block_ref, &[note_id], Some(tx_args))
+}
@@ -1,4 +1,14 @@
+mod basket_swap;
+mod batch_p2id;
+mod escrow;
 mod faucet;
+mod htlc;
+mod oracle_swap;
 mod p2id;
+mod p2id_any;
+mod p2idc;
 mod p2idr;
+mod payment;
+mod raffle;
+mod subscription;
 mod swap;
@@ -0,0 +1,355 @@
+use miden_lib::{
+    accounts::oracle::{
+        build_price_entry, build_price_update_tx_script, convert_amount, get_oracle_price,
+    },
+    notes::{create_oracle_swap_note, utils::build_p2id_recipient},
+    transaction::TransactionKernel,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountCode, AccountId, AccountStorage, StorageSlotType,
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+    },
+    assembly::{ModuleAst, ProgramAst},
+    assets::{AssetVault, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::KeyPair, rand::RpoRandomCoin, utils::Serializable},
+    notes::{Note, NoteAssets, NoteMetadata, NoteTag, PartialNote},
+    transaction::{ExecutedTransaction, OutputNote, TransactionArgs},
+    Felt, NoteError, Word, ONE,
+};
+use miden_prover::ProvingOptions;
+use miden_tx::{
+    LocalTransactionProver, ProofSecurityPolicy, TransactionExecutor, TransactionExecutorError,
+    TransactionVerifier, TransactionVerifierError,
+};
+use mock::constants::{
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+    MIN_PROOF_SECURITY_LEVEL,
+};
+
+use crate::{get_account_with_default_account_code, prove_and_verify_transaction, MockDataStore};
+
+const ORACLE_CODE: &str = "
+    use.miden::contracts::oracle
+    use.miden::contracts::auth::basic
+
+    export.oracle::set_price
+    export.basic::auth_tx_rpo_falcon512
+";
+
+/// Storage slot at which the mock oracle publishes the price.
+const PRICE_SLOT: u8 = 1;
+
+/// Price of the offered asset in units of the requested asset, i.e., 2.5 requested units per
+/// offered unit.
+const PRICE: u32 = 2_500_000;
+
+/// Block at which the mock oracle published the price; the mock chain references block 4.
+const PRICE_BLOCK: u32 = 3;
+
+// ORACLE SWAP TESTS
+// ===============================================================================================
+// We test the oracle swap script: the amount of the requested asset is computed from the price
+// published by a mock oracle account, which is read by the consuming transaction as a foreign
+// account.
+#[test]
+fn prove_oracle_swap_script() {
+    let offered = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let min_requested = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 200);
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let oracle = get_mock_oracle(PRICE, PRICE_BLOCK);
+
+    let (note, payback_serial_num) = create_oracle_swap_note(
+        sender_account_id,
+        offered,
+        min_requested,
+        oracle.id(),
+        PRICE_SLOT,
+        5,
+        rng(),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let (target_account, key_pair) = get_target_account(300);
+    let target_account_id = target_account.id();
+
+    let executed_transaction =
+        execute_swap(target_account.clone(), &key_pair, note, Some(&oracle)).unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // The requested amount is derived from the oracle price: 100 * 2.5 = 250
+    let requested_amount = convert_amount(offered.amount() as u32, PRICE);
+    assert_eq!(requested_amount, 250);
+    let requested = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, requested_amount);
+
+    // The target account has received the offered asset and paid the requested amount
+    let target_account_after = Account::new(
+        target_account_id,
+        AssetVault::new(&[
+            offered.into(),
+            fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 50).into(),
+        ])
+        .unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // A payback note containing the requested amount has been created for the sender
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+
+    let note_metadata =
+        NoteMetadata::new(target_account_id, NoteTag::for_account(sender_account_id)).unwrap();
+    let expected_note = OutputNote::Partial(PartialNote::new(
+        build_p2id_recipient(sender_account_id, payback_serial_num).unwrap(),
+        NoteAssets::new(&[requested.into()]).unwrap(),
+        note_metadata,
+    ));
+    assert_eq!(executed_transaction.output_notes().get_note(0), &expected_note);
+}
+
+// The note cannot be consumed if the oracle cannot be read, if the price is not recent, or if the
+// price results in an amount smaller than the minimum requested amount.
+#[test]
+fn oracle_swap_script_fails() {
+    let offered = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let oracle = get_mock_oracle(PRICE, PRICE_BLOCK);
+    let (target_account, key_pair) = get_target_account(300);
+
+    let swap_note = |min_requested: u64, max_price_age: u32| {
+        let min_requested = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, min_requested);
+        let (note, _) = create_oracle_swap_note(
+            sender_account_id,
+            offered,
+            min_requested,
+            oracle.id(),
+            PRICE_SLOT,
+            max_price_age,
+            rng(),
+        )
+        .unwrap();
+        note
+    };
+
+    // the oracle is not a foreign account of the transaction
+    assert!(execute_swap(target_account.clone(), &key_pair, swap_note(200, 5), None).is_err());
+
+    // the price was published 1 block before the referenced block, and thus is at least 2 blocks
+    // old in any block the transaction can be included in
+    assert!(
+        execute_swap(target_account.clone(), &key_pair, swap_note(200, 1), Some(&oracle)).is_err()
+    );
+    assert!(
+        execute_swap(target_account.clone(), &key_pair, swap_note(200, 2), Some(&oracle)).is_ok()
+    );
+
+    // the price results in 250 requested units
+    assert!(
+        execute_swap(target_account.clone(), &key_pair, swap_note(251, 5), Some(&oracle)).is_err()
+    );
+    assert!(execute_swap(target_account, &key_pair, swap_note(250, 5), Some(&oracle)).is_ok());
+}
+
+// A transaction which references an old block, at which the price is recent, expires once the
+// price is stale, and thus cannot be included in a block at which the price is stale.
+#[test]
+fn oracle_swap_expires_with_stale_price() {
+    let offered = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100);
+    let min_requested = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 200);
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let oracle = get_mock_oracle(PRICE, PRICE_BLOCK);
+    let (target_account, key_pair) = get_target_account(300);
+
+    let (note, _) = create_oracle_swap_note(
+        sender_account_id,
+        offered,
+        min_requested,
+        oracle.id(),
+        PRICE_SLOT,
+        5,
+        rng(),
+    )
+    .unwrap();
+
+    let executed_transaction =
+        execute_swap(target_account, &key_pair, note, Some(&oracle)).unwrap();
+    assert_eq!(executed_transaction.expiration_block_num(), PRICE_BLOCK + 5);
+
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+
+    let verifier = TransactionVerifier::new(ProofSecurityPolicy::new(MIN_PROOF_SECURITY_LEVEL));
+    assert!(verifier.check_expiration(&proven_transaction, PRICE_BLOCK + 5).is_ok());
+    assert!(matches!(
+        verifier.check_expiration(&proven_transaction, PRICE_BLOCK + 6),
+        Err(TransactionVerifierError::TransactionExpired { .. })
+    ));
+}
+
+// The owner of the oracle publishes a price, which is timestamped with the referenced block.
+#[test]
+fn prove_oracle_price_update() {
+    let key_pair = KeyPair::new().unwrap();
+    let oracle = get_oracle(&key_pair, PRICE, PRICE_BLOCK);
+    let oracle_id = oracle.id();
+
+    let data_store = MockDataStore::with_existing(Some(oracle.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(oracle_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let tx_script = executor
+        .compile_tx_script(
+            build_price_update_tx_script(PRICE_SLOT, 3_000_000, &auth_scheme),
+            vec![advice_map_entry(&key_pair)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction =
+        executor.execute_transaction(oracle_id, block_ref, &[], Some(tx_args)).unwrap();
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let mut oracle_after = oracle;
+    oracle_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), oracle_after.hash());
+    assert_eq!(get_oracle_price(&oracle_after, PRICE_SLOT), Some((3_000_000, block_ref)));
+
+    // the slot holding the key of the owner cannot be overwritten
+    let tx_script = executor
+        .compile_tx_script(
+            build_price_update_tx_script(0, 3_000_000, &auth_scheme),
+            vec![advice_map_entry(&key_pair)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    assert!(executor.execute_transaction(oracle_id, block_ref, &[], Some(tx_args)).is_err());
+}
+
+#[test]
+fn oracle_swap_note_validation() {
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let oracle_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let min_requested = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, 1);
+
+    // the offered amount must fit into 32 bits, so that it can be converted without overflow
+    let offered = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 1 << 32);
+    assert_eq!(
+        create_oracle_swap_note(
+            sender_account_id,
+            offered,
+            min_requested,
+            oracle_id,
+            PRICE_SLOT,
+            5,
+            rng()
+        )
+        .unwrap_err(),
+        NoteError::OracleSwapAmountTooLarge(1 << 32)
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes a transaction consuming the provided swap note against the target account; the
+/// price slot of the oracle, if provided, is readable by the transaction.
+fn execute_swap(
+    target_account: Account,
+    key_pair: &KeyPair,
+    note: Note,
+    oracle: Option<&Account>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let target_account_id = target_account.id();
+    let mut data_store = MockDataStore::with_existing(Some(target_account), Some(vec![note]));
+    if let Some(oracle) = oracle {
+        data_store = data_store.with_foreign_accounts(&[(oracle.clone(), vec![PRICE_SLOT])]);
+    }
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            vec![advice_map_entry(key_pair)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    executor.execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args))
+}
+
+/// Returns a basic wallet holding the specified amount of the requested asset.
+fn get_target_account(requested_balance: u64) -> (Account, KeyPair) {
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let key_pair = KeyPair::new().unwrap();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        key_pair.public_key().into(),
+        Some(fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, requested_balance).into()),
+    );
+
+    (target_account, key_pair)
+}
+
+/// Returns an oracle account which publishes the specified price at [PRICE_SLOT], owned by a
+/// random key.
+fn get_mock_oracle(price: u32, updated_at_block: u32) -> Account {
+    get_oracle(&KeyPair::new().unwrap(), price, updated_at_block)
+}
+
+fn get_oracle(key_pair: &KeyPair, price: u32, updated_at_block: u32) -> Account {
+    let oracle_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ORACLE_CODE).unwrap();
+    let account_code = AccountCode::new(account_code_ast, &TransactionKernel::assembler()).unwrap();
+
+    let public_key: Word = key_pair.public_key().into();
+    let account_storage = AccountStorage::new(vec![
+        (0, (StorageSlotType::Value { value_arity: 0 }, public_key)),
+        (
+            PRICE_SLOT,
+            (
+                StorageSlotType::Value { value_arity: 0 },
+                build_price_entry(price, updated_at_block),
+            ),
+        ),
+    ])
+    .unwrap();
+
+    Account::new(oracle_id, AssetVault::new(&[]).unwrap(), account_storage, account_code, ONE)
+}
+
+fn advice_map_entry(key_pair: &KeyPair) -> (Word, Vec<Felt>) {
+    let pub_key: Word = key_pair.public_key().into();
+    (pub_key, key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect())
+}
+
+fn rng() -> RpoRandomCoin {
+    RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+}
+
+fn fungible_asset(faucet_id: u64, amount: u64) -> FungibleAsset {
+    let faucet_id = AccountId::try_from(faucet_id).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap()
+}
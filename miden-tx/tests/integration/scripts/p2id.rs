@@ -1,18 +1,32 @@
-use miden_lib::notes::create_p2id_note;
+use core::ops::ControlFlow;
+use miden_lib::{
+    notes::{create_p2id_note, scripts::WellKnownScript, utils::build_p2id_recipient},
+    transaction::{NoteScriptKind, ToTransactionSummary},
+};
+
 use miden_objects::{
     accounts::{Account, AccountId},
     assembly::ProgramAst,
     assets::{Asset, AssetVault, FungibleAsset},
     crypto::rand::RpoRandomCoin,
-    transaction::TransactionArgs,
+    notes::NoteType,
+    transaction::{ExecutedTransaction, ProvenTransaction, TransactionArgs},
     utils::collections::Vec,
-    Felt,
+    Felt, Word, ZERO,
+};
+use miden_tx::{
+    LocalTransactionPipeline, PipelineError, PipelineHooks, PipelineStage, TransactionExecutor,
 };
-use miden_tx::TransactionExecutor;
-use mock::constants::{
-    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
-    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+use mock::{
+    constants::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+    },
+    mock::chain::MockChain,
+    utils::prepare_word,
 };
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
 
 use crate::{
     get_account_with_default_account_code, get_new_key_pair_with_advice_map,
@@ -43,21 +57,30 @@ fn prove_p2id_script() {
         sender_account_id,
         target_account_id,
         vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
     .unwrap();
 
+    // Record the account and the note in the first block of a mock chain, and execute the
+    // transaction against the following block
+    let mut mock_chain = MockChain::new(Pcg64::seed_from_u64(0));
+    mock_chain.add_account(target_account.clone(), Word::default());
+    mock_chain.add_note(note.clone()).unwrap();
+    mock_chain.seal_block();
+    let block_ref = mock_chain.seal_block().block_num();
+
     // CONSTRUCT AND EXECUTE TX (Success)
     // --------------------------------------------------------------------------------------------
-    let data_store =
-        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+    let note_ids = vec![note.id()];
+    let data_store = MockDataStore::from(
+        mock_chain.transaction_inputs(target_account_id, block_ref, &note_ids).unwrap(),
+    );
 
     let mut executor = TransactionExecutor::new(data_store.clone());
     executor.load_account(target_account_id).unwrap();
 
-    let block_ref = data_store.block_header.block_num();
-    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
-
     let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
 
     let tx_script_target = executor
@@ -87,6 +110,16 @@ fn prove_p2id_script() {
     );
     assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
 
+    // the summary of the transaction identifies the consumed note as a P2ID note
+    let summary = executed_transaction.summary();
+    assert_eq!(
+        summary.consumed_notes()[0].script(),
+        NoteScriptKind::WellKnown(WellKnownScript::P2ID)
+    );
+    assert_eq!(summary.consumed_notes()[0].assets(), Some([fungible_asset].as_slice()));
+    assert_eq!(summary.assets()[0].faucet_id, faucet_id);
+    assert_eq!(summary.assets()[0].amount_in, 100);
+
     // CONSTRUCT AND EXECUTE TX (Failure)
     // --------------------------------------------------------------------------------------------
     // A "malicious" account tries to consume the note, we expect an error
@@ -130,6 +163,156 @@ fn prove_p2id_script() {
     assert!(executed_transaction_2.is_err());
 }
 
+/// Records the stages completed by a [LocalTransactionPipeline] and optionally aborts it after
+/// execution.
+#[derive(Default)]
+struct RecordingHooks {
+    abort_after_execution: bool,
+    stages: Vec<&'static str>,
+}
+
+impl PipelineHooks for RecordingHooks {
+    fn after_execution(&mut self, _transaction: &ExecutedTransaction) -> ControlFlow<()> {
+        self.stages.push("executed");
+        match self.abort_after_execution {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(()),
+        }
+    }
+
+    fn after_proving(&mut self, _transaction: &ProvenTransaction) -> ControlFlow<()> {
+        self.stages.push("proven");
+        ControlFlow::Continue(())
+    }
+
+    fn after_verification(&mut self, _transaction: &ProvenTransaction) {
+        self.stages.push("verified");
+    }
+}
+
+#[test]
+fn prove_p2id_script_with_pipeline() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_sk_pk_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let mut mock_chain = MockChain::new(Pcg64::seed_from_u64(0));
+    mock_chain.add_account(target_account.clone(), Word::default());
+    mock_chain.add_note(note.clone()).unwrap();
+    mock_chain.seal_block();
+    let block_ref = mock_chain.seal_block().block_num();
+
+    let note_ids = vec![note.id()];
+    let data_store = MockDataStore::from(
+        mock_chain.transaction_inputs(target_account_id, block_ref, &note_ids).unwrap(),
+    );
+
+    let mut executor = TransactionExecutor::new(data_store);
+    executor.load_account(target_account_id).unwrap();
+    let tx_script = executor
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            vec![(target_pub_key, target_sk_pk_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    // the transaction is executed, proven and verified in a single call
+    let mut pipeline =
+        LocalTransactionPipeline::new(executor).with_hooks(RecordingHooks::default());
+    let proven_transaction = pipeline
+        .process(target_account_id, block_ref, &note_ids, Some(tx_args.clone()))
+        .unwrap();
+    assert_eq!(proven_transaction.account_id(), target_account_id);
+    assert_eq!(proven_transaction.initial_account_hash(), target_account.hash());
+    assert_eq!(pipeline.hooks().stages, vec!["executed", "proven", "verified"]);
+
+    // hooks can abort the pipeline between stages
+    let hooks = RecordingHooks {
+        abort_after_execution: true,
+        ..Default::default()
+    };
+    let mut pipeline = pipeline.with_hooks(hooks);
+    let result = pipeline.process(target_account_id, block_ref, &note_ids, Some(tx_args));
+    assert!(matches!(result, Err(PipelineError::Aborted(PipelineStage::Execution))));
+    assert_eq!(pipeline.into_hooks().stages, vec!["executed"]);
+}
+
+/// We simulate the consumption of a P2ID note by the target account without providing the secret
+/// key of the account, which is required to execute the same transaction.
+#[test]
+fn p2id_script_simulation() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, _) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let data_store = MockDataStore::with_existing(Some(target_account), Some(vec![note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the transaction script does not provide the secret key of the target account
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    // SIMULATE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let simulated_transaction = executor
+        .simulate_transaction(target_account_id, block_ref, &note_ids, Some(tx_args.clone()))
+        .unwrap();
+
+    // the target account would receive the asset and increment its nonce
+    let account_delta = simulated_transaction.account_delta();
+    assert_eq!(account_delta.vault().added_assets, vec![fungible_asset]);
+    assert!(account_delta.vault().removed_assets.is_empty());
+    assert_eq!(account_delta.nonce(), Some(Felt::new(2)));
+    assert!(simulated_transaction.output_notes().is_empty());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // the same transaction can not be executed without the secret key
+    let result =
+        executor.execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args));
+    assert!(result.is_err());
+}
+
 /// We test the Pay to script with 2 assets to test the loop inside the script.
 /// So we create a note containing two assets that can only be consumed by the target account.
 #[test]
@@ -155,6 +338,8 @@ fn p2id_script_multiple_assets() {
         sender_account_id,
         target_account_id,
         vec![fungible_asset_1, fungible_asset_2],
+        NoteType::Public,
+        ZERO,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
     .unwrap();
@@ -237,3 +422,116 @@ fn p2id_script_multiple_assets() {
     // Check that we got the expected result - TransactionExecutorError
     assert!(executed_transaction_2.is_err());
 }
+
+// We test that the recipient returned by `build_p2id_recipient()` matches the recipient computed by
+// the transaction kernel, both for notes created by a transaction and for notes consumed by it.
+#[test]
+fn p2id_recipient_matches_kernel_recipient() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_keypair_felt) = get_new_key_pair_with_advice_map();
+    let sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset.into(),
+    );
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // Create the note
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let recipient = build_p2id_recipient(target_account_id, note.serial_num()).unwrap();
+    assert_eq!(recipient, note.recipient());
+
+    // SEND THE NOTE
+    // --------------------------------------------------------------------------------------------
+    // The kernel computes the ID of the created note from the provided recipient, so the ID
+    // matches the one of the note only if the recipients match.
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.0
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+            call.wallet::send_asset drop
+            dropw dropw drop drop
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+            recipient = prepare_word(&recipient.into()),
+            note_type = Felt::from(NoteType::Public),
+            tag = Felt::from(note.metadata().tag()),
+            asset = prepare_word(&fungible_asset.into())
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account_id, block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0).id(), note.id());
+
+    // CONSUME THE NOTE
+    // --------------------------------------------------------------------------------------------
+    // The kernel computes the recipient of the consumed note from its serial number, script and
+    // inputs, so the note can be consumed only if the recipients match.
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            vec![(target_pub_key, target_keypair_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
+}
@@ -0,0 +1,276 @@
+use miden_lib::{
+    accounts::wallets::{get_claim_entries, CLAIMS_SLOT, CLAIM_SCRIPT_ROOT_SLOT},
+    notes::{create_p2idc_note, scripts},
+    transaction::TransactionKernel,
+};
+use miden_objects::{
+    accounts::{Account, AccountCode, AccountId, AccountStorage, StorageMap, StorageSlotType},
+    assembly::{ModuleAst, ProgramAst},
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::KeyPair, rand::RpoRandomCoin, utils::Serializable},
+    notes::NoteType,
+    transaction::TransactionArgs,
+    utils::collections::Vec,
+    Digest, Felt, Word, ONE, ZERO,
+};
+use miden_tx::TransactionExecutor;
+use mock::{
+    constants::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+    },
+    utils::prepare_word,
+};
+
+use crate::{prove_and_verify_transaction, MockDataStore};
+
+const CLAIMABLE_WALLET_CODE: &str = "
+    use.miden::contracts::wallets::claimable->claimable_wallet
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.claimable_wallet::receive_asset
+    export.claimable_wallet::claim
+    export.basic_wallet::send_asset
+    export.basic::auth_tx_rpo_falcon512
+";
+
+// P2IDC TESTS
+// ===============================================================================================
+// We test the Pay to ID claimable script together with the claimable wallet. The received asset
+// must be recorded as a pending claim entry and kept out of the account vault, so that it cannot
+// be spent, until the entry is claimed by consuming the claim note.
+#[test]
+fn prove_p2idc_script_and_claim() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let key_pair = KeyPair::new().unwrap();
+    let target_pub_key: Word = key_pair.public_key().into();
+    let target_sk_pk_felt: Vec<Felt> =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect();
+    let target_account =
+        get_account_with_claimable_wallet_code(target_account_id, target_pub_key, []);
+
+    // Create the payment note and the claim note
+    let (note, claim_note) = create_p2idc_note(
+        sender_account_id,
+        target_account_id,
+        fungible_asset,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let entry_id = Digest::from(note.serial_num());
+
+    // CONSUME THE NOTE
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            vec![(target_pub_key, target_sk_pk_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the asset has been moved into the claim note instead of the account vault
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0).id(), claim_note.id());
+
+    let mut account_after_receive = target_account.clone();
+    account_after_receive.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account_after_receive.hash());
+    assert_eq!(account_after_receive.vault().assets().count(), 0);
+
+    let entries = get_claim_entries(&account_after_receive).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].entry_id(), entry_id);
+    assert_eq!(entries[0].asset(), &fungible_asset);
+
+    // SEND THE UNCLAIMED ASSET (Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(account_after_receive.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.0
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+            call.wallet::send_asset drop
+            dropw dropw drop drop
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+            recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+            note_type = Felt::from(NoteType::Public),
+            tag = Felt::new(4),
+            asset = prepare_word(&fungible_asset.into())
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code,
+            vec![(target_pub_key, target_sk_pk_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction =
+        executor.execute_transaction(target_account_id, block_ref, &[], Some(tx_args));
+    assert!(executed_transaction.is_err());
+
+    // CLAIM THE ENTRY
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(account_after_receive.clone()), Some(vec![claim_note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            vec![(target_pub_key, target_sk_pk_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let mut account_after_claim = account_after_receive;
+    account_after_claim.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account_after_claim.hash());
+
+    assert!(get_claim_entries(&account_after_claim).unwrap().is_empty());
+    assert_eq!(account_after_claim.vault().get_balance(faucet_id).unwrap(), 100);
+}
+
+// We test that the claim note of an entry can only be consumed by the account which received the
+// payment, even if another account records a pending entry with the same ID and asset.
+#[test]
+fn p2idc_claim_note_cannot_be_consumed_by_another_account() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+    let (note, claim_note) = create_p2idc_note(
+        sender_account_id,
+        target_account_id,
+        fungible_asset,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let entry_id = Digest::from(note.serial_num());
+
+    // the sender of the payment tries to take the paid asset back via the claim note
+    let malicious_account_id = sender_account_id;
+    let key_pair = KeyPair::new().unwrap();
+    let malicious_pub_key: Word = key_pair.public_key().into();
+    let malicious_sk_pk_felt: Vec<Felt> =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect();
+    let malicious_account = get_account_with_claimable_wallet_code(
+        malicious_account_id,
+        malicious_pub_key,
+        [(entry_id, fungible_asset.into())],
+    );
+
+    // CONSUME THE CLAIM NOTE (Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(malicious_account), Some(vec![claim_note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(malicious_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            vec![(malicious_pub_key, malicious_sk_pk_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let executed_transaction =
+        executor.execute_transaction(malicious_account_id, block_ref, &note_ids, Some(tx_args));
+    assert!(executed_transaction.is_err());
+}
+
+// HELPER FUNCTIONS
+// ===============================================================================================
+
+fn get_account_with_claimable_wallet_code(
+    account_id: AccountId,
+    public_key: Word,
+    claim_entries: impl IntoIterator<Item = (Digest, Word)>,
+) -> Account {
+    let account_code_ast = ModuleAst::parse(CLAIMABLE_WALLET_CODE).unwrap();
+    let account_code = AccountCode::new(account_code_ast, &TransactionKernel::assembler()).unwrap();
+    let claims = StorageMap::with_entries(claim_entries).unwrap();
+    let mut account_storage = AccountStorage::new(vec![
+        (0, (StorageSlotType::Value { value_arity: 0 }, public_key)),
+        (CLAIMS_SLOT, (StorageSlotType::Map { value_arity: 0 }, claims.root().into())),
+        (
+            CLAIM_SCRIPT_ROOT_SLOT,
+            (StorageSlotType::Value { value_arity: 0 }, scripts::p2idc().hash().into()),
+        ),
+    ])
+    .unwrap();
+    account_storage.set_map(CLAIMS_SLOT, claims).unwrap();
+
+    Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        account_storage,
+        account_code,
+        Felt::new(1),
+    )
+}
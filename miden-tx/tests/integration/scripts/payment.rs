@@ -0,0 +1,168 @@
+use miden_lib::{
+    accounts::wallets::{get_payment_requests, PaymentStatus, REQUESTS_OFFSET_SLOT},
+    notes::create_payment_note,
+    transaction::TransactionKernel,
+};
+use miden_objects::{
+    accounts::{Account, AccountCode, AccountId, AccountStorage, StorageSlotType},
+    assembly::{ModuleAst, ProgramAst},
+    assets::{AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    transaction::TransactionArgs,
+    utils::collections::Vec,
+    Felt, Word, ONE,
+};
+use miden_tx::TransactionExecutor;
+use mock::constants::{
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+    ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+};
+
+use crate::{get_new_key_pair_with_advice_map, prove_and_verify_transaction, MockDataStore};
+
+const MERCHANT_WALLET_CODE: &str = "
+    use.miden::contracts::wallets::merchant->merchant_wallet
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.merchant_wallet::add_payment_request
+    export.merchant_wallet::receive_payment
+    export.basic_wallet::receive_asset
+    export.basic_wallet::send_asset
+    export.basic::auth_tx_rpo_falcon512
+";
+
+const INVOICE_ID: u8 = 7;
+
+// PAYMENT TESTS
+// ===============================================================================================
+// We test the payment script against a merchant wallet. A payment note can only be consumed if it
+// matches an open payment request, in which case the request is marked as paid.
+#[test]
+fn prove_payment_script() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let requested_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    // Create sender and merchant account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let merchant_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (merchant_pub_key, merchant_keypair_felt) = get_new_key_pair_with_advice_map();
+    let merchant_account =
+        get_merchant_account(merchant_account_id, merchant_pub_key, requested_asset);
+
+    let requests = get_payment_requests(&merchant_account).unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].invoice_id(), INVOICE_ID);
+    assert_eq!(requests[0].status(), PaymentStatus::Open);
+
+    // Create the note
+    let note = create_payment_note(
+        sender_account_id,
+        merchant_account_id,
+        requested_asset,
+        INVOICE_ID,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(merchant_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(merchant_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(merchant_pub_key, merchant_keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let executed_transaction = executor
+        .execute_transaction(merchant_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let mut merchant_account_after = merchant_account.clone();
+    merchant_account_after
+        .apply_delta(executed_transaction.account_delta())
+        .unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), merchant_account_after.hash());
+    assert_eq!(merchant_account_after.vault().get_balance(faucet_id).unwrap(), 100);
+
+    let requests = get_payment_requests(&merchant_account_after).unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].is_paid());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // A payment with an amount different from the requested one must be rejected
+
+    let wrong_asset = FungibleAsset::new(faucet_id, 99).unwrap();
+    let wrong_note = create_payment_note(
+        sender_account_id,
+        merchant_account_id,
+        wrong_asset,
+        INVOICE_ID,
+        RpoRandomCoin::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+    )
+    .unwrap();
+
+    let data_store = MockDataStore::with_existing(Some(merchant_account), Some(vec![wrong_note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(merchant_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(merchant_pub_key, merchant_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let executed_transaction =
+        executor.execute_transaction(merchant_account_id, block_ref, &note_ids, Some(tx_args));
+    assert!(executed_transaction.is_err());
+}
+
+// HELPER FUNCTIONS
+// ===============================================================================================
+
+fn get_merchant_account(
+    account_id: AccountId,
+    public_key: Word,
+    requested_asset: FungibleAsset,
+) -> Account {
+    let account_code_ast = ModuleAst::parse(MERCHANT_WALLET_CODE).unwrap();
+    let account_code = AccountCode::new(account_code_ast, &TransactionKernel::assembler()).unwrap();
+
+    // open payment request: [amount, 0, status, faucet_id]
+    let mut request: Word = requested_asset.into();
+    request[2] = ONE;
+    let account_storage = AccountStorage::new(vec![
+        (0, (StorageSlotType::Value { value_arity: 0 }, public_key)),
+        (
+            REQUESTS_OFFSET_SLOT + INVOICE_ID,
+            (StorageSlotType::Value { value_arity: 0 }, request),
+        ),
+    ])
+    .unwrap();
+
+    Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        account_storage,
+        account_code,
+        Felt::new(1),
+    )
+}
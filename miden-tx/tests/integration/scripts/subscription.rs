@@ -0,0 +1,210 @@
+use miden_lib::notes::{create_subscription_note, subscription::build_subscription_clone_details};
+use miden_objects::{
+    accounts::{Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+    assembly::ProgramAst,
+    assets::{AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteMetadata, NoteTag},
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, NoteError, Word,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::constants::{
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    prove_and_verify_transaction, MockDataStore,
+};
+
+// SUBSCRIPTION TESTS
+// ================================================================================================
+// We test the subscription script: every claim of the payee pays out the amount of one interval
+// and creates a clone of the note carrying the remaining amount, which the payee can claim only
+// once the interval has passed. The payer can cancel the subscription at any time.
+
+#[test]
+fn prove_subscription_claim() {
+    // Create the payer and payee accounts (Note: Current block height is 4)
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let amount_per_interval = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let payer_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let payee_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (payee_pub_key, payee_sk_felt) = get_new_key_pair_with_advice_map();
+    let payee_account = get_account_with_default_account_code(payee_id, payee_pub_key, None);
+
+    let note = create_subscription_note(
+        payer_id,
+        payee_id,
+        amount_per_interval,
+        10,
+        3,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Payee claims the first interval - Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = execute_subscription_note(
+        payee_account.clone(),
+        note.clone(),
+        payee_pub_key,
+        payee_sk_felt.clone(),
+    )
+    .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // Check that the payee received the amount of one interval
+    let payee_account_after = Account::new(
+        payee_id,
+        AssetVault::new(&[amount_per_interval.into()]).unwrap(),
+        payee_account.storage().clone(),
+        payee_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), payee_account_after.hash());
+
+    // Check that the clone note carrying the remaining amount has been created
+    let block_num = executed_transaction.block_header().block_num();
+    let clone_details =
+        build_subscription_clone_details(note.details(), block_num).unwrap().unwrap();
+    assert_eq!(
+        clone_details.assets().iter().next(),
+        Some(&FungibleAsset::new(faucet_id, 200).unwrap().into())
+    );
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0).id(), clone_details.id());
+
+    let clone_metadata = NoteMetadata::new(payee_id, NoteTag::for_account(payee_id)).unwrap();
+    assert_eq!(executed_transaction.output_notes().get_note(0).metadata(), &clone_metadata);
+
+    // CONSTRUCT AND EXECUTE TX (Payee claims the next interval too early - Failure)
+    // --------------------------------------------------------------------------------------------
+    let clone_note = Note::from_details(clone_details.clone(), clone_metadata);
+    let result = execute_subscription_note(payee_account, clone_note, payee_pub_key, payee_sk_felt);
+    assert!(result.is_err());
+
+    assert!(matches!(
+        build_subscription_clone_details(&clone_details, block_num),
+        Err(NoteError::SubscriptionClaimTooEarly { .. })
+    ));
+}
+
+#[test]
+fn subscription_cancellation() {
+    // Create the payer and payee accounts
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let amount_per_interval = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let payer_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (payer_pub_key, payer_sk_felt) = get_new_key_pair_with_advice_map();
+    let payer_account = get_account_with_default_account_code(payer_id, payer_pub_key, None);
+
+    let payee_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+    let note = create_subscription_note(
+        payer_id,
+        payee_id,
+        amount_per_interval,
+        10,
+        3,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Payer cancels the subscription - Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction =
+        execute_subscription_note(payer_account.clone(), note, payer_pub_key, payer_sk_felt)
+            .unwrap();
+
+    // Check that the payer got the whole amount back and no note has been created
+    let payer_account_after = Account::new(
+        payer_id,
+        AssetVault::new(&[FungibleAsset::new(faucet_id, 300).unwrap().into()]).unwrap(),
+        payer_account.storage().clone(),
+        payer_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), payer_account_after.hash());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+}
+
+#[test]
+fn subscription_final_claim() {
+    // Create the payer and payee accounts
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let amount_per_interval = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let payer_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let payee_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (payee_pub_key, payee_sk_felt) = get_new_key_pair_with_advice_map();
+    let payee_account = get_account_with_default_account_code(payee_id, payee_pub_key, None);
+
+    // the note carries the amount of a single interval
+    let note = create_subscription_note(
+        payer_id,
+        payee_id,
+        amount_per_interval,
+        10,
+        1,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Payee claims the last interval - Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = execute_subscription_note(
+        payee_account.clone(),
+        note.clone(),
+        payee_pub_key,
+        payee_sk_felt,
+    )
+    .unwrap();
+
+    // Check that the payee received the amount and no clone note has been created
+    let payee_account_after = Account::new(
+        payee_id,
+        AssetVault::new(&[amount_per_interval.into()]).unwrap(),
+        payee_account.storage().clone(),
+        payee_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), payee_account_after.hash());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+
+    let block_num = executed_transaction.block_header().block_num();
+    assert_eq!(build_subscription_clone_details(note.details(), block_num), Ok(None));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes a transaction consuming the specified note against the specified account.
+fn execute_subscription_note(
+    account: Account,
+    note: Note,
+    pub_key: Word,
+    sk_felt: Vec<Felt>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let account_id = account.id();
+    let data_store = MockDataStore::with_existing(Some(account), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, sk_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+}
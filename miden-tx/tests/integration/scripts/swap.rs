@@ -1,14 +1,22 @@
-use miden_lib::notes::{create_swap_note, utils::build_p2id_recipient};
+use miden_lib::{
+    accounts::wallets::{build_cancel_tx_script, build_fill_swap_tx_script},
+    notes::{
+        chain::{NoteChain, NoteChainInput, NoteChainStep},
+        create_p2id_note, create_swap_note,
+        utils::build_p2id_recipient,
+    },
+    AuthScheme,
+};
 use miden_objects::{
     accounts::{Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
     assembly::ProgramAst,
     assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
-    crypto::rand::RpoRandomCoin,
-    notes::{NoteAssets, NoteMetadata},
+    crypto::{dsa::rpo_falcon512::KeyPair, rand::RpoRandomCoin, utils::Serializable},
+    notes::{Note, NoteMetadata, NoteTag, NoteType, PartialNote},
     transaction::{OutputNote, TransactionArgs},
-    Felt,
+    Felt, Word, ZERO,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{LocalChainError, LocalChainSimulator, ProposedTransaction, TransactionExecutor};
 use mock::constants::{
     ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
     ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
@@ -37,7 +45,10 @@ fn prove_swap_script() {
 
     let target_account_id =
         AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
-    let (target_pub_key, target_sk_felt) = get_new_key_pair_with_advice_map();
+    let key_pair = KeyPair::new().unwrap();
+    let target_pub_key: Word = key_pair.public_key().into();
+    let target_sk_felt: Vec<Felt> =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect();
     let target_account = get_account_with_default_account_code(
         target_account_id,
         target_pub_key,
@@ -45,13 +56,20 @@ fn prove_swap_script() {
     );
 
     // Create the note containing the SWAP script
-    let (note, repay_serial_num) = create_swap_note(
+    // The payback note is a private note carrying an aux value
+    let aux = Felt::new(27);
+    let (note, payback_note) = create_swap_note(
         sender_account_id,
         fungible_asset,
         non_fungible_asset,
+        None,
+        NoteType::Private,
+        aux,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
     .unwrap();
+    assert_eq!(note.metadata().note_type(), NoteType::Private);
+    assert_eq!(note.metadata().aux(), aux);
 
     // CONSTRUCT AND EXECUTE TX (Success)
     // --------------------------------------------------------------------------------------------
@@ -64,11 +82,12 @@ fn prove_swap_script() {
     let block_ref = data_store.block_header.block_num();
     let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
 
-    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let tx_script_code = build_fill_swap_tx_script(&auth_scheme);
     let tx_script_target = executor
-        .compile_tx_script(tx_script_code.clone(), vec![(target_pub_key, target_sk_felt)], vec![])
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_sk_felt)], vec![])
         .unwrap();
-    let tx_args_target = TransactionArgs::new(Some(tx_script_target), None);
+    let tx_args_target = TransactionArgs::new(Some(tx_script_target.clone()), None);
 
     // Execute the transaction
     let executed_transaction = executor
@@ -93,16 +112,501 @@ fn prove_swap_script() {
     // Check if only one `Note` has been created
     assert_eq!(executed_transaction.output_notes().num_notes(), 1);
 
-    // Check if the created `Note` is what we expect
-    let recipient = build_p2id_recipient(sender_account_id, repay_serial_num).unwrap();
+    // Check if the created `Note` is what we expect; the kernel commits to the note type and the
+    // aux value of the payback note
+    let note_metadata =
+        NoteMetadata::new(target_account_id, NoteTag::for_account(sender_account_id))
+            .unwrap()
+            .with_note_type(NoteType::Private)
+            .with_aux(aux);
+    let requested_note = OutputNote::Partial(PartialNote::new(
+        payback_note.recipient(),
+        payback_note.assets().clone(),
+        note_metadata,
+    ));
+    assert_eq!(
+        payback_note.recipient(),
+        build_p2id_recipient(sender_account_id, payback_note.serial_num()).unwrap()
+    );
 
-    let note_metadata = NoteMetadata::new(target_account_id, sender_account_id.into());
+    let created_note = executed_transaction.output_notes().get_note(0);
 
-    let note_assets = NoteAssets::new(&[non_fungible_asset]).unwrap();
+    assert_eq!(created_note, &requested_note);
+    let output_notes_commitment = executed_transaction.output_notes().commitment();
 
-    let requested_note = OutputNote::new(recipient, note_assets, note_metadata);
+    // When the details of the payback note are provided as an expected output note, the full
+    // payback note is returned, so that the sender can consume it later
+    let tx_args_target = TransactionArgs::builder()
+        .tx_script(tx_script_target)
+        .expected_output_note(payback_note.clone())
+        .build()
+        .unwrap();
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args_target))
+        .unwrap();
 
-    let created_note = executed_transaction.output_notes().get_note(0);
+    let expected_note = OutputNote::Full(Note::from_details(payback_note, note_metadata));
+    assert_eq!(executed_transaction.output_notes().get_note(0), &expected_note);
+    assert_eq!(executed_transaction.output_notes().commitment(), output_notes_commitment);
+}
 
-    assert_eq!(created_note, &requested_note);
+// We test a swap-then-pay flow built with a note chain: the SWAP note must commit to the recipient
+// of the payback note precomputed by the chain, and consuming it must create exactly that note.
+#[test]
+fn prove_swap_then_pay_note_chain() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_sk_felt) = get_new_key_pair_with_advice_map();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, None);
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_sk_felt) = get_new_key_pair_with_advice_map();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(non_fungible_asset),
+    );
+
+    // Build the chain: the target consumes the SWAP note, and the sender consumes the payback note
+    let script_rng = RpoRandomCoin::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]);
+    let (swap_note, _) = create_swap_note(
+        sender_account_id,
+        fungible_asset,
+        non_fungible_asset,
+        None,
+        NoteType::Public,
+        ZERO,
+        script_rng,
+    )
+    .unwrap();
+    let script_rng = RpoRandomCoin::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]);
+    let p2id_note = create_p2id_note(
+        sender_account_id,
+        sender_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        script_rng,
+    )
+    .unwrap();
+
+    let requested_asset: Word = non_fungible_asset.into();
+    let mut swap_inputs = vec![NoteChainInput::NextRecipient];
+    swap_inputs.extend(requested_asset.iter().map(|value| NoteChainInput::Value(*value)));
+    swap_inputs.push(NoteChainInput::Value(NoteTag::for_account(sender_account_id).into()));
+    swap_inputs.push(NoteChainInput::Value(ZERO));
+    swap_inputs.push(NoteChainInput::Value(NoteType::Public.into()));
+    swap_inputs.push(NoteChainInput::Value(ZERO));
+
+    let chain = NoteChain::new(
+        sender_account_id,
+        vec![
+            NoteChainStep::new(
+                swap_note.script().clone(),
+                swap_inputs,
+                vec![fungible_asset],
+                target_account_id,
+                NoteTag::default(),
+            ),
+            NoteChainStep::new(
+                p2id_note.script().clone(),
+                vec![NoteChainInput::Value(sender_account_id.into())],
+                vec![non_fungible_asset],
+                sender_account_id,
+                NoteTag::for_account(sender_account_id),
+            ),
+        ],
+    );
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let transactions = chain.build(rng).unwrap();
+    assert_eq!(transactions.len(), 2);
+    assert_eq!(transactions[0].account_id(), target_account_id);
+    assert_eq!(transactions[1].account_id(), sender_account_id);
+    assert!(transactions[1].expected_output_note().is_none());
+
+    let payback_note = transactions[1].input_note();
+    assert_eq!(
+        payback_note.recipient(),
+        build_p2id_recipient(sender_account_id, payback_note.serial_num()).unwrap()
+    );
+
+    // EXECUTE THE SWAP STEP
+    // --------------------------------------------------------------------------------------------
+    let swap_note = transactions[0].input_note().clone();
+    let data_store = MockDataStore::with_existing(Some(target_account), Some(vec![swap_note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code.clone(), vec![(target_pub_key, target_sk_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // Check that the swap created the payback note precomputed by the chain
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(
+        Some(executed_transaction.output_notes().get_note(0).id()),
+        transactions[0].expected_output_note().map(OutputNote::id)
+    );
+
+    // EXECUTE THE PAYMENT STEP
+    // --------------------------------------------------------------------------------------------
+    let payback_note = payback_note.clone();
+    let data_store = MockDataStore::with_existing(Some(sender_account), Some(vec![payback_note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_sk_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::new(Some(tx_script), None);
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+    assert!(executed_transaction
+        .final_account()
+        .vault()
+        .has_non_fungible_asset(non_fungible_asset)
+        .unwrap());
+}
+
+// We test a SWAP note with an expiration height: before the note expires, the swap can be filled
+// by any account; once it has expired, only the sender can consume the note and reclaim the
+// offered asset.
+#[test]
+fn swap_script_expiration() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_sk_felt) = get_new_key_pair_with_advice_map();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, None);
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_sk_felt) = get_new_key_pair_with_advice_map();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(non_fungible_asset),
+    );
+
+    // Create the notes (Note: Current block height is 4)
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let (note_open, _) = create_swap_note(
+        sender_account_id,
+        fungible_asset,
+        non_fungible_asset,
+        Some(5),
+        NoteType::Public,
+        ZERO,
+        rng,
+    )
+    .unwrap();
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let (note_expired, _) = create_swap_note(
+        sender_account_id,
+        fungible_asset,
+        non_fungible_asset,
+        Some(3),
+        NoteType::Public,
+        ZERO,
+        rng,
+    )
+    .unwrap();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Target Account before expiration - Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note_open]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_target = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(target_pub_key, target_sk_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args_target = TransactionArgs::new(Some(tx_script_target), None);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args_target))
+        .unwrap();
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+
+    // CONSTRUCT AND EXECUTE TX (Target Account after expiration - Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(target_account), Some(vec![note_expired.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_target = executor
+        .compile_tx_script(tx_script_code.clone(), vec![(target_pub_key, target_sk_felt)], vec![])
+        .unwrap();
+    let tx_args_target = TransactionArgs::new(Some(tx_script_target), None);
+
+    let result =
+        executor.execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args_target));
+    assert!(result.is_err());
+
+    // CONSTRUCT AND EXECUTE TX (Sender Account after expiration - Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![note_expired]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_sender = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_sk_felt)], vec![])
+        .unwrap();
+    let tx_args_sender = TransactionArgs::new(Some(tx_script_sender), None);
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account_id, block_ref, &note_ids, Some(tx_args_sender))
+        .unwrap();
+
+    // Check that the sender reclaimed the offered asset and no note has been created
+    let sender_account_after: Account = Account::new(
+        sender_account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+}
+
+// We test the cancellation of a swap order: the sender can consume its own SWAP note at any time,
+// which returns the offered asset to the sender without creating a payback note.
+#[test]
+fn prove_swap_cancellation() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // Create sender account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let key_pair = KeyPair::new().unwrap();
+    let sender_pub_key: Word = key_pair.public_key().into();
+    let sender_sk_felt: Vec<Felt> =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, None);
+
+    // Create the note containing the SWAP script; the order has not expired yet
+    let (note, _) = create_swap_note(
+        sender_account_id,
+        fungible_asset,
+        non_fungible_asset,
+        Some(5),
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let tx_script_code = build_cancel_tx_script(&auth_scheme);
+    let tx_script_sender = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_sk_felt)], vec![])
+        .unwrap();
+    let tx_args_sender = TransactionArgs::new(Some(tx_script_sender), None);
+
+    // Execute the transaction
+    let executed_transaction = executor
+        .execute_transaction(sender_account_id, block_ref, &note_ids, Some(tx_args_sender))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // Check that the sender got the offered asset back and no note has been created
+    let sender_account_after: Account = Account::new(
+        sender_account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+}
+
+// We test the full swap flow on a local chain: the SWAP note is filled by the target account, and
+// the payback note created by the fill is consumed by the sender in the following block.
+#[test]
+fn prove_swap_round_trip_on_local_chain() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_sk_felt) = get_new_key_pair_with_advice_map();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, None);
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let key_pair = KeyPair::new().unwrap();
+    let target_pub_key: Word = key_pair.public_key().into();
+    let target_sk_felt: Vec<Felt> =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(non_fungible_asset),
+    );
+
+    // Create the note containing the SWAP script
+    let (note, payback_note) = create_swap_note(
+        sender_account_id,
+        fungible_asset,
+        non_fungible_asset,
+        None,
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // SET UP THE LOCAL CHAIN
+    // --------------------------------------------------------------------------------------------
+    let mut chain = LocalChainSimulator::new();
+    chain.add_account(sender_account, None).unwrap();
+    chain.add_account(target_account, None).unwrap();
+
+    // the payback note becomes consumable once the fill has been included in a block
+    chain.add_note(&note);
+    chain.add_note_details(payback_note.clone());
+    chain.seal_block();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let fill_script = chain
+        .executor()
+        .compile_tx_script(
+            build_fill_swap_tx_script(&auth_scheme),
+            vec![(target_pub_key, target_sk_felt)],
+            vec![],
+        )
+        .unwrap();
+    let consume_script = chain
+        .executor()
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            vec![(sender_pub_key, sender_sk_felt)],
+            vec![],
+        )
+        .unwrap();
+
+    // EXECUTE THE FILL AND THE PAYBACK CONSUMPTION
+    // --------------------------------------------------------------------------------------------
+    let transactions = [
+        ProposedTransaction::new(
+            target_account_id,
+            vec![note.id()],
+            Some(TransactionArgs::with_tx_script(fill_script)),
+        ),
+        ProposedTransaction::new(
+            sender_account_id,
+            vec![payback_note.id()],
+            Some(TransactionArgs::with_tx_script(consume_script)),
+        ),
+    ];
+    let executed_transactions = chain.execute_chain(&transactions).unwrap();
+
+    // Prove, serialize/deserialize and verify the consumption of the payback note
+    assert!(prove_and_verify_transaction(executed_transactions[1].clone()).is_ok());
+
+    // Check that the assets have been swapped and both notes have been consumed
+    let sender_account = chain.account(sender_account_id).unwrap();
+    assert_eq!(sender_account.vault().assets().collect::<Vec<_>>(), vec![non_fungible_asset]);
+    let target_account = chain.account(target_account_id).unwrap();
+    assert_eq!(target_account.vault().assets().collect::<Vec<_>>(), vec![fungible_asset]);
+
+    assert!(chain.note(note.id()).is_none());
+    assert!(chain.note(payback_note.id()).is_none());
+    assert_eq!(chain.latest_block_header().block_num(), 3);
+
+    // the notes cannot be consumed again
+    let result = chain.execute_transaction(&transactions[1]);
+    assert!(matches!(result, Err(LocalChainError::ExecuteTransactionFailed(_))));
 }
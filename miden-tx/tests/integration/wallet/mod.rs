@@ -1,11 +1,42 @@
-use miden_lib::{accounts::wallets::create_basic_wallet, AuthScheme};
+use miden_lib::{
+    accounts::{
+        build_code_update_tx,
+        components::{auth_component, basic_wallet_component},
+        compute_code_update_message,
+        profile::AccountProfile,
+        wallets::{
+            build_get_balance_tx_script, build_get_public_key_tx_script,
+            build_public_key_foreign_inputs, build_read_public_key_tx_script,
+            build_rotate_key_tx_script, build_set_session_key_tx_script,
+            compute_rotate_key_message, compute_set_session_key_message, create_basic_wallet,
+            create_basic_wallet_with_initial_state, get_balance_from_output,
+            get_public_key_from_output, SpendingLimit, WalletInitialState, OWNER_PUBLIC_KEY_SLOT,
+            PUBLIC_KEY_SLOT,
+        },
+    },
+    build_multisig_signers_entry, build_threshold_signers_entry,
+    notes::{create_p2id_note, scripts::WellKnownScript},
+    transaction::{
+        scripts::{build_consume_notes_script, build_send_notes_script},
+        TransactionKernel,
+    },
+    AuthScheme,
+};
 use miden_objects::{
-    accounts::{Account, AccountId, AccountStorage, StorageSlotType},
-    assembly::ProgramAst,
+    accounts::{
+        Account, AccountBuilder, AccountCode, AccountComponent, AccountId, AccountStorage,
+        AccountType, ComponentStorageSlot, StorageSlotType,
+    },
+    assembly::{ModuleAst, ProgramAst},
     assets::{Asset, AssetVault, FungibleAsset},
-    crypto::dsa::rpo_falcon512::{KeyPair, PublicKey},
+    crypto::{
+        dsa::rpo_falcon512::{KeyPair, PublicKey},
+        rand::RpoRandomCoin,
+        utils::Serializable,
+    },
+    notes::{NoteTag, NoteType},
     transaction::TransactionArgs,
-    Felt, Word, ONE, ZERO,
+    AccountError, Digest, Felt, Hasher, NoteError, Word, ONE, ZERO,
 };
 use miden_tx::TransactionExecutor;
 use mock::{
@@ -21,6 +52,18 @@ use crate::{
     get_note_with_fungible_asset_and_script, prove_and_verify_transaction, MockDataStore,
 };
 
+const PROFILE_ACCOUNT_CODE: &str = "
+    use.miden::contracts::profile
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.profile::set_display_name
+    export.profile::set_avatar_uri
+    export.profile::set_contact_key
+    export.basic_wallet::receive_asset
+    export.basic::auth_tx_rpo_falcon512
+";
+
 #[test]
 // Testing the basic Miden wallet - receiving an asset
 fn prove_receive_asset_via_wallet() {
@@ -128,6 +171,8 @@ fn prove_send_asset_via_wallet() {
 
     let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
     let tag = Felt::new(4);
+    let note_type = NoteType::Encrypted;
+    let aux = Felt::new(27);
 
     let tx_script_code = ProgramAst::parse(
         format!(
@@ -137,14 +182,17 @@ fn prove_send_asset_via_wallet() {
 
         begin
             push.{recipient}
+            push.{aux}
+            push.{note_type}
             push.{tag}
             push.{asset}
             call.wallet::send_asset drop
-            dropw dropw
+            dropw dropw drop drop
             call.auth_tx::auth_tx_rpo_falcon512
         end
         ",
             recipient = prepare_word(&recipient),
+            note_type = Felt::from(note_type),
             tag = tag,
             asset = prepare_word(&fungible_asset_1.into())
         )
@@ -164,6 +212,11 @@ fn prove_send_asset_via_wallet() {
     // Prove, serialize/deserialize and verify the transaction
     assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
 
+    // the note type and the aux value are committed to in the metadata of the created note
+    let created_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(created_note.metadata().note_type(), note_type);
+    assert_eq!(created_note.metadata().aux(), aux);
+
     // clones account info
     let sender_account_storage =
         AccountStorage::new(vec![(0, (StorageSlotType::Value { value_arity: 0 }, sender_pub_key))])
@@ -181,12 +234,160 @@ fn prove_send_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
 }
 
+#[test]
+// Testing the basic Miden wallet - sending several payments via the standard send notes script
+fn prove_send_notes_via_wallet() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset =
+        |amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let sender_pub_key: Word = key_pair.public_key().into();
+    let sender_keypair_felt =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect::<Vec<_>>();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        Some(fungible_asset(100)),
+    );
+
+    let target_1 =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN + 1).unwrap();
+    let target_2 =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN + 2).unwrap();
+    let payments = [
+        (target_1, vec![fungible_asset(30)]),
+        (target_2, vec![fungible_asset(20), fungible_asset(50)]),
+    ];
+
+    let (tx_script_code, notes) = build_send_notes_script(
+        &payments,
+        &auth_scheme,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args: TransactionArgs = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // all of the asset has been sent out of the account
+    let sender_account_after: Account = Account::new(
+        sender_account_id,
+        AssetVault::new(&[]).unwrap(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
+
+    // one P2ID note has been created for every sent asset
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 3);
+    for (i, details) in notes.iter().enumerate() {
+        assert_eq!(output_notes.get_note(i).id(), details.id());
+    }
+    assert_eq!(output_notes.get_note(2).metadata().tag(), NoteTag::for_account(target_2));
+}
+
+#[test]
+// Testing the basic Miden wallet - consuming notes via the standard consume notes script
+fn prove_consume_notes_via_wallet() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let target_pub_key: Word = key_pair.public_key().into();
+    let target_keypair_felt =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect::<Vec<_>>();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let notes = vec![note];
+
+    // notes with scripts which are not in the allowlist are rejected
+    let allowed_scripts = [WellKnownScript::P2IDR.hash()];
+    assert_eq!(
+        build_consume_notes_script(&notes, Some(&allowed_scripts), &auth_scheme).unwrap_err(),
+        NoteError::NoteScriptNotAllowed(notes[0].id(), WellKnownScript::P2ID.hash())
+    );
+
+    let allowed_scripts = [WellKnownScript::P2ID.hash(), WellKnownScript::P2IDR.hash()];
+    let tx_script_code =
+        build_consume_notes_script(&notes, Some(&allowed_scripts), &auth_scheme).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(notes));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args: TransactionArgs = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the asset of the note has been added to the account
+    let target_account_after: Account = Account::new(
+        target_account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[test]
 fn wallet_creation() {
     // we need a Falcon Public Key to create the wallet account
-
-    use miden_objects::accounts::AccountType;
     let key_pair: KeyPair = KeyPair::new().unwrap();
     let pub_key: PublicKey = key_pair.public_key();
     let auth_scheme: AuthScheme = AuthScheme::RpoFalcon512 { pub_key };
@@ -198,7 +399,7 @@ fn wallet_creation() {
     ];
 
     let (wallet, _) =
-        create_basic_wallet(init_seed, auth_scheme, AccountType::RegularAccountImmutableCode)
+        create_basic_wallet(init_seed, auth_scheme, AccountType::RegularAccountImmutableCode, None)
             .unwrap();
 
     // sender_account_id not relevant here, just to create a default account code
@@ -209,7 +410,1126 @@ fn wallet_creation() {
             .root();
 
     assert!(wallet.is_regular_account());
+    assert_eq!(wallet.account_type(), AccountType::RegularAccountImmutableCode);
     assert_eq!(wallet.code().root(), expected_code_root);
     let pub_key_word: Word = pub_key.into();
     assert_eq!(wallet.storage().get_item(0).as_elements(), pub_key_word);
+
+    // wallets with updatable code additionally expose the procedure updating the code
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key };
+    let (updatable_wallet, _) =
+        create_basic_wallet(init_seed, auth_scheme, AccountType::RegularAccountUpdatableCode, None)
+            .unwrap();
+    assert_eq!(updatable_wallet.account_type(), AccountType::RegularAccountUpdatableCode);
+    assert_eq!(updatable_wallet.code().num_procedures(), wallet.code().num_procedures() + 1);
+
+    // wallets cannot be faucets
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key };
+    assert!(matches!(
+        create_basic_wallet(init_seed, auth_scheme, AccountType::FungibleFaucet, None),
+        Err(AccountError::AccountTypeNotSupported(AccountType::FungibleFaucet))
+    ));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn wallet_creation_with_initial_state() {
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: PublicKey = key_pair.public_key();
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let initial_state = WalletInitialState {
+        storage_slots: vec![(5, value)],
+        assets: vec![fungible_asset],
+    };
+
+    let (wallet, seed) = create_basic_wallet_with_initial_state(
+        [7; 32],
+        AuthScheme::RpoFalcon512 { pub_key },
+        AccountType::RegularAccountUpdatableCode,
+        None,
+        initial_state.clone(),
+    )
+    .unwrap();
+
+    // the initial state is part of the new wallet
+    let pub_key_word: Word = pub_key.into();
+    assert!(wallet.is_new());
+    assert_eq!(wallet.storage().get_item(0), pub_key_word.into());
+    assert_eq!(wallet.storage().get_item(5), value.into());
+    assert_eq!(wallet.vault().get_balance(faucet_id), Ok(100));
+
+    // the account ID commits to the storage including the initial state
+    let id = AccountId::new(seed, wallet.code().root(), wallet.storage().root()).unwrap();
+    assert_eq!(id, wallet.id());
+
+    // the wallet without initial state has the same code but a different storage
+    let (plain_wallet, _) = create_basic_wallet(
+        [7; 32],
+        AuthScheme::RpoFalcon512 { pub_key },
+        AccountType::RegularAccountUpdatableCode,
+        None,
+    )
+    .unwrap();
+    assert_eq!(plain_wallet.code().root(), wallet.code().root());
+    assert_ne!(plain_wallet.storage().root(), wallet.storage().root());
+
+    // the slots used by the wallet interface cannot be overridden
+    let initial_state = WalletInitialState {
+        storage_slots: vec![(0, value)],
+        assets: vec![],
+    };
+    assert!(matches!(
+        create_basic_wallet_with_initial_state(
+            [7; 32],
+            AuthScheme::RpoFalcon512 { pub_key },
+            AccountType::RegularAccountUpdatableCode,
+            None,
+            initial_state,
+        ),
+        Err(AccountError::StorageSlotIsReserved(0))
+    ));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn composed_account_creation() {
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: PublicKey = key_pair.public_key();
+
+    // a custom module which exposes a counter kept in its own storage slot
+    let counter = [Felt::new(7), ZERO, ZERO, ZERO];
+    let counter_component = AccountComponent::new(
+        "
+    use.miden::account
+
+    export.get_counter
+        push.4 exec.account::get_item
+    end
+    ",
+    )
+    .with_storage_slot(4, ComponentStorageSlot::Value(counter));
+
+    let components = [
+        basic_wallet_component(None),
+        auth_component(AuthScheme::RpoFalcon512 { pub_key }, false).unwrap(),
+        counter_component,
+    ];
+    let expected_code =
+        AccountCode::from_components(&components, &TransactionKernel::assembler()).unwrap();
+
+    let (account, _) = components
+        .into_iter()
+        .fold(AccountBuilder::new(TransactionKernel::assembler()), |builder, component| {
+            builder.component(component)
+        })
+        .account_type(AccountType::RegularAccountImmutableCode)
+        .build([9; 32])
+        .unwrap();
+
+    // the composed account exposes the procedures of the basic wallet and of the custom module
+    let (wallet, _) = create_basic_wallet(
+        [9; 32],
+        AuthScheme::RpoFalcon512 { pub_key },
+        AccountType::RegularAccountImmutableCode,
+        None,
+    )
+    .unwrap();
+    assert_eq!(account.code().root(), expected_code.root());
+    assert_eq!(account.code().num_procedures(), wallet.code().num_procedures() + 1);
+    for procedure in wallet.code().procedures() {
+        assert!(account.code().has_procedure(*procedure));
+    }
+
+    // the storage of the account is initialized with the slots declared by its components
+    let pub_key_word: Word = pub_key.into();
+    assert_eq!(account.storage().get_item(0), pub_key_word.into());
+    assert_eq!(account.storage().get_item(4), counter.into());
+
+    // components cannot share storage slots
+    let clashing_component = AccountComponent::new(
+        "
+    use.miden::account
+
+    export.get_owner
+        push.0 exec.account::get_item
+    end
+    ",
+    )
+    .with_storage_slot(0, ComponentStorageSlot::Value(counter));
+    let components = [
+        auth_component(AuthScheme::RpoFalcon512 { pub_key }, false).unwrap(),
+        clashing_component,
+    ];
+    assert!(matches!(
+        AccountCode::from_components(&components, &TransactionKernel::assembler()),
+        Err(AccountError::StorageSlotCollision(0))
+    ));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+// Testing the account profile procedures - updating the profile of an account
+fn prove_update_profile_via_wallet() {
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: PublicKey = key_pair.public_key();
+    let pub_key_word: Word = pub_key.into();
+    let key_pair_felts: Vec<Felt> =
+        key_pair.to_bytes().iter().map(|byte| Felt::from(*byte)).collect();
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(PROFILE_ACCOUNT_CODE).unwrap();
+    let account_code = AccountCode::new(account_code_ast, &TransactionKernel::assembler()).unwrap();
+    let account_storage =
+        AccountStorage::new(vec![(0, (StorageSlotType::Value { value_arity: 0 }, pub_key_word))])
+            .unwrap();
+    let account =
+        Account::new(account_id, AssetVault::new(&[]).unwrap(), account_storage, account_code, ONE);
+    assert_eq!(AccountProfile::from_account(&account), AccountProfile::default());
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let contact_key = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+    let profile = AccountProfile::new("alice", "https://example.com/alice.png", contact_key);
+    let tx_script_code = profile.build_update_tx_script(&AuthScheme::RpoFalcon512 { pub_key });
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key_word, key_pair_felts)], vec![])
+        .unwrap();
+    let tx_args: TransactionArgs = TransactionArgs::with_tx_script(tx_script);
+
+    // Execute the transaction and get the witness
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let mut account_after = account.clone();
+    account_after.apply_executed_transaction(&executed_transaction).unwrap();
+
+    // the transaction cannot be applied again to the updated account
+    assert!(account_after.clone().apply_executed_transaction(&executed_transaction).is_err());
+
+    let profile_after = AccountProfile::from_account(&account_after);
+    assert_eq!(profile_after, profile);
+    assert!(profile_after.has_display_name("alice"));
+    assert!(profile_after.has_avatar_uri("https://example.com/alice.png"));
+    assert_eq!(profile_after.contact_key(), contact_key);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+// Testing the basic Miden wallet with k-of-n authentication - receiving an asset
+fn prove_receive_asset_via_multisig_wallet() {
+    let key_pairs = (0..3).map(|_| get_new_key_pair_with_advice_map()).collect::<Vec<_>>();
+    let pub_keys = key_pairs.iter().map(|(pub_key, _)| *pub_key).collect::<Vec<_>>();
+
+    // thresholds which cannot be satisfied are rejected
+    let auth_scheme = AuthScheme::RpoFalcon512Multisig { pub_keys: pub_keys.clone(), threshold: 4 };
+    assert!(matches!(
+        create_basic_wallet([7; 32], auth_scheme, AccountType::RegularAccountUpdatableCode, None),
+        Err(AccountError::MultisigInvalidThreshold { threshold: 4, num_keys: 3 })
+    ));
+
+    let auth_scheme = AuthScheme::RpoFalcon512Multisig { pub_keys: pub_keys.clone(), threshold: 2 };
+    let (wallet, _) =
+        create_basic_wallet([7; 32], auth_scheme, AccountType::RegularAccountUpdatableCode, None)
+            .unwrap();
+    assert_eq!(wallet.storage().get_item(0), [Felt::new(2), Felt::new(3), ZERO, ZERO].into());
+    assert_eq!(wallet.storage().get_item_words(1), Some(pub_keys.as_slice()));
+
+    // use the code and storage of the new wallet for an existing account
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let account = Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+    let note_script_ast = ProgramAst::parse(
+        "
+    use.miden::note
+    use.miden::contracts::wallets::basic->wallet
+
+    begin
+        dropw
+        exec.note::get_assets drop
+        mem_loadw
+        call.wallet::receive_asset
+        dropw
+    end
+    ",
+    )
+    .unwrap();
+    let note = get_note_with_fungible_asset_and_script(fungible_asset, note_script_ast);
+
+    // CONSTRUCT AND EXECUTE TX
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        call.auth_tx::auth_tx_rpo_falcon512_multisig
+    end
+    ",
+    )
+    .unwrap();
+
+    // the transaction can be simulated without any signature, and the configuration of the
+    // signers is left unchanged
+    let tx_script = executor.compile_tx_script(tx_script_code.clone(), vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let simulated_transaction = executor
+        .simulate_transaction(account.id(), block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert_eq!(simulated_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        simulated_transaction.account_delta().vault().added_assets,
+        vec![Asset::from(fungible_asset)]
+    );
+    assert!(simulated_transaction.account_delta().storage().updated_items.is_empty());
+
+    // a single signature does not satisfy the threshold
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![key_pairs[0].clone(), build_multisig_signers_entry(&pub_keys, &[0])],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    assert!(executor
+        .execute_transaction(account.id(), block_ref, &note_ids, Some(tx_args))
+        .is_err());
+
+    // signatures of the first and the last key satisfy the threshold
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code,
+            vec![
+                key_pairs[0].clone(),
+                key_pairs[2].clone(),
+                build_multisig_signers_entry(&pub_keys, &[0, 2]),
+            ],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+    let mut account_after = account.clone();
+    account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+    assert_eq!(account_after.vault().get_balance(faucet_id), Ok(100));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+// Testing the basic Miden wallet with a spending limit - sending assets
+fn prove_send_asset_via_limited_wallet() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: Word = key_pair.public_key().into();
+    let keypair_felt = key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect::<Vec<_>>();
+
+    let owner_key_pair: KeyPair = KeyPair::new().unwrap();
+    let owner_pub_key: Word = owner_key_pair.public_key().into();
+    let owner_keypair_felt = owner_key_pair
+        .to_bytes()
+        .iter()
+        .map(|a| Felt::new(*a as u64))
+        .collect::<Vec<_>>();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let spending_limit = SpendingLimit {
+        limit: FungibleAsset::new(faucet_id, 50).unwrap(),
+        owner_key: owner_key_pair.public_key(),
+    };
+    let (wallet, _) = create_basic_wallet(
+        [7; 32],
+        auth_scheme,
+        AccountType::RegularAccountUpdatableCode,
+        Some(spending_limit),
+    )
+    .unwrap();
+    assert_eq!(wallet.storage().get_item(OWNER_PUBLIC_KEY_SLOT), owner_pub_key.into());
+
+    // use the code and storage of the new wallet for an existing account
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let account = Account::new(
+        account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    // CONSTRUCT AND EXECUTE TX
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let build_tx_script = |unlock: bool, amounts: &[u64]| {
+        let unlock = if unlock {
+            "call.wallet::unlock_spending_limit"
+        } else {
+            ""
+        };
+        let send_assets = amounts
+            .iter()
+            .map(|amount| {
+                let asset: Asset = FungibleAsset::new(faucet_id, *amount).unwrap().into();
+                format!(
+                    "push.{recipient} push.0.1.4 push.{asset}
+                    call.wallet::send_asset_with_limit drop dropw dropw drop drop\n",
+                    recipient = prepare_word(&recipient),
+                    asset = prepare_word(&asset.into())
+                )
+            })
+            .collect::<String>();
+
+        ProgramAst::parse(&format!(
+            "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            {unlock}
+            {send_assets}
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        "
+        ))
+        .unwrap()
+    };
+
+    // the outflow of a transaction cannot exceed the limit
+    let tx_script = executor
+        .compile_tx_script(
+            build_tx_script(false, &[30, 30]),
+            vec![(pub_key, keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    assert!(executor
+        .execute_transaction(account.id(), block_ref, &note_ids, Some(tx_args))
+        .is_err());
+
+    // the outflow of a transaction can reach the limit
+    let tx_script = executor
+        .compile_tx_script(
+            build_tx_script(false, &[30, 20]),
+            vec![(pub_key, keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+
+    let mut account_after = account.clone();
+    account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(account_after.vault().get_balance(faucet_id), Ok(50));
+
+    // the limit cannot be lifted without the signature of the owner
+    let tx_script = executor
+        .compile_tx_script(
+            build_tx_script(true, &[60, 40]),
+            vec![(pub_key, keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    assert!(executor
+        .execute_transaction(account.id(), block_ref, &note_ids, Some(tx_args))
+        .is_err());
+
+    // the owner can lift the limit for a transaction
+    let tx_script = executor
+        .compile_tx_script(
+            build_tx_script(true, &[60, 40]),
+            vec![(pub_key, keypair_felt), (owner_pub_key, owner_keypair_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let mut account_after = account.clone();
+    account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+    assert_eq!(account_after.vault().get_balance(faucet_id), Ok(0));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+// Testing the basic Miden wallet with session key authentication
+fn prove_session_key_wallet() {
+    let root_key_pair: KeyPair = KeyPair::new().unwrap();
+    let root_pub_key: Word = root_key_pair.public_key().into();
+    let root_keypair_felt = root_key_pair
+        .to_bytes()
+        .iter()
+        .map(|a| Felt::new(*a as u64))
+        .collect::<Vec<_>>();
+
+    let session_key_pair: KeyPair = KeyPair::new().unwrap();
+    let session_pub_key: Word = session_key_pair.public_key().into();
+    let session_keypair_felt = session_key_pair
+        .to_bytes()
+        .iter()
+        .map(|a| Felt::new(*a as u64))
+        .collect::<Vec<_>>();
+
+    // use the code and storage of a new wallet for an existing account
+    let build_account = |expires_at_block: u32| {
+        let auth_scheme = AuthScheme::SessionKey {
+            root_key: root_key_pair.public_key(),
+            session_key: session_key_pair.public_key(),
+            expires_at_block,
+        };
+        let (wallet, _) = create_basic_wallet(
+            [7; 32],
+            auth_scheme,
+            AccountType::RegularAccountUpdatableCode,
+            None,
+        )
+        .unwrap();
+        assert_eq!(wallet.storage().get_item(0), root_pub_key.into());
+
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        Account::new(
+            account_id,
+            AssetVault::new(&[]).unwrap(),
+            wallet.storage().clone(),
+            wallet.code().clone(),
+            ONE,
+        )
+    };
+
+    let session_tx_script = ProgramAst::parse(
+        "
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        call.auth_tx::auth_tx_rpo_falcon512_session_key
+    end
+    ",
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (valid session key)
+    // --------------------------------------------------------------------------------------------
+    let account = build_account(10);
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    assert!(block_ref <= 10);
+
+    let tx_script = executor
+        .compile_tx_script(
+            session_tx_script.clone(),
+            vec![(session_pub_key, session_keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+    // the transaction expires with the session key
+    assert_eq!(executed_transaction.expiration_block_num(), 10);
+
+    // CONSTRUCT AND EXECUTE TX (expired session key)
+    // --------------------------------------------------------------------------------------------
+    // a session key which expires at the reference block cannot authenticate the transaction, as
+    // the transaction can only be included in a later block
+    for expires_at_block in [block_ref - 1, block_ref] {
+        let account = build_account(expires_at_block);
+        let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+        let mut executor = TransactionExecutor::new(data_store.clone());
+        executor.load_account(account.id()).unwrap();
+
+        let tx_script = executor
+            .compile_tx_script(
+                session_tx_script.clone(),
+                vec![(session_pub_key, session_keypair_felt.clone())],
+                vec![],
+            )
+            .unwrap();
+        let tx_args = TransactionArgs::with_tx_script(tx_script);
+        assert!(executor
+            .execute_transaction(account.id(), block_ref, &[], Some(tx_args))
+            .is_err());
+    }
+
+    let account = build_account(block_ref - 1);
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    // the root key can replace the expired session key
+    let new_session_key_pair: KeyPair = KeyPair::new().unwrap();
+    let tx_script = executor
+        .compile_tx_script(
+            build_set_session_key_tx_script(new_session_key_pair.public_key(), 10),
+            vec![(root_pub_key, root_keypair_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    let mut account_after = account.clone();
+    account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+
+    let new_session_pub_key: Word = new_session_key_pair.public_key().into();
+    let session_key_config = [new_session_pub_key, [Felt::new(10), ZERO, ZERO, ZERO]];
+    assert_eq!(
+        account_after.storage().get_item(1),
+        AccountStorage::compute_words_commitment(&session_key_config)
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+// Testing the basic Miden wallet with k-of-n authentication using a single on-chain commitment
+fn prove_threshold_wallet() {
+    let key_pairs = (0..3).map(|_| get_new_key_pair_with_advice_map()).collect::<Vec<_>>();
+    let pub_keys = key_pairs.iter().map(|(pub_key, _)| *pub_key).collect::<Vec<_>>();
+
+    let auth_scheme =
+        AuthScheme::RpoFalcon512Threshold { pub_keys: pub_keys.clone(), threshold: 2 };
+    let (wallet, _) =
+        create_basic_wallet([7; 32], auth_scheme, AccountType::RegularAccountUpdatableCode, None)
+            .unwrap();
+
+    // the configuration is stored as a single commitment
+    let mut config = vec![[Felt::new(2), Felt::new(3), ZERO, ZERO]];
+    config.extend_from_slice(&pub_keys);
+    assert_eq!(wallet.storage().get_item(0), AccountStorage::compute_words_commitment(&config));
+    assert_eq!(wallet.storage().get_item(1), Word::default().into());
+
+    // use the code and storage of the new wallet for an existing account
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let account = Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    // CONSTRUCT AND EXECUTE TX
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        call.auth_tx::auth_tx_rpo_falcon512_threshold
+    end
+    ",
+    )
+    .unwrap();
+
+    // a single signature does not satisfy the threshold
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![key_pairs[1].clone(), build_threshold_signers_entry(&pub_keys, 2, &[1]).unwrap()],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    assert!(executor
+        .execute_transaction(account.id(), block_ref, &[], Some(tx_args))
+        .is_err());
+
+    // signatures of the last two keys satisfy the threshold
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code,
+            vec![
+                key_pairs[1].clone(),
+                key_pairs[2].clone(),
+                build_threshold_signers_entry(&pub_keys, 2, &[1, 2]).unwrap(),
+            ],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+// Testing the rotation of the public key of the basic Miden wallet
+fn prove_rotate_key_wallet() {
+    let old_key_pair: KeyPair = KeyPair::new().unwrap();
+    let old_pub_key: Word = old_key_pair.public_key().into();
+    let old_keypair_felt =
+        old_key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect::<Vec<_>>();
+
+    let new_key_pair: KeyPair = KeyPair::new().unwrap();
+    let new_pub_key: Word = new_key_pair.public_key().into();
+    let new_keypair_felt =
+        new_key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect::<Vec<_>>();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: old_key_pair.public_key() };
+    let (wallet, _) =
+        create_basic_wallet([7; 32], auth_scheme, AccountType::RegularAccountUpdatableCode, None)
+            .unwrap();
+
+    // use the code and storage of the new wallet for an existing account
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let account = Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    // CONSTRUCT AND EXECUTE TX (rotate key)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script = executor
+        .compile_tx_script(
+            build_rotate_key_tx_script(new_key_pair.public_key()),
+            vec![(old_pub_key, old_keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let mut account_after = account.clone();
+    account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+    assert_eq!(account_after.storage().get_item(0), new_pub_key.into());
+
+    // CONSTRUCT AND EXECUTE TX (authenticate after rotation)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account_after.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_after.id()).unwrap();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+
+    // the old key can no longer authenticate transactions
+    let tx_script = executor
+        .compile_tx_script(tx_script_code.clone(), vec![(old_pub_key, old_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    assert!(executor
+        .execute_transaction(account_after.id(), block_ref, &[], Some(tx_args))
+        .is_err());
+
+    // the new key can
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(new_pub_key, new_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account_after.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(3)));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+// Testing the replacement of the code of the basic Miden wallet
+fn prove_update_code_wallet() {
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: Word = key_pair.public_key().into();
+    let keypair_felt = key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect::<Vec<_>>();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() };
+    let (wallet, _) =
+        create_basic_wallet([8; 32], auth_scheme, AccountType::RegularAccountUpdatableCode, None)
+            .unwrap();
+
+    // use the code and storage of the new wallet for an existing account
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let account = Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    // the new code can only receive assets and authenticate transactions
+    let new_code = ModuleAst::parse(
+        "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.basic_wallet::receive_asset
+    export.basic::auth_tx_rpo_falcon512
+    ",
+    )
+    .unwrap();
+    let (new_code, tx_script_code) = build_code_update_tx(new_code).unwrap();
+
+    let removed_procedures = account
+        .code()
+        .procedures()
+        .iter()
+        .filter(|procedure| !new_code.has_procedure(**procedure))
+        .copied()
+        .collect::<Vec<_>>();
+    assert!(!removed_procedures.is_empty());
+
+    // CONSTRUCT AND EXECUTE TX (update code)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, keypair_felt.clone())], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    assert_ne!(account.code().root(), new_code.root());
+    assert_eq!(executed_transaction.final_account().code_root(), new_code.root());
+
+    let mut account_after = account.clone();
+    account_after.apply_delta(executed_transaction.account_delta()).unwrap();
+    let account_after = Account::new(
+        account_after.id(),
+        account_after.vault().clone(),
+        account_after.storage().clone(),
+        new_code,
+        account_after.nonce(),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+    for procedure in removed_procedures {
+        assert!(!account_after.code().has_procedure(procedure));
+    }
+
+    // CONSTRUCT AND EXECUTE TX (invoke procedures after the update)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account_after.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_after.id()).unwrap();
+
+    // procedures of the old code can no longer be invoked
+    let tx_script = executor
+        .compile_tx_script(
+            build_rotate_key_tx_script(KeyPair::new().unwrap().public_key()),
+            vec![(pub_key, keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    assert!(executor
+        .execute_transaction(account_after.id(), block_ref, &[], Some(tx_args))
+        .is_err());
+
+    // procedures of the new code can
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, keypair_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account_after.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(3)));
+}
+
+#[test]
+// Testing that the messages authorizing key rotations and code updates are domain separated
+fn rotate_key_and_update_code_messages_are_domain_separated() {
+    let new_key_pair = KeyPair::new().unwrap();
+    let new_key_word: Word = new_key_pair.public_key().into();
+    let initial_account_hash = Digest::from([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+    // a signature authorizing a key rotation cannot authorize a code update to the same word
+    let rotate_key_message =
+        compute_rotate_key_message(new_key_pair.public_key(), initial_account_hash);
+    let update_code_message =
+        compute_code_update_message(new_key_word.into(), initial_account_hash);
+    assert_ne!(rotate_key_message, update_code_message);
+
+    // neither message matches the message of other updates signing the same word, e.g., untagged
+    let untagged_message = Hasher::merge(&[new_key_word.into(), initial_account_hash]);
+    assert_ne!(rotate_key_message, untagged_message);
+    assert_ne!(update_code_message, untagged_message);
+
+    // a signature authorizing a session key update cannot authorize any of the other updates
+    let set_session_key_message =
+        compute_set_session_key_message(new_key_pair.public_key(), 10, initial_account_hash);
+    let expiry: Word = [Felt::new(10), ZERO, ZERO, ZERO];
+    let session_config = Hasher::merge(&[new_key_word.into(), expiry.into()]);
+    let untagged_session_message = Hasher::merge(&[session_config, initial_account_hash]);
+    assert_ne!(set_session_key_message, rotate_key_message);
+    assert_ne!(set_session_key_message, update_code_message);
+    assert_ne!(set_session_key_message, untagged_session_message);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+// Testing accounts without authentication - consuming a note without any signature
+fn prove_consume_notes_via_no_auth_account() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let account_code_ast = ModuleAst::parse(
+        "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.basic_wallet::receive_asset
+    export.basic::auth_tx_no_auth
+    ",
+    )
+    .unwrap();
+    let account_code = AccountCode::new(account_code_ast, &TransactionKernel::assembler()).unwrap();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let account = Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        AccountStorage::new(vec![]).unwrap(),
+        account_code,
+        ONE,
+    );
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note = create_p2id_note(
+        sender_account_id,
+        account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        ZERO,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let notes = vec![note];
+
+    // CONSTRUCT AND EXECUTE TX (no signature is provided)
+    // --------------------------------------------------------------------------------------------
+    let tx_script_code = build_consume_notes_script(&notes, None, &AuthScheme::NoAuth).unwrap();
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(notes));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the nonce was incremented by the authentication procedure and the asset was received
+    let account_after = Account::new(
+        account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+
+    // basic wallets cannot be created without authentication
+    assert!(matches!(
+        create_basic_wallet(
+            [7; 32],
+            AuthScheme::NoAuth,
+            AccountType::RegularAccountUpdatableCode,
+            None
+        ),
+        Err(AccountError::AuthSchemeNotSupported(_))
+    ));
+}
+
+#[test]
+// Testing the read-only views of the basic Miden wallet
+fn wallet_views() {
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: PublicKey = key_pair.public_key();
+    let pub_key_word: Word = pub_key.into();
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let (wallet, _) = create_basic_wallet(
+        [7; 32],
+        AuthScheme::RpoFalcon512 { pub_key },
+        AccountType::RegularAccountImmutableCode,
+        None,
+    )
+    .unwrap();
+
+    // use the code and storage of the new wallet for an existing account holding the asset
+    let wallet = Account::new(
+        wallet.id(),
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    // CONSTRUCT AND EXECUTE TX (views of the executing wallet)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    // the views do not require authentication and do not change the state of the wallet
+    let tx_script = executor
+        .compile_tx_script(build_get_balance_tx_script(faucet_id), vec![], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(wallet.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+    let output = executed_transaction.tx_script_output().unwrap();
+    assert_eq!(get_balance_from_output(output), 100);
+    assert_eq!(executed_transaction.final_account().hash(), wallet.hash());
+
+    let tx_script = executor
+        .compile_tx_script(build_get_public_key_tx_script(), vec![], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction = executor
+        .execute_transaction(wallet.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+    let output = executed_transaction.tx_script_output().unwrap();
+    assert_eq!(get_public_key_from_output(output), pub_key_word);
+
+    // CONSTRUCT AND EXECUTE TX (public key of a foreign wallet)
+    // --------------------------------------------------------------------------------------------
+    let reader_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let reader = get_account_with_default_account_code(reader_id, [ZERO; 4], None);
+    let data_store = MockDataStore::with_existing(Some(reader), Some(vec![]))
+        .with_foreign_accounts(&[(wallet.clone(), vec![PUBLIC_KEY_SLOT])]);
+
+    // the foreign account inputs only make the public key slot readable
+    let foreign_account = &data_store.foreign_accounts[0];
+    let foreign_inputs =
+        build_public_key_foreign_inputs(&wallet, foreign_account.witness().clone()).unwrap();
+    assert_eq!(&foreign_inputs, foreign_account);
+    assert_eq!(foreign_inputs.get_item(PUBLIC_KEY_SLOT), Some(pub_key_word));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(reader_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script = executor
+        .compile_tx_script(build_read_public_key_tx_script(wallet.id()), vec![], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let executed_transaction =
+        executor.execute_transaction(reader_id, block_ref, &[], Some(tx_args)).unwrap();
+    let output = executed_transaction.tx_script_output().unwrap();
+    assert_eq!(get_public_key_from_output(output), pub_key_word);
 }
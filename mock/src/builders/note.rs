@@ -2,7 +2,7 @@ use miden_objects::{
     accounts::AccountId,
     assembly::ProgramAst,
     assets::Asset,
-    notes::{Note, NoteInclusionProof, NoteInputs, NoteScript},
+    notes::{Note, NoteInclusionProof, NoteInputs, NoteScript, NoteTag},
     utils::{
         collections::Vec,
         string::{String, ToString},
@@ -25,7 +25,7 @@ pub struct NoteBuilder {
     inputs: Vec<Felt>,
     assets: Vec<Asset>,
     serial_num: Word,
-    tag: Felt,
+    tag: NoteTag,
     code: String,
     proof: Option<NoteInclusionProof>,
 }
@@ -44,7 +44,7 @@ impl NoteBuilder {
             inputs: vec![],
             assets: vec![],
             serial_num,
-            tag: Felt::default(),
+            tag: NoteTag::default(),
             code: DEFAULT_NOTE_CODE.to_string(),
             proof: None,
         }
@@ -61,7 +61,7 @@ impl NoteBuilder {
         self
     }
 
-    pub fn tag(mut self, tag: Felt) -> Self {
+    pub fn tag(mut self, tag: NoteTag) -> Self {
         self.tag = tag;
         self
     }
@@ -1,5 +1,8 @@
 use miden_objects::{
-    accounts::{get_account_seed_single, AccountId, AccountType, SlotItem, StorageSlotType},
+    accounts::{
+        get_account_seed_single, AccountId, AccountType, SlotItem, StorageArray, StorageMap,
+        StorageSlotType,
+    },
     assets::{Asset, NonFungibleAsset, NonFungibleAssetDetails},
     Felt, FieldElement, Word, ZERO,
 };
@@ -38,6 +41,7 @@ pub const DEFAULT_ACCOUNT_CODE: &str = "
 
     export.basic_wallet::receive_asset
     export.basic_wallet::send_asset
+    export.basic_wallet::fill_swap
     export.basic_eoa::auth_tx_rpo_falcon512
 ";
 
@@ -73,11 +77,48 @@ pub fn storage_item_1() -> SlotItem {
     (STORAGE_INDEX_1, (StorageSlotType::Value { value_arity: 0 }, STORAGE_VALUE_1))
 }
 
-pub const CHILD_ROOT_PARENT_LEAF_INDEX: u8 = 10;
-pub const CHILD_SMT_DEPTH: u8 = 64;
-pub const CHILD_STORAGE_INDEX_0: u64 = 40;
-pub const CHILD_STORAGE_VALUE_0: [Felt; 4] =
-    [Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14)];
+pub const STORAGE_INDEX_2: u8 = 40;
+pub const STORAGE_MAP_KEY_0: Word =
+    [Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)];
+pub const STORAGE_MAP_VALUE_0: Word = [Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14)];
+
+pub fn storage_map_2() -> StorageMap {
+    StorageMap::with_entries([(STORAGE_MAP_KEY_0.into(), STORAGE_MAP_VALUE_0)]).unwrap()
+}
+
+pub fn storage_item_2() -> SlotItem {
+    (
+        STORAGE_INDEX_2,
+        (StorageSlotType::Map { value_arity: 0 }, storage_map_2().root().into()),
+    )
+}
+
+pub const STORAGE_INDEX_3: u8 = 50;
+pub const STORAGE_ARRAY_DEPTH_3: u8 = 4;
+pub const STORAGE_ARRAY_INDEX_0: u64 = 5;
+pub const STORAGE_ARRAY_VALUE_0: Word =
+    [Felt::new(31), Felt::new(32), Felt::new(33), Felt::new(34)];
+
+pub fn storage_array_3() -> StorageArray {
+    StorageArray::with_items(
+        STORAGE_ARRAY_DEPTH_3,
+        [(STORAGE_ARRAY_INDEX_0, STORAGE_ARRAY_VALUE_0)],
+    )
+    .unwrap()
+}
+
+pub fn storage_item_3() -> SlotItem {
+    (
+        STORAGE_INDEX_3,
+        (
+            StorageSlotType::Array {
+                depth: STORAGE_ARRAY_DEPTH_3,
+                value_arity: 0,
+            },
+            storage_array_3().root().into(),
+        ),
+    )
+}
 
 pub fn non_fungible_asset(account_id: u64) -> Asset {
     let non_fungible_asset_details = NonFungibleAssetDetails::new(
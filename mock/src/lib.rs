@@ -103,10 +103,6 @@ pub fn run_within_host<H: Host>(
 
 // TEST HELPERS
 // ================================================================================================
-pub fn consumed_note_data_ptr(note_idx: u32) -> memory::MemoryAddress {
-    memory::CONSUMED_NOTE_DATA_SECTION_OFFSET + note_idx * memory::NOTE_MEM_SIZE
-}
-
 pub fn prepare_transaction(
     tx_inputs: TransactionInputs,
     tx_args: Option<TransactionArgs>,
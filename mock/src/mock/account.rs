@@ -8,11 +8,12 @@ use miden_objects::{
 };
 
 use crate::constants::{
-    generate_account_seed, non_fungible_asset, non_fungible_asset_2, storage_item_0,
-    storage_item_1, AccountSeedType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
-    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
-    ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
-    FUNGIBLE_ASSET_AMOUNT, FUNGIBLE_FAUCET_INITIAL_BALANCE,
+    generate_account_seed, non_fungible_asset, non_fungible_asset_2, storage_array_3,
+    storage_item_0, storage_item_1, storage_item_2, storage_item_3, storage_map_2, AccountSeedType,
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, FUNGIBLE_ASSET_AMOUNT,
+    FUNGIBLE_FAUCET_INITIAL_BALANCE, STORAGE_INDEX_2, STORAGE_INDEX_3,
 };
 
 fn mock_account_vault() -> AssetVault {
@@ -39,13 +40,23 @@ fn mock_account_vault() -> AssetVault {
 
 pub fn mock_account_storage() -> AccountStorage {
     // create account storage
-    AccountStorage::new(vec![storage_item_0(), storage_item_1()]).unwrap()
+    let mut storage = AccountStorage::new(vec![
+        storage_item_0(),
+        storage_item_1(),
+        storage_item_2(),
+        storage_item_3(),
+    ])
+    .unwrap();
+    storage.set_map(STORAGE_INDEX_2, storage_map_2()).unwrap();
+    storage.set_array(STORAGE_INDEX_3, storage_array_3()).unwrap();
+    storage
 }
 
 // Constants that define the indexes of the account procedures of interest
 pub const ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX: usize = 2;
 pub const ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX: usize = 3;
 pub const ACCOUNT_PROCEDURE_SET_CODE_PROC_IDX: usize = 4;
+pub const ACCOUNT_PROCEDURE_CREATE_NOTE_PROC_IDX: usize = 5;
 
 pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
     let account_code = "\
@@ -88,13 +99,13 @@ pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
             # acct proc 5
             export.create_note
                 # apply padding
-                repeat.8
-                    push.0 movdn.9
+                repeat.10
+                    push.0 movdn.11
                 end
 
                 # create note
                 exec.tx::create_note
-                # => [ptr, 0, 0, 0, 0, 0, 0, 0, 0]
+                # => [ptr, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
             end
 
             # acct proc 6
@@ -10,20 +10,7 @@ pub fn mock_block_header(
     note_root: Option<Digest>,
     accts: &[Account],
 ) -> BlockHeader {
-    let acct_db = SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves(
-        accts
-            .iter()
-            .flat_map(|acct| {
-                if acct.is_new() {
-                    None
-                } else {
-                    let felt_id: Felt = acct.id().into();
-                    Some((felt_id.as_int(), *acct.hash()))
-                }
-            })
-            .collect::<Vec<_>>(),
-    )
-    .expect("failed to create account db");
+    let acct_db = mock_account_db(accts);
 
     let prev_hash: Digest = rand::rand_array().into();
     let chain_root: Digest = chain_root.unwrap_or(rand::rand_array().into());
@@ -46,3 +33,21 @@ pub fn mock_block_header(
         rand::rand_value(),
     )
 }
+
+/// Returns the account database containing the provided accounts; new accounts are not included.
+pub fn mock_account_db(accts: &[Account]) -> SimpleSmt<ACCOUNT_TREE_DEPTH> {
+    SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves(
+        accts
+            .iter()
+            .flat_map(|acct| {
+                if acct.is_new() {
+                    None
+                } else {
+                    let felt_id: Felt = acct.id().into();
+                    Some((felt_id.as_int(), *acct.hash()))
+                }
+            })
+            .collect::<Vec<_>>(),
+    )
+    .expect("failed to create account db")
+}
@@ -4,10 +4,11 @@ use miden_objects::{
     accounts::{Account, AccountId, AccountType, SlotItem},
     assets::Asset,
     crypto::merkle::{LeafIndex, Mmr, PartialMmr, SimpleSmt, Smt},
-    notes::{Note, NoteInclusionProof},
-    transaction::{ChainMmr, InputNote},
+    notes::{Note, NoteId, NoteInclusionProof},
+    transaction::{ChainMmr, InputNote, InputNotes, TransactionInputs},
     utils::collections::Vec,
-    BlockHeader, Digest, Felt, FieldElement, Word, ACCOUNT_TREE_DEPTH, NOTE_TREE_DEPTH,
+    BlockHeader, Digest, Felt, FieldElement, TransactionInputError, Word, ACCOUNT_TREE_DEPTH,
+    NOTE_TREE_DEPTH,
 };
 use rand::{Rng, SeedableRng};
 
@@ -73,20 +74,14 @@ impl<R: Rng> Objects<R> {
 
     /// Creates a [SimpleSmt] tree from the `notes`.
     ///
-    /// The root of the tree is a commitment to all notes created in the block. The commitment
-    /// is not for all fields of the [Note] struct, but only for note metadata + core fields of
-    /// a note (i.e., vault, inputs, script, and serial number).
+    /// The root of the tree is a commitment to all notes created in the block. The leaf of every
+    /// note is the hash of the note ID and the note metadata (see [Note::authentication_hash()]),
+    /// which is the value authenticated by the transaction kernel when the note is consumed.
     pub fn build_notes_tree(&self) -> SimpleSmt<NOTE_TREE_DEPTH> {
-        let mut entries = Vec::with_capacity(self.notes.len() * 2);
-
-        entries.extend(self.notes.iter().enumerate().map(|(index, note)| {
-            let tree_index = (index * 2) as u64;
-            (tree_index, note.id().into())
-        }));
-        entries.extend(self.notes.iter().enumerate().map(|(index, note)| {
-            let tree_index = (index * 2 + 1) as u64;
-            (tree_index, note.metadata().into())
-        }));
+        let entries = self.notes.iter().enumerate().map(|(index, note)| {
+            let tree_index = index as u64;
+            (tree_index, note.authentication_hash().into())
+        });
 
         SimpleSmt::with_leaves(entries).unwrap()
     }
@@ -175,8 +170,13 @@ pub enum Immutable {
 
 #[derive(Debug)]
 pub enum MockError {
+    AccountNotFound(AccountId),
+    BlockNotFound(u32),
     DuplicatedNullifier,
     DuplicatedNote,
+    InvalidTransactionInput(TransactionInputError),
+    NoteNotFound(NoteId),
+    NoteNotInBlock(NoteId, u32),
 }
 
 impl fmt::Display for MockError {
@@ -429,8 +429,8 @@ impl<R: Rng + SeedableRng> MockChain<R> {
     }
 
     fn check_nullifier_unknown(&self, nullifier: Digest) {
-        assert!(self.pending_objects.nullifiers.iter().any(|e| *e == nullifier));
-        assert!(self.nullifiers.get_value(&nullifier) != Smt::EMPTY_VALUE)
+        assert!(!self.pending_objects.nullifiers.iter().any(|e| *e == nullifier));
+        assert!(self.nullifiers.get_value(&nullifier) == Smt::EMPTY_VALUE)
     }
 
     // MODIFIERS
@@ -537,6 +537,49 @@ impl<R: Rng + SeedableRng> MockChain<R> {
         mmr_to_chain_mmr(&self.chain, &self.blocks)
     }
 
+    /// Get the [TransactionInputs] for executing a transaction against the account with
+    /// `account_id` at the block with `block_num`, consuming the notes with `note_ids`.
+    ///
+    /// The account is provided in its latest state, and the chain MMR of the inputs consists of all
+    /// blocks preceding the reference block. The notes must have been recorded in the reference
+    /// block or in a block preceding it.
+    pub fn transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: u32,
+        note_ids: &[NoteId],
+    ) -> Result<TransactionInputs, MockError> {
+        let (account, seed) = self
+            .objects
+            .accounts
+            .iter()
+            .find(|(account, _)| account.id() == account_id)
+            .ok_or(MockError::AccountNotFound(account_id))?;
+        let block_header =
+            *self.blocks.get(block_num as usize).ok_or(MockError::BlockNotFound(block_num))?;
+        let block_chain = mmr_to_chain_mmr(&self.chain, &self.blocks[..block_num as usize]);
+
+        let mut input_notes = Vec::with_capacity(note_ids.len());
+        for note_id in note_ids {
+            let note = self
+                .objects
+                .recorded_notes
+                .iter()
+                .find(|note| note.id() == *note_id)
+                .ok_or(MockError::NoteNotFound(*note_id))?;
+            if note.origin().is_some_and(|origin| origin.block_num > block_num) {
+                return Err(MockError::NoteNotInBlock(*note_id, block_num));
+            }
+            input_notes.push(note.clone());
+        }
+        let input_notes =
+            InputNotes::new(input_notes).map_err(MockError::InvalidTransactionInput)?;
+
+        let seed = if account.is_new() { Some(*seed) } else { None };
+        TransactionInputs::new(account.clone(), seed, block_header, block_chain, input_notes)
+            .map_err(MockError::InvalidTransactionInput)
+    }
+
     /// Get a reference to [BlockHeader] with `block_number`.
     pub fn block_header(&self, block_number: usize) -> &BlockHeader {
         &self.blocks[block_number]
@@ -654,14 +697,17 @@ pub fn mock_chain_data(consumed_notes: Vec<Note>) -> (ChainMmr, Vec<InputNote>)
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Converts the MMR into partial MMR by copying all leaves from MMR to partial MMR.
+/// Converts the MMR into partial MMR by copying the leaves of the `blocks` from MMR to partial MMR.
+///
+/// The `blocks` must be the first blocks of the chain; the partial MMR is the MMR of these blocks,
+/// which can be a prefix of the full MMR.
 fn mmr_to_chain_mmr(mmr: &Mmr, blocks: &[BlockHeader]) -> ChainMmr {
-    let num_leaves = mmr.forest();
-    let mut partial_mmr = PartialMmr::from_peaks(mmr.peaks(mmr.forest()).unwrap());
+    let num_leaves = blocks.len();
+    let mut partial_mmr = PartialMmr::from_peaks(mmr.peaks(num_leaves).unwrap());
 
     for i in 0..num_leaves {
         let node = mmr.get(i).unwrap();
-        let path = mmr.open(i, mmr.forest()).unwrap().merkle_path;
+        let path = mmr.open(i, num_leaves).unwrap().merkle_path;
         partial_mmr.track(i, node, &path).unwrap();
     }
 
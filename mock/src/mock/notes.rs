@@ -2,9 +2,9 @@ use miden_objects::{
     accounts::AccountId,
     assembly::{Assembler, ProgramAst},
     assets::{Asset, FungibleAsset},
-    notes::{Note, NoteScript},
+    notes::{Note, NoteScript, NoteTag},
     utils::collections::Vec,
-    Felt, Word, ZERO,
+    Felt, Word,
 };
 
 use crate::{
@@ -14,6 +14,7 @@ use crate::{
         ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER, CONSUMED_ASSET_1_AMOUNT,
         CONSUMED_ASSET_2_AMOUNT, CONSUMED_ASSET_3_AMOUNT,
     },
+    mock::account::{mock_account_code, ACCOUNT_PROCEDURE_CREATE_NOTE_PROC_IDX},
     utils::{prepare_assets, prepare_word},
 };
 
@@ -57,7 +58,7 @@ pub fn mock_notes(
         &[fungible_asset_1],
         SERIAL_NUM_4,
         sender,
-        ZERO,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -68,45 +69,62 @@ pub fn mock_notes(
         &[fungible_asset_2],
         SERIAL_NUM_5,
         sender,
-        ZERO,
+        NoteTag::default(),
     )
     .unwrap();
 
     const SERIAL_NUM_6: Word = [Felt::new(21), Felt::new(22), Felt::new(23), Felt::new(24)];
-    let created_note_3 =
-        Note::new(note_script, &[Felt::new(2)], &[fungible_asset_3], SERIAL_NUM_6, sender, ZERO)
-            .unwrap();
+    let created_note_3 = Note::new(
+        note_script,
+        &[Felt::new(2)],
+        &[fungible_asset_3],
+        SERIAL_NUM_6,
+        sender,
+        NoteTag::default(),
+    )
+    .unwrap();
 
     let created_notes = vec![created_note_1, created_note_2, created_note_3];
 
     // CONSUMED NOTES
     // --------------------------------------------------------------------------------------------
 
+    // MAST root of the `create_note` mock account procedure
+    let create_note_root = mock_account_code(assembler)
+        .get_procedure_by_index(ACCOUNT_PROCEDURE_CREATE_NOTE_PROC_IDX)
+        .to_hex();
+
     // create note 1 script
     let note_1_script_src = format!(
         "\
         begin
             # create note 0
             push.{created_note_0_recipient}
+            push.{created_note_0_aux}
+            push.{created_note_0_note_type}
             push.{created_note_0_tag}
             push.{created_note_0_asset}
-            # MAST root of the `create_note` mock account procedure
-            call.0xacb46cadec8d1721934827ed161b851f282f1f4b88b72391a67fed668b1a00ba
-            drop dropw dropw
+            call.{create_note_root}
+            drop dropw dropw drop drop
 
             # create note 1
             push.{created_note_1_recipient}
+            push.{created_note_1_aux}
+            push.{created_note_1_note_type}
             push.{created_note_1_tag}
             push.{created_note_1_asset}
-            # MAST root of the `create_note` mock account procedure
-            call.0xacb46cadec8d1721934827ed161b851f282f1f4b88b72391a67fed668b1a00ba
-            drop dropw dropw
+            call.{create_note_root}
+            drop dropw dropw drop drop
         end
     ",
         created_note_0_recipient = prepare_word(&created_notes[0].recipient()),
+        created_note_0_aux = created_notes[0].metadata().aux(),
+        created_note_0_note_type = Felt::from(created_notes[0].metadata().note_type()),
         created_note_0_tag = created_notes[0].metadata().tag(),
         created_note_0_asset = prepare_assets(created_notes[0].assets())[0],
         created_note_1_recipient = prepare_word(&created_notes[1].recipient()),
+        created_note_1_aux = created_notes[1].metadata().aux(),
+        created_note_1_note_type = Felt::from(created_notes[1].metadata().note_type()),
         created_note_1_tag = created_notes[1].metadata().tag(),
         created_note_1_asset = prepare_assets(created_notes[1].assets())[0],
     );
@@ -119,14 +137,17 @@ pub fn mock_notes(
         begin
             # create note 2
             push.{created_note_2_recipient}
+            push.{created_note_2_aux}
+            push.{created_note_2_note_type}
             push.{created_note_2_tag}
             push.{created_note_2_asset}
-            # MAST root of the `create_note` mock account procedure
-            call.0xacb46cadec8d1721934827ed161b851f282f1f4b88b72391a67fed668b1a00ba
-            drop dropw dropw
+            call.{create_note_root}
+            drop dropw dropw drop drop
         end
         ",
         created_note_2_recipient = prepare_word(&created_notes[2].recipient()),
+        created_note_2_aux = created_notes[2].metadata().aux(),
+        created_note_2_note_type = Felt::from(created_notes[2].metadata().note_type()),
         created_note_2_tag = created_notes[2].metadata().tag(),
         created_note_2_asset = prepare_assets(created_notes[2].assets())[0],
     );
@@ -134,10 +155,19 @@ pub fn mock_notes(
     let (note_2_script, _) = NoteScript::new(note_2_script_ast, assembler).unwrap();
 
     // Consumed Notes
+    // note 1 has more inputs than fit into a single hash permutation, so that the kernel streams
+    // them over multiple words
     const SERIAL_NUM_1: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
-    let consumed_note_1 =
-        Note::new(note_1_script, &[Felt::new(1)], &[fungible_asset_1], SERIAL_NUM_1, sender, ZERO)
-            .unwrap();
+    let note_1_inputs = (1..=20).map(Felt::new).collect::<Vec<_>>();
+    let consumed_note_1 = Note::new(
+        note_1_script,
+        &note_1_inputs,
+        &[fungible_asset_1],
+        SERIAL_NUM_1,
+        sender,
+        NoteTag::default(),
+    )
+    .unwrap();
 
     const SERIAL_NUM_2: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
     let consumed_note_2 = Note::new(
@@ -146,7 +176,7 @@ pub fn mock_notes(
         &[fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_2,
         sender,
-        ZERO,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -160,7 +190,7 @@ pub fn mock_notes(
         &[fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_3,
         sender,
-        ZERO,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -174,7 +204,7 @@ pub fn mock_notes(
         &[non_fungible_asset_2(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN)],
         SERIAL_NUM_7,
         sender,
-        ZERO,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -220,7 +250,7 @@ pub fn mock_notes(
         ],
         SERIAL_NUM_8,
         sender,
-        ZERO,
+        NoteTag::default(),
     )
     .unwrap();
 
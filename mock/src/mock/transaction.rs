@@ -1,9 +1,11 @@
 use miden_objects::{
     accounts::{Account, AccountDelta},
+    block::AccountWitness,
+    crypto::merkle::LeafIndex,
     notes::Note,
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, OutputNotes,
-        TransactionArgs, TransactionInputs, TransactionOutputs,
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes, OutputNote,
+        OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
     },
     utils::collections::Vec,
     BlockHeader, Felt, FieldElement,
@@ -16,7 +18,7 @@ use super::{
         mock_account, mock_fungible_faucet, mock_new_account, mock_non_fungible_faucet,
         MockAccountType,
     },
-    block::mock_block_header,
+    block::{mock_account_db, mock_block_header},
     chain::mock_chain_data,
     notes::{mock_notes, AssetPreservationStatus},
 };
@@ -63,6 +65,43 @@ pub fn mock_inputs_with_account_seed(
     TransactionInputs::new(account, account_seed, block_header, chain_mmr, input_notes).unwrap()
 }
 
+/// Returns mock transaction inputs which allow the transaction to read the specified storage slots
+/// of the provided foreign accounts.
+pub fn mock_inputs_with_foreign_accounts(
+    account_type: MockAccountType,
+    asset_preservation: AssetPreservationStatus,
+    foreign_accounts: &[(Account, Vec<u8>)],
+) -> TransactionInputs {
+    let tx_inputs = mock_inputs(account_type, asset_preservation);
+    let (account, account_seed, block_header, chain_mmr, input_notes) = tx_inputs.into_parts();
+
+    // rebuild the block header so that its account root commits to the foreign accounts
+    let mut accounts = vec![account.clone()];
+    accounts.extend(foreign_accounts.iter().map(|(account, _)| account.clone()));
+    let block_header = mock_block_header(
+        block_header.block_num(),
+        Some(block_header.chain_root()),
+        Some(block_header.note_root()),
+        &accounts,
+    );
+
+    // build the witnesses of the foreign accounts against the account root
+    let account_db = mock_account_db(&accounts);
+    let foreign_accounts = foreign_accounts
+        .iter()
+        .map(|(account, slots)| {
+            let path = account_db.open(&LeafIndex::from(account.id())).path;
+            let witness = AccountWitness::new(account.id(), account.hash(), path);
+            ForeignAccountInputs::from_account(account, witness, slots).unwrap()
+        })
+        .collect();
+
+    TransactionInputs::new(account, account_seed, block_header, chain_mmr, input_notes)
+        .unwrap()
+        .with_foreign_accounts(foreign_accounts)
+        .unwrap()
+}
+
 pub fn mock_inputs_with_existing(
     account_type: MockAccountType,
     asset_preservation: AssetPreservationStatus,
@@ -145,6 +184,9 @@ pub fn mock_executed_tx(asset_preservation: AssetPreservationStatus) -> Executed
     let tx_outputs = TransactionOutputs {
         account: final_account.into(),
         output_notes: OutputNotes::new(output_notes).unwrap(),
+        expiration_block_num: u32::MAX,
+        fee: None,
+        tx_script_output: None,
     };
 
     // dummy components
@@ -1,30 +1,33 @@
 use super::{
     memory::{
-        CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_NUM_ASSETS_OFFSET,
-        CREATED_NOTE_RECIPIENT_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE,
-        NUM_CREATED_NOTES_PTR,
+        created_note_data_ptr, CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET,
+        CREATED_NOTE_NUM_ASSETS_OFFSET, CREATED_NOTE_RECIPIENT_OFFSET, NUM_CREATED_NOTES_PTR,
     },
     NoteAssets, OutputNotes, Word,
 };
 
 pub fn output_notes_data_procedure(notes: &OutputNotes) -> String {
     let note_0_metadata = prepare_word(&notes.get_note(0).metadata().into());
-    let note_0_recipient = prepare_word(notes.get_note(0).recipient());
-    let note_0_assets = prepare_assets(notes.get_note(0).assets());
+    let note_0_recipient =
+        prepare_word(&notes.get_note(0).recipient().expect("note has a recipient"));
+    let note_0_assets = prepare_assets(notes.get_note(0).assets().expect("note has assets"));
     let note_0_num_assets = 1;
 
     let note_1_metadata = prepare_word(&notes.get_note(1).metadata().into());
-    let note_1_recipient = prepare_word(notes.get_note(1).recipient());
-    let note_1_assets = prepare_assets(notes.get_note(1).assets());
+    let note_1_recipient =
+        prepare_word(&notes.get_note(1).recipient().expect("note has a recipient"));
+    let note_1_assets = prepare_assets(notes.get_note(1).assets().expect("note has assets"));
     let note_1_num_assets = 1;
 
     let note_2_metadata = prepare_word(&notes.get_note(2).metadata().into());
-    let note_2_recipient = prepare_word(notes.get_note(2).recipient());
-    let note_2_assets = prepare_assets(notes.get_note(2).assets());
+    let note_2_recipient =
+        prepare_word(&notes.get_note(2).recipient().expect("note has a recipient"));
+    let note_2_assets = prepare_assets(notes.get_note(2).assets().expect("note has assets"));
     let note_2_num_assets = 1;
 
-    const NOTE_1_OFFSET: u32 = NOTE_MEM_SIZE;
-    const NOTE_2_OFFSET: u32 = NOTE_MEM_SIZE * 2;
+    const NOTE_0_PTR: u32 = created_note_data_ptr(0);
+    const NOTE_1_PTR: u32 = created_note_data_ptr(1);
+    const NOTE_2_PTR: u32 = created_note_data_ptr(2);
 
     format!(
         "
@@ -34,42 +37,42 @@ pub fn output_notes_data_procedure(notes: &OutputNotes) -> String {
 
         # populate note 0
         push.{note_0_metadata}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{CREATED_NOTE_METADATA_OFFSET} add mem_storew dropw
+        push.{NOTE_0_PTR}.{CREATED_NOTE_METADATA_OFFSET} add mem_storew dropw
 
         push.{note_0_recipient}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{CREATED_NOTE_RECIPIENT_OFFSET} add mem_storew dropw
+        push.{NOTE_0_PTR}.{CREATED_NOTE_RECIPIENT_OFFSET} add mem_storew dropw
 
         push.{note_0_num_assets}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{CREATED_NOTE_NUM_ASSETS_OFFSET} add mem_store
+        push.{NOTE_0_PTR}.{CREATED_NOTE_NUM_ASSETS_OFFSET} add mem_store
 
         push.{}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{CREATED_NOTE_ASSETS_OFFSET} add mem_storew dropw
+        push.{NOTE_0_PTR}.{CREATED_NOTE_ASSETS_OFFSET} add mem_storew dropw
 
         # populate note 1
         push.{note_1_metadata}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{NOTE_1_OFFSET}.{CREATED_NOTE_METADATA_OFFSET} add add mem_storew dropw
+        push.{NOTE_1_PTR}.{CREATED_NOTE_METADATA_OFFSET} add mem_storew dropw
 
         push.{note_1_recipient}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{NOTE_1_OFFSET}.{CREATED_NOTE_RECIPIENT_OFFSET} add add mem_storew dropw
+        push.{NOTE_1_PTR}.{CREATED_NOTE_RECIPIENT_OFFSET} add mem_storew dropw
 
         push.{note_1_num_assets}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{NOTE_1_OFFSET}.{CREATED_NOTE_NUM_ASSETS_OFFSET} add add mem_store
+        push.{NOTE_1_PTR}.{CREATED_NOTE_NUM_ASSETS_OFFSET} add mem_store
 
         push.{}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{NOTE_1_OFFSET}.{CREATED_NOTE_ASSETS_OFFSET} add add mem_storew dropw
+        push.{NOTE_1_PTR}.{CREATED_NOTE_ASSETS_OFFSET} add mem_storew dropw
 
         # populate note 2
         push.{note_2_metadata}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{NOTE_2_OFFSET}.{CREATED_NOTE_METADATA_OFFSET} add add mem_storew dropw
+        push.{NOTE_2_PTR}.{CREATED_NOTE_METADATA_OFFSET} add mem_storew dropw
 
         push.{note_2_recipient}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{NOTE_2_OFFSET}.{CREATED_NOTE_RECIPIENT_OFFSET} add add mem_storew dropw
+        push.{NOTE_2_PTR}.{CREATED_NOTE_RECIPIENT_OFFSET} add mem_storew dropw
 
         push.{note_2_num_assets}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{NOTE_2_OFFSET}.{CREATED_NOTE_NUM_ASSETS_OFFSET} add add mem_store
+        push.{NOTE_2_PTR}.{CREATED_NOTE_NUM_ASSETS_OFFSET} add mem_store
 
         push.{}
-        push.{CREATED_NOTE_SECTION_OFFSET}.{NOTE_2_OFFSET}.{CREATED_NOTE_ASSETS_OFFSET} add add mem_storew dropw
+        push.{NOTE_2_PTR}.{CREATED_NOTE_ASSETS_OFFSET} add mem_storew dropw
 
         # set num created notes
         push.{}.{NUM_CREATED_NOTES_PTR} mem_store
@@ -1,10 +1,16 @@
-use core::fmt;
+use core::{fmt, ops::ControlFlow, str::FromStr};
 
+#[cfg(feature = "concurrent")]
+use super::AccountSeedSearch;
 use super::{
-    get_account_seed, Account, AccountError, ByteReader, Deserializable, DeserializationError,
-    Digest, Felt, FieldElement, Hasher, Serializable, String, ToString, Vec, Word,
+    get_account_seed, get_account_seed_with, Account, AccountError, ByteReader, Deserializable,
+    DeserializationError, Digest, Felt, FieldElement, Hasher, Serializable, String, ToString, Vec,
+    Word,
+};
+use crate::{
+    bech32_encoding, crypto::merkle::LeafIndex, utils::hex_to_bytes, ParseAccountIdError,
+    ACCOUNT_TREE_DEPTH,
 };
-use crate::{crypto::merkle::LeafIndex, utils::hex_to_bytes, ACCOUNT_TREE_DEPTH};
 
 // ACCOUNT ID
 // ================================================================================================
@@ -19,6 +25,16 @@ pub enum AccountType {
     RegularAccountUpdatableCode,
 }
 
+/// Specifies where the data of an account is stored.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountStorageMode {
+    /// Full account data is stored on-chain.
+    OnChain,
+    /// Only the account hash is stored on-chain.
+    OffChain,
+}
+
 /// Unique identifier of an account.
 ///
 /// Account ID consists of 1 field element (~64 bits). This field element uniquely identifies a
@@ -64,6 +80,9 @@ impl AccountId {
     /// Specifies a minimum number of ones for a valid account ID.
     pub const MIN_ACCOUNT_ONES: u32 = 5;
 
+    /// The human-readable part of the bech32m encoding of account IDs.
+    pub const BECH32_HRP: &'static str = "macc";
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -149,6 +168,15 @@ impl AccountId {
         self.0.as_int() >> 61 & Self::ON_CHAIN_ACCOUNT_SELECTOR == 1
     }
 
+    /// Returns the storage mode of an account with this ID.
+    pub fn storage_mode(&self) -> AccountStorageMode {
+        if self.is_on_chain() {
+            AccountStorageMode::OnChain
+        } else {
+            AccountStorageMode::OffChain
+        }
+    }
+
     /// Finds and returns a seed suitable for creating an account ID for the specified account type
     /// using the provided initial seed as a starting point.
     pub fn get_account_seed(
@@ -161,6 +189,61 @@ impl AccountId {
         get_account_seed(init_seed, account_type, on_chain, code_root, storage_root)
     }
 
+    /// Finds and returns a seed suitable for creating an account ID for the specified account type
+    /// using the provided initial seed as a starting point, reporting progress via the provided
+    /// callback.
+    ///
+    /// The callback is invoked periodically with the number of hashes computed so far, and the
+    /// search is aborted if it returns [ControlFlow::Break]. See [get_account_seed_with()] for
+    /// details.
+    ///
+    /// # Errors
+    /// Returns an error if the search was aborted by the callback.
+    pub fn get_account_seed_with<F>(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        on_chain: bool,
+        code_root: Digest,
+        storage_root: Digest,
+        on_progress: F,
+    ) -> Result<Word, AccountError>
+    where
+        F: FnMut(u64) -> ControlFlow<()>,
+    {
+        get_account_seed_with(
+            init_seed,
+            account_type,
+            on_chain,
+            code_root,
+            storage_root,
+            on_progress,
+        )
+    }
+
+    /// Starts a search for a seed suitable for creating an account ID for the specified account
+    /// type on the specified number of background threads, and returns a handle to the search.
+    ///
+    /// The search can be awaited via [AccountSeedSearch::wait()], polled via
+    /// [AccountSeedSearch::try_result()], and cancelled via [AccountSeedSearch::cancel()].
+    #[cfg(feature = "concurrent")]
+    pub fn start_account_seed_search(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        on_chain: bool,
+        code_root: Digest,
+        storage_root: Digest,
+        num_threads: usize,
+    ) -> AccountSeedSearch {
+        AccountSeedSearch::start(
+            init_seed,
+            account_type,
+            on_chain,
+            code_root,
+            storage_root,
+            num_threads,
+        )
+    }
+
     /// Returns an error if:
     /// - There are fewer then:
     ///     - 24 trailing ZEROs in the last element of the seed digest for regular accounts.
@@ -223,6 +306,39 @@ impl AccountId {
     pub fn to_hex(&self) -> String {
         format!("0x{:02x}", self.0.as_int())
     }
+
+    /// Returns the bech32m encoding of this account ID with the specified human-readable part.
+    ///
+    /// The encoded data is the big-endian byte representation of the account ID. The
+    /// [Display](fmt::Display) implementation of [AccountId] uses [AccountId::BECH32_HRP] as the
+    /// human-readable part.
+    ///
+    /// # Errors
+    /// Returns an error if the human-readable part is not valid.
+    pub fn to_bech32(&self, hrp: &str) -> Result<String, AccountError> {
+        bech32_encoding::encode(hrp, &self.0.as_int().to_be_bytes())
+            .map_err(AccountError::Bech32ParseError)
+    }
+
+    /// Creates an account ID from its bech32m encoding with the specified human-readable part.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not a valid bech32m string with the specified human-readable part.
+    /// - The encoded data is not a valid account ID.
+    pub fn from_bech32(value: &str, hrp: &str) -> Result<AccountId, AccountError> {
+        let bytes: [u8; 8] = bech32_encoding::decode(hrp, value)
+            .map_err(AccountError::Bech32ParseError)?
+            .try_into()
+            .map_err(|bytes: Vec<u8>| {
+                AccountError::Bech32ParseError(format!(
+                    "expected 8 bytes of data, found {}",
+                    bytes.len()
+                ))
+            })?;
+
+        AccountId::try_from(u64::from_be_bytes(bytes))
+    }
 }
 
 impl PartialOrd for AccountId {
@@ -237,9 +353,64 @@ impl Ord for AccountId {
     }
 }
 
+/// Formats the account ID as its bech32m encoding with [AccountId::BECH32_HRP] as the
+/// human-readable part, or, if the alternate flag is specified (i.e., `{:#}`), as a 0x-prefixed
+/// hex string of 16 digits.
 impl fmt::Display for AccountId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "0x{:02x}", self.0.as_int())
+        if f.alternate() {
+            return write!(f, "{:#018x}", self.0.as_int());
+        }
+
+        let encoded = self.to_bech32(Self::BECH32_HRP).map_err(|_| fmt::Error)?;
+        write!(f, "{encoded}")
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = ParseAccountIdError;
+
+    /// Parses an account ID from either its bech32m encoding with [AccountId::BECH32_HRP] as the
+    /// human-readable part, or its hex encoding (if the string starts with "0x").
+    ///
+    /// Unlike [AccountId::from_hex()], the hex encoding may omit leading zeros.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() {
+            return Err(ParseAccountIdError::EmptyString);
+        }
+
+        let raw = match value.strip_prefix("0x") {
+            Some(digits) => {
+                if digits.is_empty()
+                    || digits.len() > 16
+                    || !digits.bytes().all(|b| b.is_ascii_hexdigit())
+                {
+                    return Err(ParseAccountIdError::InvalidHex(value.to_string()));
+                }
+                u64::from_str_radix(digits, 16)
+                    .map_err(|_| ParseAccountIdError::InvalidHex(value.to_string()))?
+            },
+            None => {
+                let bytes = bech32_encoding::decode(Self::BECH32_HRP, value)
+                    .map_err(ParseAccountIdError::InvalidBech32)?;
+                let bytes: [u8; 8] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+                    ParseAccountIdError::InvalidLength { expected: 8, actual: bytes.len() }
+                })?;
+                u64::from_be_bytes(bytes)
+            },
+        };
+
+        let element = parse_felt(&raw.to_le_bytes())
+            .map_err(|_| ParseAccountIdError::NotAFieldElement(raw))?;
+        let num_ones = raw.count_ones();
+        if num_ones < Self::MIN_ACCOUNT_ONES {
+            return Err(ParseAccountIdError::TooFewOnes {
+                min: Self::MIN_ACCOUNT_ONES,
+                actual: num_ones,
+            });
+        }
+
+        Ok(Self(element))
     }
 }
 
@@ -366,7 +537,7 @@ mod tests {
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
-        AccountId, AccountType,
+        AccountError, AccountId, AccountStorageMode, AccountType, ParseAccountIdError, ToString,
     };
 
     #[test]
@@ -377,6 +548,23 @@ mod tests {
         assert_eq!(account_id.to_hex(), account_id_hex);
     }
 
+    #[test]
+    fn test_bech32_and_back() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let encoded = account_id.to_string();
+        assert!(encoded.starts_with("macc1"));
+        assert_eq!(encoded.parse::<AccountId>().unwrap(), account_id);
+        assert_eq!(account_id.to_hex().parse::<AccountId>().unwrap(), account_id);
+
+        let encoded = account_id.to_bech32("mtst").unwrap();
+        assert_eq!(AccountId::from_bech32(&encoded, "mtst").unwrap(), account_id);
+        assert!(matches!(
+            AccountId::from_bech32(&encoded, AccountId::BECH32_HRP),
+            Err(AccountError::Bech32ParseError(_))
+        ));
+    }
+
     #[test]
     fn test_account_tag_identifiers() {
         let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
@@ -402,5 +590,42 @@ mod tests {
         assert!(account_id.is_faucet());
         assert_eq!(account_id.account_type(), AccountType::NonFungibleFaucet);
         assert!(!account_id.is_on_chain());
+        assert_eq!(account_id.storage_mode(), AccountStorageMode::OffChain);
+    }
+
+    #[test]
+    fn test_display_and_parse() {
+        let account_id = AccountId::try_from(0b11111_u64).expect("Valid account ID");
+        assert_eq!(account_id.storage_mode(), AccountStorageMode::OffChain);
+
+        // the alternate form is a 0x-prefixed hex string with leading zeros
+        assert_eq!(format!("{account_id:#}"), "0x000000000000001f");
+        assert_eq!("0x000000000000001f".parse::<AccountId>().unwrap(), account_id);
+        assert_eq!(account_id.to_hex().parse::<AccountId>().unwrap(), account_id);
+
+        assert_eq!("".parse::<AccountId>(), Err(ParseAccountIdError::EmptyString));
+        assert_eq!(
+            "0xzz".parse::<AccountId>(),
+            Err(ParseAccountIdError::InvalidHex("0xzz".to_string()))
+        );
+        assert_eq!(
+            "0x10000000000000000".parse::<AccountId>(),
+            Err(ParseAccountIdError::InvalidHex("0x10000000000000000".to_string()))
+        );
+        assert_eq!(
+            "0xffffffffffffffff".parse::<AccountId>(),
+            Err(ParseAccountIdError::NotAFieldElement(u64::MAX))
+        );
+        assert_eq!(
+            "0x7".parse::<AccountId>(),
+            Err(ParseAccountIdError::TooFewOnes {
+                min: AccountId::MIN_ACCOUNT_ONES,
+                actual: 3
+            })
+        );
+        assert!(matches!(
+            "macc1invalid".parse::<AccountId>(),
+            Err(ParseAccountIdError::InvalidBech32(_))
+        ));
     }
 }
@@ -0,0 +1,307 @@
+use super::{
+    component::merge_components, Account, AccountCode, AccountComponent, AccountError, AccountId,
+    AccountStorage, AccountType, Assembler, BTreeMap, ComponentStorageSlot, ModuleAst, SlotItem,
+    StorageMap, StorageSlot, StorageSlotType, Vec, Word, ZERO,
+};
+use crate::assets::{Asset, AssetVault};
+
+// ACCOUNT BUILDER
+// ================================================================================================
+
+/// A builder for new accounts.
+///
+/// The builder collects the code, the initial storage and the initial assets of an account, and
+/// grinds a seed for the account ID once all components are known (the account ID commits to the
+/// code root and the storage root of the account). By default, the builder creates off-chain
+/// regular accounts with updatable code.
+///
+/// The code of the account is either set as a whole via [AccountBuilder::code()], or composed of
+/// [AccountComponent]s added via [AccountBuilder::component()], in which case the storage slots
+/// declared by the components are initialized as well.
+///
+/// # Example
+/// ```ignore
+/// let (account, seed) = AccountBuilder::new(assembler)
+///     .code(module)
+///     .storage_slot(0, pub_key)
+///     .account_type(AccountType::RegularAccountImmutableCode)
+///     .build(init_seed)?;
+/// ```
+pub struct AccountBuilder {
+    assembler: Assembler,
+    code: Option<ModuleAst>,
+    components: Vec<AccountComponent>,
+    storage_slots: BTreeMap<u8, StorageSlot>,
+    storage_words: BTreeMap<u8, Vec<Word>>,
+    storage_maps: BTreeMap<u8, StorageMap>,
+    assets: Vec<Asset>,
+    account_type: AccountType,
+    on_chain: bool,
+}
+
+impl AccountBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountBuilder] which compiles the account code using the provided
+    /// assembler.
+    pub fn new(assembler: Assembler) -> Self {
+        Self {
+            assembler,
+            code: None,
+            components: Vec::new(),
+            storage_slots: BTreeMap::new(),
+            storage_words: BTreeMap::new(),
+            storage_maps: BTreeMap::new(),
+            assets: Vec::new(),
+            account_type: AccountType::RegularAccountUpdatableCode,
+            on_chain: false,
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the code of the account.
+    ///
+    /// Setting the code overrides the components previously added to the account.
+    pub fn code(mut self, module: ModuleAst) -> Self {
+        self.components.clear();
+        self.code = Some(module);
+        self
+    }
+
+    /// Adds a component to the account.
+    ///
+    /// The code of the account is composed of all added components (see
+    /// [AccountCode::from_components()]), and the storage slots declared by the components are
+    /// initialized with the values declared by the components. Adding a component overrides the
+    /// code previously set via [AccountBuilder::code()].
+    pub fn component(mut self, component: AccountComponent) -> Self {
+        self.code = None;
+        self.components.push(component);
+        self
+    }
+
+    /// Sets the value of the storage slot at the specified index. The slot is a simple value slot.
+    ///
+    /// Setting the same slot more than once overrides the previously set value.
+    pub fn storage_slot(self, index: u8, value: Word) -> Self {
+        self.storage_slot_with_type(index, StorageSlotType::Value { value_arity: 0 }, value)
+    }
+
+    /// Sets the type and the value of the storage slot at the specified index.
+    ///
+    /// This can be used to initialize map and array slots, in which case the value is the root of
+    /// the underlying data structure. Setting the same slot more than once overrides the
+    /// previously set value.
+    pub fn storage_slot_with_type(
+        mut self,
+        index: u8,
+        slot_type: StorageSlotType,
+        value: Word,
+    ) -> Self {
+        self.storage_words.remove(&index);
+        self.storage_maps.remove(&index);
+        self.storage_slots.insert(index, (slot_type, value));
+        self
+    }
+
+    /// Sets a multi-word value at the storage slot at the specified index.
+    ///
+    /// The value arity of the slot is defined by the number of words, and the slot is set to the
+    /// commitment to the words (see [AccountStorage::set_item_words()]).
+    pub fn storage_slot_words(mut self, index: u8, words: Vec<Word>) -> Self {
+        let value_arity = words.len().min(u8::MAX as usize) as u8;
+        let commitment = AccountStorage::compute_words_commitment(&words);
+        self.storage_maps.remove(&index);
+        self.storage_slots
+            .insert(index, (StorageSlotType::Value { value_arity }, commitment.into()));
+        self.storage_words.insert(index, words);
+        self
+    }
+
+    /// Sets a map at the storage slot at the specified index.
+    ///
+    /// The slot is a map slot set to the root of the map, and the entries of the map are kept in
+    /// the storage of the account (see [AccountStorage::set_map()]).
+    pub fn storage_map(mut self, index: u8, map: StorageMap) -> Self {
+        self.storage_words.remove(&index);
+        self.storage_slots
+            .insert(index, (StorageSlotType::Map { value_arity: 0 }, map.root().into()));
+        self.storage_maps.insert(index, map);
+        self
+    }
+
+    /// Adds an asset to the initial vault of the account.
+    ///
+    /// Note that the transaction kernel expects new accounts to have an empty vault; accounts with
+    /// initial assets are meant to be used for genesis state and testing.
+    pub fn asset(mut self, asset: Asset) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    /// Sets the type of the account.
+    pub fn account_type(mut self, account_type: AccountType) -> Self {
+        self.account_type = account_type;
+        self
+    }
+
+    /// Sets whether the account is an on-chain account.
+    pub fn on_chain(mut self, on_chain: bool) -> Self {
+        self.on_chain = on_chain;
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the account and returns it together with the seed of its ID.
+    ///
+    /// The seed is found by grinding, using the provided initial seed as a starting point. The
+    /// returned account is new (i.e., its nonce is set to ZERO).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The code of the account was not set, or it could not be compiled.
+    /// - A storage slot is declared by more than one component, or is declared by a component and
+    ///   set via the builder.
+    /// - The storage slots are invalid (e.g., a reserved slot was set).
+    /// - The assets could not be added to the vault (e.g., an asset was added twice).
+    pub fn build(mut self, init_seed: [u8; 32]) -> Result<(Account, Word), AccountError> {
+        let module = match self.code.take() {
+            Some(module) => module,
+            None if self.components.is_empty() => {
+                return Err(AccountError::AccountBuilderCodeNotSet)
+            },
+            None => {
+                let (module, component_slots) = merge_components(&self.components)?;
+                for (index, slot) in component_slots {
+                    if self.storage_slots.contains_key(&index) {
+                        return Err(AccountError::StorageSlotCollision(index));
+                    }
+                    self = match slot {
+                        ComponentStorageSlot::Value(value) => self.storage_slot(index, value),
+                        ComponentStorageSlot::Words(words) => self.storage_slot_words(index, words),
+                        ComponentStorageSlot::Map(map) => self.storage_map(index, map),
+                    };
+                }
+                module
+            },
+        };
+        let code = AccountCode::new(module, &self.assembler)?;
+
+        let slot_items: Vec<SlotItem> = self.storage_slots.into_iter().collect();
+        let mut storage = AccountStorage::new(slot_items)?;
+        for (index, words) in self.storage_words {
+            storage.set_item_words(index, words)?;
+        }
+        for (index, map) in self.storage_maps {
+            storage.set_map(index, map)?;
+        }
+
+        let vault = AssetVault::new(&self.assets).map_err(AccountError::AssetVaultUpdateError)?;
+
+        let seed = AccountId::get_account_seed(
+            init_seed,
+            self.account_type,
+            self.on_chain,
+            code.root(),
+            storage.root(),
+        )?;
+        let id = AccountId::new(seed, code.root(), storage.root())?;
+
+        Ok((Account::new(id, vault, storage, code, ZERO), seed))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccountBuilder, AccountComponent, AccountError, AccountId, AccountType, Assembler,
+        ComponentStorageSlot, ModuleAst, StorageMap,
+    };
+    use crate::{Digest, Felt, ZERO};
+
+    #[test]
+    fn account_builder_builds_new_account() {
+        let source = "
+            export.foo
+                push.1 push.2 mul
+            end
+        ";
+        let module = ModuleAst::parse(source).unwrap();
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let words = vec![value, [ZERO; 4], value];
+        let map = StorageMap::with_entries([(Digest::from(value), value)]).unwrap();
+
+        let (account, seed) = AccountBuilder::new(Assembler::default())
+            .code(module)
+            .storage_slot(0, value)
+            .storage_slot_words(1, words.clone())
+            .storage_map(2, map.clone())
+            .account_type(AccountType::RegularAccountImmutableCode)
+            .on_chain(true)
+            .build([3; 32])
+            .unwrap();
+
+        assert!(account.is_new());
+        assert!(account.is_on_chain());
+        assert_eq!(account.account_type(), AccountType::RegularAccountImmutableCode);
+        assert_eq!(account.storage().get_item(0), value.into());
+        assert_eq!(account.storage().get_item_words(1), Some(words.as_slice()));
+        assert_eq!(account.storage().get_item(2), map.root());
+        assert_eq!(account.storage().get_map_item(2, value.into()), Ok(value));
+
+        let id = AccountId::new(seed, account.code().root(), account.storage().root()).unwrap();
+        assert_eq!(id, account.id());
+
+        let result = AccountBuilder::new(Assembler::default()).build([3; 32]);
+        assert!(matches!(result, Err(AccountError::AccountBuilderCodeNotSet)));
+    }
+
+    #[test]
+    fn account_builder_builds_account_from_components() {
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let foo = AccountComponent::new(
+            "
+            export.foo
+                push.1 push.2 mul
+            end
+        ",
+        )
+        .with_storage_slot(0, ComponentStorageSlot::Value(value));
+        let bar = AccountComponent::new(
+            "
+            export.bar
+                push.3 push.4 add
+            end
+        ",
+        )
+        .with_storage_slot(1, ComponentStorageSlot::Map(StorageMap::new()));
+
+        let (account, _) = AccountBuilder::new(Assembler::default())
+            .component(foo.clone())
+            .component(bar.clone())
+            .storage_slot(2, value)
+            .build([3; 32])
+            .unwrap();
+
+        assert_eq!(account.code().num_procedures(), 2);
+        assert_eq!(account.storage().get_item(0), value.into());
+        assert_eq!(account.storage().get_item(1), StorageMap::new().root());
+        assert_eq!(account.storage().get_item(2), value.into());
+
+        // slots declared by components cannot be set via the builder
+        let result = AccountBuilder::new(Assembler::default())
+            .component(foo)
+            .component(bar)
+            .storage_slot(1, value)
+            .build([3; 32]);
+        assert!(matches!(result, Err(AccountError::StorageSlotCollision(1))));
+    }
+}
@@ -1,8 +1,9 @@
-use core::cell::OnceCell;
+use core::{cell::OnceCell, fmt};
 
 use assembly::ast::AstSerdeOptions;
 
 use super::{
+    interface::{export_signatures, AccountInterface, ProcedureDescriptor, ProcedureSignature},
     AccountError, Assembler, AssemblyContext, ByteReader, ByteWriter, Deserializable,
     DeserializationError, Digest, ModuleAst, Serializable, Vec,
 };
@@ -17,6 +18,69 @@ const MODULE_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(true);
 /// The depth of the Merkle tree that is used to commit to the account's public interface.
 pub const PROCEDURE_TREE_DEPTH: u8 = 8;
 
+// PROCEDURE ACCESS
+// ================================================================================================
+
+/// Specifies which scripts are meant to invoke a procedure of an account.
+///
+/// The access of a procedure is declared by the author of the account code, and is enforced by the
+/// transaction compiler when compiling note scripts and transaction scripts against the account
+/// (see [AccountCode::with_procedure_access()]). It is not committed to by the account code root,
+/// and thus is not enforced by the transaction kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum ProcedureAccess {
+    /// The procedure can be called by note scripts and by transaction scripts.
+    #[default]
+    NoteCallable = 0,
+    /// The procedure can only be called by transaction scripts, i.e., by the owner of the account
+    /// as part of an authenticated transaction.
+    AuthRequired = 1,
+    /// The procedure is not meant to be called by note scripts or transaction scripts.
+    Internal = 2,
+}
+
+impl ProcedureAccess {
+    /// Returns true if procedures with this access can be called by note scripts.
+    pub fn is_note_callable(&self) -> bool {
+        *self == Self::NoteCallable
+    }
+
+    /// Returns true if procedures with this access can be called by transaction scripts.
+    pub fn is_tx_script_callable(&self) -> bool {
+        *self != Self::Internal
+    }
+}
+
+impl fmt::Display for ProcedureAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoteCallable => write!(f, "note-callable"),
+            Self::AuthRequired => write!(f, "auth-required"),
+            Self::Internal => write!(f, "internal"),
+        }
+    }
+}
+
+impl Serializable for ProcedureAccess {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(*self as u8);
+    }
+}
+
+impl Deserializable for ProcedureAccess {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::NoteCallable),
+            1 => Ok(Self::AuthRequired),
+            2 => Ok(Self::Internal),
+            value => Err(DeserializationError::InvalidValue(format!(
+                "procedure access {value} is not valid"
+            ))),
+        }
+    }
+}
+
 // ACCOUNT CODE
 // ================================================================================================
 
@@ -25,10 +89,19 @@ pub const PROCEDURE_TREE_DEPTH: u8 = 8;
 /// Account's public interface consists of a set of account procedures, each procedure being a Miden
 /// VM program. Thus, MAST root of each procedure commits to the underlying program. We commit to
 /// the entire account interface by building a simple Merkle tree out of all procedure MAST roots.
+///
+/// Every procedure is annotated with a [ProcedureAccess], which restricts the scripts meant to
+/// invoke it. By default, all procedures are note-callable.
+///
+/// The names and documented stack signatures of the procedures exported by the module are
+/// retained as well, and can be obtained together with the roots and the access of the procedures
+/// via [AccountCode::interface()].
 #[derive(Debug, Clone)]
 pub struct AccountCode {
     module: ModuleAst,
     procedures: Vec<Digest>,
+    procedure_access: Vec<ProcedureAccess>,
+    procedure_signatures: Option<Vec<ProcedureSignature>>,
     procedure_tree: OnceCell<SimpleSmt<PROCEDURE_TREE_DEPTH>>,
 }
 
@@ -69,7 +142,9 @@ impl AccountCode {
 
         Ok(Self {
             procedure_tree: OnceCell::new(),
+            procedure_signatures: export_signatures(&module, procedures.len()),
             module,
+            procedure_access: vec![ProcedureAccess::default(); procedures.len()],
             procedures,
         })
     }
@@ -87,11 +162,31 @@ impl AccountCode {
         assert!(procedures.len() <= Self::MAX_NUM_PROCEDURES, "too many account procedures");
         Self {
             procedure_tree: OnceCell::new(),
+            procedure_signatures: export_signatures(&module, procedures.len()),
             module,
+            procedure_access: vec![ProcedureAccess::default(); procedures.len()],
             procedures,
         }
     }
 
+    /// Sets the access of the procedure with the specified root, and returns the updated code.
+    ///
+    /// The access does not affect the root of the account code.
+    ///
+    /// # Errors
+    /// Returns an error if the account code does not contain a procedure with the specified root.
+    pub fn with_procedure_access(
+        mut self,
+        procedure: Digest,
+        access: ProcedureAccess,
+    ) -> Result<Self, AccountError> {
+        let index = self
+            .get_procedure_index_by_root(procedure)
+            .ok_or(AccountError::AccountCodeProcedureNotFound(procedure))?;
+        self.procedure_access[index] = access;
+        Ok(self)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -139,6 +234,53 @@ impl AccountCode {
     pub fn get_procedure_index_by_root(&self, root: Digest) -> Option<usize> {
         self.procedures.iter().position(|r| r == &root)
     }
+
+    /// Returns the access of the procedure with the specified root or None if such procedure is
+    /// not defined for this account.
+    pub fn get_procedure_access(&self, root: Digest) -> Option<ProcedureAccess> {
+        self.get_procedure_index_by_root(root).map(|index| self.procedure_access[index])
+    }
+
+    /// Returns the interface of this account code, i.e., the descriptors of its procedures in
+    /// the order of their indexes.
+    ///
+    /// The descriptors contain the names and the documented stack signatures of the procedures
+    /// as exported by the module of this code. These are not available if the number of exports
+    /// of the module differs from the number of compiled procedures.
+    pub fn interface(&self) -> AccountInterface {
+        let procedures = self
+            .procedures
+            .iter()
+            .zip(self.procedure_access.iter())
+            .enumerate()
+            .map(|(index, (root, access))| {
+                let signature =
+                    self.procedure_signatures.as_ref().map(|signatures| signatures[index].clone());
+                ProcedureDescriptor::new(*root, *access, signature)
+            })
+            .collect();
+
+        AccountInterface::new(self.root(), procedures)
+    }
+
+    /// Returns an iterator over the roots of the procedures which can be called by note scripts.
+    pub fn note_callable_procedures(&self) -> impl Iterator<Item = &Digest> {
+        self.procedures
+            .iter()
+            .zip(self.procedure_access.iter())
+            .filter(|(_, access)| access.is_note_callable())
+            .map(|(procedure, _)| procedure)
+    }
+
+    /// Returns an iterator over the roots of the procedures which can be called by transaction
+    /// scripts.
+    pub fn tx_script_callable_procedures(&self) -> impl Iterator<Item = &Digest> {
+        self.procedures
+            .iter()
+            .zip(self.procedure_access.iter())
+            .filter(|(_, access)| access.is_tx_script_callable())
+            .map(|(procedure, _)| procedure)
+    }
 }
 
 // EQUALITY
@@ -147,7 +289,9 @@ impl AccountCode {
 impl PartialEq for AccountCode {
     fn eq(&self, other: &Self) -> bool {
         // TODO: consider checking equality based only on the set of procedures
-        self.module == other.module && self.procedures == other.procedures
+        self.module == other.module
+            && self.procedures == other.procedures
+            && self.procedure_access == other.procedure_access
     }
 }
 
@@ -164,6 +308,7 @@ impl Serializable for AccountCode {
         // number as a single byte - but we do have to subtract 1 to store 256 as 255.
         target.write_u8((self.procedures.len() - 1) as u8);
         target.write_many(self.procedures());
+        target.write_many(&self.procedure_access);
     }
 }
 
@@ -173,8 +318,11 @@ impl Deserializable for AccountCode {
         module.load_source_locations(source)?;
         let num_procedures = (source.read_u8()? as usize) + 1;
         let procedures = source.read_many::<Digest>(num_procedures)?;
+        let procedure_access = source.read_many::<ProcedureAccess>(num_procedures)?;
 
-        Ok(Self::from_parts(module, procedures))
+        let mut code = Self::from_parts(module, procedures);
+        code.procedure_access = procedure_access;
+        Ok(code)
     }
 }
 
@@ -204,7 +352,11 @@ fn build_procedure_tree(procedures: &[Digest]) -> SimpleSmt<PROCEDURE_TREE_DEPTH
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountCode, Assembler, Deserializable, ModuleAst, Serializable};
+    use super::{
+        AccountCode, AccountInterface, Assembler, Deserializable, ModuleAst, ProcedureAccess,
+        Serializable,
+    };
+    use crate::{AccountError, Digest};
 
     #[test]
     fn serialize_code() {
@@ -226,6 +378,109 @@ mod tests {
         // serialize and deserialize the code; make sure deserialized version matches the original
         let bytes = code1.to_bytes();
         let code2 = AccountCode::read_from_bytes(&bytes).unwrap();
-        assert_eq!(code1, code2)
+        assert_eq!(code1, code2);
+
+        // the access of the procedures is serialized as well
+        let procedure = code1.get_procedure_by_index(1);
+        let code1 = code1.with_procedure_access(procedure, ProcedureAccess::Internal).unwrap();
+        let code2 = AccountCode::read_from_bytes(&code1.to_bytes()).unwrap();
+        assert_eq!(code2.get_procedure_access(procedure), Some(ProcedureAccess::Internal));
+        assert_eq!(code1, code2);
+    }
+
+    #[test]
+    fn procedure_access() {
+        let source = "
+            export.foo
+                push.1 push.2 mul
+            end
+
+            export.bar
+                push.1 push.2 add
+            end
+
+            export.baz
+                push.1 push.2 sub
+            end
+        ";
+        let module = ModuleAst::parse(source).unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+        let [foo, bar, baz] = [0, 1, 2].map(|index| code.get_procedure_by_index(index));
+
+        // all procedures are note-callable by default
+        assert_eq!(code.get_procedure_access(foo), Some(ProcedureAccess::NoteCallable));
+        assert_eq!(code.note_callable_procedures().count(), 3);
+
+        let root = code.root();
+        let code = code
+            .with_procedure_access(bar, ProcedureAccess::AuthRequired)
+            .unwrap()
+            .with_procedure_access(baz, ProcedureAccess::Internal)
+            .unwrap();
+        assert_eq!(code.root(), root);
+        assert_eq!(code.note_callable_procedures().collect::<Vec<_>>(), vec![&foo]);
+        assert_eq!(code.tx_script_callable_procedures().collect::<Vec<_>>(), vec![&foo, &bar]);
+
+        let unknown = Digest::default();
+        assert_eq!(code.get_procedure_access(unknown), None);
+        assert!(matches!(
+            code.with_procedure_access(unknown, ProcedureAccess::Internal),
+            Err(AccountError::AccountCodeProcedureNotFound(procedure)) if procedure == unknown
+        ));
+    }
+
+    #[test]
+    fn code_interface() {
+        let source = "
+            #! Multiplies the top two elements of the stack.
+            #!
+            #! Inputs: [a, b, ...]
+            #! Outputs: [c, ...]
+            export.foo
+                mul
+            end
+
+            #! Inputs: [ASSET, tag, ...]
+            export.bar
+                drop dropw push.1
+            end
+
+            proc.helper
+                push.1 push.2 add
+            end
+
+            export.baz
+                exec.helper drop
+            end
+        ";
+        let module = ModuleAst::parse(source).unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+        let baz = code.get_procedure_by_index(2);
+        let code = code.with_procedure_access(baz, ProcedureAccess::Internal).unwrap();
+
+        let interface = code.interface();
+        assert_eq!(interface.code_root(), code.root());
+        assert_eq!(interface.procedures().len(), 3);
+
+        // exported procedures are described in the order of their indexes
+        let names: Vec<_> = interface.procedures().iter().map(|proc| proc.name()).collect();
+        assert_eq!(names, vec![Some("foo"), Some("bar"), Some("baz")]);
+
+        let foo = interface.get_by_name("foo").unwrap().signature().unwrap();
+        assert_eq!(foo.inputs(), Some("[a, b, ...]"));
+        assert_eq!((foo.num_inputs(), foo.num_outputs()), (Some(2), Some(1)));
+
+        let bar = interface.get_by_name("bar").unwrap().signature().unwrap();
+        assert_eq!((bar.num_inputs(), bar.num_outputs()), (Some(5), None));
+
+        let baz = interface.get_by_root(baz).unwrap();
+        assert_eq!(baz.access(), ProcedureAccess::Internal);
+        assert_eq!(baz.signature().unwrap().inputs(), None);
+        assert!(interface.get_by_name("helper").is_none());
+
+        // the interface survives the serialization of the code and can be serialized itself
+        let code = AccountCode::read_from_bytes(&code.to_bytes()).unwrap();
+        assert_eq!(code.interface(), interface);
+        assert_eq!(AccountInterface::read_from_bytes(&interface.to_bytes()).unwrap(), interface);
     }
 }
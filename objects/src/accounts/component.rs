@@ -0,0 +1,213 @@
+use super::{
+    AccountCode, AccountError, Assembler, BTreeMap, ModuleAst, StorageMap, String, Vec, Word,
+};
+
+// COMPONENT STORAGE SLOT
+// ================================================================================================
+
+/// The initial value of a storage slot declared by an [AccountComponent].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComponentStorageSlot {
+    /// A simple value slot (see [AccountBuilder::storage_slot()](super::AccountBuilder)).
+    Value(Word),
+    /// A multi-word value slot (see [AccountBuilder::storage_slot_words()](super::AccountBuilder)).
+    Words(Vec<Word>),
+    /// A map slot (see [AccountBuilder::storage_map()](super::AccountBuilder)).
+    Map(StorageMap),
+}
+
+// ACCOUNT COMPONENT
+// ================================================================================================
+
+/// A building block of the code and the storage of an account.
+///
+/// A component consists of MASM source code, which contributes procedures to the public interface
+/// of the account, and of the storage slots used by these procedures together with their initial
+/// values. Accounts are composed of one or more components (e.g., an authentication component, a
+/// basic wallet component and a custom module), either via [AccountCode::from_components()] or
+/// via [AccountBuilder::component()](super::AccountBuilder::component).
+///
+/// The source code of a component is a fragment of an account module: `use` statements importing
+/// the libraries the component relies on, followed by the procedures exported by the component
+/// (either defined in place or re-exported from a library). Since the fragments of all components
+/// are merged into a single module, the procedures exported by different components must have
+/// different names, and the same alias must not be used for different libraries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountComponent {
+    source: String,
+    storage_slots: BTreeMap<u8, ComponentStorageSlot>,
+}
+
+impl AccountComponent {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountComponent] with the provided source code and no storage slots.
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            storage_slots: BTreeMap::new(),
+        }
+    }
+
+    /// Declares the storage slot at the specified index as used by this component, and sets its
+    /// initial value.
+    ///
+    /// Declaring the same slot more than once overrides the previously declared value.
+    pub fn with_storage_slot(mut self, index: u8, slot: ComponentStorageSlot) -> Self {
+        self.storage_slots.insert(index, slot);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the source code of this component.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the storage slots used by this component together with their initial values.
+    pub fn storage_slots(&self) -> &BTreeMap<u8, ComponentStorageSlot> {
+        &self.storage_slots
+    }
+
+    /// Returns true if this component uses the storage slot at the specified index.
+    pub fn uses_storage_slot(&self, index: u8) -> bool {
+        self.storage_slots.contains_key(&index)
+    }
+}
+
+// ACCOUNT CODE
+// ================================================================================================
+
+impl AccountCode {
+    /// Returns the account code composed of the provided components.
+    ///
+    /// The storage slots declared by the components are not part of the account code; they are
+    /// only checked for collisions. Use [AccountBuilder::component()](super::AccountBuilder) to
+    /// build an account including the storage of its components.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No components are provided.
+    /// - Two components declare the same storage slot.
+    /// - The merged source code of the components cannot be parsed or compiled (e.g., because two
+    ///   components export procedures with the same name).
+    pub fn from_components(
+        components: &[AccountComponent],
+        assembler: &Assembler,
+    ) -> Result<Self, AccountError> {
+        let (module, _) = merge_components(components)?;
+        Self::new(module, assembler)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Merges the provided components into a single account module, and returns the module together
+/// with the storage slots declared by the components.
+///
+/// The `use` statements of all components are placed at the top of the module (statements
+/// imported by more than one component are kept only once), followed by the rest of the source
+/// code of the components in the order in which the components are provided.
+pub(super) fn merge_components(
+    components: &[AccountComponent],
+) -> Result<(ModuleAst, BTreeMap<u8, ComponentStorageSlot>), AccountError> {
+    if components.is_empty() {
+        return Err(AccountError::AccountCodeNoProcedures);
+    }
+
+    let mut storage_slots = BTreeMap::new();
+    let mut imports: Vec<&str> = Vec::new();
+    let mut body = String::new();
+    for component in components {
+        for (index, slot) in component.storage_slots() {
+            if storage_slots.insert(*index, slot.clone()).is_some() {
+                return Err(AccountError::StorageSlotCollision(*index));
+            }
+        }
+
+        for line in component.source().lines() {
+            let line = line.trim();
+            if line.starts_with("use.") {
+                if !imports.contains(&line) {
+                    imports.push(line);
+                }
+            } else {
+                body.push_str(line);
+                body.push('\n');
+            }
+        }
+    }
+
+    let mut source = imports.join("\n");
+    source.push('\n');
+    source.push_str(&body);
+
+    let module = ModuleAst::parse(&source)
+        .map_err(|err| AccountError::AccountCodeAssemblerError(err.into()))?;
+
+    Ok((module, storage_slots))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        merge_components, AccountCode, AccountComponent, AccountError, Assembler,
+        ComponentStorageSlot,
+    };
+    use crate::{Felt, ZERO};
+
+    const FOO_SOURCE: &str = "
+        export.foo
+            push.1 push.2 mul
+        end
+    ";
+
+    const BAR_SOURCE: &str = "
+        export.bar
+            push.3 push.4 add
+        end
+    ";
+
+    #[test]
+    fn account_code_from_components() {
+        let value = [Felt::new(1), ZERO, ZERO, ZERO];
+        let foo = AccountComponent::new(FOO_SOURCE)
+            .with_storage_slot(0, ComponentStorageSlot::Value(value));
+        let bar = AccountComponent::new(BAR_SOURCE)
+            .with_storage_slot(1, ComponentStorageSlot::Words(vec![value, value]));
+
+        let code = AccountCode::from_components(&[foo.clone(), bar.clone()], &Assembler::default())
+            .unwrap();
+        assert_eq!(code.num_procedures(), 2);
+
+        let (_, storage_slots) = merge_components(&[foo, bar]).unwrap();
+        assert_eq!(storage_slots.get(&0), Some(&ComponentStorageSlot::Value(value)));
+        assert_eq!(storage_slots.get(&1), Some(&ComponentStorageSlot::Words(vec![value, value])));
+    }
+
+    #[test]
+    fn account_code_from_components_rejects_collisions() {
+        let foo = AccountComponent::new(FOO_SOURCE)
+            .with_storage_slot(2, ComponentStorageSlot::Value([ZERO; 4]));
+        let bar = AccountComponent::new(BAR_SOURCE)
+            .with_storage_slot(2, ComponentStorageSlot::Value([ZERO; 4]));
+
+        let result = AccountCode::from_components(&[foo, bar], &Assembler::default());
+        assert!(matches!(result, Err(AccountError::StorageSlotCollision(2))));
+
+        // procedures exported by different components must have different names
+        let foo = AccountComponent::new(FOO_SOURCE);
+        let result = AccountCode::from_components(&[foo.clone(), foo], &Assembler::default());
+        assert!(matches!(result, Err(AccountError::AccountCodeAssemblerError(_))));
+
+        let result = AccountCode::from_components(&[], &Assembler::default());
+        assert!(matches!(result, Err(AccountError::AccountCodeNoProcedures)));
+    }
+}
@@ -1,11 +1,11 @@
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable, ToString,
-    Vec, Word, ZERO,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Serializable,
+    ToString, Vec, Word, ZERO,
 };
 use crate::{assets::Asset, AccountDeltaError};
 
 mod storage;
-pub use storage::AccountStorageDelta;
+pub use storage::{AccountStorageDelta, StorageMapDelta};
 
 mod vault;
 pub use vault::AccountVaultDelta;
@@ -155,6 +155,8 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![],
             updated_items: vec![],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
 
         let vault_delta = AccountVaultDelta {
@@ -169,6 +171,8 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![1],
             updated_items: vec![],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
 
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None).is_err());
@@ -1,6 +1,10 @@
 use super::{
-    AccountDeltaError, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
-    ToString, Vec, Word,
+    AccountDeltaError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    Serializable, ToString, Vec, Word,
+};
+use crate::{
+    accounts::{AccountStorage, StorageMap},
+    utils::{format, string::String},
 };
 
 // CONSTANTS
@@ -15,13 +19,85 @@ const MAX_MUTABLE_STORAGE_SLOT_IDX: u8 = 254;
 ///
 /// The differences are represented as follows:
 /// - item updates: represented by `cleared_items` and `updated_items` field.
+/// - map updates: represented by `updated_maps` field; for every updated map slot, the field
+///   contains the changes to the entries of the map (the new root of the map is recorded as an
+///   item update as well).
+/// - multi-word value updates: represented by `updated_words` field; for every updated multi-word
+///   value slot whose new value is known, the field contains the words of the new value (the
+///   commitment to the words is recorded as an item update as well).
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountStorageDelta {
     pub cleared_items: Vec<u8>,
     pub updated_items: Vec<(u8, Word)>,
+    pub updated_maps: Vec<(u8, StorageMapDelta)>,
+    pub updated_words: Vec<(u8, Vec<Word>)>,
 }
 
 impl AccountStorageDelta {
+    /// Returns the minimal delta which transforms the `old` storage into the `new` storage.
+    ///
+    /// Slots whose values differ are recorded as cleared items if the new value is an empty word,
+    /// and as updated items otherwise. For map slots whose entries are known in both states, the
+    /// changes to the entries of the map are recorded as well. For multi-word value slots whose
+    /// words are known in the new state, the words of the new value are recorded. The delta is
+    /// verified by applying it to the `old` storage and comparing the root of the result against
+    /// the root of the `new` storage.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The layouts of the two storages differ.
+    /// - The resulting delta is invalid.
+    /// - Applying the resulting delta to the `old` storage does not result in the `new` storage.
+    pub fn between(old: &AccountStorage, new: &AccountStorage) -> Result<Self, AccountDeltaError> {
+        if old.layout_commitment() != new.layout_commitment() {
+            return Err(AccountDeltaError::InconsistentStorageDelta(
+                "storage layouts differ".to_string(),
+            ));
+        }
+
+        let mut delta = Self::default();
+        for idx in 0..AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX {
+            let old_value = old.get_item(idx);
+            let new_value = new.get_item(idx);
+            if old_value == new_value {
+                continue;
+            }
+
+            if Word::from(new_value) == Word::default() {
+                delta.cleared_items.push(idx);
+            } else {
+                delta.updated_items.push((idx, new_value.into()));
+                if let Some(words) = new.get_item_words(idx) {
+                    delta.updated_words.push((idx, words.to_vec()));
+                }
+            }
+
+            if let (Some(old_map), Some(new_map)) = (old.get_map(idx), new.get_map(idx)) {
+                let map_delta = StorageMapDelta::between(old_map, new_map);
+                if !map_delta.is_empty() {
+                    delta.updated_maps.push((idx, map_delta));
+                }
+            }
+        }
+
+        delta.validate()?;
+
+        // make sure the delta transforms the old storage into the new one
+        let mut storage = old.clone();
+        storage
+            .apply_delta(&delta)
+            .map_err(|err| AccountDeltaError::InconsistentStorageDelta(err.to_string()))?;
+        if storage.root() != new.root() {
+            return Err(AccountDeltaError::InconsistentStorageDelta(format!(
+                "delta results in storage {} instead of {}",
+                storage.root().to_hex(),
+                new.root().to_hex()
+            )));
+        }
+
+        Ok(delta)
+    }
+
     /// Checks whether this storage delta is valid.
     ///
     /// # Errors
@@ -29,9 +105,15 @@ impl AccountStorageDelta {
     /// - The number of cleared or updated items is greater than 255.
     /// - Any of cleared or updated items are at slot 255 (i.e., immutable slot).
     /// - Any of the cleared or updated items is referenced more than once (e.g., updated twice).
+    /// - The number of updated maps is greater than 255, or any of the updated maps is at slot
+    ///   255 (i.e., immutable slot).
+    /// - Any of the updated maps is referenced more than once, or any of the map deltas is invalid.
+    /// - Any of the updated multi-word values is referenced more than once, or the commitment to
+    ///   its words is not the value of the corresponding updated item.
     pub fn validate(&self) -> Result<(), AccountDeltaError> {
         let num_cleared_items = self.cleared_items.len();
         let num_updated_items = self.updated_items.len();
+        let num_updated_maps = self.updated_maps.len();
 
         if num_cleared_items > u8::MAX as usize {
             return Err(AccountDeltaError::TooManyClearedStorageItems {
@@ -43,6 +125,11 @@ impl AccountStorageDelta {
                 actual: num_updated_items,
                 max: u8::MAX as usize,
             });
+        } else if num_updated_maps > u8::MAX as usize {
+            return Err(AccountDeltaError::TooManyUpdatedStorageMaps {
+                actual: num_updated_maps,
+                max: u8::MAX as usize,
+            });
         }
 
         // make sure cleared items vector does not contain errors
@@ -71,12 +158,38 @@ impl AccountStorageDelta {
             }
         }
 
+        // make sure updated maps vector does not contain errors
+        for (pos, (idx, map_delta)) in self.updated_maps.iter().enumerate() {
+            if *idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(AccountDeltaError::ImmutableStorageSlot(*idx as usize));
+            }
+
+            if self.updated_maps[..pos].iter().any(|x| x.0 == *idx) {
+                return Err(AccountDeltaError::DuplicateStorageMapUpdate(*idx as usize));
+            }
+
+            map_delta.validate()?;
+        }
+
+        // make sure updated multi-word values vector does not contain errors
+        for (pos, (idx, words)) in self.updated_words.iter().enumerate() {
+            if self.updated_words[..pos].iter().any(|x| x.0 == *idx) {
+                return Err(AccountDeltaError::DuplicateStorageItemUpdate(*idx as usize));
+            }
+
+            validate_words_update(&self.updated_items, *idx, words)
+                .map_err(AccountDeltaError::InconsistentStorageDelta)?;
+        }
+
         Ok(())
     }
 
     /// Returns true if storage delta contains no updates.
     pub fn is_empty(&self) -> bool {
-        self.cleared_items.is_empty() && self.updated_items.is_empty()
+        self.cleared_items.is_empty()
+            && self.updated_items.is_empty()
+            && self.updated_maps.is_empty()
+            && self.updated_words.is_empty()
     }
 }
 
@@ -94,6 +207,24 @@ impl Serializable for AccountStorageDelta {
             idx.write_into(target);
             value.write_into(target);
         }
+
+        assert!(self.updated_maps.len() <= u8::MAX as usize, "too many updated storage maps");
+        target.write_u8(self.updated_maps.len() as u8);
+        for (idx, map_delta) in self.updated_maps.iter() {
+            idx.write_into(target);
+            map_delta.write_into(target);
+        }
+
+        assert!(self.updated_words.len() <= u8::MAX as usize, "too many updated storage values");
+        target.write_u8(self.updated_words.len() as u8);
+        for (idx, words) in self.updated_words.iter() {
+            idx.write_into(target);
+            assert!(words.len() <= u8::MAX as usize, "too many words in storage value");
+            target.write_u8(words.len() as u8);
+            for word in words.iter() {
+                word.write_into(target);
+            }
+        }
     }
 }
 
@@ -153,7 +284,181 @@ impl Deserializable for AccountStorageDelta {
             updated_items.push((idx, value));
         }
 
-        Ok(Self { cleared_items, updated_items })
+        // deserialize and validate updated maps
+        let num_updated_maps = source.read_u8()? as usize;
+        let mut updated_maps: Vec<(u8, StorageMapDelta)> = Vec::with_capacity(num_updated_maps);
+        for _ in 0..num_updated_maps {
+            let idx = source.read_u8()?;
+            let map_delta = StorageMapDelta::read_from(source)?;
+
+            // make sure index is valid
+            if idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(DeserializationError::InvalidValue(
+                    "immutable storage map updated".to_string(),
+                ));
+            }
+
+            // make sure the same map hasn't been updated before
+            if updated_maps.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "storage map updated more than once".to_string(),
+                ));
+            }
+
+            updated_maps.push((idx, map_delta));
+        }
+
+        // deserialize and validate updated multi-word values
+        let num_updated_words = source.read_u8()? as usize;
+        let mut updated_words: Vec<(u8, Vec<Word>)> = Vec::with_capacity(num_updated_words);
+        for _ in 0..num_updated_words {
+            let idx = source.read_u8()?;
+            let num_words = source.read_u8()? as usize;
+            let mut words = Vec::with_capacity(num_words);
+            for _ in 0..num_words {
+                words.push(Word::read_from(source)?);
+            }
+
+            // make sure the same value hasn't been updated before
+            if updated_words.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "storage value words updated more than once".to_string(),
+                ));
+            }
+
+            // make sure the words match the updated item
+            validate_words_update(&updated_items, idx, &words)
+                .map_err(DeserializationError::InvalidValue)?;
+
+            updated_words.push((idx, words));
+        }
+
+        Ok(Self {
+            cleared_items,
+            updated_items,
+            updated_maps,
+            updated_words,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the slot at the specified index is updated to the commitment to the provided
+/// words by the provided item updates.
+fn validate_words_update(
+    updated_items: &[(u8, Word)],
+    idx: u8,
+    words: &[Word],
+) -> Result<(), String> {
+    let commitment: Word = AccountStorage::compute_words_commitment(words).into();
+    if updated_items
+        .iter()
+        .any(|&(item_idx, value)| item_idx == idx && value == commitment)
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "words of storage slot {idx} do not match the updated value of the slot"
+        ))
+    }
+}
+
+// STORAGE MAP DELTA
+// ================================================================================================
+
+/// [StorageMapDelta] stores the differences between two states of a storage map.
+///
+/// The differences are represented as follows:
+/// - leaf updates: represented by `cleared_leaves` and `updated_leaves` field, where leaves are
+///   identified by the keys of the map.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageMapDelta {
+    pub cleared_leaves: Vec<Digest>,
+    pub updated_leaves: Vec<(Digest, Word)>,
+}
+
+impl StorageMapDelta {
+    /// Returns the minimal delta which transforms the `old` map into the `new` map.
+    ///
+    /// Keys which are set in the `old` map only are recorded as cleared leaves, and keys whose
+    /// values differ are recorded as updated leaves.
+    pub fn between(old: &StorageMap, new: &StorageMap) -> Self {
+        let cleared_leaves = old
+            .entries()
+            .filter(|(key, _)| new.get_value(key) == StorageMap::EMPTY_VALUE)
+            .map(|(key, _)| *key)
+            .collect();
+        let updated_leaves = new
+            .entries()
+            .filter(|(key, value)| old.get_value(key) != *value)
+            .copied()
+            .collect();
+
+        Self { cleared_leaves, updated_leaves }
+    }
+
+    /// Checks whether this storage map delta is valid.
+    ///
+    /// # Errors
+    /// Returns an error if any of the cleared or updated leaves is referenced more than once (e.g.,
+    /// updated twice).
+    pub fn validate(&self) -> Result<(), AccountDeltaError> {
+        for (pos, key) in self.cleared_leaves.iter().enumerate() {
+            if self.cleared_leaves[..pos].contains(key) {
+                return Err(AccountDeltaError::DuplicateStorageMapLeafUpdate(*key));
+            }
+        }
+
+        for (pos, (key, _)) in self.updated_leaves.iter().enumerate() {
+            if self.cleared_leaves.contains(key)
+                || self.updated_leaves[..pos].iter().any(|x| x.0 == *key)
+            {
+                return Err(AccountDeltaError::DuplicateStorageMapLeafUpdate(*key));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if storage map delta contains no updates.
+    pub fn is_empty(&self) -> bool {
+        self.cleared_leaves.is_empty() && self.updated_leaves.is_empty()
+    }
+}
+
+impl Serializable for StorageMapDelta {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        assert!(self.cleared_leaves.len() <= u32::MAX as usize, "too many cleared map leaves");
+        target.write_u32(self.cleared_leaves.len() as u32);
+        target.write_many(&self.cleared_leaves);
+
+        assert!(self.updated_leaves.len() <= u32::MAX as usize, "too many updated map leaves");
+        target.write_u32(self.updated_leaves.len() as u32);
+        for (key, value) in self.updated_leaves.iter() {
+            key.write_into(target);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageMapDelta {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_cleared_leaves = source.read_u32()? as usize;
+        let cleared_leaves = source.read_many::<Digest>(num_cleared_leaves)?;
+
+        let num_updated_leaves = source.read_u32()? as usize;
+        let updated_leaves = (0..num_updated_leaves)
+            .map(|_| Ok((Digest::read_from(source)?, Word::read_from(source)?)))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+
+        let delta = Self { cleared_leaves, updated_leaves };
+        delta
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(delta)
     }
 }
 
@@ -162,14 +467,19 @@ impl Deserializable for AccountStorageDelta {
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorageDelta, Deserializable, Serializable};
-    use crate::{ONE, ZERO};
+    use super::{
+        AccountStorage, AccountStorageDelta, Deserializable, Digest, Serializable, StorageMap,
+        StorageMapDelta, Word,
+    };
+    use crate::{accounts::StorageSlotType, ONE, ZERO};
 
     #[test]
     fn account_storage_delta_validation() {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 3],
             updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
         assert!(delta.validate().is_ok());
 
@@ -180,6 +490,8 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 255],
             updated_items: vec![],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -190,6 +502,8 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 1],
             updated_items: vec![],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -200,6 +514,8 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![],
             updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (255, [ONE, ONE, ONE, ZERO])],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -214,6 +530,8 @@ mod tests {
                 (5, [ONE, ONE, ONE, ZERO]),
                 (4, [ONE, ONE, ZERO, ZERO]),
             ],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -224,10 +542,172 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 3],
             updated_items: vec![(2, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
         assert!(delta.validate().is_err());
 
         let bytes = delta.to_bytes();
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // valid map updates
+        let key = Digest::new([ONE, ZERO, ZERO, ZERO]);
+        let map_delta = StorageMapDelta {
+            cleared_leaves: vec![Digest::default()],
+            updated_leaves: vec![(key, [ONE, ONE, ONE, ONE])],
+        };
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, [ONE, ONE, ONE, ONE])],
+            updated_maps: vec![(4, map_delta.clone())],
+            updated_words: vec![],
+        };
+        assert!(delta.validate().is_ok());
+
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // duplicate in updated maps
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![(4, map_delta.clone()), (4, map_delta)],
+            updated_words: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate leaf across cleared and updated leaves of a map
+        let map_delta = StorageMapDelta {
+            cleared_leaves: vec![key],
+            updated_leaves: vec![(key, [ONE, ONE, ONE, ONE])],
+        };
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![(4, map_delta)],
+            updated_words: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // valid multi-word value updates
+        let words = vec![[ONE, ONE, ONE, ONE], [ONE, ZERO, ONE, ZERO]];
+        let commitment: Word = AccountStorage::compute_words_commitment(&words).into();
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, commitment)],
+            updated_maps: vec![],
+            updated_words: vec![(4, words.clone())],
+        };
+        assert!(delta.validate().is_ok());
+
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // words which do not match the updated item
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, commitment)],
+            updated_maps: vec![],
+            updated_words: vec![(4, words[..1].to_vec())],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // words of a slot which is not updated
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, commitment)],
+            updated_maps: vec![],
+            updated_words: vec![(5, words.clone())],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate in updated multi-word values
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, commitment)],
+            updated_maps: vec![],
+            updated_words: vec![(4, words.clone()), (4, words)],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+    }
+    #[test]
+    fn account_storage_delta_between() {
+        let key1 = Digest::new([ONE, ZERO, ZERO, ZERO]);
+        let key2 = Digest::new([ZERO, ONE, ZERO, ZERO]);
+        let map = StorageMap::with_entries([(key1, [ONE; 4])]).unwrap();
+
+        let mut old = AccountStorage::new(vec![
+            (0, (StorageSlotType::default(), [ONE, ONE, ONE, ONE])),
+            (1, (StorageSlotType::default(), [ONE, ONE, ONE, ZERO])),
+            (2, (StorageSlotType::Map { value_arity: 0 }, Word::default())),
+            (4, (StorageSlotType::Value { value_arity: 2 }, Word::default())),
+        ])
+        .unwrap();
+        old.set_map(2, map).unwrap();
+
+        let words = vec![[ONE, ZERO, ZERO, ONE], [ZERO, ONE, ONE, ZERO]];
+        let mut new = old.clone();
+        new.set_item(0, Word::default()).unwrap();
+        new.set_item(3, [ZERO, ZERO, ONE, ONE]).unwrap();
+        new.set_map_item(2, key1, StorageMap::EMPTY_VALUE).unwrap();
+        new.set_map_item(2, key2, [ZERO, ONE, ZERO, ONE]).unwrap();
+        new.set_item_words(4, words.clone()).unwrap();
+
+        // changed slots are cleared or updated, the entries of known maps are diffed, and the
+        // words of known multi-word values are recorded
+        let delta = AccountStorageDelta::between(&old, &new).unwrap();
+        assert_eq!(delta.cleared_items, vec![0]);
+        assert_eq!(
+            delta.updated_items,
+            vec![
+                (2, new.get_item(2).into()),
+                (3, [ZERO, ZERO, ONE, ONE]),
+                (4, new.get_item(4).into())
+            ]
+        );
+        assert_eq!(delta.updated_words, vec![(4, words)]);
+        assert_eq!(
+            delta.updated_maps,
+            vec![(
+                2,
+                StorageMapDelta {
+                    cleared_leaves: vec![key1],
+                    updated_leaves: vec![(key2, [ZERO, ONE, ZERO, ONE])],
+                }
+            )]
+        );
+
+        // applying the delta results in the new storage
+        let mut storage = old.clone();
+        storage.apply_delta(&delta).unwrap();
+        assert_eq!(storage.root(), new.root());
+        assert_eq!(storage.get_map(2), new.get_map(2));
+        assert_eq!(storage.get_item_words(4), new.get_item_words(4));
+
+        // identical storages result in an empty delta
+        assert!(AccountStorageDelta::between(&old, &old).unwrap().is_empty());
+
+        // storages with different layouts cannot be diffed
+        let other = AccountStorage::new(vec![(
+            0,
+            (StorageSlotType::Value { value_arity: 1 }, [ONE, ONE, ONE, ONE]),
+        )])
+        .unwrap();
+        assert!(AccountStorageDelta::between(&old, &other).is_err());
     }
 }
@@ -2,6 +2,11 @@ use super::{
     AccountDeltaError, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
     Serializable, ToString, Vec,
 };
+use crate::{
+    assets::{AssetVault, FungibleAsset},
+    utils::format,
+    AssetVaultError,
+};
 
 // ACCOUNT VAULT DELTA
 // ================================================================================================
@@ -18,6 +23,81 @@ pub struct AccountVaultDelta {
 }
 
 impl AccountVaultDelta {
+    /// Returns the minimal delta which transforms the `old` vault into the `new` vault.
+    ///
+    /// Fungible assets are diffed by balance, i.e., the delta contains the difference between the
+    /// balances of a faucet in the two vaults; non-fungible assets are either added or removed.
+    /// The delta is verified by applying it to the `old` vault and comparing the commitment of the
+    /// result against the commitment of the `new` vault.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The resulting delta is invalid (e.g., because it contains too many assets).
+    /// - Applying the resulting delta to the `old` vault does not result in the `new` vault.
+    pub fn between(old: &AssetVault, new: &AssetVault) -> Result<Self, AccountDeltaError> {
+        let vault_error =
+            |err: AssetVaultError| AccountDeltaError::InconsistentVaultDelta(err.to_string());
+
+        let mut delta = Self::default();
+
+        // assets which are in the new vault, and balances which have changed
+        for asset in new.assets() {
+            match asset {
+                Asset::Fungible(asset) => {
+                    let faucet_id = asset.faucet_id();
+                    let old_balance = old.get_balance(faucet_id).map_err(vault_error)?;
+                    if asset.amount() > old_balance {
+                        let added = FungibleAsset::new(faucet_id, asset.amount() - old_balance)
+                            .expect("fungible asset is well formed");
+                        delta.added_assets.push(added.into());
+                    } else if asset.amount() < old_balance {
+                        let removed = FungibleAsset::new(faucet_id, old_balance - asset.amount())
+                            .expect("fungible asset is well formed");
+                        delta.removed_assets.push(removed.into());
+                    }
+                },
+                Asset::NonFungible(_) => {
+                    if !old.has_non_fungible_asset(asset).map_err(vault_error)? {
+                        delta.added_assets.push(asset);
+                    }
+                },
+            }
+        }
+
+        // assets which are only in the old vault
+        for asset in old.assets() {
+            let is_removed = match asset {
+                Asset::Fungible(asset) => {
+                    new.get_balance(asset.faucet_id()).map_err(vault_error)? == 0
+                },
+                Asset::NonFungible(_) => !new.has_non_fungible_asset(asset).map_err(vault_error)?,
+            };
+            if is_removed {
+                delta.removed_assets.push(asset);
+            }
+        }
+
+        delta.validate()?;
+
+        // make sure the delta transforms the old vault into the new one
+        let mut vault = old.clone();
+        for &asset in delta.added_assets.iter() {
+            vault.add_asset(asset).map_err(vault_error)?;
+        }
+        for &asset in delta.removed_assets.iter() {
+            vault.remove_asset(asset).map_err(vault_error)?;
+        }
+        if vault.commitment() != new.commitment() {
+            return Err(AccountDeltaError::InconsistentVaultDelta(format!(
+                "delta results in vault {} instead of {}",
+                vault.commitment().to_hex(),
+                new.commitment().to_hex()
+            )));
+        }
+
+        Ok(delta)
+    }
+
     /// Checks whether this vault delta is valid.
     ///
     /// # Errors
@@ -127,7 +207,7 @@ impl Deserializable for AccountVaultDelta {
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountVaultDelta, Asset, Deserializable, Serializable};
+    use super::{AccountVaultDelta, Asset, AssetVault, Deserializable, Serializable};
     use crate::{
         accounts::{AccountId, AccountType},
         assets::{FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
@@ -198,4 +278,56 @@ mod tests {
         let bytes = delta.to_bytes();
         assert!(AccountVaultDelta::read_from_bytes(&bytes).is_err());
     }
+    #[test]
+    fn account_vault_delta_between() {
+        let ffid1 = AccountId::new_dummy([0; 32], AccountType::FungibleFaucet);
+        let ffid2 = AccountId::new_dummy([1; 32], AccountType::FungibleFaucet);
+        let nffid = AccountId::new_dummy([0; 32], AccountType::NonFungibleFaucet);
+
+        let non_fungible_asset = |data: Vec<u8>| -> Asset {
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid, data).unwrap())
+                .unwrap()
+                .into()
+        };
+        let fungible_asset =
+            |faucet_id, amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+
+        let old = AssetVault::new(&[
+            fungible_asset(ffid1, 10),
+            fungible_asset(ffid2, 20),
+            non_fungible_asset(vec![1, 2, 3]),
+        ])
+        .unwrap();
+        let new = AssetVault::new(&[fungible_asset(ffid1, 30), non_fungible_asset(vec![4, 5, 6])])
+            .unwrap();
+
+        // balances are diffed, and assets missing from either vault are added or removed
+        let delta = AccountVaultDelta::between(&old, &new).unwrap();
+        assert_eq!(delta.added_assets.len(), 2);
+        assert!(delta.added_assets.contains(&fungible_asset(ffid1, 20)));
+        assert!(delta.added_assets.contains(&non_fungible_asset(vec![4, 5, 6])));
+        assert_eq!(delta.removed_assets.len(), 2);
+        assert!(delta.removed_assets.contains(&fungible_asset(ffid2, 20)));
+        assert!(delta.removed_assets.contains(&non_fungible_asset(vec![1, 2, 3])));
+
+        // the reverse delta swaps added and removed assets
+        let reverse = AccountVaultDelta::between(&new, &old).unwrap();
+        assert_eq!(reverse.added_assets.len(), 2);
+        assert!(delta.added_assets.iter().all(|asset| reverse.removed_assets.contains(asset)));
+        assert!(delta.removed_assets.iter().all(|asset| reverse.added_assets.contains(asset)));
+
+        // a partially spent balance results in a partial removal
+        let spent = AssetVault::new(&[
+            fungible_asset(ffid1, 4),
+            fungible_asset(ffid2, 20),
+            non_fungible_asset(vec![1, 2, 3]),
+        ])
+        .unwrap();
+        let delta = AccountVaultDelta::between(&old, &spent).unwrap();
+        assert!(delta.added_assets.is_empty());
+        assert_eq!(delta.removed_assets, vec![fungible_asset(ffid1, 6)]);
+
+        // identical vaults result in an empty delta
+        assert!(AccountVaultDelta::between(&old, &old).unwrap().is_empty());
+    }
 }
@@ -0,0 +1,285 @@
+use super::{
+    code::ProcedureAccess, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    ModuleAst, Serializable, String, ToString, Vec,
+};
+
+// PROCEDURE SIGNATURE
+// ================================================================================================
+
+/// The name of an account procedure together with the stack signature declared in its
+/// documentation, as retained from the source module of the account code.
+///
+/// The stack signature follows the convention of the MASM sources of this repository: the doc
+/// comment of a procedure contains an `Inputs: [...]` and an `Outputs: [...]` line. Signatures are
+/// documentation only, and thus the arities derived from them are hints which are not checked
+/// against the code of the procedure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureSignature {
+    name: String,
+    inputs: Option<String>,
+    outputs: Option<String>,
+}
+
+impl ProcedureSignature {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ProcedureSignature] with the specified name and stack signature.
+    pub fn new(name: String, inputs: Option<String>, outputs: Option<String>) -> Self {
+        Self { name, inputs, outputs }
+    }
+
+    /// Returns the signature of a procedure with the specified name and doc comment.
+    fn from_docs(name: &str, docs: Option<&str>) -> Self {
+        let find_line = |prefix: &str| {
+            docs?
+                .lines()
+                .find_map(|line| line.trim().strip_prefix(prefix))
+                .map(|signature| signature.trim().to_string())
+        };
+
+        Self::new(name.to_string(), find_line("Inputs:"), find_line("Outputs:"))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the name under which the procedure is exported.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the declared stack inputs of the procedure, e.g., `[ASSET, ...]`.
+    pub fn inputs(&self) -> Option<&str> {
+        self.inputs.as_deref()
+    }
+
+    /// Returns the declared stack outputs of the procedure, e.g., `[balance, ...]`.
+    pub fn outputs(&self) -> Option<&str> {
+        self.outputs.as_deref()
+    }
+
+    /// Returns the number of stack elements consumed by the procedure according to its declared
+    /// inputs (see [ProcedureSignature::num_outputs()] for how elements are counted).
+    pub fn num_inputs(&self) -> Option<usize> {
+        self.inputs.as_deref().and_then(stack_arity)
+    }
+
+    /// Returns the number of stack elements produced by the procedure according to its declared
+    /// outputs.
+    ///
+    /// Named items in uppercase (e.g., `ASSET`) are words and count as 4 elements, other named
+    /// items count as 1 element, and the trailing `...` is ignored. None is returned if no outputs
+    /// are declared or they are not enclosed in brackets.
+    pub fn num_outputs(&self) -> Option<usize> {
+        self.outputs.as_deref().and_then(stack_arity)
+    }
+}
+
+impl Serializable for ProcedureSignature {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_string(&self.name, target);
+        for signature in [&self.inputs, &self.outputs] {
+            target.write_bool(signature.is_some());
+            if let Some(signature) = signature {
+                write_string(signature, target);
+            }
+        }
+    }
+}
+
+impl Deserializable for ProcedureSignature {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let name = read_string(source)?;
+        let inputs = if source.read_bool()? {
+            Some(read_string(source)?)
+        } else {
+            None
+        };
+        let outputs = if source.read_bool()? {
+            Some(read_string(source)?)
+        } else {
+            None
+        };
+        Ok(Self::new(name, inputs, outputs))
+    }
+}
+
+// PROCEDURE DESCRIPTOR
+// ================================================================================================
+
+/// A description of a procedure of an account interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureDescriptor {
+    root: Digest,
+    access: ProcedureAccess,
+    signature: Option<ProcedureSignature>,
+}
+
+impl ProcedureDescriptor {
+    /// Returns a new [ProcedureDescriptor] of the procedure with the specified root.
+    pub fn new(
+        root: Digest,
+        access: ProcedureAccess,
+        signature: Option<ProcedureSignature>,
+    ) -> Self {
+        Self { root, access, signature }
+    }
+
+    /// Returns the MAST root of the procedure.
+    pub fn root(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns the access of the procedure.
+    pub fn access(&self) -> ProcedureAccess {
+        self.access
+    }
+
+    /// Returns the name of the procedure, or None if the name is not known.
+    pub fn name(&self) -> Option<&str> {
+        self.signature.as_ref().map(ProcedureSignature::name)
+    }
+
+    /// Returns the signature of the procedure, or None if it is not known.
+    pub fn signature(&self) -> Option<&ProcedureSignature> {
+        self.signature.as_ref()
+    }
+}
+
+impl Serializable for ProcedureDescriptor {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.root.write_into(target);
+        self.access.write_into(target);
+        self.signature.write_into(target);
+    }
+}
+
+impl Deserializable for ProcedureDescriptor {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let root = Digest::read_from(source)?;
+        let access = ProcedureAccess::read_from(source)?;
+        let signature = <Option<ProcedureSignature>>::read_from(source)?;
+        Ok(Self::new(root, access, signature))
+    }
+}
+
+// ACCOUNT INTERFACE
+// ================================================================================================
+
+/// A description of the public interface of an account, i.e., of the procedures of its
+/// [AccountCode](super::AccountCode), in the order of their indexes.
+///
+/// Unlike the account code, the interface does not contain the code of the procedures; it is
+/// meant to be shared with tooling, e.g., to display the procedures called by a script which are
+/// missing from an account, or to generate client bindings for the procedures of an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountInterface {
+    code_root: Digest,
+    procedures: Vec<ProcedureDescriptor>,
+}
+
+impl AccountInterface {
+    /// Returns a new [AccountInterface] of the account code with the specified root, consisting
+    /// of the provided procedures.
+    pub fn new(code_root: Digest, procedures: Vec<ProcedureDescriptor>) -> Self {
+        Self { code_root, procedures }
+    }
+
+    /// Returns the root of the account code this interface describes.
+    pub fn code_root(&self) -> Digest {
+        self.code_root
+    }
+
+    /// Returns the descriptors of the procedures of this interface.
+    pub fn procedures(&self) -> &[ProcedureDescriptor] {
+        &self.procedures
+    }
+
+    /// Returns the descriptor of the procedure with the specified name, if any.
+    pub fn get_by_name(&self, name: &str) -> Option<&ProcedureDescriptor> {
+        self.procedures.iter().find(|procedure| procedure.name() == Some(name))
+    }
+
+    /// Returns the descriptor of the procedure with the specified root, if any.
+    pub fn get_by_root(&self, root: Digest) -> Option<&ProcedureDescriptor> {
+        self.procedures.iter().find(|procedure| procedure.root == root)
+    }
+}
+
+impl Serializable for AccountInterface {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.code_root.write_into(target);
+        target.write_u16(self.procedures.len() as u16);
+        target.write_many(&self.procedures);
+    }
+}
+
+impl Deserializable for AccountInterface {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let code_root = Digest::read_from(source)?;
+        let num_procedures = source.read_u16()? as usize;
+        let procedures = source.read_many::<ProcedureDescriptor>(num_procedures)?;
+        Ok(Self::new(code_root, procedures))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the signatures of the procedures exported by the provided module, in the order in
+/// which the assembler compiles them: re-exported procedures first, followed by the procedures
+/// defined in the module.
+///
+/// None is returned if the number of exports differs from the specified number of procedures,
+/// i.e., if the signatures cannot be matched with the compiled procedures.
+pub(super) fn export_signatures(
+    module: &ModuleAst,
+    num_procedures: usize,
+) -> Option<Vec<ProcedureSignature>> {
+    let reexports = module.reexported_procs().iter().map(|proc| {
+        ProcedureSignature::from_docs(proc.name().as_ref(), proc.docs().map(AsRef::as_ref))
+    });
+    let exports = module
+        .procs()
+        .iter()
+        .filter(|proc| proc.is_export)
+        .map(|proc| ProcedureSignature::from_docs(proc.name.as_ref(), proc.docs.as_deref()));
+
+    let signatures: Vec<_> = reexports.chain(exports).collect();
+    (signatures.len() == num_procedures).then_some(signatures)
+}
+
+/// Returns the number of stack elements described by the provided stack signature.
+fn stack_arity(signature: &str) -> Option<usize> {
+    let items = signature.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let arity = items
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty() && *item != "...")
+        .map(|item| {
+            let is_word = item.chars().any(|c| c.is_ascii_uppercase())
+                && item.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+            if is_word {
+                4
+            } else {
+                1
+            }
+        })
+        .sum();
+
+    Some(arity)
+}
+
+/// Writes the provided string into the target, prefixed with its length in bytes.
+fn write_string<W: ByteWriter>(string: &str, target: &mut W) {
+    target.write_u16(string.len() as u16);
+    target.write_bytes(string.as_bytes());
+}
+
+/// Reads a string written via [write_string()] from the source.
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_u16()? as usize;
+    String::from_utf8(source.read_vec(len)?)
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
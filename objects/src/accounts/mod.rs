@@ -1,6 +1,7 @@
 use super::{
     assembly::{Assembler, AssemblyContext, ModuleAst},
     assets::AssetVault,
+    transaction::ExecutedTransaction,
     utils::{
         collections::{BTreeMap, Vec},
         serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
@@ -10,19 +11,37 @@ use super::{
 };
 
 mod account_id;
-pub use account_id::{compute_digest, digest_pow, validate_account_seed, AccountId, AccountType};
+pub use account_id::{
+    compute_digest, digest_pow, validate_account_seed, AccountId, AccountStorageMode, AccountType,
+};
+
+mod builder;
+pub use builder::AccountBuilder;
 
 mod code;
-pub use code::AccountCode;
+pub use code::{AccountCode, ProcedureAccess};
+
+mod interface;
+pub use interface::{AccountInterface, ProcedureDescriptor, ProcedureSignature};
+
+mod component;
+pub use component::{AccountComponent, ComponentStorageSlot};
 
 pub mod delta;
-pub use delta::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
+pub use delta::{AccountDelta, AccountStorageDelta, AccountVaultDelta, StorageMapDelta};
 
 mod seed;
-pub use seed::{get_account_seed, get_account_seed_single};
+#[cfg(feature = "concurrent")]
+pub use seed::AccountSeedSearch;
+pub use seed::{
+    get_account_seed, get_account_seed_single, get_account_seed_with, SEED_SEARCH_PROGRESS_INTERVAL,
+};
 
 mod storage;
-pub use storage::{AccountStorage, SlotItem, StorageSlotType};
+pub use storage::{
+    verify_slot_proof, AccountStorage, SlotItem, StorageArray, StorageMap, StorageSlot,
+    StorageSlotProof, StorageSlotType,
+};
 
 mod stub;
 pub use stub::AccountStub;
@@ -182,6 +201,9 @@ impl Account {
     /// Applies the provided delta to this account. This updates account vault, storage, and nonce
     /// to the values specified by the delta.
     ///
+    /// The delta is applied atomically: if applying any part of the delta fails, the account is
+    /// left unchanged.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - Applying vault sub-delta to the vault of this account fails.
@@ -189,24 +211,74 @@ impl Account {
     /// - The nonce specified in the provided delta smaller than or equal to the current account
     ///   nonce.
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
+        // make sure the nonce can be updated before touching the rest of the account
+        if let Some(nonce) = delta.nonce() {
+            self.validate_nonce(nonce)?;
+        }
+
         // update vault; we don't check vault delta validity here because AccountDelta can contain
         // only valid vault deltas
+        let mut vault = self.vault.clone();
         for &asset in delta.vault().added_assets.iter() {
-            self.vault.add_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
+            vault.add_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
         }
 
         for &asset in delta.vault().removed_assets.iter() {
-            self.vault.remove_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
+            vault.remove_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
         }
 
         // update storage
-        self.storage.apply_delta(delta.storage())?;
+        let mut storage = self.storage.clone();
+        storage.apply_delta(delta.storage())?;
 
-        // update nonce
+        // all updates succeeded; commit them to the account
+        self.vault = vault;
+        self.storage = storage;
         if let Some(nonce) = delta.nonce() {
-            self.set_nonce(nonce)?;
+            self.nonce = nonce;
+        }
+
+        Ok(())
+    }
+
+    /// Applies the account delta of the provided executed transaction to this account.
+    ///
+    /// In addition to applying the delta (see [Account::apply_delta()]), this verifies that the
+    /// transaction was executed against the current state of this account, and that the state of
+    /// the account after applying the delta matches the final account state of the transaction.
+    /// If any of these checks fails, the account is left unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The hash of this account is different from the initial account hash of the transaction.
+    /// - Applying the account delta of the transaction fails.
+    /// - The hash of the updated account is different from the final account hash of the
+    ///   transaction.
+    pub fn apply_executed_transaction(
+        &mut self,
+        transaction: &ExecutedTransaction,
+    ) -> Result<(), AccountError> {
+        let initial_hash = transaction.initial_account().hash();
+        if self.hash() != initial_hash {
+            return Err(AccountError::InitialAccountHashMismatch {
+                expected: initial_hash,
+                actual: self.hash(),
+            });
+        }
+
+        let mut account = self.clone();
+        account.apply_delta(transaction.account_delta())?;
+
+        let final_hash = transaction.final_account().hash();
+        if account.hash() != final_hash {
+            return Err(AccountError::FinalAccountHashMismatch {
+                expected: final_hash,
+                actual: account.hash(),
+            });
         }
 
+        *self = account;
+
         Ok(())
     }
 
@@ -217,6 +289,17 @@ impl Account {
     /// - The new nonce is smaller than the actual account nonce
     /// - The new nonce is equal to the actual account nonce
     pub fn set_nonce(&mut self, nonce: Felt) -> Result<(), AccountError> {
+        self.validate_nonce(nonce)?;
+        self.nonce = nonce;
+
+        Ok(())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if the specified nonce is not greater than the current account nonce.
+    fn validate_nonce(&self, nonce: Felt) -> Result<(), AccountError> {
         if self.nonce.as_int() >= nonce.as_int() {
             return Err(AccountError::NonceNotMonotonicallyIncreasing {
                 current: self.nonce.as_int(),
@@ -224,8 +307,6 @@ impl Account {
             });
         }
 
-        self.nonce = nonce;
-
         Ok(())
     }
 
@@ -357,6 +438,8 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![0],
             updated_items: vec![(1, word)],
+            updated_maps: vec![],
+            updated_words: vec![],
         };
 
         let vault_delta = AccountVaultDelta { added_assets, removed_assets };
@@ -396,6 +479,23 @@ mod tests {
         assert_eq!(account, final_account);
     }
 
+    #[test]
+    fn failed_account_delta_leaves_account_unchanged() {
+        // build account
+        let init_nonce = Felt::new(1);
+        let (asset_0, asset_1) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let mut account = build_account(vec![asset_0], init_nonce, vec![word]);
+        let initial_account = account.clone();
+
+        // build account delta which removes an asset not present in the account vault
+        let account_delta = build_account_delta(vec![], vec![asset_1], Felt::new(2));
+
+        // applying the delta fails and the account is not modified
+        assert!(account.apply_delta(&account_delta).is_err());
+        assert_eq!(account, initial_account);
+    }
+
     #[test]
     #[should_panic]
     fn valid_account_delta_with_unchanged_nonce() {
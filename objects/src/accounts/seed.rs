@@ -1,7 +1,8 @@
+use core::ops::ControlFlow;
 #[cfg(feature = "concurrent")]
 use std::{
     sync::{
-        mpsc::{self, Sender},
+        mpsc::{self, Receiver, Sender, TryRecvError},
         Arc, RwLock,
     },
     thread::{self, spawn},
@@ -9,6 +10,13 @@ use std::{
 
 use super::{compute_digest, AccountError, AccountId, AccountType, Digest, Felt, Vec, Word};
 
+// CONSTANTS
+// --------------------------------------------------------------------------------------------
+
+/// Number of hashes computed by [get_account_seed_with()] between two consecutive progress
+/// reports.
+pub const SEED_SEARCH_PROGRESS_INTERVAL: u64 = 10_000;
+
 // SEED GENERATORS
 // --------------------------------------------------------------------------------------------
 
@@ -24,33 +32,146 @@ pub fn get_account_seed(
 ) -> Result<Word, AccountError> {
     let thread_count = thread::available_parallelism().map_or(1, |v| v.get());
 
-    let (send, recv) = mpsc::channel();
-    let stop = Arc::new(RwLock::new(false));
-
-    for count in 0..thread_count {
-        let send = send.clone();
-        let stop = Arc::clone(&stop);
-        let mut init_seed = init_seed;
-        init_seed[0] += count as u8;
-        spawn(move || {
-            get_account_seed_inner(
-                send,
-                stop,
-                init_seed,
-                account_type,
-                on_chain,
-                code_root,
-                storage_root,
-            )
-        });
+    AccountSeedSearch::start(
+        init_seed,
+        account_type,
+        on_chain,
+        code_root,
+        storage_root,
+        thread_count,
+    )
+    .wait()
+}
+
+// ACCOUNT SEED SEARCH
+// --------------------------------------------------------------------------------------------
+
+/// A handle to a search for an account seed running on a set of background threads.
+///
+/// Every thread walks the seed space starting from a distinct seed derived from the initial seed,
+/// and the search completes as soon as one of the threads finds a suitable seed. The search can be
+/// cancelled via [AccountSeedSearch::cancel()]; dropping the handle cancels the search as well.
+///
+/// The threads check for cancellation periodically (every 500,000 iterations), and thus may keep
+/// running for a short while after the search was cancelled.
+#[cfg(feature = "concurrent")]
+pub struct AccountSeedSearch {
+    recv: Receiver<(Digest, Word)>,
+    stop: Arc<RwLock<bool>>,
+}
+
+#[cfg(feature = "concurrent")]
+impl AccountSeedSearch {
+    /// Starts a search for a seed suitable for creating an account ID for the specified account
+    /// type on the specified number of threads, using the provided initial seed as a starting
+    /// point.
+    ///
+    /// At least one thread is always started.
+    pub fn start(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        on_chain: bool,
+        code_root: Digest,
+        storage_root: Digest,
+        num_threads: usize,
+    ) -> Self {
+        let (send, recv) = mpsc::channel();
+        let stop = Arc::new(RwLock::new(false));
+
+        for thread_index in 0..num_threads.max(1) {
+            let send = send.clone();
+            let stop = Arc::clone(&stop);
+            let init_seed = thread_init_seed(init_seed, thread_index);
+            spawn(move || {
+                get_account_seed_inner(
+                    send,
+                    stop,
+                    init_seed,
+                    account_type,
+                    on_chain,
+                    code_root,
+                    storage_root,
+                )
+            });
+        }
+
+        Self { recv, stop }
+    }
+
+    /// Cancels this search.
+    pub fn cancel(&self) {
+        // Safety: the lock is never held across a panic, it should never be poisoned
+        *self.stop.write().unwrap() = true;
+    }
+
+    /// Returns true if this search was cancelled or has already returned a seed.
+    pub fn is_cancelled(&self) -> bool {
+        *self.stop.read().unwrap()
     }
 
-    #[allow(unused_variables)]
-    let (digest, seed) = recv.recv().unwrap();
+    /// Returns the seed found by this search without blocking, or None if the search is still
+    /// running.
+    ///
+    /// Once a seed has been returned, the remaining threads are stopped and subsequent calls
+    /// return an error.
+    ///
+    /// # Errors
+    /// Returns an error if the search was cancelled.
+    pub fn try_result(&self) -> Option<Result<Word, AccountError>> {
+        if self.is_cancelled() {
+            return Some(Err(AccountError::AccountSeedSearchCancelled));
+        }
 
-    // Safety: this is the only writer for this lock, it should never be poisoned
-    *stop.write().unwrap() = true;
+        match self.recv.try_recv() {
+            Ok((digest, seed)) => {
+                self.cancel();
+                Some(Ok(found_seed(digest, seed)))
+            },
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(AccountError::AccountSeedSearchCancelled)),
+        }
+    }
 
+    /// Blocks until a seed is found and returns it.
+    ///
+    /// # Errors
+    /// Returns an error if the search was cancelled.
+    pub fn wait(self) -> Result<Word, AccountError> {
+        if self.is_cancelled() {
+            return Err(AccountError::AccountSeedSearchCancelled);
+        }
+
+        let (digest, seed) =
+            self.recv.recv().map_err(|_| AccountError::AccountSeedSearchCancelled)?;
+
+        // the remaining threads are stopped when the handle is dropped
+        Ok(found_seed(digest, seed))
+    }
+}
+
+#[cfg(feature = "concurrent")]
+impl Drop for AccountSeedSearch {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// Returns the initial seed of the search thread with the specified index.
+///
+/// The first 8 bytes of the seed are interpreted as a little-endian integer and incremented by the
+/// thread index, so that every thread starts from a distinct seed.
+#[cfg(feature = "concurrent")]
+fn thread_init_seed(init_seed: [u8; 32], thread_index: usize) -> [u8; 32] {
+    let mut seed = init_seed;
+    let prefix = u64::from_le_bytes(seed[..8].try_into().expect("slice has 8 bytes"));
+    seed[..8].copy_from_slice(&prefix.wrapping_add(thread_index as u64).to_le_bytes());
+    seed
+}
+
+/// Logs the seed found by a search and returns it.
+#[cfg(feature = "concurrent")]
+#[allow(unused_variables)]
+fn found_seed(digest: Digest, seed: Word) -> Word {
     #[cfg(feature = "log")]
     ::log::info!(
         "Using account seed [pow={}, digest={}, seed={}]",
@@ -59,7 +180,7 @@ pub fn get_account_seed(
         log::word_hex(seed),
     );
 
-    Ok(seed)
+    seed
 }
 
 #[cfg(feature = "concurrent")]
@@ -134,6 +255,31 @@ pub fn get_account_seed_single(
     code_root: Digest,
     storage_root: Digest,
 ) -> Result<Word, AccountError> {
+    get_account_seed_with(init_seed, account_type, on_chain, code_root, storage_root, |_| {
+        ControlFlow::Continue(())
+    })
+}
+
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// using the provided initial seed as a starting point. Using a single thread.
+///
+/// The `on_progress` callback is invoked before the search starts and then every
+/// [SEED_SEARCH_PROGRESS_INTERVAL] iterations with the total number of hashes computed so far; the
+/// search is aborted if the callback returns [ControlFlow::Break].
+///
+/// # Errors
+/// Returns an error if the search was aborted by the callback.
+pub fn get_account_seed_with<F>(
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    on_chain: bool,
+    code_root: Digest,
+    storage_root: Digest,
+    mut on_progress: F,
+) -> Result<Word, AccountError>
+where
+    F: FnMut(u64) -> ControlFlow<()>,
+{
     let init_seed: Vec<[u8; 8]> =
         init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
     let mut current_seed: Word = [
@@ -148,10 +294,17 @@ pub fn get_account_seed_single(
     let mut log = log::Log::start(current_digest, current_seed, account_type, on_chain);
 
     // loop until we have a seed that satisfies the specified account type.
+    let mut count: u64 = 0;
     loop {
         #[cfg(feature = "log")]
         log.iteration(current_digest, current_seed);
 
+        // regularly report progress and check if the search should be aborted
+        if count % SEED_SEARCH_PROGRESS_INTERVAL == 0 && on_progress(count).is_break() {
+            return Err(AccountError::AccountSeedSearchCancelled);
+        }
+        count += 1;
+
         // check if the seed satisfies the specified account type
         if AccountId::validate_seed_digest(&current_digest).is_ok() {
             if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
@@ -249,3 +402,104 @@ mod log {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        get_account_seed_with, AccountError, AccountId, AccountType, ControlFlow, Digest, Vec,
+        SEED_SEARCH_PROGRESS_INTERVAL,
+    };
+    #[cfg(feature = "concurrent")]
+    use super::{thread_init_seed, AccountSeedSearch};
+
+    #[test]
+    fn account_seed_search_reports_progress_and_can_be_aborted() {
+        let code_root = Digest::default();
+        let storage_root = Digest::default();
+
+        let mut reports = Vec::new();
+        let seed = get_account_seed_with(
+            [7; 32],
+            AccountType::FungibleFaucet,
+            true,
+            code_root,
+            storage_root,
+            |count| {
+                reports.push(count);
+                ControlFlow::Continue(())
+            },
+        )
+        .unwrap();
+
+        let account_id = AccountId::new(seed, code_root, storage_root).unwrap();
+        assert_eq!(account_id.account_type(), AccountType::FungibleFaucet);
+        assert_eq!(reports.first(), Some(&0));
+        assert!(reports.iter().all(|count| count % SEED_SEARCH_PROGRESS_INTERVAL == 0));
+
+        // the search is aborted as soon as the callback breaks
+        let mut num_reports = 0;
+        let result = get_account_seed_with(
+            [7; 32],
+            AccountType::FungibleFaucet,
+            true,
+            code_root,
+            storage_root,
+            |_| {
+                num_reports += 1;
+                ControlFlow::Break(())
+            },
+        );
+        assert!(matches!(result, Err(AccountError::AccountSeedSearchCancelled)));
+        assert_eq!(num_reports, 1);
+    }
+
+    #[cfg(feature = "concurrent")]
+    #[test]
+    fn account_seed_search_finds_valid_seed() {
+        let code_root = Digest::default();
+        let storage_root = Digest::default();
+
+        let search = AccountSeedSearch::start(
+            [7; 32],
+            AccountType::RegularAccountUpdatableCode,
+            false,
+            code_root,
+            storage_root,
+            4,
+        );
+        let seed = search.wait().unwrap();
+
+        let account_id = AccountId::new(seed, code_root, storage_root).unwrap();
+        assert_eq!(account_id.account_type(), AccountType::RegularAccountUpdatableCode);
+        assert!(!account_id.is_on_chain());
+    }
+
+    #[cfg(feature = "concurrent")]
+    #[test]
+    fn account_seed_search_can_be_cancelled() {
+        let search = AccountSeedSearch::start(
+            [7; 32],
+            AccountType::FungibleFaucet,
+            true,
+            Digest::default(),
+            Digest::default(),
+            2,
+        );
+        search.cancel();
+
+        assert!(search.is_cancelled());
+        assert!(matches!(
+            search.try_result(),
+            Some(Err(AccountError::AccountSeedSearchCancelled))
+        ));
+        assert!(matches!(search.wait(), Err(AccountError::AccountSeedSearchCancelled)));
+
+        // threads start from distinct seeds, also when the seed prefix overflows
+        let init_seed = [u8::MAX; 32];
+        assert_eq!(thread_init_seed(init_seed, 0), init_seed);
+        assert_eq!(thread_init_seed(init_seed, 1)[..9], [0, 0, 0, 0, 0, 0, 0, 0, u8::MAX]);
+    }
+}
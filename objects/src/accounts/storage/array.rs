@@ -0,0 +1,210 @@
+use super::{
+    slot::{MAX_ARRAY_DEPTH, MIN_ARRAY_DEPTH},
+    AccountError, BTreeMap, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    Serializable, ToString, Vec, Word,
+};
+use crate::crypto::merkle::{EmptySubtreeRoots, InnerNodeInfo, MerkleStore, NodeIndex};
+
+// STORAGE ARRAY
+// ================================================================================================
+
+/// A fixed-size array stored in an account storage slot of
+/// [StorageSlotType::Array](super::StorageSlotType::Array) type.
+///
+/// An array of depth `d` holds 2^d words, committed to via a Merkle tree of depth `d`; the storage
+/// slot holds the root of this tree. Items which have not been set are empty words.
+#[derive(Debug, Clone)]
+pub struct StorageArray {
+    depth: u8,
+    items: BTreeMap<u64, Word>,
+    root: Digest,
+    store: MerkleStore,
+}
+
+impl StorageArray {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StorageArray] of the specified depth with all items set to empty words.
+    ///
+    /// # Errors
+    /// Returns an error if the depth is smaller than 2 or greater than 64.
+    pub fn new(depth: u8) -> Result<Self, AccountError> {
+        if !(MIN_ARRAY_DEPTH..=MAX_ARRAY_DEPTH).contains(&depth) {
+            return Err(AccountError::StorageArrayInvalidDepth(depth));
+        }
+
+        Ok(Self {
+            depth,
+            items: BTreeMap::new(),
+            root: *EmptySubtreeRoots::entry(depth, 0),
+            store: MerkleStore::new(),
+        })
+    }
+
+    /// Returns a new [StorageArray] of the specified depth initialized with the provided items.
+    ///
+    /// Items which are not provided are set to empty words; if an item is provided more than once,
+    /// the last value is used.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The depth is smaller than 2 or greater than 64.
+    /// - The index of any of the items is out of bounds for an array of the specified depth.
+    pub fn with_items(
+        depth: u8,
+        items: impl IntoIterator<Item = (u64, Word)>,
+    ) -> Result<Self, AccountError> {
+        let mut array = Self::new(depth)?;
+        for (index, value) in items {
+            array.set(index, value)?;
+        }
+        Ok(array)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the depth of this array.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the root of the Merkle tree committing to the items of this array.
+    pub fn root(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns the item at the specified index.
+    ///
+    /// # Errors
+    /// Returns an error if the index is out of bounds.
+    pub fn get(&self, index: u64) -> Result<Word, AccountError> {
+        self.validate_index(index)?;
+        Ok(self.items.get(&index).copied().unwrap_or_default())
+    }
+
+    /// Returns an iterator over the non-empty items of this array together with their indexes.
+    pub fn items(&self) -> impl Iterator<Item = (u64, &Word)> {
+        self.items.iter().map(|(&index, value)| (index, value))
+    }
+
+    /// Returns an iterator over the inner nodes of the Merkle tree committing to this array.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.store.inner_nodes()
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the item at the specified index and returns the previous value of the item.
+    ///
+    /// # Errors
+    /// Returns an error if the index is out of bounds.
+    pub fn set(&mut self, index: u64, value: Word) -> Result<Word, AccountError> {
+        self.validate_index(index)?;
+
+        let node_index = NodeIndex::new(self.depth, index).expect("index is within bounds");
+        self.root = self
+            .store
+            .set_node(self.root, node_index, value.into())
+            .expect("array root is in the store")
+            .root;
+
+        let old_value = if value == Word::default() {
+            self.items.remove(&index)
+        } else {
+            self.items.insert(index, value)
+        };
+        Ok(old_value.unwrap_or_default())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if the specified index is out of bounds for this array.
+    fn validate_index(&self, index: u64) -> Result<(), AccountError> {
+        if self.depth < 64 && index >= 1_u64 << self.depth {
+            return Err(AccountError::StorageArrayIndexOutOfBounds { depth: self.depth, index });
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for StorageArray {
+    fn eq(&self, other: &Self) -> bool {
+        // the Merkle store may contain stale nodes, and thus is not compared
+        self.depth == other.depth && self.items == other.items
+    }
+}
+
+impl Eq for StorageArray {}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageArray {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.depth);
+
+        assert!(self.items.len() <= u32::MAX as usize, "too many items in the storage array");
+        target.write_u32(self.items.len() as u32);
+        for (&index, value) in self.items.iter() {
+            target.write_u64(index);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageArray {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let depth = source.read_u8()?;
+        let num_items = source.read_u32()? as usize;
+        let items = (0..num_items)
+            .map(|_| Ok((source.read_u64()?, Word::read_from(source)?)))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+
+        Self::with_items(depth, items)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, Serializable, StorageArray};
+    use crate::{crypto::merkle::MerkleTree, AccountError, Felt, Word, ONE};
+
+    #[test]
+    fn storage_array_matches_merkle_tree() {
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let mut array = StorageArray::with_items(3, [(2, value), (5, [ONE; 4])]).unwrap();
+
+        let mut leaves = vec![Word::default(); 8];
+        leaves[2] = value;
+        leaves[5] = [ONE; 4];
+        let tree = MerkleTree::new(leaves).unwrap();
+        assert_eq!(array.root(), tree.root());
+        assert_eq!(array.get(2), Ok(value));
+        assert_eq!(array.get(3), Ok(Word::default()));
+
+        // out of bounds items are rejected
+        assert_eq!(
+            array.get(8),
+            Err(AccountError::StorageArrayIndexOutOfBounds { depth: 3, index: 8 })
+        );
+        assert!(StorageArray::new(1).is_err());
+
+        // clearing an item updates the root
+        assert_eq!(array.set(5, Word::default()), Ok([ONE; 4]));
+        leaves[5] = Word::default();
+        assert_eq!(array.root(), MerkleTree::new(leaves).unwrap().root());
+
+        let bytes = array.to_bytes();
+        let deserialized = StorageArray::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized, array);
+        assert_eq!(deserialized.root(), array.root());
+    }
+}
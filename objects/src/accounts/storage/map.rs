@@ -0,0 +1,163 @@
+use super::{
+    AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    Serializable, ToString, Vec, Word,
+};
+use crate::{
+    accounts::delta::StorageMapDelta,
+    crypto::merkle::{InnerNodeInfo, Smt, SmtLeaf},
+};
+
+// STORAGE MAP
+// ================================================================================================
+
+/// A key-value map stored in an account storage slot of
+/// [StorageSlotType::Map](super::StorageSlotType::Map) type.
+///
+/// Both keys and values of the map are words. The map is backed by a Sparse Merkle Tree, and the
+/// storage slot holds the root of this tree. Keys which have not been set map to
+/// [StorageMap::EMPTY_VALUE].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageMap {
+    map: Smt,
+}
+
+impl StorageMap {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The value of keys which have not been set.
+    pub const EMPTY_VALUE: Word = Smt::EMPTY_VALUE;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [StorageMap].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [StorageMap] initialized with the provided entries.
+    ///
+    /// # Errors
+    /// Returns an error if the provided entries contain duplicate keys.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Digest, Word)>,
+    ) -> Result<Self, AccountError> {
+        let map = Smt::with_entries(entries).map_err(AccountError::DuplicateStorageMapKeys)?;
+        Ok(Self { map })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the Sparse Merkle Tree backing this map.
+    pub fn root(&self) -> Digest {
+        self.map.root()
+    }
+
+    /// Returns the value stored under the specified key, or [StorageMap::EMPTY_VALUE] if the key
+    /// has not been set.
+    pub fn get_value(&self, key: &Digest) -> Word {
+        self.map.get_value(key)
+    }
+
+    /// Returns an iterator over the non-empty entries of this map.
+    pub fn entries(&self) -> impl Iterator<Item = &(Digest, Word)> {
+        self.map.entries()
+    }
+
+    /// Returns an iterator over the leaves of the Sparse Merkle Tree backing this map.
+    pub fn leaves(&self) -> impl Iterator<Item = &SmtLeaf> {
+        self.map.leaves().map(|(_, leaf)| leaf)
+    }
+
+    /// Returns an iterator over the inner nodes of the Sparse Merkle Tree backing this map.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.map.inner_nodes()
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the value stored under the specified key and returns the previous value.
+    ///
+    /// Setting a key to [StorageMap::EMPTY_VALUE] removes it from the map.
+    pub fn insert(&mut self, key: Digest, value: Word) -> Word {
+        self.map.insert(key, value)
+    }
+
+    /// Applies the provided delta to this map and returns the new root of the map.
+    pub fn apply_delta(&mut self, delta: &StorageMapDelta) -> Digest {
+        for &key in delta.cleared_leaves.iter() {
+            self.map.insert(key, Self::EMPTY_VALUE);
+        }
+
+        for &(key, value) in delta.updated_leaves.iter() {
+            self.map.insert(key, value);
+        }
+
+        self.root()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let entries = self.entries().collect::<Vec<_>>();
+
+        assert!(entries.len() <= u32::MAX as usize, "too many entries in the storage map");
+        target.write_u32(entries.len() as u32);
+        for (key, value) in entries {
+            key.write_into(target);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entries = source.read_u32()? as usize;
+        let entries = (0..num_entries)
+            .map(|_| Ok((Digest::read_from(source)?, Word::read_from(source)?)))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+
+        Self::with_entries(entries)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, Digest, Serializable, StorageMap, StorageMapDelta};
+    use crate::{Felt, ONE, ZERO};
+
+    #[test]
+    fn storage_map_delta_and_serialization() {
+        let key_0 = Digest::new([ONE, ZERO, ZERO, ZERO]);
+        let key_1 = Digest::new([ZERO, ONE, ZERO, ZERO]);
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        let mut map = StorageMap::with_entries([(key_0, value)]).unwrap();
+        assert_eq!(map.get_value(&key_0), value);
+        assert_eq!(map.get_value(&key_1), StorageMap::EMPTY_VALUE);
+
+        let delta = StorageMapDelta {
+            cleared_leaves: vec![key_0],
+            updated_leaves: vec![(key_1, value)],
+        };
+        let root = map.apply_delta(&delta);
+        assert_eq!(root, StorageMap::with_entries([(key_1, value)]).unwrap().root());
+        assert_eq!(map.get_value(&key_0), StorageMap::EMPTY_VALUE);
+
+        let bytes = map.to_bytes();
+        assert_eq!(StorageMap::read_from_bytes(&bytes).unwrap(), map);
+
+        // duplicate keys are rejected
+        assert!(StorageMap::with_entries([(key_0, value), (key_0, value)]).is_err());
+    }
+}
@@ -2,7 +2,19 @@ use super::{
     AccountError, AccountStorageDelta, BTreeMap, ByteReader, ByteWriter, Deserializable,
     DeserializationError, Digest, Felt, Hasher, Serializable, String, ToString, Vec, Word,
 };
-use crate::crypto::merkle::{LeafIndex, NodeIndex, SimpleSmt};
+use crate::{
+    crypto::merkle::{LeafIndex, NodeIndex, SimpleSmt},
+    utils::format,
+};
+
+mod array;
+pub use array::StorageArray;
+
+mod map;
+pub use map::StorageMap;
+
+mod proof;
+pub use proof::{verify_slot_proof, StorageSlotProof};
 
 mod slot;
 pub use slot::StorageSlotType;
@@ -39,10 +51,24 @@ pub type StorageSlot = (StorageSlotType, Word);
 ///
 /// Storage slots are stored in a simple Sparse Merkle Tree of depth 8. Slot 255 is always reserved
 /// and contains information about slot types of all other slots.
+///
+/// Scalar slots with value arity 0 contain a single word. Scalar slots with value arity n > 0
+/// contain a commitment to a value of n words (see [AccountStorage::compute_words_commitment()]);
+/// the words of such values are kept alongside the storage slots when they are known.
+///
+/// Map slots with value arity 0 contain the root of a [StorageMap] which maps words to words; the
+/// entries of such maps are kept alongside the storage slots when they are known.
+///
+/// Array slots with value arity 0 contain the root of a [StorageArray] of the depth specified by
+/// the slot type; the items of such arrays are kept alongside the storage slots when they are
+/// known.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountStorage {
     slots: SimpleSmt<STORAGE_TREE_DEPTH>,
     layout: Vec<StorageSlotType>,
+    multi_word_values: BTreeMap<u8, Vec<Word>>,
+    maps: BTreeMap<u8, StorageMap>,
+    arrays: BTreeMap<u8, StorageArray>,
 }
 
 impl AccountStorage {
@@ -93,7 +119,21 @@ impl AccountStorage {
         let slots = SimpleSmt::<STORAGE_TREE_DEPTH>::with_leaves(entires)
             .map_err(AccountError::DuplicateStorageItems)?;
 
-        Ok(Self { slots, layout })
+        Ok(Self {
+            slots,
+            layout,
+            multi_word_values: BTreeMap::new(),
+            maps: BTreeMap::new(),
+            arrays: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the commitment to a multi-word value.
+    ///
+    /// The commitment is computed as the hash of the elements of the value, padded with an empty
+    /// word to an even number of words.
+    pub fn compute_words_commitment(words: &[Word]) -> Digest {
+        Hasher::hash_elements(&pad_words(words))
     }
 
     // PUBLIC ACCESSORS
@@ -113,6 +153,87 @@ impl AccountStorage {
         self.slots.get_node(item_index).expect("index is u8 - index within range")
     }
 
+    /// Returns the words of the multi-word value stored at the specified index.
+    ///
+    /// Returns None if the slot does not contain a multi-word value, or if the words of the value
+    /// are not known (e.g., the value was set by a transaction and only its commitment was
+    /// received).
+    pub fn get_item_words(&self, index: u8) -> Option<&[Word]> {
+        self.multi_word_values.get(&index).map(|words| words.as_slice())
+    }
+
+    /// Returns the advice map entries for all known multi-word values in this storage.
+    ///
+    /// Each entry maps the commitment to a value to the elements of the value, padded with an
+    /// empty word to an even number of words.
+    pub fn multi_word_advice_entries(&self) -> impl Iterator<Item = (Digest, Vec<Felt>)> + '_ {
+        self.multi_word_values
+            .values()
+            .map(|words| (Self::compute_words_commitment(words), pad_words(words)))
+    }
+
+    /// Returns the map stored at the specified index.
+    ///
+    /// Returns None if the slot is not a map slot, or if the entries of the map are not known
+    /// (e.g., the map was updated by a transaction and only its root was received).
+    pub fn get_map(&self, index: u8) -> Option<&StorageMap> {
+        self.maps.get(&index)
+    }
+
+    /// Returns the value stored under the specified key in the map at the specified index.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The slot at the specified index is not a map slot.
+    /// - The entries of the map at the specified index are not known.
+    pub fn get_map_item(&self, index: u8, key: Digest) -> Result<Word, AccountError> {
+        self.validate_map_slot(index)?;
+        let map = self.maps.get(&index).ok_or(AccountError::StorageMapNotFound(index))?;
+        Ok(map.get_value(&key))
+    }
+
+    /// Returns an iterator over all known maps in this storage together with their slot indexes.
+    pub fn maps(&self) -> impl Iterator<Item = (u8, &StorageMap)> {
+        self.maps.iter().map(|(&index, map)| (index, map))
+    }
+
+    /// Returns the array stored at the specified index.
+    ///
+    /// Returns None if the slot is not an array slot, or if the items of the array are not known
+    /// (e.g., the array was updated by a transaction and only its root was received).
+    pub fn get_array(&self, index: u8) -> Option<&StorageArray> {
+        self.arrays.get(&index)
+    }
+
+    /// Returns the item at the specified index of the array stored at the specified slot.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The slot at the specified index is not an array slot.
+    /// - The items of the array at the specified slot are not known.
+    /// - The item index is out of bounds for the array.
+    pub fn get_array_item(&self, slot: u8, index: u64) -> Result<Word, AccountError> {
+        self.validate_array_slot(slot, None)?;
+        let array = self.arrays.get(&slot).ok_or(AccountError::StorageArrayNotFound(slot))?;
+        array.get(index)
+    }
+
+    /// Returns an iterator over all known arrays in this storage together with their slot indexes.
+    pub fn arrays(&self) -> impl Iterator<Item = (u8, &StorageArray)> {
+        self.arrays.iter().map(|(&index, array)| (index, array))
+    }
+
+    /// Returns a proof of the value of the storage slot at the specified index against the root of
+    /// this storage.
+    ///
+    /// The proof can be verified without knowing the rest of the storage via
+    /// [verify_slot_proof()].
+    pub fn open(&self, index: u8) -> StorageSlotProof {
+        let leaf_index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+        let opening = self.slots.open(&leaf_index);
+        StorageSlotProof::new(index, opening.value.into(), opening.path)
+    }
+
     /// Returns a reference to the Sparse Merkle Tree that backs the storage slots.
     pub fn slots(&self) -> &SimpleSmt<STORAGE_TREE_DEPTH> {
         &self.slots
@@ -141,17 +262,84 @@ impl AccountStorage {
     /// - The delta implies an update to a reserved account slot.
     /// - The updates violate storage layout constraints.
     pub(super) fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
+        // update the entries of known maps; the new roots of the maps are set by the item updates
+        for (slot_idx, map_delta) in delta.updated_maps.iter() {
+            if let Some(map) = self.maps.get_mut(slot_idx) {
+                map.apply_delta(map_delta);
+            }
+        }
+
         for &slot_idx in delta.cleared_items.iter() {
-            self.set_item(slot_idx, Word::default())?;
+            self.apply_item_update(slot_idx, Word::default())?;
         }
 
         for &(slot_idx, slot_value) in delta.updated_items.iter() {
-            self.set_item(slot_idx, slot_value)?;
+            self.apply_item_update(slot_idx, slot_value)?;
+        }
+
+        // set the words of updated multi-word values; the words match the updated commitments
+        for (slot_idx, words) in delta.updated_words.iter() {
+            self.set_item_words(*slot_idx, words.clone())?;
         }
 
         Ok(())
     }
 
+    /// Sets the item at the specified index to the value of a storage delta.
+    ///
+    /// Multi-word slots are updated with the new commitment only; the words of the previous value
+    /// are discarded unless they match the new commitment, and the words of the new value are set
+    /// by [AccountStorage::apply_delta()] if the delta contains them. Similarly, map slots are
+    /// updated with the new root only; the entries of the map are discarded unless they match the
+    /// new root. The same applies to the items of array slots.
+    fn apply_item_update(&mut self, index: u8, value: Word) -> Result<(), AccountError> {
+        match self.layout[index as usize] {
+            StorageSlotType::Array { .. } => {
+                let root: Digest = value.into();
+                let is_stale =
+                    self.arrays.get(&index).map(|array| array.root() != root).unwrap_or(false);
+                if is_stale {
+                    self.arrays.remove(&index);
+                }
+
+                let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+                self.slots.insert(index, value);
+                Ok(())
+            },
+            StorageSlotType::Map { .. } => {
+                let root: Digest = value.into();
+                let is_stale = self.maps.get(&index).map(|map| map.root() != root).unwrap_or(false);
+                if is_stale {
+                    self.maps.remove(&index);
+                }
+
+                let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+                self.slots.insert(index, value);
+                Ok(())
+            },
+            StorageSlotType::Value { value_arity } if value_arity > 0 => {
+                if index == Self::SLOT_LAYOUT_COMMITMENT_INDEX {
+                    return Err(AccountError::StorageSlotIsReserved(index));
+                }
+
+                let commitment: Digest = value.into();
+                let is_stale = self
+                    .multi_word_values
+                    .get(&index)
+                    .map(|words| Self::compute_words_commitment(words) != commitment)
+                    .unwrap_or(false);
+                if is_stale {
+                    self.multi_word_values.remove(&index);
+                }
+
+                let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+                self.slots.insert(index, value);
+                Ok(())
+            },
+            _ => self.set_item(index, value).map(|_| ()),
+        }
+    }
+
     /// Sets an item from the storage at the specified index.
     ///
     /// # Errors
@@ -183,6 +371,209 @@ impl AccountStorage {
         let slot_value = self.slots.insert(index, value);
         Ok(slot_value)
     }
+
+    /// Sets a multi-word value at the specified index and returns the commitment to the previous
+    /// value.
+    ///
+    /// The slot is set to the commitment to the value, and the words of the value are kept in this
+    /// storage.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The index specifies a reserved storage slot.
+    /// - The slot at the specified index is not a value slot with non-zero arity.
+    /// - The number of words is not equal to the value arity of the slot.
+    pub fn set_item_words(&mut self, index: u8, words: Vec<Word>) -> Result<Digest, AccountError> {
+        // layout commitment slot cannot be updated
+        if index == Self::SLOT_LAYOUT_COMMITMENT_INDEX {
+            return Err(AccountError::StorageSlotIsReserved(index));
+        }
+
+        match self.layout[index as usize] {
+            StorageSlotType::Value { value_arity } => {
+                if value_arity == 0 {
+                    return Err(AccountError::StorageSlotInvalidValueArity {
+                        slot: index,
+                        expected: words.len().min(u8::MAX as usize) as u8,
+                        actual: value_arity,
+                    });
+                }
+                if words.len() != value_arity as usize {
+                    return Err(AccountError::StorageSlotInvalidValueLength {
+                        slot: index,
+                        expected: value_arity,
+                        actual: words.len(),
+                    });
+                }
+            },
+            slot_type => Err(AccountError::StorageSlotNotValueSlot(index, slot_type))?,
+        }
+
+        let commitment = Self::compute_words_commitment(&words);
+        let leaf_index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+        let old_commitment = self.slots.insert(leaf_index, commitment.into());
+        self.multi_word_values.insert(index, words);
+
+        Ok(old_commitment.into())
+    }
+
+    /// Sets the map at the specified index and returns the root of the previous map.
+    ///
+    /// The slot is set to the root of the map, and the entries of the map are kept in this
+    /// storage.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The index specifies a reserved storage slot.
+    /// - The slot at the specified index is not a map slot with value arity 0.
+    pub fn set_map(&mut self, index: u8, map: StorageMap) -> Result<Digest, AccountError> {
+        self.validate_map_slot(index)?;
+
+        let leaf_index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+        let old_root = self.slots.insert(leaf_index, map.root().into());
+        self.maps.insert(index, map);
+
+        Ok(old_root.into())
+    }
+
+    /// Sets the value stored under the specified key in the map at the specified index and
+    /// returns the previous value.
+    ///
+    /// The slot is set to the new root of the map.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The index specifies a reserved storage slot.
+    /// - The slot at the specified index is not a map slot with value arity 0.
+    /// - The entries of the map at the specified index are not known.
+    pub fn set_map_item(
+        &mut self,
+        index: u8,
+        key: Digest,
+        value: Word,
+    ) -> Result<Word, AccountError> {
+        self.validate_map_slot(index)?;
+
+        let map = self.maps.get_mut(&index).ok_or(AccountError::StorageMapNotFound(index))?;
+        let old_value = map.insert(key, value);
+        let root = map.root();
+
+        let leaf_index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+        self.slots.insert(leaf_index, root.into());
+
+        Ok(old_value)
+    }
+
+    /// Sets the array at the specified slot and returns the root of the previous array.
+    ///
+    /// The slot is set to the root of the array, and the items of the array are kept in this
+    /// storage.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The index specifies a reserved storage slot.
+    /// - The slot at the specified index is not an array slot with value arity 0.
+    /// - The depth of the array does not match the depth of the slot.
+    pub fn set_array(&mut self, slot: u8, array: StorageArray) -> Result<Digest, AccountError> {
+        self.validate_array_slot(slot, Some(array.depth()))?;
+
+        let leaf_index = LeafIndex::new(slot as u64).expect("index is u8 - index within range");
+        let old_root = self.slots.insert(leaf_index, array.root().into());
+        self.arrays.insert(slot, array);
+
+        Ok(old_root.into())
+    }
+
+    /// Sets the item at the specified index of the array stored at the specified slot and returns
+    /// the previous value of the item.
+    ///
+    /// The slot is set to the new root of the array.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The index specifies a reserved storage slot.
+    /// - The slot at the specified index is not an array slot with value arity 0.
+    /// - The items of the array at the specified slot are not known.
+    /// - The item index is out of bounds for the array.
+    pub fn set_array_item(
+        &mut self,
+        slot: u8,
+        index: u64,
+        value: Word,
+    ) -> Result<Word, AccountError> {
+        self.validate_array_slot(slot, None)?;
+
+        let array = self.arrays.get_mut(&slot).ok_or(AccountError::StorageArrayNotFound(slot))?;
+        let old_value = array.set(index, value)?;
+        let root = array.root();
+
+        let leaf_index = LeafIndex::new(slot as u64).expect("index is u8 - index within range");
+        self.slots.insert(leaf_index, root.into());
+
+        Ok(old_value)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if the slot at the specified index is reserved or is not a map slot with
+    /// value arity 0.
+    fn validate_map_slot(&self, index: u8) -> Result<(), AccountError> {
+        if index == Self::SLOT_LAYOUT_COMMITMENT_INDEX {
+            return Err(AccountError::StorageSlotIsReserved(index));
+        }
+
+        match self.layout[index as usize] {
+            StorageSlotType::Map { value_arity: 0 } => Ok(()),
+            StorageSlotType::Map { value_arity } => {
+                Err(AccountError::StorageSlotInvalidValueArity {
+                    slot: index,
+                    expected: 0,
+                    actual: value_arity,
+                })
+            },
+            slot_type => Err(AccountError::StorageSlotNotMapSlot(index, slot_type)),
+        }
+    }
+
+    /// Returns an error if the slot at the specified index is reserved or is not an array slot
+    /// with value arity 0, or if the depth of the slot does not match the provided depth.
+    fn validate_array_slot(&self, index: u8, depth: Option<u8>) -> Result<(), AccountError> {
+        if index == Self::SLOT_LAYOUT_COMMITMENT_INDEX {
+            return Err(AccountError::StorageSlotIsReserved(index));
+        }
+
+        match self.layout[index as usize] {
+            StorageSlotType::Array { depth: slot_depth, value_arity: 0 } => match depth {
+                Some(depth) if depth != slot_depth => {
+                    Err(AccountError::StorageArrayInvalidDepth(depth))
+                },
+                _ => Ok(()),
+            },
+            StorageSlotType::Array { value_arity, .. } => {
+                Err(AccountError::StorageSlotInvalidValueArity {
+                    slot: index,
+                    expected: 0,
+                    actual: value_arity,
+                })
+            },
+            slot_type => Err(AccountError::StorageSlotNotArraySlot(index, slot_type)),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the elements of the provided words, padded with an empty word to an even number of
+/// words.
+fn pad_words(words: &[Word]) -> Vec<Felt> {
+    let mut elements = Vec::with_capacity((words.len() + 1) * 4);
+    words.iter().for_each(|word| elements.extend_from_slice(word));
+    if words.len() % 2 == 1 {
+        elements.extend_from_slice(&Word::default());
+    }
+    elements
 }
 
 // SERIALIZATION
@@ -222,6 +613,30 @@ impl Serializable for AccountStorage {
             target.write_u8(idx as u8);
             target.write(value);
         }
+
+        // serialize the words of known multi-word values; the number of words is defined by the
+        // value arity of the slot
+        target.write_u8(self.multi_word_values.len() as u8);
+        for (&idx, words) in self.multi_word_values.iter() {
+            target.write_u8(idx);
+            for &word in words {
+                target.write(word);
+            }
+        }
+
+        // serialize the entries of known maps
+        target.write_u8(self.maps.len() as u8);
+        for (&idx, map) in self.maps.iter() {
+            target.write_u8(idx);
+            map.write_into(target);
+        }
+
+        // serialize the items of known arrays
+        target.write_u8(self.arrays.len() as u8);
+        for (&idx, array) in self.arrays.iter() {
+            target.write_u8(idx);
+            array.write_into(target);
+        }
     }
 }
 
@@ -247,7 +662,62 @@ impl Deserializable for AccountStorage {
             items.push((idx, (slot_type, slot_value)));
         }
 
-        Self::new(items).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        let mut storage =
+            Self::new(items).map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        // read the words of multi-word values and make sure they match the slot commitments
+        let num_multi_word_values = source.read_u8()?;
+        for _ in 0..num_multi_word_values {
+            let idx = source.read_u8()?;
+            let num_words = match storage.layout[idx as usize] {
+                StorageSlotType::Value { value_arity } => value_arity as usize,
+                _ => 0,
+            };
+            let words = (0..num_words).map(|_| source.read()).collect::<Result<Vec<Word>, _>>()?;
+
+            let commitment = storage
+                .set_item_words(idx, words)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            if commitment != storage.get_item(idx) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "multi-word value does not match the commitment in slot {idx}"
+                )));
+            }
+        }
+
+        // read the entries of known maps and make sure they match the slot roots
+        let num_maps = source.read_u8()?;
+        for _ in 0..num_maps {
+            let idx = source.read_u8()?;
+            let map = StorageMap::read_from(source)?;
+
+            let root = storage
+                .set_map(idx, map)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            if root != storage.get_item(idx) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "map entries do not match the root in slot {idx}"
+                )));
+            }
+        }
+
+        // read the items of known arrays and make sure they match the slot roots
+        let num_arrays = source.read_u8()?;
+        for _ in 0..num_arrays {
+            let idx = source.read_u8()?;
+            let array = StorageArray::read_from(source)?;
+
+            let root = storage
+                .set_array(idx, array)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            if root != storage.get_item(idx) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "array items do not match the root in slot {idx}"
+                )));
+            }
+        }
+
+        Ok(storage)
     }
 }
 
@@ -256,8 +726,11 @@ impl Deserializable for AccountStorage {
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorage, Deserializable, Serializable, StorageSlotType};
-    use crate::{ONE, ZERO};
+    use super::{
+        verify_slot_proof, AccountStorage, AccountStorageDelta, Deserializable, Digest,
+        Serializable, StorageArray, StorageMap, StorageSlotProof, StorageSlotType, Word,
+    };
+    use crate::{accounts::StorageMapDelta, AccountError, Felt, ONE, ZERO};
 
     #[test]
     fn account_storage_serialization() {
@@ -289,4 +762,168 @@ mod tests {
         let bytes = storage.to_bytes();
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
+
+    #[test]
+    fn account_storage_slot_proofs() {
+        let value = [ONE, ONE, ONE, ZERO];
+        let mut map = StorageMap::new();
+        map.insert(Digest::default(), [ONE, ZERO, ZERO, ZERO]);
+        let mut storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::default(), value)),
+            (2, (StorageSlotType::Map { value_arity: 0 }, Word::default())),
+        ])
+        .unwrap();
+        storage.set_map(2, map.clone()).unwrap();
+        let root = storage.root();
+
+        // slots holding a value, a map root and no value can be proven
+        let proof = storage.open(0);
+        assert_eq!((proof.index(), proof.value()), (0, value));
+        assert!(verify_slot_proof(root, &proof));
+        assert_eq!(storage.open(2).value(), Word::from(map.root()));
+        assert!(verify_slot_proof(root, &storage.open(2)));
+        assert_eq!(storage.open(1).value(), Word::default());
+        assert!(verify_slot_proof(root, &storage.open(1)));
+        assert!(verify_slot_proof(
+            root,
+            &storage.open(AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX)
+        ));
+
+        // proofs with a different value or index, or against a different root, are rejected
+        let (index, _, path) = proof.clone().into_parts();
+        assert!(!verify_slot_proof(root, &StorageSlotProof::new(index, [ONE; 4], path.clone())));
+        assert!(!verify_slot_proof(root, &StorageSlotProof::new(1, value, path)));
+        storage.set_item(0, [ZERO, ONE, ZERO, ONE]).unwrap();
+        assert!(!verify_slot_proof(storage.root(), &proof));
+
+        let bytes = proof.to_bytes();
+        assert_eq!(StorageSlotProof::read_from_bytes(&bytes).unwrap(), proof);
+    }
+
+    #[test]
+    fn account_storage_multi_word_values() {
+        let words: Vec<Word> = (0..3).map(|i| [Felt::new(i), ONE, ZERO, ONE]).collect();
+        let mut storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::Value { value_arity: 3 }, Word::default())),
+            (1, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ONE])),
+        ])
+        .unwrap();
+
+        // set a value with the expected number of words
+        let old_commitment = storage.set_item_words(0, words.clone()).unwrap();
+        assert_eq!(old_commitment, Word::default().into());
+        assert_eq!(storage.get_item(0), AccountStorage::compute_words_commitment(&words));
+        assert_eq!(storage.get_item_words(0), Some(words.as_slice()));
+
+        // values with an invalid number of words or in single word slots are rejected
+        assert_eq!(
+            storage.set_item_words(0, words[..2].to_vec()),
+            Err(AccountError::StorageSlotInvalidValueLength { slot: 0, expected: 3, actual: 2 })
+        );
+        assert!(storage.set_item_words(1, words.clone()).is_err());
+
+        // multi-word values survive serialization
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+
+        // deltas carrying the words of a value update the words of the slot
+        let new_words: Vec<Word> = (0..3).map(|i| [ONE, Felt::new(i), ONE, ZERO]).collect();
+        let commitment = AccountStorage::compute_words_commitment(&new_words);
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(0, commitment.into())],
+            updated_maps: vec![],
+            updated_words: vec![(0, new_words.clone())],
+        };
+        storage.apply_delta(&delta).unwrap();
+        assert_eq!(storage.get_item(0), commitment);
+        assert_eq!(storage.get_item_words(0), Some(new_words.as_slice()));
+    }
+
+    #[test]
+    fn account_storage_maps() {
+        let key = Digest::new([ONE, ZERO, ZERO, ZERO]);
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let map = StorageMap::with_entries([(key, value)]).unwrap();
+
+        let mut storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::Map { value_arity: 0 }, Word::default())),
+            (1, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ONE])),
+        ])
+        .unwrap();
+
+        // set a map and read its entries
+        storage.set_map(0, map.clone()).unwrap();
+        assert_eq!(storage.get_item(0), map.root());
+        assert_eq!(storage.get_map_item(0, key), Ok(value));
+        assert!(matches!(
+            storage.get_map_item(1, key),
+            Err(AccountError::StorageSlotNotMapSlot(1, _))
+        ));
+        assert!(storage.set_map(1, map.clone()).is_err());
+
+        // updating an entry updates the root of the map
+        let old_value = storage.set_map_item(0, key, [ONE; 4]).unwrap();
+        assert_eq!(old_value, value);
+        assert_eq!(storage.get_map_item(0, key), Ok([ONE; 4]));
+        assert_eq!(storage.get_item(0), storage.get_map(0).unwrap().root());
+
+        // maps survive serialization
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+
+        // map deltas update the entries of known maps
+        let mut expected_map = map.clone();
+        expected_map.insert(key, StorageMap::EMPTY_VALUE);
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(0, expected_map.root().into())],
+            updated_maps: vec![(
+                0,
+                StorageMapDelta {
+                    cleared_leaves: vec![key],
+                    updated_leaves: vec![],
+                },
+            )],
+            updated_words: vec![],
+        };
+        storage.apply_delta(&delta).unwrap();
+        assert_eq!(storage.get_item(0), expected_map.root());
+        assert_eq!(storage.get_map_item(0, key), Ok(StorageMap::EMPTY_VALUE));
+    }
+
+    #[test]
+    fn account_storage_arrays() {
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let array = StorageArray::with_items(4, [(3, value)]).unwrap();
+
+        let mut storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::Array { depth: 4, value_arity: 0 }, Word::default())),
+            (1, (StorageSlotType::Map { value_arity: 0 }, Word::default())),
+        ])
+        .unwrap();
+
+        // set an array and read its items
+        storage.set_array(0, array.clone()).unwrap();
+        assert_eq!(storage.get_item(0), array.root());
+        assert_eq!(storage.get_array_item(0, 3), Ok(value));
+        assert_eq!(storage.get_array_item(0, 4), Ok(Word::default()));
+        assert!(matches!(
+            storage.get_array_item(1, 3),
+            Err(AccountError::StorageSlotNotArraySlot(1, _))
+        ));
+
+        // arrays of a different depth are rejected
+        let result = storage.set_array(0, StorageArray::new(5).unwrap());
+        assert_eq!(result, Err(AccountError::StorageArrayInvalidDepth(5)));
+
+        // updating an item updates the root of the array
+        assert_eq!(storage.set_array_item(0, 3, [ONE; 4]), Ok(value));
+        assert_eq!(storage.get_array_item(0, 3), Ok([ONE; 4]));
+        assert_eq!(storage.get_item(0), storage.get_array(0).unwrap().root());
+
+        // arrays survive serialization
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+    }
 }
@@ -0,0 +1,78 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Serializable, Word,
+    STORAGE_TREE_DEPTH,
+};
+use crate::crypto::merkle::MerklePath;
+
+// STORAGE SLOT PROOF
+// ================================================================================================
+
+/// A proof that a storage slot of an account holds a given value.
+///
+/// The proof consists of the index of the slot, the value of the slot and the path from the slot
+/// to the root of the account storage. Thus, the value of a single slot can be verified against
+/// the storage root of an account (e.g., taken from an [AccountStub](crate::accounts::AccountStub))
+/// without knowing the rest of the storage (see [verify_slot_proof()]).
+///
+/// For map, array and multi-word value slots, the value of the slot is the commitment to the
+/// underlying data structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotProof {
+    index: u8,
+    value: Word,
+    path: MerklePath,
+}
+
+impl StorageSlotProof {
+    /// Returns a new [StorageSlotProof] instantiated from the provided parameters.
+    pub fn new(index: u8, value: Word, path: MerklePath) -> Self {
+        Self { index, value, path }
+    }
+
+    /// Returns the index of the storage slot.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the value of the storage slot.
+    pub fn value(&self) -> Word {
+        self.value
+    }
+
+    /// Returns the path from the storage slot to the root of the account storage.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
+    }
+
+    /// Consumes this proof and returns its index, value and path.
+    pub fn into_parts(self) -> (u8, Word, MerklePath) {
+        (self.index, self.value, self.path)
+    }
+}
+
+/// Returns true if the provided proof opens to the specified storage root.
+pub fn verify_slot_proof(storage_root: Digest, proof: &StorageSlotProof) -> bool {
+    proof.path.len() == STORAGE_TREE_DEPTH as usize
+        && proof.path.verify(proof.index as u64, proof.value.into(), &storage_root)
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageSlotProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.index);
+        target.write(self.value);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for StorageSlotProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let index = source.read_u8()?;
+        let value = source.read()?;
+        let path = MerklePath::read_from(source)?;
+
+        Ok(Self::new(index, value, path))
+    }
+}
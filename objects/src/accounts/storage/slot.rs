@@ -5,8 +5,8 @@ use super::{Felt, String, ToString};
 
 const MAX_VALUE_ARITY: u8 = u8::MAX - 1;
 
-const MIN_ARRAY_DEPTH: u8 = 2;
-const MAX_ARRAY_DEPTH: u8 = 64;
+pub(super) const MIN_ARRAY_DEPTH: u8 = 2;
+pub(super) const MAX_ARRAY_DEPTH: u8 = 64;
 
 const DEFAULT_SLOT_TYPE: StorageSlotType = StorageSlotType::Value { value_arity: 0 };
 
@@ -0,0 +1,233 @@
+use proptest::{
+    collection::{btree_map, vec},
+    prelude::*,
+};
+
+use crate::{
+    accounts::{AccountId, AccountStorage, AccountType, SlotItem, StorageSlotType},
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    notes::{NoteAssets, NoteInputs},
+    utils::collections::Vec,
+    Felt, Word, MAX_ASSETS_PER_NOTE, MAX_INPUTS_PER_NOTE,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of assets in the vaults generated by [AssetVault::arbitrary()].
+const MAX_VAULT_ASSETS: usize = 16;
+
+/// The maximum number of storage slots set in the storages generated by
+/// [AccountStorage::arbitrary()].
+const MAX_STORAGE_ITEMS: usize = 16;
+
+// STRATEGIES
+// ================================================================================================
+
+/// Returns a strategy generating arbitrary field elements.
+pub fn felt_strategy() -> impl Strategy<Value = Felt> {
+    any::<u64>().prop_map(Felt::new)
+}
+
+/// Returns a strategy generating arbitrary words.
+pub fn word_strategy() -> impl Strategy<Value = Word> {
+    [felt_strategy(), felt_strategy(), felt_strategy(), felt_strategy()]
+}
+
+/// Returns a strategy generating valid account IDs of the specified type, both for on-chain and
+/// off-chain accounts.
+///
+/// The generated IDs are valid field elements with at least [AccountId::MIN_ACCOUNT_ONES] ones;
+/// since they are not derived from a seed, they cannot be used to create new accounts.
+pub fn account_id_strategy(account_type: AccountType) -> impl Strategy<Value = AccountId> {
+    let type_tag = match account_type {
+        AccountType::FungibleFaucet => AccountId::FUNGIBLE_FAUCET_TAG,
+        AccountType::NonFungibleFaucet => AccountId::NON_FUNGIBLE_FAUCET_TAG,
+        AccountType::RegularAccountImmutableCode => AccountId::REGULAR_ACCOUNT_IMMUTABLE_CODE_TAG,
+        AccountType::RegularAccountUpdatableCode => AccountId::REGULAR_ACCOUNT_UPDATABLE_CODE_TAG,
+    };
+
+    any::<u64>().prop_filter_map("account ID is not a valid field element", move |value| {
+        let value = (type_tag << 62) | (value >> 2);
+        AccountId::try_from(value).ok()
+    })
+}
+
+// ACCOUNTS
+// ================================================================================================
+
+impl Arbitrary for AccountType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(AccountType::FungibleFaucet),
+            Just(AccountType::NonFungibleFaucet),
+            Just(AccountType::RegularAccountImmutableCode),
+            Just(AccountType::RegularAccountUpdatableCode),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for AccountId {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<AccountType>().prop_flat_map(account_id_strategy).boxed()
+    }
+}
+
+impl Arbitrary for AccountStorage {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates storages with up to [MAX_STORAGE_ITEMS] value slots; the reserved layout
+    /// commitment slot is never set.
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let slot_index = 0..AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX;
+        btree_map(slot_index, word_strategy(), 0..=MAX_STORAGE_ITEMS)
+            .prop_map(|slots| {
+                let items = slots
+                    .into_iter()
+                    .map(|(index, value)| {
+                        (index, (StorageSlotType::Value { value_arity: 0 }, value))
+                    })
+                    .collect::<Vec<SlotItem>>();
+                AccountStorage::new(items).expect("storage items are valid")
+            })
+            .boxed()
+    }
+}
+
+// ASSETS
+// ================================================================================================
+
+impl Arbitrary for FungibleAsset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (account_id_strategy(AccountType::FungibleFaucet), 0..=FungibleAsset::MAX_AMOUNT)
+            .prop_map(|(faucet_id, amount)| {
+                FungibleAsset::new(faucet_id, amount).expect("fungible asset is valid")
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for NonFungibleAsset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (account_id_strategy(AccountType::NonFungibleFaucet), word_strategy())
+            .prop_map(|(faucet_id, data_hash)| {
+                NonFungibleAsset::from_parts(faucet_id, data_hash)
+                    .expect("non-fungible asset is valid")
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Asset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<FungibleAsset>().prop_map(Asset::from),
+            any::<NonFungibleAsset>().prop_map(Asset::from),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for AssetVault {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates vaults with up to [MAX_VAULT_ASSETS] assets.
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        vec(any::<Asset>(), 0..=MAX_VAULT_ASSETS)
+            .prop_filter_map("vault assets are not unique", |assets| AssetVault::new(&assets).ok())
+            .boxed()
+    }
+}
+
+// NOTES
+// ================================================================================================
+
+impl Arbitrary for NoteInputs {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        vec(felt_strategy(), 0..=MAX_INPUTS_PER_NOTE)
+            .prop_map(|values| NoteInputs::new(values).expect("number of inputs is valid"))
+            .boxed()
+    }
+}
+
+impl Arbitrary for NoteAssets {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        vec(any::<Asset>(), 1..=MAX_ASSETS_PER_NOTE)
+            .prop_filter_map("note assets are not unique", |assets| NoteAssets::new(&assets).ok())
+            .boxed()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::{
+        accounts::{AccountId, AccountStorage},
+        assets::{Asset, AssetVault},
+        notes::{NoteAssets, NoteInputs},
+        utils::serde::{Deserializable, Serializable},
+    };
+
+    proptest! {
+        #[test]
+        fn account_id_serialization(account_id in any::<AccountId>()) {
+            let deserialized = AccountId::read_from_bytes(&account_id.to_bytes()).unwrap();
+            prop_assert_eq!(deserialized, account_id);
+            prop_assert_eq!(AccountId::try_from(u64::from(account_id)).unwrap(), account_id);
+        }
+
+        #[test]
+        fn asset_serialization(asset in any::<Asset>()) {
+            prop_assert_eq!(Asset::read_from_bytes(&asset.to_bytes()).unwrap(), asset);
+        }
+
+        #[test]
+        fn asset_vault_serialization(vault in any::<AssetVault>()) {
+            prop_assert_eq!(AssetVault::read_from_bytes(&vault.to_bytes()).unwrap(), vault);
+        }
+
+        #[test]
+        fn account_storage_serialization(storage in any::<AccountStorage>()) {
+            let deserialized = AccountStorage::read_from_bytes(&storage.to_bytes()).unwrap();
+            prop_assert_eq!(deserialized, storage);
+        }
+
+        #[test]
+        fn note_inputs_serialization(inputs in any::<NoteInputs>()) {
+            prop_assert_eq!(NoteInputs::read_from_bytes(&inputs.to_bytes()).unwrap(), inputs);
+        }
+
+        #[test]
+        fn note_assets_serialization(assets in any::<NoteAssets>()) {
+            prop_assert_eq!(NoteAssets::read_from_bytes(&assets.to_bytes()).unwrap(), assets);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use core::fmt;
 
-use super::{parse_word, AccountId, AccountType, Asset, AssetError, Felt, ToString, Word, ZERO};
+use super::{
+    parse_word, AccountId, AccountType, Asset, AssetError, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Felt, Serializable, ToString, Word, ZERO,
+};
 
 // FUNGIBLE ASSET
 // ================================================================================================
@@ -183,3 +186,23 @@ impl fmt::Display for FungibleAsset {
         write!(f, "{:?}", self)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for FungibleAsset {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let data: [u8; 32] = (*self).into();
+        target.write_bytes(&data);
+    }
+}
+
+impl Deserializable for FungibleAsset {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let data_vec = source.read_vec(32)?;
+        let data_array: [u8; 32] = data_vec.try_into().expect("Vec must be of size 32");
+
+        FungibleAsset::try_from(data_array)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
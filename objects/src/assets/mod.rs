@@ -12,13 +12,19 @@ mod fungible;
 pub use fungible::FungibleAsset;
 
 mod nonfungible;
-pub use nonfungible::{NonFungibleAsset, NonFungibleAssetDetails};
+pub use nonfungible::{NonFungibleAsset, NonFungibleAssetDetails, NonFungibleAssetMetadata};
 
 mod token_symbol;
 pub use token_symbol::TokenSymbol;
 
 mod vault;
-pub use vault::AssetVault;
+pub use vault::{AssetChange, AssetVault};
+
+mod partial_vault;
+pub use partial_vault::PartialVault;
+
+mod vault_proof;
+pub use vault_proof::VaultProof;
 
 // ASSET
 // ================================================================================================
@@ -100,6 +106,14 @@ impl Asset {
         matches!(self, Self::Fungible(_))
     }
 
+    /// Returns the ID of the faucet which issued this asset.
+    pub fn faucet_id(&self) -> AccountId {
+        match self {
+            Self::Fungible(asset) => asset.faucet_id(),
+            Self::NonFungible(asset) => asset.faucet_id(),
+        }
+    }
+
     /// Returns the key which is used to store this asset in the account vault.
     pub fn vault_key(&self) -> Word {
         match self {
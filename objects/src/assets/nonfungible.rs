@@ -3,6 +3,7 @@ use core::fmt;
 use super::{
     parse_word, AccountId, AccountType, Asset, AssetError, Felt, Hasher, ToString, Vec, Word,
 };
+use crate::{utils::string::String, Digest};
 
 /// Position of the faucet_id inside the [NonFungibleAsset] word.
 const FAUCET_ID_POS: usize = 1;
@@ -164,6 +165,7 @@ impl fmt::Display for NonFungibleAsset {
 pub struct NonFungibleAssetDetails {
     faucet_id: AccountId,
     asset_data: Vec<u8>,
+    metadata: Option<NonFungibleAssetMetadata>,
 }
 
 impl NonFungibleAssetDetails {
@@ -176,7 +178,16 @@ impl NonFungibleAssetDetails {
             return Err(AssetError::not_a_non_fungible_faucet_id(faucet_id));
         }
 
-        Ok(Self { faucet_id, asset_data })
+        Ok(Self { faucet_id, asset_data, metadata: None })
+    }
+
+    /// Returns these asset details with the specified metadata attached.
+    ///
+    /// The metadata is not part of the asset itself, i.e., the [NonFungibleAsset] built from the
+    /// details does not depend on it.
+    pub fn with_metadata(mut self, metadata: NonFungibleAssetMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
     }
 
     /// Returns ID of the faucet which issued this asset.
@@ -188,4 +199,51 @@ impl NonFungibleAssetDetails {
     pub fn asset_data(&self) -> &[u8] {
         &self.asset_data
     }
+
+    /// Returns the metadata of this asset, if any.
+    pub fn metadata(&self) -> Option<&NonFungibleAssetMetadata> {
+        self.metadata.as_ref()
+    }
+}
+
+// NON-FUNGIBLE ASSET METADATA
+// ================================================================================================
+
+/// Metadata of a non-fungible asset.
+///
+/// The metadata consists of the URI of the token (e.g., a link to a JSON document describing the
+/// asset) and the hash of the attributes of the asset. Faucets store the commitment to the metadata
+/// of every asset they issue under the asset in a storage map, which allows anyone holding the
+/// metadata to verify it against the state of the faucet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NonFungibleAssetMetadata {
+    token_uri: String,
+    attributes: Digest,
+}
+
+impl NonFungibleAssetMetadata {
+    /// Returns metadata instantiated from the specified token URI and hash of the attributes.
+    pub fn new(token_uri: &str, attributes: Digest) -> Self {
+        Self {
+            token_uri: token_uri.to_string(),
+            attributes,
+        }
+    }
+
+    /// Returns the URI of the token.
+    pub fn token_uri(&self) -> &str {
+        &self.token_uri
+    }
+
+    /// Returns the hash of the attributes of the asset.
+    pub fn attributes(&self) -> Digest {
+        self.attributes
+    }
+
+    /// Returns the commitment to this metadata, computed as
+    /// hash(hash(token_uri), attributes).
+    pub fn commitment(&self) -> Digest {
+        Hasher::merge(&[Hasher::hash(self.token_uri.as_bytes()), self.attributes])
+    }
 }
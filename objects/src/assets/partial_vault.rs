@@ -0,0 +1,284 @@
+use super::{
+    AccountId, AccountType, Asset, AssetVault, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, FungibleAsset, Serializable, ToString, Vec, ZERO,
+};
+use crate::{
+    crypto::merkle::{InnerNodeInfo, LeafIndex, MerklePath, Smt, SmtLeaf, SmtProof},
+    utils::collections::BTreeMap,
+    AssetVaultError, Digest, Word,
+};
+
+// PARTIAL VAULT
+// ================================================================================================
+
+/// A partial view of an [AssetVault] which contains only a subset of the vault entries.
+///
+/// For every tracked vault key, the partial vault contains the Sparse Merkle tree leaf holding the
+/// key together with the opening of this leaf against the vault root. This is sufficient to read
+/// and update the tracked entries of the vault (both inside and outside the Miden VM) without
+/// materializing the entire vault, which is important for accounts holding a very large number of
+/// assets.
+///
+/// A vault key may be tracked even if the vault does not contain an asset under this key; in this
+/// case, the opening proves that the entry is empty (e.g., so that an asset can be added under
+/// this key).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialVault {
+    root: Digest,
+    proofs: BTreeMap<Digest, SmtProof>,
+}
+
+impl PartialVault {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [PartialVault] instantiated with the provided vault root and the openings of
+    /// the tracked vault keys.
+    ///
+    /// # Errors
+    /// Returns an error if any of the openings does not open to the specified vault root.
+    pub fn new<I>(root: Digest, proofs: I) -> Result<Self, AssetVaultError>
+    where
+        I: IntoIterator<Item = (Digest, SmtProof)>,
+    {
+        let mut vault_proofs = BTreeMap::new();
+        for (vault_key, proof) in proofs {
+            let proof_root = proof.compute_root();
+            if proof_root != root || proof.get(&vault_key).is_none() {
+                return Err(AssetVaultError::PartialVaultInvalidOpening {
+                    vault_key,
+                    expected_root: root,
+                    actual_root: proof_root,
+                });
+            }
+            vault_proofs.insert(vault_key, proof);
+        }
+
+        Ok(Self { root, proofs: vault_proofs })
+    }
+
+    /// Returns a new [PartialVault] which tracks the specified vault keys of the provided vault.
+    pub fn from_vault<I>(vault: &AssetVault, vault_keys: I) -> Self
+    where
+        I: IntoIterator<Item = Word>,
+    {
+        let proofs = vault_keys
+            .into_iter()
+            .map(|vault_key| {
+                let vault_key = Digest::from(vault_key);
+                (vault_key, vault.asset_tree().open(&vault_key))
+            })
+            .collect();
+
+        Self { root: vault.commitment(), proofs }
+    }
+
+    /// Returns a new [PartialVault] which tracks the vault keys of the provided assets in the
+    /// provided vault.
+    ///
+    /// For fungible assets, the vault key is defined by the issuing faucet only, and thus the
+    /// partial vault tracks the entire balance of the faucet regardless of the asset amount.
+    pub fn for_assets(vault: &AssetVault, assets: &[Asset]) -> Self {
+        Self::from_vault(vault, assets.iter().map(|asset| asset.vault_key()))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the vault this partial vault was built from.
+    pub fn commitment(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns the number of vault keys tracked by this partial vault.
+    pub fn num_tracked_keys(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Returns true if the specified vault key is tracked by this partial vault.
+    pub fn is_tracked(&self, vault_key: Word) -> bool {
+        self.proofs.contains_key(&vault_key.into())
+    }
+
+    /// Returns the value stored in the vault under the specified vault key, or None if the key is
+    /// not tracked by this partial vault.
+    ///
+    /// For tracked keys which do not hold an asset, [Smt::EMPTY_VALUE] is returned.
+    pub fn get(&self, vault_key: Word) -> Option<Word> {
+        let vault_key = Digest::from(vault_key);
+        self.proofs.get(&vault_key).and_then(|proof| proof.get(&vault_key))
+    }
+
+    /// Returns the balance of the asset issued by the specified faucet. If the vault does not
+    /// contain such an asset, 0 is returned.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The specified ID is not an ID of a fungible asset faucet.
+    /// - The vault key of the faucet is not tracked by this partial vault.
+    pub fn get_balance(&self, faucet_id: AccountId) -> Result<u64, AssetVaultError> {
+        if !matches!(faucet_id.account_type(), AccountType::FungibleFaucet) {
+            return Err(AssetVaultError::NotAFungibleFaucetId(faucet_id));
+        }
+
+        let vault_key = [ZERO, ZERO, ZERO, faucet_id.into()];
+        match self.get(vault_key) {
+            None => Err(AssetVaultError::PartialVaultKeyNotTracked(vault_key.into())),
+            Some(asset) if asset == Smt::EMPTY_VALUE => Ok(0),
+            Some(asset) => Ok(FungibleAsset::new_unchecked(asset).amount()),
+        }
+    }
+
+    /// Returns true if the specified non-fungible asset is stored in the vault.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The specified asset is not a non-fungible asset.
+    /// - The vault key of the asset is not tracked by this partial vault.
+    pub fn has_non_fungible_asset(&self, asset: Asset) -> Result<bool, AssetVaultError> {
+        if asset.is_fungible() {
+            return Err(AssetVaultError::NotANonFungibleAsset(asset));
+        }
+
+        match self.get(asset.vault_key()) {
+            None => Err(AssetVaultError::PartialVaultKeyNotTracked(asset.vault_key().into())),
+            Some(asset) => Ok(asset != Smt::EMPTY_VALUE),
+        }
+    }
+
+    /// Returns an iterator over the assets stored under the tracked vault keys.
+    pub fn assets(&self) -> impl Iterator<Item = Asset> + '_ {
+        self.proofs
+            .iter()
+            .filter_map(|(vault_key, proof)| proof.get(vault_key))
+            .filter(|asset| *asset != Smt::EMPTY_VALUE)
+            .map(Asset::new_unchecked)
+    }
+
+    /// Returns an iterator over the Sparse Merkle tree leaves holding the tracked vault keys.
+    ///
+    /// A leaf holding several tracked vault keys is returned once for each of the keys.
+    pub fn leaves(&self) -> impl Iterator<Item = &SmtLeaf> {
+        self.proofs.values().map(|proof| proof.leaf())
+    }
+
+    /// Returns an iterator over the inner nodes of the vault tree which are required to
+    /// authenticate the tracked leaves against the vault root.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.proofs.values().flat_map(|proof| {
+            let (path, leaf) = proof.clone().into_parts();
+            path.inner_nodes(leaf.index().value(), leaf.hash())
+                .expect("leaf index is within the depth of the vault tree")
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PartialVault {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.root.write_into(target);
+        target.write_usize(self.proofs.len());
+        for (vault_key, proof) in self.proofs.iter() {
+            vault_key.write_into(target);
+            write_smt_proof(proof, target);
+        }
+    }
+}
+
+impl Deserializable for PartialVault {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let root = Digest::read_from(source)?;
+        let num_proofs = source.read_usize()?;
+        let mut proofs = Vec::with_capacity(num_proofs);
+        for _ in 0..num_proofs {
+            let vault_key = Digest::read_from(source)?;
+            let proof = read_smt_proof(source)?;
+            proofs.push((vault_key, proof));
+        }
+
+        Self::new(root, proofs).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+/// Writes the provided opening of a vault entry into the target.
+pub(super) fn write_smt_proof<W: ByteWriter>(proof: &SmtProof, target: &mut W) {
+    proof.path().write_into(target);
+
+    let leaf = proof.leaf();
+    target.write_u64(leaf.index().value());
+    let entries = leaf.entries();
+    target.write_usize(entries.len());
+    for (key, value) in entries {
+        key.write_into(target);
+        value.write_into(target);
+    }
+}
+
+/// Reads an opening of a vault entry written via [write_smt_proof()] from the source.
+pub(super) fn read_smt_proof<R: ByteReader>(
+    source: &mut R,
+) -> Result<SmtProof, DeserializationError> {
+    let path = MerklePath::read_from(source)?;
+
+    let leaf_index = LeafIndex::new_max_depth(source.read_u64()?);
+    let num_entries = source.read_usize()?;
+    let mut entries = Vec::with_capacity(num_entries);
+    for _ in 0..num_entries {
+        entries.push((Digest::read_from(source)?, Word::read_from(source)?));
+    }
+    let leaf = SmtLeaf::new(entries, leaf_index)
+        .map_err(|err| DeserializationError::InvalidValue(format!("{err:?}")))?;
+
+    SmtProof::new(path, leaf).map_err(|err| DeserializationError::InvalidValue(format!("{err:?}")))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountId, Asset, AssetVault, FungibleAsset, PartialVault, Smt, Vec};
+    use crate::{
+        accounts::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2},
+        crypto::merkle::MerkleStore,
+        AssetVaultError, ZERO,
+    };
+
+    #[test]
+    fn partial_vault_tracks_opened_keys() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let vault = AssetVault::new(&[asset]).unwrap();
+
+        let empty_key = [ZERO, ZERO, ZERO, other_faucet_id.into()];
+        let partial_vault =
+            PartialVault::from_vault(&vault, [asset.vault_key(), empty_key].into_iter());
+
+        assert_eq!(partial_vault.commitment(), vault.commitment());
+        assert_eq!(partial_vault.num_tracked_keys(), 2);
+        assert_eq!(partial_vault.get_balance(faucet_id), Ok(100));
+        assert_eq!(partial_vault.get_balance(other_faucet_id), Ok(0));
+        assert_eq!(partial_vault.get(empty_key), Some(Smt::EMPTY_VALUE));
+        assert_eq!(partial_vault.assets().collect::<Vec<_>>(), vec![asset]);
+
+        // the inner nodes authenticate the tracked leaves against the vault root
+        let store: MerkleStore = partial_vault.inner_nodes().collect();
+        for leaf in partial_vault.leaves() {
+            let node = store.get_node(vault.commitment(), leaf.index().into()).unwrap();
+            assert_eq!(node, leaf.hash());
+        }
+
+        // the openings can be validated against the vault root
+        let proofs =
+            [asset.vault_key()].map(|key| (key.into(), vault.asset_tree().open(&key.into())));
+        let rebuilt = PartialVault::new(vault.commitment(), proofs.clone()).unwrap();
+        assert_eq!(rebuilt.get(asset.vault_key()), Some(asset.into()));
+
+        let result = PartialVault::new(AssetVault::default().commitment(), proofs);
+        assert!(matches!(result, Err(AssetVaultError::PartialVaultInvalidOpening { .. })));
+    }
+}
@@ -1,8 +1,29 @@
 use super::{
     AccountId, AccountType, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
-    FungibleAsset, NonFungibleAsset, Serializable, ToString, Vec, ZERO,
+    FungibleAsset, NonFungibleAsset, Serializable, ToString, VaultProof, Vec, Word, ZERO,
 };
-use crate::{crypto::merkle::Smt, AssetVaultError, Digest};
+use crate::{accounts::AccountVaultDelta, crypto::merkle::Smt, AssetVaultError, Digest};
+
+// ASSET CHANGE
+// ================================================================================================
+
+/// A single change to be applied to an [AssetVault] via [AssetVault::apply()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetChange {
+    /// Adds the asset to the vault.
+    Add(Asset),
+    /// Removes the asset from the vault.
+    Remove(Asset),
+}
+
+impl AssetChange {
+    /// Returns the asset affected by this change.
+    pub fn asset(&self) -> Asset {
+        match self {
+            Self::Add(asset) | Self::Remove(asset) => *asset,
+        }
+    }
+}
 
 // ASSET VAULT
 // ================================================================================================
@@ -79,6 +100,27 @@ impl AssetVault {
         self.asset_tree.entries().map(|x| Asset::new_unchecked(x.1))
     }
 
+    /// Returns an iterator over the balances of the fungible assets stored in the vault, as
+    /// `(faucet_id, amount)` pairs.
+    ///
+    /// The balances are returned in the order of the vault keys of the assets, and thus are not
+    /// sorted by faucet ID.
+    pub fn fungible_balances(&self) -> impl Iterator<Item = (AccountId, u64)> + '_ {
+        self.assets().filter_map(|asset| match asset {
+            Asset::Fungible(asset) => Some((asset.faucet_id(), asset.amount())),
+            Asset::NonFungible(_) => None,
+        })
+    }
+
+    /// Returns a proof of the content of this vault under the specified vault key against the
+    /// commitment to this vault.
+    ///
+    /// The vault key of an asset can be obtained via [Asset::vault_key()]; for fungible assets, the
+    /// proof covers the entire balance of the issuing faucet.
+    pub fn open(&self, vault_key: Word) -> VaultProof {
+        VaultProof::new(vault_key, self.asset_tree.open(&vault_key.into()))
+    }
+
     /// Returns a reference to the Sparse Merkle Tree underling this asset vault.
     pub fn asset_tree(&self) -> &Smt {
         &self.asset_tree
@@ -143,6 +185,70 @@ impl AssetVault {
         Ok(asset)
     }
 
+    // APPLY CHANGES
+    // --------------------------------------------------------------------------------------------
+    /// Applies the specified changes to the vault in order, and returns the delta between the
+    /// initial and final states of the vault.
+    ///
+    /// The changes are applied atomically: if any of the changes cannot be applied, the vault is
+    /// left unmodified. The returned delta contains the net effect of the changes; e.g., adding
+    /// and then removing the same asset results in an empty delta.
+    ///
+    /// # Errors
+    /// Returns an error if any of the changes cannot be applied to the vault in the specified
+    /// order (see [AssetVault::add_asset()] and [AssetVault::remove_asset()]).
+    pub fn apply(
+        &mut self,
+        changes: impl IntoIterator<Item = AssetChange>,
+    ) -> Result<AccountVaultDelta, AssetVaultError> {
+        let mut updated = self.clone();
+        let mut touched_assets: Vec<Asset> = Vec::new();
+        for change in changes {
+            match change {
+                AssetChange::Add(asset) => updated.add_asset(asset)?,
+                AssetChange::Remove(asset) => updated.remove_asset(asset)?,
+            };
+
+            let asset = change.asset();
+            if !touched_assets.iter().any(|a| a.is_same(&asset)) {
+                touched_assets.push(asset);
+            }
+        }
+
+        // compute the net change of each of the affected assets
+        let mut delta = AccountVaultDelta::default();
+        for asset in touched_assets {
+            match asset {
+                Asset::Fungible(asset) => {
+                    let faucet_id = asset.faucet_id();
+                    let initial = self.get_balance(faucet_id)?;
+                    let current = updated.get_balance(faucet_id)?;
+                    if current > initial {
+                        let added = FungibleAsset::new(faucet_id, current - initial)
+                            .expect("fungible asset is well formed");
+                        delta.added_assets.push(added.into());
+                    } else if current < initial {
+                        let removed = FungibleAsset::new(faucet_id, initial - current)
+                            .expect("fungible asset is well formed");
+                        delta.removed_assets.push(removed.into());
+                    }
+                },
+                Asset::NonFungible(_) => {
+                    let initial = self.has_non_fungible_asset(asset)?;
+                    let current = updated.has_non_fungible_asset(asset)?;
+                    match (initial, current) {
+                        (false, true) => delta.added_assets.push(asset),
+                        (true, false) => delta.removed_assets.push(asset),
+                        _ => (),
+                    }
+                },
+            }
+        }
+
+        *self = updated;
+        Ok(delta)
+    }
+
     // REMOVE ASSET
     // --------------------------------------------------------------------------------------------
     /// Remove the specified asset from the vault.
@@ -235,3 +341,171 @@ impl Deserializable for AssetVault {
         Self::new(&assets).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccountId, AccountVaultDelta, Asset, AssetChange, AssetVault, Deserializable,
+        FungibleAsset, NonFungibleAsset, Serializable, VaultProof, Vec,
+    };
+    use crate::{
+        accounts::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+        },
+        assets::NonFungibleAssetDetails,
+        AssetError, AssetVaultError,
+    };
+
+    #[test]
+    fn vault_balance_queries() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let nft_faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+
+        let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let non_fungible_asset: Asset = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![1, 2, 3]).unwrap(),
+        )
+        .unwrap()
+        .into();
+        let missing_asset: Asset = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![4, 5, 6]).unwrap(),
+        )
+        .unwrap()
+        .into();
+
+        let vault = AssetVault::new(&[fungible_asset, non_fungible_asset]).unwrap();
+
+        assert_eq!(vault.get_balance(faucet_id), Ok(100));
+        assert_eq!(vault.get_balance(other_faucet_id), Ok(0));
+        assert_eq!(
+            vault.get_balance(nft_faucet_id),
+            Err(AssetVaultError::NotAFungibleFaucetId(nft_faucet_id))
+        );
+
+        assert_eq!(vault.has_non_fungible_asset(non_fungible_asset), Ok(true));
+        assert_eq!(vault.has_non_fungible_asset(missing_asset), Ok(false));
+        assert_eq!(
+            vault.has_non_fungible_asset(fungible_asset),
+            Err(AssetVaultError::NotANonFungibleAsset(fungible_asset))
+        );
+
+        assert_eq!(vault.fungible_balances().collect::<Vec<_>>(), vec![(faucet_id, 100)]);
+        assert_eq!(AssetVault::default().fungible_balances().count(), 0);
+    }
+
+    #[test]
+    fn vault_proofs() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let nft_faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+
+        let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+        let non_fungible_asset = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![1, 2, 3]).unwrap(),
+        )
+        .unwrap();
+        let missing_asset = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![4, 5, 6]).unwrap(),
+        )
+        .unwrap();
+
+        let vault = AssetVault::new(&[fungible_asset.into(), non_fungible_asset.into()]).unwrap();
+        let root = vault.commitment();
+
+        // the proof of a fungible asset proves any balance up to the amount held by the vault
+        let proof = vault.open(fungible_asset.vault_key());
+        assert!(proof.verify(root));
+        assert_eq!(proof.asset(), Some(fungible_asset.into()));
+        assert!(proof.verify_fungible_balance(root, FungibleAsset::new(faucet_id, 60).unwrap()));
+        assert!(proof.verify_fungible_balance(root, fungible_asset));
+        assert!(!proof.verify_fungible_balance(root, FungibleAsset::new(faucet_id, 101).unwrap()));
+        assert!(
+            !proof.verify_fungible_balance(root, FungibleAsset::new(other_faucet_id, 1).unwrap())
+        );
+        assert!(!proof.verify_absence(root));
+
+        // proofs of non-fungible assets
+        let proof = vault.open(non_fungible_asset.vault_key());
+        assert!(proof.verify_non_fungible_asset(root, non_fungible_asset));
+        let proof = vault.open(missing_asset.vault_key());
+        assert!(!proof.verify_non_fungible_asset(root, missing_asset));
+        assert!(proof.verify_absence(root));
+        let proof = vault.open(FungibleAsset::new(other_faucet_id, 1).unwrap().vault_key());
+        assert!(proof.verify_absence(root));
+
+        // proofs do not verify against a different vault root
+        let proof = vault.open(fungible_asset.vault_key());
+        assert!(!proof.verify(AssetVault::default().commitment()));
+        assert!(!proof.verify_fungible_balance(AssetVault::default().commitment(), fungible_asset));
+
+        let bytes = proof.to_bytes();
+        assert_eq!(VaultProof::read_from_bytes(&bytes).unwrap(), proof);
+    }
+
+    #[test]
+    fn vault_apply_changes() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let nft_faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+
+        let fungible =
+            |faucet_id, amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+        let non_fungible_asset: Asset = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![1, 2, 3]).unwrap(),
+        )
+        .unwrap()
+        .into();
+
+        let mut vault =
+            AssetVault::new(&[fungible(faucet_id, 100), fungible(other_faucet_id, 50)]).unwrap();
+
+        // the delta contains the net effect of the changes
+        let delta = vault
+            .apply([
+                AssetChange::Add(fungible(faucet_id, 30)),
+                AssetChange::Remove(fungible(faucet_id, 50)),
+                AssetChange::Add(non_fungible_asset),
+                AssetChange::Add(fungible(other_faucet_id, 10)),
+                AssetChange::Remove(fungible(other_faucet_id, 10)),
+            ])
+            .unwrap();
+        assert_eq!(
+            delta,
+            AccountVaultDelta {
+                added_assets: vec![non_fungible_asset],
+                removed_assets: vec![fungible(faucet_id, 20)],
+            }
+        );
+        assert_eq!(
+            vault,
+            AssetVault::new(&[
+                fungible(faucet_id, 80),
+                fungible(other_faucet_id, 50),
+                non_fungible_asset
+            ])
+            .unwrap()
+        );
+
+        // a failing change leaves the vault unmodified
+        let expected_vault = vault.clone();
+        let result = vault.apply([
+            AssetChange::Remove(non_fungible_asset),
+            AssetChange::Remove(fungible(faucet_id, 100)),
+        ]);
+        assert!(matches!(
+            result,
+            Err(AssetVaultError::SubtractFungibleAssetBalanceError(
+                AssetError::AssetAmountNotSufficient(80, 100)
+            ))
+        ));
+        assert_eq!(vault, expected_vault);
+
+        // an empty batch of changes results in an empty delta
+        assert!(vault.apply([]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+use super::{
+    partial_vault::{read_smt_proof, write_smt_proof},
+    Asset, ByteReader, ByteWriter, Deserializable, DeserializationError, FungibleAsset,
+    NonFungibleAsset, Serializable,
+};
+use crate::{
+    crypto::merkle::{Smt, SmtProof},
+    Digest, Word,
+};
+
+// VAULT PROOF
+// ================================================================================================
+
+/// A proof of the content of an [AssetVault](super::AssetVault) under a single vault key.
+///
+/// The proof consists of the vault key and the opening of the Sparse Merkle tree leaf holding the
+/// key against the vault root. Thus, it proves either that the vault holds a given asset under
+/// the key (for fungible assets, this is the entire balance of the issuing faucet), or that the
+/// vault does not hold an asset under the key.
+///
+/// A proof can be verified against a vault root without knowing the rest of the vault (see
+/// [VaultProof::verify()]); this allows third parties, e.g., bridges or auditors, to check the
+/// holdings of an account at a given vault root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaultProof {
+    vault_key: Digest,
+    proof: SmtProof,
+}
+
+impl VaultProof {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [VaultProof] instantiated from the provided vault key and opening.
+    pub fn new(vault_key: Word, proof: SmtProof) -> Self {
+        Self { vault_key: vault_key.into(), proof }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the vault key this proof is for.
+    pub fn vault_key(&self) -> Word {
+        self.vault_key.into()
+    }
+
+    /// Returns the opening of the vault key against the vault root.
+    pub fn proof(&self) -> &SmtProof {
+        &self.proof
+    }
+
+    /// Returns the vault root this proof opens to.
+    pub fn compute_root(&self) -> Digest {
+        self.proof.compute_root()
+    }
+
+    /// Returns the asset stored under the vault key according to this proof, or None if the vault
+    /// does not hold an asset under the key.
+    ///
+    /// This does not check the validity of the proof; see [VaultProof::verify()].
+    pub fn asset(&self) -> Option<Asset> {
+        match self.proof.get(&self.vault_key) {
+            Some(value) if value != Smt::EMPTY_VALUE => Some(Asset::new_unchecked(value)),
+            _ => None,
+        }
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if this proof opens to the specified vault root.
+    pub fn verify(&self, vault_root: Digest) -> bool {
+        self.proof.get(&self.vault_key).is_some() && self.proof.compute_root() == vault_root
+    }
+
+    /// Returns true if this proof opens to the specified vault root and proves that the vault
+    /// holds at least the amount of the provided asset.
+    pub fn verify_fungible_balance(&self, vault_root: Digest, asset: FungibleAsset) -> bool {
+        if !self.verify(vault_root) || self.vault_key() != asset.vault_key() {
+            return false;
+        }
+
+        match self.asset() {
+            Some(Asset::Fungible(balance)) => balance.amount() >= asset.amount(),
+            _ => false,
+        }
+    }
+
+    /// Returns true if this proof opens to the specified vault root and proves that the vault
+    /// holds the provided asset.
+    pub fn verify_non_fungible_asset(&self, vault_root: Digest, asset: NonFungibleAsset) -> bool {
+        self.verify(vault_root) && self.asset() == Some(Asset::NonFungible(asset))
+    }
+
+    /// Returns true if this proof opens to the specified vault root and proves that the vault does
+    /// not hold an asset under the vault key.
+    pub fn verify_absence(&self, vault_root: Digest) -> bool {
+        self.verify(vault_root) && self.asset().is_none()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for VaultProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.vault_key.write_into(target);
+        write_smt_proof(&self.proof, target);
+    }
+}
+
+impl Deserializable for VaultProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let vault_key = Digest::read_from(source)?;
+        let proof = read_smt_proof(source)?;
+
+        Ok(Self { vault_key, proof })
+    }
+}
@@ -0,0 +1,37 @@
+use bech32::{FromBase32, ToBase32, Variant};
+
+use crate::utils::{
+    collections::Vec,
+    format,
+    string::{String, ToString},
+};
+
+// BECH32 ENCODING
+// ================================================================================================
+
+/// Returns the bech32m encoding of the provided bytes with the specified human-readable part.
+///
+/// # Errors
+/// Returns an error if the human-readable part is not valid (e.g., if it is empty or contains
+/// characters outside of the printable ASCII range).
+pub(crate) fn encode(hrp: &str, bytes: &[u8]) -> Result<String, String> {
+    bech32::encode(hrp, bytes.to_base32(), Variant::Bech32m).map_err(|err| err.to_string())
+}
+
+/// Decodes the provided bech32m string and returns the encoded bytes.
+///
+/// # Errors
+/// Returns an error if:
+/// - The string is not a valid bech32m string (e.g., the checksum is invalid).
+/// - The human-readable part of the string is not the specified one.
+pub(crate) fn decode(hrp: &str, value: &str) -> Result<Vec<u8>, String> {
+    let (actual_hrp, data, variant) = bech32::decode(value).map_err(|err| err.to_string())?;
+    if variant != Variant::Bech32m {
+        return Err("string is bech32 encoded, expected bech32m".to_string());
+    }
+    if actual_hrp != hrp.to_lowercase() {
+        return Err(format!("expected human-readable part {hrp}, found {actual_hrp}"));
+    }
+
+    Vec::<u8>::from_base32(&data).map_err(|err| err.to_string())
+}
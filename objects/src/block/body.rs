@@ -0,0 +1,153 @@
+use super::{
+    BlockHeader, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher,
+    Serializable, Vec,
+};
+use crate::{
+    accounts::AccountId,
+    crypto::merkle::{LeafIndex, SimpleSmt},
+    notes::{NoteEnvelope, NoteInclusionProof, Nullifier},
+    Word, NOTE_TREE_DEPTH,
+};
+
+// BLOCK BODY
+// ================================================================================================
+
+/// The effects of a block on the state of the chain.
+///
+/// The body of a block consists of:
+/// - The updated accounts: the ID and the new state hash of every account updated in the block,
+///   sorted by account ID.
+/// - The nullifiers of the notes consumed in the block.
+/// - The notes created in the block, in the order of their index in the note tree of the block.
+///
+/// The note tree of the block is a Sparse Merkle tree of depth [NOTE_TREE_DEPTH]; the leaf of the
+/// note at index `i` is the hash of the ID and the metadata of the `i`-th created note. The root
+/// of the tree is the note root of the block header.
+#[derive(Debug, Clone)]
+pub struct BlockBody {
+    updated_accounts: Vec<(AccountId, Digest)>,
+    nullifiers: Vec<Nullifier>,
+    output_notes: Vec<NoteEnvelope>,
+    note_tree: SimpleSmt<NOTE_TREE_DEPTH>,
+}
+
+impl BlockBody {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BlockBody] instantiated from the provided effects.
+    ///
+    /// # Panics
+    /// Panics if more than 2^[NOTE_TREE_DEPTH] output notes are provided.
+    pub fn new(
+        updated_accounts: Vec<(AccountId, Digest)>,
+        nullifiers: Vec<Nullifier>,
+        output_notes: Vec<NoteEnvelope>,
+    ) -> Self {
+        let note_tree = build_note_tree(&output_notes);
+        Self {
+            updated_accounts,
+            nullifiers,
+            output_notes,
+            note_tree,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID and the new state hash of every account updated in the block.
+    pub fn updated_accounts(&self) -> &[(AccountId, Digest)] {
+        &self.updated_accounts
+    }
+
+    /// Returns the nullifiers of the notes consumed in the block.
+    pub fn nullifiers(&self) -> &[Nullifier] {
+        &self.nullifiers
+    }
+
+    /// Returns the notes created in the block.
+    pub fn output_notes(&self) -> &[NoteEnvelope] {
+        &self.output_notes
+    }
+
+    /// Returns the root of the note tree of the block.
+    pub fn note_root(&self) -> Digest {
+        self.note_tree.root()
+    }
+
+    /// Returns the proof of inclusion of the note at the specified index in the block with the
+    /// provided header, or None if the block does not contain a note at this index.
+    ///
+    /// The header must be the header of the block this body belongs to.
+    pub fn open_note(&self, header: &BlockHeader, index: usize) -> Option<NoteInclusionProof> {
+        if index >= self.output_notes.len() {
+            return None;
+        }
+
+        let leaf_index = LeafIndex::new(index as u64).expect("index is within the note tree");
+        let proof = NoteInclusionProof::new(
+            header.block_num(),
+            header.sub_hash(),
+            header.note_root(),
+            index as u64,
+            self.note_tree.open(&leaf_index).path,
+        )
+        .expect("index is within the note tree");
+
+        Some(proof)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the note tree of a block containing the provided notes.
+pub(super) fn build_note_tree(notes: &[NoteEnvelope]) -> SimpleSmt<NOTE_TREE_DEPTH> {
+    let leaves = notes.iter().enumerate().map(|(index, note)| {
+        let leaf = Hasher::merge(&[note.note_id().inner(), Word::from(note.metadata()).into()]);
+        (index as u64, Word::from(leaf))
+    });
+
+    SimpleSmt::with_leaves(leaves).expect("note tree has enough leaves for all notes of a block")
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BlockBody {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.updated_accounts.len());
+        for (account_id, account_hash) in self.updated_accounts.iter() {
+            account_id.write_into(target);
+            account_hash.write_into(target);
+        }
+        target.write_usize(self.nullifiers.len());
+        target.write_many(&self.nullifiers);
+        target.write_usize(self.output_notes.len());
+        target.write_many(&self.output_notes);
+    }
+}
+
+impl Deserializable for BlockBody {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_accounts = source.read_usize()?;
+        let mut updated_accounts = Vec::with_capacity(num_accounts);
+        for _ in 0..num_accounts {
+            let account_id = AccountId::read_from(source)?;
+            let account_hash = Digest::read_from(source)?;
+            updated_accounts.push((account_id, account_hash));
+        }
+        let num_nullifiers = source.read_usize()?;
+        let nullifiers = source.read_many::<Nullifier>(num_nullifiers)?;
+        let num_notes = source.read_usize()?;
+        if num_notes > 1 << NOTE_TREE_DEPTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "block body contains {num_notes} notes, which exceeds the capacity of the note tree"
+            )));
+        }
+        let output_notes = source.read_many::<NoteEnvelope>(num_notes)?;
+
+        Ok(Self::new(updated_accounts, nullifiers, output_notes))
+    }
+}
@@ -0,0 +1,204 @@
+use super::{BlockHeader, Digest, Felt, ZERO};
+
+// BLOCK HEADER BUILDER
+// ================================================================================================
+
+/// A builder for block headers.
+///
+/// All fields of the header which are not explicitly set default to zero. A builder for the
+/// successor of an existing block can be created via [BlockHeaderBuilder::next()], in which case
+/// the previous hash, the block number and the protocol version are derived from the previous
+/// block header.
+///
+/// # Example
+/// ```ignore
+/// let header = BlockHeaderBuilder::next(&prev_header)
+///     .chain_root(chain_root)
+///     .account_root(account_root)
+///     .timestamp(timestamp)
+///     .build();
+/// assert!(header.validate_against(&prev_header).is_ok());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BlockHeaderBuilder {
+    prev_hash: Digest,
+    block_num: u32,
+    chain_root: Digest,
+    account_root: Digest,
+    nullifier_root: Digest,
+    note_root: Digest,
+    batch_root: Digest,
+    proof_hash: Digest,
+    version: Felt,
+    timestamp: Felt,
+}
+
+impl BlockHeaderBuilder {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BlockHeaderBuilder] with all fields set to zero, i.e., a builder for the
+    /// genesis block header.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [BlockHeaderBuilder] for the block following the specified block.
+    ///
+    /// The previous hash is set to the hash of the specified header, the block number to the
+    /// block number of the specified header plus one, and the protocol version and the timestamp
+    /// to the ones of the specified header.
+    ///
+    /// # Panics
+    /// Panics if the block number of the specified header is [u32::MAX].
+    pub fn next(prev: &BlockHeader) -> Self {
+        let block_num = prev.block_num().checked_add(1).expect("block number overflow");
+
+        Self {
+            prev_hash: prev.hash(),
+            block_num,
+            version: prev.version(),
+            timestamp: prev.timestamp(),
+            ..Self::default()
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the hash of the previous block header.
+    pub fn prev_hash(mut self, prev_hash: Digest) -> Self {
+        self.prev_hash = prev_hash;
+        self
+    }
+
+    /// Sets the block number.
+    pub fn block_num(mut self, block_num: u32) -> Self {
+        self.block_num = block_num;
+        self
+    }
+
+    /// Sets the root of the chain MMR.
+    pub fn chain_root(mut self, chain_root: Digest) -> Self {
+        self.chain_root = chain_root;
+        self
+    }
+
+    /// Sets the account database root.
+    pub fn account_root(mut self, account_root: Digest) -> Self {
+        self.account_root = account_root;
+        self
+    }
+
+    /// Sets the nullifier database root.
+    pub fn nullifier_root(mut self, nullifier_root: Digest) -> Self {
+        self.nullifier_root = nullifier_root;
+        self
+    }
+
+    /// Sets the root of the tree of notes created in the block.
+    pub fn note_root(mut self, note_root: Digest) -> Self {
+        self.note_root = note_root;
+        self
+    }
+
+    /// Sets the batch root.
+    pub fn batch_root(mut self, batch_root: Digest) -> Self {
+        self.batch_root = batch_root;
+        self
+    }
+
+    /// Sets the proof hash.
+    pub fn proof_hash(mut self, proof_hash: Digest) -> Self {
+        self.proof_hash = proof_hash;
+        self
+    }
+
+    /// Sets the protocol version.
+    pub fn version(mut self, version: Felt) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the timestamp at which the block was created.
+    pub fn timestamp(mut self, timestamp: Felt) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the block header.
+    pub fn build(self) -> BlockHeader {
+        BlockHeader::new(
+            self.prev_hash,
+            self.block_num,
+            self.chain_root,
+            self.account_root,
+            self.nullifier_root,
+            self.note_root,
+            self.batch_root,
+            self.proof_hash,
+            self.version,
+            self.timestamp,
+        )
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{BlockHeaderBuilder, Digest, Felt, ZERO};
+    use crate::{BlockHeaderError, ONE};
+
+    #[test]
+    fn build_and_validate_block_headers() {
+        let genesis = BlockHeaderBuilder::new().timestamp(Felt::new(10)).build();
+        assert_eq!(genesis.block_num(), 0);
+        assert_eq!(genesis.prev_hash(), Digest::default());
+        assert_eq!(genesis.version(), ZERO);
+
+        let root: Digest = [ONE, ZERO, ZERO, ZERO].into();
+        let header = BlockHeaderBuilder::next(&genesis)
+            .account_root(root)
+            .timestamp(Felt::new(20))
+            .build();
+        assert_eq!(header.block_num(), 1);
+        assert_eq!(header.prev_hash(), genesis.hash());
+        assert_eq!(header.account_root(), root);
+        assert_eq!(header.validate_against(&genesis), Ok(()));
+
+        // the builder computes the same header as the constructor
+        let expected = BlockHeaderBuilder::new()
+            .prev_hash(genesis.hash())
+            .block_num(1)
+            .account_root(root)
+            .timestamp(Felt::new(20))
+            .build();
+        assert_eq!(header, expected);
+
+        // block number must be sequential
+        let header = BlockHeaderBuilder::next(&genesis).block_num(2).build();
+        assert_eq!(
+            header.validate_against(&genesis),
+            Err(BlockHeaderError::InconsistentBlockNum { expected: 1, actual: 2 })
+        );
+
+        // previous hash must be the hash of the previous header
+        let header = BlockHeaderBuilder::next(&genesis).prev_hash(root).build();
+        assert_eq!(
+            header.validate_against(&genesis),
+            Err(BlockHeaderError::InconsistentPrevHash { expected: genesis.hash(), actual: root })
+        );
+
+        // timestamp must not decrease
+        let header = BlockHeaderBuilder::next(&genesis).timestamp(Felt::new(5)).build();
+        assert_eq!(
+            header.validate_against(&genesis),
+            Err(BlockHeaderError::TimestampDecreased { prev: 10, current: 5 })
+        );
+    }
+}
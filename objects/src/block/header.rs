@@ -1,4 +1,7 @@
-use super::{Digest, Felt, Hasher, Vec, ZERO};
+use super::{
+    BlockHeaderError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt,
+    Hasher, Serializable, StarkField, Vec, ZERO,
+};
 
 /// The header of a block. It contains metadata about the block, commitments to the current
 /// state of the chain and the hash of the proof that attests to the integrity of the chain.
@@ -149,6 +152,46 @@ impl BlockHeader {
         self.timestamp
     }
 
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that this block header is a valid successor of the specified block header.
+    ///
+    /// Only the fields which can be checked against the previous header alone are validated;
+    /// specifically, the chain root, the database roots and the proof hash are not checked.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of this header is not the block number of the previous header plus one.
+    /// - The previous hash of this header is not the hash of the previous header.
+    /// - The timestamp of this header is smaller than the timestamp of the previous header.
+    pub fn validate_against(&self, prev: &BlockHeader) -> Result<(), BlockHeaderError> {
+        let expected_block_num =
+            prev.block_num.checked_add(1).ok_or(BlockHeaderError::BlockNumOverflow)?;
+        if self.block_num != expected_block_num {
+            return Err(BlockHeaderError::InconsistentBlockNum {
+                expected: expected_block_num,
+                actual: self.block_num,
+            });
+        }
+
+        if self.prev_hash != prev.hash {
+            return Err(BlockHeaderError::InconsistentPrevHash {
+                expected: prev.hash,
+                actual: self.prev_hash,
+            });
+        }
+
+        if self.timestamp.as_int() < prev.timestamp.as_int() {
+            return Err(BlockHeaderError::TimestampDecreased {
+                prev: prev.timestamp.as_int(),
+                current: self.timestamp.as_int(),
+            });
+        }
+
+        Ok(())
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -181,3 +224,49 @@ impl BlockHeader {
         Hasher::hash_elements(&elements)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BlockHeader {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.prev_hash.write_into(target);
+        target.write_u32(self.block_num);
+        self.chain_root.write_into(target);
+        self.account_root.write_into(target);
+        self.nullifier_root.write_into(target);
+        self.note_root.write_into(target);
+        self.batch_root.write_into(target);
+        self.proof_hash.write_into(target);
+        self.version.write_into(target);
+        self.timestamp.write_into(target);
+    }
+}
+
+impl Deserializable for BlockHeader {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let prev_hash = Digest::read_from(source)?;
+        let block_num = source.read_u32()?;
+        let chain_root = Digest::read_from(source)?;
+        let account_root = Digest::read_from(source)?;
+        let nullifier_root = Digest::read_from(source)?;
+        let note_root = Digest::read_from(source)?;
+        let batch_root = Digest::read_from(source)?;
+        let proof_hash = Digest::read_from(source)?;
+        let version = Felt::read_from(source)?;
+        let timestamp = Felt::read_from(source)?;
+
+        Ok(Self::new(
+            prev_hash,
+            block_num,
+            chain_root,
+            account_root,
+            nullifier_root,
+            note_root,
+            batch_root,
+            proof_hash,
+            version,
+            timestamp,
+        ))
+    }
+}
@@ -1,4 +1,28 @@
-use super::{utils::collections::Vec, Digest, Felt, Hasher, ZERO};
+use super::{
+    utils::{
+        collections::Vec,
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    },
+    BlockHeaderError, Digest, Felt, Hasher, StarkField, ZERO,
+};
+
+mod body;
+pub use body::BlockBody;
+
+mod builder;
+pub use builder::BlockHeaderBuilder;
 
 mod header;
 pub use header::BlockHeader;
+
+mod nullifier_tree;
+pub use nullifier_tree::NullifierTree;
+
+mod proposed;
+pub use proposed::{apply_batches, ProposedBlock};
+
+mod state;
+pub use state::ChainState;
+
+mod witness;
+pub use witness::{AccountWitness, NullifierWitness};
@@ -0,0 +1,168 @@
+use super::{BlockHeader, Digest, Felt, NullifierWitness, StarkField, ZERO};
+use crate::{
+    crypto::merkle::Smt,
+    notes::{Note, Nullifier},
+    BlockError, Word, EMPTY_WORD,
+};
+
+// NULLIFIER TREE
+// ================================================================================================
+
+/// The set of nullifiers of consumed notes, i.e., the nullifier database of the chain.
+///
+/// The nullifier tree is a Sparse Merkle tree which maps the nullifier of every consumed note to
+/// `[block_num, 0, 0, 0]`, where `block_num` is the number of the block in which the note was
+/// consumed; the nullifiers of notes which have not been consumed are mapped to the empty word.
+/// Thus, the root of the tree as of a given block is the nullifier root of the header of the block
+/// (see [BlockHeader::nullifier_root()]), and a [NullifierWitness] opened against the tree proves
+/// whether a note has been consumed.
+///
+/// Besides block producers, the tree can be used by data stores to reject transactions consuming
+/// notes which have already been consumed (see [NullifierTree::is_note_consumed()]) before
+/// executing them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullifierTree {
+    smt: Smt,
+}
+
+impl NullifierTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [NullifierTree].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [NullifierTree] containing the provided nullifiers, each of which is marked as
+    /// spent in the specified block.
+    ///
+    /// # Errors
+    /// Returns an error if the same nullifier is provided more than once.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Nullifier, u32)>,
+    ) -> Result<Self, BlockError> {
+        let mut tree = Self::new();
+        for (nullifier, block_num) in entries {
+            tree.insert(nullifier, block_num)?;
+        }
+
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of this tree.
+    pub fn root(&self) -> Digest {
+        self.smt.root()
+    }
+
+    /// Returns true if the specified nullifier is in this tree, i.e., if the note with this
+    /// nullifier has been consumed.
+    pub fn contains(&self, nullifier: Nullifier) -> bool {
+        self.get_block_num(nullifier).is_some()
+    }
+
+    /// Returns true if the specified note has been consumed according to this tree.
+    pub fn is_note_consumed(&self, note: &Note) -> bool {
+        self.contains(note.nullifier())
+    }
+
+    /// Returns the number of the block in which the note with the specified nullifier was
+    /// consumed, or None if the nullifier is not in this tree.
+    pub fn get_block_num(&self, nullifier: Nullifier) -> Option<u32> {
+        match self.smt.get_value(&nullifier.inner()) {
+            value if value == EMPTY_WORD => None,
+            value => Some(value[0].as_int() as u32),
+        }
+    }
+
+    /// Returns a witness of the state of the specified nullifier in this tree.
+    ///
+    /// The witness is valid for a block header whose nullifier root is the root of this tree (see
+    /// [NullifierWitness::is_valid_for()]).
+    pub fn open(&self, nullifier: Nullifier) -> NullifierWitness {
+        NullifierWitness::new(nullifier, self.smt.open(&nullifier.inner()))
+    }
+
+    /// Returns true if the root of this tree is the nullifier root of the provided block header.
+    pub fn matches(&self, block_header: &BlockHeader) -> bool {
+        self.root() == block_header.nullifier_root()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Marks the specified nullifier as spent in the specified block.
+    ///
+    /// # Errors
+    /// Returns an error if the nullifier is already in this tree; the tree is not modified in this
+    /// case.
+    pub fn insert(&mut self, nullifier: Nullifier, block_num: u32) -> Result<(), BlockError> {
+        if self.contains(nullifier) {
+            return Err(BlockError::NullifierAlreadySpent(nullifier));
+        }
+        self.smt.insert(nullifier.inner(), build_nullifier_value(block_num));
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value of a nullifier spent in the specified block.
+fn build_nullifier_value(block_num: u32) -> Word {
+    [Felt::from(block_num), ZERO, ZERO, ZERO]
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{BlockError, Nullifier, NullifierTree};
+    use crate::{block::BlockHeaderBuilder, Felt, ONE, ZERO};
+
+    #[test]
+    fn nullifier_tree() {
+        let nullifier1 = Nullifier::from([ONE, ZERO, ZERO, ZERO]);
+        let nullifier2 = Nullifier::from([ZERO, ONE, ZERO, ZERO]);
+
+        let mut tree = NullifierTree::new();
+        let empty_root = tree.root();
+        tree.insert(nullifier1, 3).unwrap();
+        assert!(tree.contains(nullifier1));
+        assert!(!tree.contains(nullifier2));
+        assert_eq!(tree.get_block_num(nullifier1), Some(3));
+        assert_ne!(tree.root(), empty_root);
+
+        // a nullifier cannot be spent twice
+        let root = tree.root();
+        assert_eq!(tree.insert(nullifier1, 4), Err(BlockError::NullifierAlreadySpent(nullifier1)));
+        assert_eq!(tree.root(), root);
+        assert_eq!(
+            NullifierTree::with_entries([(nullifier2, 1), (nullifier2, 2)]),
+            Err(BlockError::NullifierAlreadySpent(nullifier2))
+        );
+
+        // witnesses open to the nullifier root of a block committing to the tree
+        let header = BlockHeaderBuilder::new()
+            .nullifier_root(tree.root())
+            .timestamp(Felt::new(1))
+            .build();
+        assert!(tree.matches(&header));
+        let witness = tree.open(nullifier1);
+        assert!(witness.is_valid_for(&header));
+        assert!(!witness.is_unspent());
+        let witness = tree.open(nullifier2);
+        assert!(witness.is_valid_for(&header));
+        assert!(witness.is_unspent());
+
+        // the tree does not depend on the order in which nullifiers are inserted
+        let other = NullifierTree::with_entries([(nullifier2, 5), (nullifier1, 3)]).unwrap();
+        tree.insert(nullifier2, 5).unwrap();
+        assert_eq!(other.root(), tree.root());
+    }
+}
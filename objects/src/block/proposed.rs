@@ -0,0 +1,311 @@
+use super::{BlockBody, BlockHeader, BlockHeaderBuilder, ChainState, Digest, Felt, Hasher, Vec};
+use crate::{
+    accounts::AccountId,
+    notes::{NoteEnvelope, NoteId, Nullifier},
+    transaction::TransactionBatch,
+    utils::collections::{BTreeMap, BTreeSet},
+    BlockError, StarkField, MAX_BATCHES_PER_BLOCK, NOTE_TREE_DEPTH,
+};
+
+// PROPOSED BLOCK
+// ================================================================================================
+
+/// A block assembled from transaction batches, which has not been applied to the chain yet.
+///
+/// A proposed block is built on top of a previous block and aggregates the effects of its
+/// batches: the combined state transitions of the updated accounts, the nullifiers of the consumed
+/// notes and the created notes. Applying the proposed block to the [ChainState] as of the previous
+/// block (see [ProposedBlock::apply()]) updates the account database, the nullifier database and
+/// the chain MMR, and returns the header and the body of the new block.
+///
+/// Batches updating the same account must be provided in the order in which they are applied.
+/// Unauthenticated notes consumed by the batches are not authenticated by the proposed block; this
+/// is the responsibility of the block producer.
+#[derive(Debug, Clone)]
+pub struct ProposedBlock {
+    prev_header: BlockHeader,
+    batches: Vec<TransactionBatch>,
+    account_updates: BTreeMap<AccountId, (Digest, Digest)>,
+    nullifiers: Vec<Nullifier>,
+    output_notes: Vec<NoteEnvelope>,
+    timestamp: Felt,
+}
+
+impl ProposedBlock {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ProposedBlock] built from the provided batches on top of the block with the
+    /// provided header.
+    ///
+    /// The timestamp of the proposed block defaults to the timestamp of the previous block; see
+    /// [ProposedBlock::with_timestamp()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of the previous block is [u32::MAX].
+    /// - More than [MAX_BATCHES_PER_BLOCK] batches are provided.
+    /// - A batch expires before the proposed block.
+    /// - A batch updating an account does not start from the final state of the previous batch
+    ///   updating the same account.
+    /// - The same note is consumed by more than one batch.
+    /// - The same note is created by more than one batch.
+    /// - The batches create more notes than fit into the note tree of a block.
+    pub fn new(
+        prev_header: BlockHeader,
+        batches: Vec<TransactionBatch>,
+    ) -> Result<Self, BlockError> {
+        let block_num =
+            prev_header.block_num().checked_add(1).ok_or(BlockError::BlockNumOverflow)?;
+        if batches.len() > MAX_BATCHES_PER_BLOCK {
+            return Err(BlockError::TooManyBatches {
+                max: MAX_BATCHES_PER_BLOCK,
+                actual: batches.len(),
+            });
+        }
+
+        let mut account_updates: BTreeMap<AccountId, (Digest, Digest)> = BTreeMap::new();
+        let mut nullifiers = Vec::new();
+        let mut unique_nullifiers = BTreeSet::new();
+        let mut output_notes = Vec::new();
+        let mut unique_notes: BTreeSet<NoteId> = BTreeSet::new();
+        for batch in batches.iter() {
+            if batch.expiration_block_num() < block_num {
+                return Err(BlockError::ExpiredBatch {
+                    batch_id: batch.id(),
+                    expiration_block_num: batch.expiration_block_num(),
+                    block_num,
+                });
+            }
+
+            for update in batch.account_updates() {
+                match account_updates.get_mut(&update.account_id()) {
+                    Some((_, final_state_hash)) => {
+                        if *final_state_hash != update.initial_state_hash() {
+                            return Err(BlockError::InconsistentAccountStateTransition {
+                                account_id: update.account_id(),
+                                expected: *final_state_hash,
+                                actual: update.initial_state_hash(),
+                            });
+                        }
+                        *final_state_hash = update.final_state_hash();
+                    },
+                    None => {
+                        let transition = (update.initial_state_hash(), update.final_state_hash());
+                        account_updates.insert(update.account_id(), transition);
+                    },
+                }
+            }
+
+            for &nullifier in batch.nullifiers() {
+                if !unique_nullifiers.insert(nullifier) {
+                    return Err(BlockError::DuplicateNullifier(nullifier));
+                }
+                nullifiers.push(nullifier);
+            }
+
+            for &note in batch.output_notes() {
+                if !unique_notes.insert(note.note_id()) {
+                    return Err(BlockError::DuplicateOutputNote(note.note_id()));
+                }
+                output_notes.push(note);
+            }
+        }
+
+        let max_notes = 1 << NOTE_TREE_DEPTH;
+        if output_notes.len() > max_notes {
+            return Err(BlockError::TooManyOutputNotes {
+                max: max_notes,
+                actual: output_notes.len(),
+            });
+        }
+
+        Ok(Self {
+            timestamp: prev_header.timestamp(),
+            prev_header,
+            batches,
+            account_updates,
+            nullifiers,
+            output_notes,
+        })
+    }
+
+    /// Sets the timestamp at which the proposed block is created.
+    pub fn with_timestamp(mut self, timestamp: Felt) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the header of the block the proposed block is built on.
+    pub fn prev_header(&self) -> &BlockHeader {
+        &self.prev_header
+    }
+
+    /// Returns the number of the proposed block.
+    pub fn block_num(&self) -> u32 {
+        self.prev_header.block_num() + 1
+    }
+
+    /// Returns the batches of the proposed block, in the order in which they were provided.
+    pub fn batches(&self) -> &[TransactionBatch] {
+        &self.batches
+    }
+
+    /// Returns the nullifiers of the notes consumed in the proposed block.
+    pub fn nullifiers(&self) -> &[Nullifier] {
+        &self.nullifiers
+    }
+
+    /// Returns the notes created in the proposed block.
+    pub fn output_notes(&self) -> &[NoteEnvelope] {
+        &self.output_notes
+    }
+
+    /// Returns the batch root of the proposed block, i.e., a commitment to the IDs of its batches.
+    pub fn batch_root(&self) -> Digest {
+        let elements: Vec<Felt> = self
+            .batches
+            .iter()
+            .flat_map(|batch| batch.id().as_elements().to_vec())
+            .collect();
+        Hasher::hash_elements(&elements)
+    }
+
+    // STATE TRANSITION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the proposed block to the provided chain state, and returns the header and the
+    /// body of the new block.
+    ///
+    /// Accounts which are not in the account database are created by the block, regardless of
+    /// their initial state hash. The nullifiers of the consumed notes are recorded as spent in
+    /// the new block, the chain MMR is extended by the new block, and the note tree of the new
+    /// block is built from the created notes.
+    ///
+    /// The chain state is not modified if an error is returned.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The chain state is not the state as of the previous block, i.e., its roots do not match
+    ///   the roots committed to by the previous block header, or its chain MMR does not end with
+    ///   the previous block.
+    /// - The timestamp of the proposed block is smaller than the timestamp of the previous block.
+    /// - An updated account is in the account database with a state hash other than the initial
+    ///   state hash of its update.
+    /// - A consumed note has already been consumed.
+    pub fn apply(self, state: &mut ChainState) -> Result<(BlockHeader, BlockBody), BlockError> {
+        self.validate_against(state)?;
+
+        let block_num = self.block_num();
+        let mut updated_accounts = Vec::with_capacity(self.account_updates.len());
+        for (&account_id, &(_, final_state_hash)) in self.account_updates.iter() {
+            state.set_account_hash(account_id, final_state_hash);
+            updated_accounts.push((account_id, final_state_hash));
+        }
+        for &nullifier in self.nullifiers.iter() {
+            state.spend_nullifier(nullifier, block_num);
+        }
+
+        let body =
+            BlockBody::new(updated_accounts, self.nullifiers.clone(), self.output_notes.clone());
+        let header = BlockHeaderBuilder::next(&self.prev_header)
+            .chain_root(state.chain_root())
+            .account_root(state.account_root())
+            .nullifier_root(state.nullifier_root())
+            .note_root(body.note_root())
+            .batch_root(self.batch_root())
+            .timestamp(self.timestamp)
+            .build();
+        state.add_block(&header);
+
+        Ok((header, body))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the proposed block can be applied to the provided chain state.
+    fn validate_against(&self, state: &ChainState) -> Result<(), BlockError> {
+        let prev = &self.prev_header;
+        if state.account_root() != prev.account_root() {
+            return Err(BlockError::AccountRootMismatch {
+                expected: prev.account_root(),
+                actual: state.account_root(),
+            });
+        }
+        if state.nullifier_root() != prev.nullifier_root() {
+            return Err(BlockError::NullifierRootMismatch {
+                expected: prev.nullifier_root(),
+                actual: state.nullifier_root(),
+            });
+        }
+
+        // the chain root of a block commits to all blocks preceding it
+        let chain_length = prev.block_num() as usize + 1;
+        if state.chain_length() != chain_length {
+            return Err(BlockError::ChainLengthMismatch {
+                expected: chain_length,
+                actual: state.chain_length(),
+            });
+        }
+        let chain_root = state
+            .chain()
+            .peaks(prev.block_num() as usize)
+            .expect("forest is within the chain MMR")
+            .hash_peaks();
+        if chain_root != prev.chain_root() {
+            return Err(BlockError::ChainRootMismatch {
+                expected: prev.chain_root(),
+                actual: chain_root,
+            });
+        }
+
+        if self.timestamp.as_int() < prev.timestamp().as_int() {
+            return Err(BlockError::TimestampDecreased {
+                prev: prev.timestamp().as_int(),
+                current: self.timestamp.as_int(),
+            });
+        }
+
+        for (&account_id, &(initial_state_hash, _)) in self.account_updates.iter() {
+            let account_hash = state.account_hash(account_id);
+            if account_hash != Digest::default() && account_hash != initial_state_hash {
+                return Err(BlockError::AccountStateMismatch {
+                    account_id,
+                    expected: account_hash,
+                    actual: initial_state_hash,
+                });
+            }
+        }
+
+        for &nullifier in self.nullifiers.iter() {
+            if state.nullifier_block_num(nullifier).is_some() {
+                return Err(BlockError::NullifierAlreadySpent(nullifier));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// BLOCK ASSEMBLY
+// ================================================================================================
+
+/// Builds a block from the provided batches on top of the block with the provided header, applies
+/// it to the provided chain state, and returns the header and the body of the new block.
+///
+/// This is a shorthand for [ProposedBlock::new()] followed by [ProposedBlock::apply()].
+///
+/// # Errors
+/// Returns an error if the block cannot be built or applied; the chain state is not modified in
+/// this case.
+pub fn apply_batches(
+    prev_header: &BlockHeader,
+    batches: Vec<TransactionBatch>,
+    state: &mut ChainState,
+) -> Result<(BlockHeader, BlockBody), BlockError> {
+    ProposedBlock::new(*prev_header, batches)?.apply(state)
+}
@@ -0,0 +1,159 @@
+use super::{
+    body::build_note_tree, AccountWitness, BlockHeader, BlockHeaderBuilder, Digest, Felt,
+    NullifierTree, NullifierWitness,
+};
+use crate::{
+    accounts::AccountId,
+    crypto::merkle::{LeafIndex, Mmr, SimpleSmt},
+    notes::Nullifier,
+    ACCOUNT_TREE_DEPTH,
+};
+
+// CHAIN STATE
+// ================================================================================================
+
+/// The state of the chain as of its latest block, i.e., the data structures committed to by the
+/// roots of the next block header.
+///
+/// The chain state consists of:
+/// - The account database: a Sparse Merkle tree of depth [ACCOUNT_TREE_DEPTH] mapping the ID of
+///   every account to its state hash.
+/// - The nullifier database: the [NullifierTree] of the nullifiers of all consumed notes.
+/// - The chain MMR: a Merkle Mountain Range containing the hashes of all blocks of the chain.
+///
+/// The chain state is advanced by applying a [ProposedBlock](super::ProposedBlock) to it.
+#[derive(Debug, Clone)]
+pub struct ChainState {
+    accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    nullifiers: NullifierTree,
+    chain: Mmr,
+}
+
+impl ChainState {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ChainState] with the provided account database, nullifier database and
+    /// chain MMR.
+    pub fn from_parts(
+        accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+        nullifiers: NullifierTree,
+        chain: Mmr,
+    ) -> Self {
+        Self { accounts, nullifiers, chain }
+    }
+
+    /// Returns the state of a new chain containing the provided accounts, together with the
+    /// header of the genesis block of the chain.
+    ///
+    /// If the same account is provided more than once, the last provided state hash is used.
+    pub fn genesis(
+        accounts: impl IntoIterator<Item = (AccountId, Digest)>,
+        timestamp: Felt,
+    ) -> (Self, BlockHeader) {
+        let mut state = Self::default();
+        for (account_id, account_hash) in accounts {
+            state.accounts.insert(LeafIndex::from(account_id), account_hash.into());
+        }
+
+        let header = BlockHeaderBuilder::new()
+            .chain_root(state.chain_root())
+            .account_root(state.account_root())
+            .nullifier_root(state.nullifier_root())
+            .note_root(build_note_tree(&[]).root())
+            .timestamp(timestamp)
+            .build();
+        state.chain.add(header.hash());
+
+        (state, header)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the account database.
+    pub fn account_root(&self) -> Digest {
+        self.accounts.root()
+    }
+
+    /// Returns the root of the nullifier database.
+    pub fn nullifier_root(&self) -> Digest {
+        self.nullifiers.root()
+    }
+
+    /// Returns the nullifier database.
+    pub fn nullifiers(&self) -> &NullifierTree {
+        &self.nullifiers
+    }
+
+    /// Returns the chain MMR, which contains the hashes of all blocks of the chain.
+    pub fn chain(&self) -> &Mmr {
+        &self.chain
+    }
+
+    /// Returns the root of the chain MMR, i.e., the chain root of the next block of the chain.
+    pub fn chain_root(&self) -> Digest {
+        self.chain.peaks(self.chain.forest()).expect("forest is valid").hash_peaks()
+    }
+
+    /// Returns the number of blocks of the chain.
+    pub fn chain_length(&self) -> usize {
+        self.chain.forest()
+    }
+
+    /// Returns the state hash of the specified account, or [Digest::default()] if the account is
+    /// not in the account database.
+    pub fn account_hash(&self, account_id: AccountId) -> Digest {
+        self.accounts.get_leaf(&LeafIndex::from(account_id)).into()
+    }
+
+    /// Returns the number of the block in which the note with the specified nullifier was
+    /// consumed, or None if the note has not been consumed yet.
+    pub fn nullifier_block_num(&self, nullifier: Nullifier) -> Option<u32> {
+        self.nullifiers.get_block_num(nullifier)
+    }
+
+    /// Returns a witness of the state of the specified account in the account database.
+    pub fn open_account(&self, account_id: AccountId) -> AccountWitness {
+        let path = self.accounts.open(&LeafIndex::from(account_id)).path;
+        AccountWitness::new(account_id, self.account_hash(account_id), path)
+    }
+
+    /// Returns a witness of the state of the specified nullifier in the nullifier database.
+    pub fn open_nullifier(&self, nullifier: Nullifier) -> NullifierWitness {
+        self.nullifiers.open(nullifier)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the state hash of the specified account.
+    pub(super) fn set_account_hash(&mut self, account_id: AccountId, account_hash: Digest) {
+        self.accounts.insert(LeafIndex::from(account_id), account_hash.into());
+    }
+
+    /// Marks the note with the specified nullifier as consumed in the specified block.
+    ///
+    /// # Panics
+    /// Panics if the note has already been consumed.
+    pub(super) fn spend_nullifier(&mut self, nullifier: Nullifier, block_num: u32) {
+        self.nullifiers
+            .insert(nullifier, block_num)
+            .expect("nullifier has not been spent");
+    }
+
+    /// Adds the specified block to the chain MMR.
+    pub(super) fn add_block(&mut self, header: &BlockHeader) {
+        self.chain.add(header.hash());
+    }
+}
+
+impl Default for ChainState {
+    fn default() -> Self {
+        Self::from_parts(
+            SimpleSmt::new().expect("account tree depth is valid"),
+            NullifierTree::new(),
+            Mmr::default(),
+        )
+    }
+}
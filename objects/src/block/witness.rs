@@ -0,0 +1,116 @@
+use super::{
+    BlockHeader, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    Serializable,
+};
+use crate::{
+    accounts::AccountId,
+    crypto::merkle::{MerklePath, Smt, SmtProof},
+    notes::Nullifier,
+};
+
+// ACCOUNT WITNESS
+// ================================================================================================
+
+/// A proof that an account has a given state hash in the account database of a block.
+///
+/// The account database is a Sparse Merkle tree of depth 64 keyed by account ID; the witness
+/// contains the path from the leaf of the account to the root of the tree. For accounts which are
+/// not in the database (e.g., new accounts), the state hash is [Digest::default()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountWitness {
+    account_id: AccountId,
+    account_hash: Digest,
+    path: MerklePath,
+}
+
+impl AccountWitness {
+    /// Returns a new [AccountWitness] instantiated from the provided parameters.
+    pub fn new(account_id: AccountId, account_hash: Digest, path: MerklePath) -> Self {
+        Self { account_id, account_hash, path }
+    }
+
+    /// Returns the ID of the account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the state hash of the account.
+    pub fn account_hash(&self) -> Digest {
+        self.account_hash
+    }
+
+    /// Returns the path from the leaf of the account to the root of the account database.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
+    }
+
+    /// Returns true if this witness opens to the account root of the provided block header.
+    pub fn is_valid_for(&self, block_header: &BlockHeader) -> bool {
+        self.path
+            .verify(self.account_id.into(), self.account_hash, &block_header.account_root())
+    }
+}
+
+// NULLIFIER WITNESS
+// ================================================================================================
+
+/// A proof of the state of a nullifier in the nullifier database of a block.
+///
+/// The nullifier database is a Sparse Merkle tree keyed by nullifier; the value of a nullifier is
+/// empty if the nullifier has not been spent yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierWitness {
+    nullifier: Nullifier,
+    proof: SmtProof,
+}
+
+impl NullifierWitness {
+    /// Returns a new [NullifierWitness] instantiated from the provided parameters.
+    pub fn new(nullifier: Nullifier, proof: SmtProof) -> Self {
+        Self { nullifier, proof }
+    }
+
+    /// Returns the nullifier this witness is for.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the opening of the nullifier against the root of the nullifier database.
+    pub fn proof(&self) -> &SmtProof {
+        &self.proof
+    }
+
+    /// Returns true if this witness opens to the nullifier root of the provided block header.
+    pub fn is_valid_for(&self, block_header: &BlockHeader) -> bool {
+        self.proof.get(&self.nullifier.inner()).is_some()
+            && self.proof.compute_root() == block_header.nullifier_root()
+    }
+
+    /// Returns true if the nullifier has not been spent according to this witness.
+    ///
+    /// This does not check the validity of the witness; see [NullifierWitness::is_valid_for()].
+    pub fn is_unspent(&self) -> bool {
+        self.proof.get(&self.nullifier.inner()) == Some(Smt::EMPTY_VALUE)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.account_hash.write_into(target);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for AccountWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let account_hash = Digest::read_from(source)?;
+        let path = MerklePath::read_from(source)?;
+
+        Ok(Self::new(account_id, account_hash, path))
+    }
+}
@@ -16,12 +16,35 @@ pub const MAX_INPUTS_PER_NOTE: usize = 128;
 /// The maximum number of notes that can be consumed by a single transaction.
 pub const MAX_INPUT_NOTES_PER_TX: usize = 1023;
 
+/// The maximum number of foreign accounts which can be read by a single transaction.
+pub const MAX_FOREIGN_ACCOUNTS_PER_TX: usize = 64;
+
 /// The maximum number of new notes created by a single transaction.
-pub const MAX_OUTPUT_NOTES_PER_TX: usize = 4096;
+pub const MAX_OUTPUT_NOTES_PER_TX: usize = 16384;
+
+/// The number of words a transaction script returns to the caller of the transaction, i.e., the
+/// number of words at the top of the stack of the script when the script returns.
+pub const TX_SCRIPT_OUTPUT_WORDS: usize = 4;
+
+/// The number of output notes hashed together into a single chunk when computing the commitment to
+/// the output notes of a transaction.
+pub const OUTPUT_NOTES_COMMITMENT_CHUNK_SIZE: usize = 1024;
 
 /// The minimum proof security level used by the Miden prover & verifier.
 pub const MIN_PROOF_SECURITY_LEVEL: u32 = 96;
 
+/// The version of the transaction kernel used by the Miden prover & verifier.
+///
+/// This value must be incremented whenever the ABI of the transaction kernel, i.e., the layout of
+/// its stack inputs, stack outputs or advice inputs, changes, so that kernels of different versions
+/// can be told apart in a [KernelRegistry](crate::transaction::KernelRegistry). The versions are:
+/// - 0: the initial kernel.
+/// - 1: foreign account data is provided via the advice inputs.
+/// - 2: the expiration block number is returned in the stack outputs.
+/// - 3: unauthenticated input notes are provided via the advice inputs.
+/// - 4: the fee is returned in the stack outputs, and the advice inputs carry the fee data.
+pub const TX_KERNEL_VERSION: u8 = 4;
+
 // TRANSACTION BATCH
 // ================================================================================================
 
@@ -29,7 +52,7 @@ pub const MIN_PROOF_SECURITY_LEVEL: u32 = 96;
 ///
 /// A single note uses two leaves in the tree. The even leaf is used to store the note's id, the
 /// odd leaf is used to store the note's metadata.
-pub const BATCH_OUTPUT_NOTES_TREE_DEPTH: u8 = 13;
+pub const BATCH_OUTPUT_NOTES_TREE_DEPTH: u8 = 15;
 
 /// The maximum number of notes that can be created in a single batch.
 ///
@@ -38,7 +61,15 @@ pub const BATCH_OUTPUT_NOTES_TREE_DEPTH: u8 = 13;
 pub const MAX_NOTES_PER_BATCH: usize = 2_usize.pow((BATCH_OUTPUT_NOTES_TREE_DEPTH - 1) as u32);
 
 /// The maximum number of transaction in a single batch.
-pub const MAX_TRANSACTIONS_PER_BATCH: usize = MAX_NOTES_PER_BATCH / MAX_OUTPUT_NOTES_PER_TX;
+///
+/// The limit is independent of [MAX_OUTPUT_NOTES_PER_TX]: a batch may contain transactions which
+/// together create up to [MAX_NOTES_PER_BATCH] notes.
+pub const MAX_TRANSACTIONS_PER_BATCH: usize = 64;
+
+// a batch must be able to hold several transactions, and at least one transaction creating the
+// maximum number of notes
+const _: () = assert!(MAX_TRANSACTIONS_PER_BATCH > 1);
+const _: () = assert!(MAX_NOTES_PER_BATCH >= MAX_OUTPUT_NOTES_PER_TX);
 
 // BLOCK
 // ================================================================================================
@@ -4,12 +4,13 @@ use assembly::AssemblyError;
 use vm_processor::DeserializationError;
 
 use super::{
-    accounts::{AccountId, StorageSlotType},
+    accounts::{AccountId, AccountType, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
-    notes::NoteId,
+    notes::{NoteId, NoteTag, Nullifier},
+    transaction::TransactionId,
     utils::string::String,
-    Digest, Word,
+    Digest, Felt, Word,
 };
 
 // ACCOUNT ERROR
@@ -17,21 +18,40 @@ use super::{
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AccountError {
+    AccountBuilderCodeNotSet,
     AccountCodeAssemblerError(AssemblyError),
     AccountCodeNoProcedures,
+    AccountCodeProcedureNotFound(Digest),
     AccountCodeTooManyProcedures { max: usize, actual: usize },
     AccountIdInvalidFieldElement(String),
     AccountIdTooFewOnes,
+    AccountSeedSearchCancelled,
+    AccountTypeNotSupported(AccountType),
     AssetVaultUpdateError(AssetVaultError),
+    AuthSchemeNotSupported(String),
+    Bech32ParseError(String),
     DuplicateStorageItems(MerkleError),
+    DuplicateStorageMapKeys(MerkleError),
+    FinalAccountHashMismatch { expected: Digest, actual: Digest },
     FungibleFaucetIdInvalidFirstBit,
     FungibleFaucetInvalidMetadata(String),
     HexParseError(String),
     InconsistentAccountIdSeed { expected: AccountId, actual: AccountId },
+    InitialAccountHashMismatch { expected: Digest, actual: Digest },
+    MultisigInvalidThreshold { threshold: u32, num_keys: usize },
+    MultisigTooManyKeys { max: usize, actual: usize },
     NonceNotMonotonicallyIncreasing { current: u64, new: u64 },
     SeedDigestTooFewTrailingZeros { expected: u32, actual: u32 },
+    StorageArrayIndexOutOfBounds { depth: u8, index: u64 },
+    StorageArrayInvalidDepth(u8),
+    StorageArrayNotFound(u8),
+    StorageMapNotFound(u8),
+    StorageSlotCollision(u8),
     StorageSlotInvalidValueArity { slot: u8, expected: u8, actual: u8 },
+    StorageSlotInvalidValueLength { slot: u8, expected: u8, actual: usize },
     StorageSlotIsReserved(u8),
+    StorageSlotNotArraySlot(u8, StorageSlotType),
+    StorageSlotNotMapSlot(u8, StorageSlotType),
     StorageSlotNotValueSlot(u8, StorageSlotType),
     StubDataIncorrectLength(usize, usize),
 }
@@ -54,14 +74,237 @@ impl AccountError {
     }
 }
 
+impl AccountError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of account errors are in the range 1000..1100. The code of an error never changes,
+    /// and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AccountBuilderCodeNotSet => 1000,
+            Self::AccountCodeAssemblerError(_) => 1001,
+            Self::AccountCodeNoProcedures => 1002,
+            Self::AccountCodeTooManyProcedures { .. } => 1003,
+            Self::AccountIdInvalidFieldElement(_) => 1004,
+            Self::AccountIdTooFewOnes => 1005,
+            Self::AccountSeedSearchCancelled => 1006,
+            Self::AssetVaultUpdateError(_) => 1007,
+            Self::AuthSchemeNotSupported(_) => 1008,
+            Self::Bech32ParseError(_) => 1009,
+            Self::DuplicateStorageItems(_) => 1010,
+            Self::DuplicateStorageMapKeys(_) => 1011,
+            Self::FinalAccountHashMismatch { .. } => 1012,
+            Self::FungibleFaucetIdInvalidFirstBit => 1013,
+            Self::FungibleFaucetInvalidMetadata(_) => 1014,
+            Self::HexParseError(_) => 1015,
+            Self::InconsistentAccountIdSeed { .. } => 1016,
+            Self::InitialAccountHashMismatch { .. } => 1017,
+            Self::MultisigInvalidThreshold { .. } => 1018,
+            Self::MultisigTooManyKeys { .. } => 1019,
+            Self::NonceNotMonotonicallyIncreasing { .. } => 1020,
+            Self::SeedDigestTooFewTrailingZeros { .. } => 1021,
+            Self::StorageArrayIndexOutOfBounds { .. } => 1022,
+            Self::StorageArrayInvalidDepth(_) => 1023,
+            Self::StorageArrayNotFound(_) => 1024,
+            Self::StorageMapNotFound(_) => 1025,
+            Self::StorageSlotInvalidValueArity { .. } => 1026,
+            Self::StorageSlotInvalidValueLength { .. } => 1027,
+            Self::StorageSlotIsReserved(_) => 1028,
+            Self::StorageSlotNotArraySlot(..) => 1029,
+            Self::StorageSlotNotMapSlot(..) => 1030,
+            Self::StorageSlotNotValueSlot(..) => 1031,
+            Self::StubDataIncorrectLength(..) => 1032,
+            Self::AccountTypeNotSupported(_) => 1033,
+            Self::StorageSlotCollision(_) => 1034,
+            Self::AccountCodeProcedureNotFound(_) => 1035,
+        }
+    }
+}
+
 impl fmt::Display for AccountError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AccountBuilderCodeNotSet => write!(f, "account code was not provided"),
+            Self::AccountCodeAssemblerError(_) => write!(f, "failed to assemble account code"),
+            Self::AccountCodeNoProcedures => write!(f, "account code does not export procedures"),
+            Self::AccountCodeProcedureNotFound(procedure) => {
+                write!(f, "account code does not export procedure {}", procedure.to_hex())
+            },
+            Self::AccountCodeTooManyProcedures { max, actual } => {
+                write!(f, "account code exports {actual} procedures, but at most {max} are allowed")
+            },
+            Self::AccountIdInvalidFieldElement(msg) => {
+                write!(f, "account ID is not a valid field element: {msg}")
+            },
+            Self::AccountIdTooFewOnes => write!(f, "account ID has too few bits set"),
+            Self::AccountSeedSearchCancelled => {
+                write!(f, "search for an account seed was cancelled")
+            },
+            Self::AccountTypeNotSupported(account_type) => {
+                write!(f, "account type {account_type:?} is not supported by this kind of account")
+            },
+            Self::AssetVaultUpdateError(_) => write!(f, "failed to update account vault"),
+            Self::AuthSchemeNotSupported(scheme) => {
+                write!(f, "authentication scheme {scheme} is not supported")
+            },
+            Self::Bech32ParseError(msg) => write!(f, "failed to parse bech32 string: {msg}"),
+            Self::DuplicateStorageItems(_) => write!(f, "account storage contains duplicate slots"),
+            Self::DuplicateStorageMapKeys(_) => {
+                write!(f, "account storage map contains duplicate keys")
+            },
+            Self::FinalAccountHashMismatch { expected, actual } => write!(
+                f,
+                "final account hash {} differs from the expected hash {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::FungibleFaucetIdInvalidFirstBit => {
+                write!(f, "most significant bit of a fungible faucet ID must be set")
+            },
+            Self::FungibleFaucetInvalidMetadata(msg) => {
+                write!(f, "invalid fungible faucet metadata: {msg}")
+            },
+            Self::HexParseError(msg) => write!(f, "failed to parse hex string: {msg}"),
+            Self::InconsistentAccountIdSeed { expected, actual } => write!(
+                f,
+                "account seed derives account ID {actual}, but the expected ID is {expected}"
+            ),
+            Self::InitialAccountHashMismatch { expected, actual } => write!(
+                f,
+                "initial account hash {} differs from the expected hash {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::MultisigInvalidThreshold { threshold, num_keys } => write!(
+                f,
+                "multisig threshold {threshold} is not between 1 and the number of keys {num_keys}"
+            ),
+            Self::MultisigTooManyKeys { max, actual } => {
+                write!(f, "multisig has {actual} keys, but at most {max} are allowed")
+            },
+            Self::NonceNotMonotonicallyIncreasing { current, new } => {
+                write!(f, "new account nonce {new} is not greater than the current nonce {current}")
+            },
+            Self::SeedDigestTooFewTrailingZeros { expected, actual } => write!(
+                f,
+                "account seed digest has {actual} trailing zeros, but at least {expected} are \
+                 required"
+            ),
+            Self::StorageArrayIndexOutOfBounds { depth, index } => {
+                write!(f, "index {index} is out of bounds for a storage array of depth {depth}")
+            },
+            Self::StorageArrayInvalidDepth(depth) => {
+                write!(f, "storage array depth {depth} is not supported")
+            },
+            Self::StorageArrayNotFound(slot) => write!(f, "storage array in slot {slot} not found"),
+            Self::StorageMapNotFound(slot) => write!(f, "storage map in slot {slot} not found"),
+            Self::StorageSlotCollision(slot) => {
+                write!(f, "storage slot {slot} is used by more than one account component")
+            },
+            Self::StorageSlotInvalidValueArity { slot, expected, actual } => write!(
+                f,
+                "value in storage slot {slot} has arity {actual}, but arity {expected} is expected"
+            ),
+            Self::StorageSlotInvalidValueLength { slot, expected, actual } => write!(
+                f,
+                "value in storage slot {slot} has {actual} elements, but {expected} are expected"
+            ),
+            Self::StorageSlotIsReserved(slot) => write!(f, "storage slot {slot} is reserved"),
+            Self::StorageSlotNotArraySlot(slot, slot_type) => {
+                write!(f, "storage slot {slot} is a {slot_type:?} slot, not an array slot")
+            },
+            Self::StorageSlotNotMapSlot(slot, slot_type) => {
+                write!(f, "storage slot {slot} is a {slot_type:?} slot, not a map slot")
+            },
+            Self::StorageSlotNotValueSlot(slot, slot_type) => {
+                write!(f, "storage slot {slot} is a {slot_type:?} slot, not a value slot")
+            },
+            Self::StubDataIncorrectLength(actual, expected) => {
+                write!(f, "account stub data has {actual} elements, but {expected} are expected")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for AccountError {}
+impl std::error::Error for AccountError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AccountCodeAssemblerError(err) => Some(err),
+            Self::AssetVaultUpdateError(err) => Some(err),
+            Self::DuplicateStorageItems(err) | Self::DuplicateStorageMapKeys(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// PARSE ACCOUNT ID ERROR
+// ================================================================================================
+
+/// Errors that can occur when parsing an [AccountId] from a string.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseAccountIdError {
+    EmptyString,
+    InvalidBech32(String),
+    InvalidHex(String),
+    InvalidLength { expected: usize, actual: usize },
+    NotAFieldElement(u64),
+    TooFewOnes { min: u32, actual: u32 },
+}
+
+impl fmt::Display for ParseAccountIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyString => {
+                write!(f, "account ID is empty; expected a bech32m or a 0x-prefixed hex string")
+            },
+            Self::InvalidBech32(err) => write!(
+                f,
+                "account ID is not a valid bech32m string ({err}); hex encoded IDs must start \
+                 with 0x"
+            ),
+            Self::InvalidHex(value) => write!(
+                f,
+                "account ID {value} is not valid hex; expected 0x followed by at most 16 hex \
+                 digits"
+            ),
+            Self::InvalidLength { expected, actual } => write!(
+                f,
+                "account ID encodes {actual} bytes, but exactly {expected} bytes are expected"
+            ),
+            Self::NotAFieldElement(value) => write!(
+                f,
+                "account ID {value:#018x} is not a valid field element; it must be smaller than \
+                 the field modulus"
+            ),
+            Self::TooFewOnes { min, actual } => write!(
+                f,
+                "account ID has {actual} bits set, but at least {min} are required; make sure \
+                 the ID was derived from an account seed"
+            ),
+        }
+    }
+}
+
+impl ParseAccountIdError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of account ID parsing errors are in the range 1100..1200. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::EmptyString => 1100,
+            Self::InvalidBech32(_) => 1101,
+            Self::InvalidHex(_) => 1102,
+            Self::InvalidLength { .. } => 1103,
+            Self::NotAFieldElement(_) => 1104,
+            Self::TooFewOnes { .. } => 1105,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAccountIdError {}
 
 // ACCOUNT DELTA ERROR
 // ================================================================================================
@@ -69,13 +312,42 @@ impl std::error::Error for AccountError {}
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AccountDeltaError {
     DuplicateStorageItemUpdate(usize),
+    DuplicateStorageMapLeafUpdate(Digest),
+    DuplicateStorageMapUpdate(usize),
     DuplicateVaultUpdate(Asset),
     InconsistentNonceUpdate(String),
+    InconsistentStorageDelta(String),
+    InconsistentVaultDelta(String),
     ImmutableStorageSlot(usize),
     TooManyAddedAsset { actual: usize, max: usize },
     TooManyClearedStorageItems { actual: usize, max: usize },
     TooManyRemovedAssets { actual: usize, max: usize },
     TooManyUpdatedStorageItems { actual: usize, max: usize },
+    TooManyUpdatedStorageMaps { actual: usize, max: usize },
+}
+
+impl AccountDeltaError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of account delta errors are in the range 1200..1300. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::DuplicateStorageItemUpdate(_) => 1200,
+            Self::DuplicateStorageMapLeafUpdate(_) => 1201,
+            Self::DuplicateStorageMapUpdate(_) => 1202,
+            Self::DuplicateVaultUpdate(_) => 1203,
+            Self::InconsistentNonceUpdate(_) => 1204,
+            Self::InconsistentStorageDelta(_) => 1211,
+            Self::InconsistentVaultDelta(_) => 1212,
+            Self::ImmutableStorageSlot(_) => 1205,
+            Self::TooManyAddedAsset { .. } => 1206,
+            Self::TooManyClearedStorageItems { .. } => 1207,
+            Self::TooManyRemovedAssets { .. } => 1208,
+            Self::TooManyUpdatedStorageItems { .. } => 1209,
+            Self::TooManyUpdatedStorageMaps { .. } => 1210,
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -83,7 +355,41 @@ impl std::error::Error for AccountDeltaError {}
 
 impl fmt::Display for AccountDeltaError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::DuplicateStorageItemUpdate(slot) => {
+                write!(f, "storage slot {slot} is updated more than once")
+            },
+            Self::DuplicateStorageMapLeafUpdate(key) => {
+                write!(f, "storage map key {} is updated more than once", key.to_hex())
+            },
+            Self::DuplicateStorageMapUpdate(slot) => {
+                write!(f, "storage map in slot {slot} is updated more than once")
+            },
+            Self::DuplicateVaultUpdate(asset) => {
+                write!(f, "asset {asset:?} is updated more than once")
+            },
+            Self::InconsistentNonceUpdate(msg) => write!(f, "inconsistent nonce update: {msg}"),
+            Self::InconsistentStorageDelta(msg) => write!(f, "inconsistent storage delta: {msg}"),
+            Self::InconsistentVaultDelta(msg) => write!(f, "inconsistent vault delta: {msg}"),
+            Self::ImmutableStorageSlot(slot) => {
+                write!(f, "storage slot {slot} is immutable and cannot be updated")
+            },
+            Self::TooManyAddedAsset { actual, max } => {
+                write!(f, "delta adds {actual} assets, but at most {max} are allowed")
+            },
+            Self::TooManyClearedStorageItems { actual, max } => {
+                write!(f, "delta clears {actual} storage slots, but at most {max} are allowed")
+            },
+            Self::TooManyRemovedAssets { actual, max } => {
+                write!(f, "delta removes {actual} assets, but at most {max} are allowed")
+            },
+            Self::TooManyUpdatedStorageItems { actual, max } => {
+                write!(f, "delta updates {actual} storage slots, but at most {max} are allowed")
+            },
+            Self::TooManyUpdatedStorageMaps { actual, max } => {
+                write!(f, "delta updates {actual} storage maps, but at most {max} are allowed")
+            },
+        }
     }
 }
 
@@ -162,9 +468,65 @@ impl AssetError {
     }
 }
 
+impl AssetError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of asset errors are in the range 2000..2100. The code of an error never changes, and
+    /// codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AmountTooBig(_) => 2000,
+            Self::AssetAmountNotSufficient(..) => 2001,
+            Self::FungibleAssetInvalidFirstBit => 2002,
+            Self::FungibleAssetInvalidTag(_) => 2003,
+            Self::FungibleAssetInvalidWord(_) => 2004,
+            Self::InconsistentFaucetIds(..) => 2005,
+            Self::InvalidAccountId(_) => 2006,
+            Self::InvalidFieldElement(_) => 2007,
+            Self::NonFungibleAssetInvalidFirstBit => 2008,
+            Self::NonFungibleAssetInvalidTag(_) => 2009,
+            Self::NotAFungibleFaucetId(_) => 2010,
+            Self::NotANonFungibleFaucetId(_) => 2011,
+            Self::NotAnAsset(_) => 2012,
+            Self::TokenSymbolError(_) => 2013,
+        }
+    }
+}
+
 impl fmt::Display for AssetError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AmountTooBig(amount) => write!(f, "asset amount {amount} is too big"),
+            Self::AssetAmountNotSufficient(available, requested) => {
+                write!(f, "requested amount {requested} exceeds the available amount {available}")
+            },
+            Self::FungibleAssetInvalidFirstBit => {
+                write!(f, "most significant bit of a fungible asset faucet ID must be set")
+            },
+            Self::FungibleAssetInvalidTag(tag) => {
+                write!(f, "fungible asset has invalid tag {tag}")
+            },
+            Self::FungibleAssetInvalidWord(word) => {
+                write!(f, "word {word:?} does not encode a fungible asset")
+            },
+            Self::InconsistentFaucetIds(id1, id2) => {
+                write!(f, "assets were issued by different faucets {id1} and {id2}")
+            },
+            Self::InvalidAccountId(msg) => write!(f, "invalid faucet ID: {msg}"),
+            Self::InvalidFieldElement(msg) => write!(f, "invalid field element: {msg}"),
+            Self::NonFungibleAssetInvalidFirstBit => {
+                write!(f, "most significant bit of a non-fungible asset faucet ID must not be set")
+            },
+            Self::NonFungibleAssetInvalidTag(tag) => {
+                write!(f, "non-fungible asset has invalid tag {tag}")
+            },
+            Self::NotAFungibleFaucetId(id) => write!(f, "account {id} is not a fungible faucet"),
+            Self::NotANonFungibleFaucetId(id) => {
+                write!(f, "account {id} is not a non-fungible faucet")
+            },
+            Self::NotAnAsset(word) => write!(f, "word {word:?} does not encode an asset"),
+            Self::TokenSymbolError(msg) => write!(f, "invalid token symbol: {msg}"),
+        }
     }
 }
 
@@ -183,17 +545,84 @@ pub enum AssetVaultError {
     NotANonFungibleAsset(Asset),
     NotAFungibleFaucetId(AccountId),
     NonFungibleAssetNotFound(NonFungibleAsset),
+    PartialVaultInvalidOpening {
+        vault_key: Digest,
+        expected_root: Digest,
+        actual_root: Digest,
+    },
+    PartialVaultKeyNotTracked(Digest),
     SubtractFungibleAssetBalanceError(AssetError),
 }
 
+impl AssetVaultError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of asset vault errors are in the range 2100..2200. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AddFungibleAssetBalanceError(_) => 2100,
+            Self::DuplicateAsset(_) => 2101,
+            Self::DuplicateNonFungibleAsset(_) => 2102,
+            Self::FungibleAssetNotFound(_) => 2103,
+            Self::NotANonFungibleAsset(_) => 2104,
+            Self::NotAFungibleFaucetId(_) => 2105,
+            Self::NonFungibleAssetNotFound(_) => 2106,
+            Self::PartialVaultInvalidOpening { .. } => 2107,
+            Self::PartialVaultKeyNotTracked(_) => 2108,
+            Self::SubtractFungibleAssetBalanceError(_) => 2109,
+        }
+    }
+}
+
 impl fmt::Display for AssetVaultError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AddFungibleAssetBalanceError(_) => {
+                write!(f, "failed to add fungible asset to the vault")
+            },
+            Self::DuplicateAsset(_) => write!(f, "vault contains duplicate assets"),
+            Self::DuplicateNonFungibleAsset(asset) => {
+                write!(f, "non-fungible asset {asset} is already in the vault")
+            },
+            Self::FungibleAssetNotFound(asset) => {
+                write!(f, "fungible asset {asset} not found in the vault")
+            },
+            Self::NotANonFungibleAsset(asset) => {
+                write!(f, "asset {asset:?} is not a non-fungible asset")
+            },
+            Self::NotAFungibleFaucetId(id) => write!(f, "account {id} is not a fungible faucet"),
+            Self::NonFungibleAssetNotFound(asset) => {
+                write!(f, "non-fungible asset {asset} not found in the vault")
+            },
+            Self::PartialVaultInvalidOpening { vault_key, expected_root, actual_root } => write!(
+                f,
+                "opening of vault key {} resolves to root {}, but the vault root is {}",
+                vault_key.to_hex(),
+                actual_root.to_hex(),
+                expected_root.to_hex()
+            ),
+            Self::PartialVaultKeyNotTracked(vault_key) => {
+                write!(f, "vault key {} is not tracked by the partial vault", vault_key.to_hex())
+            },
+            Self::SubtractFungibleAssetBalanceError(_) => {
+                write!(f, "failed to remove fungible asset from the vault")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for AssetVaultError {}
+impl std::error::Error for AssetVaultError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AddFungibleAssetBalanceError(err)
+            | Self::SubtractFungibleAssetBalanceError(err) => Some(err),
+            Self::DuplicateAsset(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // NOTE ERROR
 // ================================================================================================
@@ -202,17 +631,52 @@ impl std::error::Error for AssetVaultError {}
 pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    DuplicateEscrowSigner(Word),
+    DuplicateNoteTarget(AccountId),
     EmptyAssetList,
+    EmptyBatchPayouts,
+    EmptyNoteChain,
+    EmptyNoteTargets,
+    EmptySubscription,
+    InconsistentEncryptedNoteId(NoteId, NoteId),
     InconsistentStubId(NoteId, NoteId),
     InconsistentStubAssetHash(Digest, Digest),
     InvalidStubDataLen(usize),
+    InvalidNoteIdEncoding(String),
     InvalidOriginIndex(String),
     InvalidAssetData(AssetError),
+    InvalidEscrowSignature(Word),
+    InvalidEscrowThreshold {
+        threshold: usize,
+        num_signers: usize,
+    },
+    InvalidNoteEncryptionKey,
+    InvalidNoteInputValue(&'static str),
+    InvalidNoteTagUseCase(u16),
+    InvalidNoteTagValue(u64),
+    InvalidNoteType(u64),
+    InvalidSubscriptionInterval(u32),
+    InvalidSubscriptionNote(NoteId),
+    NetworkNoteTagForOffChainAccount(NoteTag),
+    NoteChainMissingNextStep(usize),
+    NoteDecryptionFailed(NoteId),
     NoteDeserializationError(DeserializationError),
+    NoteInputsLayoutMismatch(String),
     NoteMetadataSenderInvalid(AccountError),
+    NoteScriptNotAllowed(NoteId, Digest),
+    OracleSwapAmountTooLarge(u64),
     ScriptCompilationError(AssemblyError),
+    SubscriptionClaimTooEarly {
+        next_claim_block: u32,
+        claim_block_num: u32,
+    },
     TooManyAssets(usize),
+    TooManyBatchPayouts(usize),
+    TooManyEscrowSigners(usize),
     TooManyInputs(usize),
+    TooManyRequestedAssets(usize),
+    UnknownEscrowSigner(Word),
+    UnknownNoteScript(Digest),
 }
 
 impl NoteError {
@@ -241,14 +705,231 @@ impl NoteError {
     }
 }
 
+impl NoteError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of note errors are in the range 3000..3100. The code of an error never changes, and
+    /// codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::DuplicateFungibleAsset(_) => 3000,
+            Self::DuplicateNonFungibleAsset(_) => 3001,
+            Self::DuplicateEscrowSigner(_) => 3002,
+            Self::DuplicateNoteTarget(_) => 3003,
+            Self::EmptyAssetList => 3004,
+            Self::EmptyBatchPayouts => 3005,
+            Self::EmptyNoteChain => 3006,
+            Self::EmptyNoteTargets => 3007,
+            Self::EmptySubscription => 3008,
+            Self::InconsistentEncryptedNoteId(..) => 3009,
+            Self::InconsistentStubId(..) => 3010,
+            Self::InconsistentStubAssetHash(..) => 3011,
+            Self::InvalidStubDataLen(_) => 3012,
+            Self::InvalidNoteIdEncoding(_) => 3013,
+            Self::InvalidOriginIndex(_) => 3014,
+            Self::InvalidAssetData(_) => 3015,
+            Self::InvalidEscrowSignature(_) => 3041,
+            Self::InvalidEscrowThreshold { .. } => 3016,
+            Self::InvalidNoteEncryptionKey => 3017,
+            Self::InvalidNoteInputValue(_) => 3039,
+            Self::InvalidNoteTagUseCase(_) => 3018,
+            Self::InvalidNoteTagValue(_) => 3019,
+            Self::InvalidNoteType(_) => 3038,
+            Self::InvalidSubscriptionInterval(_) => 3020,
+            Self::InvalidSubscriptionNote(_) => 3021,
+            Self::NetworkNoteTagForOffChainAccount(_) => 3022,
+            Self::NoteChainMissingNextStep(_) => 3023,
+            Self::NoteDecryptionFailed(_) => 3024,
+            Self::NoteDeserializationError(_) => 3025,
+            Self::NoteInputsLayoutMismatch(_) => 3040,
+            Self::NoteMetadataSenderInvalid(_) => 3026,
+            Self::NoteScriptNotAllowed(..) => 3027,
+            Self::OracleSwapAmountTooLarge(_) => 3037,
+            Self::ScriptCompilationError(_) => 3028,
+            Self::SubscriptionClaimTooEarly { .. } => 3029,
+            Self::TooManyAssets(_) => 3030,
+            Self::TooManyBatchPayouts(_) => 3031,
+            Self::TooManyEscrowSigners(_) => 3032,
+            Self::TooManyInputs(_) => 3033,
+            Self::TooManyRequestedAssets(_) => 3036,
+            Self::UnknownEscrowSigner(_) => 3034,
+            Self::UnknownNoteScript(_) => 3035,
+        }
+    }
+}
+
 impl fmt::Display for NoteError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::DuplicateFungibleAsset(faucet_id) => {
+                write!(f, "note contains more than one asset issued by faucet {faucet_id}")
+            },
+            Self::DuplicateNonFungibleAsset(asset) => {
+                write!(f, "note contains non-fungible asset {asset} more than once")
+            },
+            Self::DuplicateEscrowSigner(pub_key) => {
+                write!(f, "escrow signer {pub_key:?} is specified more than once")
+            },
+            Self::DuplicateNoteTarget(account_id) => {
+                write!(f, "account {account_id} is targeted more than once")
+            },
+            Self::EmptyAssetList => write!(f, "note must contain at least one asset"),
+            Self::EmptyBatchPayouts => write!(f, "batch must contain at least one payout"),
+            Self::EmptyNoteChain => write!(f, "note chain must contain at least one step"),
+            Self::EmptyNoteTargets => write!(f, "note must have at least one target"),
+            Self::EmptySubscription => write!(f, "subscription must contain at least one payment"),
+            Self::InconsistentEncryptedNoteId(expected, actual) => write!(
+                f,
+                "decrypted note has ID {actual}, but the encrypted note has ID {expected}"
+            ),
+            Self::InconsistentStubId(actual, expected) => {
+                write!(f, "note stub has ID {actual}, but the expected ID is {expected}")
+            },
+            Self::InconsistentStubAssetHash(expected, actual) => write!(
+                f,
+                "note stub assets commit to {}, but the expected commitment is {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::InvalidStubDataLen(len) => {
+                write!(f, "note stub data has invalid length {len}")
+            },
+            Self::InvalidNoteIdEncoding(msg) => write!(f, "invalid note ID encoding: {msg}"),
+            Self::InvalidOriginIndex(msg) => write!(f, "invalid note origin index: {msg}"),
+            Self::InvalidAssetData(_) => write!(f, "invalid note asset"),
+            Self::InvalidEscrowSignature(pub_key) => {
+                write!(f, "escrow approval of signer {pub_key:?} is not a valid signature")
+            },
+            Self::InvalidEscrowThreshold { threshold, num_signers } => write!(
+                f,
+                "escrow threshold {threshold} is not between 1 and the number of signers \
+                 {num_signers}"
+            ),
+            Self::InvalidNoteEncryptionKey => write!(f, "invalid note encryption key"),
+            Self::InvalidNoteInputValue(field) => {
+                write!(f, "note input field {field} has an invalid value")
+            },
+            Self::InvalidNoteTagUseCase(use_case) => {
+                write!(f, "note tag use case {use_case} is not valid")
+            },
+            Self::InvalidNoteTagValue(value) => write!(f, "note tag value {value} is not valid"),
+            Self::InvalidNoteType(value) => write!(f, "note type {value} is not valid"),
+            Self::InvalidSubscriptionInterval(interval) => {
+                write!(f, "subscription interval {interval} is not valid")
+            },
+            Self::InvalidSubscriptionNote(note_id) => {
+                write!(f, "note {note_id} is not a valid subscription note")
+            },
+            Self::NetworkNoteTagForOffChainAccount(tag) => {
+                write!(f, "network note tag {tag} cannot target an off-chain account")
+            },
+            Self::NoteChainMissingNextStep(step) => {
+                write!(f, "step {step} of the note chain has no next step")
+            },
+            Self::NoteDecryptionFailed(note_id) => write!(f, "failed to decrypt note {note_id}"),
+            Self::NoteDeserializationError(_) => write!(f, "failed to deserialize note"),
+            Self::NoteInputsLayoutMismatch(msg) => {
+                write!(f, "note inputs do not match their layout: {msg}")
+            },
+            Self::NoteMetadataSenderInvalid(_) => write!(f, "note sender is not valid"),
+            Self::NoteScriptNotAllowed(note_id, script_root) => {
+                write!(f, "script {} of note {note_id} is not allowed", script_root.to_hex())
+            },
+            Self::OracleSwapAmountTooLarge(amount) => {
+                write!(f, "oracle swap offers amount {amount}, which exceeds the maximum")
+            },
+            Self::ScriptCompilationError(_) => write!(f, "failed to compile note script"),
+            Self::SubscriptionClaimTooEarly { next_claim_block, claim_block_num } => write!(
+                f,
+                "subscription cannot be claimed at block {claim_block_num}, the next claim is \
+                 possible at block {next_claim_block}"
+            ),
+            Self::TooManyAssets(num_assets) => {
+                write!(f, "note contains {num_assets} assets, which exceeds the maximum")
+            },
+            Self::TooManyBatchPayouts(num_payouts) => {
+                write!(f, "batch contains {num_payouts} payouts, which exceeds the maximum")
+            },
+            Self::TooManyEscrowSigners(num_signers) => {
+                write!(f, "escrow has {num_signers} signers, which exceeds the maximum")
+            },
+            Self::TooManyInputs(num_inputs) => {
+                write!(f, "note has {num_inputs} inputs, which exceeds the maximum")
+            },
+            Self::TooManyRequestedAssets(num_assets) => {
+                write!(f, "swap requests {num_assets} assets, which exceeds the maximum")
+            },
+            Self::UnknownEscrowSigner(pub_key) => {
+                write!(f, "{pub_key:?} is not a signer of the escrow")
+            },
+            Self::UnknownNoteScript(script_root) => {
+                write!(f, "note script {} is not known", script_root.to_hex())
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidAssetData(err) => Some(err),
+            Self::NoteDeserializationError(err) => Some(err),
+            Self::NoteMetadataSenderInvalid(err) => Some(err),
+            Self::ScriptCompilationError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// BLOCK HEADER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BlockHeaderError {
+    BlockNumOverflow,
+    InconsistentBlockNum { expected: u32, actual: u32 },
+    InconsistentPrevHash { expected: Digest, actual: Digest },
+    TimestampDecreased { prev: u64, current: u64 },
+}
+
+impl BlockHeaderError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of block header errors are in the range 4000..4100. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::BlockNumOverflow => 4000,
+            Self::InconsistentBlockNum { .. } => 4001,
+            Self::InconsistentPrevHash { .. } => 4002,
+            Self::TimestampDecreased { .. } => 4003,
+        }
+    }
+}
+
+impl fmt::Display for BlockHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlockNumOverflow => write!(f, "block number overflowed"),
+            Self::InconsistentBlockNum { expected, actual } => {
+                write!(f, "block has number {actual}, but number {expected} is expected")
+            },
+            Self::InconsistentPrevHash { expected, actual } => write!(
+                f,
+                "block references previous block {}, but the previous block is {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::TimestampDecreased { prev, current } => {
+                write!(f, "block timestamp {current} is smaller than the previous timestamp {prev}")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for NoteError {}
+impl std::error::Error for BlockHeaderError {}
 
 // CHAIN MMR ERROR
 // ================================================================================================
@@ -257,6 +938,8 @@ impl std::error::Error for NoteError {}
 pub enum ChainMmrError {
     BlockNumTooBig { chain_length: usize, block_num: u32 },
     DuplicateBlock { block_num: u32 },
+    InvalidBlockPath { block_num: u32 },
+    NonSequentialBlock { expected: u32, actual: u32 },
     UntrackedBlock { block_num: u32 },
 }
 
@@ -269,20 +952,370 @@ impl ChainMmrError {
         Self::DuplicateBlock { block_num }
     }
 
+    pub fn invalid_block_path(block_num: u32) -> Self {
+        Self::InvalidBlockPath { block_num }
+    }
+
+    pub fn non_sequential_block(expected: u32, actual: u32) -> Self {
+        Self::NonSequentialBlock { expected, actual }
+    }
+
     pub fn untracked_block(block_num: u32) -> Self {
         Self::UntrackedBlock { block_num }
     }
 }
 
+impl ChainMmrError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of chain MMR errors are in the range 4100..4200. The code of an error never changes,
+    /// and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::BlockNumTooBig { .. } => 4100,
+            Self::DuplicateBlock { .. } => 4101,
+            Self::NonSequentialBlock { .. } => 4102,
+            Self::UntrackedBlock { .. } => 4103,
+            Self::InvalidBlockPath { .. } => 4104,
+        }
+    }
+}
+
 impl fmt::Display for ChainMmrError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::BlockNumTooBig { chain_length, block_num } => {
+                write!(f, "block {block_num} is not in the chain, which has {chain_length} blocks")
+            },
+            Self::DuplicateBlock { block_num } => {
+                write!(f, "block {block_num} is provided more than once")
+            },
+            Self::InvalidBlockPath { block_num } => {
+                write!(f, "authentication path of block {block_num} does not match the chain MMR")
+            },
+            Self::NonSequentialBlock { expected, actual } => {
+                write!(f, "block {actual} is not the next block {expected} of the chain")
+            },
+            Self::UntrackedBlock { block_num } => {
+                write!(f, "block {block_num} is not tracked by the chain MMR")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for ChainMmrError {}
 
+// BLOCK ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BlockError {
+    AccountRootMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    AccountStateMismatch {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    BlockNumOverflow,
+    ChainLengthMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    ChainRootMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    DuplicateNullifier(Nullifier),
+    DuplicateOutputNote(NoteId),
+    ExpiredBatch {
+        batch_id: Digest,
+        expiration_block_num: u32,
+        block_num: u32,
+    },
+    InconsistentAccountStateTransition {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    NullifierAlreadySpent(Nullifier),
+    NullifierRootMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    TimestampDecreased {
+        prev: u64,
+        current: u64,
+    },
+    TooManyBatches {
+        max: usize,
+        actual: usize,
+    },
+    TooManyOutputNotes {
+        max: usize,
+        actual: usize,
+    },
+}
+
+impl BlockError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of block errors are in the range 4200..4300. The code of an error never changes, and
+    /// codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AccountRootMismatch { .. } => 4200,
+            Self::AccountStateMismatch { .. } => 4201,
+            Self::BlockNumOverflow => 4202,
+            Self::ChainLengthMismatch { .. } => 4203,
+            Self::ChainRootMismatch { .. } => 4204,
+            Self::DuplicateNullifier(_) => 4205,
+            Self::DuplicateOutputNote(_) => 4206,
+            Self::ExpiredBatch { .. } => 4207,
+            Self::InconsistentAccountStateTransition { .. } => 4208,
+            Self::NullifierAlreadySpent(_) => 4209,
+            Self::NullifierRootMismatch { .. } => 4210,
+            Self::TimestampDecreased { .. } => 4211,
+            Self::TooManyBatches { .. } => 4212,
+            Self::TooManyOutputNotes { .. } => 4213,
+        }
+    }
+}
+
+impl fmt::Display for BlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccountRootMismatch { expected, actual } => write!(
+                f,
+                "account root of the chain state is {}, but the previous block commits to {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::AccountStateMismatch { account_id, expected, actual } => write!(
+                f,
+                "block updates account {account_id} from state {}, but the account is in state {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::BlockNumOverflow => write!(f, "block number overflowed"),
+            Self::ChainLengthMismatch { expected, actual } => write!(
+                f,
+                "chain MMR of the chain state contains {actual} blocks, but {expected} are expected"
+            ),
+            Self::ChainRootMismatch { expected, actual } => write!(
+                f,
+                "chain root of the chain state is {}, but the previous block commits to {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::DuplicateNullifier(nullifier) => {
+                write!(f, "note with nullifier {nullifier} is consumed by more than one batch")
+            },
+            Self::DuplicateOutputNote(note_id) => {
+                write!(f, "note {} is created by more than one batch", note_id.inner().to_hex())
+            },
+            Self::ExpiredBatch {
+                batch_id,
+                expiration_block_num,
+                block_num,
+            } => write!(
+                f,
+                "batch {} expired at block {expiration_block_num}, but the block number is \
+                 {block_num}",
+                batch_id.to_hex()
+            ),
+            Self::InconsistentAccountStateTransition { account_id, expected, actual } => write!(
+                f,
+                "batch updates account {account_id} from state {}, but the previous batch \
+                 transitions it to state {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::NullifierAlreadySpent(nullifier) => {
+                write!(f, "note with nullifier {nullifier} has already been consumed")
+            },
+            Self::NullifierRootMismatch { expected, actual } => write!(
+                f,
+                "nullifier root of the chain state is {}, but the previous block commits to {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::TimestampDecreased { prev, current } => {
+                write!(f, "block timestamp {current} is smaller than the previous timestamp {prev}")
+            },
+            Self::TooManyBatches { max, actual } => {
+                write!(f, "block contains {actual} batches, but at most {max} are allowed")
+            },
+            Self::TooManyOutputNotes { max, actual } => {
+                write!(f, "block creates {actual} notes, but at most {max} are allowed")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlockError {}
+
+// PROVEN TRANSACTION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProvenTransactionError {
+    AccountDeltaApplicationFailed(AccountError),
+    AccountDetailsMissing(AccountId),
+    AccountDetailsNotAvailable(AccountId),
+    AccountIdMismatch { expected: AccountId, actual: AccountId },
+    ExistingOnChainAccountRequiresDelta(AccountId),
+    FinalAccountHashMismatch { expected: Digest, actual: Digest },
+    InitialAccountHashMismatch { expected: Digest, actual: Digest },
+    InitialAccountMissing(AccountId),
+    NewOnChainAccountRequiresFullState(AccountId),
+    OffChainAccountWithDetails(AccountId),
+}
+
+impl ProvenTransactionError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of proven transaction errors are in the range 5000..5100. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AccountDeltaApplicationFailed(_) => 5000,
+            Self::AccountDetailsMissing(_) => 5001,
+            Self::AccountDetailsNotAvailable(_) => 5002,
+            Self::AccountIdMismatch { .. } => 5003,
+            Self::ExistingOnChainAccountRequiresDelta(_) => 5004,
+            Self::FinalAccountHashMismatch { .. } => 5005,
+            Self::InitialAccountHashMismatch { .. } => 5006,
+            Self::InitialAccountMissing(_) => 5007,
+            Self::NewOnChainAccountRequiresFullState(_) => 5008,
+            Self::OffChainAccountWithDetails(_) => 5009,
+        }
+    }
+}
+
+impl fmt::Display for ProvenTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccountDeltaApplicationFailed(_) => write!(f, "failed to apply account delta"),
+            Self::AccountDetailsMissing(account_id) => {
+                write!(f, "details of on-chain account {account_id} are missing")
+            },
+            Self::AccountDetailsNotAvailable(account_id) => {
+                write!(f, "details of account {account_id} are not available")
+            },
+            Self::AccountIdMismatch { expected, actual } => {
+                write!(f, "account {actual} differs from the expected account {expected}")
+            },
+            Self::ExistingOnChainAccountRequiresDelta(account_id) => write!(
+                f,
+                "details of existing on-chain account {account_id} must be an account delta"
+            ),
+            Self::FinalAccountHashMismatch { expected, actual } => write!(
+                f,
+                "final account hash {} differs from the expected hash {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::InitialAccountHashMismatch { expected, actual } => write!(
+                f,
+                "initial account hash {} differs from the expected hash {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::InitialAccountMissing(account_id) => {
+                write!(f, "initial state of account {account_id} is missing")
+            },
+            Self::NewOnChainAccountRequiresFullState(account_id) => write!(
+                f,
+                "details of new on-chain account {account_id} must contain the full account state"
+            ),
+            Self::OffChainAccountWithDetails(account_id) => {
+                write!(f, "off-chain account {account_id} must not have account details")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProvenTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AccountDeltaApplicationFailed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// TRANSACTION GRAPH ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionGraphError {
+    ConflictingAccountUpdates {
+        account_id: AccountId,
+        first: TransactionId,
+        second: TransactionId,
+    },
+    DependencyCycle(TransactionId),
+    DuplicateTransaction(TransactionId),
+    NoteConsumedTwice {
+        note_id: NoteId,
+        first: TransactionId,
+        second: TransactionId,
+    },
+    NoteProducedTwice {
+        note_id: NoteId,
+        first: TransactionId,
+        second: TransactionId,
+    },
+}
+
+impl TransactionGraphError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction graph errors are in the range 5100..5200. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::ConflictingAccountUpdates { .. } => 5100,
+            Self::DependencyCycle(_) => 5101,
+            Self::DuplicateTransaction(_) => 5102,
+            Self::NoteConsumedTwice { .. } => 5103,
+            Self::NoteProducedTwice { .. } => 5104,
+        }
+    }
+}
+
+impl fmt::Display for TransactionGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictingAccountUpdates { account_id, first, second } => write!(
+                f,
+                "transactions {first} and {second} update account {account_id} from the same state"
+            ),
+            Self::DependencyCycle(tx_id) => {
+                write!(f, "transaction {tx_id} is part of a dependency cycle")
+            },
+            Self::DuplicateTransaction(tx_id) => {
+                write!(f, "transaction {tx_id} is added more than once")
+            },
+            Self::NoteConsumedTwice { note_id, first, second } => {
+                write!(f, "note {note_id} is consumed by both transactions {first} and {second}")
+            },
+            Self::NoteProducedTwice { note_id, first, second } => {
+                write!(f, "note {note_id} is produced by both transactions {first} and {second}")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionGraphError {}
+
 // TRANSACTION SCRIPT ERROR
 // ================================================================================================
 
@@ -291,14 +1324,34 @@ pub enum TransactionScriptError {
     ScriptCompilationError(AssemblyError),
 }
 
+impl TransactionScriptError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction script errors are in the range 5200..5300. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::ScriptCompilationError(_) => 5200,
+        }
+    }
+}
+
 impl fmt::Display for TransactionScriptError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::ScriptCompilationError(_) => write!(f, "failed to compile transaction script"),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionScriptError {}
+impl std::error::Error for TransactionScriptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ScriptCompilationError(err) => Some(err),
+        }
+    }
+}
 
 // TRANSACTION INPUT ERROR
 // ================================================================================================
@@ -307,23 +1360,129 @@ impl std::error::Error for TransactionScriptError {}
 pub enum TransactionInputError {
     AccountSeedNotProvidedForNewAccount,
     AccountSeedProvidedForExistingAccount,
+    ConflictingAdviceMapEntry(Digest),
+    DuplicateForeignAccount(AccountId),
     DuplicateInputNote(Digest),
+    ForeignAccountNotInBlock(AccountId),
     InconsistentChainLength { expected: u32, actual: u32 },
     InconsistentChainRoot { expected: Digest, actual: Digest },
+    InconsistentForeignAccountWitness(AccountId),
     InputNoteBlockNotInChainMmr(NoteId),
     InputNoteNotInBlock(NoteId, u32),
     InvalidAccountSeed(AccountError),
+    InvalidChainMmrBlock(ChainMmrError),
+    InvalidForeignAccountStorageOpening(AccountId, u8),
+    PartialVaultRootMismatch { expected: Digest, actual: Digest },
+    TooManyForeignAccounts { max: usize, actual: usize },
     TooManyInputNotes { max: usize, actual: usize },
 }
 
+impl TransactionInputError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction input errors are in the range 5300..5400. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AccountSeedNotProvidedForNewAccount => 5300,
+            Self::AccountSeedProvidedForExistingAccount => 5301,
+            Self::ConflictingAdviceMapEntry(_) => 5302,
+            Self::DuplicateForeignAccount(_) => 5303,
+            Self::DuplicateInputNote(_) => 5304,
+            Self::ForeignAccountNotInBlock(_) => 5305,
+            Self::InconsistentChainLength { .. } => 5306,
+            Self::InconsistentChainRoot { .. } => 5307,
+            Self::InconsistentForeignAccountWitness(_) => 5308,
+            Self::InputNoteBlockNotInChainMmr(_) => 5309,
+            Self::InputNoteNotInBlock(..) => 5310,
+            Self::InvalidAccountSeed(_) => 5311,
+            Self::InvalidForeignAccountStorageOpening(..) => 5312,
+            Self::PartialVaultRootMismatch { .. } => 5313,
+            Self::TooManyForeignAccounts { .. } => 5314,
+            Self::TooManyInputNotes { .. } => 5315,
+            Self::InvalidChainMmrBlock(_) => 5316,
+        }
+    }
+}
+
 impl fmt::Display for TransactionInputError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::AccountSeedNotProvidedForNewAccount => {
+                write!(f, "account seed must be provided for a new account")
+            },
+            Self::AccountSeedProvidedForExistingAccount => {
+                write!(f, "account seed must not be provided for an existing account")
+            },
+            Self::ConflictingAdviceMapEntry(key) => {
+                write!(f, "advice map key {} is mapped to conflicting values", key.to_hex())
+            },
+            Self::DuplicateForeignAccount(account_id) => {
+                write!(f, "foreign account {account_id} is provided more than once")
+            },
+            Self::DuplicateInputNote(nullifier) => write!(
+                f,
+                "input note with nullifier {} is provided more than once",
+                nullifier.to_hex()
+            ),
+            Self::ForeignAccountNotInBlock(account_id) => write!(
+                f,
+                "foreign account {account_id} is not in the account tree of the reference block"
+            ),
+            Self::InconsistentChainLength { expected, actual } => write!(
+                f,
+                "chain MMR has {actual} blocks, but the reference block requires {expected}"
+            ),
+            Self::InconsistentChainRoot { expected, actual } => write!(
+                f,
+                "chain MMR root {} differs from the chain root {} of the reference block",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::InconsistentForeignAccountWitness(account_id) => {
+                write!(f, "witness of foreign account {account_id} is inconsistent")
+            },
+            Self::InputNoteBlockNotInChainMmr(note_id) => {
+                write!(f, "block of input note {note_id} is not in the chain MMR")
+            },
+            Self::InputNoteNotInBlock(note_id, block_num) => {
+                write!(f, "input note {note_id} is not in block {block_num}")
+            },
+            Self::InvalidAccountSeed(_) => write!(f, "invalid account seed"),
+            Self::InvalidChainMmrBlock(_) => {
+                write!(f, "block header cannot be added to the chain MMR")
+            },
+            Self::InvalidForeignAccountStorageOpening(account_id, slot) => write!(
+                f,
+                "opening of storage slot {slot} of foreign account {account_id} is invalid"
+            ),
+            Self::PartialVaultRootMismatch { expected, actual } => write!(
+                f,
+                "partial vault root {} differs from the account vault root {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::TooManyForeignAccounts { max, actual } => write!(
+                f,
+                "transaction reads {actual} foreign accounts, but at most {max} are allowed"
+            ),
+            Self::TooManyInputNotes { max, actual } => {
+                write!(f, "transaction consumes {actual} notes, but at most {max} are allowed")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionInputError {}
+impl std::error::Error for TransactionInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidAccountSeed(err) => Some(err),
+            Self::InvalidChainMmrBlock(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // TRANSACTION OUTPUT ERROR
 // ===============================================================================================
@@ -333,17 +1492,288 @@ pub enum TransactionOutputError {
     DuplicateOutputNote(NoteId),
     FinalAccountDataNotFound,
     FinalAccountStubDataInvalid(AccountError),
+    InvalidExpirationBlockNum(Felt),
+    InvalidFee(AssetError),
     OutputNoteDataNotFound,
     OutputNoteDataInvalid(NoteError),
     OutputNotesCommitmentInconsistent(Digest, Digest),
     TooManyOutputNotes { max: usize, actual: usize },
 }
 
+impl TransactionOutputError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction output errors are in the range 5400..5500. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::DuplicateOutputNote(_) => 5400,
+            Self::FinalAccountDataNotFound => 5401,
+            Self::FinalAccountStubDataInvalid(_) => 5402,
+            Self::InvalidExpirationBlockNum(_) => 5403,
+            Self::InvalidFee(_) => 5408,
+            Self::OutputNoteDataNotFound => 5404,
+            Self::OutputNoteDataInvalid(_) => 5405,
+            Self::OutputNotesCommitmentInconsistent(..) => 5406,
+            Self::TooManyOutputNotes { .. } => 5407,
+        }
+    }
+}
+
 impl fmt::Display for TransactionOutputError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::DuplicateOutputNote(note_id) => {
+                write!(f, "output note {note_id} is created more than once")
+            },
+            Self::FinalAccountDataNotFound => write!(f, "final account data not found"),
+            Self::FinalAccountStubDataInvalid(_) => write!(f, "final account data is invalid"),
+            Self::InvalidExpirationBlockNum(value) => {
+                write!(f, "expiration block number {value} is not valid")
+            },
+            Self::InvalidFee(_) => write!(f, "transaction fee is not a valid fungible asset"),
+            Self::OutputNoteDataNotFound => write!(f, "output note data not found"),
+            Self::OutputNoteDataInvalid(_) => write!(f, "output note data is invalid"),
+            Self::OutputNotesCommitmentInconsistent(expected, actual) => write!(
+                f,
+                "output notes commit to {}, but the transaction outputs commitment {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::TooManyOutputNotes { max, actual } => {
+                write!(f, "transaction creates {actual} notes, but at most {max} are allowed")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionOutputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::FinalAccountStubDataInvalid(err) => Some(err),
+            Self::InvalidFee(err) => Some(err),
+            Self::OutputNoteDataInvalid(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// TRANSACTION BATCH ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionBatchError {
+    DuplicateInputNote {
+        nullifier: Nullifier,
+        first: TransactionId,
+        second: TransactionId,
+    },
+    DuplicateOutputNote {
+        note_id: NoteId,
+        first: TransactionId,
+        second: TransactionId,
+    },
+    DuplicateTransaction(TransactionId),
+    EmptyBatch,
+    InconsistentAccountStateTransition {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    TooManyOutputNotes {
+        max: usize,
+        actual: usize,
+    },
+    TooManyTransactions {
+        max: usize,
+        actual: usize,
+    },
+}
+
+impl TransactionBatchError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of transaction batch errors are in the range 5500..5600. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::DuplicateInputNote { .. } => 5500,
+            Self::DuplicateOutputNote { .. } => 5501,
+            Self::DuplicateTransaction(_) => 5502,
+            Self::EmptyBatch => 5503,
+            Self::InconsistentAccountStateTransition { .. } => 5504,
+            Self::TooManyOutputNotes { .. } => 5505,
+            Self::TooManyTransactions { .. } => 5506,
+        }
+    }
+}
+
+impl fmt::Display for TransactionBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateInputNote { nullifier, first, second } => write!(
+                f,
+                "note with nullifier {nullifier} is consumed by both transactions {first} and \
+                 {second}"
+            ),
+            Self::DuplicateOutputNote { note_id, first, second } => {
+                write!(f, "note {note_id} is created by both transactions {first} and {second}")
+            },
+            Self::DuplicateTransaction(tx_id) => {
+                write!(f, "transaction {tx_id} is added to the batch more than once")
+            },
+            Self::EmptyBatch => write!(f, "batch does not contain any transactions"),
+            Self::InconsistentAccountStateTransition { account_id, expected, actual } => write!(
+                f,
+                "transaction against account {account_id} starts from state {}, but the previous \
+                 transaction of the batch ends in state {}",
+                actual.to_hex(),
+                expected.to_hex()
+            ),
+            Self::TooManyOutputNotes { max, actual } => {
+                write!(f, "batch creates {actual} notes, but at most {max} are allowed")
+            },
+            Self::TooManyTransactions { max, actual } => {
+                write!(f, "batch contains {actual} transactions, but at most {max} are allowed")
+            },
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionOutputError {}
+impl std::error::Error for TransactionBatchError {}
+
+// JSON CONVERSION ERROR
+// ================================================================================================
+
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonConversionError {
+    DeserializationFailed(DeserializationError),
+    InconsistentFields,
+    InvalidHex,
+    InvalidJson(String),
+    MissingField(&'static str),
+    UnexpectedType { expected: &'static str, actual: String },
+    UnsupportedVersion(u64),
+}
+
+#[cfg(feature = "json")]
+impl JsonConversionError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of JSON conversion errors are in the range 6000..6100. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::DeserializationFailed(_) => 6000,
+            Self::InconsistentFields => 6001,
+            Self::InvalidHex => 6002,
+            Self::InvalidJson(_) => 6003,
+            Self::MissingField(_) => 6004,
+            Self::UnexpectedType { .. } => 6005,
+            Self::UnsupportedVersion(_) => 6006,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl fmt::Display for JsonConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DeserializationFailed(_) => write!(f, "failed to deserialize binary field"),
+            Self::InconsistentFields => write!(f, "JSON fields are inconsistent with each other"),
+            Self::InvalidHex => write!(f, "field is not a valid hex string"),
+            Self::InvalidJson(msg) => write!(f, "invalid JSON: {msg}"),
+            Self::MissingField(field) => write!(f, "field '{field}' is missing"),
+            Self::UnexpectedType { expected, actual } => {
+                write!(f, "expected a {expected} value, but found {actual}")
+            },
+            Self::UnsupportedVersion(version) => write!(f, "version {version} is not supported"),
+        }
+    }
+}
+
+#[cfg(all(feature = "json", feature = "std"))]
+impl std::error::Error for JsonConversionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::DeserializationFailed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// PROTO CONVERSION ERROR
+// ================================================================================================
+
+#[cfg(feature = "proto")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProtoConversionError {
+    DeserializationFailed(DeserializationError),
+    InvalidAccountId(AccountError),
+    InvalidAsset(AssetError),
+    InvalidInputNotes(TransactionInputError),
+    InvalidNote(NoteError),
+    InvalidOutputNotes(TransactionOutputError),
+    InvalidProvenTransaction(ProvenTransactionError),
+    MissingField(&'static str),
+    NotACanonicalFieldElement(u64),
+}
+
+#[cfg(feature = "proto")]
+impl ProtoConversionError {
+    /// Returns the numeric code of this error.
+    ///
+    /// Codes of protobuf conversion errors are in the range 6100..6200. The code of an error never
+    /// changes, and codes of removed errors are not reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::DeserializationFailed(_) => 6100,
+            Self::InvalidAccountId(_) => 6101,
+            Self::InvalidAsset(_) => 6102,
+            Self::InvalidInputNotes(_) => 6103,
+            Self::InvalidNote(_) => 6104,
+            Self::InvalidOutputNotes(_) => 6105,
+            Self::InvalidProvenTransaction(_) => 6106,
+            Self::MissingField(_) => 6107,
+            Self::NotACanonicalFieldElement(_) => 6108,
+        }
+    }
+}
+
+#[cfg(feature = "proto")]
+impl fmt::Display for ProtoConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DeserializationFailed(_) => write!(f, "failed to deserialize binary field"),
+            Self::InvalidAccountId(_) => write!(f, "invalid account ID"),
+            Self::InvalidAsset(_) => write!(f, "invalid asset"),
+            Self::InvalidInputNotes(_) => write!(f, "invalid input notes"),
+            Self::InvalidNote(_) => write!(f, "invalid note"),
+            Self::InvalidOutputNotes(_) => write!(f, "invalid output notes"),
+            Self::InvalidProvenTransaction(_) => write!(f, "invalid proven transaction"),
+            Self::MissingField(field) => write!(f, "field '{field}' is missing"),
+            Self::NotACanonicalFieldElement(value) => {
+                write!(f, "{value} is not a canonical field element")
+            },
+        }
+    }
+}
+
+#[cfg(all(feature = "proto", feature = "std"))]
+impl std::error::Error for ProtoConversionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::DeserializationFailed(err) => Some(err),
+            Self::InvalidAccountId(err) => Some(err),
+            Self::InvalidAsset(err) => Some(err),
+            Self::InvalidInputNotes(err) => Some(err),
+            Self::InvalidNote(err) => Some(err),
+            Self::InvalidOutputNotes(err) => Some(err),
+            Self::InvalidProvenTransaction(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! Canonical JSON encoding of transactions, for RPC services and explorers which need to exchange
+//! transactions without defining their own schema.
+//!
+//! Every encoded object is a JSON object tagged with the version of the encoding (`"version"`)
+//! and the kind of the object (`"type"`). Digests (e.g., account hashes, note IDs and nullifiers)
+//! and account IDs are encoded as `0x`-prefixed, big-endian hex strings; numbers which fit into a
+//! JSON number without loss of precision (e.g., block numbers and note tags) are encoded as
+//! numbers. Asset amounts, which may exceed the precision of JSON numbers, are encoded as decimal
+//! strings.
+//!
+//! A [ProvenTransaction] is encoded as follows:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "type": "proven_transaction",
+//!   "id": "0x...",
+//!   "account_id": "0x...",
+//!   "initial_account_hash": "0x...",
+//!   "final_account_hash": "0x...",
+//!   "input_notes": ["0x<nullifier>", ...],
+//!   "unauthenticated_notes": [{ "id": "0x...", "sender": "0x...", "tag": 1234 }, ...],
+//!   "output_notes": [{ "id": "0x...", "sender": "0x...", "tag": 1234 }, ...],
+//!   "account_details": "full" | "delta" | null,
+//!   "tx_script_root": "0x..." | null,
+//!   "kernel_root": "0x...",
+//!   "block_ref": "0x...",
+//!   "expiration_block_num": 1234,
+//!   "fee": { "faucet_id": "0x...", "amount": "1234" } | null,
+//!   "data": "0x<serialized transaction>"
+//! }
+//! ```
+//!
+//! The `data` field contains the [Serializable] encoding of the transaction (including the
+//! account details and the proof), and is the authoritative representation of the transaction:
+//! the other fields are provided for readability, and [ProvenTransaction::from_json()] rejects
+//! encodings in which they are inconsistent with `data`.
+//!
+//! [ExecutedTransaction]s and [TransactionWitness]es are encoded in the same way, with the
+//! `"executed_transaction"` and `"transaction_witness"` types respectively. Since transaction
+//! programs have no serialized form, these encodings identify the program by its hash
+//! (`"program_hash"`), do not contain a `data` field, and thus cannot be decoded.
+
+use serde_json::{json, Value};
+
+use crate::{
+    assets::FungibleAsset,
+    notes::NoteMetadata,
+    transaction::{
+        AccountDetails, ExecutedTransaction, InputNotes, ProvenTransaction, TransactionWitness,
+    },
+    utils::{
+        collections::Vec,
+        serde::{Deserializable, Serializable},
+        string::{String, ToString},
+    },
+    JsonConversionError,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The version of the JSON encoding produced by this module.
+pub const JSON_ENCODING_VERSION: u64 = 1;
+
+// PROVEN TRANSACTION
+// ================================================================================================
+
+impl ProvenTransaction {
+    /// Returns the canonical JSON encoding of this transaction.
+    pub fn to_json(&self) -> String {
+        self.to_json_value().to_string()
+    }
+
+    /// Returns the transaction encoded in the provided canonical JSON string.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not valid JSON, or does not encode a proven transaction of a supported
+    ///   version.
+    /// - The `data` field is missing or cannot be deserialized into a transaction.
+    /// - Any of the other fields is inconsistent with the `data` field.
+    pub fn from_json(json: &str) -> Result<Self, JsonConversionError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|err| JsonConversionError::InvalidJson(err.to_string()))?;
+        check_header(&value, "proven_transaction")?;
+
+        let data = value
+            .get("data")
+            .and_then(Value::as_str)
+            .ok_or(JsonConversionError::MissingField("data"))?;
+        let transaction = ProvenTransaction::read_from_bytes(&decode_hex(data)?)
+            .map_err(JsonConversionError::DeserializationFailed)?;
+
+        if transaction.to_json_value() != value {
+            return Err(JsonConversionError::InconsistentFields);
+        }
+
+        Ok(transaction)
+    }
+
+    fn to_json_value(&self) -> Value {
+        let account_details = self.account_details().map(|details| match details {
+            AccountDetails::Full(_) => "full",
+            AccountDetails::Delta(_) => "delta",
+        });
+
+        json!({
+            "version": JSON_ENCODING_VERSION,
+            "type": "proven_transaction",
+            "id": self.id().to_hex(),
+            "account_id": self.account_id().to_hex(),
+            "initial_account_hash": self.initial_account_hash().to_hex(),
+            "final_account_hash": self.final_account_hash().to_hex(),
+            "input_notes": self
+                .input_notes()
+                .iter()
+                .map(|note| note.nullifier().to_hex())
+                .collect::<Vec<_>>(),
+            "unauthenticated_notes": self
+                .unauthenticated_notes()
+                .map(|note| encode_note(note.note_id().to_hex(), note.metadata()))
+                .collect::<Vec<_>>(),
+            "output_notes": self
+                .output_notes()
+                .iter()
+                .map(|note| encode_note(note.note_id().to_hex(), note.metadata()))
+                .collect::<Vec<_>>(),
+            "account_details": account_details,
+            "tx_script_root": self.tx_script_root().map(|root| root.to_hex()),
+            "kernel_root": self.kernel_root().to_hex(),
+            "block_ref": self.block_ref().to_hex(),
+            "expiration_block_num": self.expiration_block_num(),
+            "fee": self.fee().map(encode_fee),
+            "data": encode_hex(&self.to_bytes()),
+        })
+    }
+}
+
+// EXECUTED TRANSACTION
+// ================================================================================================
+
+impl ExecutedTransaction {
+    /// Returns the canonical JSON encoding of this transaction.
+    ///
+    /// The encoding describes the effects of the transaction, but cannot be decoded back into an
+    /// [ExecutedTransaction] (see the [module-level documentation](crate::json)).
+    pub fn to_json(&self) -> String {
+        json!({
+            "version": JSON_ENCODING_VERSION,
+            "type": "executed_transaction",
+            "id": self.id().to_hex(),
+            "account_id": self.account_id().to_hex(),
+            "initial_account_hash": self.initial_account().hash().to_hex(),
+            "final_account_hash": self.final_account().hash().to_hex(),
+            "input_notes": encode_input_notes(self.input_notes()),
+            "output_notes": self
+                .output_notes()
+                .iter()
+                .map(|note| encode_note(note.id().to_hex(), note.metadata()))
+                .collect::<Vec<_>>(),
+            "tx_script_root": self.tx_args().tx_script().map(|script| script.hash().to_hex()),
+            "program_hash": self.program().hash().to_hex(),
+            "block_num": self.block_header().block_num(),
+            "block_ref": self.block_header().hash().to_hex(),
+            "expiration_block_num": self.expiration_block_num(),
+            "fee": self.fee().map(encode_fee),
+        })
+        .to_string()
+    }
+}
+
+// TRANSACTION WITNESS
+// ================================================================================================
+
+impl TransactionWitness {
+    /// Returns the canonical JSON encoding of this witness.
+    ///
+    /// The encoding describes the inputs of the transaction, but cannot be decoded back into a
+    /// [TransactionWitness] (see the [module-level documentation](crate::json)).
+    pub fn to_json(&self) -> String {
+        json!({
+            "version": JSON_ENCODING_VERSION,
+            "type": "transaction_witness",
+            "account_id": self.account().id().to_hex(),
+            "initial_account_hash": self.account().hash().to_hex(),
+            "input_notes": encode_input_notes(self.input_notes()),
+            "tx_script_root": self.tx_args().tx_script().map(|script| script.hash().to_hex()),
+            "program_hash": self.program().hash().to_hex(),
+            "block_num": self.block_header().block_num(),
+            "block_ref": self.block_header().hash().to_hex(),
+        })
+        .to_string()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the provided value is tagged with the supported encoding version and the expected
+/// object type.
+fn check_header(value: &Value, expected_type: &'static str) -> Result<(), JsonConversionError> {
+    let version = value
+        .get("version")
+        .and_then(Value::as_u64)
+        .ok_or(JsonConversionError::MissingField("version"))?;
+    if version != JSON_ENCODING_VERSION {
+        return Err(JsonConversionError::UnsupportedVersion(version));
+    }
+
+    let object_type = value
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or(JsonConversionError::MissingField("type"))?;
+    if object_type != expected_type {
+        return Err(JsonConversionError::UnexpectedType {
+            expected: expected_type,
+            actual: object_type.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn encode_input_notes(notes: &InputNotes) -> Vec<Value> {
+    notes
+        .iter()
+        .map(|note| {
+            json!({
+                "id": note.id().to_hex(),
+                "nullifier": note.note().nullifier().to_hex(),
+            })
+        })
+        .collect()
+}
+
+fn encode_note(note_id: String, metadata: &NoteMetadata) -> Value {
+    json!({
+        "id": note_id,
+        "sender": metadata.sender().to_hex(),
+        "tag": metadata.tag().inner(),
+    })
+}
+
+fn encode_fee(fee: FungibleAsset) -> Value {
+    json!({
+        "faucet_id": fee.faucet_id().to_hex(),
+        "amount": fee.amount().to_string(),
+    })
+}
+
+/// Returns the `0x`-prefixed hex encoding of the provided bytes.
+fn encode_hex(bytes: &[u8]) -> String {
+    const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut result = String::with_capacity(2 + 2 * bytes.len());
+    result.push_str("0x");
+    for byte in bytes {
+        result.push(HEX_CHARS[(byte >> 4) as usize] as char);
+        result.push(HEX_CHARS[(byte & 0xf) as usize] as char);
+    }
+    result
+}
+
+/// Returns the bytes encoded by the provided `0x`-prefixed hex string.
+fn decode_hex(hex: &str) -> Result<Vec<u8>, JsonConversionError> {
+    let digits = hex
+        .strip_prefix("0x")
+        .filter(|digits| digits.len() % 2 == 0)
+        .ok_or(JsonConversionError::InvalidHex)?;
+
+    digits
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16);
+            let low = (pair[1] as char).to_digit(16);
+            match (high, low) {
+                (Some(high), Some(low)) => Ok((high * 16 + low) as u8),
+                _ => Err(JsonConversionError::InvalidHex),
+            }
+        })
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_hex, encode_hex};
+    use crate::JsonConversionError;
+
+    #[test]
+    fn hex_round_trip() {
+        let bytes = [0x00, 0x01, 0xab, 0xff];
+        let hex = encode_hex(&bytes);
+        assert_eq!(hex, "0x0001abff");
+        assert_eq!(decode_hex(&hex).unwrap(), bytes);
+
+        assert_eq!(decode_hex("0001"), Err(JsonConversionError::InvalidHex));
+        assert_eq!(decode_hex("0x001"), Err(JsonConversionError::InvalidHex));
+        assert_eq!(decode_hex("0x0g"), Err(JsonConversionError::InvalidHex));
+    }
+}
@@ -7,20 +7,33 @@ extern crate alloc;
 pub mod accounts;
 pub mod assets;
 pub mod block;
+#[cfg(feature = "json")]
+pub mod json;
 pub mod notes;
+#[cfg(feature = "proto")]
+pub mod proto;
 pub mod transaction;
 
+#[cfg(any(feature = "proptest", test))]
+pub mod arbitrary;
+
+mod bech32_encoding;
 mod constants;
 mod errors;
 
 // RE-EXPORTS
 // ================================================================================================
 
-pub use block::BlockHeader;
+pub use block::{BlockHeader, BlockHeaderBuilder};
 pub use constants::*;
+#[cfg(feature = "json")]
+pub use errors::JsonConversionError;
+#[cfg(feature = "proto")]
+pub use errors::ProtoConversionError;
 pub use errors::{
-    AccountDeltaError, AccountError, AssetError, AssetVaultError, ChainMmrError, NoteError,
-    TransactionInputError, TransactionOutputError, TransactionScriptError,
+    AccountDeltaError, AccountError, AssetError, AssetVaultError, BlockError, BlockHeaderError,
+    ChainMmrError, NoteError, ParseAccountIdError, ProvenTransactionError, TransactionBatchError,
+    TransactionGraphError, TransactionInputError, TransactionOutputError, TransactionScriptError,
 };
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
 pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO};
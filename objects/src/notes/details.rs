@@ -0,0 +1,185 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher, Note, NoteAssets,
+    NoteId, NoteInclusionProof, NoteInputs, NoteMetadata, NoteScript, Nullifier, Serializable,
+    Word,
+};
+use crate::transaction::InputNote;
+
+// NOTE DETAILS
+// ================================================================================================
+
+/// The details of a note which are known to its recipient, without the note metadata.
+///
+/// Note details consist of the note assets and the components of the note recipient (i.e., the
+/// note script, the note inputs, and the serial number). They are sufficient to compute the ID and
+/// the nullifier of a note, and are used by the creator of a private note to share the note with
+/// its recipient before the note is included in a block.
+///
+/// Once the note has been included in a block, the details can be combined with the observed
+/// metadata and the inclusion proof of the note into an [InputNote] via
+/// [NoteDetails::into_input_note()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteDetails {
+    script: NoteScript,
+    inputs: NoteInputs,
+    assets: NoteAssets,
+    serial_num: Word,
+}
+
+impl NoteDetails {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [NoteDetails] instantiated from the provided parameters.
+    pub fn new(
+        script: NoteScript,
+        inputs: NoteInputs,
+        assets: NoteAssets,
+        serial_num: Word,
+    ) -> Self {
+        Self { script, inputs, assets, serial_num }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the script of the note.
+    pub fn script(&self) -> &NoteScript {
+        &self.script
+    }
+
+    /// Returns a reference to the inputs of the note.
+    pub fn inputs(&self) -> &NoteInputs {
+        &self.inputs
+    }
+
+    /// Returns a reference to the assets of the note.
+    pub fn assets(&self) -> &NoteAssets {
+        &self.assets
+    }
+
+    /// Returns the serial number of the note.
+    pub fn serial_num(&self) -> Word {
+        self.serial_num
+    }
+
+    /// Returns the recipient of the note.
+    ///
+    /// Recipient is defined and calculated as:
+    ///  hash(hash(hash(serial_num, [0; 4]), script_hash), input_hash)
+    pub fn recipient(&self) -> Digest {
+        let serial_num_hash = Hasher::merge(&[self.serial_num.into(), Digest::default()]);
+        let merge_script = Hasher::merge(&[serial_num_hash, self.script.hash()]);
+        Hasher::merge(&[merge_script, self.inputs.commitment()])
+    }
+
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        NoteId::new(self.recipient(), self.assets.commitment())
+    }
+
+    /// Returns the nullifier of the note.
+    pub fn nullifier(&self) -> Nullifier {
+        Nullifier::new(
+            self.script.hash(),
+            self.inputs.commitment(),
+            self.assets.commitment(),
+            self.serial_num,
+        )
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note described by these details and the provided metadata.
+    pub fn into_note(self, metadata: NoteMetadata) -> Note {
+        Note::from_details(self, metadata)
+    }
+
+    /// Returns an [InputNote] built from these details, the metadata of the note and the proof of
+    /// inclusion of the note in a block.
+    ///
+    /// The inclusion proof is not verified against a block; this is done when the note is used as
+    /// an input of a transaction.
+    pub fn into_input_note(self, metadata: NoteMetadata, proof: NoteInclusionProof) -> InputNote {
+        InputNote::new(self.into_note(metadata), proof)
+    }
+}
+
+impl From<&Note> for NoteDetails {
+    fn from(note: &Note) -> Self {
+        note.details().clone()
+    }
+}
+
+impl From<Note> for NoteDetails {
+    fn from(note: Note) -> Self {
+        note.into_parts().0
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteDetails {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.script.write_into(target);
+        self.inputs.write_into(target);
+        self.assets.write_into(target);
+        self.serial_num.write_into(target);
+    }
+}
+
+impl Deserializable for NoteDetails {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let script = NoteScript::read_from(source)?;
+        let inputs = NoteInputs::read_from(source)?;
+        let assets = NoteAssets::read_from(source)?;
+        let serial_num = Word::read_from(source)?;
+
+        Ok(Self::new(script, inputs, assets, serial_num))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, Note, NoteDetails, NoteScript, Serializable};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assembly::ProgramAst,
+        assets::{Asset, FungibleAsset},
+        notes::NoteTag,
+        Digest, Felt,
+    };
+
+    #[test]
+    fn note_details_match_note() {
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let script = NoteScript::from_parts(code, Digest::default());
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let note =
+            Note::new(script, &[Felt::new(5)], &[asset], serial_num, sender, NoteTag::from(6))
+                .unwrap();
+
+        let details = NoteDetails::from(&note);
+        assert_eq!(details.recipient(), note.recipient());
+        assert_eq!(details.id(), note.id());
+        assert_eq!(details.nullifier(), note.nullifier());
+
+        let bytes = details.to_bytes();
+        let details = NoteDetails::read_from_bytes(&bytes).unwrap();
+        let restored_note = details.into_note(*note.metadata());
+        assert_eq!(restored_note.id(), note.id());
+        assert_eq!(restored_note.to_bytes(), note.to_bytes());
+    }
+}
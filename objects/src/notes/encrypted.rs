@@ -0,0 +1,95 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, NoteId, Serializable, Vec,
+};
+
+// ENCRYPTED NOTE DETAILS
+// ================================================================================================
+
+/// The [NoteDetails](super::NoteDetails) of a note encrypted to the public key of its recipient.
+///
+/// Encrypted note details allow the creator of a private note to share the note with its recipient
+/// over a public channel. The envelope consists of:
+/// - The ID of the note, which the recipient can use to match the envelope against the notes
+///   observed on chain, and which is authenticated by the encryption.
+/// - The ephemeral public key generated by the creator of the note for this envelope.
+/// - The ciphertext of the serialized note details.
+///
+/// Helpers for building and opening encrypted note details are provided by `miden-lib`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedNoteDetails {
+    note_id: NoteId,
+    ephemeral_key: [u8; 32],
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedNoteDetails {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [EncryptedNoteDetails] instantiated from the provided parameters.
+    pub fn new(note_id: NoteId, ephemeral_key: [u8; 32], ciphertext: Vec<u8>) -> Self {
+        Self { note_id, ephemeral_key, ciphertext }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the encrypted note.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the ephemeral public key used to encrypt the note details.
+    pub fn ephemeral_key(&self) -> &[u8; 32] {
+        &self.ephemeral_key
+    }
+
+    /// Returns the ciphertext of the note details.
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for EncryptedNoteDetails {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note_id.write_into(target);
+        target.write_bytes(&self.ephemeral_key);
+
+        assert!(self.ciphertext.len() <= u32::MAX as usize, "ciphertext is too long");
+        target.write_u32(self.ciphertext.len() as u32);
+        target.write_bytes(&self.ciphertext);
+    }
+}
+
+impl Deserializable for EncryptedNoteDetails {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let note_id = NoteId::read_from(source)?;
+        let ephemeral_key = source.read_array()?;
+        let ciphertext_len = source.read_u32()? as usize;
+        let ciphertext = source.read_vec(ciphertext_len)?;
+
+        Ok(Self::new(note_id, ephemeral_key, ciphertext))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, EncryptedNoteDetails, NoteId, Serializable};
+    use crate::{Digest, Felt};
+
+    #[test]
+    fn encrypted_note_details_serialization() {
+        let note_id =
+            NoteId::from(Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]));
+        let encrypted = EncryptedNoteDetails::new(note_id, [7; 32], vec![1, 2, 3, 4, 5]);
+
+        let bytes = encrypted.to_bytes();
+        assert_eq!(EncryptedNoteDetails::read_from_bytes(&bytes).unwrap(), encrypted);
+    }
+}
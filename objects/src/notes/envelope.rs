@@ -10,13 +10,14 @@ use super::{
 ///
 /// Contains:
 /// - note_id: ID of the note that was created
-/// - note_metadata: metadata of the note that was created. Metadata is padded with ZERO such that
-///   it is four elements in size (a word). The metadata includes the following elements:
-///     - sender
+/// - note_metadata: metadata of the note that was created. Metadata is four elements in size (a
+///   word), and includes the following elements:
 ///     - tag
-///     - num assets
-///     - ZERO
+///     - sender
+///     - note type
+///     - aux
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteEnvelope {
     note_id: NoteId,
     note_metadata: NoteMetadata,
@@ -0,0 +1,222 @@
+#[cfg(feature = "std")]
+use std::{fs, io, path::Path};
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Note, NoteDetails, NoteId,
+    NoteInclusionProof, NoteMetadata, Serializable, ToString,
+};
+use crate::{transaction::InputNote, utils::format};
+
+// NOTE FILE
+// ================================================================================================
+
+/// A self-describing container of all the data needed by the recipient of a note to import the
+/// note.
+///
+/// A note file consists of the [NoteDetails] and the [NoteMetadata] of a note, and optionally of
+/// the proof of inclusion of the note in a block; the proof is absent if the note was exported
+/// before it was included in a block. Note files are intended for off-chain delivery of notes
+/// (e.g., as `.mno` files exchanged between wallets).
+///
+/// The binary format of a note file starts with [NoteFile::MAGIC] followed by the version of the
+/// format, which allows readers to reject unrelated or unsupported files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteFile {
+    details: NoteDetails,
+    metadata: NoteMetadata,
+    proof: Option<NoteInclusionProof>,
+}
+
+impl NoteFile {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The bytes at the start of every serialized note file.
+    pub const MAGIC: [u8; 4] = *b"MNOT";
+
+    /// The current version of the note file format.
+    pub const VERSION: u8 = 1;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteFile] instantiated from the provided parameters.
+    pub fn new(
+        details: NoteDetails,
+        metadata: NoteMetadata,
+        proof: Option<NoteInclusionProof>,
+    ) -> Self {
+        Self { details, metadata, proof }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.details.id()
+    }
+
+    /// Returns a reference to the details of the note.
+    pub fn details(&self) -> &NoteDetails {
+        &self.details
+    }
+
+    /// Returns a reference to the metadata of the note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        &self.metadata
+    }
+
+    /// Returns a reference to the inclusion proof of the note, if the note file contains one.
+    pub fn proof(&self) -> Option<&NoteInclusionProof> {
+        self.proof.as_ref()
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note contained in this file.
+    pub fn into_note(self) -> Note {
+        self.details.into_note(self.metadata)
+    }
+
+    /// Returns an [InputNote] built from the contents of this file, or None if the file does not
+    /// contain an inclusion proof of the note.
+    pub fn into_input_note(self) -> Option<InputNote> {
+        let proof = self.proof?;
+        Some(self.details.into_input_note(self.metadata, proof))
+    }
+
+    // FILE IO
+    // --------------------------------------------------------------------------------------------
+
+    #[cfg(feature = "std")]
+    /// Serializes and writes this note file to the specified path.
+    pub fn write(&self, filepath: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(filepath, self.to_bytes())
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads the file at the specified path and deserializes a note file from it.
+    pub fn read(filepath: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = fs::read(filepath)?;
+        Self::read_from_bytes(&bytes).map_err(|_| io::ErrorKind::InvalidData.into())
+    }
+}
+
+impl From<&Note> for NoteFile {
+    fn from(note: &Note) -> Self {
+        Self::new(note.into(), *note.metadata(), None)
+    }
+}
+
+impl From<InputNote> for NoteFile {
+    fn from(note: InputNote) -> Self {
+        Self::new(note.note().into(), *note.note().metadata(), note.proof().cloned())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteFile {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&Self::MAGIC);
+        target.write_u8(Self::VERSION);
+
+        self.details.write_into(target);
+        self.metadata.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for NoteFile {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 4] = source.read_array()?;
+        if magic != Self::MAGIC {
+            return Err(DeserializationError::InvalidValue("not a note file".to_string()));
+        }
+
+        let version = source.read_u8()?;
+        if version != Self::VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported note file version {version}"
+            )));
+        }
+
+        let details = NoteDetails::read_from(source)?;
+        let metadata = NoteMetadata::read_from(source)?;
+        let proof = <Option<NoteInclusionProof>>::read_from(source)?;
+
+        Ok(Self::new(details, metadata, proof))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::{Deserializable, NoteFile, Serializable};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assembly::ProgramAst,
+        assets::{Asset, FungibleAsset},
+        crypto::merkle::MerklePath,
+        notes::{Note, NoteInclusionProof, NoteScript, NoteTag},
+        transaction::InputNote,
+        Digest, Felt, NOTE_TREE_DEPTH,
+    };
+
+    fn build_note() -> Note {
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let script = NoteScript::from_parts(code, Digest::default());
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        Note::new(script, &[Felt::new(5)], &[asset], serial_num, sender, NoteTag::from(6)).unwrap()
+    }
+
+    #[test]
+    fn note_file_serialization() {
+        let note = build_note();
+
+        // a note file without an inclusion proof cannot be converted into an input note
+        let file = NoteFile::from(&note);
+        let deserialized = NoteFile::read_from_bytes(&file.to_bytes()).unwrap();
+        assert_eq!(deserialized, file);
+        assert_eq!(deserialized.id(), note.id());
+        assert!(deserialized.into_input_note().is_none());
+
+        let path = MerklePath::new(vec![Digest::default(); NOTE_TREE_DEPTH as usize]);
+        let proof = NoteInclusionProof::new(3, Digest::default(), Digest::default(), 1, path);
+        let input_note = InputNote::new(note.clone(), proof.unwrap());
+        let file = NoteFile::from(input_note.clone());
+        let deserialized = NoteFile::read_from_bytes(&file.to_bytes()).unwrap();
+        assert_eq!(deserialized.into_input_note(), Some(input_note));
+
+        // files with an unknown header or version are rejected
+        let mut bytes = file.to_bytes();
+        bytes[4] = NoteFile::VERSION + 1;
+        assert!(NoteFile::read_from_bytes(&bytes).is_err());
+        bytes[0] = 0;
+        assert!(NoteFile::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn note_file_is_written_and_read() {
+        let dir = tempdir().unwrap();
+        let filepath = dir.path().join("note.mno");
+
+        let file = NoteFile::from(&build_note());
+        file.write(filepath.as_path()).unwrap();
+        assert_eq!(NoteFile::read(filepath.as_path()).unwrap(), file);
+    }
+}
@@ -125,7 +125,7 @@ impl Deserializable for NoteInputs {
 mod tests {
     use miden_crypto::utils::Deserializable;
 
-    use super::{Felt, NoteInputs, Serializable};
+    use super::{Felt, Hasher, NoteInputs, Serializable, ZERO};
 
     #[test]
     fn test_input_ordering() {
@@ -147,4 +147,18 @@ mod tests {
         let parsed_note_inputs = NoteInputs::read_from_bytes(&bytes).unwrap();
         assert_eq!(note_inputs, parsed_note_inputs);
     }
+
+    #[test]
+    fn test_input_commitment() {
+        // inputs spanning multiple hash permutations are padded to the next multiple of 8
+        let inputs = (1..=20).map(Felt::new).collect::<Vec<_>>();
+        let note_inputs = NoteInputs::new(inputs.clone()).unwrap();
+
+        let mut padded_inputs = inputs;
+        padded_inputs.resize(24, ZERO);
+        assert_eq!(note_inputs.to_padded_values(), padded_inputs);
+        assert_eq!(note_inputs.commitment(), Hasher::hash_elements(&padded_inputs));
+
+        assert!(NoteInputs::new(vec![ZERO; NoteInputs::MAX_INPUTS_PER_NOTE + 1]).is_err());
+    }
 }
@@ -0,0 +1,580 @@
+use core::fmt;
+
+use super::{AccountId, Digest, Felt, Hasher, NoteError, NoteInputs, String, Vec, Word, WORD_SIZE};
+use crate::{utils::format, StarkField};
+
+// NOTE INPUT KIND
+// ================================================================================================
+
+/// The kind of a field of typed note inputs, which determines how the field is encoded into note
+/// input values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteInputKind {
+    /// A u64 value smaller than the field modulus, encoded as a single value.
+    U64,
+    /// An account ID, encoded as a single value.
+    AccountId,
+    /// A word, encoded as 4 values in the order of its elements.
+    Word,
+    /// A fixed-length array of u64 values, encoded as one value per element.
+    U64Array(usize),
+    /// A fixed-length array of words, encoded as 4 values per word.
+    WordArray(usize),
+}
+
+impl NoteInputKind {
+    /// Returns the number of note input values a field of this kind is encoded into.
+    pub const fn num_values(&self) -> usize {
+        match self {
+            Self::U64 | Self::AccountId => 1,
+            Self::Word => WORD_SIZE,
+            Self::U64Array(len) => *len,
+            Self::WordArray(len) => *len * WORD_SIZE,
+        }
+    }
+
+    /// Returns the elements by which this kind is committed to in the layout commitment.
+    fn to_elements(self) -> [Felt; 2] {
+        let (tag, len) = match self {
+            Self::U64 => (0, 1),
+            Self::AccountId => (1, 1),
+            Self::Word => (2, 1),
+            Self::U64Array(len) => (3, len),
+            Self::WordArray(len) => (4, len),
+        };
+        [Felt::from(tag as u8), Felt::new(len as u64)]
+    }
+}
+
+impl fmt::Display for NoteInputKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::U64 => write!(f, "u64"),
+            Self::AccountId => write!(f, "account ID"),
+            Self::Word => write!(f, "word"),
+            Self::U64Array(len) => write!(f, "array of {len} u64 values"),
+            Self::WordArray(len) => write!(f, "array of {len} words"),
+        }
+    }
+}
+
+// NOTE INPUTS LAYOUT
+// ================================================================================================
+
+/// A named field of typed note inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteInputField {
+    name: &'static str,
+    kind: NoteInputKind,
+}
+
+impl NoteInputField {
+    /// Returns a new [NoteInputField] with the specified name and kind.
+    pub const fn new(name: &'static str, kind: NoteInputKind) -> Self {
+        Self { name, kind }
+    }
+
+    /// Returns the name of this field.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the kind of this field.
+    pub fn kind(&self) -> NoteInputKind {
+        self.kind
+    }
+}
+
+/// The layout of typed note inputs, i.e., the ordered list of fields which are encoded into the
+/// note input values.
+///
+/// Fields are encoded one after the other, starting with the first field at input index 0, and
+/// thus the offset of a field is the total number of values of the fields preceding it. The same
+/// layout is meant to be shared by the Rust code creating a note and by the script of the note:
+/// [NoteInputsLayout::to_masm_constants()] generates the MASM constants with the offsets of the
+/// fields, and [NoteInputsLayout::commitment()] commits to the encoding of the fields, so that
+/// scripts written against a layout can be checked to still match it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteInputsLayout {
+    fields: &'static [NoteInputField],
+}
+
+impl NoteInputsLayout {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteInputsLayout] consisting of the provided fields.
+    pub const fn new(fields: &'static [NoteInputField]) -> Self {
+        Self { fields }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the fields of this layout.
+    pub fn fields(&self) -> &'static [NoteInputField] {
+        self.fields
+    }
+
+    /// Returns the total number of note input values the fields of this layout are encoded into.
+    pub fn num_values(&self) -> usize {
+        self.fields.iter().map(|field| field.kind.num_values()).sum()
+    }
+
+    /// Returns the index of the first note input value of the field with the specified name, or
+    /// None if this layout does not contain such a field.
+    pub fn offset_of(&self, name: &str) -> Option<usize> {
+        self.offsets().find(|(field, _)| field.name == name).map(|(_, offset)| offset)
+    }
+
+    /// Returns an iterator over the fields of this layout together with their offsets.
+    pub fn offsets(&self) -> impl Iterator<Item = (&'static NoteInputField, usize)> {
+        self.fields.iter().scan(0, |offset, field| {
+            let field_offset = *offset;
+            *offset += field.kind.num_values();
+            Some((field, field_offset))
+        })
+    }
+
+    /// Returns a commitment to the encoding of this layout.
+    ///
+    /// The commitment is computed from the kinds of the fields (including the lengths of arrays)
+    /// in their order, but not from their names, and thus it changes if and only if the encoding
+    /// of the note inputs changes.
+    pub fn commitment(&self) -> Digest {
+        commit_to_kinds(self.fields.iter().map(|field| field.kind))
+    }
+
+    /// Returns the MASM constant declarations describing this layout.
+    ///
+    /// For every field, a constant named after the field in uppercase with an `_OFFSET` suffix is
+    /// set to the offset of the field; the `NUM_INPUTS` constant is set to the total number of
+    /// values.
+    pub fn to_masm_constants(&self) -> String {
+        let mut constants = String::new();
+        for (field, offset) in self.offsets() {
+            constants.push_str(&format!("const.{}_OFFSET={offset}\n", field.name.to_uppercase()));
+        }
+        constants.push_str(&format!("const.NUM_INPUTS={}\n", self.num_values()));
+        constants
+    }
+}
+
+// NOTE INPUTS BUILDER
+// ================================================================================================
+
+/// A builder of note inputs encoded according to a [NoteInputsLayout].
+///
+/// Fields must be added in the order of the layout. Adding a field of an unexpected kind, or a
+/// value which cannot be encoded, does not fail immediately; instead, the first such error is
+/// returned by [NoteInputsBuilder::build()].
+#[derive(Debug, Clone)]
+pub struct NoteInputsBuilder {
+    layout: NoteInputsLayout,
+    values: Vec<Felt>,
+    num_fields: usize,
+    error: Option<NoteError>,
+}
+
+impl NoteInputsBuilder {
+    /// Returns a new [NoteInputsBuilder] for note inputs with the specified layout.
+    pub fn new(layout: NoteInputsLayout) -> Self {
+        Self {
+            layout,
+            values: Vec::with_capacity(layout.num_values()),
+            num_fields: 0,
+            error: None,
+        }
+    }
+
+    /// Adds a u64 field to the note inputs.
+    pub fn add_u64(mut self, value: u64) -> Self {
+        if let Some(field) = self.next_field(NoteInputKind::U64) {
+            self.push_u64(field, value);
+        }
+        self
+    }
+
+    /// Adds an account ID field to the note inputs.
+    pub fn add_account_id(mut self, account_id: AccountId) -> Self {
+        if self.next_field(NoteInputKind::AccountId).is_some() {
+            self.values.push(account_id.into());
+        }
+        self
+    }
+
+    /// Adds a word field to the note inputs.
+    pub fn add_word(mut self, word: Word) -> Self {
+        if self.next_field(NoteInputKind::Word).is_some() {
+            self.values.extend(word);
+        }
+        self
+    }
+
+    /// Adds an array of u64 values to the note inputs.
+    pub fn add_u64_array(mut self, values: &[u64]) -> Self {
+        if let Some(field) = self.next_field(NoteInputKind::U64Array(values.len())) {
+            values.iter().for_each(|value| self.push_u64(field, *value));
+        }
+        self
+    }
+
+    /// Adds an array of words to the note inputs.
+    pub fn add_word_array(mut self, words: &[Word]) -> Self {
+        if self.next_field(NoteInputKind::WordArray(words.len())).is_some() {
+            self.values.extend(words.iter().flatten());
+        }
+        self
+    }
+
+    /// Returns the note inputs built from the added fields.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The kinds of the added fields do not match the layout, or not all fields of the layout
+    ///   were added.
+    /// - Any of the added u64 values is not smaller than the field modulus.
+    pub fn build(self) -> Result<NoteInputs, NoteError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        if self.num_fields != self.layout.fields.len() {
+            return Err(NoteError::NoteInputsLayoutMismatch(format!(
+                "layout has {} fields, but only {} fields were added",
+                self.layout.fields.len(),
+                self.num_fields
+            )));
+        }
+
+        NoteInputs::new(self.values)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the next field of the layout if it is of the specified kind; otherwise, records a
+    /// layout mismatch and returns None.
+    fn next_field(&mut self, kind: NoteInputKind) -> Option<&'static NoteInputField> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        match check_field(self.layout, self.num_fields, kind) {
+            Ok(field) => {
+                self.num_fields += 1;
+                Some(field)
+            },
+            Err(err) => {
+                self.error = Some(err);
+                None
+            },
+        }
+    }
+
+    fn push_u64(&mut self, field: &NoteInputField, value: u64) {
+        if value >= Felt::MODULUS {
+            self.error.get_or_insert(NoteError::InvalidNoteInputValue(field.name));
+        }
+        self.values.push(Felt::new(value));
+    }
+}
+
+// NOTE INPUTS READER
+// ================================================================================================
+
+/// A reader of note inputs encoded according to a [NoteInputsLayout].
+///
+/// Fields must be read in the order of the layout, and [NoteInputsReader::finish()] must be called
+/// to check that all fields were read.
+#[derive(Debug, Clone)]
+pub struct NoteInputsReader<'a> {
+    layout: NoteInputsLayout,
+    values: &'a [Felt],
+    num_fields: usize,
+}
+
+impl<'a> NoteInputsReader<'a> {
+    /// Returns a new [NoteInputsReader] of the provided note inputs with the specified layout.
+    ///
+    /// # Errors
+    /// Returns an error if the number of input values differs from the number of values of the
+    /// layout.
+    pub fn new(layout: NoteInputsLayout, inputs: &'a NoteInputs) -> Result<Self, NoteError> {
+        let values = inputs.values();
+        if values.len() != layout.num_values() {
+            return Err(NoteError::NoteInputsLayoutMismatch(format!(
+                "layout has {} values, but the note has {} inputs",
+                layout.num_values(),
+                values.len()
+            )));
+        }
+
+        Ok(Self { layout, values, num_fields: 0 })
+    }
+
+    /// Reads a u64 field from the note inputs.
+    pub fn read_u64(&mut self) -> Result<u64, NoteError> {
+        Ok(self.next_values(NoteInputKind::U64)?[0].as_int())
+    }
+
+    /// Reads an account ID field from the note inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the next field is not an account ID, or if the value is not a valid
+    /// account ID.
+    pub fn read_account_id(&mut self) -> Result<AccountId, NoteError> {
+        let value = self.next_values(NoteInputKind::AccountId)?[0];
+        let field = &self.layout.fields[self.num_fields - 1];
+        AccountId::try_from(value).map_err(|_| NoteError::InvalidNoteInputValue(field.name))
+    }
+
+    /// Reads a word field from the note inputs.
+    pub fn read_word(&mut self) -> Result<Word, NoteError> {
+        let values = self.next_values(NoteInputKind::Word)?;
+        Ok([values[0], values[1], values[2], values[3]])
+    }
+
+    /// Reads an array of `N` u64 values from the note inputs.
+    pub fn read_u64_array<const N: usize>(&mut self) -> Result<[u64; N], NoteError> {
+        let values = self.next_values(NoteInputKind::U64Array(N))?;
+        Ok(core::array::from_fn(|i| values[i].as_int()))
+    }
+
+    /// Reads an array of `N` words from the note inputs.
+    pub fn read_word_array<const N: usize>(&mut self) -> Result<[Word; N], NoteError> {
+        let values = self.next_values(NoteInputKind::WordArray(N))?;
+        Ok(core::array::from_fn(|i| {
+            let word = &values[i * WORD_SIZE..(i + 1) * WORD_SIZE];
+            [word[0], word[1], word[2], word[3]]
+        }))
+    }
+
+    /// Checks that all fields of the layout were read.
+    ///
+    /// # Errors
+    /// Returns an error if not all fields of the layout were read.
+    pub fn finish(self) -> Result<(), NoteError> {
+        if self.num_fields != self.layout.fields.len() {
+            return Err(NoteError::NoteInputsLayoutMismatch(format!(
+                "layout has {} fields, but only {} fields were read",
+                self.layout.fields.len(),
+                self.num_fields
+            )));
+        }
+
+        Ok(())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the values of the next field of the layout if it is of the specified kind.
+    fn next_values(&mut self, kind: NoteInputKind) -> Result<&'a [Felt], NoteError> {
+        check_field(self.layout, self.num_fields, kind)?;
+        let offset: usize = self.layout.fields[..self.num_fields]
+            .iter()
+            .map(|field| field.kind.num_values())
+            .sum();
+        self.num_fields += 1;
+
+        Ok(&self.values[offset..offset + kind.num_values()])
+    }
+}
+
+// TYPED NOTE INPUTS
+// ================================================================================================
+
+/// A type which can be encoded into note inputs and decoded from them according to a fixed
+/// [NoteInputsLayout].
+///
+/// Implementations add and read their fields in the order of [TypedNoteInputs::LAYOUT]; the
+/// provided methods check that the encoding matches the layout.
+pub trait TypedNoteInputs: Sized {
+    /// The layout of the note inputs this type is encoded into.
+    const LAYOUT: NoteInputsLayout;
+
+    /// Adds the fields of this value to the provided builder.
+    fn write_inputs(&self, builder: NoteInputsBuilder) -> NoteInputsBuilder;
+
+    /// Reads a value of this type from the provided reader.
+    fn read_inputs(reader: &mut NoteInputsReader) -> Result<Self, NoteError>;
+
+    /// Returns the note inputs encoding this value.
+    ///
+    /// # Errors
+    /// Returns an error if this value cannot be encoded according to [TypedNoteInputs::LAYOUT].
+    fn to_note_inputs(&self) -> Result<NoteInputs, NoteError> {
+        self.write_inputs(NoteInputsBuilder::new(Self::LAYOUT)).build()
+    }
+
+    /// Returns the value encoded by the provided note inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the inputs do not encode a value according to
+    /// [TypedNoteInputs::LAYOUT].
+    fn from_note_inputs(inputs: &NoteInputs) -> Result<Self, NoteError> {
+        let mut reader = NoteInputsReader::new(Self::LAYOUT, inputs)?;
+        let value = Self::read_inputs(&mut reader)?;
+        reader.finish()?;
+        Ok(value)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the field of the layout at the specified index if it is of the specified kind.
+fn check_field(
+    layout: NoteInputsLayout,
+    index: usize,
+    kind: NoteInputKind,
+) -> Result<&'static NoteInputField, NoteError> {
+    let field = layout.fields.get(index).ok_or_else(|| {
+        NoteError::NoteInputsLayoutMismatch(format!(
+            "layout has {} fields, but a {kind} was provided as field {index}",
+            layout.fields.len()
+        ))
+    })?;
+
+    if field.kind != kind {
+        return Err(NoteError::NoteInputsLayoutMismatch(format!(
+            "field {} is a {}, but a {kind} was provided",
+            field.name, field.kind
+        )));
+    }
+
+    Ok(field)
+}
+
+/// Returns the commitment to a layout whose fields are of the provided kinds.
+fn commit_to_kinds(kinds: impl Iterator<Item = NoteInputKind>) -> Digest {
+    let elements: Vec<Felt> = kinds.flat_map(NoteInputKind::to_elements).collect();
+    Hasher::hash_elements(&elements)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccountId, Felt, NoteError, NoteInputField, NoteInputKind, NoteInputs, NoteInputsBuilder,
+        NoteInputsLayout, NoteInputsReader, TypedNoteInputs, Word,
+    };
+    use crate::{accounts::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, StarkField};
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Offer {
+        target: AccountId,
+        amount: u64,
+        asset: Word,
+        deadlines: [u64; 2],
+    }
+
+    impl TypedNoteInputs for Offer {
+        const LAYOUT: NoteInputsLayout = NoteInputsLayout::new(&[
+            NoteInputField::new("target", NoteInputKind::AccountId),
+            NoteInputField::new("amount", NoteInputKind::U64),
+            NoteInputField::new("asset", NoteInputKind::Word),
+            NoteInputField::new("deadlines", NoteInputKind::U64Array(2)),
+        ]);
+
+        fn write_inputs(&self, builder: NoteInputsBuilder) -> NoteInputsBuilder {
+            builder
+                .add_account_id(self.target)
+                .add_u64(self.amount)
+                .add_word(self.asset)
+                .add_u64_array(&self.deadlines)
+        }
+
+        fn read_inputs(reader: &mut NoteInputsReader) -> Result<Self, NoteError> {
+            Ok(Self {
+                target: reader.read_account_id()?,
+                amount: reader.read_u64()?,
+                asset: reader.read_word()?,
+                deadlines: reader.read_u64_array()?,
+            })
+        }
+    }
+
+    fn offer() -> Offer {
+        Offer {
+            target: AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
+                .unwrap(),
+            amount: 100,
+            asset: [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+            deadlines: [10, 20],
+        }
+    }
+
+    #[test]
+    fn test_typed_inputs_encoding() {
+        let offer = offer();
+        let inputs = offer.to_note_inputs().unwrap();
+
+        // fields are encoded one after the other in the order of the layout
+        let mut expected = vec![offer.target.into(), Felt::new(100)];
+        expected.extend(offer.asset);
+        expected.extend([Felt::new(10), Felt::new(20)]);
+        assert_eq!(inputs.values(), &expected);
+        assert_eq!(Offer::from_note_inputs(&inputs).unwrap(), offer);
+
+        assert_eq!(Offer::LAYOUT.num_values(), 8);
+        assert_eq!(Offer::LAYOUT.offset_of("asset"), Some(2));
+        assert_eq!(Offer::LAYOUT.offset_of("deadlines"), Some(6));
+        assert_eq!(Offer::LAYOUT.offset_of("unknown"), None);
+        assert_eq!(
+            Offer::LAYOUT.to_masm_constants(),
+            "const.TARGET_OFFSET=0\nconst.AMOUNT_OFFSET=1\nconst.ASSET_OFFSET=2\n\
+             const.DEADLINES_OFFSET=6\nconst.NUM_INPUTS=8\n"
+        );
+    }
+
+    #[test]
+    fn test_typed_inputs_layout_checks() {
+        let offer = offer();
+
+        // fields of unexpected kinds, missing fields and invalid values are rejected
+        let builder = NoteInputsBuilder::new(Offer::LAYOUT).add_account_id(offer.target);
+        assert!(builder.clone().add_word(offer.asset).build().is_err());
+        assert!(builder.clone().add_u64(100).build().is_err());
+        assert!(matches!(
+            builder
+                .add_u64(Felt::MODULUS)
+                .add_word(offer.asset)
+                .add_u64_array(&[1, 2])
+                .build(),
+            Err(NoteError::InvalidNoteInputValue("amount"))
+        ));
+
+        let inputs = NoteInputs::new(vec![Felt::new(1); 7]).unwrap();
+        assert!(Offer::from_note_inputs(&inputs).is_err());
+        let inputs = NoteInputs::new(vec![Felt::new(1); 8]).unwrap();
+        assert!(matches!(
+            Offer::from_note_inputs(&inputs),
+            Err(NoteError::InvalidNoteInputValue("target"))
+        ));
+
+        let inputs = offer.to_note_inputs().unwrap();
+        let mut reader = NoteInputsReader::new(Offer::LAYOUT, &inputs).unwrap();
+        assert!(reader.clone().read_u64().is_err());
+        reader.read_account_id().unwrap();
+        assert!(reader.finish().is_err());
+
+        // the commitment depends on the kinds of the fields, but not on their names
+        const RENAMED: NoteInputsLayout = NoteInputsLayout::new(&[
+            NoteInputField::new("recipient", NoteInputKind::AccountId),
+            NoteInputField::new("value", NoteInputKind::U64),
+            NoteInputField::new("asset", NoteInputKind::Word),
+            NoteInputField::new("deadlines", NoteInputKind::U64Array(2)),
+        ]);
+        const RESIZED: NoteInputsLayout = NoteInputsLayout::new(&[
+            NoteInputField::new("target", NoteInputKind::AccountId),
+            NoteInputField::new("amount", NoteInputKind::U64),
+            NoteInputField::new("asset", NoteInputKind::Word),
+            NoteInputField::new("deadlines", NoteInputKind::U64Array(3)),
+        ]);
+        assert_eq!(Offer::LAYOUT.commitment(), RENAMED.commitment());
+        assert_ne!(Offer::LAYOUT.commitment(), RESIZED.commitment());
+    }
+}
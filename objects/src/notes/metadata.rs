@@ -1,7 +1,8 @@
 use vm_processor::DeserializationError;
 
 use super::{
-    AccountId, ByteReader, ByteWriter, Deserializable, Felt, NoteError, Serializable, Word,
+    AccountId, ByteReader, ByteWriter, Deserializable, Felt, NoteError, NoteTag, NoteType,
+    Serializable, Word, ZERO,
 };
 
 // NOTE METADATA
@@ -11,18 +12,52 @@ use super::{
 ///
 /// The metadata consists of:
 /// - sender is the account which created the note.
-/// - tag is a value which can be used by the recipient(s) to identify notes intended for them.
+/// - tag is a value which can be used by the recipient(s) to identify notes intended for them (see
+///   [NoteTag]).
+/// - note_type specifies how the details of the note are stored by the operator (see [NoteType]).
+/// - aux is an arbitrary value which the sender can attach to the note.
+///
+/// The tag of the metadata is always valid (see [NoteTag::validate()]).
+///
+/// The metadata is committed to by the transaction kernel as [tag, sender, note_type, aux], and
+/// thus operators can enforce storage policies based on the type of a note.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteMetadata {
     sender: AccountId,
-    tag: Felt,
+    tag: NoteTag,
+    note_type: NoteType,
+    aux: Felt,
 }
 
 impl NoteMetadata {
     /// Returns a new [NoteMetadata] instantiated with the specified parameters.
-    pub fn new(sender: AccountId, tag: Felt) -> Self {
-        Self { sender, tag }
+    ///
+    /// The metadata describes a public note with the aux value set to zero; these can be changed
+    /// via [NoteMetadata::with_note_type()] and [NoteMetadata::with_aux()].
+    ///
+    /// # Errors
+    /// Returns an error if the tag is not valid (see [NoteTag::validate()]).
+    pub fn new(sender: AccountId, tag: NoteTag) -> Result<Self, NoteError> {
+        tag.validate()?;
+        Ok(Self {
+            sender,
+            tag,
+            note_type: NoteType::Public,
+            aux: ZERO,
+        })
+    }
+
+    /// Returns this metadata with the specified note type.
+    pub fn with_note_type(mut self, note_type: NoteType) -> Self {
+        self.note_type = note_type;
+        self
+    }
+
+    /// Returns this metadata with the specified aux value.
+    pub fn with_aux(mut self, aux: Felt) -> Self {
+        self.aux = aux;
+        self
     }
 
     /// Returns the account which created the note.
@@ -31,9 +66,19 @@ impl NoteMetadata {
     }
 
     /// Returns the tag associated with the note.
-    pub fn tag(&self) -> Felt {
+    pub fn tag(&self) -> NoteTag {
         self.tag
     }
+
+    /// Returns the type of the note.
+    pub fn note_type(&self) -> NoteType {
+        self.note_type
+    }
+
+    /// Returns the aux value attached to the note.
+    pub fn aux(&self) -> Felt {
+        self.aux
+    }
 }
 
 impl From<NoteMetadata> for Word {
@@ -44,10 +89,12 @@ impl From<NoteMetadata> for Word {
 
 impl From<&NoteMetadata> for Word {
     fn from(metadata: &NoteMetadata) -> Self {
-        let mut elements = Word::default();
-        elements[0] = metadata.tag;
-        elements[1] = metadata.sender.into();
-        elements
+        [
+            metadata.tag.into(),
+            metadata.sender.into(),
+            metadata.note_type.into(),
+            metadata.aux,
+        ]
     }
 }
 
@@ -55,10 +102,10 @@ impl TryFrom<Word> for NoteMetadata {
     type Error = NoteError;
 
     fn try_from(elements: Word) -> Result<Self, Self::Error> {
-        Ok(Self {
-            sender: elements[1].try_into().map_err(NoteError::NoteMetadataSenderInvalid)?,
-            tag: elements[0],
-        })
+        let sender = elements[1].try_into().map_err(NoteError::NoteMetadataSenderInvalid)?;
+        let tag = elements[0].try_into()?;
+        let note_type = elements[2].try_into()?;
+        Ok(Self::new(sender, tag)?.with_note_type(note_type).with_aux(elements[3]))
     }
 }
 
@@ -69,14 +116,18 @@ impl Serializable for NoteMetadata {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.sender.write_into(target);
         self.tag.write_into(target);
+        self.note_type.write_into(target);
+        self.aux.write_into(target);
     }
 }
 
 impl Deserializable for NoteMetadata {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let sender = AccountId::read_from(source)?;
-        let tag = Felt::read_from(source)?;
+        let tag = NoteTag::read_from(source)?;
+        let note_type = NoteType::read_from(source)?;
+        let aux = Felt::read_from(source)?;
 
-        Ok(Self { sender, tag })
+        Ok(Self { sender, tag, note_type, aux })
     }
 }
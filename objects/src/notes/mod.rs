@@ -13,27 +13,54 @@ use super::{
     Digest, Felt, Hasher, NoteError, Word, NOTE_TREE_DEPTH, WORD_SIZE, ZERO,
 };
 
+mod details;
+pub use details::NoteDetails;
+
+mod encrypted;
+pub use encrypted::EncryptedNoteDetails;
+
 mod envelope;
 pub use envelope::NoteEnvelope;
 
+mod file;
+pub use file::NoteFile;
+
 mod inputs;
 pub use inputs::NoteInputs;
 
+mod inputs_layout;
+pub use inputs_layout::{
+    NoteInputField, NoteInputKind, NoteInputsBuilder, NoteInputsLayout, NoteInputsReader,
+    TypedNoteInputs,
+};
+
 mod metadata;
 pub use metadata::NoteMetadata;
 
 mod note_id;
 pub use note_id::NoteId;
 
+mod note_tag;
+pub use note_tag::{NoteExecutionHint, NoteTag};
+
+mod note_type;
+pub use note_type::NoteType;
+
 mod nullifier;
 pub use nullifier::Nullifier;
 
 mod origin;
 pub use origin::{NoteInclusionProof, NoteOrigin};
 
+mod partial;
+pub use partial::PartialNote;
+
 mod script;
 pub use script::NoteScript;
 
+mod serial_num;
+pub use serial_num::SerialNumSecret;
+
 mod assets;
 pub use assets::NoteAssets;
 
@@ -53,7 +80,7 @@ pub const NOTE_LEAF_DEPTH: u8 = NOTE_TREE_DEPTH + 1;
 /// This struct is a full description of a note which is needed to execute a note in a transaction.
 /// A note consists of:
 ///
-/// Core on-chain data which is used to execute a note:
+/// Core on-chain data which is used to execute a note (see [NoteDetails]):
 /// - A script which must be executed in a context of some account to claim the assets.
 /// - A set of inputs which can be read to memory during script execution via the invocation of the
 ///   `note::get_inputs` in the kernel API.
@@ -65,10 +92,7 @@ pub const NOTE_LEAF_DEPTH: u8 = NOTE_TREE_DEPTH + 1;
 ///   assets in the note.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Note {
-    script: NoteScript,
-    inputs: NoteInputs,
-    assets: NoteAssets,
-    serial_num: Word,
+    details: NoteDetails,
     metadata: NoteMetadata,
 
     id: OnceCell<NoteId>,
@@ -76,32 +100,31 @@ pub struct Note {
 }
 
 impl Note {
-    // CONSTRUCTOR
+    // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
     /// Returns a new note created with the specified parameters.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The number of inputs exceeds 16.
+    /// - The number of inputs exceeds 128.
     /// - The number of provided assets exceeds 1000.
     /// - The list of assets contains duplicates.
+    /// - The tag is not valid (see [NoteTag::validate()]).
     pub fn new(
         script: NoteScript,
         inputs: &[Felt],
         assets: &[Asset],
         serial_num: Word,
         sender: AccountId,
-        tag: Felt,
+        tag: NoteTag,
     ) -> Result<Self, NoteError> {
-        Ok(Self {
+        let details = NoteDetails::new(
             script,
-            inputs: NoteInputs::new(inputs.to_vec())?,
-            assets: NoteAssets::new(assets)?,
+            NoteInputs::new(inputs.to_vec())?,
+            NoteAssets::new(assets)?,
             serial_num,
-            metadata: NoteMetadata::new(sender, tag),
-            id: OnceCell::new(),
-            nullifier: OnceCell::new(),
-        })
+        );
+        Ok(Self::from_details(details, NoteMetadata::new(sender, tag)?))
     }
 
     /// Returns a note instance created from the provided parts.
@@ -112,11 +135,13 @@ impl Note {
         serial_num: Word,
         metadata: NoteMetadata,
     ) -> Self {
+        Self::from_details(NoteDetails::new(script, inputs, assets, serial_num), metadata)
+    }
+
+    /// Returns a note instance created from the provided details and metadata.
+    pub fn from_details(details: NoteDetails, metadata: NoteMetadata) -> Self {
         Self {
-            script,
-            inputs,
-            assets,
-            serial_num,
+            details,
             metadata,
             id: OnceCell::new(),
             nullifier: OnceCell::new(),
@@ -126,24 +151,29 @@ impl Note {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns a reference to the details of this note.
+    pub fn details(&self) -> &NoteDetails {
+        &self.details
+    }
+
     /// Returns a reference script which locks the assets of this note.
     pub fn script(&self) -> &NoteScript {
-        &self.script
+        self.details.script()
     }
 
     /// Returns a reference to the note inputs.
     pub fn inputs(&self) -> &NoteInputs {
-        &self.inputs
+        self.details.inputs()
     }
 
     /// Returns a reference to the asset of this note.
     pub fn assets(&self) -> &NoteAssets {
-        &self.assets
+        self.details.assets()
     }
 
     /// Returns a serial number of this note.
     pub fn serial_num(&self) -> Word {
-        self.serial_num
+        self.details.serial_num()
     }
 
     /// Returns the metadata associated with this note.
@@ -155,9 +185,7 @@ impl Note {
     /// Recipient is defined and calculated as:
     ///  hash(hash(hash(serial_num, [0; 4]), script_hash), input_hash)
     pub fn recipient(&self) -> Digest {
-        let serial_num_hash = Hasher::merge(&[self.serial_num.into(), Digest::default()]);
-        let merge_script = Hasher::merge(&[serial_num_hash, self.script.hash()]);
-        Hasher::merge(&[merge_script, self.inputs.commitment()])
+        self.details.recipient()
     }
 
     /// Returns a unique identifier of this note, which is simultaneously a commitment to the note.
@@ -172,9 +200,22 @@ impl Note {
     }
 
     /// Returns the nullifier for this note.
+    ///
+    /// The nullifier is published when the note is consumed, and the set of nullifiers of all
+    /// consumed notes is the nullifier database of the chain (see
+    /// [NullifierTree](crate::block::NullifierTree)). The nullifier is computed on first access and
+    /// cached afterwards.
     pub fn nullifier(&self) -> Nullifier {
         *self.nullifier.get_or_init(|| self.into())
     }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes this note and returns its details and metadata.
+    pub fn into_parts(self) -> (NoteDetails, NoteMetadata) {
+        (self.details, self.metadata)
+    }
 }
 
 // SERIALIZATION
@@ -182,42 +223,19 @@ impl Note {
 
 impl Serializable for Note {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        let Note {
-            script,
-            inputs,
-            assets,
-            serial_num,
-            metadata,
-
-            id: _,
-            nullifier: _,
-        } = self;
+        let Note { details, metadata, id: _, nullifier: _ } = self;
 
-        script.write_into(target);
-        inputs.write_into(target);
-        assets.write_into(target);
-        serial_num.write_into(target);
+        details.write_into(target);
         metadata.write_into(target);
     }
 }
 
 impl Deserializable for Note {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let script = NoteScript::read_from(source)?;
-        let inputs = NoteInputs::read_from(source)?;
-        let assets = NoteAssets::read_from(source)?;
-        let serial_num = Word::read_from(source)?;
+        let details = NoteDetails::read_from(source)?;
         let metadata = NoteMetadata::read_from(source)?;
 
-        Ok(Self {
-            script,
-            inputs,
-            assets,
-            serial_num,
-            metadata,
-            id: OnceCell::new(),
-            nullifier: OnceCell::new(),
-        })
+        Ok(Self::from_details(details, metadata))
     }
 }
 
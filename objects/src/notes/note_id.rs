@@ -1,8 +1,13 @@
-use super::{Digest, Felt, Hasher, Note, Word};
-use crate::utils::{
-    serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    string::String,
-    HexParseError,
+use core::{fmt, str::FromStr};
+
+use super::{Digest, Felt, Hasher, Note, NoteError, Word};
+use crate::{
+    bech32_encoding,
+    utils::{
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        string::{String, ToString},
+        HexParseError,
+    },
 };
 
 // NOTE ID
@@ -22,9 +27,14 @@ use crate::utils::{
 /// - To compute a note ID, we do not need to know the note's serial_num. Knowing the hash
 ///   of the serial_num (as well as script hash, input hash, and note assets) is sufficient.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct NoteId(Digest);
 
 impl NoteId {
+    /// The human-readable part of the bech32m encoding of note IDs.
+    pub const BECH32_HRP: &'static str = "mnote";
+
     /// Returns a new [NoteId] instantiated from the provided note components.
     pub fn new(recipient: Digest, asset_commitment: Digest) -> Self {
         Self(Hasher::merge(&[recipient, asset_commitment]))
@@ -49,6 +59,54 @@ impl NoteId {
     pub fn inner(&self) -> Digest {
         self.0
     }
+
+    /// Returns the bech32m encoding of this note ID with the specified human-readable part.
+    ///
+    /// The encoded data is the byte representation of the note ID. The
+    /// [Display](fmt::Display) implementation of [NoteId] uses [NoteId::BECH32_HRP] as the
+    /// human-readable part.
+    ///
+    /// # Errors
+    /// Returns an error if the human-readable part is not valid.
+    pub fn to_bech32(&self, hrp: &str) -> Result<String, NoteError> {
+        bech32_encoding::encode(hrp, &self.as_bytes()).map_err(NoteError::InvalidNoteIdEncoding)
+    }
+
+    /// Creates a note ID from its bech32m encoding with the specified human-readable part.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not a valid bech32m string with the specified human-readable part.
+    /// - The encoded data is not a valid note ID.
+    pub fn from_bech32(value: &str, hrp: &str) -> Result<NoteId, NoteError> {
+        let bytes =
+            bech32_encoding::decode(hrp, value).map_err(NoteError::InvalidNoteIdEncoding)?;
+        Digest::read_from_bytes(&bytes)
+            .map(NoteId)
+            .map_err(|err| NoteError::InvalidNoteIdEncoding(err.to_string()))
+    }
+}
+
+impl fmt::Display for NoteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = self.to_bech32(Self::BECH32_HRP).map_err(|_| fmt::Error)?;
+        write!(f, "{encoded}")
+    }
+}
+
+impl FromStr for NoteId {
+    type Err = NoteError;
+
+    /// Parses a note ID from either its bech32m encoding with [NoteId::BECH32_HRP] as the
+    /// human-readable part, or its hex encoding (if the string starts with "0x").
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.starts_with("0x") {
+            NoteId::try_from_hex(value)
+                .map_err(|err| NoteError::InvalidNoteIdEncoding(err.to_string()))
+        } else {
+            NoteId::from_bech32(value, Self::BECH32_HRP)
+        }
+    }
 }
 
 // CONVERSIONS INTO NOTE ID
@@ -124,7 +182,7 @@ impl Deserializable for NoteId {
 
 #[cfg(test)]
 mod tests {
-    use super::NoteId;
+    use super::{NoteId, String};
 
     #[test]
     fn note_id_try_from_hex() {
@@ -133,4 +191,22 @@ mod tests {
 
         assert_eq!(note_id.inner().to_string(), note_id_hex)
     }
+
+    #[test]
+    fn note_id_bech32_round_trip() {
+        let note_id_hex = "0xc9d31c82c098e060c9b6e3af2710b3fc5009a1a6f82ef9465f8f35d1f5ba4a80";
+        let note_id = NoteId::try_from_hex(note_id_hex).unwrap();
+
+        let encoded = note_id.to_string();
+        assert!(encoded.starts_with("mnote1"));
+        assert_eq!(encoded.parse::<NoteId>().unwrap(), note_id);
+        assert_eq!(note_id_hex.parse::<NoteId>().unwrap(), note_id);
+
+        // the checksum detects typos
+        let mut corrupted = encoded.into_bytes();
+        let last = corrupted.len() - 1;
+        corrupted[last] = if corrupted[last] == b'q' { b'p' } else { b'q' };
+        let corrupted = String::from_utf8(corrupted).unwrap();
+        assert!(corrupted.parse::<NoteId>().is_err());
+    }
 }
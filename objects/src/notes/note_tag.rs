@@ -0,0 +1,274 @@
+use core::fmt;
+
+use super::{
+    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, NoteError,
+    Serializable, ToString,
+};
+use crate::StarkField;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The bit of a tag which specifies the execution hint of the note.
+const NETWORK_EXECUTION_BIT: u32 = 1 << 31;
+
+/// The bit of a tag which specifies whether the note is targeted at an account or a use case.
+const USE_CASE_BIT: u32 = 1 << 30;
+
+/// The bit of the payload of an account tag which specifies whether the account is on-chain; this
+/// is the third most significant bit of the account ID.
+const ON_CHAIN_ACCOUNT_BIT: u32 = 1 << 27;
+
+/// The number of bits of an account ID which are included in an account tag.
+const ACCOUNT_TAG_BITS: u32 = 30;
+
+// NOTE EXECUTION HINT
+// ================================================================================================
+
+/// Specifies who is expected to consume a note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteExecutionHint {
+    /// The note is expected to be consumed by a client in a locally executed transaction.
+    Local,
+    /// The note is expected to be consumed by the network operator.
+    Network,
+}
+
+// NOTE TAG
+// ================================================================================================
+
+/// A value which can be used by the recipient(s) of a note to identify notes intended for them.
+///
+/// A tag is a 32-bit value with the following layout:
+/// - The most significant bit is the execution hint of the note: 0 for local execution and 1 for
+///   network execution.
+/// - The second most significant bit specifies what the note is targeted at: 0 for an account and
+///   1 for a use case.
+/// - For notes targeted at an account, the remaining 30 bits are the 30 most significant bits of
+///   the account ID.
+/// - For notes targeted at a use case, the remaining 30 bits consist of a 14-bit use case ID
+///   followed by a 16-bit payload, the meaning of which is defined by the use case.
+///
+/// Notes which are executed by the network must be targeted at on-chain accounts, as the network
+/// does not have access to the state of off-chain accounts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct NoteTag(u32);
+
+impl NoteTag {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum value of a use case ID.
+    pub const MAX_USE_CASE_ID: u16 = (1 << 14) - 1;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a tag for notes targeted at the specified account, with the local execution hint.
+    ///
+    /// The tag is derived from the 30 most significant bits of the account ID; thus, an account
+    /// needs to track a single tag to discover the notes targeted at it.
+    pub fn for_account(account_id: AccountId) -> Self {
+        let id: u64 = account_id.into();
+        Self((id >> (u64::BITS - ACCOUNT_TAG_BITS)) as u32)
+    }
+
+    /// Returns a tag for notes targeted at the specified use case, with the local execution hint.
+    ///
+    /// # Errors
+    /// Returns an error if the use case ID is greater than [NoteTag::MAX_USE_CASE_ID].
+    pub fn for_use_case(use_case_id: u16, payload: u16) -> Result<Self, NoteError> {
+        if use_case_id > Self::MAX_USE_CASE_ID {
+            return Err(NoteError::InvalidNoteTagUseCase(use_case_id));
+        }
+
+        Ok(Self(USE_CASE_BIT | (use_case_id as u32) << 16 | payload as u32))
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns this tag with the local execution hint.
+    pub fn local(self) -> Self {
+        Self(self.0 & !NETWORK_EXECUTION_BIT)
+    }
+
+    /// Returns this tag with the network execution hint.
+    ///
+    /// Note that tags with the network execution hint which are targeted at off-chain accounts
+    /// are invalid (see [NoteTag::validate()]).
+    pub fn network(self) -> Self {
+        Self(self.0 | NETWORK_EXECUTION_BIT)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the execution hint of this tag.
+    pub fn execution_hint(&self) -> NoteExecutionHint {
+        if self.0 & NETWORK_EXECUTION_BIT == 0 {
+            NoteExecutionHint::Local
+        } else {
+            NoteExecutionHint::Network
+        }
+    }
+
+    /// Returns true if this tag targets an account.
+    pub fn is_account_target(&self) -> bool {
+        self.0 & USE_CASE_BIT == 0
+    }
+
+    /// Returns true if this tag targets the specified account, regardless of its execution hint.
+    pub fn targets_account(&self, account_id: AccountId) -> bool {
+        self.local() == Self::for_account(account_id)
+    }
+
+    /// Returns the use case ID and the payload of this tag, or None if this tag targets an
+    /// account.
+    pub fn use_case(&self) -> Option<(u16, u16)> {
+        if self.is_account_target() {
+            return None;
+        }
+
+        let use_case_id = (self.0 >> 16) as u16 & Self::MAX_USE_CASE_ID;
+        Some((use_case_id, self.0 as u16))
+    }
+
+    /// Returns the value of this tag.
+    pub fn inner(&self) -> u32 {
+        self.0
+    }
+
+    /// Validates this tag.
+    ///
+    /// # Errors
+    /// Returns an error if this tag has the network execution hint and targets an off-chain
+    /// account.
+    pub fn validate(&self) -> Result<(), NoteError> {
+        let is_network = self.execution_hint() == NoteExecutionHint::Network;
+        if is_network && self.is_account_target() && self.0 & ON_CHAIN_ACCOUNT_BIT == 0 {
+            return Err(NoteError::NetworkNoteTagForOffChainAccount(*self));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for NoteTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<u32> for NoteTag {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NoteTag> for u32 {
+    fn from(tag: NoteTag) -> Self {
+        tag.0
+    }
+}
+
+impl From<NoteTag> for u64 {
+    fn from(tag: NoteTag) -> Self {
+        tag.0 as u64
+    }
+}
+
+impl From<NoteTag> for Felt {
+    fn from(tag: NoteTag) -> Self {
+        Felt::from(tag.0)
+    }
+}
+
+impl TryFrom<u64> for NoteTag {
+    type Error = NoteError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        u32::try_from(value)
+            .map(Self)
+            .map_err(|_| NoteError::InvalidNoteTagValue(value))
+    }
+}
+
+impl TryFrom<Felt> for NoteTag {
+    type Error = NoteError;
+
+    fn try_from(value: Felt) -> Result<Self, Self::Error> {
+        value.as_int().try_into()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteTag {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.0);
+    }
+}
+
+impl Deserializable for NoteTag {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tag = Self(source.read_u32()?);
+        tag.validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        Ok(tag)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{NoteExecutionHint, NoteTag};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        },
+        Felt, NoteError,
+    };
+
+    #[test]
+    fn note_tag_encodings() {
+        let on_chain =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let off_chain =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+        // account tags keep the 30 most significant bits of the account ID
+        let tag = NoteTag::for_account(on_chain);
+        assert_eq!(tag.inner() as u64, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN >> 34);
+        assert_eq!(tag.execution_hint(), NoteExecutionHint::Local);
+        assert!(tag.targets_account(on_chain));
+        assert!(tag.network().targets_account(on_chain));
+        assert!(!tag.targets_account(off_chain));
+        assert_eq!(tag.use_case(), None);
+
+        // network execution is allowed only for on-chain accounts
+        assert!(tag.network().validate().is_ok());
+        let tag = NoteTag::for_account(off_chain).network();
+        assert_eq!(tag.validate(), Err(NoteError::NetworkNoteTagForOffChainAccount(tag)));
+        assert!(tag.local().validate().is_ok());
+
+        // use case tags keep the use case ID and the payload
+        let tag = NoteTag::for_use_case(NoteTag::MAX_USE_CASE_ID, 7).unwrap().network();
+        assert_eq!(tag.use_case(), Some((NoteTag::MAX_USE_CASE_ID, 7)));
+        assert_eq!(tag.execution_hint(), NoteExecutionHint::Network);
+        assert!(tag.validate().is_ok());
+        assert!(NoteTag::for_use_case(NoteTag::MAX_USE_CASE_ID + 1, 7).is_err());
+
+        // tags must fit into 32 bits
+        assert_eq!(NoteTag::try_from(Felt::new(u32::MAX as u64)), Ok(NoteTag::from(u32::MAX)));
+        assert!(NoteTag::try_from(u32::MAX as u64 + 1).is_err());
+    }
+}
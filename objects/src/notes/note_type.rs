@@ -0,0 +1,133 @@
+use core::fmt;
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, NoteError, Serializable,
+    ToString,
+};
+use crate::StarkField;
+
+// NOTE TYPE
+// ================================================================================================
+
+/// Specifies how the details of a note are stored by the operator.
+///
+/// The type of a note is committed to in the note metadata, and thus operators can enforce storage
+/// policies based on it (e.g., refuse to store the details of public notes above a certain size).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum NoteType {
+    /// The details of the note are stored on-chain, and are thus visible to everyone.
+    #[default]
+    Public = 1,
+    /// Only the hash of the note is stored on-chain; the details of the note are shared with the
+    /// recipient off-chain.
+    Private = 2,
+    /// The details of the note are stored on-chain in encrypted form (see
+    /// [EncryptedNoteDetails](super::EncryptedNoteDetails)).
+    Encrypted = 3,
+}
+
+impl NoteType {
+    /// Returns true if the details of notes of this type are visible to everyone.
+    pub fn is_public(&self) -> bool {
+        *self == Self::Public
+    }
+}
+
+impl fmt::Display for NoteType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Public => write!(f, "public"),
+            Self::Private => write!(f, "private"),
+            Self::Encrypted => write!(f, "encrypted"),
+        }
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<NoteType> for u8 {
+    fn from(note_type: NoteType) -> Self {
+        note_type as u8
+    }
+}
+
+impl From<NoteType> for Felt {
+    fn from(note_type: NoteType) -> Self {
+        Felt::from(note_type as u8)
+    }
+}
+
+impl TryFrom<u64> for NoteType {
+    type Error = NoteError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Public),
+            2 => Ok(Self::Private),
+            3 => Ok(Self::Encrypted),
+            _ => Err(NoteError::InvalidNoteType(value)),
+        }
+    }
+}
+
+impl TryFrom<u8> for NoteType {
+    type Error = NoteError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        (value as u64).try_into()
+    }
+}
+
+impl TryFrom<Felt> for NoteType {
+    type Error = NoteError;
+
+    fn try_from(value: Felt) -> Result<Self, Self::Error> {
+        value.as_int().try_into()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteType {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(*self as u8);
+    }
+}
+
+impl Deserializable for NoteType {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        source
+            .read_u8()?
+            .try_into()
+            .map_err(|err: NoteError| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::NoteType;
+    use crate::{
+        utils::serde::{Deserializable, Serializable},
+        Felt, NoteError,
+    };
+
+    #[test]
+    fn note_type_encodings() {
+        for note_type in [NoteType::Public, NoteType::Private, NoteType::Encrypted] {
+            assert_eq!(NoteType::try_from(Felt::from(note_type)), Ok(note_type));
+            assert_eq!(NoteType::read_from_bytes(&note_type.to_bytes()).unwrap(), note_type);
+        }
+
+        assert_eq!(NoteType::default(), NoteType::Public);
+        assert_eq!(NoteType::try_from(0u64), Err(NoteError::InvalidNoteType(0)));
+        assert_eq!(NoteType::try_from(Felt::new(4)), Err(NoteError::InvalidNoteType(4)));
+        assert!(NoteType::read_from_bytes(&[0]).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Note, NoteAssets,
+    NoteEnvelope, NoteId, NoteMetadata, Serializable,
+};
+
+// PARTIAL NOTE
+// ================================================================================================
+
+/// A note of which only the recipient, the assets and the metadata are known.
+///
+/// This is the data about a note which is output by the transaction kernel when the note is
+/// created: the script, the inputs and the serial number of the note are committed to via the
+/// recipient, but are not known to the kernel. A partial note is sufficient to compute the ID of
+/// the note, but not its nullifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialNote {
+    envelope: NoteEnvelope,
+    recipient: Digest,
+    assets: NoteAssets,
+}
+
+impl PartialNote {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [PartialNote] instantiated from the provided parameters.
+    pub fn new(recipient: Digest, assets: NoteAssets, metadata: NoteMetadata) -> Self {
+        let note_id = NoteId::new(recipient, assets.commitment());
+        Self {
+            envelope: NoteEnvelope::new(note_id, metadata),
+            recipient,
+            assets,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the recipient of the note.
+    pub fn recipient(&self) -> &Digest {
+        &self.recipient
+    }
+
+    /// Returns a reference to the assets of the note.
+    pub fn assets(&self) -> &NoteAssets {
+        &self.assets
+    }
+
+    /// Returns the metadata of the note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        self.envelope.metadata()
+    }
+
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.envelope.note_id()
+    }
+}
+
+impl From<&Note> for PartialNote {
+    fn from(note: &Note) -> Self {
+        Self::new(note.recipient(), note.assets().clone(), *note.metadata())
+    }
+}
+
+impl From<Note> for PartialNote {
+    fn from(note: Note) -> Self {
+        (&note).into()
+    }
+}
+
+impl From<&PartialNote> for NoteEnvelope {
+    fn from(note: &PartialNote) -> Self {
+        note.envelope
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PartialNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.recipient.write_into(target);
+        self.assets.write_into(target);
+        self.envelope.metadata().write_into(target);
+    }
+}
+
+impl Deserializable for PartialNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let recipient = Digest::read_from(source)?;
+        let assets = NoteAssets::read_from(source)?;
+        let metadata = NoteMetadata::read_from(source)?;
+
+        Ok(Self::new(recipient, assets, metadata))
+    }
+}
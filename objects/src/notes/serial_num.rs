@@ -0,0 +1,116 @@
+use core::fmt::{Debug, Formatter};
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Hasher, NoteTag,
+    Serializable, Word, ZERO,
+};
+
+// SERIAL NUMBER SECRET
+// ================================================================================================
+
+/// A secret from which the sender of notes derives their serial numbers deterministically.
+///
+/// The serial number of the note with a given tag and counter is derived as
+/// `hash(secret, tag, counter)`, where the counter is incremented for every note created with the
+/// same tag. Unlike random serial numbers, derived serial numbers can be regenerated from the
+/// secret, and thus a sender which lost its local state can recover the details of its outgoing
+/// notes: for every tag, it re-derives the serial numbers for increasing counters and rebuilds the
+/// notes until their IDs no longer match notes found on chain.
+///
+/// Derived serial numbers are opt-in: notes can still be created with random serial numbers. The
+/// secret must be kept private, since anybody knowing it can compute the serial numbers, and thus
+/// the nullifiers, of the notes created with it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SerialNumSecret(Word);
+
+impl SerialNumSecret {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [SerialNumSecret] instantiated from the provided secret word.
+    pub fn new(secret: Word) -> Self {
+        Self(secret)
+    }
+
+    /// Returns a new [SerialNumSecret] derived from the provided seed, e.g., the seed of a wallet.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self(Hasher::hash(&seed).into())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the serial number of the note with the specified tag and counter.
+    pub fn derive(&self, tag: NoteTag, counter: u32) -> Word {
+        let mut elements = [ZERO; 6];
+        elements[..4].copy_from_slice(&self.0);
+        elements[4] = tag.into();
+        elements[5] = Felt::from(counter);
+        Hasher::hash_elements(&elements).into()
+    }
+
+    /// Returns an iterator over the counters and the serial numbers of the notes with the specified
+    /// tag, starting with the specified counter.
+    pub fn serial_nums(&self, tag: NoteTag, start: u32) -> impl Iterator<Item = (u32, Word)> + '_ {
+        (start..=u32::MAX).map(move |counter| (counter, self.derive(tag, counter)))
+    }
+
+    /// Returns the counter from which the specified serial number of a note with the specified tag
+    /// was derived, or None if it was not derived from this secret with a counter smaller than
+    /// `max_counter`.
+    pub fn find_counter(&self, tag: NoteTag, serial_num: Word, max_counter: u32) -> Option<u32> {
+        (0..max_counter).find(|&counter| self.derive(tag, counter) == serial_num)
+    }
+}
+
+impl Debug for SerialNumSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SerialNumSecret(..)")
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SerialNumSecret {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for SerialNumSecret {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self(Word::read_from(source)?))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, NoteTag, SerialNumSecret, Serializable};
+
+    #[test]
+    fn serial_num_derivation() {
+        let secret = SerialNumSecret::from_seed([1; 32]);
+        let tag = NoteTag::from(7);
+
+        // serial numbers are deterministic and depend on the secret, the tag and the counter
+        assert_eq!(secret.derive(tag, 3), SerialNumSecret::from_seed([1; 32]).derive(tag, 3));
+        assert_ne!(secret.derive(tag, 3), secret.derive(tag, 4));
+        assert_ne!(secret.derive(tag, 3), secret.derive(NoteTag::from(8), 3));
+        assert_ne!(secret.derive(tag, 3), SerialNumSecret::from_seed([2; 32]).derive(tag, 3));
+
+        let serial_nums: Vec<_> = secret.serial_nums(tag, 2).take(2).collect();
+        assert_eq!(serial_nums, vec![(2, secret.derive(tag, 2)), (3, secret.derive(tag, 3))]);
+
+        // the counter of a derived serial number can be recovered
+        assert_eq!(secret.find_counter(tag, secret.derive(tag, 5), 10), Some(5));
+        assert_eq!(secret.find_counter(tag, secret.derive(tag, 5), 5), None);
+        assert_eq!(secret.find_counter(NoteTag::from(8), secret.derive(tag, 5), 10), None);
+
+        let bytes = secret.to_bytes();
+        assert_eq!(SerialNumSecret::read_from_bytes(&bytes).unwrap(), secret);
+    }
+}
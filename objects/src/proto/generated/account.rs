@@ -0,0 +1,9 @@
+// This file is @generated by prost-build.
+/// A Miden account ID, which is a field element with the account type and storage mode encoded in
+/// its most significant bits.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountId {
+    #[prost(fixed64, tag = "1")]
+    pub id: u64,
+}
@@ -0,0 +1,4 @@
+pub mod account;
+pub mod digest;
+pub mod note;
+pub mod transaction;
@@ -0,0 +1,46 @@
+// This file is @generated by prost-build.
+/// Metadata of a note.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NoteMetadata {
+    /// The account which created the note.
+    #[prost(message, optional, tag = "1")]
+    pub sender: ::core::option::Option<super::account::AccountId>,
+    /// A value which can be used by the recipient(s) to identify notes intended for them.
+    #[prost(fixed64, tag = "2")]
+    pub tag: u64,
+    /// The type of the note: 1 for public, 2 for private and 3 for encrypted notes.
+    #[prost(uint32, tag = "3")]
+    pub note_type: u32,
+    /// An arbitrary value attached to the note by its sender.
+    #[prost(fixed64, tag = "4")]
+    pub aux: u64,
+}
+/// The public part of a note created by a transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NoteEnvelope {
+    #[prost(message, optional, tag = "1")]
+    pub note_id: ::core::option::Option<super::digest::Digest>,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: ::core::option::Option<NoteMetadata>,
+}
+/// A note with all of its details.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Note {
+    /// The note script, serialized with the miden-objects binary encoding of `NoteScript`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub script: ::prost::alloc::vec::Vec<u8>,
+    /// The inputs of the note; at most 16 field elements.
+    #[prost(fixed64, repeated, tag = "2")]
+    pub inputs: ::prost::alloc::vec::Vec<u64>,
+    /// The assets of the note, each encoded as a word.
+    #[prost(message, repeated, tag = "3")]
+    pub assets: ::prost::alloc::vec::Vec<super::digest::Digest>,
+    /// The serial number of the note.
+    #[prost(message, optional, tag = "4")]
+    pub serial_num: ::core::option::Option<super::digest::Digest>,
+    #[prost(message, optional, tag = "5")]
+    pub metadata: ::core::option::Option<NoteMetadata>,
+}
@@ -0,0 +1,57 @@
+// This file is @generated by prost-build.
+/// The result of executing and proving a transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProvenTransaction {
+    /// The account against which the transaction was executed.
+    #[prost(message, optional, tag = "1")]
+    pub account_id: ::core::option::Option<super::account::AccountId>,
+    /// The hash of the account state before the transaction was executed.
+    #[prost(message, optional, tag = "2")]
+    pub initial_account_hash: ::core::option::Option<super::digest::Digest>,
+    /// The hash of the account state after the transaction was executed.
+    #[prost(message, optional, tag = "3")]
+    pub final_account_hash: ::core::option::Option<super::digest::Digest>,
+    /// The commitments to the notes consumed by the transaction.
+    #[prost(message, repeated, tag = "4")]
+    pub input_notes: ::prost::alloc::vec::Vec<InputNoteCommitment>,
+    /// The notes created by the transaction.
+    #[prost(message, repeated, tag = "5")]
+    pub output_notes: ::prost::alloc::vec::Vec<super::note::NoteEnvelope>,
+    /// The changes made to the state of an on-chain account, serialized with the miden-objects
+    /// binary encoding of `AccountDetails`; not set for off-chain accounts.
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub account_details: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// The script root of the transaction, if a transaction script was used.
+    #[prost(message, optional, tag = "7")]
+    pub tx_script_root: ::core::option::Option<super::digest::Digest>,
+    /// The root of the transaction kernel the transaction was proven against.
+    #[prost(message, optional, tag = "8")]
+    pub kernel_root: ::core::option::Option<super::digest::Digest>,
+    /// The hash of the last known block at the time the transaction was executed.
+    #[prost(message, optional, tag = "9")]
+    pub block_ref: ::core::option::Option<super::digest::Digest>,
+    /// The STARK proof of the transaction, serialized with the binary encoding of `ExecutionProof`.
+    #[prost(bytes = "vec", tag = "10")]
+    pub proof: ::prost::alloc::vec::Vec<u8>,
+    /// The number of the last block in which the transaction can be included; not set for
+    /// transactions which never expire.
+    #[prost(uint32, optional, tag = "11")]
+    pub expiration_block_num: ::core::option::Option<u32>,
+    /// The fungible asset paid as a fee by the transaction, encoded as a word; not set for
+    /// transactions which pay no fee.
+    #[prost(message, optional, tag = "12")]
+    pub fee: ::core::option::Option<super::digest::Digest>,
+}
+/// The commitment of a transaction to one of the notes it consumes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InputNoteCommitment {
+    /// The nullifier of the note.
+    #[prost(message, optional, tag = "1")]
+    pub nullifier: ::core::option::Option<super::digest::Digest>,
+    /// The header of the note; set only for notes which were not authenticated during transaction
+    /// execution, and thus must be authenticated by the operator.
+    #[prost(message, optional, tag = "2")]
+    pub header: ::core::option::Option<super::note::NoteEnvelope>,
+}
@@ -0,0 +1,391 @@
+//! Protobuf messages for the objects exchanged between the Miden node, clients and this crate.
+//!
+//! The message definitions live in the `proto` directory of this crate; the Rust code in the
+//! [generated] module is produced from them by `prost-build` and must be regenerated whenever the
+//! definitions change. This module provides conversions between the protobuf messages and the
+//! corresponding objects of this crate.
+//!
+//! Objects which do not have a protobuf representation yet (e.g., note scripts, account details
+//! and execution proofs) are embedded as bytes using their [Serializable] encoding.
+
+use crate::{
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    notes::{
+        Note, NoteAssets, NoteEnvelope, NoteId, NoteInputs, NoteMetadata, NoteScript, NoteTag,
+        NoteType,
+    },
+    transaction::{
+        AccountDetails, InputNoteCommitment, InputNotes, OutputNotes, ProvenTransaction,
+    },
+    utils::{
+        collections::Vec,
+        serde::{Deserializable, Serializable},
+    },
+    vm::ExecutionProof,
+    Digest, Felt, ProtoConversionError, StarkField, Word,
+};
+
+pub mod generated;
+
+// DIGEST
+// ================================================================================================
+
+impl From<Digest> for generated::digest::Digest {
+    fn from(digest: Digest) -> Self {
+        let elements = digest.as_elements();
+        Self {
+            d0: elements[0].as_int(),
+            d1: elements[1].as_int(),
+            d2: elements[2].as_int(),
+            d3: elements[3].as_int(),
+        }
+    }
+}
+
+impl From<Word> for generated::digest::Digest {
+    fn from(word: Word) -> Self {
+        Digest::from(word).into()
+    }
+}
+
+impl TryFrom<generated::digest::Digest> for Word {
+    type Error = ProtoConversionError;
+
+    fn try_from(digest: generated::digest::Digest) -> Result<Self, Self::Error> {
+        Ok([
+            felt_from_u64(digest.d0)?,
+            felt_from_u64(digest.d1)?,
+            felt_from_u64(digest.d2)?,
+            felt_from_u64(digest.d3)?,
+        ])
+    }
+}
+
+impl TryFrom<generated::digest::Digest> for Digest {
+    type Error = ProtoConversionError;
+
+    fn try_from(digest: generated::digest::Digest) -> Result<Self, Self::Error> {
+        Word::try_from(digest).map(Digest::from)
+    }
+}
+
+// ACCOUNT ID
+// ================================================================================================
+
+impl From<AccountId> for generated::account::AccountId {
+    fn from(account_id: AccountId) -> Self {
+        Self { id: account_id.into() }
+    }
+}
+
+impl TryFrom<generated::account::AccountId> for AccountId {
+    type Error = ProtoConversionError;
+
+    fn try_from(account_id: generated::account::AccountId) -> Result<Self, Self::Error> {
+        AccountId::try_from(account_id.id).map_err(ProtoConversionError::InvalidAccountId)
+    }
+}
+
+// NOTE METADATA
+// ================================================================================================
+
+impl From<NoteMetadata> for generated::note::NoteMetadata {
+    fn from(metadata: NoteMetadata) -> Self {
+        Self {
+            sender: Some(metadata.sender().into()),
+            tag: metadata.tag().into(),
+            note_type: u8::from(metadata.note_type()).into(),
+            aux: metadata.aux().as_int(),
+        }
+    }
+}
+
+impl TryFrom<generated::note::NoteMetadata> for NoteMetadata {
+    type Error = ProtoConversionError;
+
+    fn try_from(metadata: generated::note::NoteMetadata) -> Result<Self, Self::Error> {
+        let sender = required(metadata.sender, "NoteMetadata.sender")?.try_into()?;
+        let tag = NoteTag::try_from(metadata.tag).map_err(ProtoConversionError::InvalidNote)?;
+        let note_type = NoteType::try_from(metadata.note_type as u64)
+            .map_err(ProtoConversionError::InvalidNote)?;
+        let aux = felt_from_u64(metadata.aux)?;
+
+        Ok(NoteMetadata::new(sender, tag)
+            .map_err(ProtoConversionError::InvalidNote)?
+            .with_note_type(note_type)
+            .with_aux(aux))
+    }
+}
+
+// NOTE ENVELOPE
+// ================================================================================================
+
+impl From<NoteEnvelope> for generated::note::NoteEnvelope {
+    fn from(envelope: NoteEnvelope) -> Self {
+        Self {
+            note_id: Some(envelope.note_id().inner().into()),
+            metadata: Some((*envelope.metadata()).into()),
+        }
+    }
+}
+
+impl TryFrom<generated::note::NoteEnvelope> for NoteEnvelope {
+    type Error = ProtoConversionError;
+
+    fn try_from(envelope: generated::note::NoteEnvelope) -> Result<Self, Self::Error> {
+        let note_id: Digest = required(envelope.note_id, "NoteEnvelope.note_id")?.try_into()?;
+        let metadata = required(envelope.metadata, "NoteEnvelope.metadata")?.try_into()?;
+
+        Ok(NoteEnvelope::new(NoteId::from(note_id), metadata))
+    }
+}
+
+// NOTE
+// ================================================================================================
+
+impl From<&Note> for generated::note::Note {
+    fn from(note: &Note) -> Self {
+        Self {
+            script: note.script().to_bytes(),
+            inputs: note.inputs().values().iter().map(|input| input.as_int()).collect(),
+            assets: note.assets().iter().map(|&asset| Word::from(asset).into()).collect(),
+            serial_num: Some(note.serial_num().into()),
+            metadata: Some((*note.metadata()).into()),
+        }
+    }
+}
+
+impl From<Note> for generated::note::Note {
+    fn from(note: Note) -> Self {
+        (&note).into()
+    }
+}
+
+impl TryFrom<generated::note::Note> for Note {
+    type Error = ProtoConversionError;
+
+    fn try_from(note: generated::note::Note) -> Result<Self, Self::Error> {
+        let script = NoteScript::read_from_bytes(&note.script)
+            .map_err(ProtoConversionError::DeserializationFailed)?;
+
+        let inputs = note.inputs.into_iter().map(felt_from_u64).collect::<Result<Vec<_>, _>>()?;
+        let inputs = NoteInputs::new(inputs).map_err(ProtoConversionError::InvalidNote)?;
+
+        let assets = note
+            .assets
+            .into_iter()
+            .map(|asset| {
+                Asset::try_from(Word::try_from(asset)?).map_err(ProtoConversionError::InvalidAsset)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let assets = NoteAssets::new(&assets).map_err(ProtoConversionError::InvalidNote)?;
+
+        let serial_num = required(note.serial_num, "Note.serial_num")?.try_into()?;
+        let metadata = required(note.metadata, "Note.metadata")?.try_into()?;
+
+        Ok(Note::from_parts(script, inputs, assets, serial_num, metadata))
+    }
+}
+
+// INPUT NOTE COMMITMENT
+// ================================================================================================
+
+impl From<&InputNoteCommitment> for generated::transaction::InputNoteCommitment {
+    fn from(note: &InputNoteCommitment) -> Self {
+        Self {
+            nullifier: Some(note.nullifier().inner().into()),
+            header: note.header().map(|&header| header.into()),
+        }
+    }
+}
+
+impl TryFrom<generated::transaction::InputNoteCommitment> for InputNoteCommitment {
+    type Error = ProtoConversionError;
+
+    fn try_from(note: generated::transaction::InputNoteCommitment) -> Result<Self, Self::Error> {
+        let nullifier: Digest =
+            required(note.nullifier, "InputNoteCommitment.nullifier")?.try_into()?;
+
+        match note.header {
+            Some(header) => {
+                Ok(InputNoteCommitment::unauthenticated(nullifier.into(), header.try_into()?))
+            },
+            None => Ok(InputNoteCommitment::new(nullifier.into())),
+        }
+    }
+}
+
+// PROVEN TRANSACTION
+// ================================================================================================
+
+impl From<&ProvenTransaction> for generated::transaction::ProvenTransaction {
+    fn from(tx: &ProvenTransaction) -> Self {
+        Self {
+            account_id: Some(tx.account_id().into()),
+            initial_account_hash: Some(tx.initial_account_hash().into()),
+            final_account_hash: Some(tx.final_account_hash().into()),
+            input_notes: tx.input_notes().iter().map(Into::into).collect(),
+            output_notes: tx.output_notes().iter().map(|&envelope| envelope.into()).collect(),
+            account_details: tx.account_details().map(|details| details.to_bytes()),
+            tx_script_root: tx.tx_script_root().map(Into::into),
+            kernel_root: Some(tx.kernel_root().into()),
+            block_ref: Some(tx.block_ref().into()),
+            proof: tx.proof().to_bytes(),
+            expiration_block_num: Some(tx.expiration_block_num())
+                .filter(|&block_num| block_num != u32::MAX),
+            fee: tx.fee().map(|fee| Word::from(fee).into()),
+        }
+    }
+}
+
+impl From<ProvenTransaction> for generated::transaction::ProvenTransaction {
+    fn from(tx: ProvenTransaction) -> Self {
+        (&tx).into()
+    }
+}
+
+impl TryFrom<generated::transaction::ProvenTransaction> for ProvenTransaction {
+    type Error = ProtoConversionError;
+
+    fn try_from(tx: generated::transaction::ProvenTransaction) -> Result<Self, Self::Error> {
+        let account_id = required(tx.account_id, "ProvenTransaction.account_id")?.try_into()?;
+        let initial_account_hash =
+            required(tx.initial_account_hash, "ProvenTransaction.initial_account_hash")?
+                .try_into()?;
+        let final_account_hash =
+            required(tx.final_account_hash, "ProvenTransaction.final_account_hash")?.try_into()?;
+
+        let input_notes = tx
+            .input_notes
+            .into_iter()
+            .map(InputNoteCommitment::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let input_notes =
+            InputNotes::new(input_notes).map_err(ProtoConversionError::InvalidInputNotes)?;
+
+        let output_notes = tx
+            .output_notes
+            .into_iter()
+            .map(NoteEnvelope::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let output_notes =
+            OutputNotes::new(output_notes).map_err(ProtoConversionError::InvalidOutputNotes)?;
+
+        let account_details = tx
+            .account_details
+            .map(|details| AccountDetails::read_from_bytes(&details))
+            .transpose()
+            .map_err(ProtoConversionError::DeserializationFailed)?;
+
+        let tx_script_root = tx.tx_script_root.map(Digest::try_from).transpose()?;
+        let kernel_root = required(tx.kernel_root, "ProvenTransaction.kernel_root")?.try_into()?;
+        let block_ref = required(tx.block_ref, "ProvenTransaction.block_ref")?.try_into()?;
+        let expiration_block_num = tx.expiration_block_num.unwrap_or(u32::MAX);
+        let fee = tx
+            .fee
+            .map(|fee| {
+                FungibleAsset::try_from(Word::try_from(fee)?)
+                    .map_err(ProtoConversionError::InvalidAsset)
+            })
+            .transpose()?;
+
+        let proof = ExecutionProof::read_from_bytes(&tx.proof)
+            .map_err(ProtoConversionError::DeserializationFailed)?;
+
+        ProvenTransaction::new(
+            account_id,
+            initial_account_hash,
+            final_account_hash,
+            input_notes,
+            output_notes,
+            account_details,
+            tx_script_root,
+            kernel_root,
+            block_ref,
+            expiration_block_num,
+            fee,
+            proof,
+        )
+        .map_err(ProtoConversionError::InvalidProvenTransaction)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the field element with the provided canonical value.
+fn felt_from_u64(value: u64) -> Result<Felt, ProtoConversionError> {
+    if value >= Felt::MODULUS {
+        return Err(ProtoConversionError::NotACanonicalFieldElement(value));
+    }
+    Ok(Felt::new(value))
+}
+
+/// Returns the value of a required message field, or an error if the field is not set.
+fn required<T>(field: Option<T>, name: &'static str) -> Result<T, ProtoConversionError> {
+    field.ok_or(ProtoConversionError::MissingField(name))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::generated;
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+        assembly::{Assembler, ProgramAst},
+        assets::{Asset, FungibleAsset},
+        notes::{Note, NoteMetadata, NoteScript, NoteTag, NoteType},
+        Felt, NoteError, ProtoConversionError, StarkField,
+    };
+
+    #[test]
+    fn proto_note_round_trip() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let (script, _) = NoteScript::new(code, &Assembler::default()).unwrap();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let note =
+            Note::new(script, &[Felt::new(7)], &[asset], serial_num, faucet_id, NoteTag::from(9))
+                .unwrap();
+
+        let bytes = generated::note::Note::from(&note).encode_to_vec();
+        let decoded = generated::note::Note::decode(bytes.as_slice()).unwrap();
+        assert_eq!(Note::try_from(decoded).unwrap(), note);
+
+        // a note without metadata is rejected
+        let mut message = generated::note::Note::from(&note);
+        message.metadata = None;
+        assert_eq!(
+            Note::try_from(message),
+            Err(ProtoConversionError::MissingField("Note.metadata"))
+        );
+
+        // non-canonical field elements are rejected
+        let mut message = generated::note::Note::from(&note);
+        message.inputs = vec![Felt::MODULUS];
+        assert_eq!(
+            Note::try_from(message),
+            Err(ProtoConversionError::NotACanonicalFieldElement(Felt::MODULUS))
+        );
+
+        // the note type and the aux value of the metadata are preserved
+        let metadata = note.metadata().with_note_type(NoteType::Private).with_aux(Felt::new(5));
+        let message = generated::note::NoteMetadata::from(metadata);
+        assert_eq!(NoteMetadata::try_from(message.clone()).unwrap(), metadata);
+
+        // invalid note types are rejected
+        let mut message = message;
+        message.note_type = 0;
+        assert_eq!(
+            NoteMetadata::try_from(message),
+            Err(ProtoConversionError::InvalidNote(NoteError::InvalidNoteType(0)))
+        );
+    }
+}
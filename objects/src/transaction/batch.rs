@@ -0,0 +1,282 @@
+use super::{AccountId, Digest, Hasher, NoteEnvelope, Nullifier, ProvenTransaction, TransactionId};
+use crate::{
+    notes::NoteId,
+    utils::{
+        collections::{BTreeMap, BTreeSet, Vec},
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        string::ToString,
+    },
+    Felt, TransactionBatchError, MAX_NOTES_PER_BATCH, MAX_TRANSACTIONS_PER_BATCH,
+};
+
+// BATCH ACCOUNT UPDATE
+// ================================================================================================
+
+/// The combined state transition of an account updated by one or more transactions of a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchAccountUpdate {
+    account_id: AccountId,
+    initial_state_hash: Digest,
+    final_state_hash: Digest,
+    transactions: Vec<TransactionId>,
+}
+
+impl BatchAccountUpdate {
+    /// Returns the ID of the updated account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the hash of the account state before the first transaction of the batch against
+    /// this account.
+    pub fn initial_state_hash(&self) -> Digest {
+        self.initial_state_hash
+    }
+
+    /// Returns the hash of the account state after the last transaction of the batch against this
+    /// account.
+    pub fn final_state_hash(&self) -> Digest {
+        self.final_state_hash
+    }
+
+    /// Returns the IDs of the transactions against this account, in the order in which they are
+    /// applied.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+}
+
+// TRANSACTION BATCH
+// ================================================================================================
+
+/// A set of proven transactions which are submitted to the operator together.
+///
+/// The batch aggregates the effects of its transactions:
+/// - The account updates: for every account, the state transition from the initial state of the
+///   first transaction against the account to the final state of the last one. Transactions
+///   against the same account must be provided in the order in which they are applied.
+/// - The nullifiers of the notes consumed by the transactions.
+/// - The headers of the unauthenticated notes consumed by the transactions, which must be
+///   authenticated by the operator.
+/// - The notes created by the transactions.
+///
+/// Notes created by a transaction of the batch and consumed as unauthenticated notes by another
+/// transaction of the same batch are erased: they are neither part of the output notes nor of the
+/// unauthenticated notes, and their nullifiers are not part of the nullifiers of the batch, since
+/// such notes never appear on chain.
+#[derive(Debug, Clone)]
+pub struct TransactionBatch {
+    id: Digest,
+    transactions: Vec<ProvenTransaction>,
+    account_updates: BTreeMap<AccountId, BatchAccountUpdate>,
+    nullifiers: Vec<Nullifier>,
+    unauthenticated_notes: Vec<NoteEnvelope>,
+    output_notes: Vec<NoteEnvelope>,
+}
+
+impl TransactionBatch {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionBatch] built from the provided transactions.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No transactions are provided.
+    /// - More than [MAX_TRANSACTIONS_PER_BATCH] transactions are provided.
+    /// - The same transaction is provided more than once.
+    /// - A transaction against an account does not start from the final state of the previous
+    ///   transaction of the batch against the same account.
+    /// - The same note is consumed by more than one transaction.
+    /// - The same note is created by more than one transaction.
+    /// - The output notes of the batch, i.e., the created notes which are not erased, are more than
+    ///   [MAX_NOTES_PER_BATCH].
+    pub fn new(transactions: Vec<ProvenTransaction>) -> Result<Self, TransactionBatchError> {
+        if transactions.is_empty() {
+            return Err(TransactionBatchError::EmptyBatch);
+        }
+        if transactions.len() > MAX_TRANSACTIONS_PER_BATCH {
+            return Err(TransactionBatchError::TooManyTransactions {
+                max: MAX_TRANSACTIONS_PER_BATCH,
+                actual: transactions.len(),
+            });
+        }
+
+        let mut tx_ids = BTreeSet::new();
+        let mut account_updates: BTreeMap<AccountId, BatchAccountUpdate> = BTreeMap::new();
+        let mut consumed: BTreeMap<Nullifier, TransactionId> = BTreeMap::new();
+        let mut created: BTreeMap<NoteId, TransactionId> = BTreeMap::new();
+        for tx in transactions.iter() {
+            if !tx_ids.insert(tx.id()) {
+                return Err(TransactionBatchError::DuplicateTransaction(tx.id()));
+            }
+
+            match account_updates.get_mut(&tx.account_id()) {
+                Some(update) => {
+                    if update.final_state_hash != tx.initial_account_hash() {
+                        return Err(TransactionBatchError::InconsistentAccountStateTransition {
+                            account_id: tx.account_id(),
+                            expected: update.final_state_hash,
+                            actual: tx.initial_account_hash(),
+                        });
+                    }
+                    update.final_state_hash = tx.final_account_hash();
+                    update.transactions.push(tx.id());
+                },
+                None => {
+                    let update = BatchAccountUpdate {
+                        account_id: tx.account_id(),
+                        initial_state_hash: tx.initial_account_hash(),
+                        final_state_hash: tx.final_account_hash(),
+                        transactions: vec![tx.id()],
+                    };
+                    account_updates.insert(tx.account_id(), update);
+                },
+            }
+
+            for note in tx.input_notes().iter() {
+                if let Some(first) = consumed.insert(note.nullifier(), tx.id()) {
+                    return Err(TransactionBatchError::DuplicateInputNote {
+                        nullifier: note.nullifier(),
+                        first,
+                        second: tx.id(),
+                    });
+                }
+            }
+
+            for note in tx.output_notes().iter() {
+                if let Some(first) = created.insert(note.note_id(), tx.id()) {
+                    return Err(TransactionBatchError::DuplicateOutputNote {
+                        note_id: note.note_id(),
+                        first,
+                        second: tx.id(),
+                    });
+                }
+            }
+        }
+
+        // erase the notes which are both created and consumed within the batch
+        let mut nullifiers = Vec::new();
+        let mut unauthenticated_notes = Vec::new();
+        let mut erased_notes = BTreeSet::new();
+        for note in transactions.iter().flat_map(|tx| tx.input_notes().iter()) {
+            match note.header() {
+                Some(header) if created.contains_key(&header.note_id()) => {
+                    erased_notes.insert(header.note_id());
+                },
+                Some(header) => {
+                    nullifiers.push(note.nullifier());
+                    unauthenticated_notes.push(*header);
+                },
+                None => nullifiers.push(note.nullifier()),
+            }
+        }
+        let output_notes: Vec<NoteEnvelope> = transactions
+            .iter()
+            .flat_map(|tx| tx.output_notes().iter())
+            .filter(|note| !erased_notes.contains(&note.note_id()))
+            .copied()
+            .collect();
+        if output_notes.len() > MAX_NOTES_PER_BATCH {
+            return Err(TransactionBatchError::TooManyOutputNotes {
+                max: MAX_NOTES_PER_BATCH,
+                actual: output_notes.len(),
+            });
+        }
+
+        let id = compute_batch_id(transactions.iter().map(|tx| tx.id()));
+
+        Ok(Self {
+            id,
+            transactions,
+            account_updates,
+            nullifiers,
+            unauthenticated_notes,
+            output_notes,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this batch, i.e., a commitment to the IDs of its transactions.
+    pub fn id(&self) -> Digest {
+        self.id
+    }
+
+    /// Returns the transactions of this batch, in the order in which they were provided.
+    pub fn transactions(&self) -> &[ProvenTransaction] {
+        &self.transactions
+    }
+
+    /// Returns an iterator over the combined state transitions of the accounts updated by this
+    /// batch, sorted by account ID.
+    pub fn account_updates(&self) -> impl Iterator<Item = &BatchAccountUpdate> {
+        self.account_updates.values()
+    }
+
+    /// Returns the combined state transition of the specified account, or None if the account is
+    /// not updated by this batch.
+    pub fn get_account_update(&self, account_id: AccountId) -> Option<&BatchAccountUpdate> {
+        self.account_updates.get(&account_id)
+    }
+
+    /// Returns the nullifiers of the notes consumed by this batch.
+    pub fn nullifiers(&self) -> &[Nullifier] {
+        &self.nullifiers
+    }
+
+    /// Returns the headers of the unauthenticated notes consumed by this batch, which must be
+    /// authenticated by the operator.
+    pub fn unauthenticated_notes(&self) -> &[NoteEnvelope] {
+        &self.unauthenticated_notes
+    }
+
+    /// Returns the notes created by this batch.
+    pub fn output_notes(&self) -> &[NoteEnvelope] {
+        &self.output_notes
+    }
+
+    /// Returns the number of the last block in which this batch can be included, i.e., the
+    /// smallest expiration block number of its transactions.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.transactions
+            .iter()
+            .map(|tx| tx.expiration_block_num())
+            .min()
+            .expect("batch contains at least one transaction")
+    }
+
+    /// Consumes this batch and returns its transactions.
+    pub fn into_transactions(self) -> Vec<ProvenTransaction> {
+        self.transactions
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the commitment to the provided sequence of transaction IDs.
+fn compute_batch_id(tx_ids: impl Iterator<Item = TransactionId>) -> Digest {
+    let elements: Vec<Felt> = tx_ids.flat_map(|id| id.as_elements().to_vec()).collect();
+    Hasher::hash_elements(&elements)
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionBatch {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.transactions.len());
+        target.write_many(&self.transactions);
+    }
+}
+
+impl Deserializable for TransactionBatch {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_transactions = source.read_usize()?;
+        let transactions = source.read_many::<ProvenTransaction>(num_transactions)?;
+
+        Self::new(transactions).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
@@ -1,7 +1,11 @@
 use crate::{
-    crypto::merkle::{InnerNodeInfo, MmrPeaks, PartialMmr},
-    utils::collections::{BTreeMap, Vec},
-    BlockHeader, ChainMmrError,
+    crypto::merkle::{InnerNodeInfo, MerklePath, MmrPeaks, MmrProof, PartialMmr},
+    utils::{
+        collections::{BTreeMap, Vec},
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        string::ToString,
+    },
+    BlockHeader, ChainMmrError, Digest,
 };
 
 // CHAIN MMR
@@ -59,6 +63,33 @@ impl ChainMmr {
         Ok(Self { mmr, blocks: block_map })
     }
 
+    /// Returns a new [ChainMmr] for the chain consisting of the provided block headers, tracking
+    /// authentication paths for all of the blocks.
+    ///
+    /// The headers must form the full chain, i.e., the header at position `i` must be the header
+    /// of block `i`. To build the chain MMR for a transaction executed against block `n`, the
+    /// headers of blocks `0..n` must be provided.
+    ///
+    /// # Errors
+    /// Returns an error if the block number of any of the headers does not match its position in
+    /// the provided list.
+    pub fn from_block_headers(headers: &[BlockHeader]) -> Result<Self, ChainMmrError> {
+        let peaks = MmrPeaks::new(0, Vec::new()).expect("empty MMR peaks are valid");
+        let mut chain_mmr = Self::new(PartialMmr::from_peaks(peaks), Vec::new())?;
+
+        for (block_num, header) in headers.iter().enumerate() {
+            if header.block_num() as usize != block_num {
+                return Err(ChainMmrError::non_sequential_block(
+                    block_num as u32,
+                    header.block_num(),
+                ));
+            }
+            chain_mmr.add_block(*header, true);
+        }
+
+        Ok(chain_mmr)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -83,6 +114,24 @@ impl ChainMmr {
         self.blocks.get(&block_num)
     }
 
+    /// Returns the authentication path of the specified block against the peaks of this MMR.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number is greater than or equal to the chain length.
+    /// - The authentication path for the block is not tracked by this chain MMR.
+    pub fn open(&self, block_num: u32) -> Result<MmrProof, ChainMmrError> {
+        let chain_length = self.chain_length();
+        if block_num as usize >= chain_length {
+            return Err(ChainMmrError::block_num_too_big(chain_length, block_num));
+        }
+
+        self.mmr
+            .open(block_num as usize)
+            .expect("block number is within the chain")
+            .ok_or(ChainMmrError::untracked_block(block_num))
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -90,7 +139,8 @@ impl ChainMmr {
     /// block header is for the next block in the chain.
     ///
     /// If `track` parameter is set to true, the authentication path for the provided block header
-    /// will be added to this chain MMR.
+    /// will be added to this chain MMR, and the block header will be added to the blocks of this
+    /// chain MMR.
     ///
     /// # Panics
     /// Panics if the `block_header.block_num` is not equal to the current chain length (i.e., the
@@ -98,6 +148,44 @@ impl ChainMmr {
     pub fn add_block(&mut self, block_header: BlockHeader, track: bool) {
         assert_eq!(block_header.block_num(), self.chain_length() as u32);
         self.mmr.add(block_header.hash(), track);
+        if track {
+            self.blocks.insert(block_header.block_num(), block_header);
+        }
+    }
+
+    /// Adds the provided block header to the blocks of this chain MMR, tracking its authentication
+    /// path against the peaks of this MMR.
+    ///
+    /// Unlike [ChainMmr::add_block()], this does not extend the chain; it allows a chain MMR which
+    /// tracks only a subset of the blocks of the chain (or only the peaks) to be extended with the
+    /// blocks needed to authenticate input notes.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number is greater than or equal to the chain length.
+    /// - The block is already present in this chain MMR.
+    /// - The provided path is not a valid authentication path of the block against the peaks of
+    ///   this MMR; the chain MMR is not modified in this case.
+    pub fn track_block(
+        &mut self,
+        block_header: BlockHeader,
+        path: &MerklePath,
+    ) -> Result<(), ChainMmrError> {
+        let block_num = block_header.block_num();
+        let chain_length = self.chain_length();
+        if block_num as usize >= chain_length {
+            return Err(ChainMmrError::block_num_too_big(chain_length, block_num));
+        }
+        if self.blocks.contains_key(&block_num) {
+            return Err(ChainMmrError::duplicate_block(block_num));
+        }
+
+        self.mmr
+            .track(block_num as usize, block_header.hash(), path)
+            .map_err(|_| ChainMmrError::invalid_block_path(block_num))?;
+        self.blocks.insert(block_num, block_header);
+
+        Ok(())
     }
 
     // ITERATORS
@@ -112,15 +200,62 @@ impl ChainMmr {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ChainMmr {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let peaks = self.mmr.peaks();
+        target.write_usize(peaks.num_leaves());
+        target.write_usize(peaks.peaks().len());
+        target.write_many(peaks.peaks());
+
+        // the authentication path of every block is written together with its header
+        target.write_usize(self.blocks.len());
+        for (block_num, block) in self.blocks.iter() {
+            let proof = self
+                .mmr
+                .open(*block_num as usize)
+                .expect("block number is within the chain")
+                .expect("block is tracked by the partial MMR");
+            block.write_into(target);
+            proof.merkle_path.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for ChainMmr {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_leaves = source.read_usize()?;
+        let num_peaks = source.read_usize()?;
+        let peaks = source.read_many::<Digest>(num_peaks)?;
+        let peaks = MmrPeaks::new(num_leaves, peaks)
+            .map_err(|err| DeserializationError::InvalidValue(format!("{err:?}")))?;
+        let mut mmr = PartialMmr::from_peaks(peaks);
+
+        let num_blocks = source.read_usize()?;
+        let mut blocks = Vec::with_capacity(num_blocks);
+        for _ in 0..num_blocks {
+            let block = BlockHeader::read_from(source)?;
+            let path = MerklePath::read_from(source)?;
+            mmr.track(block.block_num() as usize, block.hash(), &path)
+                .map_err(|err| DeserializationError::InvalidValue(format!("{err:?}")))?;
+            blocks.push(block);
+        }
+
+        Self::new(mmr, blocks).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::{ChainMmr, Vec};
+    use super::{ChainMmr, Deserializable, Serializable, Vec};
     use crate::{
         crypto::merkle::{Mmr, PartialMmr},
-        BlockHeader, Digest, ZERO,
+        BlockHeader, ChainMmrError, Digest, ZERO,
     };
 
     #[test]
@@ -168,6 +303,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_chain_mmr_serialization() {
+        let mut mmr = Mmr::default();
+        let blocks = (0..5).map(int_to_block_header).collect::<Vec<_>>();
+        for block in blocks.iter() {
+            mmr.add(block.hash());
+        }
+
+        // track the blocks 1 and 3 only
+        let mut partial_mmr: PartialMmr = mmr.peaks(mmr.forest()).unwrap().into();
+        for block_num in [1, 3] {
+            let path = mmr.open(block_num, mmr.forest()).unwrap().merkle_path;
+            partial_mmr.track(block_num, blocks[block_num].hash(), &path).unwrap();
+        }
+        let chain_mmr = ChainMmr::new(partial_mmr, vec![blocks[1], blocks[3]]).unwrap();
+
+        let deserialized = ChainMmr::read_from_bytes(&chain_mmr.to_bytes()).unwrap();
+        assert_eq!(deserialized, chain_mmr);
+        assert_eq!(deserialized.get_block(3), Some(&blocks[3]));
+    }
+
+    #[test]
+    fn test_chain_mmr_from_block_headers() {
+        let mut mmr = Mmr::default();
+        let blocks = (0..6).map(int_to_block_header).collect::<Vec<_>>();
+        for block in blocks.iter() {
+            mmr.add(block.hash());
+        }
+
+        let chain_mmr = ChainMmr::from_block_headers(&blocks).unwrap();
+        assert_eq!(chain_mmr.chain_length(), 6);
+        assert_eq!(chain_mmr.peaks().hash_peaks(), mmr.peaks(mmr.forest()).unwrap().hash_peaks());
+        for block in blocks.iter() {
+            let block_num = block.block_num();
+            assert_eq!(chain_mmr.get_block(block_num), Some(block));
+            assert_eq!(
+                chain_mmr.open(block_num).unwrap(),
+                mmr.open(block_num as usize, mmr.forest()).unwrap()
+            );
+        }
+        assert_eq!(chain_mmr.open(6), Err(ChainMmrError::block_num_too_big(6, 6)));
+
+        // the block headers must form the chain
+        assert_eq!(
+            ChainMmr::from_block_headers(&blocks[1..]),
+            Err(ChainMmrError::non_sequential_block(0, 1))
+        );
+
+        // blocks which are not tracked cannot be opened
+        let partial_mmr: PartialMmr = mmr.peaks(mmr.forest()).unwrap().into();
+        let chain_mmr = ChainMmr::new(partial_mmr, Vec::new()).unwrap();
+        assert_eq!(chain_mmr.open(2), Err(ChainMmrError::untracked_block(2)));
+    }
+
+    #[test]
+    fn test_chain_mmr_track_block() {
+        let mut mmr = Mmr::default();
+        let blocks = (0..7).map(int_to_block_header).collect::<Vec<_>>();
+        for block in blocks.iter() {
+            mmr.add(block.hash());
+        }
+
+        // start from a chain MMR which tracks only the peaks
+        let partial_mmr: PartialMmr = mmr.peaks(mmr.forest()).unwrap().into();
+        let mut chain_mmr = ChainMmr::new(partial_mmr, Vec::new()).unwrap();
+
+        let path = mmr.open(2, mmr.forest()).unwrap().merkle_path;
+        chain_mmr.track_block(blocks[2], &path).unwrap();
+        assert_eq!(chain_mmr.get_block(2), Some(&blocks[2]));
+        assert_eq!(chain_mmr.open(2).unwrap(), mmr.open(2, mmr.forest()).unwrap());
+        assert_eq!(chain_mmr.track_block(blocks[2], &path), Err(ChainMmrError::duplicate_block(2)));
+
+        // the path must authenticate the block against the peaks of the chain MMR
+        let path = mmr.open(4, mmr.forest()).unwrap().merkle_path;
+        assert_eq!(
+            chain_mmr.track_block(blocks[5], &path),
+            Err(ChainMmrError::invalid_block_path(5))
+        );
+        assert!(!chain_mmr.contains_block(5));
+
+        assert_eq!(
+            chain_mmr.track_block(int_to_block_header(7), &path),
+            Err(ChainMmrError::block_num_too_big(7, 7))
+        );
+    }
+
     fn int_to_block_header(block_num: u32) -> BlockHeader {
         BlockHeader::new(
             Digest::default(),
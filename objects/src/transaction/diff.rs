@@ -0,0 +1,160 @@
+use super::{AccountId, ExecutedTransaction, Felt, Nullifier, OutputNote, Word};
+use crate::{
+    assets::{Asset, NonFungibleAsset},
+    utils::collections::{BTreeMap, Vec},
+};
+
+// TRANSACTION DIFF
+// ================================================================================================
+
+/// A structured description of the effects of an executed transaction.
+///
+/// The diff combines the account delta with the initial account state and the transaction
+/// outputs, and describes:
+/// - The fungible assets which entered and left the account vault, aggregated per faucet.
+/// - The non-fungible assets which entered and left the account vault.
+/// - The storage slots which were changed, together with their values before and after the
+///   transaction.
+/// - The change of the account nonce, if the nonce was incremented.
+/// - The notes created by the transaction.
+/// - The nullifiers of the notes consumed by the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionDiff {
+    account_id: AccountId,
+    fungible_assets_in: BTreeMap<AccountId, u64>,
+    fungible_assets_out: BTreeMap<AccountId, u64>,
+    non_fungible_assets_in: Vec<NonFungibleAsset>,
+    non_fungible_assets_out: Vec<NonFungibleAsset>,
+    storage_changes: Vec<StorageSlotChange>,
+    nonce_change: Option<(Felt, Felt)>,
+    created_notes: Vec<OutputNote>,
+    nullifiers: Vec<Nullifier>,
+}
+
+impl TransactionDiff {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the amounts of fungible assets which were added to the account vault, keyed by
+    /// faucet ID.
+    pub fn fungible_assets_in(&self) -> &BTreeMap<AccountId, u64> {
+        &self.fungible_assets_in
+    }
+
+    /// Returns the amounts of fungible assets which were removed from the account vault, keyed by
+    /// faucet ID.
+    pub fn fungible_assets_out(&self) -> &BTreeMap<AccountId, u64> {
+        &self.fungible_assets_out
+    }
+
+    /// Returns the non-fungible assets which were added to the account vault.
+    pub fn non_fungible_assets_in(&self) -> &[NonFungibleAsset] {
+        &self.non_fungible_assets_in
+    }
+
+    /// Returns the non-fungible assets which were removed from the account vault.
+    pub fn non_fungible_assets_out(&self) -> &[NonFungibleAsset] {
+        &self.non_fungible_assets_out
+    }
+
+    /// Returns the storage slots changed by the transaction, ordered by slot index.
+    pub fn storage_changes(&self) -> &[StorageSlotChange] {
+        &self.storage_changes
+    }
+
+    /// Returns the account nonce before and after the transaction, or None if the nonce was not
+    /// changed.
+    pub fn nonce_change(&self) -> Option<(Felt, Felt)> {
+        self.nonce_change
+    }
+
+    /// Returns the notes created by the transaction.
+    pub fn created_notes(&self) -> &[OutputNote] {
+        &self.created_notes
+    }
+
+    /// Returns the nullifiers of the notes consumed by the transaction.
+    pub fn nullifiers(&self) -> &[Nullifier] {
+        &self.nullifiers
+    }
+}
+
+impl From<&ExecutedTransaction> for TransactionDiff {
+    fn from(tx: &ExecutedTransaction) -> Self {
+        let initial_account = tx.initial_account();
+        let delta = tx.account_delta();
+
+        // vault changes
+        let mut fungible_assets_in = BTreeMap::new();
+        let mut non_fungible_assets_in = Vec::new();
+        for asset in delta.vault().added_assets.iter() {
+            match asset {
+                Asset::Fungible(asset) => {
+                    *fungible_assets_in.entry(asset.faucet_id()).or_insert(0) += asset.amount();
+                },
+                Asset::NonFungible(asset) => non_fungible_assets_in.push(*asset),
+            }
+        }
+
+        let mut fungible_assets_out = BTreeMap::new();
+        let mut non_fungible_assets_out = Vec::new();
+        for asset in delta.vault().removed_assets.iter() {
+            match asset {
+                Asset::Fungible(asset) => {
+                    *fungible_assets_out.entry(asset.faucet_id()).or_insert(0) += asset.amount();
+                },
+                Asset::NonFungible(asset) => non_fungible_assets_out.push(*asset),
+            }
+        }
+
+        // storage changes
+        let storage = initial_account.storage();
+        let cleared_items =
+            delta.storage().cleared_items.iter().map(|&index| (index, Word::default()));
+        let updated_items = delta.storage().updated_items.iter().copied();
+        let mut storage_changes = cleared_items
+            .chain(updated_items)
+            .map(|(index, after)| StorageSlotChange {
+                index,
+                before: storage.get_item(index).into(),
+                after,
+            })
+            .collect::<Vec<_>>();
+        storage_changes.sort_by_key(|change| change.index);
+
+        // nonce change
+        let nonce_change = delta.nonce().map(|nonce| (initial_account.nonce(), nonce));
+
+        // notes
+        let created_notes = tx.output_notes().iter().cloned().collect();
+        let nullifiers = tx.input_notes().iter().map(|note| note.note().nullifier()).collect();
+
+        Self {
+            account_id: initial_account.id(),
+            fungible_assets_in,
+            fungible_assets_out,
+            non_fungible_assets_in,
+            non_fungible_assets_out,
+            storage_changes,
+            nonce_change,
+            created_notes,
+            nullifiers,
+        }
+    }
+}
+
+// STORAGE SLOT CHANGE
+// ================================================================================================
+
+/// A change of the value of a single account storage slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageSlotChange {
+    pub index: u8,
+    pub before: Word,
+    pub after: Word,
+}
@@ -1,9 +1,15 @@
 use core::cell::OnceCell;
 
 use super::{
-    Account, AccountDelta, AccountId, AccountStub, AdviceInputs, BlockHeader, InputNotes,
-    OutputNotes, Program, TransactionArgs, TransactionId, TransactionInputs, TransactionOutputs,
-    TransactionWitness,
+    tx_witness::{write_witness, SerializedWitness},
+    Account, AccountDelta, AccountId, AccountStub, AdviceInputs, BlockHeader, Digest, InputNotes,
+    OutputNotes, Program, TransactionArgs, TransactionDiff, TransactionId, TransactionInputs,
+    TransactionOutputs, TransactionWitness,
+};
+use crate::{
+    assets::FungibleAsset,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Word, TX_SCRIPT_OUTPUT_WORDS,
 };
 
 // EXECUTED TRANSACTION
@@ -19,6 +25,11 @@ use super::{
 ///   stateless manner. This includes all public transaction inputs, but also all nondeterministic
 ///   inputs that the host provided to Miden VM while executing the transaction (i.e., advice
 ///   witness).
+///
+/// Similarly to [TransactionWitness], a serialized executed transaction contains only the hash of
+/// the transaction program (see [ExecutedTransaction::read_without_program()]). The serialized
+/// transaction starts with the serialized witness of the transaction, and thus can also be read
+/// as a witness via [TransactionWitness::read_without_program()].
 #[derive(Debug, Clone)]
 pub struct ExecutedTransaction {
     id: OnceCell<TransactionId>,
@@ -98,6 +109,22 @@ impl ExecutedTransaction {
         &self.tx_outputs.output_notes
     }
 
+    /// Returns the number of the last block in which this transaction can be included.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.tx_outputs.expiration_block_num
+    }
+
+    /// Returns the fee paid by this transaction, or None if the transaction pays no fee.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.tx_outputs.fee
+    }
+
+    /// Returns the words returned by the transaction script, with the word at the top of the
+    /// stack first, or None if the transaction was executed without a transaction script.
+    pub fn tx_script_output(&self) -> Option<&[Word; TX_SCRIPT_OUTPUT_WORDS]> {
+        self.tx_outputs.tx_script_output.as_ref()
+    }
+
     /// Returns a reference to the transaction args.
     pub fn tx_args(&self) -> &TransactionArgs {
         &self.tx_args
@@ -124,6 +151,11 @@ impl ExecutedTransaction {
         &self.advice_witness
     }
 
+    /// Returns a structured description of the effects of this transaction.
+    pub fn diff(&self) -> TransactionDiff {
+        self.into()
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -138,6 +170,52 @@ impl ExecutedTransaction {
 
         (self.account_delta, self.tx_outputs, tx_witness)
     }
+
+    // SERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads an executed transaction serialized via [Serializable::to_bytes()] from the provided
+    /// bytes, and returns the hash of its program together with the remaining components of the
+    /// transaction, in the order expected by [ExecutedTransaction::new()].
+    ///
+    /// The program of the transaction is expected to be recompiled from the returned inputs and
+    /// arguments, and the hash of the recompiled program must be checked against the returned
+    /// hash before the transaction is instantiated.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialized executed transaction.
+    #[allow(clippy::type_complexity)]
+    pub fn read_without_program(
+        bytes: &[u8],
+    ) -> Result<
+        (
+            Digest,
+            TransactionInputs,
+            TransactionOutputs,
+            AccountDelta,
+            TransactionArgs,
+            AdviceInputs,
+        ),
+        DeserializationError,
+    > {
+        let SerializedExecutedTransaction { witness, tx_outputs, account_delta } =
+            SerializedExecutedTransaction::read_from_bytes(bytes)?;
+
+        if witness.tx_inputs.account().id() != tx_outputs.account.id() {
+            return Err(DeserializationError::InvalidValue(
+                "input and output account IDs are not the same".into(),
+            ));
+        }
+
+        Ok((
+            witness.program_hash,
+            witness.tx_inputs,
+            tx_outputs,
+            account_delta,
+            witness.tx_args,
+            witness.advice_witness,
+        ))
+    }
 }
 
 impl From<ExecutedTransaction> for TransactionWitness {
@@ -146,3 +224,32 @@ impl From<ExecutedTransaction> for TransactionWitness {
         tx_witness
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ExecutedTransaction {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_witness(&self.program, &self.tx_inputs, &self.tx_args, &self.advice_witness, target);
+        self.tx_outputs.write_into(target);
+        self.account_delta.write_into(target);
+    }
+}
+
+/// The components of a serialized executed transaction; the program of the transaction is
+/// represented by its hash.
+struct SerializedExecutedTransaction {
+    witness: SerializedWitness,
+    tx_outputs: TransactionOutputs,
+    account_delta: AccountDelta,
+}
+
+impl Deserializable for SerializedExecutedTransaction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let witness = SerializedWitness::read_from(source)?;
+        let tx_outputs = TransactionOutputs::read_from(source)?;
+        let account_delta = AccountDelta::read_from(source)?;
+
+        Ok(Self { witness, tx_outputs, account_delta })
+    }
+}
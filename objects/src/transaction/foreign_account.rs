@@ -0,0 +1,249 @@
+use super::{BlockHeader, Word};
+use crate::{
+    accounts::{Account, AccountId, AccountStub},
+    block::AccountWitness,
+    crypto::merkle::{InnerNodeInfo, MerklePath},
+    utils::{
+        collections::{BTreeMap, Vec},
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        string::ToString,
+    },
+    TransactionInputError,
+};
+
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// The data of an account, other than the account against which a transaction is executed, which
+/// can be read by the transaction.
+///
+/// Foreign account inputs consist of:
+/// - The stub of the account, i.e., its ID, nonce and the roots of its vault, storage and code.
+/// - The witness of the account in the account database of the block referenced by the
+///   transaction; the transaction kernel uses the witness to authenticate the stub.
+/// - The values and the openings of the storage slots of the account which can be read by the
+///   transaction; reading any other storage slot of a foreign account will fail.
+///
+/// Foreign accounts are read-only: a transaction cannot update their state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignAccountInputs {
+    account: AccountStub,
+    witness: AccountWitness,
+    storage_items: BTreeMap<u8, (Word, MerklePath)>,
+}
+
+impl ForeignAccountInputs {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [ForeignAccountInputs] instantiated from the provided parameters.
+    ///
+    /// The storage items are provided as (slot index, value, opening) tuples, where the opening is
+    /// the path from the slot to the storage root of the account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The witness is not a witness of the provided account stub.
+    /// - Any of the storage openings does not open to the storage root of the account.
+    pub fn new<I>(
+        account: AccountStub,
+        witness: AccountWitness,
+        storage_items: I,
+    ) -> Result<Self, TransactionInputError>
+    where
+        I: IntoIterator<Item = (u8, Word, MerklePath)>,
+    {
+        if witness.account_id() != account.id() || witness.account_hash() != account.hash() {
+            return Err(TransactionInputError::InconsistentForeignAccountWitness(account.id()));
+        }
+
+        let mut items = BTreeMap::new();
+        for (index, value, path) in storage_items {
+            if !path.verify(index as u64, value.into(), &account.storage_root()) {
+                return Err(TransactionInputError::InvalidForeignAccountStorageOpening(
+                    account.id(),
+                    index,
+                ));
+            }
+            items.insert(index, (value, path));
+        }
+
+        Ok(Self { account, witness, storage_items: items })
+    }
+
+    /// Returns new [ForeignAccountInputs] which make the specified storage slots of the provided
+    /// account readable.
+    ///
+    /// # Errors
+    /// Returns an error if the witness is not a witness of the provided account.
+    pub fn from_account(
+        account: &Account,
+        witness: AccountWitness,
+        slots: &[u8],
+    ) -> Result<Self, TransactionInputError> {
+        let storage = account.storage();
+        let storage_items = slots.iter().map(|&index| storage.open(index).into_parts());
+
+        Self::new(account.into(), witness, storage_items)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the foreign account.
+    pub fn id(&self) -> AccountId {
+        self.account.id()
+    }
+
+    /// Returns the stub of the foreign account.
+    pub fn account(&self) -> &AccountStub {
+        &self.account
+    }
+
+    /// Returns the witness of the foreign account in the account database.
+    pub fn witness(&self) -> &AccountWitness {
+        &self.witness
+    }
+
+    /// Returns the value of the storage slot at the specified index, or None if the slot is not
+    /// readable.
+    pub fn get_item(&self, index: u8) -> Option<Word> {
+        self.storage_items.get(&index).map(|(value, _)| *value)
+    }
+
+    /// Returns an iterator over the readable storage slots of the foreign account, as
+    /// (slot index, value) pairs.
+    pub fn storage_items(&self) -> impl Iterator<Item = (u8, Word)> + '_ {
+        self.storage_items.iter().map(|(index, (value, _))| (*index, *value))
+    }
+
+    /// Returns true if the witness of the foreign account opens to the account root of the
+    /// provided block header.
+    pub fn is_valid_for(&self, block_header: &BlockHeader) -> bool {
+        self.witness.is_valid_for(block_header)
+    }
+
+    /// Returns an iterator over the inner nodes of the account witness and of the storage
+    /// openings of the foreign account.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        let account_id: u64 = self.account.id().into();
+        let witness_nodes = self
+            .witness
+            .path()
+            .inner_nodes(account_id, self.account.hash())
+            .expect("account ID is a valid index in the account database");
+
+        let storage_nodes = self.storage_items.iter().flat_map(|(index, (value, path))| {
+            path.inner_nodes(*index as u64, (*value).into())
+                .expect("storage slot index is a valid index in the storage tree")
+        });
+
+        witness_nodes.chain(storage_nodes)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ForeignAccountInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.witness.write_into(target);
+        target.write_usize(self.storage_items.len());
+        for (index, (value, path)) in self.storage_items.iter() {
+            target.write_u8(*index);
+            value.write_into(target);
+            path.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for ForeignAccountInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = AccountStub::read_from(source)?;
+        let witness = AccountWitness::read_from(source)?;
+        let num_items = source.read_usize()?;
+        let mut storage_items = Vec::with_capacity(num_items);
+        for _ in 0..num_items {
+            let index = source.read_u8()?;
+            let value = Word::read_from(source)?;
+            let path = MerklePath::read_from(source)?;
+            storage_items.push((index, value, path));
+        }
+
+        Self::new(account, witness, storage_items)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::ForeignAccountInputs;
+    use crate::{
+        accounts::{
+            Account, AccountCode, AccountId, AccountStorage, SlotItem, StorageSlotType,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assembly::{Assembler, ModuleAst},
+        assets::AssetVault,
+        block::AccountWitness,
+        crypto::merkle::{LeafIndex, SimpleSmt},
+        Felt, TransactionInputError, Word, ACCOUNT_TREE_DEPTH, ONE,
+    };
+
+    const PRICE_SLOT: u8 = 7;
+    const PRICE: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    fn build_account() -> Account {
+        let id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let module = ModuleAst::parse("export.foo push.1 drop end").unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+        let item: SlotItem = (PRICE_SLOT, (StorageSlotType::Value { value_arity: 0 }, PRICE));
+        let storage = AccountStorage::new(vec![item]).unwrap();
+        Account::new(id, AssetVault::default(), storage, code, ONE)
+    }
+
+    #[test]
+    fn foreign_account_inputs() {
+        let account = build_account();
+        let account_db = SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves([(
+            u64::from(account.id()),
+            *account.hash(),
+        )])
+        .unwrap();
+        let path = account_db.open(&LeafIndex::from(account.id())).path;
+        let witness = AccountWitness::new(account.id(), account.hash(), path);
+
+        let inputs =
+            ForeignAccountInputs::from_account(&account, witness.clone(), &[PRICE_SLOT, 8])
+                .unwrap();
+        assert_eq!(inputs.get_item(PRICE_SLOT), Some(PRICE));
+        assert_eq!(inputs.get_item(8), Some(Word::default()));
+        assert_eq!(inputs.get_item(9), None);
+
+        // openings of the storage slots must open to the storage root of the account
+        let path = account.storage().slots().open(&LeafIndex::new(PRICE_SLOT as u64).unwrap()).path;
+        let result = ForeignAccountInputs::new(
+            (&account).into(),
+            witness.clone(),
+            [(PRICE_SLOT, Word::default(), path)],
+        );
+        assert_eq!(
+            result,
+            Err(TransactionInputError::InvalidForeignAccountStorageOpening(
+                account.id(),
+                PRICE_SLOT
+            ))
+        );
+
+        // the witness must be a witness of the account
+        let witness = AccountWitness::new(account.id(), Default::default(), witness.path().clone());
+        assert_eq!(
+            ForeignAccountInputs::from_account(&account, witness, &[]),
+            Err(TransactionInputError::InconsistentForeignAccountWitness(account.id()))
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use super::{AccountId, Digest, ExecutedTransaction, TransactionId};
+use crate::{
+    notes::NoteId,
+    utils::collections::{BTreeMap, BTreeSet, Vec},
+    TransactionGraphError,
+};
+
+// TRANSACTION NODE
+// ================================================================================================
+
+/// Describes how a transaction relates to other transactions: which notes it consumes and
+/// produces, and which account state transition it performs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionNode {
+    id: TransactionId,
+    account_id: AccountId,
+    initial_account_hash: Digest,
+    final_account_hash: Digest,
+    consumed_notes: Vec<NoteId>,
+    produced_notes: Vec<NoteId>,
+}
+
+impl TransactionNode {
+    /// Returns a new [TransactionNode] instantiated from the provided parameters.
+    pub fn new(
+        id: TransactionId,
+        account_id: AccountId,
+        initial_account_hash: Digest,
+        final_account_hash: Digest,
+        consumed_notes: Vec<NoteId>,
+        produced_notes: Vec<NoteId>,
+    ) -> Self {
+        Self {
+            id,
+            account_id,
+            initial_account_hash,
+            final_account_hash,
+            consumed_notes,
+            produced_notes,
+        }
+    }
+
+    /// Returns the ID of the transaction.
+    pub fn id(&self) -> TransactionId {
+        self.id
+    }
+
+    /// Returns the ID of the account against which the transaction is executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the IDs of the notes consumed by the transaction.
+    pub fn consumed_notes(&self) -> &[NoteId] {
+        &self.consumed_notes
+    }
+
+    /// Returns the IDs of the notes produced by the transaction.
+    pub fn produced_notes(&self) -> &[NoteId] {
+        &self.produced_notes
+    }
+
+    /// Returns true if the transaction changes the state of its account.
+    pub fn updates_account(&self) -> bool {
+        self.initial_account_hash != self.final_account_hash
+    }
+}
+
+impl From<&ExecutedTransaction> for TransactionNode {
+    fn from(tx: &ExecutedTransaction) -> Self {
+        Self::new(
+            tx.id(),
+            tx.account_id(),
+            tx.initial_account().hash(),
+            tx.final_account().hash(),
+            tx.input_notes().iter().map(|note| note.id()).collect(),
+            tx.output_notes().iter().map(|note| note.id()).collect(),
+        )
+    }
+}
+
+// TRANSACTION GRAPH
+// ================================================================================================
+
+/// A dependency graph over a set of transactions.
+///
+/// A transaction depends on another transaction if:
+/// - It consumes a note produced by the other transaction.
+/// - It is executed against the account state produced by the other transaction.
+///
+/// The graph rejects sets of transactions which cannot all be applied, i.e., sets in which the
+/// same note is consumed by several transactions, several transactions update the same account
+/// state, or the dependencies form a cycle. For valid sets, the graph provides an execution order
+/// in which every transaction comes after all transactions it depends on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionGraph {
+    nodes: Vec<TransactionNode>,
+    dependencies: Vec<BTreeSet<usize>>,
+    execution_order: Vec<usize>,
+}
+
+impl TransactionGraph {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionGraph] built from the provided transactions.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same transaction is provided more than once.
+    /// - The same note is consumed by more than one transaction.
+    /// - The same note is produced by more than one transaction.
+    /// - More than one transaction updates the same state of an account.
+    /// - The dependencies between the transactions form a cycle.
+    pub fn new(nodes: Vec<TransactionNode>) -> Result<Self, TransactionGraphError> {
+        let mut tx_ids = BTreeSet::new();
+        let mut consumers: BTreeMap<NoteId, usize> = BTreeMap::new();
+        let mut producers: BTreeMap<NoteId, usize> = BTreeMap::new();
+        let mut state_updates: BTreeMap<(AccountId, Digest), usize> = BTreeMap::new();
+
+        for (index, node) in nodes.iter().enumerate() {
+            if !tx_ids.insert(node.id) {
+                return Err(TransactionGraphError::DuplicateTransaction(node.id));
+            }
+
+            for &note_id in node.consumed_notes.iter() {
+                if let Some(&other) = consumers.get(&note_id) {
+                    return Err(TransactionGraphError::NoteConsumedTwice {
+                        note_id,
+                        first: nodes[other].id,
+                        second: node.id,
+                    });
+                }
+                consumers.insert(note_id, index);
+            }
+
+            for &note_id in node.produced_notes.iter() {
+                if let Some(&other) = producers.get(&note_id) {
+                    return Err(TransactionGraphError::NoteProducedTwice {
+                        note_id,
+                        first: nodes[other].id,
+                        second: node.id,
+                    });
+                }
+                producers.insert(note_id, index);
+            }
+
+            if node.updates_account() {
+                let key = (node.account_id, node.initial_account_hash);
+                if let Some(&other) = state_updates.get(&key) {
+                    return Err(TransactionGraphError::ConflictingAccountUpdates {
+                        account_id: node.account_id,
+                        first: nodes[other].id,
+                        second: node.id,
+                    });
+                }
+                state_updates.insert(key, index);
+            }
+        }
+
+        // build the dependencies of every transaction
+        let mut dependencies = vec![BTreeSet::new(); nodes.len()];
+        for (index, node) in nodes.iter().enumerate() {
+            for note_id in node.consumed_notes.iter() {
+                if let Some(&producer) = producers.get(note_id) {
+                    dependencies[index].insert(producer);
+                }
+            }
+        }
+        for (index, node) in nodes.iter().enumerate().filter(|(_, node)| node.updates_account()) {
+            if let Some(&next) = state_updates.get(&(node.account_id, node.final_account_hash)) {
+                dependencies[next].insert(index);
+            }
+        }
+
+        let execution_order = compute_execution_order(&nodes, &dependencies)?;
+
+        Ok(Self { nodes, dependencies, execution_order })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the transactions in this graph, in the order in which they were provided.
+    pub fn nodes(&self) -> &[TransactionNode] {
+        &self.nodes
+    }
+
+    /// Returns the IDs of the transactions the specified transaction directly depends on, or None
+    /// if the transaction is not in this graph.
+    pub fn dependencies(&self, tx_id: TransactionId) -> Option<Vec<TransactionId>> {
+        let index = self.nodes.iter().position(|node| node.id == tx_id)?;
+        Some(self.dependencies[index].iter().map(|&dep| self.nodes[dep].id).collect())
+    }
+
+    /// Returns the IDs of all transactions in an order in which every transaction comes after all
+    /// transactions it depends on.
+    ///
+    /// Among independent transactions, the order in which the transactions were provided is
+    /// preserved.
+    pub fn execution_order(&self) -> Vec<TransactionId> {
+        self.execution_order.iter().map(|&index| self.nodes[index].id).collect()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the indexes of the provided nodes sorted topologically according to the provided
+/// dependencies.
+///
+/// # Errors
+/// Returns an error if the dependencies form a cycle.
+fn compute_execution_order(
+    nodes: &[TransactionNode],
+    dependencies: &[BTreeSet<usize>],
+) -> Result<Vec<usize>, TransactionGraphError> {
+    let mut num_pending: Vec<usize> = dependencies.iter().map(|deps| deps.len()).collect();
+    let mut dependents = vec![Vec::new(); nodes.len()];
+    for (index, deps) in dependencies.iter().enumerate() {
+        deps.iter().for_each(|&dep| dependents[dep].push(index));
+    }
+
+    let mut ready: BTreeSet<usize> =
+        (0..nodes.len()).filter(|&index| num_pending[index] == 0).collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(index) = ready.pop_first() {
+        order.push(index);
+        for &dependent in dependents[index].iter() {
+            num_pending[dependent] -= 1;
+            if num_pending[dependent] == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+
+    // transactions which were never ready are part of a cycle
+    if let Some(index) = (0..nodes.len()).find(|&index| num_pending[index] > 0) {
+        return Err(TransactionGraphError::DependencyCycle(nodes[index].id));
+    }
+
+    Ok(order)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountId, Digest, NoteId, TransactionGraph, TransactionId, TransactionNode};
+    use crate::{
+        accounts::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, Felt, TransactionGraphError,
+    };
+
+    fn digest(value: u64) -> Digest {
+        [Felt::new(value); 4].into()
+    }
+
+    fn node(
+        id: u64,
+        states: (u64, u64),
+        consumed_notes: &[u64],
+        produced_notes: &[u64],
+    ) -> TransactionNode {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        TransactionNode::new(
+            TransactionId::from(digest(id)),
+            account_id,
+            digest(states.0),
+            digest(states.1),
+            consumed_notes.iter().map(|&note| NoteId::from(digest(note))).collect(),
+            produced_notes.iter().map(|&note| NoteId::from(digest(note))).collect(),
+        )
+    }
+
+    #[test]
+    fn transaction_graph_execution_order() {
+        // tx 1 consumes a note produced by tx 3, tx 3 is executed against the state produced by
+        // tx 2
+        let graph = TransactionGraph::new(vec![
+            node(1, (20, 20), &[100], &[]),
+            node(2, (10, 11), &[], &[]),
+            node(3, (11, 12), &[], &[100]),
+        ])
+        .unwrap();
+
+        let order = graph.execution_order();
+        assert_eq!(order, [2, 3, 1].map(|id| TransactionId::from(digest(id))));
+        assert_eq!(
+            graph.dependencies(TransactionId::from(digest(1))),
+            Some(vec![TransactionId::from(digest(3))])
+        );
+    }
+
+    #[test]
+    fn transaction_graph_conflicts() {
+        // the same note is consumed twice
+        let result = TransactionGraph::new(vec![
+            node(1, (20, 20), &[100], &[]),
+            node(2, (30, 30), &[100], &[]),
+        ]);
+        assert!(matches!(result, Err(TransactionGraphError::NoteConsumedTwice { .. })));
+
+        // the same account state is updated twice
+        let result =
+            TransactionGraph::new(vec![node(1, (10, 11), &[], &[]), node(2, (10, 12), &[], &[])]);
+        assert!(matches!(result, Err(TransactionGraphError::ConflictingAccountUpdates { .. })));
+
+        // the transactions depend on each other
+        let result = TransactionGraph::new(vec![
+            node(1, (20, 20), &[100], &[101]),
+            node(2, (30, 30), &[101], &[100]),
+        ]);
+        assert!(matches!(result, Err(TransactionGraphError::DependencyCycle(_))));
+    }
+}
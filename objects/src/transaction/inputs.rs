@@ -1,15 +1,17 @@
 use core::fmt::Debug;
 
-use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
+use super::{BlockHeader, ChainMmr, Digest, Felt, ForeignAccountInputs, Hasher, Word};
 use crate::{
     accounts::{validate_account_seed, Account},
-    notes::{Note, NoteId, NoteInclusionProof, NoteOrigin, Nullifier},
+    assets::PartialVault,
+    crypto::merkle::MerklePath,
+    notes::{Note, NoteEnvelope, NoteId, NoteInclusionProof, NoteOrigin, Nullifier},
     utils::{
         collections::{self, BTreeSet, Vec},
         serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
         string::ToString,
     },
-    TransactionInputError, MAX_INPUT_NOTES_PER_TX,
+    TransactionInputError, MAX_FOREIGN_ACCOUNTS_PER_TX, MAX_INPUT_NOTES_PER_TX,
 };
 
 // TRANSACTION INPUTS
@@ -23,6 +25,8 @@ pub struct TransactionInputs {
     block_header: BlockHeader,
     block_chain: ChainMmr,
     input_notes: InputNotes,
+    partial_vault: Option<PartialVault>,
+    foreign_accounts: Vec<ForeignAccountInputs>,
 }
 
 impl TransactionInputs {
@@ -30,10 +34,19 @@ impl TransactionInputs {
     // --------------------------------------------------------------------------------------------
     /// Returns new [TransactionInputs] instantiated with the specified parameters.
     ///
+    /// Inclusion proofs of the input notes are not verified by the constructor because this may
+    /// be expensive for transactions consuming many notes; use [TransactionInputs::validate()]
+    /// to verify them before the transaction is executed.
+    ///
+    /// The chain MMR is not required to contain the blocks of the authenticated input notes; the
+    /// missing blocks (see [TransactionInputs::untracked_note_blocks()]) can be added with
+    /// [TransactionInputs::with_block_headers()] before the inputs are validated.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - For a new account, account seed is not provided or the provided seed is invalid.
     /// - For an existing account, account seed was provided.
+    /// - The chain MMR is inconsistent with the block header.
     pub fn new(
         account: Account,
         account_seed: Option<Word>,
@@ -41,60 +54,189 @@ impl TransactionInputs {
         block_chain: ChainMmr,
         input_notes: InputNotes,
     ) -> Result<Self, TransactionInputError> {
-        match (account.is_new(), account_seed) {
-            (true, Some(seed)) => validate_account_seed(&account, seed)
-                .map_err(TransactionInputError::InvalidAccountSeed),
-            (true, None) => Err(TransactionInputError::AccountSeedNotProvidedForNewAccount),
-            (false, Some(_)) => Err(TransactionInputError::AccountSeedProvidedForExistingAccount),
-            (false, None) => Ok(()),
-        }?;
-
-        // make sure block_chain and block_header are consistent
-
-        let block_num = block_header.block_num();
-        if block_chain.chain_length() != block_header.block_num() as usize {
-            return Err(TransactionInputError::InconsistentChainLength {
-                expected: block_header.block_num(),
-                actual: block_chain.chain_length() as u32,
+        validate_account_seed_for(&account, account_seed)?;
+        validate_block_chain(&block_header, &block_chain)?;
+
+        Ok(Self {
+            account,
+            account_seed,
+            block_header,
+            block_chain,
+            input_notes,
+            partial_vault: None,
+            foreign_accounts: Vec::new(),
+        })
+    }
+
+    /// Returns these transaction inputs with the provided partial vault of the account.
+    ///
+    /// When a partial vault is provided, only the vault entries tracked by the partial vault are
+    /// made available to the transaction kernel. This way, accounts with very large vaults do not
+    /// need to load their entire vault for every transaction; however, executing a transaction
+    /// which accesses a vault entry not tracked by the partial vault will fail.
+    ///
+    /// # Errors
+    /// Returns an error if the root of the partial vault is different from the vault root of the
+    /// account.
+    pub fn with_partial_vault(
+        mut self,
+        partial_vault: PartialVault,
+    ) -> Result<Self, TransactionInputError> {
+        let vault_root = self.account.vault().commitment();
+        if partial_vault.commitment() != vault_root {
+            return Err(TransactionInputError::PartialVaultRootMismatch {
+                expected: vault_root,
+                actual: partial_vault.commitment(),
             });
         }
 
-        if block_chain.peaks().hash_peaks() != block_header.chain_root() {
-            return Err(TransactionInputError::InconsistentChainRoot {
-                expected: block_header.chain_root(),
-                actual: block_chain.peaks().hash_peaks(),
+        self.partial_vault = Some(partial_vault);
+        Ok(self)
+    }
+
+    /// Returns these transaction inputs with the provided foreign accounts.
+    ///
+    /// The storage of foreign accounts can be read (but not updated) by the transaction; the
+    /// foreign accounts are authenticated against the account root of the block referenced by the
+    /// transaction.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of foreign accounts is greater than 64.
+    /// - The list of foreign accounts contains duplicates.
+    /// - Any of the foreign accounts is not in the account database of the block referenced by
+    ///   the transaction.
+    pub fn with_foreign_accounts(
+        mut self,
+        foreign_accounts: Vec<ForeignAccountInputs>,
+    ) -> Result<Self, TransactionInputError> {
+        if foreign_accounts.len() > MAX_FOREIGN_ACCOUNTS_PER_TX {
+            return Err(TransactionInputError::TooManyForeignAccounts {
+                max: MAX_FOREIGN_ACCOUNTS_PER_TX,
+                actual: foreign_accounts.len(),
             });
         }
 
-        // make sure that block_chain has authentication paths for all input notes; for input notes
-        // which were created in the current block we skip this check because their authentication
-        // paths are derived implicitly
-        for note in input_notes.iter() {
-            let note_block_num = note.origin().block_num;
-
-            let block_header = if note_block_num == block_num {
-                &block_header
-            } else {
-                match block_chain.get_block(note_block_num) {
-                    Some(block_header) => block_header,
-                    None => Err(TransactionInputError::InputNoteBlockNotInChainMmr(note.id()))?,
-                }
-            };
-
-            // this check may have non-negligible performance impact as we need to verify inclusion
-            // proofs for all notes; TODO: consider enabling this via a feature flag
+        let mut seen_accounts = BTreeSet::new();
+        for account in foreign_accounts.iter() {
+            if !seen_accounts.insert(account.id()) {
+                return Err(TransactionInputError::DuplicateForeignAccount(account.id()));
+            }
+
+            if !account.is_valid_for(&self.block_header) {
+                return Err(TransactionInputError::ForeignAccountNotInBlock(account.id()));
+            }
+        }
+
+        self.foreign_accounts = foreign_accounts;
+        Ok(self)
+    }
+
+    /// Returns these transaction inputs with the provided unauthenticated notes appended to the
+    /// input notes.
+    ///
+    /// Unauthenticated notes are consumed without proofs of their inclusion in the chain (e.g.,
+    /// notes created in the same block, or notes transmitted off-chain before being included in a
+    /// block); instead, the transaction commits to their headers, and the notes are authenticated
+    /// by the operator when the transaction is included in a block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The total number of input notes is greater than 1023.
+    /// - Any of the notes is already among the input notes.
+    pub fn with_unauthenticated_notes(
+        mut self,
+        notes: Vec<Note>,
+    ) -> Result<Self, TransactionInputError> {
+        if notes.is_empty() {
+            return Ok(self);
+        }
+
+        let mut input_notes = self.input_notes.into_vec();
+        input_notes.extend(notes.into_iter().map(InputNote::unauthenticated));
+
+        self.input_notes = InputNotes::new(input_notes)?;
+        Ok(self)
+    }
+
+    /// Returns these transaction inputs with the provided block headers added to the chain MMR.
+    ///
+    /// Each header must be accompanied by the authentication path of the block against the peaks
+    /// of the chain MMR, i.e., against the chain root of the block referenced by the transaction.
+    /// This allows a data store to provide a chain MMR tracking only the peaks of the chain, and
+    /// to supply the blocks of the input notes separately.
+    ///
+    /// # Errors
+    /// Returns an error if any of the blocks is not in the chain, is already present in the chain
+    /// MMR, or is provided with an invalid authentication path.
+    pub fn with_block_headers(
+        mut self,
+        block_headers: Vec<(BlockHeader, MerklePath)>,
+    ) -> Result<Self, TransactionInputError> {
+        for (block_header, path) in block_headers {
+            self.block_chain
+                .track_block(block_header, &path)
+                .map_err(TransactionInputError::InvalidChainMmrBlock)?;
+        }
+
+        Ok(self)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that these transaction inputs are consistent, so that the transaction can be
+    /// executed against them.
+    ///
+    /// In addition to the checks performed when the inputs are constructed, this verifies the
+    /// inclusion proofs of all input notes against the note roots of the blocks in which the notes
+    /// were created.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - For a new account, account seed is not provided or the provided seed is invalid.
+    /// - For an existing account, account seed was provided.
+    /// - The chain MMR is inconsistent with the block header.
+    /// - The chain MMR does not contain the block of any of the authenticated input notes.
+    /// - The inclusion proof of any of the authenticated input notes is not valid.
+    /// - The root of the partial vault is different from the vault root of the account.
+    /// - Any of the foreign accounts is not in the account database of the referenced block.
+    pub fn validate(&self) -> Result<(), TransactionInputError> {
+        validate_account_seed_for(&self.account, self.account_seed)?;
+        validate_block_chain(&self.block_header, &self.block_chain)?;
+
+        for note in self.input_notes.iter() {
+            // unauthenticated notes are authenticated by the operator when the transaction is
+            // included in a block
+            let Some(proof) = note.proof() else { continue };
+
+            let block_header =
+                get_note_block(note.id(), proof, &self.block_header, &self.block_chain)?;
             if !note.is_in_block(block_header) {
-                return Err(TransactionInputError::InputNoteNotInBlock(note.id(), note_block_num));
+                return Err(TransactionInputError::InputNoteNotInBlock(
+                    note.id(),
+                    proof.origin().block_num,
+                ));
             }
         }
 
-        Ok(Self {
-            account,
-            account_seed,
-            block_header,
-            block_chain,
-            input_notes,
-        })
+        if let Some(partial_vault) = &self.partial_vault {
+            let vault_root = self.account.vault().commitment();
+            if partial_vault.commitment() != vault_root {
+                return Err(TransactionInputError::PartialVaultRootMismatch {
+                    expected: vault_root,
+                    actual: partial_vault.commitment(),
+                });
+            }
+        }
+
+        for account in self.foreign_accounts.iter() {
+            if !account.is_valid_for(&self.block_header) {
+                return Err(TransactionInputError::ForeignAccountNotInBlock(account.id()));
+            }
+        }
+
+        Ok(())
     }
 
     // PUBLIC ACCESSORS
@@ -126,6 +268,38 @@ impl TransactionInputs {
         &self.input_notes
     }
 
+    /// Returns the partial vault of the account, if the transaction is to be executed against a
+    /// partial view of the account vault.
+    pub fn partial_vault(&self) -> Option<&PartialVault> {
+        self.partial_vault.as_ref()
+    }
+
+    /// Returns the foreign accounts which can be read by the transaction.
+    pub fn foreign_accounts(&self) -> &[ForeignAccountInputs] {
+        &self.foreign_accounts
+    }
+
+    /// Returns the numbers of the blocks, sorted and without duplicates, which are needed to
+    /// authenticate the input notes but are not present in the chain MMR.
+    ///
+    /// Notes created in the block referenced by the transaction are authenticated against its
+    /// header, and unauthenticated notes are not authenticated by the transaction at all; thus,
+    /// the blocks of such notes are never returned.
+    pub fn untracked_note_blocks(&self) -> Vec<u32> {
+        let block_nums: BTreeSet<u32> = self
+            .input_notes
+            .iter()
+            .filter_map(|note| note.proof())
+            .map(|proof| proof.origin().block_num)
+            .filter(|&block_num| {
+                block_num != self.block_header.block_num()
+                    && !self.block_chain.contains_block(block_num)
+            })
+            .collect();
+
+        block_nums.into_iter().collect()
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -141,23 +315,160 @@ impl TransactionInputs {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the seed is provided if and only if the account is new, and that the provided
+/// seed is valid for the account.
+fn validate_account_seed_for(
+    account: &Account,
+    account_seed: Option<Word>,
+) -> Result<(), TransactionInputError> {
+    match (account.is_new(), account_seed) {
+        (true, Some(seed)) => {
+            validate_account_seed(account, seed).map_err(TransactionInputError::InvalidAccountSeed)
+        },
+        (true, None) => Err(TransactionInputError::AccountSeedNotProvidedForNewAccount),
+        (false, Some(_)) => Err(TransactionInputError::AccountSeedProvidedForExistingAccount),
+        (false, None) => Ok(()),
+    }
+}
+
+/// Checks that the chain MMR commits to all blocks preceding the block with the specified header.
+fn validate_block_chain(
+    block_header: &BlockHeader,
+    block_chain: &ChainMmr,
+) -> Result<(), TransactionInputError> {
+    if block_chain.chain_length() != block_header.block_num() as usize {
+        return Err(TransactionInputError::InconsistentChainLength {
+            expected: block_header.block_num(),
+            actual: block_chain.chain_length() as u32,
+        });
+    }
+
+    if block_chain.peaks().hash_peaks() != block_header.chain_root() {
+        return Err(TransactionInputError::InconsistentChainRoot {
+            expected: block_header.chain_root(),
+            actual: block_chain.peaks().hash_peaks(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the header of the block in which the note with the specified inclusion proof was
+/// created.
+///
+/// For notes created in the block referenced by the transaction, the header of the referenced
+/// block is returned because their authentication paths are derived implicitly; for all other
+/// notes, the block must be present in the chain MMR.
+fn get_note_block<'a>(
+    note_id: NoteId,
+    proof: &NoteInclusionProof,
+    block_header: &'a BlockHeader,
+    block_chain: &'a ChainMmr,
+) -> Result<&'a BlockHeader, TransactionInputError> {
+    let note_block_num = proof.origin().block_num;
+    if note_block_num == block_header.block_num() {
+        return Ok(block_header);
+    }
+
+    block_chain
+        .get_block(note_block_num)
+        .ok_or(TransactionInputError::InputNoteBlockNotInChainMmr(note_id))
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.account_seed.write_into(target);
+        self.block_header.write_into(target);
+        self.block_chain.write_into(target);
+        self.input_notes.write_into(target);
+        self.partial_vault.write_into(target);
+        target.write_u8(self.foreign_accounts.len() as u8);
+        target.write_many(&self.foreign_accounts);
+    }
+}
+
+impl Deserializable for TransactionInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = Account::read_from(source)?;
+        let account_seed = <Option<Word>>::read_from(source)?;
+        let block_header = BlockHeader::read_from(source)?;
+        let block_chain = ChainMmr::read_from(source)?;
+        let input_notes = InputNotes::read_from(source)?;
+        let partial_vault = <Option<PartialVault>>::read_from(source)?;
+        let num_foreign_accounts = source.read_u8()?;
+        let foreign_accounts =
+            source.read_many::<ForeignAccountInputs>(num_foreign_accounts.into())?;
+
+        let mut tx_inputs =
+            Self::new(account, account_seed, block_header, block_chain, input_notes)
+                .and_then(|tx_inputs| tx_inputs.with_foreign_accounts(foreign_accounts))
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        if let Some(partial_vault) = partial_vault {
+            tx_inputs = tx_inputs
+                .with_partial_vault(partial_vault)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        Ok(tx_inputs)
+    }
+}
+
 // TO NULLIFIER TRAIT
 // ================================================================================================
 
 /// Defines how a note object can be reduced to a nullifier.
 ///
-/// This trait is implemented on both [InputNote] and [Nullifier] so that we can treat them
-/// generically as [InputNotes].
+/// This trait is implemented on [InputNote], [InputNoteCommitment] and [Nullifier] so that we can
+/// treat them generically as [InputNotes].
 pub trait ToNullifier:
     Debug + Clone + PartialEq + Eq + Serializable + Deserializable + Sized
 {
+    /// Returns the nullifier of the note.
     fn nullifier(&self) -> Nullifier;
+
+    /// Returns the hash of the note header for unauthenticated notes, and [ZERO; 4] for notes
+    /// which were authenticated against the chain during transaction execution.
+    ///
+    /// The default implementation treats the note as authenticated.
+    fn header_hash(&self) -> Word {
+        Word::default()
+    }
 }
 
 impl ToNullifier for InputNote {
     fn nullifier(&self) -> Nullifier {
         self.note.nullifier()
     }
+
+    fn header_hash(&self) -> Word {
+        match self.proof {
+            Some(_) => Word::default(),
+            None => self.note.authentication_hash().into(),
+        }
+    }
+}
+
+impl ToNullifier for InputNoteCommitment {
+    fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    fn header_hash(&self) -> Word {
+        match self.header {
+            Some(header) => {
+                Hasher::merge(&[header.note_id().inner(), Word::from(header.metadata()).into()])
+                    .into()
+            },
+            None => Word::default(),
+        }
+    }
 }
 
 impl ToNullifier for Nullifier {
@@ -166,20 +477,17 @@ impl ToNullifier for Nullifier {
     }
 }
 
-impl From<InputNotes> for InputNotes<Nullifier> {
+impl From<InputNotes> for InputNotes<InputNoteCommitment> {
     fn from(value: InputNotes) -> Self {
-        Self {
-            notes: value.notes.iter().map(|note| note.nullifier()).collect(),
-            commitment: build_input_notes_commitment(&value.notes),
-        }
+        (&value).into()
     }
 }
 
-impl From<&InputNotes> for InputNotes<Nullifier> {
+impl From<&InputNotes> for InputNotes<InputNoteCommitment> {
     fn from(value: &InputNotes) -> Self {
         Self {
-            notes: value.notes.iter().map(|note| note.nullifier()).collect(),
-            commitment: build_input_notes_commitment(&value.notes),
+            notes: value.notes.iter().map(InputNoteCommitment::from).collect(),
+            commitment: value.commitment,
         }
     }
 }
@@ -191,8 +499,8 @@ impl From<&InputNotes> for InputNotes<Nullifier> {
 /// not consume any notes.
 ///
 /// For the purposes of this struct, anything that can be reduced to a [Nullifier] can be an input
-/// note. However, [ToNullifier] trait is currently implemented only for [InputNote] and [Nullifier],
-/// and so these are the only two allowed input note types.
+/// note. However, [ToNullifier] trait is currently implemented only for [InputNote],
+/// [InputNoteCommitment] and [Nullifier], and so these are the only allowed input note types.
 #[derive(Debug, Clone)]
 pub struct InputNotes<T: ToNullifier = InputNote> {
     notes: Vec<T>,
@@ -319,8 +627,9 @@ impl<T: ToNullifier> Deserializable for InputNotes<T> {
 
 /// Returns the commitment to the input notes represented by the specified nullifiers.
 ///
-/// For a non-empty list of notes, this is a sequential hash of all (nullifier, ZERO) pairs for
-/// the notes consumed in the transaction. For an empty list, [ZERO; 4] is returned.
+/// For a non-empty list of notes, this is a sequential hash of all (nullifier, HEADER_HASH) pairs
+/// for the notes consumed in the transaction, where HEADER_HASH is [ZERO; 4] for notes which were
+/// authenticated during transaction execution. For an empty list, [ZERO; 4] is returned.
 pub fn build_input_notes_commitment<T: ToNullifier>(notes: &[T]) -> Digest {
     if notes.is_empty() {
         return Digest::default();
@@ -329,7 +638,7 @@ pub fn build_input_notes_commitment<T: ToNullifier>(notes: &[T]) -> Digest {
     let mut elements: Vec<Felt> = Vec::new();
     for note in notes {
         elements.extend_from_slice(note.nullifier().as_elements());
-        elements.extend_from_slice(&Word::default());
+        elements.extend_from_slice(&note.header_hash());
     }
     Hasher::hash_elements(&elements)
 }
@@ -338,17 +647,28 @@ pub fn build_input_notes_commitment<T: ToNullifier>(notes: &[T]) -> Digest {
 // ================================================================================================
 
 /// An input note for a transaction.
+///
+/// An input note is either authenticated, in which case it comes with a proof of its inclusion in
+/// the chain which the transaction kernel verifies, or unauthenticated. Unauthenticated notes
+/// (e.g., notes created in the same block, or notes transmitted off-chain before being included
+/// in a block) are committed to by the transaction via their headers, and are authenticated by
+/// the operator when the transaction is included in a block.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct InputNote {
     note: Note,
-    proof: NoteInclusionProof,
+    proof: Option<NoteInclusionProof>,
 }
 
 impl InputNote {
-    /// Returns a new instance of an [InputNote] with the specified note and proof.
+    /// Returns a new instance of an authenticated [InputNote] with the specified note and proof.
     pub fn new(note: Note, proof: NoteInclusionProof) -> Self {
-        Self { note, proof }
+        Self { note, proof: Some(proof) }
+    }
+
+    /// Returns a new instance of an unauthenticated [InputNote] with the specified note.
+    pub fn unauthenticated(note: Note) -> Self {
+        Self { note, proof: None }
     }
 
     /// Returns the ID of the note.
@@ -361,21 +681,36 @@ impl InputNote {
         &self.note
     }
 
-    /// Returns a reference to the inclusion proof of the note.
-    pub fn proof(&self) -> &NoteInclusionProof {
-        &self.proof
+    /// Returns the nullifier of the note, which is published when the note is consumed.
+    pub fn nullifier(&self) -> Nullifier {
+        self.note.nullifier()
     }
 
-    /// Returns a reference to the origin of the note.
-    pub fn origin(&self) -> &NoteOrigin {
-        self.proof.origin()
+    /// Returns a reference to the inclusion proof of the note, or None if the note is
+    /// unauthenticated.
+    pub fn proof(&self) -> Option<&NoteInclusionProof> {
+        self.proof.as_ref()
+    }
+
+    /// Returns a reference to the origin of the note, or None if the note is unauthenticated.
+    pub fn origin(&self) -> Option<&NoteOrigin> {
+        self.proof.as_ref().map(|proof| proof.origin())
+    }
+
+    /// Returns true if the note comes with a proof of its inclusion in the chain.
+    pub fn is_authenticated(&self) -> bool {
+        self.proof.is_some()
     }
 
     /// Returns true if this note belongs to the note tree of the specified block.
+    ///
+    /// Unauthenticated notes are never considered to belong to a block.
     fn is_in_block(&self, block_header: &BlockHeader) -> bool {
-        let note_index = self.origin().node_index.value();
+        let Some(proof) = &self.proof else { return false };
+
+        let note_index = proof.origin().node_index.value();
         let note_hash = self.note.authentication_hash();
-        self.proof.note_path().verify(note_index, note_hash, &block_header.note_root())
+        proof.note_path().verify(note_index, note_hash, &block_header.note_root())
     }
 }
 
@@ -392,8 +727,85 @@ impl Serializable for InputNote {
 impl Deserializable for InputNote {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let note = Note::read_from(source)?;
-        let proof = NoteInclusionProof::read_from(source)?;
+        let proof = <Option<NoteInclusionProof>>::read_from(source)?;
 
         Ok(Self { note, proof })
     }
 }
+
+// INPUT NOTE COMMITMENT
+// ================================================================================================
+
+/// The public commitment of a transaction to one of its input notes.
+///
+/// Contains the nullifier of the note and, for unauthenticated notes, the header of the note
+/// (its ID and metadata), which the operator needs to authenticate the note when the transaction
+/// is included in a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputNoteCommitment {
+    nullifier: Nullifier,
+    header: Option<NoteEnvelope>,
+}
+
+impl InputNoteCommitment {
+    /// Returns a new [InputNoteCommitment] for an authenticated note with the specified nullifier.
+    pub fn new(nullifier: Nullifier) -> Self {
+        Self { nullifier, header: None }
+    }
+
+    /// Returns a new [InputNoteCommitment] for an unauthenticated note with the specified nullifier
+    /// and header.
+    pub fn unauthenticated(nullifier: Nullifier, header: NoteEnvelope) -> Self {
+        Self { nullifier, header: Some(header) }
+    }
+
+    /// Returns the nullifier of the note.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the header of the note, or None if the note was authenticated during transaction
+    /// execution.
+    pub fn header(&self) -> Option<&NoteEnvelope> {
+        self.header.as_ref()
+    }
+
+    /// Returns true if the note was authenticated during transaction execution.
+    pub fn is_authenticated(&self) -> bool {
+        self.header.is_none()
+    }
+}
+
+impl From<&InputNote> for InputNoteCommitment {
+    fn from(note: &InputNote) -> Self {
+        match note.proof {
+            Some(_) => Self::new(note.note.nullifier()),
+            None => Self::unauthenticated(note.note.nullifier(), note.note().into()),
+        }
+    }
+}
+
+impl From<InputNote> for InputNoteCommitment {
+    fn from(note: InputNote) -> Self {
+        (&note).into()
+    }
+}
+
+// SERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for InputNoteCommitment {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.nullifier.write_into(target);
+        self.header.write_into(target);
+    }
+}
+
+impl Deserializable for InputNoteCommitment {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let nullifier = Nullifier::read_from(source)?;
+        let header = <Option<NoteEnvelope>>::read_from(source)?;
+
+        Ok(Self { nullifier, header })
+    }
+}
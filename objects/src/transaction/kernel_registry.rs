@@ -0,0 +1,76 @@
+use super::{Digest, ProgramInfo};
+use crate::utils::collections::BTreeMap;
+
+// KERNEL REGISTRY
+// ================================================================================================
+
+/// A registry of transaction kernel programs, keyed by kernel version.
+///
+/// The registry is the single source of the transaction kernel program hashes: the transaction
+/// prover uses it to determine the kernel a transaction is proven against, and the transaction
+/// verifier uses it to determine the set of kernels it accepts. Keeping the kernels of previous
+/// versions in the registry makes it possible to accept transactions proven against them while a
+/// kernel upgrade is rolled out.
+///
+/// The [ProgramInfo] of each kernel is stored, rather than only its program hash, because
+/// verifying a transaction proof requires the procedures of the kernel as well.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KernelRegistry {
+    kernels: BTreeMap<u8, ProgramInfo>,
+}
+
+impl KernelRegistry {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [KernelRegistry].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the kernel program described by the provided [ProgramInfo] to this registry under the
+    /// specified version, replacing the kernel previously registered under this version, if any.
+    pub fn with_kernel(mut self, version: u8, program_info: ProgramInfo) -> Self {
+        self.kernels.insert(version, program_info);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [ProgramInfo] of the kernel registered under the specified version.
+    pub fn get(&self, version: u8) -> Option<&ProgramInfo> {
+        self.kernels.get(&version)
+    }
+
+    /// Returns the program hash of the kernel registered under the specified version.
+    pub fn program_hash(&self, version: u8) -> Option<Digest> {
+        self.kernels.get(&version).map(|program_info| *program_info.program_hash())
+    }
+
+    /// Returns the version and the [ProgramInfo] of the kernel with the specified program hash.
+    pub fn find(&self, program_hash: Digest) -> Option<(u8, &ProgramInfo)> {
+        self.kernels
+            .iter()
+            .find(|(_, program_info)| *program_info.program_hash() == program_hash)
+            .map(|(&version, program_info)| (version, program_info))
+    }
+
+    /// Returns true if a kernel with the specified program hash is registered.
+    pub fn contains(&self, program_hash: Digest) -> bool {
+        self.find(program_hash).is_some()
+    }
+
+    /// Returns the version and the [ProgramInfo] of the kernel with the highest version.
+    pub fn latest(&self) -> Option<(u8, &ProgramInfo)> {
+        self.kernels
+            .iter()
+            .next_back()
+            .map(|(&version, program_info)| (version, program_info))
+    }
+
+    /// Returns an iterator over the registered kernels, ordered by version.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &ProgramInfo)> {
+        self.kernels.iter().map(|(&version, program_info)| (version, program_info))
+    }
+}
@@ -1,13 +1,18 @@
 use super::{
     accounts::{Account, AccountDelta, AccountId, AccountStub},
     notes::{NoteEnvelope, Nullifier},
-    vm::{AdviceInputs, Program},
+    vm::{AdviceInputs, Program, ProgramInfo},
     BlockHeader, Digest, Felt, Hasher, Word, WORD_SIZE, ZERO,
 };
 
+mod batch;
 mod chain_mmr;
+mod diff;
 mod executed_tx;
+mod foreign_account;
+mod graph;
 mod inputs;
+mod kernel_registry;
 mod outputs;
 mod prepared_tx;
 mod proven_tx;
@@ -15,12 +20,17 @@ mod transaction_id;
 mod tx_args;
 mod tx_witness;
 
+pub use batch::{BatchAccountUpdate, TransactionBatch};
 pub use chain_mmr::ChainMmr;
+pub use diff::{StorageSlotChange, TransactionDiff};
 pub use executed_tx::ExecutedTransaction;
-pub use inputs::{InputNote, InputNotes, TransactionInputs};
+pub use foreign_account::ForeignAccountInputs;
+pub use graph::{TransactionGraph, TransactionNode};
+pub use inputs::{InputNote, InputNoteCommitment, InputNotes, TransactionInputs};
+pub use kernel_registry::KernelRegistry;
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use prepared_tx::PreparedTransaction;
-pub use proven_tx::ProvenTransaction;
+pub use proven_tx::{AccountDetails, ProvenTransaction};
 pub use transaction_id::TransactionId;
-pub use tx_args::{TransactionArgs, TransactionScript};
+pub use tx_args::{TransactionArgs, TransactionArgsBuilder, TransactionScript};
 pub use tx_witness::TransactionWitness;
@@ -2,23 +2,66 @@ use core::fmt::Debug;
 
 use crate::{
     accounts::AccountStub,
-    notes::{Note, NoteAssets, NoteEnvelope, NoteId, NoteMetadata},
+    assets::FungibleAsset,
+    notes::{Note, NoteAssets, NoteEnvelope, NoteId, NoteMetadata, PartialNote},
     utils::{
         collections::{self, BTreeSet, Vec},
+        format,
         serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
         string::ToString,
     },
     Digest, Felt, Hasher, TransactionOutputError, Word, MAX_OUTPUT_NOTES_PER_TX,
+    OUTPUT_NOTES_COMMITMENT_CHUNK_SIZE, TX_SCRIPT_OUTPUT_WORDS,
 };
 
 // TRANSACTION OUTPUTS
 // ================================================================================================
 
 /// Describes the result of executing a transaction.
+///
+/// The expiration block number is the number of the last block in which the transaction can be
+/// included; it is [u32::MAX] unless it was lowered by the transaction. The fee is the fungible
+/// asset deducted from the account vault by the transaction kernel, if any.
+///
+/// The transaction script output consists of the [TX_SCRIPT_OUTPUT_WORDS] words at the top of the
+/// stack of the transaction script when the script returns, with the top word first; it is None
+/// if the transaction was executed without a transaction script. Unlike the other outputs, the
+/// transaction script output is not committed to by the transaction proof.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionOutputs {
     pub account: AccountStub,
     pub output_notes: OutputNotes,
+    pub expiration_block_num: u32,
+    pub fee: Option<FungibleAsset>,
+    pub tx_script_output: Option<[Word; TX_SCRIPT_OUTPUT_WORDS]>,
+}
+
+impl Serializable for TransactionOutputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.output_notes.write_into(target);
+        target.write_u32(self.expiration_block_num);
+        self.fee.write_into(target);
+        self.tx_script_output.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionOutputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = AccountStub::read_from(source)?;
+        let output_notes = OutputNotes::read_from(source)?;
+        let expiration_block_num = source.read_u32()?;
+        let fee = <Option<FungibleAsset>>::read_from(source)?;
+        let tx_script_output = <Option<[Word; TX_SCRIPT_OUTPUT_WORDS]>>::read_from(source)?;
+
+        Ok(Self {
+            account,
+            output_notes,
+            expiration_block_num,
+            fee,
+            tx_script_output,
+        })
+    }
 }
 
 // TO ENVELOPE TRAIT
@@ -58,7 +101,7 @@ impl ToEnvelope for NoteEnvelope {
 impl From<OutputNotes> for OutputNotes<NoteEnvelope> {
     fn from(notes: OutputNotes) -> Self {
         Self {
-            notes: notes.notes.iter().map(|note| note.envelope).collect(),
+            notes: notes.notes.iter().map(NoteEnvelope::from).collect(),
             commitment: build_output_notes_commitment(&notes.notes),
         }
     }
@@ -86,7 +129,7 @@ impl<T: ToEnvelope> OutputNotes<T> {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The total number of notes is greater than 1024.
+    /// - The total number of notes is greater than [MAX_OUTPUT_NOTES_PER_TX].
     /// - The vector of notes contains duplicates.
     pub fn new(notes: Vec<T>) -> Result<Self, TransactionOutputError> {
         if notes.len() > MAX_OUTPUT_NOTES_PER_TX {
@@ -113,8 +156,10 @@ impl<T: ToEnvelope> OutputNotes<T> {
 
     /// Returns the commitment to the output notes.
     ///
-    /// The commitment is computed as a sequential hash of (hash, metadata) tuples for the notes
-    /// created in a transaction.
+    /// The notes are split into chunks of [OUTPUT_NOTES_COMMITMENT_CHUNK_SIZE] notes, and the
+    /// commitment to each chunk is computed as a sequential hash of (hash, metadata) tuples for the
+    /// notes in the chunk. If all notes fit into a single chunk, the commitment is the commitment
+    /// to this chunk; otherwise, it is a sequential hash of the chunk commitments.
     pub fn commitment(&self) -> Digest {
         self.commitment
     }
@@ -190,9 +235,34 @@ impl<T: ToEnvelope> Deserializable for OutputNotes<T> {
 
 /// Build a commitment to output notes.
 ///
-/// For a non-empty list of notes, this is a sequential hash of (note_id, metadata) tuples for the
-/// notes created in a transaction. For an empty list, [ZERO; 4] is returned.
+/// If the notes fit into a single chunk of [OUTPUT_NOTES_COMMITMENT_CHUNK_SIZE] notes, this is the
+/// commitment to the chunk. Otherwise, this is a sequential hash of the commitments to the chunks
+/// of the notes, padded with an empty digest to an even number of chunks.
+///
+/// This mirrors the way the commitment is computed in the transaction kernel epilogue.
 fn build_output_notes_commitment<T: ToEnvelope>(notes: &[T]) -> Digest {
+    if notes.len() <= OUTPUT_NOTES_COMMITMENT_CHUNK_SIZE {
+        return build_output_notes_chunk_commitment(notes);
+    }
+
+    let mut elements: Vec<Felt> = Vec::new();
+    for chunk in notes.chunks(OUTPUT_NOTES_COMMITMENT_CHUNK_SIZE) {
+        elements.extend_from_slice(build_output_notes_chunk_commitment(chunk).as_elements());
+    }
+
+    // the kernel absorbs two chunk commitments per permutation
+    if elements.len() % 8 != 0 {
+        elements.extend_from_slice(Digest::default().as_elements());
+    }
+
+    Hasher::hash_elements(&elements)
+}
+
+/// Build a commitment to a chunk of output notes.
+///
+/// For a non-empty list of notes, this is a sequential hash of (note_id, metadata) tuples for the
+/// notes in the chunk. For an empty list, [ZERO; 4] is returned.
+fn build_output_notes_chunk_commitment<T: ToEnvelope>(notes: &[T]) -> Digest {
     if notes.is_empty() {
         return Digest::default();
     }
@@ -209,76 +279,112 @@ fn build_output_notes_commitment<T: ToEnvelope>(notes: &[T]) -> Digest {
 // OUTPUT NOTE
 // ================================================================================================
 
-/// An note create during a transaction.
+/// A note created during a transaction.
 ///
 /// When a note is produced in a transaction, the note's recipient, assets, and metadata must be
-/// known. However, other information about the note may or may not be know to the note's producer.
+/// known. However, other information about the note may or may not be known to the note's
+/// producer, and the producer of a private note may choose to retain only the ID and the metadata
+/// of the note. Thus, an output note can be:
+/// - [OutputNote::Full]: a note of which all details are known.
+/// - [OutputNote::Partial]: a note of which only the recipient, the assets and the metadata are
+///   known; this is how the notes output by the transaction kernel are described.
+/// - [OutputNote::Header]: a note of which only the ID and the metadata are known.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct OutputNote {
-    envelope: NoteEnvelope,
-    recipient: Digest,
-    assets: NoteAssets,
+pub enum OutputNote {
+    Full(Note),
+    Partial(PartialNote),
+    Header(NoteEnvelope),
 }
 
 impl OutputNote {
-    // CONSTRUCTOR
-    // --------------------------------------------------------------------------------------------
-    /// Returns a new [OutputNote] instantiated from the provided parameters.
-    pub fn new(recipient: Digest, assets: NoteAssets, metadata: NoteMetadata) -> Self {
-        let note_id = NoteId::new(recipient, assets.commitment());
-        Self {
-            envelope: NoteEnvelope::new(note_id, metadata),
-            recipient,
-            assets,
-        }
-    }
-
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns the recipient of the note.
-    pub fn recipient(&self) -> &Digest {
-        &self.recipient
+    /// Returns the recipient of the note, or None if only the header of the note is known.
+    pub fn recipient(&self) -> Option<Digest> {
+        match self {
+            OutputNote::Full(note) => Some(note.recipient()),
+            OutputNote::Partial(note) => Some(*note.recipient()),
+            OutputNote::Header(_) => None,
+        }
     }
 
-    /// Returns a reference to the asset of this note.
-    pub fn assets(&self) -> &NoteAssets {
-        &self.assets
+    /// Returns a reference to the assets of the note, or None if only the header of the note is
+    /// known.
+    pub fn assets(&self) -> Option<&NoteAssets> {
+        match self {
+            OutputNote::Full(note) => Some(note.assets()),
+            OutputNote::Partial(note) => Some(note.assets()),
+            OutputNote::Header(_) => None,
+        }
     }
 
     /// Returns the metadata associated with this note.
     pub fn metadata(&self) -> &NoteMetadata {
-        self.envelope.metadata()
+        match self {
+            OutputNote::Full(note) => note.metadata(),
+            OutputNote::Partial(note) => note.metadata(),
+            OutputNote::Header(envelope) => envelope.metadata(),
+        }
     }
 
     /// Return the unique ID of this note.
     pub fn id(&self) -> NoteId {
-        self.envelope.note_id()
+        match self {
+            OutputNote::Full(note) => note.id(),
+            OutputNote::Partial(note) => note.id(),
+            OutputNote::Header(envelope) => envelope.note_id(),
+        }
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns this note reduced to its header (i.e., the ID and the metadata of the note).
+    ///
+    /// This can be used by the producer of a private note to discard the details of the note.
+    pub fn shrink(&self) -> Self {
+        OutputNote::Header(self.into())
     }
 }
 
 impl From<OutputNote> for NoteEnvelope {
-    fn from(note_stub: OutputNote) -> Self {
-        note_stub.envelope
+    fn from(note: OutputNote) -> Self {
+        (&note).into()
     }
 }
 
 impl From<&OutputNote> for NoteEnvelope {
-    fn from(note_stub: &OutputNote) -> Self {
-        note_stub.envelope
+    fn from(note: &OutputNote) -> Self {
+        match note {
+            OutputNote::Full(note) => NoteEnvelope::new(note.id(), *note.metadata()),
+            OutputNote::Partial(note) => note.into(),
+            OutputNote::Header(envelope) => *envelope,
+        }
     }
 }
 
 impl From<Note> for OutputNote {
     fn from(note: Note) -> Self {
-        (&note).into()
+        OutputNote::Full(note)
     }
 }
 
 impl From<&Note> for OutputNote {
     fn from(note: &Note) -> Self {
-        let recipient = note.recipient();
-        Self::new(recipient, note.assets().clone(), *note.metadata())
+        OutputNote::Full(note.clone())
+    }
+}
+
+impl From<PartialNote> for OutputNote {
+    fn from(note: PartialNote) -> Self {
+        OutputNote::Partial(note)
+    }
+}
+
+impl From<NoteEnvelope> for OutputNote {
+    fn from(envelope: NoteEnvelope) -> Self {
+        OutputNote::Header(envelope)
     }
 }
 
@@ -287,18 +393,80 @@ impl From<&Note> for OutputNote {
 
 impl Serializable for OutputNote {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        self.recipient.write_into(target);
-        self.assets.write_into(target);
-        self.envelope.metadata().write_into(target);
+        match self {
+            OutputNote::Full(note) => {
+                0_u8.write_into(target);
+                note.write_into(target);
+            },
+            OutputNote::Partial(note) => {
+                1_u8.write_into(target);
+                note.write_into(target);
+            },
+            OutputNote::Header(envelope) => {
+                2_u8.write_into(target);
+                envelope.write_into(target);
+            },
+        }
     }
 }
 
 impl Deserializable for OutputNote {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let recipient = Digest::read_from(source)?;
-        let assets = NoteAssets::read_from(source)?;
-        let metadata = NoteMetadata::read_from(source)?;
+        match u8::read_from(source)? {
+            0 => Ok(OutputNote::Full(Note::read_from(source)?)),
+            1 => Ok(OutputNote::Partial(PartialNote::read_from(source)?)),
+            2 => Ok(OutputNote::Header(NoteEnvelope::read_from(source)?)),
+            variant => Err(DeserializationError::InvalidValue(format!(
+                "unknown output note variant: {variant}"
+            ))),
+        }
+    }
+}
 
-        Ok(Self::new(recipient, assets, metadata))
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, NoteEnvelope, OutputNote, PartialNote, Serializable};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assembly::ProgramAst,
+        assets::{Asset, FungibleAsset},
+        notes::{Note, NoteScript, NoteTag},
+        Digest, Felt,
+    };
+
+    #[test]
+    fn output_note_variants() {
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let script = NoteScript::from_parts(code, Digest::default());
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let note =
+            Note::new(script, &[Felt::new(5)], &[asset], serial_num, sender, NoteTag::from(6))
+                .unwrap();
+
+        let full = OutputNote::from(&note);
+        let partial = OutputNote::from(PartialNote::from(&note));
+        let envelope = NoteEnvelope::new(note.id(), *note.metadata());
+        let header = full.shrink();
+        assert_eq!(header, OutputNote::Header(envelope));
+        assert_eq!(partial.recipient(), Some(note.recipient()));
+        assert_eq!(header.recipient(), None);
+
+        for output_note in [full, partial, header] {
+            assert_eq!(output_note.id(), note.id());
+            assert_eq!(NoteEnvelope::from(&output_note), envelope);
+
+            let bytes = output_note.to_bytes();
+            assert_eq!(OutputNote::read_from_bytes(&bytes).unwrap(), output_note);
+        }
     }
 }
@@ -1,8 +1,17 @@
 use miden_verifier::ExecutionProof;
 
-use super::{AccountId, Digest, InputNotes, NoteEnvelope, Nullifier, OutputNotes, TransactionId};
-use crate::utils::serde::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+use super::{
+    Account, AccountDelta, AccountId, Digest, InputNoteCommitment, InputNotes, NoteEnvelope,
+    OutputNotes, TransactionId,
+};
+use crate::{
+    assets::FungibleAsset,
+    utils::{
+        format,
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        string::ToString,
+    },
+    ProvenTransactionError,
 };
 
 // PROVEN TRANSACTION
@@ -15,11 +24,18 @@ use crate::utils::serde::{
 /// - account_id: ID of the account that the transaction was executed against.
 /// - initial_account_hash: the hash of the account before the transaction was executed.
 /// - final_account_hash: the hash of the account after the transaction was executed.
-/// - input_notes: a list of nullifier for all notes consumed by the transaction.
+/// - input_notes: a list of nullifiers for all notes consumed by the transaction, together with
+///   the headers of the unauthenticated notes, which are authenticated by the operator when the
+///   transaction is included in a block.
 /// - output_notes: a list of (note_id, metadata) tuples for all notes created by the
 ///   transaction.
+/// - account_details: the details of the account state changes for on-chain accounts; None for
+///   off-chain accounts.
 /// - tx_script_root: the script root of the transaction, if one was used.
+/// - kernel_root: the root of the transaction kernel program the transaction was proven against.
 /// - block_ref: the block hash of the last known block at the time the transaction was executed.
+/// - expiration_block_num: the number of the last block in which the transaction can be included.
+/// - fee: the fungible asset deducted from the account vault as a transaction fee, if any.
 /// - proof: a STARK proof that attests to the correct execution of the transaction.
 #[derive(Clone, Debug)]
 pub struct ProvenTransaction {
@@ -27,10 +43,14 @@ pub struct ProvenTransaction {
     account_id: AccountId,
     initial_account_hash: Digest,
     final_account_hash: Digest,
-    input_notes: InputNotes<Nullifier>,
+    input_notes: InputNotes<InputNoteCommitment>,
     output_notes: OutputNotes<NoteEnvelope>,
+    account_details: Option<AccountDetails>,
     tx_script_root: Option<Digest>,
+    kernel_root: Digest,
     block_ref: Digest,
+    expiration_block_num: u32,
+    fee: Option<FungibleAsset>,
     proof: ExecutionProof,
 }
 
@@ -39,17 +59,37 @@ impl ProvenTransaction {
     // --------------------------------------------------------------------------------------------
 
     /// Returns a new [ProvenTransaction] instantiated from the provided parameters.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account is off-chain and account details were provided.
+    /// - The account is on-chain and account details were not provided.
+    /// - The account is a new on-chain account and the details do not contain the full account
+    ///   state, or the account state does not match the account ID and the final account hash.
+    /// - The account is an existing on-chain account and the details do not contain an account
+    ///   delta.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         account_id: AccountId,
         initial_account_hash: Digest,
         final_account_hash: Digest,
-        input_notes: InputNotes<Nullifier>,
+        input_notes: InputNotes<InputNoteCommitment>,
         output_notes: OutputNotes<NoteEnvelope>,
+        account_details: Option<AccountDetails>,
         tx_script_root: Option<Digest>,
+        kernel_root: Digest,
         block_ref: Digest,
+        expiration_block_num: u32,
+        fee: Option<FungibleAsset>,
         proof: ExecutionProof,
-    ) -> Self {
+    ) -> Result<Self, ProvenTransactionError> {
+        validate_account_details(
+            account_id,
+            initial_account_hash,
+            final_account_hash,
+            account_details.as_ref(),
+        )?;
+
         let id = TransactionId::new(
             initial_account_hash,
             final_account_hash,
@@ -57,17 +97,21 @@ impl ProvenTransaction {
             output_notes.commitment(),
         );
 
-        Self {
+        Ok(Self {
             id,
             account_id,
             initial_account_hash,
             final_account_hash,
             input_notes,
             output_notes,
+            account_details,
             tx_script_root,
+            kernel_root,
             block_ref,
+            expiration_block_num,
+            fee,
             proof,
-        }
+        })
     }
 
     // PUBLIC ACCESSORS
@@ -94,20 +138,40 @@ impl ProvenTransaction {
     }
 
     /// Returns a reference to the notes consumed by the transaction.
-    pub fn input_notes(&self) -> &InputNotes<Nullifier> {
+    pub fn input_notes(&self) -> &InputNotes<InputNoteCommitment> {
         &self.input_notes
     }
 
+    /// Returns an iterator over the headers of the unauthenticated notes consumed by the
+    /// transaction.
+    ///
+    /// These notes were not authenticated against the chain during transaction execution, and
+    /// thus must be authenticated by the operator before the transaction can be included in a
+    /// block.
+    pub fn unauthenticated_notes(&self) -> impl Iterator<Item = &NoteEnvelope> {
+        self.input_notes.iter().filter_map(|note| note.header())
+    }
+
     /// Returns a reference to the notes produced by the transaction.
     pub fn output_notes(&self) -> &OutputNotes<NoteEnvelope> {
         &self.output_notes
     }
 
+    /// Returns the details of the account state changes, or None if the account is off-chain.
+    pub fn account_details(&self) -> Option<&AccountDetails> {
+        self.account_details.as_ref()
+    }
+
     /// Returns the script root of the transaction.
     pub fn tx_script_root(&self) -> Option<Digest> {
         self.tx_script_root
     }
 
+    /// Returns the root of the transaction kernel program the transaction was proven against.
+    pub fn kernel_root(&self) -> Digest {
+        self.kernel_root
+    }
+
     /// Returns the proof of the transaction.
     pub fn proof(&self) -> &ExecutionProof {
         &self.proof
@@ -117,6 +181,122 @@ impl ProvenTransaction {
     pub fn block_ref(&self) -> Digest {
         self.block_ref
     }
+
+    /// Returns the number of the last block in which the transaction can be included.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.expiration_block_num
+    }
+
+    /// Returns the fee paid by the transaction, or None if the transaction pays no fee.
+    ///
+    /// The fee is bound to the proof of the transaction, and thus can be relied upon once the
+    /// transaction has been verified.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.fee
+    }
+
+    /// Returns true if the transaction can no longer be included in the block with the specified
+    /// number.
+    pub fn is_expired_at(&self, block_num: u32) -> bool {
+        block_num > self.expiration_block_num
+    }
+
+    // ACCOUNT STATE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the state of the account after this transaction was executed.
+    ///
+    /// For new accounts the full account state is included in the transaction. For existing
+    /// accounts, the account delta is applied to the provided initial account state, which must
+    /// match the initial account hash of this transaction.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account is off-chain, and thus its state is not included in the transaction.
+    /// - The account is an existing account and the initial account state was not provided, does
+    ///   not match the account ID or the initial account hash of this transaction.
+    /// - Applying the account delta to the initial account state fails, or results in a state
+    ///   which does not match the final account hash of this transaction.
+    pub fn build_final_account(
+        &self,
+        initial_account: Option<&Account>,
+    ) -> Result<Account, ProvenTransactionError> {
+        match &self.account_details {
+            None => Err(ProvenTransactionError::AccountDetailsNotAvailable(self.account_id)),
+            Some(AccountDetails::Full(account)) => Ok(account.clone()),
+            Some(AccountDetails::Delta(delta)) => {
+                let mut account = initial_account
+                    .ok_or(ProvenTransactionError::InitialAccountMissing(self.account_id))?
+                    .clone();
+
+                if account.id() != self.account_id {
+                    return Err(ProvenTransactionError::AccountIdMismatch {
+                        expected: self.account_id,
+                        actual: account.id(),
+                    });
+                }
+                if account.hash() != self.initial_account_hash {
+                    return Err(ProvenTransactionError::InitialAccountHashMismatch {
+                        expected: self.initial_account_hash,
+                        actual: account.hash(),
+                    });
+                }
+
+                account
+                    .apply_delta(delta)
+                    .map_err(ProvenTransactionError::AccountDeltaApplicationFailed)?;
+
+                if account.hash() != self.final_account_hash {
+                    return Err(ProvenTransactionError::FinalAccountHashMismatch {
+                        expected: self.final_account_hash,
+                        actual: account.hash(),
+                    });
+                }
+
+                Ok(account)
+            },
+        }
+    }
+}
+
+// ACCOUNT DETAILS
+// ================================================================================================
+
+/// Describes the changes made to the state of an on-chain account by a transaction.
+///
+/// - Full: the complete state of a new account after the transaction was executed.
+/// - Delta: the changes made to the state of an existing account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountDetails {
+    Full(Account),
+    Delta(AccountDelta),
+}
+
+impl Serializable for AccountDetails {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            AccountDetails::Full(account) => {
+                0_u8.write_into(target);
+                account.write_into(target);
+            },
+            AccountDetails::Delta(delta) => {
+                1_u8.write_into(target);
+                delta.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for AccountDetails {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match u8::read_from(source)? {
+            0 => Ok(AccountDetails::Full(Account::read_from(source)?)),
+            1 => Ok(AccountDetails::Delta(AccountDelta::read_from(source)?)),
+            variant => Err(DeserializationError::InvalidValue(format!(
+                "unknown account details variant: {variant}"
+            ))),
+        }
+    }
 }
 
 // SERIALIZATION
@@ -129,8 +309,12 @@ impl Serializable for ProvenTransaction {
         self.final_account_hash.write_into(target);
         self.input_notes.write_into(target);
         self.output_notes.write_into(target);
+        self.account_details.write_into(target);
         self.tx_script_root.write_into(target);
+        self.kernel_root.write_into(target);
         self.block_ref.write_into(target);
+        target.write_u32(self.expiration_block_num);
+        self.fee.write_into(target);
         self.proof.write_into(target);
     }
 }
@@ -141,14 +325,26 @@ impl Deserializable for ProvenTransaction {
         let initial_account_hash = Digest::read_from(source)?;
         let final_account_hash = Digest::read_from(source)?;
 
-        let input_notes = InputNotes::<Nullifier>::read_from(source)?;
+        let input_notes = InputNotes::<InputNoteCommitment>::read_from(source)?;
         let output_notes = OutputNotes::<NoteEnvelope>::read_from(source)?;
+        let account_details = <Option<AccountDetails>>::read_from(source)?;
 
         let tx_script_root = Deserializable::read_from(source)?;
+        let kernel_root = Digest::read_from(source)?;
 
         let block_ref = Digest::read_from(source)?;
+        let expiration_block_num = source.read_u32()?;
+        let fee = <Option<FungibleAsset>>::read_from(source)?;
         let proof = ExecutionProof::read_from(source)?;
 
+        validate_account_details(
+            account_id,
+            initial_account_hash,
+            final_account_hash,
+            account_details.as_ref(),
+        )
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
         let id = TransactionId::new(
             initial_account_hash,
             final_account_hash,
@@ -163,9 +359,79 @@ impl Deserializable for ProvenTransaction {
             final_account_hash,
             input_notes,
             output_notes,
+            account_details,
             tx_script_root,
+            kernel_root,
             block_ref,
+            expiration_block_num,
+            fee,
             proof,
         })
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ProvenTransaction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes();
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ProvenTransaction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use crate::utils::collections::Vec;
+
+        let bytes: Vec<u8> = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::read_from_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the provided account details are consistent with the account and the account state
+/// hashes of a transaction.
+///
+/// Account deltas cannot be checked against the final account hash without the initial account
+/// state; this check is performed by [ProvenTransaction::build_final_account()].
+fn validate_account_details(
+    account_id: AccountId,
+    initial_account_hash: Digest,
+    final_account_hash: Digest,
+    account_details: Option<&AccountDetails>,
+) -> Result<(), ProvenTransactionError> {
+    match (account_id.is_on_chain(), account_details) {
+        (false, None) => Ok(()),
+        (false, Some(_)) => Err(ProvenTransactionError::OffChainAccountWithDetails(account_id)),
+        (true, None) => Err(ProvenTransactionError::AccountDetailsMissing(account_id)),
+        (true, Some(AccountDetails::Full(account))) => {
+            // the initial hash of new accounts is set to the default digest
+            if initial_account_hash != Digest::default() {
+                return Err(ProvenTransactionError::ExistingOnChainAccountRequiresDelta(
+                    account_id,
+                ));
+            }
+            if account.id() != account_id {
+                return Err(ProvenTransactionError::AccountIdMismatch {
+                    expected: account_id,
+                    actual: account.id(),
+                });
+            }
+            if account.hash() != final_account_hash {
+                return Err(ProvenTransactionError::FinalAccountHashMismatch {
+                    expected: final_account_hash,
+                    actual: account.hash(),
+                });
+            }
+            Ok(())
+        },
+        (true, Some(AccountDetails::Delta(_))) => {
+            if initial_account_hash == Digest::default() {
+                return Err(ProvenTransactionError::NewOnChainAccountRequiresFullState(account_id));
+            }
+            Ok(())
+        },
+    }
+}
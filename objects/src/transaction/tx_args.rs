@@ -1,12 +1,24 @@
-use super::{Digest, Felt, Word};
+use super::{Digest, Felt, Hasher, Word};
 use crate::{
-    assembly::{Assembler, AssemblyContext, ProgramAst},
-    notes::NoteId,
-    utils::collections::{BTreeMap, Vec},
+    assembly::{Assembler, AssemblyContext, AstSerdeOptions, ProgramAst},
+    assets::FungibleAsset,
+    crypto::merkle::{InnerNodeInfo, MerkleStore},
+    notes::{NoteDetails, NoteId},
+    utils::{
+        collections::{BTreeMap, Vec},
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+        string::ToString,
+    },
     vm::CodeBlock,
-    TransactionScriptError,
+    TransactionInputError, TransactionScriptError,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+/// Default serialization options for transaction script code AST.
+const CODE_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(true);
+
 // TRANSACTION ARGS
 // ================================================================================================
 
@@ -17,10 +29,31 @@ use crate::{
 ///   scripts have been executed..
 /// - Note arguments: data put onto the the stack right before a note script is executed. These
 ///   are different from note inputs, as the executing account can specify arbitrary note args.
+/// - Advice map: entries which are loaded into the advice map of the transaction. These include
+///   the inputs of the transaction script.
+/// - Merkle store: nodes which are loaded into the Merkle store of the transaction, e.g., the
+///   Merkle paths verified by custom note scripts.
+/// - Expected output notes: details of notes which the transaction is expected to create (e.g.,
+///   the payback note of a SWAP note). The preimages of their recipients are loaded into the
+///   advice map, so that note scripts can build the notes. The transaction kernel outputs only the
+///   recipients of the created notes; when the details of a created note are known, the note is
+///   returned as a full note by
+///   [ExecutedTransaction::output_notes()](super::ExecutedTransaction::output_notes).
+/// - Fee: a fungible asset which the transaction kernel deducts from the account vault in the
+///   epilogue. The fee is a public output of the transaction, and thus block producers can
+///   enforce fee policies on proven transactions. Since paying a fee changes the account state,
+///   transactions paying a fee must increment the account nonce.
+///
+/// Transaction arguments can be assembled via the [TransactionArgsBuilder] returned by
+/// [TransactionArgs::builder()].
 #[derive(Clone, Debug, Default)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: Option<BTreeMap<NoteId, Word>>,
+    advice_map: BTreeMap<Digest, Vec<Felt>>,
+    merkle_store: MerkleStore,
+    expected_output_notes: BTreeMap<NoteId, NoteDetails>,
+    fee: Option<FungibleAsset>,
 }
 
 impl TransactionArgs {
@@ -33,21 +66,29 @@ impl TransactionArgs {
         tx_script: Option<TransactionScript>,
         note_args: Option<BTreeMap<NoteId, Word>>,
     ) -> Self {
-        Self { tx_script, note_args }
+        let advice_map =
+            tx_script.as_ref().map(|script| script.inputs().clone()).unwrap_or_default();
+        Self {
+            tx_script,
+            note_args,
+            advice_map,
+            merkle_store: MerkleStore::default(),
+            expected_output_notes: BTreeMap::new(),
+            fee: None,
+        }
     }
 
     pub fn with_tx_script(tx_script: TransactionScript) -> Self {
-        Self {
-            tx_script: Some(tx_script),
-            note_args: None,
-        }
+        Self::new(Some(tx_script), None)
     }
 
     pub fn with_note_args(not_args: BTreeMap<NoteId, Word>) -> Self {
-        Self {
-            tx_script: None,
-            note_args: Some(not_args),
-        }
+        Self::new(None, Some(not_args))
+    }
+
+    /// Returns a new [TransactionArgsBuilder].
+    pub fn builder() -> TransactionArgsBuilder {
+        TransactionArgsBuilder::default()
     }
 
     // PUBLIC ACCESSORS
@@ -67,6 +108,198 @@ impl TransactionArgs {
     pub fn get_note_args(&self, note_id: NoteId) -> Option<&Word> {
         self.note_args.as_ref().and_then(|map| map.get(&note_id))
     }
+
+    /// Returns a reference to the entries which are loaded into the advice map of the
+    /// transaction, including the inputs of the transaction script.
+    pub fn advice_map(&self) -> &BTreeMap<Digest, Vec<Felt>> {
+        &self.advice_map
+    }
+
+    /// Returns a reference to the Merkle store whose nodes are loaded into the Merkle store of the
+    /// transaction.
+    pub fn merkle_store(&self) -> &MerkleStore {
+        &self.merkle_store
+    }
+
+    /// Returns an iterator over the details of the notes the transaction is expected to create.
+    pub fn expected_output_notes(&self) -> impl Iterator<Item = &NoteDetails> {
+        self.expected_output_notes.values()
+    }
+
+    /// Returns the details of the expected output note with the specified ID, if any.
+    pub fn get_expected_output_note(&self, note_id: NoteId) -> Option<&NoteDetails> {
+        self.expected_output_notes.get(&note_id)
+    }
+
+    /// Returns the fee paid by the transaction, or None if the transaction pays no fee.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.fee
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Extends the advice map of the transaction with the provided entries.
+    ///
+    /// Entries which are already present in the advice map are overridden.
+    pub fn extend_advice_map<T: IntoIterator<Item = (Digest, Vec<Felt>)>>(&mut self, entries: T) {
+        self.advice_map.extend(entries);
+    }
+
+    /// Extends the Merkle store of the transaction with the provided nodes.
+    pub fn extend_merkle_store<T: IntoIterator<Item = InnerNodeInfo>>(&mut self, nodes: T) {
+        self.merkle_store.extend(nodes);
+    }
+
+    /// Adds the details of a note which the transaction is expected to create.
+    ///
+    /// The following entries are added to the advice map of the transaction, so that note scripts
+    /// can build the note from its recipient:
+    /// - recipient |-> [SERIAL_NUM, SCRIPT_ROOT, INPUTS_HASH]
+    /// - inputs_hash |-> inputs
+    pub fn add_expected_output_note(&mut self, note: &NoteDetails) {
+        self.advice_map.extend(build_recipient_advice(note));
+        self.expected_output_notes.insert(note.id(), note.clone());
+    }
+
+    /// Replaces the transaction script with the provided one.
+    ///
+    /// The inputs of the provided script are added to the advice map of the transaction.
+    pub fn set_tx_script(&mut self, tx_script: TransactionScript) {
+        self.advice_map.extend(tx_script.inputs().clone());
+        self.tx_script = Some(tx_script);
+    }
+
+    /// Sets the fee paid by the transaction.
+    ///
+    /// Setting the fee more than once overrides the previously set fee.
+    pub fn set_fee(&mut self, fee: FungibleAsset) {
+        self.fee = Some(fee);
+    }
+
+    /// Extends the expected output notes of the transaction with the provided note details.
+    ///
+    /// See [TransactionArgs::add_expected_output_note()] for details.
+    pub fn extend_expected_output_notes<T: IntoIterator<Item = NoteDetails>>(&mut self, notes: T) {
+        for note in notes {
+            self.add_expected_output_note(&note);
+        }
+    }
+}
+
+// TRANSACTION ARGS BUILDER
+// ================================================================================================
+
+/// A builder for [TransactionArgs].
+///
+/// The advice map of the built arguments is the union of the inputs of the transaction script and
+/// of the entries provided via [TransactionArgsBuilder::advice_entry()]. An entry may be provided
+/// more than once as long as its values are the same every time. The Merkle store of the built
+/// arguments contains the nodes provided via [TransactionArgsBuilder::merkle_nodes()].
+///
+/// # Example
+/// ```ignore
+/// let tx_args = TransactionArgs::builder()
+///     .tx_script(tx_script)
+///     .note_arg(note_id, note_arg)
+///     .advice_entry(key, values)
+///     .merkle_nodes(tree.inner_nodes())
+///     .build()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TransactionArgsBuilder {
+    tx_script: Option<TransactionScript>,
+    note_args: BTreeMap<NoteId, Word>,
+    advice_entries: Vec<(Digest, Vec<Felt>)>,
+    merkle_store: MerkleStore,
+    expected_output_notes: BTreeMap<NoteId, NoteDetails>,
+    fee: Option<FungibleAsset>,
+}
+
+impl TransactionArgsBuilder {
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the transaction script.
+    pub fn tx_script(mut self, tx_script: TransactionScript) -> Self {
+        self.tx_script = Some(tx_script);
+        self
+    }
+
+    /// Sets the argument of the note with the specified ID.
+    ///
+    /// Setting the argument of the same note more than once overrides the previously set value.
+    pub fn note_arg(mut self, note_id: NoteId, note_arg: Word) -> Self {
+        self.note_args.insert(note_id, note_arg);
+        self
+    }
+
+    /// Adds an entry to the advice map of the transaction.
+    pub fn advice_entry(mut self, key: Digest, values: Vec<Felt>) -> Self {
+        self.advice_entries.push((key, values));
+        self
+    }
+
+    /// Adds the provided nodes to the Merkle store of the transaction.
+    ///
+    /// This makes the Merkle paths of the nodes available to the note scripts and the transaction
+    /// script, e.g., via the `mtree_get` instruction.
+    pub fn merkle_nodes<T: IntoIterator<Item = InnerNodeInfo>>(mut self, nodes: T) -> Self {
+        self.merkle_store.extend(nodes);
+        self
+    }
+
+    /// Adds the details of a note which the transaction is expected to create.
+    ///
+    /// See [TransactionArgs::add_expected_output_note()] for details.
+    pub fn expected_output_note(mut self, details: NoteDetails) -> Self {
+        self.advice_entries.extend(build_recipient_advice(&details));
+        self.expected_output_notes.insert(details.id(), details);
+        self
+    }
+
+    /// Sets the fee paid by the transaction.
+    ///
+    /// See [TransactionArgs::set_fee()] for details.
+    pub fn fee(mut self, fee: FungibleAsset) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the transaction arguments.
+    ///
+    /// # Errors
+    /// Returns an error if the same advice map key is provided with different values, either via
+    /// [TransactionArgsBuilder::advice_entry()] or via the inputs of the transaction script.
+    pub fn build(self) -> Result<TransactionArgs, TransactionInputError> {
+        let script_inputs = self.tx_script.iter().flat_map(|script| script.inputs().clone());
+
+        let mut advice_map = BTreeMap::new();
+        for (key, values) in self.advice_entries.into_iter().chain(script_inputs) {
+            match advice_map.get(&key) {
+                Some(existing) if existing != &values => {
+                    return Err(TransactionInputError::ConflictingAdviceMapEntry(key));
+                },
+                _ => {
+                    advice_map.insert(key, values);
+                },
+            }
+        }
+
+        let note_args = (!self.note_args.is_empty()).then_some(self.note_args);
+
+        Ok(TransactionArgs {
+            tx_script: self.tx_script,
+            note_args,
+            advice_map,
+            merkle_store: self.merkle_store,
+            expected_output_notes: self.expected_output_notes,
+            fee: self.fee,
+        })
+    }
 }
 
 // TRANSACTION SCRIPT
@@ -150,3 +383,264 @@ impl TransactionScript {
         &self.inputs
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionArgs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_script.write_into(target);
+        target.write_bool(self.note_args.is_some());
+        if let Some(note_args) = &self.note_args {
+            target.write_usize(note_args.len());
+            for (note_id, note_arg) in note_args.iter() {
+                note_id.write_into(target);
+                note_arg.write_into(target);
+            }
+        }
+        write_advice_map(&self.advice_map, target);
+        write_merkle_store(&self.merkle_store, target);
+        target.write_usize(self.expected_output_notes.len());
+        for details in self.expected_output_notes.values() {
+            details.write_into(target);
+        }
+        self.fee.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionArgs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tx_script = <Option<TransactionScript>>::read_from(source)?;
+        let note_args = if source.read_bool()? {
+            let num_note_args = source.read_usize()?;
+            let mut note_args = BTreeMap::new();
+            for _ in 0..num_note_args {
+                note_args.insert(NoteId::read_from(source)?, Word::read_from(source)?);
+            }
+            Some(note_args)
+        } else {
+            None
+        };
+        let advice_map = read_advice_map(source)?;
+        let merkle_store = read_merkle_store(source)?;
+        let num_expected_output_notes = source.read_usize()?;
+        let expected_output_notes = source
+            .read_many::<NoteDetails>(num_expected_output_notes)?
+            .into_iter()
+            .map(|details| (details.id(), details))
+            .collect();
+        let fee = <Option<FungibleAsset>>::read_from(source)?;
+
+        Ok(Self {
+            tx_script,
+            note_args,
+            advice_map,
+            merkle_store,
+            expected_output_notes,
+            fee,
+        })
+    }
+}
+
+impl Serializable for TransactionScript {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.hash.write_into(target);
+        self.code.write_into(target, CODE_SERDE_OPTIONS);
+        write_advice_map(&self.inputs, target);
+    }
+}
+
+impl Deserializable for TransactionScript {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let hash = Digest::read_from(source)?;
+        let code = ProgramAst::read_from(source)?;
+        let inputs = read_advice_map(source)?;
+
+        Ok(Self { code, hash, inputs })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the advice map entries which make the recipient preimage of the specified note
+/// available to note scripts:
+/// - recipient |-> [SERIAL_NUM, SCRIPT_ROOT, INPUTS_HASH]
+/// - inputs_hash |-> inputs
+fn build_recipient_advice(note: &NoteDetails) -> [(Digest, Vec<Felt>); 2] {
+    let mut recipient_data = Vec::with_capacity(12);
+    recipient_data.extend_from_slice(&note.serial_num());
+    recipient_data.extend_from_slice(note.script().hash().as_elements());
+    recipient_data.extend_from_slice(note.inputs().commitment().as_elements());
+
+    [
+        (note.recipient(), recipient_data),
+        (note.inputs().commitment(), note.inputs().to_padded_values()),
+    ]
+}
+
+/// Writes the entries of the provided advice map into the specified target.
+pub(super) fn write_advice_map<W: ByteWriter>(map: &BTreeMap<Digest, Vec<Felt>>, target: &mut W) {
+    target.write_usize(map.len());
+    for (key, values) in map.iter() {
+        key.write_into(target);
+        target.write_usize(values.len());
+        target.write_many(values);
+    }
+}
+
+/// Reads the entries of an advice map written via [write_advice_map()] from the specified source.
+pub(super) fn read_advice_map<R: ByteReader>(
+    source: &mut R,
+) -> Result<BTreeMap<Digest, Vec<Felt>>, DeserializationError> {
+    let num_entries = source.read_usize()?;
+    let mut map = BTreeMap::new();
+    for _ in 0..num_entries {
+        let key = Digest::read_from(source)?;
+        let num_values = source.read_usize()?;
+        map.insert(key, source.read_many::<Felt>(num_values)?);
+    }
+    Ok(map)
+}
+
+/// Writes the inner nodes of the provided Merkle store into the specified target.
+pub(super) fn write_merkle_store<W: ByteWriter>(store: &MerkleStore, target: &mut W) {
+    let nodes: Vec<InnerNodeInfo> = store.inner_nodes().collect();
+    target.write_usize(nodes.len());
+    for node in nodes {
+        node.value.write_into(target);
+        node.left.write_into(target);
+        node.right.write_into(target);
+    }
+}
+
+/// Reads a Merkle store written via [write_merkle_store()] from the specified source.
+///
+/// # Errors
+/// Returns an error if the value of any of the nodes is not the hash of its children.
+pub(super) fn read_merkle_store<R: ByteReader>(
+    source: &mut R,
+) -> Result<MerkleStore, DeserializationError> {
+    let num_nodes = source.read_usize()?;
+    let mut nodes = Vec::with_capacity(num_nodes);
+    for _ in 0..num_nodes {
+        let value = Digest::read_from(source)?;
+        let left = Digest::read_from(source)?;
+        let right = Digest::read_from(source)?;
+        if Hasher::merge(&[left, right]) != value {
+            return Err(DeserializationError::InvalidValue(
+                "Merkle store node is not the hash of its children".to_string(),
+            ));
+        }
+        nodes.push(InnerNodeInfo { value, left, right });
+    }
+
+    let mut store = MerkleStore::default();
+    store.extend(nodes);
+    Ok(store)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Digest, Felt, TransactionArgs, TransactionScript};
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+        assembly::{Assembler, ProgramAst},
+        assets::FungibleAsset,
+        crypto::merkle::{MerkleTree, NodeIndex},
+        notes::{NoteAssets, NoteDetails, NoteId, NoteInputs, NoteScript},
+        utils::serde::{Deserializable, Serializable},
+        TransactionInputError, Word, ONE,
+    };
+
+    #[test]
+    fn transaction_args_builder_merges_advice_inputs() {
+        let key_1: Word = [ONE; 4];
+        let key_2 = Digest::new([Felt::new(2); 4]);
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let tx_script =
+            TransactionScript::from_parts(code, Digest::default(), [(key_1, vec![Felt::new(1)])])
+                .unwrap();
+        let note_id = NoteId::from(Digest::default());
+        let leaves: Vec<Word> = (0..4).map(|i| [Felt::new(i); 4]).collect();
+        let tree = MerkleTree::new(leaves).unwrap();
+
+        // entries provided by the script and the caller are merged, and duplicates are allowed
+        let tx_args = TransactionArgs::builder()
+            .tx_script(tx_script.clone())
+            .note_arg(note_id, [ONE; 4])
+            .advice_entry(key_1.into(), vec![Felt::new(1)])
+            .advice_entry(key_2, vec![Felt::new(2), Felt::new(3)])
+            .merkle_nodes(tree.inner_nodes())
+            .build()
+            .unwrap();
+        assert_eq!(tx_args.get_note_args(note_id), Some(&[ONE; 4]));
+        assert_eq!(tx_args.advice_map().len(), 2);
+        assert_eq!(tx_args.advice_map().get(&key_2), Some(&vec![Felt::new(2), Felt::new(3)]));
+
+        // the paths of the provided Merkle nodes can be retrieved from the Merkle store
+        let index = NodeIndex::new(2, 3).unwrap();
+        assert_eq!(
+            tx_args.merkle_store().get_path(tree.root(), index).unwrap().value,
+            [Felt::new(3); 4].into()
+        );
+
+        // conflicting entries are rejected
+        let result = TransactionArgs::builder()
+            .tx_script(tx_script)
+            .advice_entry(key_1.into(), vec![Felt::new(5)])
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            TransactionInputError::ConflictingAdviceMapEntry(key_1.into())
+        );
+    }
+
+    #[test]
+    fn expected_output_notes_add_recipient_advice() {
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let (script, _) = NoteScript::new(code, &Assembler::default()).unwrap();
+        let inputs = NoteInputs::new(vec![Felt::new(1), Felt::new(2)]).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+        let assets = NoteAssets::new(&[asset.into()]).unwrap();
+        let serial_num = [Felt::new(3); 4];
+        let details = NoteDetails::new(script, inputs, assets, serial_num);
+
+        let mut recipient_data = serial_num.to_vec();
+        recipient_data.extend_from_slice(details.script().hash().as_elements());
+        recipient_data.extend_from_slice(details.inputs().commitment().as_elements());
+
+        let mut tx_args = TransactionArgs::default();
+        tx_args.add_expected_output_note(&details);
+        let built_args = TransactionArgs::builder()
+            .expected_output_note(details.clone())
+            .build()
+            .unwrap();
+
+        for tx_args in [tx_args, built_args] {
+            assert_eq!(tx_args.get_expected_output_note(details.id()), Some(&details));
+            assert_eq!(tx_args.advice_map().get(&details.recipient()), Some(&recipient_data));
+            assert_eq!(
+                tx_args.advice_map().get(&details.inputs().commitment()),
+                Some(&details.inputs().to_padded_values())
+            );
+        }
+    }
+
+    #[test]
+    fn transaction_args_carry_fee() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fee = FungibleAsset::new(faucet_id, 10).unwrap();
+        assert_eq!(TransactionArgs::default().fee(), None);
+
+        let tx_args = TransactionArgs::builder().fee(fee).build().unwrap();
+        assert_eq!(tx_args.fee(), Some(fee));
+
+        let deserialized = TransactionArgs::read_from_bytes(&tx_args.to_bytes()).unwrap();
+        assert_eq!(deserialized.fee(), Some(fee));
+    }
+}
@@ -1,5 +1,11 @@
 use super::{
-    Account, AdviceInputs, BlockHeader, InputNotes, Program, TransactionArgs, TransactionInputs,
+    tx_args::{read_advice_map, read_merkle_store, write_advice_map, write_merkle_store},
+    Account, AdviceInputs, BlockHeader, Digest, Felt, InputNotes, Program, TransactionArgs,
+    TransactionInputs,
+};
+use crate::utils::{
+    collections::{BTreeMap, Vec},
+    serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 // TRANSACTION WITNESS
@@ -19,6 +25,12 @@ use super::{
 /// - Advice witness which contains all data requested by the VM from the advice provider while
 ///   executing the transaction program.
 ///
+/// The transaction program cannot be serialized; thus, a serialized transaction witness contains
+/// only the hash of the program, and the program must be recompiled from the transaction inputs
+/// and arguments when the witness is deserialized (see
+/// [TransactionWitness::read_without_program()]). This makes it possible to execute a transaction
+/// locally and to delegate proving of the transaction to another party.
+///
 /// TODO: currently, the advice witness contains redundant and irrelevant data (e.g., tx inputs
 /// and tx outputs). we should optimize it to contain only the minimum data required for
 /// executing/proving the transaction.
@@ -85,4 +97,101 @@ impl TransactionWitness {
     pub fn advice_witness(&self) -> &AdviceInputs {
         &self.advice_witness
     }
+
+    // SERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads a transaction witness serialized via [Serializable::to_bytes()] from the provided
+    /// bytes, and returns the hash of its program together with its transaction inputs,
+    /// transaction arguments and advice witness.
+    ///
+    /// The program of the transaction is expected to be recompiled from the returned inputs and
+    /// arguments, and the hash of the recompiled program must be checked against the returned
+    /// hash before the witness is instantiated via [TransactionWitness::new()].
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialized transaction witness.
+    pub fn read_without_program(
+        bytes: &[u8],
+    ) -> Result<(Digest, TransactionInputs, TransactionArgs, AdviceInputs), DeserializationError>
+    {
+        let SerializedWitness { program_hash, tx_inputs, tx_args, advice_witness } =
+            SerializedWitness::read_from_bytes(bytes)?;
+        Ok((program_hash, tx_inputs, tx_args, advice_witness))
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_witness(
+            &self.program,
+            &self.tx_inputs,
+            &self.tx_args,
+            &self.advice_witness,
+            target,
+        );
+    }
+}
+
+/// The components of a serialized transaction witness; the program of the transaction is
+/// represented by its hash.
+pub(super) struct SerializedWitness {
+    pub program_hash: Digest,
+    pub tx_inputs: TransactionInputs,
+    pub tx_args: TransactionArgs,
+    pub advice_witness: AdviceInputs,
+}
+
+impl Deserializable for SerializedWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let program_hash = Digest::read_from(source)?;
+        let tx_inputs = TransactionInputs::read_from(source)?;
+        let tx_args = TransactionArgs::read_from(source)?;
+        let advice_witness = read_advice_inputs(source)?;
+
+        Ok(Self { program_hash, tx_inputs, tx_args, advice_witness })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the components of a transaction witness into the specified target; the program is
+/// represented by its hash.
+pub(super) fn write_witness<W: ByteWriter>(
+    program: &Program,
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    advice_witness: &AdviceInputs,
+    target: &mut W,
+) {
+    program.hash().write_into(target);
+    tx_inputs.write_into(target);
+    tx_args.write_into(target);
+    write_advice_inputs(advice_witness, target);
+}
+
+/// Writes the advice stack, the advice map and the Merkle store of the provided advice inputs
+/// into the specified target.
+fn write_advice_inputs<W: ByteWriter>(advice_inputs: &AdviceInputs, target: &mut W) {
+    let (stack, map, store) = advice_inputs.clone().into_parts();
+    target.write_usize(stack.len());
+    target.write_many(&stack);
+    write_advice_map(&map.into_iter().collect(), target);
+    write_merkle_store(&store, target);
+}
+
+/// Reads advice inputs written via [write_advice_inputs()] from the specified source.
+fn read_advice_inputs<R: ByteReader>(source: &mut R) -> Result<AdviceInputs, DeserializationError> {
+    let stack_len = source.read_usize()?;
+    let stack = source.read_many::<Felt>(stack_len)?;
+    let map: BTreeMap<Digest, Vec<Felt>> = read_advice_map(source)?;
+    let store = read_merkle_store(source)?;
+
+    let mut advice_inputs = AdviceInputs::default().with_map(map).with_merkle_store(store);
+    advice_inputs.extend_stack(stack);
+    Ok(advice_inputs)
 }
@@ -0,0 +1,129 @@
+use core::fmt::Display;
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assembly::ProgramAst,
+    notes::{Note, NoteId},
+    transaction::{ProvenTransaction, TransactionArgs},
+    utils::serde::{Deserializable, Serializable},
+};
+use miden_tx::{
+    LocalChainSimulator, LocalTransactionProver, ProofSecurityPolicy, ProposedTransaction,
+    ProvingOptions, TransactionVerifier,
+};
+use wasm_bindgen::prelude::*;
+
+// LOCAL CHAIN
+// ================================================================================================
+
+/// A local chain against which transactions can be executed and proven client-side.
+///
+/// Accounts and notes are passed in their serialized form, and are identified by the hex encoding
+/// of their IDs.
+#[wasm_bindgen]
+pub struct LocalChain {
+    simulator: LocalChainSimulator,
+}
+
+#[wasm_bindgen]
+impl LocalChain {
+    /// Returns a new local chain consisting of an empty genesis block.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> LocalChain {
+        Self { simulator: LocalChainSimulator::new() }
+    }
+
+    /// Adds the provided serialized account to the local chain and returns its ID.
+    ///
+    /// The account is expected to exist on chain already, i.e., it must not be a new account.
+    pub fn add_account(&mut self, account: &[u8]) -> Result<String, JsError> {
+        let account = Account::read_from_bytes(account).map_err(to_js_error)?;
+        let account_id = account.id();
+        self.simulator.add_account(account, None).map_err(to_js_error)?;
+
+        Ok(account_id.to_hex())
+    }
+
+    /// Adds the provided serialized note to the next block of the local chain and returns its ID.
+    ///
+    /// The note can be consumed once the block has been sealed via [LocalChain::seal_block()].
+    pub fn add_note(&mut self, note: &[u8]) -> Result<String, JsError> {
+        let note = Note::read_from_bytes(note).map_err(to_js_error)?;
+        self.simulator.add_note(&note);
+
+        Ok(note.id().to_hex())
+    }
+
+    /// Seals a new block of the local chain and returns its number.
+    pub fn seal_block(&mut self) -> u32 {
+        self.simulator.seal_block().block_num()
+    }
+
+    /// Executes a transaction against the specified account which consumes the specified notes
+    /// and runs the provided transaction script, if any, then proves the transaction and returns
+    /// the serialized [ProvenTransaction].
+    pub fn execute_and_prove(
+        &mut self,
+        account_id: &str,
+        note_ids: Vec<String>,
+        tx_script: Option<String>,
+    ) -> Result<Vec<u8>, JsError> {
+        let account_id = AccountId::from_hex(account_id).map_err(to_js_error)?;
+        let note_ids = note_ids
+            .iter()
+            .map(|note_id| NoteId::try_from_hex(note_id))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(to_js_error)?;
+
+        let tx_args = match tx_script {
+            Some(tx_script) => {
+                let tx_script = ProgramAst::parse(&tx_script).map_err(to_js_error)?;
+                let tx_script = self
+                    .simulator
+                    .executor()
+                    .compile_tx_script(tx_script, vec![], vec![])
+                    .map_err(to_js_error)?;
+                Some(TransactionArgs::with_tx_script(tx_script))
+            },
+            None => None,
+        };
+
+        let transaction = ProposedTransaction::new(account_id, note_ids, tx_args);
+        let executed_transaction =
+            self.simulator.execute_transaction(&transaction).map_err(to_js_error)?;
+
+        let prover = LocalTransactionProver::new(ProvingOptions::default());
+        let proven_transaction =
+            prover.prove_transaction(executed_transaction).map_err(to_js_error)?;
+
+        Ok(proven_transaction.to_bytes())
+    }
+}
+
+impl Default for LocalChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Verifies the provided serialized [ProvenTransaction] against the current transaction kernel.
+#[wasm_bindgen]
+pub fn verify_transaction(proven_transaction: &[u8]) -> Result<(), JsError> {
+    let proven_transaction =
+        ProvenTransaction::read_from_bytes(proven_transaction).map_err(to_js_error)?;
+
+    TransactionVerifier::new(ProofSecurityPolicy::default())
+        .verify(proven_transaction)
+        .map_err(to_js_error)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Converts the provided error into a JavaScript error carrying its message.
+fn to_js_error<E: Display>(err: E) -> JsError {
+    JsError::new(&err.to_string())
+}